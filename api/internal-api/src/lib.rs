@@ -13,7 +13,12 @@ pub use self::requests::update_spu::*;
 pub use self::requests::update_replica::*;
 pub use self::requests::register_spu::*;
 pub use self::requests::update_lrs::*;
+pub use self::requests::replica_removed::*;
 pub use self::requests::update_all::*;
+pub use self::requests::update_acl::*;
+pub use self::requests::update_credentials::*;
+pub use self::requests::update_quotas::*;
+pub use self::requests::update_replication_throttle::*;
 
 use kf_protocol::api::RequestMessage;
 
@@ -9,7 +9,7 @@
 //! operation the comparing message with internal metadata.
 //!
 use std::fmt;
-
+use std::collections::BTreeMap;
 
 use kf_protocol::derive::{Decode, Encode};
 
@@ -68,6 +68,8 @@ pub struct Replica {
     pub id: ReplicaKey,
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
+    /// topic config overrides, applied to replica storage
+    pub config: BTreeMap<String, String>,
 }
 
 
@@ -105,6 +107,12 @@ impl Replica {
             id,
             leader,
             replicas,
+            config: BTreeMap::new(),
         }
     }
+
+    pub fn with_config(mut self, config: BTreeMap<String, String>) -> Self {
+        self.config = config;
+        self
+    }
 }
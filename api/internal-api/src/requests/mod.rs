@@ -3,3 +3,8 @@ pub mod update_spu;
 pub mod update_replica;
 pub mod register_spu;
 pub mod update_lrs;
+pub mod replica_removed;
+pub mod update_acl;
+pub mod update_credentials;
+pub mod update_quotas;
+pub mod update_replication_throttle;
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use kf_protocol::api::Request;
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+
+use types::SpuId;
+use metadata::partition::ReplicaKey;
+
+use crate::InternalScKey;
+
+/// Confirmation that SPU has removed replica and its storage
+#[derive(Decode, Encode, Debug, Default, PartialEq, Clone)]
+pub struct ReplicaRemovedRequest {
+    pub id: ReplicaKey,
+    pub spu: SpuId,
+}
+
+
+impl fmt::Display for ReplicaRemovedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,"ReplicaRemoved {} spu: {}",self.id,self.spu)
+    }
+}
+
+
+impl ReplicaRemovedRequest {
+    pub fn new(id: ReplicaKey,spu: SpuId) -> Self {
+        Self {
+            id,
+            spu
+        }
+    }
+}
+
+impl Request for ReplicaRemovedRequest {
+    const API_KEY: u16 = InternalScKey::ReplicaRemoved as u16;
+    type Response = ReplicaRemovedResponse;
+}
+
+#[derive(Decode, Encode, Default, Debug)]
+pub struct ReplicaRemovedResponse {}
@@ -0,0 +1,30 @@
+use kf_protocol::api::Request;
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+use metadata::acl::AclSpec;
+
+use crate::InternalSpuApi;
+
+/// Full set of ACL bindings.  SPU replaces its bindings with this set.
+#[derive(Decode, Encode, Debug, Default)]
+pub struct UpdateAclRequest {
+    pub acls: Vec<AclSpec>,
+}
+
+impl Request for UpdateAclRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateAcl as u16;
+    type Response = UpdateAclResponse;
+}
+
+impl UpdateAclRequest {
+    pub fn new(acls: Vec<AclSpec>) -> Self {
+        Self { acls }
+    }
+
+    pub fn acls(self) -> Vec<AclSpec> {
+        self.acls
+    }
+}
+
+#[derive(Decode, Encode, Default, Debug)]
+pub struct UpdateAclResponse {}
@@ -0,0 +1,30 @@
+use kf_protocol::api::Request;
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+use metadata::auth_token::SaslCredentialSpec;
+
+use crate::InternalSpuApi;
+
+/// Full set of SASL credentials.  SPU replaces its credentials with this set.
+#[derive(Decode, Encode, Debug, Default)]
+pub struct UpdateCredentialsRequest {
+    pub credentials: Vec<SaslCredentialSpec>,
+}
+
+impl Request for UpdateCredentialsRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateCredentials as u16;
+    type Response = UpdateCredentialsResponse;
+}
+
+impl UpdateCredentialsRequest {
+    pub fn new(credentials: Vec<SaslCredentialSpec>) -> Self {
+        Self { credentials }
+    }
+
+    pub fn credentials(self) -> Vec<SaslCredentialSpec> {
+        self.credentials
+    }
+}
+
+#[derive(Decode, Encode, Default, Debug)]
+pub struct UpdateCredentialsResponse {}
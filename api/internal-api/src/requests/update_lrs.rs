@@ -6,16 +6,20 @@ use kf_protocol::derive::Encode;
 
 
 use crate::InternalScKey;
+use types::SpuId;
 use metadata::partition::ReplicaKey;
 use metadata::partition::ReplicaStatus;
 
 /// Live Replica Status
-/// First lrs is leader by convention but should not be relied upon
+/// First lrs is leader by convention but should not be relied upon.
+/// SPU whose replica storage has failed reports itself in offline, statuses are not set in that case
 #[derive(Decode, Encode, Debug, Default, PartialEq, Clone)]
 pub struct UpdateLrsRequest {
     pub id: ReplicaKey,
     pub leader: ReplicaStatus,
-    pub replicas: Vec<ReplicaStatus>
+    pub replicas: Vec<ReplicaStatus>,
+    #[fluvio_kf(min_version = 1)]
+    pub offline: Vec<SpuId>
 }
 
 
@@ -32,13 +36,24 @@ impl UpdateLrsRequest {
         Self {
             id,
             leader,
-            replicas
+            replicas,
+            offline: vec![]
+        }
+    }
+
+    /// replica on spu is offline
+    pub fn offline(id: ReplicaKey,spu: SpuId) -> Self {
+        Self {
+            id,
+            offline: vec![spu],
+            ..Default::default()
         }
     }
 }
 
 impl Request for UpdateLrsRequest {
     const API_KEY: u16 = InternalScKey::UpdateLrs as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = UpdateLrsResponse;
 }
 
@@ -0,0 +1,30 @@
+use kf_protocol::api::Request;
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+use metadata::quota::ClientQuotaSpec;
+
+use crate::InternalSpuApi;
+
+/// Full set of client quotas.  SPU replaces its quotas with this set.
+#[derive(Decode, Encode, Debug, Default)]
+pub struct UpdateQuotasRequest {
+    pub quotas: Vec<ClientQuotaSpec>,
+}
+
+impl Request for UpdateQuotasRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateQuotas as u16;
+    type Response = UpdateQuotasResponse;
+}
+
+impl UpdateQuotasRequest {
+    pub fn new(quotas: Vec<ClientQuotaSpec>) -> Self {
+        Self { quotas }
+    }
+
+    pub fn quotas(self) -> Vec<ClientQuotaSpec> {
+        self.quotas
+    }
+}
+
+#[derive(Decode, Encode, Default, Debug)]
+pub struct UpdateQuotasResponse {}
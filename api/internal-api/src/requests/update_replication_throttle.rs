@@ -0,0 +1,43 @@
+use kf_protocol::api::Request;
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+
+use crate::InternalSpuApi;
+
+/// Replication throttle rate for out of sync followers, overrides SPU config.
+/// Negative rate reverts to rate from SPU config.
+#[derive(Decode, Encode, Debug)]
+pub struct UpdateReplicationThrottleRequest {
+    pub rate: i64,
+}
+
+impl Default for UpdateReplicationThrottleRequest {
+    fn default() -> Self {
+        Self { rate: -1 }
+    }
+}
+
+impl Request for UpdateReplicationThrottleRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateReplicationThrottle as u16;
+    type Response = UpdateReplicationThrottleResponse;
+}
+
+impl UpdateReplicationThrottleRequest {
+    pub fn new(rate: Option<u64>) -> Self {
+        Self {
+            rate: rate.map(|rate| rate as i64).unwrap_or(-1),
+        }
+    }
+
+    /// override rate, none if SPU config should be used
+    pub fn rate(&self) -> Option<u64> {
+        if self.rate < 0 {
+            None
+        } else {
+            Some(self.rate as u64)
+        }
+    }
+}
+
+#[derive(Decode, Encode, Default, Debug)]
+pub struct UpdateReplicationThrottleResponse {}
@@ -12,13 +12,15 @@ use kf_protocol::derive::Decode;
 
 use super::RegisterSpuRequest;
 use super::UpdateLrsRequest;
+use super::ReplicaRemovedRequest;
 
 
 #[derive(PartialEq, Debug, Encode, Decode, Clone, Copy)]
 #[repr(u16)]
 pub enum InternalScKey {
     RegisterSpu = 2000,
-    UpdateLrs = 2001
+    UpdateLrs = 2001,
+    ReplicaRemoved = 2002
 }
 
 
@@ -36,6 +38,7 @@ impl Default for InternalScKey {
 pub enum InternalScRequest {
      RegisterSpuRequest(RequestMessage<RegisterSpuRequest>),
      UpdateLrsRequest(RequestMessage<UpdateLrsRequest>),
+     ReplicaRemovedRequest(RequestMessage<ReplicaRemovedRequest>),
 }
 
 // Added to satisfy Encode/Decode traits
@@ -61,7 +64,8 @@ impl KfRequestMessage for InternalScRequest {
     {
         match header.api_key().try_into()? {
             InternalScKey::RegisterSpu => api_decode!(InternalScRequest, RegisterSpuRequest, src, header),
-            InternalScKey::UpdateLrs => api_decode!(InternalScRequest,UpdateLrsRequest, src, header)
+            InternalScKey::UpdateLrs => api_decode!(InternalScRequest,UpdateLrsRequest, src, header),
+            InternalScKey::ReplicaRemoved => api_decode!(InternalScRequest,ReplicaRemovedRequest, src, header)
         }
     }
 }
@@ -12,6 +12,10 @@ use kf_protocol::derive::Decode;
 use super::UpdateSpuRequest;
 use super::UpdateReplicaRequest;
 use super::UpdateAllRequest;
+use super::UpdateAclRequest;
+use super::UpdateCredentialsRequest;
+use super::UpdateQuotasRequest;
+use super::UpdateReplicationThrottleRequest;
 
 
 #[derive(PartialEq, Debug, Encode, Decode, Clone, Copy)]
@@ -20,6 +24,10 @@ pub enum InternalSpuApi {
     UpdateAll = 1000,
     UpdateSpu = 1001,
     UpdateReplica = 1003,
+    UpdateAcl = 1004,
+    UpdateCredentials = 1005,
+    UpdateQuotas = 1006,
+    UpdateReplicationThrottle = 1007,
 }
 
 
@@ -36,6 +44,10 @@ pub enum InternalSpuRequest {
     UpdateAllRequest(RequestMessage<UpdateAllRequest>),
     UpdateSpuRequest(RequestMessage<UpdateSpuRequest>),
     UpdateReplicaRequest(RequestMessage<UpdateReplicaRequest>),
+    UpdateAclRequest(RequestMessage<UpdateAclRequest>),
+    UpdateCredentialsRequest(RequestMessage<UpdateCredentialsRequest>),
+    UpdateQuotasRequest(RequestMessage<UpdateQuotasRequest>),
+    UpdateReplicationThrottleRequest(RequestMessage<UpdateReplicationThrottleRequest>),
 }
 
 // Added to satisfy Encode/Decode traits
@@ -68,7 +80,11 @@ impl KfRequestMessage for InternalSpuRequest {
         match header.api_key().try_into()? {
             InternalSpuApi::UpdateAll => api_decode!(InternalSpuRequest, UpdateAllRequest, src, header),
             InternalSpuApi::UpdateSpu => api_decode!(InternalSpuRequest, UpdateSpuRequest, src, header),
-            InternalSpuApi::UpdateReplica => api_decode!(InternalSpuRequest,UpdateReplicaRequest,src,header)
+            InternalSpuApi::UpdateReplica => api_decode!(InternalSpuRequest,UpdateReplicaRequest,src,header),
+            InternalSpuApi::UpdateAcl => api_decode!(InternalSpuRequest,UpdateAclRequest,src,header),
+            InternalSpuApi::UpdateCredentials => api_decode!(InternalSpuRequest,UpdateCredentialsRequest,src,header),
+            InternalSpuApi::UpdateQuotas => api_decode!(InternalSpuRequest,UpdateQuotasRequest,src,header),
+            InternalSpuApi::UpdateReplicationThrottle => api_decode!(InternalSpuRequest,UpdateReplicationThrottleRequest,src,header)
         }
     }
 }
@@ -15,6 +15,13 @@ pub enum ScApiKey {
 
     // Kafka
     KfMetadata = 3,
+    KfSaslHandshake = 17,
+    KfDescribeAcls = 29,
+    KfCreateAcls = 30,
+    KfDeleteAcls = 31,
+    KfDescribeConfigs = 32,
+    KfAlterConfigs = 33,
+    KfSaslAuthenticate = 36,
 
     // Topics
     FlvCreateTopics = 2001,
@@ -31,6 +38,12 @@ pub enum ScApiKey {
     FlvCreateSpuGroups = 2008,
     FlvDeleteSpuGroups = 2009,
     FlvFetchSpuGroups = 2010,
+
+    // Partitions
+    FlvRebalanceLeaders = 2011,
+
+    // Metadata
+    FlvWatchMetadata = 2012,
 }
 
 impl Default for ScApiKey {
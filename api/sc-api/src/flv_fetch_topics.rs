@@ -70,6 +70,9 @@ pub struct FlvPartitionReplica {
 
     /// Only live replicas in replica assignment
     pub live_replicas: Vec<i32>,
+
+    /// Bytes of log segments on leader, -1 if unknown
+    pub size: i64,
 }
 
 // -----------------------------------
@@ -78,6 +81,7 @@ pub struct FlvPartitionReplica {
 
 impl Request for FlvFetchTopicsRequest {
     const API_KEY: u16 = ScApiKey::FlvFetchTopics as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = FlvFetchTopicsResponse;
 }
 
@@ -112,6 +116,15 @@ impl FlvFetchTopicResponse {
         }
     }
 
+    /// Constructor for topics that principal may not describe
+    pub fn new_not_authorized(name: String) -> Self {
+        FlvFetchTopicResponse {
+            name,
+            error_code: FlvErrorCode::TopicAuthorizationFailed,
+            topic: None,
+        }
+    }
+
     /// Update topic partitions.
     /// Requirements:
     ///  * Must be called with valid topic, otherwise, update will fail silently
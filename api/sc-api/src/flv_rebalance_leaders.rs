@@ -0,0 +1,65 @@
+//!
+//! # Rebalance Leaders
+//!
+//! Public API to request the SC to move partition leadership back to the preferred replica.
+//! Preferred replica is the first entry in the partition replica list.
+//!
+use kf_protocol::api::Request;
+use kf_protocol::derive::{Decode, Encode};
+use kf_protocol::api::FlvErrorCode;
+
+use crate::ScApiKey;
+
+// -----------------------------------
+// FlvRebalanceLeadersRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Default, Debug)]
+pub struct FlvRebalanceLeadersRequest {
+    /// Topics to rebalance, empty for all topics
+    pub topics: Vec<String>,
+
+    /// Compute leader moves without applying them
+    pub dry_run: bool,
+}
+
+// -----------------------------------
+// FlvRebalanceLeadersResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Default, Debug)]
+pub struct FlvRebalanceLeadersResponse {
+    /// The error code, None for no errors
+    pub error_code: FlvErrorCode,
+
+    /// The error message, if any
+    pub error_message: Option<String>,
+
+    /// Leader moves planned (dry run) or applied
+    pub moves: Vec<FlvLeaderMove>,
+}
+
+#[derive(Encode, Decode, Default, Debug)]
+pub struct FlvLeaderMove {
+    /// The topic name
+    pub topic: String,
+
+    /// The partition index
+    pub partition_idx: i32,
+
+    /// The current leader
+    pub from_leader: i32,
+
+    /// The preferred leader
+    pub to_leader: i32,
+}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl Request for FlvRebalanceLeadersRequest {
+    const API_KEY: u16 = ScApiKey::FlvRebalanceLeaders as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = FlvRebalanceLeadersResponse;
+}
@@ -0,0 +1,144 @@
+//!
+//! # Watch Metadata
+//!
+//! Public API to subscribe to SC metadata changes. The SC replies with a snapshot
+//! (or the changes missed since a known epoch) and keeps pushing incremental
+//! changes as additional responses carrying the same correlation id.
+//!
+//! Epochs are only meaningful within the SC generation that produced them, a client
+//! resuming with another generation receives a snapshot.
+//!
+use kf_protocol::api::Request;
+use kf_protocol::api::FlvErrorCode;
+use kf_protocol::derive::{Decode, Encode};
+
+use metadata::topic::{TopicSpec, TopicStatus};
+use metadata::partition::{PartitionSpec, PartitionStatus};
+use metadata::spu::{SpuSpec, SpuStatus};
+
+use crate::ScApiKey;
+
+/// Epoch value requesting a full snapshot
+pub const WATCH_SNAPSHOT_EPOCH: i64 = -1;
+
+// -----------------------------------
+// FlvWatchMetadataRequest
+// -----------------------------------
+
+#[derive(Decode, Encode, Debug)]
+pub struct FlvWatchMetadataRequest {
+    /// Subscribe to topic changes
+    pub topics: bool,
+
+    /// Subscribe to partition changes
+    pub partitions: bool,
+
+    /// Subscribe to SPU changes
+    pub spus: bool,
+
+    /// Generation of SC that produced the epoch, ignored for a full snapshot
+    pub generation: i64,
+
+    /// Last epoch seen by the client, -1 for a full snapshot
+    pub epoch: i64,
+}
+
+// -----------------------------------
+// FlvWatchMetadataResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Default, Debug)]
+pub struct FlvWatchMetadataResponse {
+    /// The error code, None for no errors
+    pub error_code: FlvErrorCode,
+
+    /// Generation of SC process, epochs of other generation are not valid
+    pub generation: i64,
+
+    /// Epoch of the last change included in this response
+    pub epoch: i64,
+
+    /// Changes are a full snapshot, client must discard previous state
+    pub snapshot: bool,
+
+    /// Topic changes
+    pub topics: Vec<FlvTopicChange>,
+
+    /// Partition changes
+    pub partitions: Vec<FlvPartitionChange>,
+
+    /// SPU changes
+    pub spus: Vec<FlvSpuChange>,
+}
+
+/// Kind of change applied to a metadata object
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum FlvChangeType {
+    Add,
+    Mod,
+    Delete,
+}
+
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct FlvTopicChange {
+    pub change_type: FlvChangeType,
+    pub name: String,
+    pub spec: TopicSpec,
+    pub status: TopicStatus,
+}
+
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct FlvPartitionChange {
+    pub change_type: FlvChangeType,
+    pub topic: String,
+    pub partition: i32,
+    pub spec: PartitionSpec,
+    pub status: PartitionStatus,
+}
+
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq)]
+pub struct FlvSpuChange {
+    pub change_type: FlvChangeType,
+    pub name: String,
+    pub spec: SpuSpec,
+    pub status: SpuStatus,
+}
+
+// -----------------------------------
+// Defaults
+// -----------------------------------
+
+impl ::std::default::Default for FlvWatchMetadataRequest {
+    fn default() -> Self {
+        Self {
+            topics: true,
+            partitions: true,
+            spus: true,
+            generation: 0,
+            epoch: WATCH_SNAPSHOT_EPOCH,
+        }
+    }
+}
+
+impl ::std::default::Default for FlvChangeType {
+    fn default() -> Self {
+        FlvChangeType::Add
+    }
+}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl FlvWatchMetadataResponse {
+    /// true if response carries no changes
+    pub fn is_empty(&self) -> bool {
+        self.topics.is_empty() && self.partitions.is_empty() && self.spus.is_empty()
+    }
+}
+
+impl Request for FlvWatchMetadataRequest {
+    const API_KEY: u16 = ScApiKey::FlvWatchMetadata as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = FlvWatchMetadataResponse;
+}
@@ -9,6 +9,8 @@ mod flv_delete_spu_groups;
 mod flv_fetch_spu_groups;
 mod flv_fetch_topics;
 mod flv_topic_composition;
+mod flv_rebalance_leaders;
+mod flv_watch_metadata;
 mod api_versions;
 mod public_api;
 mod common;
@@ -47,8 +49,13 @@ pub mod topic {
     pub use crate::flv_delete_topics::*;
     pub use crate::flv_fetch_topics::*;
     pub use crate::flv_topic_composition::*;
+    pub use crate::flv_rebalance_leaders::*;
 
     pub use metadata::topic::TopicSpec as FlvTopicSpecMetadata;
     pub use metadata::topic::PartitionMap as FlvTopicPartitionMap;
     pub use metadata::topic::TopicResolution as FlvTopicResolution;
 }
+
+pub mod watch {
+    pub use crate::flv_watch_metadata::*;
+}
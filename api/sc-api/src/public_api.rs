@@ -19,6 +19,13 @@ use kf_protocol::api::api_decode;
 use kf_protocol::derive::Encode;
 
 use kf_protocol::message::metadata::KfMetadataRequest;
+use kf_protocol::message::config::KfDescribeConfigsRequest;
+use kf_protocol::message::config::KfAlterConfigsRequest;
+use kf_protocol::message::acl::KfCreateAclsRequest;
+use kf_protocol::message::acl::KfDescribeAclsRequest;
+use kf_protocol::message::acl::KfDeleteAclsRequest;
+use kf_protocol::message::sasl::KfSaslHandshakeRequest;
+use kf_protocol::message::sasl::KfSaslAuthenticateRequest;
 
 use crate::versions::ApiVersionsRequest;
 use crate::spu::FlvCreateCustomSpusRequest;
@@ -31,6 +38,8 @@ use crate::topic::FlvCreateTopicsRequest;
 use crate::topic::FlvDeleteTopicsRequest;
 use crate::topic::FlvFetchTopicsRequest;
 use crate::topic::FlvTopicCompositionRequest;
+use crate::topic::FlvRebalanceLeadersRequest;
+use crate::watch::FlvWatchMetadataRequest;
 
 use super::ScApiKey;
 
@@ -41,6 +50,13 @@ pub enum PublicRequest {
 
     // Kafka
     KfMetadataRequest(RequestMessage<KfMetadataRequest>),
+    KfDescribeConfigsRequest(RequestMessage<KfDescribeConfigsRequest>),
+    KfAlterConfigsRequest(RequestMessage<KfAlterConfigsRequest>),
+    KfCreateAclsRequest(RequestMessage<KfCreateAclsRequest>),
+    KfDescribeAclsRequest(RequestMessage<KfDescribeAclsRequest>),
+    KfDeleteAclsRequest(RequestMessage<KfDeleteAclsRequest>),
+    KfSaslHandshakeRequest(RequestMessage<KfSaslHandshakeRequest>),
+    KfSaslAuthenticateRequest(RequestMessage<KfSaslAuthenticateRequest>),
 
     // Fluvio - Topics
     FlvCreateTopicsRequest(RequestMessage<FlvCreateTopicsRequest>),
@@ -56,6 +72,12 @@ pub enum PublicRequest {
     FlvCreateSpuGroupsRequest(RequestMessage<FlvCreateSpuGroupsRequest>),
     FlvDeleteSpuGroupsRequest(RequestMessage<FlvDeleteSpuGroupsRequest>),
     FlvFetchSpuGroupsRequest(RequestMessage<FlvFetchSpuGroupsRequest>),
+
+    // Fluvio - Partitions
+    FlvRebalanceLeadersRequest(RequestMessage<FlvRebalanceLeadersRequest>),
+
+    // Fluvio - Metadata
+    FlvWatchMetadataRequest(RequestMessage<FlvWatchMetadataRequest>),
 }
 
 impl Default for PublicRequest {
@@ -80,6 +102,27 @@ impl KfRequestMessage for PublicRequest {
 
             //Kafka
             ScApiKey::KfMetadata => api_decode!(PublicRequest, KfMetadataRequest, src, header),
+            ScApiKey::KfDescribeConfigs => {
+                api_decode!(PublicRequest, KfDescribeConfigsRequest, src, header)
+            }
+            ScApiKey::KfAlterConfigs => {
+                api_decode!(PublicRequest, KfAlterConfigsRequest, src, header)
+            }
+            ScApiKey::KfCreateAcls => {
+                api_decode!(PublicRequest, KfCreateAclsRequest, src, header)
+            }
+            ScApiKey::KfDescribeAcls => {
+                api_decode!(PublicRequest, KfDescribeAclsRequest, src, header)
+            }
+            ScApiKey::KfDeleteAcls => {
+                api_decode!(PublicRequest, KfDeleteAclsRequest, src, header)
+            }
+            ScApiKey::KfSaslHandshake => {
+                api_decode!(PublicRequest, KfSaslHandshakeRequest, src, header)
+            }
+            ScApiKey::KfSaslAuthenticate => {
+                api_decode!(PublicRequest, KfSaslAuthenticateRequest, src, header)
+            }
 
             // Fluvio - Topics
             ScApiKey::FlvCreateTopics => {
@@ -113,6 +156,16 @@ impl KfRequestMessage for PublicRequest {
             ScApiKey::FlvFetchSpuGroups => {
                 api_decode!(PublicRequest, FlvFetchSpuGroupsRequest, src, header)
             }
+
+            // Fluvio - Partitions
+            ScApiKey::FlvRebalanceLeaders => {
+                api_decode!(PublicRequest, FlvRebalanceLeadersRequest, src, header)
+            }
+
+            // Fluvio - Metadata
+            ScApiKey::FlvWatchMetadata => {
+                api_decode!(PublicRequest, FlvWatchMetadataRequest, src, header)
+            }
         }
     }
 }
@@ -11,6 +11,12 @@ pub enum SpuApiKey {
     // Kafka
     KfProduce = 0,
     KfFetch = 1,
+    KfListOffsets = 2,
+    KfSaslHandshake = 17,
+    KfDeleteRecords = 21,
+    KfAlterReplicaLogDirs = 34,
+    KfDescribeLogDirs = 35,
+    KfSaslAuthenticate = 36,
 
     // Fluvio
     FlvFetchLocalSpu = 1001,
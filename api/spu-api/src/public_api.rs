@@ -15,6 +15,12 @@ use kf_protocol::api::api_decode;
 use kf_protocol::message::produce::DefaultKfProduceRequest;
 use kf_protocol::api::RequestHeader;
 use kf_protocol::api::RequestMessage;
+use kf_protocol::message::sasl::KfSaslHandshakeRequest;
+use kf_protocol::message::sasl::KfSaslAuthenticateRequest;
+use kf_protocol::message::records::KfDeleteRecordsRequest;
+use kf_protocol::message::log_dirs::KfDescribeLogDirsRequest;
+use kf_protocol::message::log_dirs::KfAlterReplicaLogDirsRequest;
+use kf_protocol::message::offset::KfListOffsetRequest;
 use kf_socket::KfFileFetchRequest;
 
 use crate::SpuApiKey;
@@ -30,6 +36,12 @@ pub enum PublicRequest {
     // Kafka
     KfProduceRequest(RequestMessage<DefaultKfProduceRequest>),
     KfFileFetchRequest(RequestMessage<KfFileFetchRequest>),
+    KfListOffsetRequest(RequestMessage<KfListOffsetRequest>),
+    KfSaslHandshakeRequest(RequestMessage<KfSaslHandshakeRequest>),
+    KfSaslAuthenticateRequest(RequestMessage<KfSaslAuthenticateRequest>),
+    KfDeleteRecordsRequest(RequestMessage<KfDeleteRecordsRequest>),
+    KfDescribeLogDirsRequest(RequestMessage<KfDescribeLogDirsRequest>),
+    KfAlterReplicaLogDirsRequest(RequestMessage<KfAlterReplicaLogDirsRequest>),
 
     // Fluvio
     FlvFetchLocalSpuRequest(RequestMessage<FlvFetchLocalSpuRequest>),
@@ -64,6 +76,24 @@ impl KfRequestMessage for PublicRequest {
                 )))
             }
             SpuApiKey::KfFetch => api_decode!(PublicRequest, KfFileFetchRequest, src, header),
+            SpuApiKey::KfListOffsets => {
+                api_decode!(PublicRequest, KfListOffsetRequest, src, header)
+            }
+            SpuApiKey::KfSaslHandshake => {
+                api_decode!(PublicRequest, KfSaslHandshakeRequest, src, header)
+            }
+            SpuApiKey::KfSaslAuthenticate => {
+                api_decode!(PublicRequest, KfSaslAuthenticateRequest, src, header)
+            }
+            SpuApiKey::KfDeleteRecords => {
+                api_decode!(PublicRequest, KfDeleteRecordsRequest, src, header)
+            }
+            SpuApiKey::KfDescribeLogDirs => {
+                api_decode!(PublicRequest, KfDescribeLogDirsRequest, src, header)
+            }
+            SpuApiKey::KfAlterReplicaLogDirs => {
+                api_decode!(PublicRequest, KfAlterReplicaLogDirsRequest, src, header)
+            }
 
             // Fluvio
             SpuApiKey::FlvFetchLocalSpu => {
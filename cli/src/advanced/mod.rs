@@ -1,17 +1,20 @@
 mod generate;
 mod run;
 mod request_api;
+mod scram_credential;
 
 use structopt::StructOpt;
 
 use generate::GenerateTemplateOpt;
 use run::RunRequestOpt;
 use request_api::RequestApi;
+use scram_credential::ScramCredentialOpt;
 
 pub use generate::process_generate_template;
 pub use run::process_run_request;
 pub use request_api::send_request_to_server;
 pub use request_api::parse_request_from_file;
+pub use scram_credential::process_scram_credential;
 
 use crate::error::CliError;
 
@@ -32,11 +35,20 @@ pub enum AdvancedOpt {
 {all-args}
 ", about = "Send request to server")]
     Run(RunRequestOpt),
+
+    #[structopt(name = "scram-credential", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+", about = "Generate SCRAM credential for SASL authentication")]
+    ScramCredential(ScramCredentialOpt),
 }
 
 pub fn process_advanced(opt: AdvancedOpt) -> Result<(), CliError> {
     match opt {
         AdvancedOpt::Generate(generate_opt) => process_generate_template(generate_opt),
         AdvancedOpt::Run(run_opt) => process_run_request(run_opt),
+        AdvancedOpt::ScramCredential(scram_opt) => process_scram_credential(scram_opt),
     }
 }
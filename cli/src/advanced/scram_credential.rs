@@ -0,0 +1,168 @@
+//!
+//! # Generate SCRAM Credential
+//!
+//! Generate salted SCRAM credential for a user.  Output can be applied with kubectl
+//! or copied into local metadata store, clear text password is never stored.
+//!
+use structopt::StructOpt;
+use std::env;
+use std::fs::read_to_string;
+use std::io;
+use std::io::BufRead;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use kf_service::sasl::ScramCredential;
+use kf_service::sasl::ScramMechanism;
+use kf_service::sasl::MIN_ITERATIONS;
+use k8_metadata::auth_token::SaslCredentialSpec;
+use k8_metadata::auth_token::CredentialMechanism;
+use k8_metadata::auth_token::credential_name;
+use k8_metadata::core::Spec;
+use k8_metadata::core::metadata::InputK8Obj;
+use k8_metadata::core::metadata::InputObjectMeta;
+
+use crate::error::CliError;
+
+#[derive(Debug, StructOpt)]
+pub struct ScramCredentialOpt {
+    /// User name
+    #[structopt(short = "u", long = "user", value_name = "string")]
+    user: String,
+
+    /// Read password from file (first line)
+    #[structopt(
+        long = "password-file",
+        value_name = "file",
+        parse(from_os_str),
+        raw(conflicts_with_all = "&[\"password_env\", \"password_stdin\"]")
+    )]
+    password_file: Option<PathBuf>,
+
+    /// Read password from environment variable
+    #[structopt(
+        long = "password-env",
+        value_name = "variable",
+        raw(conflicts_with = "\"password_stdin\"")
+    )]
+    password_env: Option<String>,
+
+    /// Read password from first line of stdin
+    #[structopt(long = "password-stdin")]
+    password_stdin: bool,
+
+    /// SCRAM mechanism
+    #[structopt(
+        short = "m",
+        long = "mechanism",
+        value_name = "mechanism",
+        default_value = "SCRAM-SHA-256",
+        raw(possible_values = "&[\"SCRAM-SHA-256\", \"SCRAM-SHA-512\"]")
+    )]
+    mechanism: String,
+
+    /// Number of iterations
+    #[structopt(short = "i", long = "iterations", value_name = "integer", default_value = "4096")]
+    iterations: i32,
+
+    /// Namespace of credential object
+    #[structopt(short = "n", long = "namespace", value_name = "string", default_value = "default")]
+    namespace: String,
+}
+
+// -----------------------------------
+//  CLI Processing
+// -----------------------------------
+
+/// Generate credential and print SaslCredential object
+pub fn process_scram_credential(opt: ScramCredentialOpt) -> Result<(), CliError> {
+    if opt.iterations < MIN_ITERATIONS {
+        return Err(CliError::IoError(IoError::new(
+            ErrorKind::InvalidInput,
+            format!("iterations must be at least {}", MIN_ITERATIONS),
+        )));
+    }
+
+    if opt.user.is_empty() {
+        return Err(CliError::IoError(IoError::new(
+            ErrorKind::InvalidInput,
+            "user must not be empty",
+        )));
+    }
+
+    let (mechanism, k8_mechanism) = match opt.mechanism.as_str() {
+        "SCRAM-SHA-512" => (ScramMechanism::Sha512, CredentialMechanism::ScramSha512),
+        _ => (ScramMechanism::Sha256, CredentialMechanism::ScramSha256),
+    };
+
+    let password = read_password(&opt)?;
+    let credential = ScramCredential::generate(&mechanism, &password, opt.iterations);
+    let spec = SaslCredentialSpec {
+        user: opt.user.clone(),
+        mechanism: k8_mechanism,
+        salt: credential.encoded_salt(),
+        iterations: credential.iterations,
+        stored_key: credential.encoded_stored_key(),
+        server_key: credential.encoded_server_key(),
+    };
+
+    let input = InputK8Obj {
+        api_version: SaslCredentialSpec::api_version(),
+        kind: SaslCredentialSpec::kind(),
+        metadata: InputObjectMeta {
+            name: credential_name(&opt.user, &opt.mechanism),
+            namespace: opt.namespace,
+            ..Default::default()
+        },
+        spec,
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string_pretty(&input)
+        .map_err(|err| IoError::new(ErrorKind::InvalidData, format!("{}", err)))?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+/// Read password from file, environment, stdin or prompt (without echo), in that order.
+/// Password is never taken from command line as it would leak into shell history and process list.
+fn read_password(opt: &ScramCredentialOpt) -> Result<String, CliError> {
+    let password = if let Some(ref file) = opt.password_file {
+        let content = read_to_string(file)?;
+        first_line(&content)
+    } else if let Some(ref var) = opt.password_env {
+        env::var(var).map_err(|err| {
+            IoError::new(ErrorKind::InvalidInput, format!("{}: {}", var, err))
+        })?
+    } else if opt.password_stdin {
+        let mut line = String::new();
+        io::stdin().lock().read_line(&mut line)?;
+        first_line(&line)
+    } else {
+        let password = rpassword::read_password_from_tty(Some("Password: "))?;
+        let confirm = rpassword::read_password_from_tty(Some("Confirm password: "))?;
+        if password != confirm {
+            return Err(CliError::IoError(IoError::new(
+                ErrorKind::InvalidInput,
+                "passwords do not match",
+            )));
+        }
+        password
+    };
+
+    if password.is_empty() {
+        return Err(CliError::IoError(IoError::new(
+            ErrorKind::InvalidInput,
+            "password must not be empty",
+        )));
+    }
+
+    Ok(password)
+}
+
+/// first line without line terminator
+fn first_line(content: &str) -> String {
+    content.lines().next().unwrap_or("").to_owned()
+}
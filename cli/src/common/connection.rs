@@ -53,14 +53,7 @@ impl Connection {
     {
         trace!("send API '{}' req to srv '{}'", R::API_KEY, self.server_addr);
 
-        let mut req_msg: RequestMessage<R> = RequestMessage::new_request(request);
-        req_msg
-            .get_mut_header()
-            .set_client_id("fluvio")
-            .set_correlation_id(rand_correlation_id());
-        if let Some(ver) = version {
-            req_msg.get_mut_header().set_api_version(ver);
-        }
+        let req_msg = new_request_message(request, version);
         // send request & save response
         match self.socket.send(&req_msg).await {
             Err(err) => Err(CliError::IoError(IoError::new(
@@ -79,8 +72,76 @@ impl Connection {
         }
     }
 
+    /// Send request without waiting for response. Server may reply with multiple
+    /// responses which are read with next_response.
+    pub async fn send_stream_request<R>(
+        &mut self,
+        request: R,
+        version: Option<i16>,
+    ) -> Result<RequestMessage<R>, CliError>
+    where
+        R: Request,
+    {
+        trace!("send stream API '{}' req to srv '{}'", R::API_KEY, self.server_addr);
+
+        let req_msg = new_request_message(request, version);
+        self.socket
+            .get_mut_sink()
+            .send_request(&req_msg)
+            .await
+            .map_err(|err| {
+                CliError::IoError(IoError::new(
+                    ErrorKind::Other,
+                    format!("send '{}' to srv '{}': {}", R::API_KEY, self.server_addr, err),
+                ))
+            })?;
+
+        Ok(req_msg)
+    }
+
+    /// Wait for next response of a stream request
+    pub async fn next_response<R>(
+        &mut self,
+        req_msg: &RequestMessage<R>,
+    ) -> Result<R::Response, CliError>
+    where
+        R: Request,
+    {
+        match self.socket.get_mut_stream().next_response(req_msg).await {
+            Err(err) => Err(CliError::IoError(IoError::new(
+                ErrorKind::Other,
+                format!(
+                    "rsvd '{}' from srv '{}': {}",
+                    R::API_KEY,
+                    self.server_addr,
+                    err
+                ),
+            ))),
+            Ok(response) => {
+                trace!("rsvd '{}' res from srv '{}' ", R::API_KEY, self.server_addr);
+                Ok(response.response)
+            }
+        }
+    }
+
     /// Accessor for server address
     pub fn server_addr(&self) -> &SocketAddr {
         &self.server_addr
     }
 }
+
+/// Create request message with fluvio client id and random correlation id
+fn new_request_message<R>(request: R, version: Option<i16>) -> RequestMessage<R>
+where
+    R: Request,
+{
+    let mut req_msg: RequestMessage<R> = RequestMessage::new_request(request);
+    req_msg
+        .get_mut_header()
+        .set_client_id("fluvio")
+        .set_correlation_id(rand_correlation_id());
+    if let Some(ver) = version {
+        req_msg.get_mut_header().set_api_version(ver);
+    }
+    req_msg
+}
@@ -12,17 +12,20 @@ use crate::common::Connection;
 
 /// Query for API versions
 pub async fn kf_get_api_versions(conn: &mut Connection) -> Result<KfApiVersions, CliError> {
+    // brokers reject v3 requests without a valid client software name & version
+    let mut request = KfApiVersionsRequest::default();
+    request.client_software_name = env!("CARGO_PKG_NAME").to_owned();
+    request.client_software_version = env!("CARGO_PKG_VERSION").to_owned();
+
     // Version is None, as we want API to request max_version.
-    let response = conn
-        .send_request(KfApiVersionsRequest::default(), None)
-        .await?;
+    let response = conn.send_request(request, None).await?;
     Ok(response.api_keys)
 }
 
 /// Given an API key, it returns max_version. None if not found
 pub fn kf_lookup_version(api_key: AllKfApiKey, versions: &KfApiVersions) -> Option<i16> {
     for version in versions {
-        if version.index == api_key as i16 {
+        if version.api_key == api_key as i16 {
             return Some(version.max_version);
         }
     }
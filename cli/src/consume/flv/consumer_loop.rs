@@ -0,0 +1,111 @@
+//!
+//! # Fluvio SC - Consume Loop
+//!
+//! Consume logs through consumer client, which looks-up partition leaders
+//! from SC and follows them if leader moves.
+//!
+
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use ctrlc;
+use log::debug;
+
+use flv_client::Consumer;
+use flv_client::ConsumerConfig;
+use flv_client::ConsumerOffset;
+
+use futures::channel::mpsc;
+use futures::channel::mpsc::Sender;
+use futures::future::FutureExt;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use futures::select;
+use future_helper::run_block_on;
+use future_helper::sleep;
+
+use crate::error::CliError;
+
+use crate::consume::ConsumeLogConfig;
+use crate::consume::ReponseLogParams;
+use crate::consume::process_consumer_records;
+
+// -----------------------------------
+//  Fluvio SC - Consume Loop
+// -----------------------------------
+
+/// Consume logs from one partition, or all partitions of topic if partition is not set.
+/// Exits after first round unless continous, or on Ctrl-C
+pub async fn sc_consume_loop(
+    sc_addr: SocketAddr,
+    cfg: ConsumeLogConfig,
+    partition: Option<i32>,
+    response_params: ReponseLogParams,
+) -> Result<(), CliError> {
+    let config = ConsumerConfig {
+        client_id: "fluvio".to_owned(),
+        max_bytes: cfg.max_bytes,
+        ..Default::default()
+    };
+    let mut consumer = Consumer::new(sc_addr, config);
+
+    let start = if cfg.from_beginning {
+        ConsumerOffset::Beginning
+    } else {
+        ConsumerOffset::End
+    };
+    match partition {
+        Some(partition) => {
+            debug!("consume topic '{}' partition: {}", cfg.topic, partition);
+            consumer.assign(&cfg.topic, partition, start);
+        }
+        None => {
+            debug!("consume topic '{}'", cfg.topic);
+            consumer.subscribe(&cfg.topic, start).await?;
+        }
+    }
+
+    // consume channel
+    let (sender, mut receiver) = mpsc::channel::<bool>(5);
+
+    // attach sender to Ctrl-C event handler
+    if let Err(err) = ctrlc::set_handler(move || {
+        debug!("<Ctrl-C> received");
+        send_ctrlc_signal(sender.clone());
+    }) {
+        return Err(CliError::IoError(IoError::new(
+            ErrorKind::InvalidData,
+            format!("CTRL-C handler: {}", err),
+        )));
+    }
+
+    // consumer waits between polls if there are no new records
+    let delay = Duration::from_millis(0);
+    loop {
+        select! {
+            _ = (sleep(delay)).fuse() => {
+                let records = consumer.poll_records().await?;
+                process_consumer_records(&records, &response_params);
+
+                if !cfg.continous {
+                    return Ok(());
+                }
+            },
+            _ = receiver.next() => {
+                debug!("<Ctrl-C>... consumer of topic {} exiting", cfg.topic);
+                println!("");
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Send CTRL c signal to consume loop
+fn send_ctrlc_signal(mut sender: Sender<bool>) {
+    let _ = run_block_on(async move {
+        sender.send(true).await.expect("should not fail");
+        Ok(()) as Result<(), ()>
+    });
+}
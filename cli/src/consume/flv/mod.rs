@@ -1,5 +1,6 @@
 mod sc_fetch_topic_all;
 mod sc_fetch_topic_part;
+mod consumer_loop;
 mod spu_fetch_topic_part;
 mod spu_fetch_log_loop;
 mod query;
@@ -1,7 +1,8 @@
 //!
 //! # Fluvio SPU - Fetch logs from Topic / Partition
 //!
-//! Connect to Fluvio Streaming Processing Unit, fetch logs (if leader)
+//! Connect to Fluvio Streaming Processing Unit, fetch logs (if leader).
+//! Consumer client looks-up leaders from SC, so SPU is fetched directly.
 //!
 //! ## Connect to SPU
 //!   * APIVersions
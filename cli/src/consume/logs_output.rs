@@ -10,10 +10,13 @@ use serde_json;
 use serde_json::Value;
 use types::print_cli_err;
 
+use kf_protocol::api::DefaultAsyncBuffer;
 use kf_protocol::api::DefaultRecords;
 use kf_protocol::message::fetch::DefaultKfFetchResponse;
 use kf_protocol::message::fetch::FetchablePartitionResponse;
 
+use flv_client::ConsumerRecord;
+
 use crate::error::CliError;
 use crate::common::ConsumeOutputType;
 use crate::common::{bytes_to_hex_dump, hex_dump_separator};
@@ -65,6 +68,50 @@ pub fn process_fetch_topic_reponse(
     Ok(())
 }
 
+/// Process records from consumer based on output type
+pub fn process_consumer_records(records: &[ConsumerRecord], params: &ReponseLogParams) {
+    let values: Vec<DefaultAsyncBuffer> = records
+        .iter()
+        .map(|record| DefaultAsyncBuffer::from(record.value.clone()))
+        .collect();
+
+    match params.output {
+        ConsumeOutputType::json => {
+            let json_records: Vec<Value> = values
+                .iter()
+                .filter_map(|value| value_to_json_record(value, params.suppress))
+                .collect();
+            if !json_records.is_empty() {
+                print_json_records(&json_records);
+            }
+        }
+        ConsumeOutputType::text => {
+            for value in &values {
+                print_text_value(value, params.suppress);
+            }
+        }
+        ConsumeOutputType::binary => {
+            let mut printed = false;
+            for value in &values {
+                printed |= print_binary_value(value);
+            }
+            if printed {
+                println!("{}", hex_dump_separator());
+            }
+        }
+        ConsumeOutputType::dynamic => {
+            for value in &values {
+                print_dynamic_value(value);
+            }
+        }
+        ConsumeOutputType::raw => {
+            for value in &values {
+                print_raw_value(value);
+            }
+        }
+    }
+}
+
 // -----------------------------------
 //  JSON
 // -----------------------------------
@@ -100,17 +147,8 @@ pub fn partition_to_json_records(
     // convert all batches to json records
     for batch in &partition.records.batches {
         for record in &batch.records {
-            if let Some(batch_record) = record.get_value().inner_value_ref() {
-                match serde_json::from_slice(&batch_record) {
-                    Ok(value) => json_records.push(value),
-                    Err(_) => {
-                        if !suppress {
-                            json_records.push(serde_json::json!({
-                                "error": record.get_value().describe()
-                            }));
-                        }
-                    }
-                }
+            if let Some(value) = value_to_json_record(record.get_value(), suppress) {
+                json_records.push(value);
             }
         }
     }
@@ -118,6 +156,23 @@ pub fn partition_to_json_records(
     json_records
 }
 
+/// Parse record value to json, describe error unless suppressed
+fn value_to_json_record(value: &DefaultAsyncBuffer, suppress: bool) -> Option<Value> {
+    let batch_record = value.inner_value_ref().as_ref()?;
+    match serde_json::from_slice(batch_record) {
+        Ok(value) => Some(value),
+        Err(_) => {
+            if suppress {
+                None
+            } else {
+                Some(serde_json::json!({
+                    "error": value.describe()
+                }))
+            }
+        }
+    }
+}
+
 /// Print json records to screen
 fn print_json_records(records: &Vec<Value>) {
     println!("{},", serde_json::to_string_pretty(&records).unwrap());
@@ -141,16 +196,21 @@ pub fn print_text_records(
 
         for batch in &r_partition.records.batches {
             for record in &batch.records {
-                if record.get_value().inner_value_ref().is_some() {
-                    if record.get_value().is_binary() {
-                        if !suppress {
-                            println!("{}", record.get_value().describe());
-                        }
-                    } else {
-                        println!("{}", record.get_value());
-                    }
-                }
+                print_text_value(record.get_value(), suppress);
+            }
+        }
+    }
+}
+
+/// Print record value as text, binary value is described unless suppressed
+fn print_text_value(value: &DefaultAsyncBuffer, suppress: bool) {
+    if value.inner_value_ref().is_some() {
+        if value.is_binary() {
+            if !suppress {
+                println!("{}", value.describe());
             }
+        } else {
+            println!("{}", value);
         }
     }
 }
@@ -175,11 +235,7 @@ pub fn print_binary_records(
 
         for batch in &r_partition.records.batches {
             for record in &batch.records {
-                if let Some(batch_record) = record.get_value().inner_value_ref() {
-                    println!("{}", hex_dump_separator());
-                    println!("{}", bytes_to_hex_dump(&batch_record));
-                    printed = true;
-                }
+                printed |= print_binary_value(record.get_value());
             }
         }
     }
@@ -188,6 +244,17 @@ pub fn print_binary_records(
     }
 }
 
+/// Print record value as hex dump, returns true if anything was printed
+fn print_binary_value(value: &DefaultAsyncBuffer) -> bool {
+    if let Some(batch_record) = value.inner_value_ref() {
+        println!("{}", hex_dump_separator());
+        println!("{}", bytes_to_hex_dump(&batch_record));
+        true
+    } else {
+        false
+    }
+}
+
 // -----------------------------------
 //  Dynamic
 // -----------------------------------
@@ -205,20 +272,25 @@ pub fn print_dynamic_records(
 
         for batch in &r_partition.records.batches {
             for record in &batch.records {
-                if let Some(batch_record) = record.get_value().inner_value_ref() {
-                    if record.get_value().is_binary() {
-                        println!("{}", hex_dump_separator());
-                        println!("{}", bytes_to_hex_dump(&batch_record));
-                        println!("{}", hex_dump_separator());
-                    } else {
-                        println!("{}", record.get_value());
-                    }
-                }
+                print_dynamic_value(record.get_value());
             }
         }
     }
 }
 
+/// Print record value as hex dump if binary, otherwise as text
+fn print_dynamic_value(value: &DefaultAsyncBuffer) {
+    if let Some(batch_record) = value.inner_value_ref() {
+        if value.is_binary() {
+            println!("{}", hex_dump_separator());
+            println!("{}", bytes_to_hex_dump(&batch_record));
+            println!("{}", hex_dump_separator());
+        } else {
+            println!("{}", value);
+        }
+    }
+}
+
 // -----------------------------------
 //  Raw
 // -----------------------------------
@@ -236,14 +308,19 @@ pub fn print_raw_records(
 
         for batch in &r_partition.records.batches {
             for record in &batch.records {
-                if let Some(value) = record.get_value().inner_value_ref() {
-                    let _ = io::stdout().write(value);
-                }
+                print_raw_value(record.get_value());
             }
         }
     }
 }
 
+/// Write record value to stdout as is
+fn print_raw_value(value: &DefaultAsyncBuffer) {
+    if let Some(value) = value.inner_value_ref() {
+        let _ = io::stdout().write(value);
+    }
+}
+
 // -----------------------------------
 //  Utilities
 // -----------------------------------
@@ -16,3 +16,4 @@ pub use flv::spu_consume_log_from_topic_partition;
 
 pub use logs_output::ReponseLogParams;
 pub use logs_output::process_fetch_topic_reponse;
+pub use logs_output::process_consumer_records;
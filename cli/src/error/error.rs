@@ -1,12 +1,14 @@
 use std::fmt;
 
 use kf_socket::KfSocketError;
+use flv_client::ClientError;
 use std::io::Error as IoError;
 
 #[derive(Debug)]
 pub enum CliError {
     IoError(IoError),
     KfSocketError(KfSocketError),
+    ClientError(ClientError),
 }
 
 impl From<IoError> for CliError {
@@ -21,11 +23,18 @@ impl From<KfSocketError> for CliError {
     }
 }
 
+impl From<ClientError> for CliError {
+    fn from(error: ClientError) -> Self {
+        CliError::ClientError(error)
+    }
+}
+
 impl fmt::Display for CliError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             CliError::IoError(err) => write!(f, "{}", err),
             CliError::KfSocketError(err) => write!(f, "{}", err),
+            CliError::ClientError(err) => write!(f, "{}", err),
         }
     }
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ mod root_cli;
 mod spu;
 mod topic;
 mod advanced;
+mod watch;
 //mod auth_token;
 
 pub use self::error::CliError;
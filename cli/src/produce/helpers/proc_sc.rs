@@ -1,24 +1,24 @@
 //!
 //! # Fluvio SC Produce Log
 //!
-//! Looks-up the SPU responsible for the topic, connects to the server
-//! and sends the log.
+//! Sends records through producer client, which looks-up partition leader
+//! from SC and resends records if leader moves.
 //!
 
+use std::io;
 use std::net::SocketAddr;
+use std::time::Duration;
 
-use log::debug;
+use types::{print_cli_err, print_cli_ok};
 use future_helper::run_block_on;
+use flv_client::Producer;
+use flv_client::ProducerConfig;
+use flv_client::ProducerRecord;
 
 use crate::error::CliError;
-use crate::common::Connection;
-use crate::common::find_spu_leader_for_topic_partition;
-use crate::common::sc_get_api_versions;
 
 use crate::produce::cli::RecordTouples;
 
-use super::process_spu_produce_record;
-
 // -----------------------------------
 //  Fluvio SC - Process Request
 // -----------------------------------
@@ -29,28 +29,67 @@ pub fn process_sc_produce_record(
     topic: String,
     partition: i32,
     record_touples: RecordTouples,
-    continous: bool
+    continous: bool,
 ) -> Result<(), CliError> {
-    // look-up lookup spu for topic/partition leader
-    let spu_addr = run_block_on(spu_leader_for_topic_partition(
+    // producer task runs on the runtime of this call, keep it for all records
+    run_block_on(produce_records(
         server_addr,
-        topic.clone(),
+        topic,
         partition,
-    ))?;
-
-    // send records to SPU
-    process_spu_produce_record(spu_addr, topic, partition, record_touples,continous)
+        record_touples,
+        continous,
+    ))
 }
 
-// Connect to SC Controller, find spu, and send log
-async fn spu_leader_for_topic_partition(
-    sc_addr: SocketAddr,
+async fn produce_records(
+    server_addr: SocketAddr,
     topic: String,
     partition: i32,
-) -> Result<SocketAddr, CliError> {
-    let mut conn = Connection::new(&sc_addr).await?;
-    let sc_vers = sc_get_api_versions(&mut conn).await?;
+    record_touples: RecordTouples,
+    continous: bool,
+) -> Result<(), CliError> {
+    // records are sent one by one, no need to wait for more
+    let config = ProducerConfig {
+        client_id: "fluvio".to_owned(),
+        linger: Duration::from_millis(0),
+        ..Default::default()
+    };
+    let mut producer = Producer::new(server_addr, config);
+
+    if !record_touples.is_empty() {
+        // records from files
+        for r_touple in record_touples {
+            println!("{}", r_touple.0);
+            process_record(&mut producer, topic.clone(), partition, r_touple.1).await;
+        }
+    } else {
+        // stdin lock is not held across await
+        let mut line = String::new();
+        while io::stdin().read_line(&mut line)? > 0 {
+            let text = line.trim_end_matches('\n').trim_end_matches('\r');
+            let record = text.as_bytes().to_vec();
+            line.clear();
+            process_record(&mut producer, topic.clone(), partition, record).await;
+            if !continous {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send record, wait for delivery and print success or error
+async fn process_record(producer: &mut Producer, topic: String, partition: i32, record: Vec<u8>) {
+    let record = ProducerRecord::new(topic, record).partition(partition);
+    match send_record(producer, record).await {
+        Ok(()) => print_cli_ok!(),
+        Err(err) => print_cli_err!(format!("{}", err)),
+    }
+}
 
-    debug!("got sc version: {:#?}", sc_vers);
-    find_spu_leader_for_topic_partition(&mut conn, topic.clone(), partition, &sc_vers).await
+async fn send_record(producer: &mut Producer, record: ProducerRecord) -> Result<(), CliError> {
+    let delivery = producer.send(record).await?;
+    delivery.await?;
+    Ok(())
 }
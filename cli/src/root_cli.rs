@@ -13,6 +13,7 @@ use super::advanced::process_advanced;
 use super::spu::all::process_spu;
 use super::spu::custom::process_custom_spu;
 use super::spu::group::process_spu_group;
+use super::watch::process_watch;
 
 use super::consume::ConsumeLogOpt;
 use super::produce::ProduceLogOpt;
@@ -21,6 +22,7 @@ use super::advanced::AdvancedOpt;
 use super::spu::all::SpuOpt;
 use super::spu::custom::CustomSpuOpt;
 use super::spu::group::SpuGroupOpt;
+use super::watch::WatchOpt;
 
 use super::CliError;
 
@@ -98,6 +100,14 @@ enum Root {
 ", about = "Topic operations")]
     Topic(TopicOpt),
 
+    #[structopt(name = "watch", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+", about = "Stream metadata changes")]
+    Watch(WatchOpt),
+
     #[structopt(name = "advanced", author = "", template = "{about}
 
 {usage}
@@ -115,6 +125,7 @@ pub fn run_cli() -> Result<(), CliError> {
         Root::SPUGroup(spu_group) => process_spu_group(spu_group),
         Root::CustomSPU(custom_spu) => process_custom_spu(custom_spu),
         Root::Topic(topic) => process_topic(topic),
+        Root::Watch(watch) => process_watch(watch),
         Root::Advanced(advanced) => process_advanced(advanced),
     }
 }
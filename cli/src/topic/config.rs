@@ -0,0 +1,189 @@
+//!
+//! # Topic Config
+//!
+//! CLI tree to read and update per topic configuration
+//!
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use structopt::StructOpt;
+
+use crate::error::CliError;
+use crate::profile::{ProfileConfig, TargetServer};
+
+use super::helpers::process_get_topic_config;
+use super::helpers::process_set_topic_config;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, StructOpt)]
+pub enum TopicConfigOpt {
+    #[structopt(name = "get", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+",about = "Show topic configuration")]
+    Get(GetTopicConfigOpt),
+
+    #[structopt(name = "set", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+",about = "Update topic configuration")]
+    Set(SetTopicConfigOpt),
+}
+
+#[derive(Debug, StructOpt)]
+pub struct GetTopicConfigOpt {
+    /// Topic name
+    #[structopt(short = "t", long = "topic", value_name = "string")]
+    topic: String,
+
+    /// Config keys, all keys if omitted
+    #[structopt(short = "k", long = "key", value_name = "string")]
+    keys: Vec<String>,
+
+    /// Address of Streaming Controller
+    #[structopt(short = "c", long = "sc", value_name = "host:port")]
+    sc: Option<String>,
+
+    /// Address of Kafka Controller
+    #[structopt(long = "kf", value_name = "host:port", conflicts_with = "sc")]
+    kf: Option<String>,
+
+    /// Profile name
+    #[structopt(short = "P", long = "profile")]
+    profile: Option<String>,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct SetTopicConfigOpt {
+    /// Topic name
+    #[structopt(short = "t", long = "topic", value_name = "string")]
+    topic: String,
+
+    /// Config entries to set
+    #[structopt(
+        short = "s",
+        long = "set",
+        value_name = "key=value",
+        parse(try_from_str = "parse_config_entry")
+    )]
+    entries: Vec<(String, String)>,
+
+    /// Config keys to remove, default applies
+    #[structopt(short = "u", long = "unset", value_name = "key")]
+    unset: Vec<String>,
+
+    /// Address of Streaming Controller
+    #[structopt(short = "c", long = "sc", value_name = "host:port")]
+    sc: Option<String>,
+
+    /// Address of Kafka Controller
+    #[structopt(long = "kf", value_name = "host:port", conflicts_with = "sc")]
+    kf: Option<String>,
+
+    /// Profile name
+    #[structopt(short = "P", long = "profile")]
+    profile: Option<String>,
+}
+
+// -----------------------------------
+//  Parsed Config
+// -----------------------------------
+
+#[derive(Debug)]
+pub struct GetTopicConfig {
+    pub topic: String,
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SetTopicConfig {
+    pub topic: String,
+    pub entries: Vec<(String, String)>,
+    pub unset: Vec<String>,
+}
+
+// -----------------------------------
+//  CLI Processing
+// -----------------------------------
+
+/// Process topic config cli request
+pub fn process_topic_config(opt: TopicConfigOpt) -> Result<(), CliError> {
+    match opt {
+        TopicConfigOpt::Get(get_opt) => {
+            let target_server = parse_target_server(&get_opt.sc, &get_opt.kf, &get_opt.profile)?;
+            let get_cfg = GetTopicConfig {
+                topic: get_opt.topic,
+                keys: get_opt.keys,
+            };
+            process_get_topic_config(target_server, get_cfg)
+        }
+        TopicConfigOpt::Set(set_opt) => {
+            let target_server = parse_target_server(&set_opt.sc, &set_opt.kf, &set_opt.profile)?;
+            if set_opt.entries.is_empty() && set_opt.unset.is_empty() {
+                return Err(CliError::IoError(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "nothing to update, use --set or --unset",
+                )));
+            }
+            let set_cfg = SetTopicConfig {
+                topic: set_opt.topic,
+                entries: set_opt.entries,
+                unset: set_opt.unset,
+            };
+            process_set_topic_config(target_server, set_cfg)
+        }
+    }
+}
+
+/// Topic config is served by SC or Kafka controller
+fn parse_target_server(
+    sc: &Option<String>,
+    kf: &Option<String>,
+    profile: &Option<String>,
+) -> Result<TargetServer, CliError> {
+    let profile_config = ProfileConfig::new(sc, kf, profile)?;
+    let target_server = profile_config.target_server()?;
+    match target_server {
+        TargetServer::Sc(_) | TargetServer::Kf(_) => Ok(target_server),
+        _ => Err(CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("Invalid Target Server Server {:?}", target_server),
+        ))),
+    }
+}
+
+/// Parse config entry in key=value format
+fn parse_config_entry(entry: &str) -> Result<(String, String), IoError> {
+    let mut parts = entry.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(key), Some(value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_owned(), value.trim().to_owned()))
+        }
+        _ => Err(IoError::new(
+            ErrorKind::InvalidInput,
+            format!("invalid config entry '{}', expected key=value", entry),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_config_entry;
+
+    #[test]
+    fn test_parse_config_entry() {
+        assert_eq!(
+            parse_config_entry("segment.bytes=1000").expect("entry"),
+            ("segment.bytes".to_owned(), "1000".to_owned())
+        );
+        assert!(parse_config_entry("segment.bytes").is_err());
+        assert!(parse_config_entry("=1000").is_err());
+    }
+}
@@ -6,6 +6,8 @@ mod proc_describe_sc;
 mod proc_describe_kf;
 mod proc_list_sc;
 mod proc_list_kf;
+mod proc_rebalance_sc;
+mod proc_config;
 
 mod topic_metadata_kf;
 mod topic_metadata_sc;
@@ -20,6 +22,9 @@ pub use proc_describe_sc::process_sc_describe_topics;
 pub use proc_describe_kf::process_kf_describe_topics;
 pub use proc_list_sc::process_list_topics as process_sc_list_topics;
 pub use proc_list_kf::process_list_topics as process_kf_list_topics;
+pub use proc_rebalance_sc::process_rebalance_leaders as process_sc_rebalance_leaders;
+pub use proc_config::process_get_topic_config;
+pub use proc_config::process_set_topic_config;
 
 pub use topic_metadata_sc::ScTopicMetadata;
 pub use topic_metadata_sc::query_sc_topic_metadata;
@@ -0,0 +1,265 @@
+//!
+//! # Topic Config Processing
+//!
+//! Sends Describe Configs and Alter Configs requests to Fluvio Streaming Controller
+//! or Kafka Controller. Both servers use Kafka config messages.
+//!
+
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::collections::BTreeMap;
+
+use log::trace;
+use prettytable::Row;
+use prettytable::row;
+use prettytable::cell;
+
+use future_helper::run_block_on;
+
+use kf_protocol::api::AllKfApiKey;
+use kf_protocol::message::config::{KfDescribeConfigsRequest, KfDescribeConfigsResponse};
+use kf_protocol::message::config::{KfAlterConfigsRequest, KfAlterConfigsResponse};
+use kf_protocol::message::config::DescribeConfigsResource;
+use kf_protocol::message::config::DescribeConfigsResult;
+use kf_protocol::message::config::AlterConfigsResource;
+use kf_protocol::message::config::AlterableConfig;
+use kf_protocol::message::config::TOPIC_RESOURCE_TYPE;
+use kf_protocol::message::config::TOPIC_CONFIG_SOURCE;
+use sc_api::apis::ScApiKey;
+
+use crate::error::CliError;
+use crate::common::Connection;
+use crate::common::TableOutputHandler;
+use crate::common::{sc_get_api_versions, sc_lookup_version};
+use crate::common::{kf_get_api_versions, kf_lookup_version};
+use crate::profile::TargetServer;
+
+use crate::topic::config::GetTopicConfig;
+use crate::topic::config::SetTopicConfig;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+struct TopicConfigEntries {
+    entries: Vec<(String, Option<String>)>,
+}
+
+/// connection with negotiated config api versions
+struct ConfigConnection {
+    conn: Connection,
+    describe_version: Option<i16>,
+    alter_version: Option<i16>,
+}
+
+// -----------------------------------
+//  Process Request
+// -----------------------------------
+
+/// Connect to server and display topic config
+pub fn process_get_topic_config(
+    target_server: TargetServer,
+    get_cfg: GetTopicConfig,
+) -> Result<(), CliError> {
+    let topic = get_cfg.topic.clone();
+
+    let result = run_block_on(get_topic_config(target_server, get_cfg))
+        .map_err(|err| config_error(&topic, err))?;
+
+    let table = TopicConfigEntries {
+        entries: result
+            .configs
+            .into_iter()
+            .map(|config| (config.name, config.value))
+            .collect(),
+    };
+    table.display_table(false);
+
+    Ok(())
+}
+
+/// Connect to server, merge updates into current topic config and apply it
+pub fn process_set_topic_config(
+    target_server: TargetServer,
+    set_cfg: SetTopicConfig,
+) -> Result<(), CliError> {
+    let topic = set_cfg.topic.clone();
+
+    run_block_on(set_topic_config(target_server, set_cfg))
+        .map_err(|err| config_error(&topic, err))?;
+
+    println!("topic '{}' config updated", topic);
+    Ok(())
+}
+
+async fn get_topic_config(
+    target_server: TargetServer,
+    get_cfg: GetTopicConfig,
+) -> Result<DescribeConfigsResult, CliError> {
+    let mut config_conn = connect(target_server).await?;
+    describe_topic_config(&mut config_conn, get_cfg.topic, get_cfg.keys).await
+}
+
+/// alter configs replaces whole topic config, so current config is read first
+async fn set_topic_config(
+    target_server: TargetServer,
+    set_cfg: SetTopicConfig,
+) -> Result<(), CliError> {
+    let mut config_conn = connect(target_server).await?;
+
+    let current = describe_topic_config(&mut config_conn, set_cfg.topic.clone(), vec![]).await?;
+    let mut config: BTreeMap<String, String> = BTreeMap::new();
+    for entry in current.configs.into_iter() {
+        if entry.config_source == TOPIC_CONFIG_SOURCE {
+            if let Some(value) = entry.value {
+                config.insert(entry.name, value);
+            }
+        }
+    }
+    for (key, value) in set_cfg.entries.into_iter() {
+        config.insert(key, value);
+    }
+    for key in set_cfg.unset.iter() {
+        config.remove(key);
+    }
+
+    let request = KfAlterConfigsRequest {
+        resources: vec![AlterConfigsResource {
+            resource_type: TOPIC_RESOURCE_TYPE,
+            resource_name: set_cfg.topic,
+            configs: config
+                .into_iter()
+                .map(|(name, value)| AlterableConfig {
+                    name,
+                    value: Some(value),
+                })
+                .collect(),
+        }],
+        validate_only: false,
+    };
+
+    trace!("alter configs req '{}': {:#?}", config_conn.conn.server_addr(), request);
+
+    let version = config_conn.alter_version;
+    let response: KfAlterConfigsResponse = config_conn.conn.send_request(request, version).await?;
+
+    trace!("alter configs res '{}': {:#?}", config_conn.conn.server_addr(), response);
+
+    match response.resources.into_iter().next() {
+        Some(result) => {
+            if result.error_code.is_error() {
+                let error_code = result.error_code;
+                let msg = result.error_message.unwrap_or_else(|| error_code.to_sentence());
+                Err(CliError::IoError(IoError::new(ErrorKind::Other, msg)))
+            } else {
+                Ok(())
+            }
+        }
+        None => Err(empty_response()),
+    }
+}
+
+/// Connect to SC or Kafka server and look up config api versions
+async fn connect(target_server: TargetServer) -> Result<ConfigConnection, CliError> {
+    match target_server {
+        TargetServer::Sc(server_addr) => {
+            let mut conn = Connection::new(&server_addr).await?;
+            let versions = sc_get_api_versions(&mut conn).await?;
+            Ok(ConfigConnection {
+                describe_version: sc_lookup_version(ScApiKey::KfDescribeConfigs, &versions),
+                alter_version: sc_lookup_version(ScApiKey::KfAlterConfigs, &versions),
+                conn,
+            })
+        }
+        TargetServer::Kf(server_addr) => {
+            let mut conn = Connection::new(&server_addr).await?;
+            let versions = kf_get_api_versions(&mut conn).await?;
+            Ok(ConfigConnection {
+                describe_version: kf_lookup_version(AllKfApiKey::DescribeConfigs, &versions),
+                alter_version: kf_lookup_version(AllKfApiKey::AlterConfigs, &versions),
+                conn,
+            })
+        }
+        _ => Err(CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("invalid target server {:?}", target_server),
+        ))),
+    }
+}
+
+/// Send describe configs request for single topic
+async fn describe_topic_config(
+    config_conn: &mut ConfigConnection,
+    topic: String,
+    keys: Vec<String>,
+) -> Result<DescribeConfigsResult, CliError> {
+    let mut request = KfDescribeConfigsRequest::default();
+    request.resources.push(DescribeConfigsResource {
+        resource_type: TOPIC_RESOURCE_TYPE,
+        resource_name: topic,
+        configuration_keys: if keys.is_empty() { None } else { Some(keys) },
+    });
+
+    trace!("describe configs req '{}': {:#?}", config_conn.conn.server_addr(), request);
+
+    let version = config_conn.describe_version;
+    let response: KfDescribeConfigsResponse =
+        config_conn.conn.send_request(request, version).await?;
+
+    trace!("describe configs res '{}': {:#?}", config_conn.conn.server_addr(), response);
+
+    match response.results.into_iter().next() {
+        Some(result) => {
+            if result.error_code.is_error() {
+                let msg = result
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| result.error_code.to_sentence());
+                Err(CliError::IoError(IoError::new(ErrorKind::Other, msg)))
+            } else {
+                Ok(result)
+            }
+        }
+        None => Err(empty_response()),
+    }
+}
+
+fn empty_response() -> CliError {
+    CliError::IoError(IoError::new(ErrorKind::Other, "empty response"))
+}
+
+fn config_error(topic: &str, err: CliError) -> CliError {
+    CliError::IoError(IoError::new(
+        ErrorKind::Other,
+        format!("topic '{}' config: {}", topic, err),
+    ))
+}
+
+// -----------------------------------
+// Output Handlers
+// -----------------------------------
+
+impl TableOutputHandler for TopicConfigEntries {
+    /// table header implementation
+    fn header(&self) -> Row {
+        row!["KEY", "VALUE"]
+    }
+
+    /// return errors in string format
+    fn errors(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// table content implementation
+    fn content(&self) -> Vec<Row> {
+        self.entries
+            .iter()
+            .map(|(key, value)| {
+                row![
+                    l -> key,
+                    l -> value.as_ref().map(|v| v.as_str()).unwrap_or("(default)"),
+                ]
+            })
+            .collect()
+    }
+}
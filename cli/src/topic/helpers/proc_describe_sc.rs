@@ -137,7 +137,7 @@ impl DescribeObjectHandler for DescribeScTopic {
 impl TableOutputHandler for DescribeScTopic {
     /// table header implementation
     fn header(&self) -> Row {
-        row!["ID", "LEADER", "REPLICAS", "LIVE-REPLICAS",]
+        row!["ID", "LEADER", "REPLICAS", "LIVE-REPLICAS", "SIZE",]
     }
 
     /// return errors in string format
@@ -156,6 +156,7 @@ impl TableOutputHandler for DescribeScTopic {
                         c -> partition.leader,
                         l -> format!("{:?}", partition.replicas),
                         l -> format!("{:?}", partition.live_replicas),
+                        r -> partition.size_str(),
                     ]);
                 }
             }
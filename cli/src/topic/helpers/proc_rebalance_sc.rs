@@ -0,0 +1,139 @@
+//!
+//! # Fluvio SC - Rebalance Leaders Processing
+//!
+//! Sends Rebalance Leaders request to Fluvio Streaming Controller
+//!
+
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+
+use log::trace;
+use prettytable::Row;
+use prettytable::row;
+use prettytable::cell;
+
+use future_helper::run_block_on;
+
+use sc_api::apis::ScApiKey;
+use sc_api::topic::{FlvRebalanceLeadersRequest, FlvRebalanceLeadersResponse};
+use sc_api::topic::FlvLeaderMove;
+
+use crate::error::CliError;
+use crate::common::Connection;
+use crate::common::TableOutputHandler;
+use crate::common::sc_get_api_versions;
+use crate::common::sc_lookup_version;
+
+use crate::topic::rebalance::RebalanceLeadersConfig;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+struct LeaderMoves {
+    moves: Vec<FlvLeaderMove>,
+}
+
+// -----------------------------------
+//  Fluvio SC - Process Request
+// -----------------------------------
+
+// Connect to Fluvio Streaming Controller and process Rebalance Leaders Request
+pub fn process_rebalance_leaders(
+    server_addr: SocketAddr,
+    rebalance_cfg: RebalanceLeadersConfig,
+) -> Result<(), CliError> {
+    let dry_run = rebalance_cfg.dry_run;
+
+    // Run command and collect results
+    match run_block_on(send_request_to_server(server_addr, rebalance_cfg)) {
+        Err(err) => Err(CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("sending rebalance leaders: {}", err),
+        ))),
+        Ok(response) => {
+            let leader_moves = LeaderMoves {
+                moves: response.moves,
+            };
+
+            if leader_moves.moves.is_empty() {
+                println!("all partition leaders are on preferred replica");
+            } else {
+                if dry_run {
+                    println!("planned leader moves:");
+                }
+                leader_moves.display_table(false);
+            }
+
+            if response.error_code.is_error() {
+                let error_code = response.error_code;
+                let msg = response.error_message.unwrap_or_else(|| error_code.to_sentence());
+                Err(CliError::IoError(IoError::new(
+                    ErrorKind::Other,
+                    format!("rebalance leaders failed: {}", msg),
+                )))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Connect to server, get version, and send rebalance request
+async fn send_request_to_server(
+    server_addr: SocketAddr,
+    rebalance_cfg: RebalanceLeadersConfig,
+) -> Result<FlvRebalanceLeadersResponse, CliError> {
+    let mut conn = Connection::new(&server_addr).await?;
+    let request = encode_request(rebalance_cfg);
+    let versions = sc_get_api_versions(&mut conn).await?;
+    let version = sc_lookup_version(ScApiKey::FlvRebalanceLeaders, &versions);
+
+    trace!("rebalance leaders req '{}': {:#?}", server_addr, request);
+
+    let response = conn.send_request(request, version).await?;
+
+    trace!("rebalance leaders res '{}': {:#?}", server_addr, response);
+
+    Ok(response)
+}
+
+/// encode RebalanceLeadersConfig in Fluvio format
+fn encode_request(rebalance_cfg: RebalanceLeadersConfig) -> FlvRebalanceLeadersRequest {
+    FlvRebalanceLeadersRequest {
+        topics: rebalance_cfg.topics,
+        dry_run: rebalance_cfg.dry_run,
+    }
+}
+
+// -----------------------------------
+// Output Handlers
+// -----------------------------------
+
+impl TableOutputHandler for LeaderMoves {
+    /// table header implementation
+    fn header(&self) -> Row {
+        row!["TOPIC", "PARTITION", "FROM-LEADER", "TO-LEADER"]
+    }
+
+    /// return errors in string format
+    fn errors(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// table content implementation
+    fn content(&self) -> Vec<Row> {
+        self.moves
+            .iter()
+            .map(|leader_move| {
+                row![
+                    l -> leader_move.topic,
+                    c -> leader_move.partition_idx,
+                    c -> leader_move.from_leader,
+                    c -> leader_move.to_leader,
+                ]
+            })
+            .collect()
+    }
+}
@@ -70,6 +70,7 @@ pub struct PartitionReplica {
     pub leader: i32,
     pub replicas: Vec<i32>,
     pub live_replicas: Vec<i32>,
+    pub size: i64,
 }
 
 #[derive(Serialize, Debug)]
@@ -79,6 +80,7 @@ pub enum TopicResolution {
     Pending,
     InsufficientResources,
     InvalidConfig,
+    Deleting,
 }
 
 // -----------------------------------
@@ -165,6 +167,15 @@ impl PartitionReplica {
             leader: flv_partition_replica.leader,
             replicas: flv_partition_replica.replicas.clone(),
             live_replicas: flv_partition_replica.live_replicas.clone(),
+            size: flv_partition_replica.size,
+        }
+    }
+
+    pub fn size_str(&self) -> String {
+        if self.size < 0 {
+            "-".to_owned()
+        } else {
+            self.size.to_string()
         }
     }
 }
@@ -177,6 +188,7 @@ impl TopicResolution {
             FlvTopicResolution::Pending => TopicResolution::Pending,
             FlvTopicResolution::InsufficientResources => TopicResolution::InsufficientResources,
             FlvTopicResolution::InvalidConfig => TopicResolution::InvalidConfig,
+            FlvTopicResolution::Deleting => TopicResolution::Deleting,
         }
     }
 
@@ -187,6 +199,7 @@ impl TopicResolution {
             TopicResolution::Pending => "pending",
             TopicResolution::InsufficientResources => "no-resource-for-replica-map",
             TopicResolution::InvalidConfig => "invalid-config",
+            TopicResolution::Deleting => "deleting",
         }
     }
 }
@@ -3,6 +3,8 @@ mod create;
 mod delete;
 mod describe;
 mod list;
+mod rebalance;
+mod config;
 
 use structopt::StructOpt;
 
@@ -10,11 +12,15 @@ use create::CreateTopicOpt;
 use delete::DeleteTopicOpt;
 use describe::DescribeTopicsOpt;
 use list::ListTopicsOpt;
+use rebalance::RebalanceLeadersOpt;
+use config::TopicConfigOpt;
 
 use create::process_create_topic;
 use delete::process_delete_topic;
 use describe::process_describe_topics;
 use list::process_list_topics;
+use rebalance::process_rebalance_leaders;
+use config::process_topic_config;
 
 use super::CliError;
 
@@ -52,6 +58,22 @@ pub enum TopicOpt {
 {all-args}
 ",about = "Show all topics")]
     List(ListTopicsOpt),
+
+    #[structopt(name = "rebalance-leaders", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+",about = "Move partition leaders to preferred replica")]
+    RebalanceLeaders(RebalanceLeadersOpt),
+
+    #[structopt(name = "config", author = "", template = "{about}
+
+{usage}
+
+{all-args}
+",about = "Show or update topic configuration")]
+    Config(TopicConfigOpt),
 }
 
 pub(crate) fn process_topic(topic_opt: TopicOpt) -> Result<(), CliError> {
@@ -60,5 +82,7 @@ pub(crate) fn process_topic(topic_opt: TopicOpt) -> Result<(), CliError> {
         TopicOpt::Delete(delete_topic_opt) => process_delete_topic(delete_topic_opt),
         TopicOpt::Describe(describe_topics_opt) => process_describe_topics(describe_topics_opt),
         TopicOpt::List(list_topics_opt) => process_list_topics(list_topics_opt),
+        TopicOpt::RebalanceLeaders(rebalance_opt) => process_rebalance_leaders(rebalance_opt),
+        TopicOpt::Config(config_opt) => process_topic_config(config_opt),
     }
 }
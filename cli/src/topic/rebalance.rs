@@ -0,0 +1,78 @@
+//!
+//! # Rebalance Leaders
+//!
+//! CLI tree to move partition leaders back to their preferred replica
+//!
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use structopt::StructOpt;
+
+use crate::error::CliError;
+use crate::profile::{ProfileConfig, TargetServer};
+
+use super::helpers::process_sc_rebalance_leaders;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, StructOpt)]
+pub struct RebalanceLeadersOpt {
+    /// Topic names, all topics if omitted
+    #[structopt(short = "t", long = "topic", value_name = "string")]
+    topics: Vec<String>,
+
+    /// List planned leader moves without applying them
+    #[structopt(short = "d", long = "dry-run")]
+    dry_run: bool,
+
+    /// Address of Streaming Controller
+    #[structopt(short = "c", long = "sc", value_name = "host:port")]
+    sc: Option<String>,
+
+    /// Profile name
+    #[structopt(short = "P", long = "profile")]
+    profile: Option<String>,
+}
+
+// -----------------------------------
+//  Parsed Config
+// -----------------------------------
+
+#[derive(Debug)]
+pub struct RebalanceLeadersConfig {
+    pub topics: Vec<String>,
+    pub dry_run: bool,
+}
+
+// -----------------------------------
+//  CLI Processing
+// -----------------------------------
+
+/// Process rebalance leaders cli request
+pub fn process_rebalance_leaders(opt: RebalanceLeadersOpt) -> Result<(), CliError> {
+    let (target_server, rebalance_cfg) = parse_opt(opt)?;
+
+    match target_server {
+        TargetServer::Sc(server_addr) => process_sc_rebalance_leaders(server_addr, rebalance_cfg),
+        _ => Err(CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("invalid sc server {:?}", target_server),
+        ))),
+    }
+}
+
+/// Validate cli options. Generate target-server and rebalance configuration.
+fn parse_opt(opt: RebalanceLeadersOpt) -> Result<(TargetServer, RebalanceLeadersConfig), CliError> {
+    // profile specific configurations (target server)
+    let profile_config = ProfileConfig::new(&opt.sc, &None, &opt.profile)?;
+    let target_server = profile_config.target_server()?;
+    let rebalance_cfg = RebalanceLeadersConfig {
+        topics: opt.topics,
+        dry_run: opt.dry_run,
+    };
+
+    // return server separately from config
+    Ok((target_server, rebalance_cfg))
+}
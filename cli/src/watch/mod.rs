@@ -0,0 +1,89 @@
+//!
+//! # Watch
+//!
+//! CLI tree to stream metadata changes from the Streaming Controller
+//!
+mod proc_watch_sc;
+
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use structopt::StructOpt;
+
+use crate::error::CliError;
+use crate::profile::{ProfileConfig, TargetServer};
+
+use proc_watch_sc::process_sc_watch;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, StructOpt)]
+pub struct WatchOpt {
+    /// Watch topics (all object types if no type is selected)
+    #[structopt(short = "t", long = "topics")]
+    topics: bool,
+
+    /// Watch partitions
+    #[structopt(short = "p", long = "partitions")]
+    partitions: bool,
+
+    /// Watch SPUs
+    #[structopt(short = "s", long = "spus")]
+    spus: bool,
+
+    /// Address of Streaming Controller
+    #[structopt(short = "c", long = "sc", value_name = "host:port")]
+    sc: Option<String>,
+
+    /// Profile name
+    #[structopt(short = "P", long = "profile")]
+    profile: Option<String>,
+}
+
+// -----------------------------------
+//  Parsed Config
+// -----------------------------------
+
+#[derive(Debug)]
+pub struct WatchConfig {
+    pub topics: bool,
+    pub partitions: bool,
+    pub spus: bool,
+}
+
+// -----------------------------------
+//  CLI Processing
+// -----------------------------------
+
+/// Process watch cli request
+pub fn process_watch(opt: WatchOpt) -> Result<(), CliError> {
+    let (target_server, watch_cfg) = parse_opt(opt)?;
+
+    match target_server {
+        TargetServer::Sc(server_addr) => process_sc_watch(server_addr, watch_cfg),
+        _ => Err(CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("invalid sc server {:?}", target_server),
+        ))),
+    }
+}
+
+/// Validate cli options. Generate target-server and watch configuration.
+fn parse_opt(opt: WatchOpt) -> Result<(TargetServer, WatchConfig), CliError> {
+    // profile specific configurations (target server)
+    let profile_config = ProfileConfig::new(&opt.sc, &None, &opt.profile)?;
+    let target_server = profile_config.target_server()?;
+
+    // no selection watches everything
+    let all = !opt.topics && !opt.partitions && !opt.spus;
+    let watch_cfg = WatchConfig {
+        topics: all || opt.topics,
+        partitions: all || opt.partitions,
+        spus: all || opt.spus,
+    };
+
+    // return server separately from config
+    Ok((target_server, watch_cfg))
+}
@@ -0,0 +1,168 @@
+//!
+//! # Fluvio SC - Watch Processing
+//!
+//! Subscribe to metadata changes on Fluvio Streaming Controller and print them.
+//! Lost connections are re-established and resumed from the last epoch received.
+//! If SC was restarted in between, it starts over with a snapshot.
+//!
+
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use log::{debug, trace};
+
+use future_helper::run_block_on;
+use future_helper::sleep;
+
+use sc_api::apis::ScApiKey;
+use sc_api::watch::{FlvWatchMetadataRequest, FlvWatchMetadataResponse};
+use sc_api::watch::FlvChangeType;
+use sc_api::watch::WATCH_SNAPSHOT_EPOCH;
+
+use crate::error::CliError;
+use crate::common::Connection;
+use crate::common::sc_get_api_versions;
+use crate::common::sc_lookup_version;
+
+use super::WatchConfig;
+
+const WATCH_RECONNECT_MS: u64 = 3000;
+
+// -----------------------------------
+//  Fluvio SC - Process Request
+// -----------------------------------
+
+// Connect to Fluvio Streaming Controller and print metadata changes until interrupted
+pub fn process_sc_watch(server_addr: SocketAddr, watch_cfg: WatchConfig) -> Result<(), CliError> {
+    run_block_on(watch_loop(server_addr, watch_cfg)).map_err(|err| {
+        CliError::IoError(IoError::new(
+            ErrorKind::Other,
+            format!("watch metadata: {}", err),
+        ))
+    })
+}
+
+/// Position in SC change stream, epoch is only valid within its generation
+struct WatchPosition {
+    generation: i64,
+    epoch: i64,
+}
+
+/// Watch and reconnect. Errors before first response are returned to the user.
+async fn watch_loop(server_addr: SocketAddr, watch_cfg: WatchConfig) -> Result<(), CliError> {
+    let mut position = WatchPosition {
+        generation: 0,
+        epoch: WATCH_SNAPSHOT_EPOCH,
+    };
+
+    loop {
+        if let Err(err) = watch_session(&server_addr, &watch_cfg, &mut position).await {
+            if position.epoch == WATCH_SNAPSHOT_EPOCH {
+                return Err(err);
+            }
+            debug!("watch session terminated: {}", err);
+        }
+
+        eprintln!("watch connection lost, resuming from epoch {}", position.epoch);
+        sleep(Duration::from_millis(WATCH_RECONNECT_MS)).await;
+    }
+}
+
+/// Single watch connection, position is advanced as responses are received
+async fn watch_session(
+    server_addr: &SocketAddr,
+    watch_cfg: &WatchConfig,
+    position: &mut WatchPosition,
+) -> Result<(), CliError> {
+    let mut conn = Connection::new(server_addr).await?;
+    let versions = sc_get_api_versions(&mut conn).await?;
+    let version = sc_lookup_version(ScApiKey::FlvWatchMetadata, &versions);
+
+    let request = FlvWatchMetadataRequest {
+        topics: watch_cfg.topics,
+        partitions: watch_cfg.partitions,
+        spus: watch_cfg.spus,
+        generation: position.generation,
+        epoch: position.epoch,
+    };
+
+    trace!("watch metadata req '{}': {:#?}", server_addr, request);
+
+    let req_msg = conn.send_stream_request(request, version).await?;
+
+    loop {
+        let response = conn.next_response(&req_msg).await?;
+        trace!("watch metadata res '{}': {:#?}", server_addr, response);
+
+        if response.error_code.is_error() {
+            return Err(CliError::IoError(IoError::new(
+                ErrorKind::Other,
+                response.error_code.to_sentence(),
+            )));
+        }
+
+        print_changes(&response);
+        position.generation = response.generation;
+        position.epoch = response.epoch;
+    }
+}
+
+// -----------------------------------
+// Output
+// -----------------------------------
+
+/// print one line per change
+fn print_changes(response: &FlvWatchMetadataResponse) {
+    if response.snapshot {
+        println!(
+            "{:<14} SNAPSHOT  {} topics, {} partitions, {} spus",
+            response.epoch,
+            response.topics.len(),
+            response.partitions.len(),
+            response.spus.len()
+        );
+    }
+
+    for change in &response.topics {
+        println!(
+            "{:<14} {:<9} topic      {} ({})",
+            response.epoch,
+            change_label(&change.change_type),
+            change.name,
+            change.status.resolution().resolution_label()
+        );
+    }
+
+    for change in &response.partitions {
+        println!(
+            "{:<14} {:<9} partition  {}/{} (leader: {}, replicas: {:?}, {:?})",
+            response.epoch,
+            change_label(&change.change_type),
+            change.topic,
+            change.partition,
+            change.spec.leader,
+            change.spec.replicas,
+            change.status.resolution
+        );
+    }
+
+    for change in &response.spus {
+        println!(
+            "{:<14} {:<9} spu        {} ({})",
+            response.epoch,
+            change_label(&change.change_type),
+            change.name,
+            change.status.resolution_label()
+        );
+    }
+}
+
+fn change_label(change_type: &FlvChangeType) -> &'static str {
+    match change_type {
+        FlvChangeType::Add => "ADD",
+        FlvChangeType::Mod => "MOD",
+        FlvChangeType::Delete => "DELETE",
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! # Leader Connections
+//!
+//! Multiplexed connections to SPU leaders. Requests over same connection are matched to
+//! responses by correlation id, so requests to different leaders can be in flight at same time
+//! and connection doesn't need to be borrowed mutably while waiting for response.
+//!
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use log::debug;
+
+use kf_protocol::api::Request;
+use kf_socket::KfSocket;
+use kf_socket::MultiplexerSocket;
+
+use crate::ClientError;
+use crate::new_request_message;
+
+#[derive(Default)]
+pub(crate) struct Connections {
+    sockets: HashMap<SocketAddr, Arc<MultiplexerSocket>>,
+}
+
+impl Connections {
+    /// connection to addr, closed connection is replaced with new one
+    pub async fn connection(&mut self, addr: SocketAddr) -> Result<Arc<MultiplexerSocket>, ClientError> {
+        if let Some(socket) = self.sockets.get(&addr) {
+            if !socket.is_terminated() {
+                return Ok(socket.clone());
+            }
+            debug!("connection to: {} terminated, reconnecting", addr);
+        }
+
+        let socket = Arc::new(MultiplexerSocket::new(KfSocket::connect(&addr).await?));
+        self.sockets.insert(addr, socket.clone());
+        Ok(socket)
+    }
+
+    /// drop connection after error, next request reconnects
+    pub fn remove(&mut self, addr: &SocketAddr) {
+        self.sockets.remove(addr);
+    }
+}
+
+/// send request with client id and wait for its response
+pub(crate) async fn send_request<R>(
+    socket: Arc<MultiplexerSocket>,
+    request: R,
+    client_id: &str,
+) -> Result<R::Response, ClientError>
+where
+    R: Request,
+{
+    let req_msg = new_request_message(request, client_id);
+    let response = socket.send_and_receive(req_msg).await?;
+    Ok(response.response)
+}
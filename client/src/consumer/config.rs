@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+/// consumer settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsumerConfig {
+    pub client_id: String,
+    /// max bytes fetched in one request
+    pub max_bytes: i32,
+    pub max_wait_ms: i32,
+    /// wait before fetching again when there were no new records
+    pub poll_interval: Duration,
+    /// wait before fetch is retried after retriable error, metadata is refreshed in between
+    pub retry_backoff: Duration,
+}
+
+impl Default for ConsumerConfig {
+    fn default() -> Self {
+        Self {
+            client_id: "fluvio-consumer".to_owned(),
+            max_bytes: 1_048_576,
+            max_wait_ms: 500,
+            poll_interval: Duration::from_millis(500),
+            retry_backoff: Duration::from_millis(100),
+        }
+    }
+}
@@ -0,0 +1,584 @@
+//!
+//! # Consumer
+//!
+//! Fetches records of assigned partitions from their leaders. Leaders are resolved from SC
+//! and resolved again when leader moves or connection is lost, so stream of records continues
+//! across leader failover.
+//!
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+
+use log::debug;
+use log::trace;
+use futures::future::join_all;
+use futures::stream;
+use futures::stream::Stream;
+
+use future_helper::sleep;
+use kf_protocol::api::DefaultBatch;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::Isolation;
+use kf_protocol::api::Offset;
+use kf_protocol::api::Request;
+use kf_protocol::message::fetch::DefaultKfFetchRequest;
+use kf_protocol::message::fetch::DefaultKfFetchResponse;
+use kf_protocol::message::fetch::FetchPartition;
+use kf_protocol::message::fetch::FetchableTopic;
+use kf_protocol::message::offset::KfListOffsetRequest;
+use kf_protocol::message::offset::ListOffsetPartition;
+use kf_protocol::message::offset::ListOffsetTopic;
+use spu_api::offsets::FetchOffsetPartition;
+use spu_api::offsets::FetchOffsetTopic;
+use spu_api::offsets::FlvFetchOffsetsRequest;
+
+use crate::ClientError;
+use crate::ClusterMetadata;
+use crate::connections::Connections;
+use crate::connections::send_request;
+use super::ConsumerConfig;
+
+/// where to start consuming partition
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsumerOffset {
+    /// first offset still in log
+    Beginning,
+    /// only records produced from now on
+    End,
+    Absolute(Offset),
+    /// first record with timestamp (ms) at or after given time.
+    /// leader resolves it to batch containing the record, earlier records of batch are skipped
+    Timestamp(i64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsumerRecord {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: Offset,
+    pub timestamp: i64,
+    pub key: Option<Vec<u8>>,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct PartitionState {
+    /// next offset to fetch, unknown until seek is resolved by leader
+    fetch_offset: Option<Offset>,
+    seek: Option<ConsumerOffset>,
+    min_timestamp: Option<i64>,
+}
+
+impl PartitionState {
+    fn new(seek: ConsumerOffset) -> Self {
+        Self {
+            fetch_offset: None,
+            seek: Some(seek),
+            min_timestamp: None,
+        }
+    }
+}
+
+pub struct Consumer {
+    config: ConsumerConfig,
+    metadata: ClusterMetadata,
+    partitions: BTreeMap<(String, i32), PartitionState>,
+    connections: Connections,
+    buffer: VecDeque<ConsumerRecord>,
+}
+
+impl Consumer {
+    pub fn new(sc_addr: SocketAddr, config: ConsumerConfig) -> Self {
+        let metadata = ClusterMetadata::new(sc_addr, config.client_id.clone());
+        Self {
+            config,
+            metadata,
+            partitions: BTreeMap::new(),
+            connections: Connections::default(),
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// assign all partitions of topic
+    pub async fn subscribe(&mut self, topic: &str, start: ConsumerOffset) -> Result<(), ClientError> {
+        let leaders = self.metadata.topic(topic).await?;
+        for partition in leaders.partitions() {
+            self.assign(topic, partition, start.clone());
+        }
+        Ok(())
+    }
+
+    /// assign partition, replaces position if already assigned
+    pub fn assign(&mut self, topic: &str, partition: i32, start: ConsumerOffset) {
+        debug!("assign {}/{} from: {:?}", topic, partition, start);
+        self.drop_buffered(topic, partition);
+        self.partitions
+            .insert((topic.to_owned(), partition), PartitionState::new(start));
+    }
+
+    pub fn unassign(&mut self, topic: &str, partition: i32) {
+        self.drop_buffered(topic, partition);
+        self.partitions.remove(&(topic.to_owned(), partition));
+    }
+
+    /// move assigned partition, records already fetched from old position are dropped
+    pub fn seek(&mut self, topic: &str, partition: i32, offset: ConsumerOffset) -> Result<(), ClientError> {
+        if !self.partitions.contains_key(&(topic.to_owned(), partition)) {
+            return Err(ClientError::KfError(ErrorCode::UnknownTopicOrPartition));
+        }
+        self.assign(topic, partition, offset);
+        Ok(())
+    }
+
+    /// offset of next record returned for partition, none until position is resolved
+    pub fn position(&self, topic: &str, partition: i32) -> Option<Offset> {
+        let buffered = self
+            .buffer
+            .iter()
+            .find(|record| record.topic == topic && record.partition == partition);
+        if let Some(record) = buffered {
+            return Some(record.offset);
+        }
+
+        self.partitions
+            .get(&(topic.to_owned(), partition))
+            .and_then(|state| state.fetch_offset)
+    }
+
+    /// next record of any assigned partition, waits until there is one
+    pub async fn next_record(&mut self) -> Result<ConsumerRecord, ClientError> {
+        loop {
+            if let Some(record) = self.buffer.pop_front() {
+                return Ok(record);
+            }
+            self.poll().await?;
+        }
+    }
+
+    /// records already buffered, or fetched in single round from all leaders if there are none.
+    /// empty if there are no new records
+    pub async fn poll_records(&mut self) -> Result<Vec<ConsumerRecord>, ClientError> {
+        if self.buffer.is_empty() {
+            self.poll().await?;
+        }
+        Ok(self.buffer.drain(..).collect())
+    }
+
+    /// endless stream of records, non retriable errors are passed to stream
+    pub fn into_stream(self) -> impl Stream<Item = Result<ConsumerRecord, ClientError>> {
+        stream::unfold(self, |mut consumer| {
+            async move {
+                let record = consumer.next_record().await;
+                Some((record, consumer))
+            }
+        })
+    }
+
+    fn drop_buffered(&mut self, topic: &str, partition: i32) {
+        self.buffer
+            .retain(|record| !(record.topic == topic && record.partition == partition));
+    }
+
+    /// single round of offset resolution and fetch to all leaders
+    async fn poll(&mut self) -> Result<(), ClientError> {
+        if self.partitions.is_empty() {
+            return Err(ClientError::IoError(IoError::new(
+                ErrorKind::InvalidInput,
+                "no partition is assigned",
+            )));
+        }
+
+        let mut retry = false;
+
+        let pending: Vec<(String, i32, ConsumerOffset)> = self
+            .partitions
+            .iter()
+            .filter_map(|((topic, partition), state)| {
+                state
+                    .seek
+                    .as_ref()
+                    .map(|seek| (topic.clone(), *partition, seek.clone()))
+            })
+            .collect();
+        for (topic, partition, seek) in pending {
+            match self.resolve_offset(&topic, partition, &seek).await {
+                Ok(offset) => {
+                    debug!("{}/{} {:?} resolved to: {}", topic, partition, seek, offset);
+                    if let Some(state) = self.partitions.get_mut(&(topic, partition)) {
+                        state.fetch_offset = Some(offset);
+                        state.seek = None;
+                        state.min_timestamp = match seek {
+                            ConsumerOffset::Timestamp(timestamp) => Some(timestamp),
+                            _ => None,
+                        };
+                    }
+                }
+                Err(err) => self.handle_error(&topic, err, &mut retry)?,
+            }
+        }
+
+        let ready: Vec<(String, i32, Offset)> = self
+            .partitions
+            .iter()
+            .filter_map(|((topic, partition), state)| {
+                state
+                    .fetch_offset
+                    .map(|offset| (topic.clone(), *partition, offset))
+            })
+            .collect();
+        let mut by_leader: HashMap<SocketAddr, Vec<(String, i32, Offset)>> = HashMap::new();
+        for (topic, partition, offset) in ready {
+            match self.metadata.leader(&topic, partition).await {
+                Ok(leader) => by_leader
+                    .entry(leader)
+                    .or_insert_with(Vec::new)
+                    .push((topic, partition, offset)),
+                Err(err) => self.handle_error(&topic, err, &mut retry)?,
+            }
+        }
+
+        // fetch from all leaders at same time
+        let mut fetches = vec![];
+        for (leader, partitions) in by_leader {
+            match self.connections.connection(leader).await {
+                Ok(socket) => fetches.push((leader, socket, partitions)),
+                Err(err) => {
+                    for (topic, _, _) in &partitions {
+                        self.handle_error(topic, err.duplicate(), &mut retry)?;
+                    }
+                }
+            }
+        }
+
+        let config = &self.config;
+        let responses = join_all(fetches.into_iter().map(|(leader, socket, partitions)| {
+            async move {
+                let request = fetch_request(&partitions, config);
+                let response = send_request(socket, request, &config.client_id).await;
+                (leader, response, partitions)
+            }
+        }))
+        .await;
+
+        for (leader, response, partitions) in responses {
+            match response {
+                Ok(response) => self.process_fetch_response(response, &mut retry)?,
+                Err(err) => {
+                    self.connections.remove(&leader);
+                    for (topic, _, _) in &partitions {
+                        self.handle_error(topic, err.duplicate(), &mut retry)?;
+                    }
+                }
+            }
+        }
+
+        if retry {
+            sleep(self.config.retry_backoff).await;
+        } else if self.buffer.is_empty() {
+            sleep(self.config.poll_interval).await;
+        }
+
+        Ok(())
+    }
+
+    fn process_fetch_response(
+        &mut self,
+        response: DefaultKfFetchResponse,
+        retry: &mut bool,
+    ) -> Result<(), ClientError> {
+        if response.error_code != ErrorCode::None {
+            return Err(ClientError::KfError(response.error_code));
+        }
+
+        for topic_response in response.topics {
+            for partition_response in topic_response.partitions {
+                let partition = partition_response.partition_index;
+                if partition_response.error_code != ErrorCode::None {
+                    self.handle_error(
+                        &topic_response.name,
+                        ClientError::KfError(partition_response.error_code),
+                        retry,
+                    )?;
+                    continue;
+                }
+
+                // partition may have been unassigned while fetching
+                let state = match self
+                    .partitions
+                    .get_mut(&(topic_response.name.clone(), partition))
+                {
+                    Some(state) => state,
+                    None => continue,
+                };
+                let fetch_offset = match state.fetch_offset {
+                    Some(offset) => offset,
+                    None => continue,
+                };
+
+                let records = partition_records(
+                    &topic_response.name,
+                    partition,
+                    partition_response.records.batches,
+                    fetch_offset,
+                    &mut state.min_timestamp,
+                );
+                trace!(
+                    "fetched {} records from {}/{}",
+                    records.len(),
+                    topic_response.name,
+                    partition
+                );
+                if let Some(last) = records.last() {
+                    state.fetch_offset = Some(last.offset + 1);
+                }
+                self.buffer.extend(records);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// retriable errors are recovered by looking up leader again in next poll
+    fn handle_error(&mut self, topic: &str, err: ClientError, retry: &mut bool) -> Result<(), ClientError> {
+        if err.is_retriable() {
+            debug!("retrying topic: {} after: {}", topic, err);
+            self.metadata.invalidate(topic);
+            *retry = true;
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    async fn resolve_offset(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        seek: &ConsumerOffset,
+    ) -> Result<Offset, ClientError> {
+        let timestamp = match seek {
+            ConsumerOffset::Absolute(offset) => return Ok(*offset),
+            ConsumerOffset::Timestamp(timestamp) => *timestamp,
+            _ => return self.fetch_offset(topic, partition, seek).await,
+        };
+
+        let leader = self.metadata.leader(topic, partition).await?;
+        let mut list_partition = ListOffsetPartition::default();
+        list_partition.partition_index = partition;
+        list_partition.current_leader_epoch = -1;
+        list_partition.timestamp = timestamp;
+        let mut request = KfListOffsetRequest::default();
+        request.replica_id = -1;
+        request.isolation_level = Isolation::ReadCommitted;
+        request.topics = vec![ListOffsetTopic {
+            name: topic.to_owned(),
+            partitions: vec![list_partition],
+        }];
+        let response = self.send_to_leader(leader, request).await?;
+
+        let offsets = response
+            .topics
+            .iter()
+            .find(|topic_response| topic_response.name == topic)
+            .and_then(|topic_response| {
+                topic_response
+                    .partitions
+                    .iter()
+                    .find(|partition_response| partition_response.partition_index == partition)
+            })
+            .ok_or(ClientError::KfError(ErrorCode::UnknownTopicOrPartition))?;
+        if offsets.error_code != ErrorCode::None {
+            return Err(ClientError::KfError(offsets.error_code));
+        }
+
+        // no record at or after timestamp yet, consume new records
+        if offsets.offset < 0 {
+            return self.fetch_offset(topic, partition, &ConsumerOffset::End).await;
+        }
+        Ok(offsets.offset)
+    }
+
+    /// resolve beginning or end offset
+    async fn fetch_offset(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        seek: &ConsumerOffset,
+    ) -> Result<Offset, ClientError> {
+        let leader = self.metadata.leader(topic, partition).await?;
+        let mut request = FlvFetchOffsetsRequest::default();
+        request.topics = vec![FetchOffsetTopic {
+            name: topic.to_owned(),
+            partitions: vec![FetchOffsetPartition {
+                partition_index: partition,
+            }],
+        }];
+        let response = self.send_to_leader(leader, request).await?;
+
+        let offsets = response
+            .topics
+            .iter()
+            .find(|topic_response| topic_response.name == topic)
+            .and_then(|topic_response| {
+                topic_response
+                    .partitions
+                    .iter()
+                    .find(|partition_response| partition_response.partition_index == partition)
+            })
+            .ok_or(ClientError::KfError(ErrorCode::UnknownTopicOrPartition))?;
+        if offsets.error_code.is_error() {
+            return Err(ClientError::FlvError(offsets.error_code));
+        }
+
+        Ok(match seek {
+            ConsumerOffset::End => offsets.last_stable_offset,
+            _ => offsets.start_offset,
+        })
+    }
+
+    /// connection is dropped on error, next request reconnects
+    async fn send_to_leader<R>(&mut self, leader: SocketAddr, request: R) -> Result<R::Response, ClientError>
+    where
+        R: Request,
+    {
+        let socket = self.connections.connection(leader).await?;
+        match send_request(socket, request, &self.config.client_id).await {
+            Ok(response) => Ok(response),
+            Err(err) => {
+                self.connections.remove(&leader);
+                Err(err)
+            }
+        }
+    }
+}
+
+/// fetch partitions of same leader, grouped by topic
+fn fetch_request(partitions: &[(String, i32, Offset)], config: &ConsumerConfig) -> DefaultKfFetchRequest {
+    let mut topics: BTreeMap<String, FetchableTopic> = BTreeMap::new();
+    for (topic, partition, offset) in partitions {
+        let mut fetch_partition = FetchPartition::default();
+        fetch_partition.partition_index = *partition;
+        fetch_partition.current_leader_epoch = -1;
+        fetch_partition.fetch_offset = *offset;
+        fetch_partition.log_start_offset = -1;
+        fetch_partition.max_bytes = config.max_bytes;
+
+        topics
+            .entry(topic.clone())
+            .or_insert_with(|| {
+                let mut fetch_topic = FetchableTopic::default();
+                fetch_topic.name = topic.clone();
+                fetch_topic
+            })
+            .fetch_partitions
+            .push(fetch_partition);
+    }
+
+    let mut request = DefaultKfFetchRequest::default();
+    request.replica_id = -1;
+    request.max_wait = config.max_wait_ms;
+    request.min_bytes = 1;
+    request.max_bytes = config.max_bytes;
+    request.isolation_level = Isolation::ReadCommitted;
+    request.session_id = 0;
+    request.epoch = -1;
+    request.topics = topics.into_iter().map(|(_, topic)| topic).collect();
+    request
+}
+
+/// records at or after fetch offset; batch may start before fetch offset.
+/// records before min timestamp are skipped until first one at or after it is found
+fn partition_records(
+    topic: &str,
+    partition: i32,
+    batches: Vec<DefaultBatch>,
+    fetch_offset: Offset,
+    min_timestamp: &mut Option<i64>,
+) -> Vec<ConsumerRecord> {
+    let mut records = vec![];
+    for batch in batches {
+        let base_offset = batch.get_base_offset();
+        let first_timestamp = batch.get_header().first_timestamp;
+        for record in batch.records {
+            let offset = base_offset + record.get_offset_delta();
+            if offset < fetch_offset {
+                continue;
+            }
+
+            let timestamp = first_timestamp + record.get_timestamp_delta();
+            if let Some(min) = *min_timestamp {
+                if timestamp < min {
+                    continue;
+                }
+                *min_timestamp = None;
+            }
+
+            records.push(ConsumerRecord {
+                topic: topic.to_owned(),
+                partition,
+                offset,
+                timestamp,
+                key: record.key.inner_value(),
+                value: record.value.inner_value().unwrap_or_default(),
+            });
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod test {
+
+    use kf_protocol::api::DefaultBatch;
+    use kf_protocol::api::DefaultRecord;
+
+    use super::partition_records;
+
+    fn batch(base_offset: i64, first_timestamp: i64, count: u8) -> DefaultBatch {
+        let mut batch = DefaultBatch::default();
+        batch.set_base_offset(base_offset);
+        batch.get_mut_header().first_timestamp = first_timestamp;
+        for value in 0..count {
+            let record: DefaultRecord = vec![value].into();
+            batch.add_record(record);
+        }
+        batch
+    }
+
+    #[test]
+    fn test_partition_records_from_offset() {
+        let mut min_timestamp = None;
+        let records = partition_records(
+            "test",
+            0,
+            vec![batch(5, 100, 3), batch(8, 200, 2)],
+            6,
+            &mut min_timestamp,
+        );
+
+        let offsets: Vec<i64> = records.iter().map(|record| record.offset).collect();
+        assert_eq!(offsets, vec![6, 7, 8, 9]);
+        assert_eq!(records[0].value, vec![1]);
+        assert_eq!(records[0].timestamp, 100);
+        assert_eq!(records[2].timestamp, 200);
+    }
+
+    #[test]
+    fn test_partition_records_from_timestamp() {
+        let mut min_timestamp = Some(150);
+        let records = partition_records(
+            "test",
+            0,
+            vec![batch(0, 100, 2), batch(2, 200, 2)],
+            0,
+            &mut min_timestamp,
+        );
+
+        let offsets: Vec<i64> = records.iter().map(|record| record.offset).collect();
+        assert_eq!(offsets, vec![2, 3]);
+        assert_eq!(min_timestamp, None);
+    }
+}
@@ -0,0 +1,7 @@
+mod config;
+mod consumer;
+
+pub use self::config::ConsumerConfig;
+pub use self::consumer::Consumer;
+pub use self::consumer::ConsumerOffset;
+pub use self::consumer::ConsumerRecord;
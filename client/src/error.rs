@@ -0,0 +1,84 @@
+use std::fmt;
+use std::io::Error as IoError;
+
+use kf_socket::KfSocketError;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::FlvErrorCode;
+
+#[derive(Debug)]
+pub enum ClientError {
+    IoError(IoError),
+    KfSocketError(KfSocketError),
+    /// error code returned by spu
+    KfError(ErrorCode),
+    /// error code returned by sc
+    FlvError(FlvErrorCode),
+    /// client was closed before request completed
+    Closed,
+}
+
+impl ClientError {
+    /// error may go away after leader is resolved again
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            ClientError::IoError(_) | ClientError::KfSocketError(_) => true,
+            ClientError::KfError(code) => match code {
+                ErrorCode::NotLeaderForPartition
+                | ErrorCode::LeaderNotAvailable
+                | ErrorCode::UnknownTopicOrPartition
+                | ErrorCode::RequestTimedOut
+                | ErrorCode::NotEnoughReplicas
+                | ErrorCode::NetworkException => true,
+                _ => false,
+            },
+            ClientError::FlvError(code) => match code {
+                FlvErrorCode::SpuOffline
+                | FlvErrorCode::PartitionPendingInitialization
+                | FlvErrorCode::PartitionNotLeader
+                | FlvErrorCode::TopicPendingInitialization => true,
+                _ => false,
+            },
+            ClientError::Closed => false,
+        }
+    }
+
+    /// copy of error for every record of failed batch, io errors keep kind and message
+    pub fn duplicate(&self) -> Self {
+        match self {
+            ClientError::IoError(err) => ClientError::IoError(IoError::new(err.kind(), err.to_string())),
+            ClientError::KfSocketError(KfSocketError::IoError(err)) => {
+                ClientError::IoError(IoError::new(err.kind(), err.to_string()))
+            }
+            ClientError::KfSocketError(err) => {
+                ClientError::IoError(IoError::new(std::io::ErrorKind::Other, err.to_string()))
+            }
+            ClientError::KfError(code) => ClientError::KfError(*code),
+            ClientError::FlvError(code) => ClientError::FlvError(*code),
+            ClientError::Closed => ClientError::Closed,
+        }
+    }
+}
+
+impl From<IoError> for ClientError {
+    fn from(error: IoError) -> Self {
+        ClientError::IoError(error)
+    }
+}
+
+impl From<KfSocketError> for ClientError {
+    fn from(error: KfSocketError) -> Self {
+        ClientError::KfSocketError(error)
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::IoError(err) => write!(f, "{}", err),
+            ClientError::KfSocketError(err) => write!(f, "{}", err),
+            ClientError::KfError(code) => write!(f, "{}", code.to_sentence()),
+            ClientError::FlvError(code) => write!(f, "{}", code.to_sentence()),
+            ClientError::Closed => write!(f, "client closed"),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+#![recursion_limit = "256"]
+
+mod connections;
+mod consumer;
+mod error;
+mod metadata;
+mod producer;
+
+pub use self::error::ClientError;
+pub use self::metadata::ClusterMetadata;
+pub use self::metadata::TopicLeaders;
+pub use self::producer::Producer;
+pub use self::producer::ProducerConfig;
+pub use self::producer::ProducerRecord;
+pub use self::producer::RecordMetadata;
+pub use self::producer::DeliveryFuture;
+pub use self::producer::Partitioner;
+pub use self::producer::RoundRobinPartitioner;
+pub use self::producer::KeyHashPartitioner;
+pub use self::consumer::Consumer;
+pub use self::consumer::ConsumerConfig;
+pub use self::consumer::ConsumerOffset;
+pub use self::consumer::ConsumerRecord;
+
+use kf_protocol::api::Request;
+use kf_protocol::api::RequestMessage;
+
+/// Create request message with client id, correlation id is assigned by multiplexed connection
+pub(crate) fn new_request_message<R>(request: R, client_id: &str) -> RequestMessage<R>
+where
+    R: Request,
+{
+    let mut req_msg: RequestMessage<R> = RequestMessage::new_request(request);
+    req_msg.get_mut_header().set_client_id(client_id);
+    req_msg
+}
@@ -0,0 +1,203 @@
+//!
+//! # Cluster Metadata
+//!
+//! Partition leaders of topics, resolved from SC topic composition and cached until
+//! invalidated by caller (typically after leader moved).
+//!
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use log::debug;
+use log::trace;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::FlvErrorCode;
+use kf_socket::KfSocket;
+use kf_socket::MultiplexerSocket;
+use sc_api::topic::FlvTopicCompositionRequest;
+use sc_api::topic::FlvTopicCompositionResponse;
+use types::socket_helpers::host_port_to_socket_addr;
+
+use crate::ClientError;
+use crate::connections::send_request;
+
+/// leaders by partition, partition without live leader has no address
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TopicLeaders {
+    partitions: BTreeMap<i32, Option<SocketAddr>>,
+}
+
+impl TopicLeaders {
+    pub fn partition_count(&self) -> i32 {
+        self.partitions.len() as i32
+    }
+
+    pub fn partitions(&self) -> Vec<i32> {
+        self.partitions.keys().cloned().collect()
+    }
+
+    pub fn leader(&self, partition: i32) -> Option<&SocketAddr> {
+        self.partitions.get(&partition).and_then(|leader| leader.as_ref())
+    }
+}
+
+pub struct ClusterMetadata {
+    sc_addr: SocketAddr,
+    client_id: String,
+    sc_socket: Option<Arc<MultiplexerSocket>>,
+    topics: HashMap<String, TopicLeaders>,
+}
+
+impl ClusterMetadata {
+    pub fn new(sc_addr: SocketAddr, client_id: String) -> Self {
+        Self {
+            sc_addr,
+            client_id,
+            sc_socket: None,
+            topics: HashMap::new(),
+        }
+    }
+
+    /// cached leaders of topic, fetched from sc if not known
+    pub async fn topic(&mut self, topic: &str) -> Result<TopicLeaders, ClientError> {
+        if let Some(leaders) = self.topics.get(topic) {
+            return Ok(leaders.clone());
+        }
+        self.refresh(topic).await
+    }
+
+    /// leader of partition, fetched from sc if not known
+    pub async fn leader(&mut self, topic: &str, partition: i32) -> Result<SocketAddr, ClientError> {
+        let leaders = self.topic(topic).await?;
+        match leaders.partitions.get(&partition) {
+            Some(Some(addr)) => Ok(*addr),
+            Some(None) => Err(ClientError::KfError(ErrorCode::LeaderNotAvailable)),
+            None => Err(ClientError::KfError(ErrorCode::UnknownTopicOrPartition)),
+        }
+    }
+
+    /// drop cached leaders, next lookup goes to sc
+    pub fn invalidate(&mut self, topic: &str) {
+        self.topics.remove(topic);
+    }
+
+    pub async fn refresh(&mut self, topic: &str) -> Result<TopicLeaders, ClientError> {
+        let response = match self.topic_composition(topic).await {
+            Ok(response) => response,
+            Err(err) => {
+                // reconnect on next lookup
+                self.sc_socket = None;
+                return Err(err);
+            }
+        };
+
+        let leaders = topic_leaders(&response, topic)?;
+        debug!("topic: {} leaders: {:?}", topic, leaders);
+        self.topics.insert(topic.to_owned(), leaders.clone());
+        Ok(leaders)
+    }
+
+    async fn topic_composition(&mut self, topic: &str) -> Result<FlvTopicCompositionResponse, ClientError> {
+        let socket = match &self.sc_socket {
+            Some(socket) if !socket.is_terminated() => socket.clone(),
+            _ => {
+                let socket = Arc::new(MultiplexerSocket::new(KfSocket::connect(&self.sc_addr).await?));
+                self.sc_socket = Some(socket.clone());
+                socket
+            }
+        };
+
+        let mut request = FlvTopicCompositionRequest::default();
+        request.topic_names = vec![topic.to_owned()];
+
+        trace!("topic composition req '{}': {:?}", self.sc_addr, request);
+        send_request(socket, request, &self.client_id).await
+    }
+}
+
+/// convert topic composition into leaders of topic
+pub fn topic_leaders(
+    response: &FlvTopicCompositionResponse,
+    topic: &str,
+) -> Result<TopicLeaders, ClientError> {
+    let topic_response = response
+        .topics
+        .iter()
+        .find(|topic_response| topic_response.name == topic)
+        .ok_or(ClientError::FlvError(FlvErrorCode::TopicNotFound))?;
+
+    if topic_response.error_code.is_error() {
+        return Err(ClientError::FlvError(topic_response.error_code));
+    }
+
+    let mut partitions = BTreeMap::new();
+    for partition in &topic_response.partitions {
+        let leader = if partition.error_code.is_error() {
+            None
+        } else {
+            response
+                .spus
+                .iter()
+                .find(|spu| spu.spu_id == partition.leader_id && !spu.error_code.is_error())
+                .and_then(|spu| host_port_to_socket_addr(&spu.host, spu.port).ok())
+        };
+        partitions.insert(partition.partition_idx, leader);
+    }
+
+    Ok(TopicLeaders { partitions })
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::net::SocketAddr;
+
+    use kf_protocol::api::FlvErrorCode;
+    use sc_api::topic::FlvTopicCompositionResponse;
+    use sc_api::topic::FetchTopicReponse;
+    use sc_api::topic::FetchPartitionResponse;
+    use sc_api::topic::FetchSpuReponse;
+
+    use super::topic_leaders;
+
+    fn partition(partition_idx: i32, leader_id: i32, error_code: FlvErrorCode) -> FetchPartitionResponse {
+        FetchPartitionResponse {
+            error_code,
+            partition_idx,
+            leader_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_topic_leaders() {
+        let response = FlvTopicCompositionResponse {
+            topics: vec![FetchTopicReponse {
+                name: "test".to_owned(),
+                partitions: vec![
+                    partition(0, 5001, FlvErrorCode::None),
+                    partition(1, 5002, FlvErrorCode::None),
+                    partition(2, 5001, FlvErrorCode::PartitionPendingInitialization),
+                ],
+                ..Default::default()
+            }],
+            spus: vec![FetchSpuReponse {
+                spu_id: 5001,
+                host: "127.0.0.1".to_owned(),
+                port: 9005,
+                ..Default::default()
+            }],
+        };
+
+        let leaders = topic_leaders(&response, "test").expect("leaders");
+        let addr: SocketAddr = "127.0.0.1:9005".parse().expect("addr");
+        assert_eq!(leaders.partition_count(), 3);
+        assert_eq!(leaders.leader(0), Some(&addr));
+        assert_eq!(leaders.leader(1), None);
+        assert_eq!(leaders.leader(2), None);
+
+        assert!(topic_leaders(&response, "unknown").is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//!
+//! # Record Accumulator
+//!
+//! Records are collected in batch per partition until batch is full or has lingered long enough.
+//!
+use std::collections::BTreeMap;
+use std::mem;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::channel::oneshot::Sender;
+
+use kf_protocol::api::DefaultBatch;
+use kf_protocol::api::DefaultRecord;
+use kf_protocol::api::Offset;
+use utils::now_ms;
+
+use crate::ClientError;
+use super::RecordMetadata;
+
+pub type DeliverySender = Sender<Result<RecordMetadata, ClientError>>;
+
+struct PendingRecord {
+    key: Option<Vec<u8>>,
+    value: Vec<u8>,
+    delivery: DeliverySender,
+}
+
+/// records of one partition which are sent together
+pub struct ProducerBatch {
+    pub topic: String,
+    pub partition: i32,
+    pub attempts: u32,
+    records: Vec<PendingRecord>,
+    size: usize,
+    created: Instant,
+}
+
+impl ProducerBatch {
+    fn new(topic: String, partition: i32) -> Self {
+        Self {
+            topic,
+            partition,
+            attempts: 0,
+            records: vec![],
+            size: 0,
+            created: Instant::now(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    #[cfg(test)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn push(&mut self, key: Option<Vec<u8>>, value: Vec<u8>, delivery: DeliverySender) {
+        self.size += key.as_ref().map(|key| key.len()).unwrap_or(0) + value.len();
+        self.records.push(PendingRecord {
+            key,
+            value,
+            delivery,
+        });
+    }
+
+    /// batch to be sent, rebuilt for each attempt
+    pub fn to_batch(&self) -> DefaultBatch {
+        let now = now_ms();
+
+        let mut batch = DefaultBatch::default();
+        batch.get_mut_header().first_timestamp = now;
+        batch.get_mut_header().max_time_stamp = now;
+        for pending in &self.records {
+            let mut record: DefaultRecord = pending.value.clone().into();
+            record.key = pending.key.clone().into();
+            batch.add_record(record);
+        }
+        batch
+    }
+
+    /// records are assigned consecutive offsets from base offset
+    pub fn complete(self, base_offset: Offset) {
+        for (index, pending) in self.records.into_iter().enumerate() {
+            let _ = pending.delivery.send(Ok(RecordMetadata {
+                topic: self.topic.clone(),
+                partition: self.partition,
+                offset: base_offset + index as Offset,
+            }));
+        }
+    }
+
+    pub fn fail(self, err: ClientError) {
+        for pending in self.records.into_iter() {
+            let _ = pending.delivery.send(Err(err.duplicate()));
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RecordAccumulator {
+    batches: BTreeMap<(String, i32), ProducerBatch>,
+}
+
+impl RecordAccumulator {
+    pub fn append(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        key: Option<Vec<u8>>,
+        value: Vec<u8>,
+        delivery: DeliverySender,
+    ) {
+        self.batches
+            .entry((topic.to_owned(), partition))
+            .or_insert_with(|| ProducerBatch::new(topic.to_owned(), partition))
+            .push(key, value, delivery);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    /// remove batches which are full or lingered long enough, all batches if flushed
+    pub fn drain_ready(&mut self, linger: Duration, batch_size: usize, flush: bool) -> Vec<ProducerBatch> {
+        if flush {
+            return mem::replace(&mut self.batches, BTreeMap::new())
+                .into_iter()
+                .map(|(_, batch)| batch)
+                .collect();
+        }
+
+        let ready_keys: Vec<(String, i32)> = self
+            .batches
+            .iter()
+            .filter(|(_, batch)| batch.size >= batch_size || batch.created.elapsed() >= linger)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        ready_keys
+            .into_iter()
+            .filter_map(|key| self.batches.remove(&key))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use futures::channel::oneshot::channel;
+
+    use super::RecordAccumulator;
+
+    #[test]
+    fn test_drain_ready() {
+        let mut accumulator = RecordAccumulator::default();
+        let (sender, _receiver) = channel();
+        accumulator.append("test", 0, None, vec![0; 10], sender);
+        let (sender, _receiver) = channel();
+        accumulator.append("test", 1, Some(vec![1; 5]), vec![0; 20], sender);
+
+        // nothing full, nothing lingered
+        assert!(accumulator.drain_ready(Duration::from_secs(60), 30, false).is_empty());
+
+        let (sender, _receiver) = channel();
+        accumulator.append("test", 0, None, vec![0; 10], sender);
+        let full = accumulator.drain_ready(Duration::from_secs(60), 25, false);
+        assert_eq!(full.len(), 1);
+        assert_eq!(full[0].partition, 1);
+        assert_eq!(full[0].size(), 25);
+
+        let flushed = accumulator.drain_ready(Duration::from_secs(60), 25, true);
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].len(), 2);
+        assert!(accumulator.is_empty());
+    }
+
+    #[test]
+    fn test_complete_offsets() {
+        let mut accumulator = RecordAccumulator::default();
+        let (first, mut first_receiver) = channel();
+        let (second, mut second_receiver) = channel();
+        accumulator.append("test", 0, None, vec![1], first);
+        accumulator.append("test", 0, None, vec![2], second);
+
+        let batch = accumulator.drain_ready(Duration::from_secs(0), 100, false).remove(0);
+        assert_eq!(batch.to_batch().records.len(), 2);
+        batch.complete(10);
+
+        let first = first_receiver.try_recv().expect("sent").expect("value").expect("ok");
+        let second = second_receiver.try_recv().expect("sent").expect("value").expect("ok");
+        assert_eq!(first.offset, 10);
+        assert_eq!(second.offset, 11);
+    }
+}
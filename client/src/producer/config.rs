@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// producer settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProducerConfig {
+    pub client_id: String,
+    /// how long records wait for more records of same partition before batch is sent
+    pub linger: Duration,
+    /// batch is sent as soon as records reach this size in bytes
+    pub batch_size: usize,
+    /// max number of records waiting to be sent before send is blocked
+    pub max_pending: usize,
+    /// number of times batch is resent after retriable error
+    pub retries: u32,
+    /// wait before resend, metadata is refreshed in between
+    pub retry_backoff: Duration,
+    pub acks: i16,
+    pub timeout_ms: i32,
+}
+
+impl Default for ProducerConfig {
+    fn default() -> Self {
+        Self {
+            client_id: "fluvio-producer".to_owned(),
+            linger: Duration::from_millis(5),
+            batch_size: 16384,
+            max_pending: 1000,
+            retries: 3,
+            retry_backoff: Duration::from_millis(100),
+            acks: 1,
+            timeout_ms: 1500,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod accumulator;
+mod config;
+mod partitioner;
+mod producer;
+
+pub use self::config::ProducerConfig;
+pub use self::partitioner::Partitioner;
+pub use self::partitioner::RoundRobinPartitioner;
+pub use self::partitioner::KeyHashPartitioner;
+pub use self::producer::Producer;
+pub use self::producer::ProducerRecord;
+pub use self::producer::RecordMetadata;
+pub use self::producer::DeliveryFuture;
@@ -0,0 +1,120 @@
+//!
+//! # Partitioners
+//!
+//! Select partition for record without explicit partition.
+//!
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+pub trait Partitioner: Send + Sync {
+    fn partition(&self, topic: &str, key: Option<&[u8]>, partition_count: i32) -> i32;
+}
+
+/// records are spread over partitions in turn, regardless of key
+#[derive(Debug, Default)]
+pub struct RoundRobinPartitioner {
+    counter: AtomicUsize,
+}
+
+impl Partitioner for RoundRobinPartitioner {
+    fn partition(&self, _topic: &str, _key: Option<&[u8]>, partition_count: i32) -> i32 {
+        if partition_count <= 0 {
+            return 0;
+        }
+        let next = self.counter.fetch_add(1, Ordering::Relaxed);
+        (next % partition_count as usize) as i32
+    }
+}
+
+/// records with same key go to same partition, same hash as Kafka default partitioner.
+/// records without key are spread in turn
+#[derive(Debug, Default)]
+pub struct KeyHashPartitioner {
+    round_robin: RoundRobinPartitioner,
+}
+
+impl Partitioner for KeyHashPartitioner {
+    fn partition(&self, topic: &str, key: Option<&[u8]>, partition_count: i32) -> i32 {
+        match key {
+            Some(key) if partition_count > 0 => {
+                (murmur2(key) & 0x7fff_ffff) % partition_count
+            }
+            _ => self.round_robin.partition(topic, key, partition_count),
+        }
+    }
+}
+
+/// murmur2 hash as implemented by Kafka
+pub fn murmur2(data: &[u8]) -> i32 {
+    const SEED: u32 = 0x9747_b28c;
+    const M: u32 = 0x5bd1_e995;
+    const R: u32 = 24;
+
+    let length = data.len();
+    let mut h: u32 = SEED ^ (length as u32);
+
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M);
+        h ^= k;
+    }
+
+    let tail = chunks.remainder();
+    if tail.len() == 3 {
+        h ^= u32::from(tail[2]) << 16;
+    }
+    if tail.len() >= 2 {
+        h ^= u32::from(tail[1]) << 8;
+    }
+    if !tail.is_empty() {
+        h ^= u32::from(tail[0]);
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    h as i32
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::murmur2;
+    use super::Partitioner;
+    use super::KeyHashPartitioner;
+    use super::RoundRobinPartitioner;
+
+    #[test]
+    fn test_murmur2() {
+        // same values as Kafka client tests
+        assert_eq!(murmur2(b"21"), -973932308);
+        assert_eq!(murmur2(b"foobar"), -790332482);
+        assert_eq!(murmur2(b"a-little-bit-long-string"), -985981536);
+        assert_eq!(murmur2(b"a-little-bit-longer-string"), -1486304829);
+        assert_eq!(murmur2(b"lkjh234lh9fiuh90y23oiuhsafujhadof229phr9h19h89h8"), -58897971);
+        assert_eq!(murmur2(b"abc"), 479470107);
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let partitioner = RoundRobinPartitioner::default();
+        let partitions: Vec<i32> = (0..4).map(|_| partitioner.partition("test", None, 3)).collect();
+        assert_eq!(partitions, vec![0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_key_hash() {
+        let partitioner = KeyHashPartitioner::default();
+        let first = partitioner.partition("test", Some(b"key"), 10);
+        assert_eq!(partitioner.partition("test", Some(b"key"), 10), first);
+        assert!(first >= 0 && first < 10);
+        assert_eq!(partitioner.partition("test", None, 2), 0);
+        assert_eq!(partitioner.partition("test", None, 2), 1);
+    }
+}
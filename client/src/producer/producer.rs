@@ -0,0 +1,362 @@
+//!
+//! # Producer
+//!
+//! Records are handed to background task which groups them in batches per partition,
+//! sends batches to partition leaders and completes delivery future of each record.
+//! Batches failing with retriable error are resent after leaders are refreshed from SC.
+//!
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use log::debug;
+use log::error;
+use log::trace;
+use futures::channel::mpsc::channel;
+use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::Sender;
+use futures::channel::oneshot;
+use futures::future::join_all;
+use futures::future::FutureExt;
+use futures::select;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+
+use future_helper::sleep;
+use future_helper::spawn;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::Offset;
+use kf_protocol::message::produce::DefaultKfPartitionRequest;
+use kf_protocol::message::produce::DefaultKfProduceRequest;
+use kf_protocol::message::produce::DefaultKfTopicRequest;
+
+use crate::ClientError;
+use crate::ClusterMetadata;
+use crate::connections::Connections;
+use crate::connections::send_request;
+use super::accumulator::ProducerBatch;
+use super::accumulator::RecordAccumulator;
+use super::KeyHashPartitioner;
+use super::Partitioner;
+use super::ProducerConfig;
+
+const IDLE_WAIT: Duration = Duration::from_secs(60);
+
+/// record to be produced, partition is chosen by partitioner if not set
+#[derive(Debug, Clone, Default)]
+pub struct ProducerRecord {
+    pub topic: String,
+    pub partition: Option<i32>,
+    pub key: Option<Vec<u8>>,
+    pub value: Vec<u8>,
+}
+
+impl ProducerRecord {
+    pub fn new<T: Into<String>>(topic: T, value: Vec<u8>) -> Self {
+        Self {
+            topic: topic.into(),
+            value,
+            ..Default::default()
+        }
+    }
+
+    pub fn key(mut self, key: Vec<u8>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn partition(mut self, partition: i32) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+}
+
+/// where record was stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordMetadata {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: Offset,
+}
+
+/// resolves when record is acknowledged by leader or failed after retries
+pub struct DeliveryFuture(oneshot::Receiver<Result<RecordMetadata, ClientError>>);
+
+impl Future for DeliveryFuture {
+    type Output = Result<RecordMetadata, ClientError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        match Pin::new(&mut self.0).poll(cx) {
+            Poll::Ready(Ok(result)) => Poll::Ready(result),
+            Poll::Ready(Err(_)) => Poll::Ready(Err(ClientError::Closed)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+enum ProducerCommand {
+    Send(ProducerRecord, oneshot::Sender<Result<RecordMetadata, ClientError>>),
+    Flush(oneshot::Sender<()>),
+}
+
+/// handle to producer task, task finishes pending records when all handles are dropped
+#[derive(Clone)]
+pub struct Producer {
+    sender: Sender<ProducerCommand>,
+}
+
+impl Producer {
+    /// producer with key hash partitioner
+    pub fn new(sc_addr: SocketAddr, config: ProducerConfig) -> Self {
+        Self::with_partitioner(sc_addr, config, Arc::new(KeyHashPartitioner::default()))
+    }
+
+    pub fn with_partitioner(
+        sc_addr: SocketAddr,
+        config: ProducerConfig,
+        partitioner: Arc<dyn Partitioner>,
+    ) -> Self {
+        let (sender, receiver) = channel(config.max_pending);
+        let metadata = ClusterMetadata::new(sc_addr, config.client_id.clone());
+        ProducerLoop {
+            config,
+            partitioner,
+            metadata,
+            receiver,
+            accumulator: RecordAccumulator::default(),
+            connections: Connections::default(),
+        }
+        .run();
+
+        Self { sender }
+    }
+
+    /// queue record, waits only if too many records are pending
+    pub async fn send(&mut self, record: ProducerRecord) -> Result<DeliveryFuture, ClientError> {
+        let (delivery, receiver) = oneshot::channel();
+        self.sender
+            .send(ProducerCommand::Send(record, delivery))
+            .await
+            .map_err(|_| ClientError::Closed)?;
+        Ok(DeliveryFuture(receiver))
+    }
+
+    /// send all queued records without waiting for linger
+    pub async fn flush(&mut self) -> Result<(), ClientError> {
+        let (done, receiver) = oneshot::channel();
+        self.sender
+            .send(ProducerCommand::Flush(done))
+            .await
+            .map_err(|_| ClientError::Closed)?;
+        receiver.await.map_err(|_| ClientError::Closed)
+    }
+}
+
+struct ProducerLoop {
+    config: ProducerConfig,
+    partitioner: Arc<dyn Partitioner>,
+    metadata: ClusterMetadata,
+    receiver: Receiver<ProducerCommand>,
+    accumulator: RecordAccumulator,
+    connections: Connections,
+}
+
+impl ProducerLoop {
+    fn run(self) {
+        spawn(self.dispatch_loop());
+    }
+
+    async fn dispatch_loop(mut self) {
+        debug!("starting producer: {}", self.config.client_id);
+        loop {
+            // timer only matters while records are waiting
+            let linger = if self.accumulator.is_empty() {
+                IDLE_WAIT
+            } else {
+                self.config.linger
+            };
+
+            select! {
+                command = self.receiver.next().fuse() => match command {
+                    Some(ProducerCommand::Send(record, delivery)) => {
+                        self.append(record, delivery).await;
+                        self.send_ready(false).await;
+                    },
+                    Some(ProducerCommand::Flush(done)) => {
+                        self.send_ready(true).await;
+                        let _ = done.send(());
+                    },
+                    None => {
+                        debug!("producer handles dropped, sending remaining records");
+                        self.send_ready(true).await;
+                        break;
+                    }
+                },
+                _ = (sleep(linger)).fuse() => {
+                    if !self.accumulator.is_empty() {
+                        self.send_ready(false).await;
+                    }
+                }
+            }
+        }
+        debug!("producer: {} terminated", self.config.client_id);
+    }
+
+    async fn append(
+        &mut self,
+        record: ProducerRecord,
+        delivery: oneshot::Sender<Result<RecordMetadata, ClientError>>,
+    ) {
+        let partition = match record.partition {
+            Some(partition) => partition,
+            None => match self.metadata.topic(&record.topic).await {
+                Ok(leaders) => self.partitioner.partition(
+                    &record.topic,
+                    record.key.as_ref().map(|key| key.as_slice()),
+                    leaders.partition_count(),
+                ),
+                Err(err) => {
+                    let _ = delivery.send(Err(err));
+                    return;
+                }
+            },
+        };
+
+        self.accumulator
+            .append(&record.topic, partition, record.key, record.value, delivery);
+    }
+
+    async fn send_ready(&mut self, flush: bool) {
+        let mut batches =
+            self.accumulator
+                .drain_ready(self.config.linger, self.config.batch_size, flush);
+
+        while !batches.is_empty() {
+            let retries = self.send_batches(batches).await;
+            if retries.is_empty() {
+                break;
+            }
+
+            // leaders may have moved, look them up again before resending
+            sleep(self.config.retry_backoff).await;
+            let topics: BTreeSet<String> = retries.iter().map(|batch| batch.topic.clone()).collect();
+            for topic in topics {
+                self.metadata.invalidate(&topic);
+            }
+            batches = retries;
+        }
+    }
+
+    /// send batches grouped by leader, return batches to be retried
+    async fn send_batches(&mut self, batches: Vec<ProducerBatch>) -> Vec<ProducerBatch> {
+        let mut retries = vec![];
+        let mut by_leader: HashMap<SocketAddr, Vec<ProducerBatch>> = HashMap::new();
+        for batch in batches {
+            match self.metadata.leader(&batch.topic, batch.partition).await {
+                Ok(leader) => by_leader.entry(leader).or_insert_with(Vec::new).push(batch),
+                Err(err) => self.retry_or_fail(batch, err, &mut retries),
+            }
+        }
+
+        // produce requests to different leaders are in flight at same time
+        let mut requests = vec![];
+        for (leader, batches) in by_leader {
+            match self.connections.connection(leader).await {
+                Ok(socket) => {
+                    let request = self.produce_request(&batches);
+                    trace!("produce {} batches to: {}", batches.len(), leader);
+                    requests.push((leader, socket, request, batches));
+                }
+                Err(err) => {
+                    error!("error connecting to: {}, {}", leader, err);
+                    for batch in batches {
+                        self.retry_or_fail(batch, err.duplicate(), &mut retries);
+                    }
+                }
+            }
+        }
+
+        let client_id = &self.config.client_id;
+        let responses = join_all(requests.into_iter().map(|(leader, socket, request, batches)| {
+            async move {
+                let response = send_request(socket, request, client_id).await;
+                (leader, response, batches)
+            }
+        }))
+        .await;
+
+        for (leader, response, batches) in responses {
+            match response {
+                Ok(response) => {
+                    for batch in batches {
+                        let result = response
+                            .find_partition_response(&batch.topic, batch.partition)
+                            .map(|partition| (partition.error_code, partition.base_offset));
+                        match result {
+                            Some((ErrorCode::None, base_offset)) => batch.complete(base_offset),
+                            Some((error_code, _)) => {
+                                self.retry_or_fail(batch, ClientError::KfError(error_code), &mut retries)
+                            }
+                            None => self.retry_or_fail(
+                                batch,
+                                ClientError::KfError(ErrorCode::UnknownServerError),
+                                &mut retries,
+                            ),
+                        }
+                    }
+                }
+                Err(err) => {
+                    error!("error producing to: {}, {}", leader, err);
+                    self.connections.remove(&leader);
+                    for batch in batches {
+                        self.retry_or_fail(batch, err.duplicate(), &mut retries);
+                    }
+                }
+            }
+        }
+
+        retries
+    }
+
+    fn retry_or_fail(&self, mut batch: ProducerBatch, err: ClientError, retries: &mut Vec<ProducerBatch>) {
+        batch.attempts += 1;
+        if err.is_retriable() && batch.attempts <= self.config.retries {
+            debug!(
+                "retrying {}/{} after: {}, attempt: {}",
+                batch.topic, batch.partition, err, batch.attempts
+            );
+            retries.push(batch);
+        } else {
+            batch.fail(err);
+        }
+    }
+
+    fn produce_request(&self, batches: &[ProducerBatch]) -> DefaultKfProduceRequest {
+        let mut topics: BTreeMap<String, DefaultKfTopicRequest> = BTreeMap::new();
+        for batch in batches {
+            let mut partition_request = DefaultKfPartitionRequest::default();
+            partition_request.partition_index = batch.partition;
+            partition_request.records.batches.push(batch.to_batch());
+
+            let topic_request = topics.entry(batch.topic.clone()).or_insert_with(|| {
+                let mut topic_request = DefaultKfTopicRequest::default();
+                topic_request.name = batch.topic.clone();
+                topic_request
+            });
+            topic_request.partitions.push(partition_request);
+        }
+
+        let mut request = DefaultKfProduceRequest::default();
+        request.acks = self.config.acks;
+        request.timeout_ms = self.config.timeout_ms;
+        request.topics = topics.into_iter().map(|(_, topic)| topic).collect();
+        request
+    }
+}
@@ -5,7 +5,6 @@ use std::task::Poll;
 
 use futures::future::Future;
 use futures_1::Async as Async_01;
-use std::fmt::Debug;
 use std::io;
 
 pub struct BlockIO<F> {
@@ -14,6 +13,7 @@ pub struct BlockIO<F> {
 
 impl<F> Unpin for BlockIO<F> {}
 
+/// run blocking closure on tokio blocking pool
 pub fn asyncify<F, R, E>(f: F) -> BlockIO<F>
 where
     F: FnOnce() -> Result<R,E>,
@@ -24,15 +24,16 @@ where
 impl<R,E, F> Future for BlockIO<F>
 where
     F: FnOnce() -> Result<R,E>,
-    R: Debug,
     E: From<io::Error>
 {
     type Output = Result<R,E>;
 
     fn poll(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Result<R,E>> {
-        let result = tokio_threadpool_1::blocking(self.f.take().unwrap());
+        // closure is only taken when pool has capacity, otherwise it is kept for next poll
+        let f = &mut self.f;
+        let result = tokio_threadpool_1::blocking(|| (f.take().unwrap())());
         match result {
-            Ok(Async_01::Ready(Ok(v))) => Poll::Ready(Ok(v.into())),
+            Ok(Async_01::Ready(Ok(v))) => Poll::Ready(Ok(v)),
             Ok(Async_01::Ready(Err(err))) => Poll::Ready(Err(err)),
             Ok(Async_01::NotReady) => Poll::Pending,
             Err(_) => Poll::Ready(Err(blocking_err().into())),
@@ -15,9 +15,9 @@ mod zero_copy;
 pub mod net;
 
 #[cfg(feature = "tokio2")]
-use self::io_util_3::asyncify;
+pub use self::io_util_3::asyncify;
 #[cfg(not(feature = "tokio2"))]
-use self::io_util_1::asyncify;
+pub use self::io_util_1::asyncify;
 
 pub use self::write::AsyncWrite2;
 pub use self::write::WriteBufAll;
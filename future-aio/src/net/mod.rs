@@ -16,6 +16,7 @@ pub use self::common_tcp_stream::AsyncTcpStream;
 pub use self::common_tcp_stream::TcpStreamSplitStream;
 pub use self::common_tcp_stream::TcpStreamSplitSink;
 pub use self::common_tcp_stream::TcpStreamSplit;
+pub use self::common_tcp_stream::SimpleCodec;
 
 
 
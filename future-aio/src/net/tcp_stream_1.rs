@@ -102,6 +102,17 @@ impl AsyncTcpStream {
         self.into()
     }
 
+    /// split with configured codec instead of default one
+    pub fn split_with_codec<C>(self, codec: C) -> TcpStreamSplit<C>
+        where C: Decoder + Encoder
+    {
+        let (sink, stream) = codec.framed(self.0).split();
+        TcpStreamSplit {
+            sink: Compat01As03Sink::new(sink),
+            stream: Compat01As03::new(stream)
+        }
+    }
+
 }
 
 
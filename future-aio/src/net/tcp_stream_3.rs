@@ -96,6 +96,17 @@ impl AsyncTcpStream {
         self.into()
     }
 
+    /// split with configured codec instead of default one
+    pub fn split_with_codec<C>(self, codec: C) -> TcpStreamSplit<C>
+        where C: TkDecoder + TkEncoder + Unpin
+    {
+        let (sink, stream) = codec.framed(self.0).split();
+        TcpStreamSplit {
+            sink,
+            stream
+        }
+    }
+
 }
 
 
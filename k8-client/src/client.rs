@@ -39,6 +39,8 @@ use k8_metadata::core::metadata::K8Meta;
 use k8_metadata::core::metadata::items_uri;
 use k8_metadata::core::metadata::InputK8Obj;
 use k8_metadata::core::metadata::UpdateK8ObjStatus;
+use k8_metadata::core::metadata::K8SpecObj;
+use k8_metadata::core::metadata::UpdateItemMeta;
 use k8_metadata::core::metadata::K8List;
 use k8_metadata::core::metadata::K8Obj;
 use k8_metadata::core::metadata::K8Status;
@@ -155,6 +157,10 @@ impl K8Client {
             return Err(ClientError::NotFound);
         }
 
+        if status == StatusCode::CONFLICT {
+            return Err(ClientError::Conflict);
+        }
+
         let body = resp.into_body().concat2().compat().await?;
         serde_json::from_slice(&body).map_err(|err| {
             error!("parser error: {}", err);
@@ -389,6 +395,42 @@ impl K8Client {
         }
     }
 
+    /// replace existing object. resource version must match current object,
+    /// otherwise conflict error is returned (optimistic concurrency)
+    pub async fn replace_item<S>(
+        &self,
+        value: &K8SpecObj<S,UpdateItemMeta>,
+    ) -> Result<K8Obj<S,S::Status>, ClientError>
+    where
+        K8SpecObj<S,UpdateItemMeta>: Serialize + Debug,
+        K8Obj<S,S::Status>: DeserializeOwned,
+        S: Spec
+    {
+        let uri = item_uri::<S>(
+            self.hostname(),
+            &value.metadata.name,
+            &value.metadata.namespace,
+            None,
+        );
+        debug!("replacing '{}' - uri: {}", value.metadata.name, uri);
+        trace!("replace: {:#?}", &value);
+
+        let req = || -> Result<_, ClientError> {
+            let bytes = serde_json::to_vec(&value)?;
+            trace!(
+                "replace raw: {}",
+                String::from_utf8_lossy(&bytes).to_string()
+            );
+            self.default_req(uri)
+                .method(Method::PUT)
+                .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                .body(Body::from(bytes))
+                .map_err(|e| e.into())
+        }();
+
+        self.handle_request(req).await
+    }
+
     /// update status
     pub async fn update_status<S>(
         &self,
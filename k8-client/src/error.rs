@@ -19,6 +19,7 @@ pub enum ClientError {
     DiffError(DiffError),
     PatchError,
     NotFound,
+    Conflict,
 }
 
 impl From<IoError> for ClientError {
@@ -66,6 +67,7 @@ impl fmt::Display for ClientError {
             ClientError::HyperError(err) => write!(f, "{}", err),
             ClientError::JsonError(err) => write!(f, "{}", err),
             ClientError::NotFound => write!(f, "not found"),
+            ClientError::Conflict => write!(f, "conflict, object has been modified"),
             ClientError::DiffError(err) => write!(f, "{:#?}", err),
             ClientError::PatchError => write!(f, "patch error"),
         }
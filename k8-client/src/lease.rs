@@ -0,0 +1,99 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use k8_metadata::core::Crd;
+use k8_metadata::core::CrdNames;
+use k8_metadata::core::Spec;
+use k8_metadata::core::Status;
+
+
+//
+// Lease Object, used for leader election
+const LEASE_API: Crd = Crd {
+    group: "coordination.k8s.io",
+    version: "v1",
+    names: CrdNames {
+        kind: "Lease",
+        plural: "leases",
+        singular: "lease",
+    },
+};
+
+impl Spec for LeaseSpec {
+
+    type Status = LeaseStatus;
+
+    fn metadata() -> &'static Crd {
+        &LEASE_API
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaseSpec {
+    pub holder_identity: Option<String>,
+    pub lease_duration_seconds: Option<i32>,
+    pub acquire_time: Option<String>,
+    pub renew_time: Option<String>,
+    pub lease_transitions: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaseStatus {}
+
+impl Status for LeaseStatus{}
+
+
+/// format time as K8 MicroTime (RFC3339 with microseconds, UTC)
+pub fn micro_time(time: SystemTime) -> String {
+    let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = elapsed.as_secs() as i64;
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let day_secs = secs % 86400;
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+        year,
+        month,
+        day,
+        day_secs / 3600,
+        (day_secs % 3600) / 60,
+        day_secs % 60,
+        elapsed.subsec_micros()
+    )
+}
+
+/// convert days since epoch to (year, month, day)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+    use std::time::UNIX_EPOCH;
+
+    use super::micro_time;
+
+    #[test]
+    fn test_micro_time() {
+        assert_eq!(micro_time(UNIX_EPOCH), "1970-01-01T00:00:00.000000Z");
+        let time = UNIX_EPOCH + Duration::from_micros(1_567_000_000_123_456);
+        assert_eq!(micro_time(time), "2019-08-28T13:46:40.123456Z");
+    }
+}
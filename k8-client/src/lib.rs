@@ -7,6 +7,7 @@ mod wstream;
 mod config;
 mod config_map;
 mod secret;
+mod lease;
 
 #[cfg(feature = "k8")]
 pub mod fixture;
@@ -20,6 +21,8 @@ pub use self::error::ClientError;
 pub use self::pod::ContainerPortSpec;
 pub use self::pod::ContainerSpec;
 pub use self::pod::PodSpec;
+pub use self::pod::Probe;
+pub use self::pod::HttpGetAction;
 pub use self::pod::PodStatus;
 pub use self::pod::VolumeMount;
 pub use self::service::ServicePort;
@@ -32,3 +35,6 @@ pub use self::config_map::ConfigMapSpec;
 pub use self::config_map::ConfigMapStatus;
 pub use self::secret::SecretSpec;
 pub use self::secret::SecretStatus;
+pub use self::lease::LeaseSpec;
+pub use self::lease::LeaseStatus;
+pub use self::lease::micro_time;
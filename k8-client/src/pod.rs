@@ -66,7 +66,39 @@ pub struct ContainerSpec {
     pub resource: Option<ResourceRequirements>,
     pub termination_mssage_path: Option<String>,
     pub termination_message_policy: Option<String>,
-    pub tty: Option<bool>
+    pub tty: Option<bool>,
+    pub liveness_probe: Option<Probe>,
+    pub readiness_probe: Option<Probe>
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Probe {
+    pub http_get: Option<HttpGetAction>,
+    pub initial_delay_seconds: Option<u32>,
+    pub period_seconds: Option<u32>,
+    pub timeout_seconds: Option<u32>,
+    pub failure_threshold: Option<u32>,
+}
+
+impl Probe {
+    /// probe by http get on container port
+    pub fn http<T: Into<String>>(path: T, port: u16) -> Self {
+        Probe {
+            http_get: Some(HttpGetAction {
+                path: path.into(),
+                port,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpGetAction {
+    pub path: String,
+    pub port: u16,
 }
 
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
@@ -0,0 +1,25 @@
+mod spec;
+mod status;
+
+pub use self::spec::AclSpec;
+pub use self::spec::AclResourceType;
+pub use self::spec::AclPatternType;
+pub use self::spec::AclOperation;
+pub use self::spec::AclPermission;
+
+pub use self::status::AclStatus;
+
+use metadata_core::Crd;
+use metadata_core::CrdNames;
+use metadata_core::GROUP;
+use metadata_core::V1;
+
+const ACL_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "Acl",
+        plural: "acls",
+        singular: "acl",
+    },
+};
@@ -0,0 +1,98 @@
+//!
+//! # ACL Spec
+//!
+//! Interface to the ACL metadata spec in K8 key value store
+//!
+use metadata_core::Crd;
+use metadata_core::Spec;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ACL_API;
+
+use super::AclStatus;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+impl Spec for AclSpec {
+    type Status = AclStatus;
+
+    fn metadata() -> &'static Crd {
+        &ACL_API
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AclSpec {
+    pub principal: String,
+    pub host: String,
+    pub resource_type: AclResourceType,
+    pub pattern_type: AclPatternType,
+    pub resource_name: String,
+    pub operation: AclOperation,
+    pub permission: AclPermission,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub enum AclResourceType {
+    Topic,
+    Group,
+    Cluster,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub enum AclPatternType {
+    Literal,
+    Prefixed,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub enum AclOperation {
+    All,
+    Read,
+    Write,
+    Create,
+    Delete,
+    Alter,
+    Describe,
+    ClusterAction,
+    DescribeConfigs,
+    AlterConfigs,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub enum AclPermission {
+    Allow,
+    Deny,
+}
+
+// -----------------------------------
+// Defaults
+// -----------------------------------
+
+impl Default for AclResourceType {
+    fn default() -> Self {
+        AclResourceType::Topic
+    }
+}
+
+impl Default for AclPatternType {
+    fn default() -> Self {
+        AclPatternType::Literal
+    }
+}
+
+impl Default for AclOperation {
+    fn default() -> Self {
+        AclOperation::All
+    }
+}
+
+impl Default for AclPermission {
+    fn default() -> Self {
+        AclPermission::Allow
+    }
+}
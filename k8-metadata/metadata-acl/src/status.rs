@@ -0,0 +1,15 @@
+//!
+//! # ACL Status
+//!
+//! Interface to the ACL metadata status in K8 key value store.
+//! ACLs have no runtime state, status is kept to match other objects.
+//!
+use serde::Deserialize;
+use serde::Serialize;
+
+use metadata_core::Status;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
+pub struct AclStatus {}
+
+impl Status for AclStatus {}
@@ -0,0 +1,84 @@
+//!
+//! # SASL Credential Spec
+//!
+//! Interface to the SASL credential metadata in K8 key value store.
+//! Only salted SCRAM keys are stored, never clear text passwords.
+//!
+
+use metadata_core::Crd;
+use metadata_core::Spec;
+use metadata_core::Status;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::SASL_CREDENTIAL_API;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+impl Spec for SaslCredentialSpec {
+    type Status = SaslCredentialStatus;
+
+    fn metadata() -> &'static Crd {
+        &SASL_CREDENTIAL_API
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SaslCredentialSpec {
+    pub user: String,
+    pub mechanism: CredentialMechanism,
+    pub salt: String,
+    pub iterations: i32,
+    pub stored_key: String,
+    pub server_key: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub enum CredentialMechanism {
+    ScramSha256,
+    ScramSha512,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+pub struct SaslCredentialStatus {}
+
+impl Status for SaslCredentialStatus {}
+
+// -----------------------------------
+// Defaults
+// -----------------------------------
+
+impl Default for CredentialMechanism {
+    fn default() -> Self {
+        CredentialMechanism::ScramSha256
+    }
+}
+
+// -----------------------------------
+// Naming
+// -----------------------------------
+
+/// object name of credential for user and SASL mechanism name (ex: SCRAM-SHA-256).
+/// user is hex encoded, so name is valid object name and distinct users never share name
+pub fn credential_name(user: &str, mechanism_name: &str) -> String {
+    let hex_user: String = user.bytes().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}-{}", mechanism_name.to_lowercase(), hex_user)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::credential_name;
+
+    #[test]
+    fn test_credential_name() {
+        assert_eq!(credential_name("alice", "SCRAM-SHA-256"), "scram-sha-256-616c696365");
+        assert_ne!(credential_name("Alice", "SCRAM-SHA-256"), credential_name("alice", "SCRAM-SHA-256"));
+        assert_ne!(credential_name("a-b", "SCRAM-SHA-256"), credential_name("a", "SCRAM-SHA-256"));
+        assert_ne!(credential_name("alice", "SCRAM-SHA-512"), credential_name("alice", "SCRAM-SHA-256"));
+    }
+}
@@ -1,10 +1,15 @@
 mod spec;
 mod status;
+mod credential;
 
 pub use self::spec::AuthTokenSpec;
 pub use self::spec::TokenType;
 pub use self::status::AuthTokenStatus;
 pub use self::status::TokenResolution;
+pub use self::credential::SaslCredentialSpec;
+pub use self::credential::SaslCredentialStatus;
+pub use self::credential::CredentialMechanism;
+pub use self::credential::credential_name;
 
 use metadata_core::Crd;
 use metadata_core::CrdNames;
@@ -20,3 +25,13 @@ const AUTH_TOKEN_API: Crd = Crd {
         singular: "auth-token",
     },
 };
+
+const SASL_CREDENTIAL_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "SaslCredential",
+        plural: "sasl-credentials",
+        singular: "sasl-credential",
+    },
+};
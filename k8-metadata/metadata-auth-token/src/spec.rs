@@ -28,7 +28,7 @@ impl Spec for AuthTokenSpec {
 
 // TODO: add refresh secret
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthTokenSpec {
     pub token_type: TokenType,
@@ -47,6 +47,12 @@ pub enum TokenType {
 // Implementation - AuthTokenSpec
 // -----------------------------------
 
+impl Default for TokenType {
+    fn default() -> Self {
+        TokenType::Any
+    }
+}
+
 impl AuthTokenSpec {
     pub fn new(token_type: TokenType, min_spu: i32, max_spu: i32) -> Self {
         AuthTokenSpec {
@@ -29,3 +29,12 @@ pub enum TokenResolution {
 }
 
 impl Status for AuthTokenStatus{}
+
+impl Default for AuthTokenStatus {
+    fn default() -> Self {
+        AuthTokenStatus {
+            resolution: TokenResolution::Init,
+            reason: "".to_owned(),
+        }
+    }
+}
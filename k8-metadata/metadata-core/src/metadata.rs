@@ -14,6 +14,7 @@ use crate::Spec;
 
 pub const DEFAULT_NS: &'static str = "default";
 pub const TYPE_OPAQUE: &'static str = "Opaque";
+pub const MAX_NAME_LEN: usize = 253;
 
 pub trait K8Meta<S> where S: Spec {
 
@@ -610,11 +611,37 @@ pub struct ObjectFieldSelector {
     pub field_path: String
 }
 
+/// validate object name as DNS-1123 subdomain, same rule Kubernetes applies to object names:
+/// lowercase alphanumeric, '-' or '.', must start and end with alphanumeric character
+pub fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("name must not be empty".to_owned());
+    }
+
+    if name.len() > MAX_NAME_LEN {
+        return Err(format!("name '{}' must be no more than {} characters", name, MAX_NAME_LEN));
+    }
+
+    let valid_char = |ch: char| ch.is_ascii_lowercase() || ch.is_ascii_digit();
+    let valid = name.chars().all(|ch| valid_char(ch) || ch == '-' || ch == '.')
+        && name.starts_with(valid_char)
+        && name.ends_with(valid_char);
+    if !valid {
+        return Err(format!(
+            "name '{}' must consist of lower case alphanumeric characters, '-' or '.', and must start and end with an alphanumeric character",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
 
     use super::Env;
     use super::ObjectMeta;
+    use super::validate_name;
 
     #[test]
     fn test_metadata_label() {
@@ -632,4 +659,18 @@ mod test {
         assert_eq!(env.value, Some("english".to_owned()));
     }
 
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("test").is_ok());
+        assert!(validate_name("test-0").is_ok());
+        assert!(validate_name("my.topic-1").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("..").is_err());
+        assert!(validate_name("../test").is_err());
+        assert!(validate_name("a/b").is_err());
+        assert!(validate_name("Test").is_err());
+        assert!(validate_name("-test").is_err());
+        assert!(validate_name(&"a".repeat(254)).is_err());
+    }
+
 }
@@ -3,6 +3,8 @@
 //!
 //! Interface to the Partition metadata spec in K8 key value store
 //!
+use std::collections::BTreeMap;
+
 use crate::PARTITION_API;
 use metadata_core::Crd;
 use metadata_core::Spec;
@@ -26,13 +28,17 @@ impl Spec for PartitionSpec {
 pub struct PartitionSpec {
     pub leader: i32,
     pub replicas: Vec<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<BTreeMap<String, String>>,
 }
 
 impl PartitionSpec {
     pub fn new(leader: i32,replicas: Vec<i32>) -> Self {
         PartitionSpec { 
             leader,
-            replicas
+            replicas,
+            config: None,
         }
     }
 }
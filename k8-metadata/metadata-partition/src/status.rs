@@ -22,7 +22,11 @@ pub struct PartitionStatus {
 pub struct ReplicaStatus {
     pub spu: i32,
     pub hw: i64,
-    pub leo: i64
+    pub leo: i64,
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub segments: u32
 }
 
 
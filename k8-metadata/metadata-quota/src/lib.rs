@@ -0,0 +1,21 @@
+mod spec;
+mod status;
+
+pub use self::spec::ClientQuotaSpec;
+
+pub use self::status::ClientQuotaStatus;
+
+use metadata_core::Crd;
+use metadata_core::CrdNames;
+use metadata_core::GROUP;
+use metadata_core::V1;
+
+const CLIENT_QUOTA_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "ClientQuota",
+        plural: "client-quotas",
+        singular: "client-quota",
+    },
+};
@@ -0,0 +1,40 @@
+//!
+//! # Client Quota Spec
+//!
+//! Interface to the client quota metadata spec in K8 key value store
+//!
+use metadata_core::Crd;
+use metadata_core::Spec;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::CLIENT_QUOTA_API;
+
+use super::ClientQuotaStatus;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+impl Spec for ClientQuotaSpec {
+    type Status = ClientQuotaStatus;
+
+    fn metadata() -> &'static Crd {
+        &CLIENT_QUOTA_API
+    }
+}
+
+/// Byte rates for principal and/or client id.
+/// `<default>` entity applies to each principal or client id without specific quota
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientQuotaSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer_byte_rate: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_byte_rate: Option<i64>,
+}
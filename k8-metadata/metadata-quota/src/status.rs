@@ -0,0 +1,15 @@
+//!
+//! # Client Quota Status
+//!
+//! Interface to the client quota metadata status in K8 key value store.
+//! Quotas have no runtime state, status is kept to match other objects.
+//!
+use serde::Deserialize;
+use serde::Serialize;
+
+use metadata_core::Status;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
+pub struct ClientQuotaStatus {}
+
+impl Status for ClientQuotaStatus {}
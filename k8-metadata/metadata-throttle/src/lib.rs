@@ -0,0 +1,21 @@
+mod spec;
+mod status;
+
+pub use self::spec::ReplicationThrottleSpec;
+
+pub use self::status::ReplicationThrottleStatus;
+
+use metadata_core::Crd;
+use metadata_core::CrdNames;
+use metadata_core::GROUP;
+use metadata_core::V1;
+
+const REPLICATION_THROTTLE_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "ReplicationThrottle",
+        plural: "replication-throttles",
+        singular: "replication-throttle",
+    },
+};
@@ -0,0 +1,34 @@
+//!
+//! # Replication Throttle Spec
+//!
+//! Interface to the replication throttle metadata spec in K8 key value store
+//!
+use metadata_core::Crd;
+use metadata_core::Spec;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::REPLICATION_THROTTLE_API;
+
+use super::ReplicationThrottleStatus;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+impl Spec for ReplicationThrottleSpec {
+    type Status = ReplicationThrottleStatus;
+
+    fn metadata() -> &'static Crd {
+        &REPLICATION_THROTTLE_API
+    }
+}
+
+/// Replication throttle rate of spu, or of all spus if spu is not set
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplicationThrottleSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spu: Option<i32>,
+    pub rate: i64,
+}
@@ -0,0 +1,15 @@
+//!
+//! # Replication Throttle Status
+//!
+//! Interface to the replication throttle metadata status in K8 key value store.
+//! Throttles have no runtime state, status is kept to match other objects.
+//!
+use serde::Deserialize;
+use serde::Serialize;
+
+use metadata_core::Status;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
+pub struct ReplicationThrottleStatus {}
+
+impl Status for ReplicationThrottleStatus {}
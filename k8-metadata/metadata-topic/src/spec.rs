@@ -3,6 +3,8 @@
 //!
 //! Interface to the Topic metadata spec in K8 key value store
 //!
+use std::collections::BTreeMap;
+
 use metadata_core::Crd;
 use metadata_core::Spec;
 use serde::Deserialize;
@@ -39,6 +41,9 @@ pub struct TopicSpec {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_replica_assignment: Option<Vec<Partition>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
@@ -70,8 +75,14 @@ impl TopicSpec {
             replication_factor,
             ignore_rack_assignment,
             custom_replica_assignment,
+            config: None,
         }
     }
+
+    pub fn with_config(mut self, config: Option<BTreeMap<String, String>>) -> Self {
+        self.config = config;
+        self
+    }
 }
 
 impl Partition {
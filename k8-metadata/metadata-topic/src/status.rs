@@ -25,6 +25,7 @@ pub enum TopicStatusResolution {
     InsufficientResources,    // out of sync with partition/replication_factor
     InvalidConfig,              // invalid configuration
     Provisioned,                // spu allocated
+    Deleting,                   // waiting for replicas to be removed
 }
 
 impl Default for TopicStatusResolution {
@@ -21,3 +21,15 @@ pub mod partition {
 pub mod spg {
     pub use metadata_spg::*;
 }
+
+pub mod acl {
+    pub use metadata_acl::*;
+}
+
+pub mod quota {
+    pub use metadata_quota::*;
+}
+
+pub mod throttle {
+    pub use metadata_throttle::*;
+}
@@ -5,4 +5,8 @@ DATA_DIR=$(dirname "$0")
 kubectl apply -f ${DATA_DIR}/crd_spu.yaml
 kubectl apply -f ${DATA_DIR}/crd_spg.yaml
 kubectl apply -f ${DATA_DIR}/crd_partition.yaml
-kubectl apply -f ${DATA_DIR}/crd_topic.yaml
\ No newline at end of file
+kubectl apply -f ${DATA_DIR}/crd_topic.yaml
+kubectl apply -f ${DATA_DIR}/crd_acl.yaml
+kubectl apply -f ${DATA_DIR}/crd_sasl_credential.yaml
+kubectl apply -f ${DATA_DIR}/crd_client_quota.yaml
+kubectl apply -f ${DATA_DIR}/crd_replication_throttle.yaml
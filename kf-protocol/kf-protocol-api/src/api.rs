@@ -14,6 +14,7 @@ use log::debug;
 
 use kf_protocol::Decoder;
 use kf_protocol::Encoder;
+use kf_protocol::Version;
 use kf_protocol::bytes::Buf;
 use kf_protocol_derive::Decode;
 use kf_protocol_derive::Encode;
@@ -26,9 +27,22 @@ pub trait Request: Encoder + Decoder + Debug {
     const DEFAULT_API_VERSION: i16 = 0;
     const MIN_API_VERSION: i16 = 0;
     const MAX_API_VERSION: i16 = -1;
+    /// first version using flexible encoding, -1 if api has no flexible versions
+    const MIN_FLEXIBLE_VERSION: i16 = -1;
 
     type Response: Encoder + Decoder + Debug ;
 
+    /// flexible versions use request header v2 and response header v1 with tagged fields
+    fn is_flexible(version: Version) -> bool {
+        Self::MIN_FLEXIBLE_VERSION >= 0 && version >= Self::MIN_FLEXIBLE_VERSION
+    }
+
+    /// response header has tagged fields. ApiVersions overrides this since its response header
+    /// must be readable by clients which don't know supported versions yet
+    fn is_flexible_response_header(version: Version) -> bool {
+        Self::is_flexible(version)
+    }
+
 }
 
 
@@ -36,6 +36,11 @@ pub enum FlvErrorCode {
     // Partition errors
     PartitionPendingInitialization = 11,
     PartitionNotLeader = 12,
+    PartitionError = 13,
+
+    // Authorization errors
+    TopicAuthorizationFailed = 14,
+    ClusterAuthorizationFailed = 15,
 }
 
 impl Default for FlvErrorCode {
@@ -26,6 +26,7 @@ pub use self::batch::Batch;
 pub use self::batch::BatchRecords;
 pub use self::batch::DefaultBatch;
 pub use self::batch::DefaultBatchRecords;
+pub use self::record::DefaultAsyncBuffer;
 pub use self::record::DefaultRecord;
 pub use self::record::DefaultRecords;
 pub use self::record::Record;
@@ -46,8 +47,6 @@ pub const MAX_BYTES: i32 = 52428800;
 #[macro_export]
 macro_rules! api_decode {
     ($api:ident,$req:ident,$src:expr,$header:expr) => {{
-        use kf_protocol::Decoder;
-        let request = $req::decode_from($src, $header.api_version())?;
-        Ok($api::$req(RequestMessage::new($header, request)))
+        Ok($api::$req($crate::RequestMessage::decode_with_header($src, $header)?))
     }};
 }
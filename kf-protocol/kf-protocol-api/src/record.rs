@@ -276,6 +276,11 @@ where
         self.preamble.offset_delta
     }
 
+    /// timestamp relative to first timestamp of batch
+    pub fn get_timestamp_delta(&self) -> i64 {
+        self.preamble.timestamp_delta
+    }
+
     pub fn get_value(&self) -> &B {
         &self.value
     }
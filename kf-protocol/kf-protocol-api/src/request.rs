@@ -10,6 +10,9 @@ use kf_protocol::bytes::BufMut;
 use kf_protocol::Decoder;
 use kf_protocol::Encoder;
 use kf_protocol::Version;
+use kf_protocol::flexible::encode_empty_tagged_fields;
+use kf_protocol::flexible::skip_tagged_fields;
+use kf_protocol::flexible::uvarint_size;
 
 use crate::Request;
 use crate::RequestHeader;
@@ -79,6 +82,7 @@ where
 
     pub fn new_response(&self, response: R::Response) -> ResponseMessage<R::Response> {
         Self::response_with_header(&self.header, response)
+            .set_flexible_header(R::is_flexible_response_header(self.header.api_version()))
     }
 
     pub fn response_with_header<H>(header: H, response: R::Response) -> ResponseMessage<R::Response>
@@ -96,7 +100,7 @@ where
     where
         T: Buf,
     {
-        ResponseMessage::decode_from(src, version)
+        ResponseMessage::decode_with_header(src, version, R::is_flexible_response_header(version))
     }
 
     pub fn decode_response_from_file<H: AsRef<Path>>(
@@ -107,6 +111,18 @@ where
         ResponseMessage::decode_from_file(file_name, version)
     }
 
+    /// decode request following header, header v2 tagged fields are skipped
+    pub fn decode_with_header<T>(src: &mut T, header: RequestHeader) -> Result<Self, IoError>
+    where
+        T: Buf,
+    {
+        if R::is_flexible(header.api_version()) {
+            skip_tagged_fields(src)?;
+        }
+        let request = R::decode_from(src, header.api_version())?;
+        Ok(Self::new(header, request))
+    }
+
     /// helper function to set client id
     pub fn set_client_id<T>(mut self, client_id: T) -> Self
     where
@@ -126,6 +142,9 @@ where
         T: Buf,
     {
         self.header.decode(src, version)?;
+        if R::is_flexible(self.header.api_version()) {
+            skip_tagged_fields(src)?;
+        }
         self.request.decode(src, self.header.api_version())?;
         Ok(())
     }
@@ -136,7 +155,12 @@ where
     R: Request,
 {
     fn write_size(&self, version: Version) -> usize {
-        self.header.write_size(version) + self.request.write_size(self.header.api_version())
+        let header_size = if R::is_flexible(self.header.api_version()) {
+            self.header.write_size(version) + uvarint_size(0)
+        } else {
+            self.header.write_size(version)
+        };
+        header_size + self.request.write_size(self.header.api_version())
     }
 
     fn encode<T>(&self, out: &mut T, version: Version) -> Result<(), IoError>
@@ -149,6 +173,9 @@ where
 
         trace!("encoding request header: {:#?}", &self.header);
         self.header.encode(out, version)?;
+        if R::is_flexible(self.header.api_version()) {
+            encode_empty_tagged_fields(out)?;
+        }
 
         trace!("encoding request: {:#?}", &self.request);
         self.request.encode(out, self.header.api_version())?;
@@ -331,4 +358,50 @@ mod test {
         }
     }
 
+    #[derive(Decode, Encode, Debug, Default)]
+    pub struct FlexibleRequest {
+        pub value: i8,
+    }
+
+    impl Request for FlexibleRequest {
+        const API_KEY: u16 = AllKfApiKey::ApiVersion as u16;
+        const MAX_API_VERSION: i16 = 1;
+        const MIN_FLEXIBLE_VERSION: i16 = 1;
+
+        type Response = FlexibleRequest;
+    }
+
+    #[test]
+    fn test_flexible_headers() {
+        let mut message = RequestMessage::new_request(FlexibleRequest { value: 7 });
+        message.get_mut_header().set_api_version(1);
+
+        // request header v2 ends with empty tagged fields
+        let mut out = vec![];
+        message.encode(&mut out, 0).expect("encode");
+        assert_eq!(out.len(), message.write_size(0) + 4);
+        assert_eq!(out[out.len() - 2..], [0x00, 0x07]);
+
+        let mut src = Cursor::new(&out);
+        let mut len: i32 = 0;
+        len.decode(&mut src, 0).expect("len");
+        let header = RequestHeader::decode_from(&mut src, 0).expect("header");
+        let request: RequestMessage<FlexibleRequest> =
+            RequestMessage::decode_with_header(&mut src, header).expect("request");
+        assert_eq!(request.request.value, 7);
+
+        // response header v1 has tagged fields after correlation id
+        let response = request.new_response(FlexibleRequest { value: 8 });
+        assert!(response.flexible_header);
+        let mut out = vec![];
+        response.encode(&mut out, 1).expect("encode");
+        assert_eq!(out, vec![0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x08]);
+
+        let decoded = request
+            .decode_response(&mut Cursor::new(&out[4..]), 1)
+            .expect("response");
+        assert_eq!(decoded.correlation_id, 1);
+        assert_eq!(decoded.response.value, 8);
+    }
+
 }
@@ -14,12 +14,17 @@ use kf_protocol::bytes::BufMut;
 use kf_protocol::Decoder;
 use kf_protocol::Encoder;
 use kf_protocol::Version;
+use kf_protocol::flexible::encode_empty_tagged_fields;
+use kf_protocol::flexible::skip_tagged_fields;
+use kf_protocol::flexible::uvarint_size;
 
 use crate::RequestHeader;
 
 #[derive(Debug, Default)]
 pub struct ResponseMessage<P> {
     pub correlation_id: i32,
+    /// response header v1, which has tagged fields after correlation id
+    pub flexible_header: bool,
     pub response: P,
 }
 
@@ -34,10 +39,17 @@ impl<P> ResponseMessage<P> {
      pub fn new(correlation_id: i32, response: P) -> Self {
         Self {
             correlation_id,
+            flexible_header: false,
             response,
         }
     }
 
+    /// use response header of flexible versions
+    pub fn set_flexible_header(mut self, flexible_header: bool) -> Self {
+        self.flexible_header = flexible_header;
+        self
+    }
+
 }
 
 
@@ -48,13 +60,25 @@ impl<P> ResponseMessage<P>
     pub fn decode_from<T>(src: &mut T,version: Version) -> Result<Self,IoError>
         where T:Buf  {
 
+        Self::decode_with_header(src,version,false)
+    }
+
+    /// decode response, header tagged fields are skipped if response header is flexible
+    pub fn decode_with_header<T>(src: &mut T,version: Version,flexible_header: bool) -> Result<Self,IoError>
+        where T:Buf  {
+
         let mut correlation_id: i32 = 0;
         correlation_id.decode(src,version)?;
         trace!("decoded correlation id: {}",correlation_id);
 
+        if flexible_header {
+            skip_tagged_fields(src)?;
+        }
+
         let response = P::decode_from(src, version)?;
         Ok(ResponseMessage {
             correlation_id,
+            flexible_header,
             response
         })
         
@@ -96,7 +120,12 @@ where
     P: Encoder + Default,
 {
     fn write_size(&self,version: Version) -> usize {
-        self.correlation_id.write_size(version) + self.response.write_size(version)
+        let header_size = if self.flexible_header {
+            self.correlation_id.write_size(version) + uvarint_size(0)
+        } else {
+            self.correlation_id.write_size(version)
+        };
+        header_size + self.response.write_size(version)
     }
 
     fn encode<T>(&self, out: &mut T,version: Version) -> Result<(), IoError>
@@ -106,6 +135,9 @@ where
         let len = self.write_size(version) as i32;
         len.encode(out,version)?;
         self.correlation_id.encode(out,version)?;
+        if self.flexible_header {
+            encode_empty_tagged_fields(out)?;
+        }
         self.response.encode(out,version)?;
         Ok(())
     }
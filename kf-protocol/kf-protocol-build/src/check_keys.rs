@@ -37,6 +37,9 @@ fn check_known_field_keys(fields: &Vec<Value>) -> Result<(), IoError> {
         "versions".to_owned(),
         "nullableVersions".to_owned(),
         "entityType".to_owned(),
+        "tag".to_owned(),
+        "taggedVersions".to_owned(),
+        "zeroCopy".to_owned(),
         "fields".to_owned(),
     ];
 
@@ -71,6 +74,8 @@ fn check_known_header_and_field_keys(file_path: &PathBuf) -> Result<(), IoError>
     let known_header_keys = vec![
         "name".to_owned(),
         "validVersions".to_owned(),
+        "flexibleVersions".to_owned(),
+        "commonStructs".to_owned(),
         "type".to_owned(),
         "fields".to_owned(),
         "apiKey".to_owned(),
@@ -85,13 +90,14 @@ fn check_known_header_and_field_keys(file_path: &PathBuf) -> Result<(), IoError>
                         format!("unknown header key: '{}'", key),
                     ));
                 }
-                if key == "fields" {
+                // common structs have the same keys as fields
+                if key == "fields" || key == "commonStructs" {
                     match val.as_array() {
                         Some(field_val) => check_known_field_keys(field_val)?,
                         None => {
                             return Err(IoError::new(
                                 ErrorKind::InvalidData,
-                                "key 'fields' must be array",
+                                format!("key '{}' must be array", key),
                             ));
                         }
                     }
@@ -11,7 +11,7 @@ use textwrap::fill;
 
 use super::spec_msg::NullableVersions;
 use super::spec_msg::SpecMessage;
-use super::spec_msg::{SpecField, SpecFieldType, SpecFields};
+use super::spec_msg::{SpecField, SpecFieldType, SpecFields, SpecStructs};
 
 // -----------------------------------
 // Structures
@@ -27,6 +27,7 @@ pub struct FileContent {
 pub struct Request {
     pub name: String,
     pub annotation: RequestAnnotation,
+    pub flexible_versions: Option<i16>,
     pub fields: Vec<Field>,
     pub structures: Vec<Structure>,
 }
@@ -41,6 +42,7 @@ pub struct RequestAnnotation {
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Response {
     pub name: String,
+    pub flexible_versions: Option<i16>,
     pub fields: Vec<Field>,
     pub structures: Vec<Structure>,
 }
@@ -48,6 +50,7 @@ pub struct Response {
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Structure {
     pub name: String,
+    pub flexible_versions: Option<i16>,
     pub fields: Vec<Field>,
 }
 
@@ -75,6 +78,8 @@ pub struct FieldAnnotation {
     pub ignorable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<String>,
+
+    pub tag: Option<i64>,
 }
 
 // -----------------------------------
@@ -147,12 +152,16 @@ fn build_request(s_msg: &SpecMessage) -> Request {
     let mut structures: Vec<Structure> = vec![];
     let name = make_kf_name!(&s_msg.name).clone();
     let annotation = request_annotation(s_msg);
+    let flexible_versions = flexible_versions(s_msg);
     let fields = build_fields_and_structs(&s_msg.fields, &mut structures);
     structures.reverse();
+    build_common_structs(&s_msg.common_structs, &mut structures);
+    set_flexible_versions(flexible_versions, &mut structures);
 
     Request {
         name,
         annotation,
+        flexible_versions,
         fields,
         structures,
     }
@@ -162,11 +171,15 @@ fn build_request(s_msg: &SpecMessage) -> Request {
 fn build_response(s_msg: &SpecMessage) -> Response {
     let mut structures: Vec<Structure> = vec![];
     let name = make_kf_name!(&s_msg.name).clone();
+    let flexible_versions = flexible_versions(s_msg);
     let fields = build_fields_and_structs(&s_msg.fields, &mut structures);
     structures.reverse();
+    build_common_structs(&s_msg.common_structs, &mut structures);
+    set_flexible_versions(flexible_versions, &mut structures);
 
     Response {
         name,
+        flexible_versions,
         fields,
         structures,
     }
@@ -177,29 +190,35 @@ pub fn build_fields_and_structs(
     maybe_s_fields: &Option<SpecFields>,
     parent_structures: &mut Vec<Structure>,
 ) -> Vec<Field> {
+    match maybe_s_fields {
+        Some(s_fields) => build_fields(s_fields, parent_structures),
+        None => vec![],
+    }
+}
+
+/// Loop through SpecFields and generate fields, structures of sub-fields are added to parent
+fn build_fields(s_fields: &SpecFields, parent_structures: &mut Vec<Structure>) -> Vec<Field> {
     let mut fields: Vec<Field> = vec![];
     let mut collect_structures: Vec<Structure> = vec![];
 
-    if let Some(s_fields) = maybe_s_fields {
-        for s_field in s_fields {
-            // check if field and subtree should be skipped
-            if skip_field(s_field) {
-                continue;
-            }
+    for s_field in s_fields {
+        // check if field and subtree should be skipped
+        if skip_field(s_field) {
+            continue;
+        }
 
-            // generate field
-            fields.push(generate_field(s_field));
+        // generate field
+        fields.push(generate_field(s_field));
 
-            // generate structs (if sub-fields)
-            if s_field.fields.is_some() {
-                let structure = generate_struct(
-                    &s_field.typ.custom_value_name(),
-                    &s_field.fields,
-                    parent_structures,
-                );
+        // generate structs (if sub-fields)
+        if s_field.fields.is_some() {
+            let structure = generate_struct(
+                &s_field.typ.custom_value_name(),
+                &s_field.fields,
+                parent_structures,
+            );
 
-                collect_structures.insert(0, structure);
-            }
+            collect_structures.insert(0, structure);
         }
     }
 
@@ -208,6 +227,24 @@ pub fn build_fields_and_structs(
     fields
 }
 
+/// Generate Structures for common structs, referenced by name from fields
+fn build_common_structs(maybe_s_structs: &Option<SpecStructs>, parent_structures: &mut Vec<Structure>) {
+    if let Some(s_structs) = maybe_s_structs {
+        for s_struct in s_structs {
+            let mut structures: Vec<Structure> = vec![];
+            let fields = build_fields(&s_struct.fields, &mut structures);
+            structures.reverse();
+
+            parent_structures.push(Structure {
+                name: s_struct.name.clone(),
+                flexible_versions: None,
+                fields,
+            });
+            parent_structures.append(&mut structures);
+        }
+    }
+}
+
 /// Generate Structure
 pub fn generate_struct<'a>(
     name: &String,
@@ -216,7 +253,26 @@ pub fn generate_struct<'a>(
 ) -> Structure {
     let name = name.clone();
     let fields = build_fields_and_structs(maybe_s_fields, parent_structures);
-    Structure { name, fields }
+    Structure {
+        name,
+        flexible_versions: None,
+        fields,
+    }
+}
+
+/// First flexible version of message, applies to all its structures
+pub fn flexible_versions(s_msg: &SpecMessage) -> Option<i16> {
+    s_msg
+        .flexible_versions
+        .as_ref()
+        .map(|flexible| flexible.min_ver)
+}
+
+/// Structures inherit flexible versions from message
+fn set_flexible_versions(flexible_versions: Option<i16>, structures: &mut Vec<Structure>) {
+    for structure in structures.iter_mut() {
+        structure.flexible_versions = flexible_versions;
+    }
 }
 
 /// Generate Field
@@ -249,9 +305,13 @@ pub fn request_annotation(req: &SpecMessage) -> RequestAnnotation {
 pub fn field_annotation(field: &SpecField) -> Option<FieldAnnotation> {
     let mut annotation = FieldAnnotation::default();
 
-    // provision versions
-    if !field.versions.is_zero_plus() {
-        let (min_version, max_version) = field.versions.touples();
+    // provision versions - tagged fields are limited to tagged versions
+    let versions = match (&field.tag, &field.tagged_versions) {
+        (Some(_), Some(tagged_versions)) => tagged_versions,
+        _ => &field.versions,
+    };
+    if !versions.is_zero_plus() {
+        let (min_version, max_version) = versions.touples();
         annotation.min_version = Some(min_version);
         annotation.max_version = max_version;
     }
@@ -266,9 +326,15 @@ pub fn field_annotation(field: &SpecField) -> Option<FieldAnnotation> {
         annotation.default = Some(default.value());
     }
 
+    // provision tag
+    if let Some(tag) = field.tag {
+        annotation.tag = Some(tag);
+    }
+
     if annotation.min_version.is_some()
         || annotation.ignorable.is_some()
         || annotation.default.is_some()
+        || annotation.tag.is_some()
     {
         Some(annotation)
     } else {
@@ -291,10 +357,10 @@ pub fn field_name(name: &String, map_key: &Option<bool>, entity_type: &Option<St
     new_name.to_snake_case()
 }
 
-/// Generate field value, if nullableVersion is set and Typpe is String or Array, make it an Option
+/// Generate field value, if nullableVersion is set and Type is String, Array or Structure, make it an Option
 pub fn field_value(field_type: &SpecFieldType, nullable_ver: &Option<NullableVersions>) -> String {
     if nullable_ver.is_some() {
-        if field_type.is_string_or_array() {
+        if field_type.is_nullable() {
             return format!("Option<{}>", field_type.value());
         }
     }
@@ -379,4 +445,71 @@ mod test {
         println!("{:#?}", file_content);
         assert_eq!(file_content, file_content_expected);
     }
+
+    #[test]
+    fn test_flexible_file_content() {
+        let (req_msg, res_msg) = file_to_msgs(
+            "./test-data/flexible/DescribeClusterRequest.json",
+            "./test-data/flexible/DescribeClusterResponse.json",
+        );
+        let file_content = build_file_content(&req_msg, &res_msg);
+
+        // message and all its structures are flexible
+        assert_eq!(file_content.request.flexible_versions, Some(0));
+        assert_eq!(file_content.response.flexible_versions, Some(0));
+        assert_eq!(file_content.response.structures.len(), 1);
+        let broker = &file_content.response.structures[0];
+        assert_eq!(broker.name, "DescribeClusterBroker");
+        assert_eq!(broker.flexible_versions, Some(0));
+
+        let rack = broker.fields.iter().find(|f| f.name == "rack").unwrap();
+        assert_eq!(rack.value, "Option<String>");
+    }
+
+    #[test]
+    fn test_tagged_file_content() {
+        let (req_msg, res_msg) = file_to_msgs(
+            "./test-data/flexible/ApiVersionsRequest.json",
+            "./test-data/flexible/ApiVersionsResponse.json",
+        );
+        let file_content = build_file_content(&req_msg, &res_msg);
+        assert_eq!(file_content.request.flexible_versions, Some(3));
+
+        let features = file_content
+            .response
+            .fields
+            .iter()
+            .find(|f| f.name == "supported_features")
+            .unwrap();
+        assert_eq!(features.value, "Vec<SupportedFeatureKey>");
+        let annotation = features.annotation.as_ref().unwrap();
+        assert_eq!(annotation.tag, Some(0));
+        assert_eq!(annotation.min_version, Some(3));
+
+        let epoch = file_content
+            .response
+            .fields
+            .iter()
+            .find(|f| f.name == "finalized_features_epoch")
+            .unwrap();
+        assert_eq!(epoch.annotation.as_ref().unwrap().tag, Some(1));
+
+        // regular fields are not tagged
+        let error_code = file_content
+            .response
+            .fields
+            .iter()
+            .find(|f| f.name == "error_code")
+            .unwrap();
+        assert!(error_code.annotation.is_none());
+    }
+
+    #[test]
+    fn test_nullable_struct_value() {
+        let typ = SpecFieldType::CUSTOM("LeaderIdAndEpoch".to_owned());
+        assert_eq!(field_value(&typ, &None), "LeaderIdAndEpoch");
+
+        let nullable = NullableVersions::decode("nullableVersions", "0+").unwrap();
+        assert_eq!(field_value(&typ, &Some(nullable)), "Option<LeaderIdAndEpoch>");
+    }
 }
@@ -103,3 +103,46 @@ fn generate_code_from_files(
     // use template and file content to generate code
     template.generate_code(&build_file_content(&req_msg, &res_msg))
 }
+
+// -----------------------------------
+// Test Cases
+// -----------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::fs::read_to_string;
+
+    use inflector::Inflector;
+
+    use super::*;
+
+    const SPEC_DIR: &str = "./input";
+    const CODE_GEN_DIR: &str = "../kf-protocol-message/src/kf_code_gen";
+
+    /// Checked-in messages must match code generated from the checked-in specs
+    #[test]
+    fn test_generated_code_is_up_to_date() {
+        let template = TemplateFormat::new(None).expect("templates");
+        let file_pairs = FilePairs::new(SPEC_DIR).expect("spec file pairs");
+        assert!(!file_pairs.pairs.is_empty());
+
+        let mut stale = vec![];
+        for file_pair in &file_pairs.pairs {
+            let content = generate_code_from_files(file_pair, &template).expect("generate");
+            let code = augment_code(&content, false).expect("format");
+
+            let path = format!("{}/{}.rs", CODE_GEN_DIR, file_pair.filename.to_snake_case());
+            let checked_in = read_to_string(&path).expect("checked-in message");
+            if code != checked_in {
+                stale.push(path);
+            }
+        }
+
+        assert!(
+            stale.is_empty(),
+            "regenerate messages from '{}': {:?}",
+            SPEC_DIR,
+            stale
+        );
+    }
+}
@@ -11,8 +11,9 @@ use serde_json::Value;
 
 use super::spec_msg::SpecMessage;
 use super::spec_msg::SpecMessageType;
-use super::spec_msg::{ApiVersions, DefaultType, NullableVersions, Versions};
+use super::spec_msg::{ApiVersions, DefaultType, FlexibleVersions, NullableVersions, Versions};
 use super::spec_msg::{SpecField, SpecFieldType, SpecFields};
+use super::spec_msg::{SpecStruct, SpecStructs};
 
 /// Convert Json to Request Msg
 pub fn parse_json_to_request(val: Value) -> Result<SpecMessage, Error> {
@@ -49,18 +50,22 @@ fn parse_message(val: &Value, typ: SpecMessageType) -> Result<SpecMessage, Error
     let name = get_name(&val)?;
     let api_key = get_api_key(&val)?;
     let api_versions = get_api_versions(&val)?;
+    let flexible_versions = maybe_get_flexible_versions(&val)?;
     let fields = match maybe_get_fields(&val)? {
         Some(vals) => Some(parse_fields(vals)?),
         None => None,
     };
+    let common_structs = maybe_get_common_structs(&val)?;
 
     // build message
     Ok(SpecMessage {
         name,
         api_key,
         api_versions,
+        flexible_versions,
         typ,
         fields,
+        common_structs,
     })
 }
 
@@ -75,6 +80,17 @@ fn parse_fields<'a>(vals: &'a Vec<Value>) -> Result<SpecFields, Error> {
     Ok(fields)
 }
 
+/// Json object into structure shared by fields of the message
+fn parse_struct(val: &Value) -> Result<SpecStruct, Error> {
+    let name = get_name(&val)?;
+    let fields = match maybe_get_fields(&val)? {
+        Some(vals) => parse_fields(vals)?,
+        None => vec![],
+    };
+
+    Ok(SpecStruct { name, fields })
+}
+
 /// Json object into fields
 fn parse_field(val: &Value) -> Result<SpecField, Error> {
     let name = get_name(&val)?;
@@ -86,6 +102,8 @@ fn parse_field(val: &Value) -> Result<SpecField, Error> {
     let ignorable = maybe_bool(&val, "ignorable")?;
     let map_key = maybe_bool(&val, "mapKey")?;
     let nullable_versions = maybe_get_nullable_versions(&val)?;
+    let tag = maybe_i64(&val, "tag")?;
+    let tagged_versions = maybe_get_tagged_versions(&val)?;
     let fields = match maybe_get_fields(&val)? {
         Some(vals) => Some(parse_fields(vals)?),
         None => None,
@@ -101,6 +119,8 @@ fn parse_field(val: &Value) -> Result<SpecField, Error> {
         entity_type,
         ignorable,
         map_key,
+        tag,
+        tagged_versions,
         fields,
     })
 }
@@ -155,6 +175,20 @@ fn get_i64(val: &Value, key: &str) -> Result<i64, Error> {
     }
 }
 
+/// Decode 'i64' if avialable at key or error
+fn maybe_i64(val: &Value, key: &str) -> Result<Option<i64>, Error> {
+    match get_key(&val, key) {
+        Ok(v) => match v.as_i64() {
+            Some(v) => Ok(Some(v)),
+            None => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', not i64 number", key),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
 #[allow(dead_code)]
 /// Decode 'bool' at key or error
 fn get_bool(val: &Value, key: &str) -> Result<bool, Error> {
@@ -260,6 +294,57 @@ fn maybe_get_nullable_versions(val: &Value) -> Result<Option<NullableVersions>,
     }
 }
 
+/// Decode 'flexibleVersions' if avaialble
+fn maybe_get_flexible_versions(val: &Value) -> Result<Option<FlexibleVersions>, Error> {
+    let key = "flexibleVersions";
+    match get_key(&val, key) {
+        Ok(v_raw) => match v_raw.as_str() {
+            Some(v) => FlexibleVersions::decode(key, v),
+            None => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', not version string", key),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
+/// Decode 'taggedVersions' if avaialble
+fn maybe_get_tagged_versions(val: &Value) -> Result<Option<Versions>, Error> {
+    let key = "taggedVersions";
+    match get_key(&val, key) {
+        Ok(v_raw) => match v_raw.as_str() {
+            Some(v) => Ok(Some(Versions::decode(key, v)?)),
+            None => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', not version string", key),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
+/// Decode 'commonStructs' if avaialble
+fn maybe_get_common_structs(val: &Value) -> Result<Option<SpecStructs>, Error> {
+    let key = "commonStructs";
+    match get_key(&val, key) {
+        Ok(v_raw) => match v_raw.as_array() {
+            Some(vals) => {
+                let mut structs: SpecStructs = vec![];
+                for val in vals {
+                    structs.push(parse_struct(val)?);
+                }
+                Ok(Some(structs))
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', not a array", key),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
 /// Decode 'fileds' is avaialble
 fn maybe_get_fields(val: &Value) -> Result<Option<&Vec<Value>>, Error> {
     match get_key(&val, "fields") {
@@ -427,6 +512,71 @@ mod test {
             typ.unwrap(),
             SpecFieldType::CUSTOM_ARRAY("CreatableTopic".to_owned())
         );
+
+        // Custom Structure
+        let val = serde_json::from_str("{\"type\": \"LeaderIdAndEpoch\"}").unwrap();
+        let typ = get_field_type(&val);
+        assert!(typ.is_ok());
+        assert_eq!(
+            typ.unwrap(),
+            SpecFieldType::CUSTOM("LeaderIdAndEpoch".to_owned())
+        );
+
+        // Unknown primitive
+        let val = serde_json::from_str("{\"type\": \"int128\"}").unwrap();
+        assert!(get_field_type(&val).is_err());
+    }
+
+    #[test]
+    fn test_parse_common_structs() {
+        // not available
+        let val = serde_json::from_str("{\"fields\": []}").unwrap();
+        assert_eq!(maybe_get_common_structs(&val).unwrap(), None);
+
+        let val = serde_json::from_str(
+            "{\"commonStructs\": [{\"name\": \"ReplicaState\", \"versions\": \"0+\", \"fields\": [
+                {\"name\": \"ReplicaId\", \"type\": \"int32\", \"versions\": \"0+\"}
+            ]}]}",
+        )
+        .unwrap();
+        let structs = maybe_get_common_structs(&val).unwrap().unwrap();
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "ReplicaState");
+        assert_eq!(structs[0].fields.len(), 1);
+        assert_eq!(structs[0].fields[0].name, "ReplicaId");
+    }
+
+    #[test]
+    fn test_parse_flexible_versions() {
+        // not available
+        let val = serde_json::from_str("{\"notFlexible\": \"3+\"}").unwrap();
+        assert_eq!(maybe_get_flexible_versions(&val).unwrap(), None);
+
+        // none
+        let val = serde_json::from_str("{\"flexibleVersions\": \"none\"}").unwrap();
+        assert_eq!(maybe_get_flexible_versions(&val).unwrap(), None);
+
+        // greater or equal
+        let val = serde_json::from_str("{\"flexibleVersions\": \"3+\"}").unwrap();
+        assert_eq!(
+            maybe_get_flexible_versions(&val).unwrap(),
+            Some(FlexibleVersions { min_ver: 3 })
+        );
+
+        // range is invalid
+        let val = serde_json::from_str("{\"flexibleVersions\": \"1-3\"}").unwrap();
+        assert!(maybe_get_flexible_versions(&val).is_err());
+    }
+
+    #[test]
+    fn test_parse_tagged_field() {
+        let val = serde_json::from_str(
+            "{\"name\": \"ClusterId\", \"type\": \"string\", \"versions\": \"3+\", \"tag\": 0, \"taggedVersions\": \"3+\"}",
+        )
+        .unwrap();
+        let field = parse_field(&val).unwrap();
+        assert_eq!(field.tag, Some(0));
+        assert_eq!(field.tagged_versions, Some(Versions::GreaterOrEqualTo(3)));
     }
 
     #[test]
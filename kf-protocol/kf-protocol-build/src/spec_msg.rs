@@ -14,8 +14,10 @@ pub struct SpecMessage {
     pub name: String,
     pub api_key: i64,
     pub api_versions: ApiVersions,
+    pub flexible_versions: Option<FlexibleVersions>,
     pub typ: SpecMessageType,
     pub fields: Option<SpecFields>,
+    pub common_structs: Option<SpecStructs>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -29,10 +31,19 @@ pub struct SpecField {
     pub entity_type: Option<String>,
     pub ignorable: Option<bool>,
     pub map_key: Option<bool>,
+    pub tag: Option<i64>,
+    pub tagged_versions: Option<Versions>,
     pub fields: Option<SpecFields>,
 }
 pub type SpecFields = Vec<SpecField>;
 
+#[derive(Debug, PartialEq)]
+pub struct SpecStruct {
+    pub name: String,
+    pub fields: SpecFields,
+}
+pub type SpecStructs = Vec<SpecStruct>;
+
 #[derive(Debug, PartialEq)]
 pub enum SpecMessageType {
     Request,
@@ -46,6 +57,7 @@ pub type StructName = String;
 pub enum SpecFieldType {
     PRIMITIVE(PType),
     PRIMITIVE_ARRAY(PType),
+    CUSTOM(StructName),
     CUSTOM_ARRAY(StructName),
 }
 
@@ -84,6 +96,11 @@ pub struct NullableVersions {
     min_ver: i16,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct FlexibleVersions {
+    pub min_ver: i16,
+}
+
 // -----------------------------------
 // Implement - SpecMessageType
 // -----------------------------------
@@ -151,11 +168,25 @@ impl PType {
 // -----------------------------------
 
 impl SpecFieldType {
-    /// Decode field type, primitives, primitive arrays, and custom arrays
+    /// Decode field type, primitives, primitive arrays, custom structures and custom arrays
     pub fn decode(key: &str, val: &str) -> Result<SpecFieldType, Error> {
         match PType::new(val) {
             Ok(primitive) => Ok(SpecFieldType::PRIMITIVE(primitive)),
-            Err(_) => SpecFieldType::decode_array(key, val),
+            Err(_) if val.starts_with("[]") => SpecFieldType::decode_array(key, val),
+            Err(_) => SpecFieldType::decode_custom(key, val),
+        }
+    }
+
+    /// Decode custom structure, struct names are capitalized
+    pub fn decode_custom(key: &str, val: &str) -> Result<SpecFieldType, Error> {
+        match val.chars().next() {
+            Some(first) if first.is_ascii_uppercase() => {
+                Ok(SpecFieldType::CUSTOM(val.to_string()))
+            }
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', incorrect type '{}'", key, val),
+            )),
         }
     }
 
@@ -185,8 +216,8 @@ impl SpecFieldType {
         }
     }
 
-    /// True if primitive String or Array
-    pub fn is_string_or_array(&self) -> bool {
+    /// True if type can be null: primitive String, Array or Structure
+    pub fn is_nullable(&self) -> bool {
         match self {
             SpecFieldType::PRIMITIVE(p_type) => match p_type {
                 PType::BYTES => true, //array of u8
@@ -195,6 +226,7 @@ impl SpecFieldType {
             },
 
             SpecFieldType::PRIMITIVE_ARRAY(_) => true,
+            SpecFieldType::CUSTOM(_) => true,
             SpecFieldType::CUSTOM_ARRAY(_) => true,
         }
     }
@@ -204,6 +236,7 @@ impl SpecFieldType {
         match self {
             SpecFieldType::PRIMITIVE(p_type) => p_type.from().to_owned(),
             SpecFieldType::PRIMITIVE_ARRAY(p_type) => format!("Vec<{}>", p_type.from().to_owned()),
+            SpecFieldType::CUSTOM(struct_name) => struct_name.clone(),
             SpecFieldType::CUSTOM_ARRAY(struct_name) => format!("Vec<{}>", struct_name),
         }
     }
@@ -211,6 +244,7 @@ impl SpecFieldType {
     /// Return value name
     pub fn custom_value_name(&self) -> String {
         match self {
+            SpecFieldType::CUSTOM(struct_name) => struct_name.clone(),
             SpecFieldType::CUSTOM_ARRAY(struct_name) => struct_name.clone(),
             _ => "unknown".to_owned(),
         }
@@ -362,3 +396,24 @@ impl NullableVersions {
         }
     }
 }
+
+// -----------------------------------
+// Implement - FlexibleVersions
+// -----------------------------------
+
+impl FlexibleVersions {
+    /// Decode flexible versions, "none" if message has no flexible versions
+    pub fn decode(key: &str, val: &str) -> Result<Option<FlexibleVersions>, Error> {
+        if val == "none" {
+            return Ok(None);
+        }
+
+        match Versions::decode(key, val)? {
+            Versions::GreaterOrEqualTo(min_ver) => Ok(Some(FlexibleVersions { min_ver })),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("key '{}', incorrect flexibleVersions", key),
+            )),
+        }
+    }
+}
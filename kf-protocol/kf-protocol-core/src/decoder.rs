@@ -11,6 +11,7 @@ use log::trace;
 
 use crate::Version;
 use super::varint::varint_decode;
+use super::flexible::decode_compact_len;
 
 // trait for encoding and decoding using Kafka Protocol
 pub trait Decoder: Sized + Default {
@@ -27,6 +28,11 @@ pub trait Decoder: Sized + Default {
 
 
     fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error> where T: Buf;
+
+    /// decode using flexible version format, where strings, bytes and arrays have compact length
+    fn decode_compact<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error> where T: Buf {
+        self.decode(src,version)
+    }
 }
 
 pub trait DecoderVarInt {
@@ -56,6 +62,37 @@ where
 
         Ok(())
     }
+
+    default fn decode_compact<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if let Some(len) = decode_compact_len(src)? {
+            trace!("decoding compact Vec len:{}", len);
+            decode_compact_vec(len,self,src,version)?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_compact_vec<T,M>(len: usize,item: &mut Vec<M>,src: &mut T, version: Version) -> Result<(),Error>
+    where T:Buf, M:Default + Decoder {
+
+    // every compact element takes at least one byte, reject bogus length before looping
+    if src.remaining() < len {
+        return Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            format!("compact Vec, expecting {} elements but only {} bytes left", len, src.remaining()),
+        ));
+    }
+
+    for _ in 0..len {
+        let mut value = <M>::default();
+        value.decode_compact(src,version)?;
+        item.push(value);
+    }
+
+    Ok(())
 }
 
 fn decode_vec<T,M>(len: i32,item: &mut Vec<M>,src: &mut T, version: Version) -> Result<(),Error> 
@@ -97,6 +134,21 @@ impl<M> Decoder for Option<Vec<M>>
         Ok(())
     }
 
+    fn decode_compact<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        match decode_compact_len(src)? {
+            Some(len) => {
+                let mut item: Vec<M> = vec![];
+                decode_compact_vec(len,&mut item,src,version)?;
+                *self = Some(item);
+            },
+            None => *self = None
+        }
+        Ok(())
+    }
+
 
 }
 
@@ -119,6 +171,22 @@ where
         }
         Ok(())
     }
+
+    default fn decode_compact<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut some = false;
+        some.decode(src,version)?;
+        if some {
+            let mut value = <M>::default();
+            value.decode_compact(src,version)?;
+            *self = Some(value)
+        } else {
+            *self = None
+        }
+        Ok(())
+    }
 }
 
 
@@ -350,17 +418,28 @@ impl Decoder for Option<String> {
             *self = Some(String::default());
         }
 
-        let value = decode_string(len,src)?;
+        let value = decode_string(len as usize,src)?;
         *self = Some(value);
         Ok(())
     }
+
+    fn decode_compact<T>(&mut self, src: &mut T,_version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        match decode_compact_len(src)? {
+            Some(len) => *self = Some(decode_string(len,src)?),
+            None => *self = None
+        }
+        Ok(())
+    }
 }
 
-fn decode_string<T>(len: i16,src: &mut T) -> Result<String,Error>  where T:Buf{
+fn decode_string<T>(len: usize,src: &mut T) -> Result<String,Error>  where T:Buf{
     let mut value = String::default();
-    let read_size = src.take(len as usize).reader().read_to_string(&mut value)?;
+    let read_size = src.take(len).reader().read_to_string(&mut value)?;
 
-    if read_size != len as usize {
+    if read_size != len {
         return Err(Error::new(ErrorKind::UnexpectedEof, "not enough string"));
     }
     Ok(value)
@@ -382,10 +461,20 @@ impl Decoder for String {
             return Ok(());
         }
 
-        let value = decode_string(len,src)?;
+        let value = decode_string(len as usize,src)?;
         *self = value;
         Ok(())
     }
+
+    fn decode_compact<T>(&mut self, src: &mut T,_version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if let Some(len) = decode_compact_len(src)? {
+            *self = decode_string(len,src)?;
+        }
+        Ok(())
+    }
 }
 
 
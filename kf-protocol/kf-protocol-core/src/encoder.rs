@@ -14,6 +14,8 @@ use crate::Version;
 
 use super::varint::variant_encode;
 use super::varint::variant_size;
+use super::flexible::compact_len_size;
+use super::flexible::encode_compact_len;
 
 
 // trait for encoding and decoding using Kafka Protocol
@@ -26,6 +28,16 @@ pub trait Encoder  {
     /// encoding contents for buffer
     fn encode<T>(&self, dest: &mut T,version: Version) -> Result<(), Error> where T: BufMut;
 
+    /// size using flexible version format
+    fn compact_write_size(&self,version: Version) -> usize {
+        self.write_size(version)
+    }
+
+    /// encoding using flexible version format, where strings, bytes and arrays have compact length
+    fn encode_compact<T>(&self, dest: &mut T,version: Version) -> Result<(), Error> where T: BufMut {
+        self.encode(dest,version)
+    }
+
     fn as_bytes(&self, version: Version) -> Result<Bytes,Error>  {
         trace!("encoding as bytes");
         let mut out = vec![];
@@ -71,6 +83,23 @@ impl<M> Encoder for Vec<M> where M:  Encoder,
 
         Ok(())
     }
+
+    fn compact_write_size(&self,version: Version) -> usize {
+        self.iter().fold(compact_len_size(Some(self.len())), |sum, val| sum + val.compact_write_size(version) )
+    }
+
+    fn encode_compact<T>(&self, dest: &mut T,version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        encode_compact_len(dest,Some(self.len()))?;
+
+        for v in self {
+            v.encode_compact(dest,version)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<M> Encoder for Option<M> where M: Encoder  {
@@ -95,6 +124,27 @@ impl<M> Encoder for Option<M> where M: Encoder  {
             None => false.encode(dest,version)
         }
      }
+
+    default fn compact_write_size(&self,version: Version) -> usize {
+
+         match *self  {
+            Some(ref value) =>  {
+                true.write_size(version) + value.compact_write_size(version)
+            },
+            None => false.write_size(version)
+        }
+     }
+
+    default fn encode_compact<T>(&self, dest: &mut T,version: Version) -> Result<(), Error> where  T: BufMut {
+
+        match *self  {
+            Some(ref value) =>  {
+                true.encode(dest,version)?;
+                value.encode_compact(dest,version)
+            },
+            None => false.encode(dest,version)
+        }
+     }
 }
 
 
@@ -135,6 +185,23 @@ impl<M> Encoder for Option<Vec<M>> where M: Encoder,
         let inner = self.as_ref().unwrap();
         inner.encode(dest,version)
     }
+
+    fn compact_write_size(&self,version: Version) -> usize {
+        match self {
+            Some(inner) => inner.compact_write_size(version),
+            None => compact_len_size(None)
+        }
+    }
+
+    fn encode_compact<T>(&self, dest: &mut T,version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        match self {
+            Some(inner) => inner.encode_compact(dest,version),
+            None => encode_compact_len(dest,None)
+        }
+    }
 }
 
 
@@ -428,6 +495,23 @@ impl Encoder for Option<String> {
 
         str_value.encode(dest,version)
     }
+
+    fn compact_write_size(&self,version: Version) -> usize {
+        match self {
+            Some(value) => value.compact_write_size(version),
+            None => compact_len_size(None)
+        }
+    }
+
+    fn encode_compact<T>(&self, dest: &mut T,version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        match self {
+            Some(value) => value.encode_compact(dest,version),
+            None => encode_compact_len(dest,None)
+        }
+    }
 }
 
 impl Encoder for String {
@@ -467,6 +551,27 @@ impl Encoder for String {
 
         Ok(())
     }
+
+    fn compact_write_size(&self,_version: Version) -> usize {
+        compact_len_size(Some(self.len())) + self.len()
+    }
+
+    fn encode_compact<T>(&self, dest: &mut T,_version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        encode_compact_len(dest,Some(self.len()))?;
+
+        if dest.remaining_mut() < self.len() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for string",
+            ));
+        }
+
+        dest.put_slice(self.as_bytes());
+        Ok(())
+    }
 }
 
 impl EncoderVarInt for Option<Vec<u8>> {
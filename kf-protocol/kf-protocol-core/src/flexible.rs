@@ -0,0 +1,303 @@
+//!
+//! # Flexible Versions
+//!
+//! Flexible versions encode lengths of strings, bytes and arrays as unsigned varint (compact encoding)
+//! and end each structure with tagged fields section. Tagged field is written as tag, size and value,
+//! so receiver can skip tags it doesn't know.
+//!
+use std::io::Error;
+use std::io::ErrorKind;
+
+use bytes::Buf;
+use bytes::BufMut;
+use log::trace;
+
+use crate::Decoder;
+use crate::Encoder;
+use crate::Version;
+
+/// decode unsigned varint, at most 5 bytes for u32
+pub fn decode_uvarint<T>(src: &mut T) -> Result<u32, Error>
+where
+    T: Buf,
+{
+    let mut num: u32 = 0;
+    let mut shift: u32 = 0;
+
+    loop {
+        if src.remaining() == 0 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "no more bytes left for uvarint"));
+        }
+        if shift > 28 {
+            return Err(Error::new(ErrorKind::InvalidData, "uvarint is too long"));
+        }
+
+        let b = src.get_u8();
+        num |= u32::from(b & 0x7f) << shift;
+        shift += 7;
+
+        if b & 0x80 == 0 {
+            break;
+        }
+    }
+
+    Ok(num)
+}
+
+pub fn encode_uvarint<T>(dest: &mut T, num: u32) -> Result<(), Error>
+where
+    T: BufMut,
+{
+    let mut v = num;
+    while v >= 0x80 {
+        if dest.remaining_mut() == 0 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "no more bytes left for uvarint"));
+        }
+        dest.put_u8((v as u8 & 0x7f) | 0x80);
+        v >>= 7;
+    }
+    if dest.remaining_mut() == 0 {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "no more bytes left for uvarint"));
+    }
+    dest.put_u8(v as u8);
+    Ok(())
+}
+
+pub fn uvarint_size(num: u32) -> usize {
+    let mut v = num;
+    let mut bytes = 1;
+    while v >= 0x80 {
+        bytes += 1;
+        v >>= 7;
+    }
+    bytes
+}
+
+/// decode compact length, 0 is null, otherwise length + 1
+pub fn decode_compact_len<T>(src: &mut T) -> Result<Option<usize>, Error>
+where
+    T: Buf,
+{
+    let len = decode_uvarint(src)?;
+    if len == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(len as usize - 1))
+    }
+}
+
+pub fn encode_compact_len<T>(dest: &mut T, len: Option<usize>) -> Result<(), Error>
+where
+    T: BufMut,
+{
+    match len {
+        Some(len) => encode_uvarint(dest, len as u32 + 1),
+        None => encode_uvarint(dest, 0),
+    }
+}
+
+pub fn compact_len_size(len: Option<usize>) -> usize {
+    match len {
+        Some(len) => uvarint_size(len as u32 + 1),
+        None => uvarint_size(0),
+    }
+}
+
+/// write tagged field with compact encoding of value
+pub fn encode_tagged_field<T, E>(dest: &mut T, tag: u32, value: &E, version: Version) -> Result<(), Error>
+where
+    T: BufMut,
+    E: Encoder,
+{
+    encode_uvarint(dest, tag)?;
+    encode_uvarint(dest, value.compact_write_size(version) as u32)?;
+    value.encode_compact(dest, version)
+}
+
+pub fn tagged_field_size<E>(tag: u32, value: &E, version: Version) -> usize
+where
+    E: Encoder,
+{
+    let size = value.compact_write_size(version);
+    uvarint_size(tag) + uvarint_size(size as u32) + size
+}
+
+/// read value of tagged field, value can't read past size of the field
+pub fn decode_tagged_field<T, D>(src: &mut T, size: usize, value: &mut D, version: Version) -> Result<(), Error>
+where
+    T: Buf,
+    D: Decoder,
+{
+    if src.remaining() < size {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "not enough bytes for tagged field"));
+    }
+
+    let mut field = src.take(size);
+    value.decode_compact(&mut field, version)?;
+    // newer version of field may have more data
+    let unread = field.remaining();
+    field.advance(unread);
+    Ok(())
+}
+
+pub fn skip_tagged_field<T>(src: &mut T, tag: u32, size: usize) -> Result<(), Error>
+where
+    T: Buf,
+{
+    if src.remaining() < size {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "not enough bytes for tagged field"));
+    }
+    trace!("skipping unknown tagged field: {}, size: {}", tag, size);
+    src.advance(size);
+    Ok(())
+}
+
+/// skip tagged fields section, used where no tagged fields are known such as request header v2
+pub fn skip_tagged_fields<T>(src: &mut T) -> Result<(), Error>
+where
+    T: Buf,
+{
+    let count = decode_uvarint(src)?;
+    for _ in 0..count {
+        let tag = decode_uvarint(src)?;
+        let size = decode_uvarint(src)? as usize;
+        skip_tagged_field(src, tag, size)?;
+    }
+    Ok(())
+}
+
+/// write tagged fields section without any field
+pub fn encode_empty_tagged_fields<T>(dest: &mut T) -> Result<(), Error>
+where
+    T: BufMut,
+{
+    encode_uvarint(dest, 0)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::io::Cursor;
+
+    use crate::Decoder;
+    use crate::Encoder;
+
+    use super::decode_uvarint;
+    use super::encode_uvarint;
+    use super::uvarint_size;
+    use super::decode_tagged_field;
+    use super::encode_tagged_field;
+    use super::skip_tagged_fields;
+    use super::tagged_field_size;
+
+    #[test]
+    fn test_uvarint_with_test_set() {
+        let test_set = vec![
+            (0, vec![0x00]),
+            (1, vec![0x01]),
+            (127, vec![0x7f]),
+            (128, vec![0x80, 0x01]),
+            (300, vec![0xac, 0x02]),
+            (16384, vec![0x80, 0x80, 0x01]),
+            (std::u32::MAX, vec![0xff, 0xff, 0xff, 0xff, 0x0f]),
+        ];
+
+        for (num, bytes) in test_set {
+            let mut dest = vec![];
+            encode_uvarint(&mut dest, num).expect("encode");
+            assert_eq!(dest, bytes);
+            assert_eq!(uvarint_size(num), bytes.len());
+
+            let value = decode_uvarint(&mut Cursor::new(&bytes)).expect("decode");
+            assert_eq!(value, num);
+        }
+    }
+
+    #[test]
+    fn test_uvarint_too_long() {
+        let data = vec![0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        assert!(decode_uvarint(&mut Cursor::new(&data)).is_err());
+    }
+
+    #[test]
+    fn test_compact_string() {
+        let value = "hello".to_owned();
+        let mut dest = vec![];
+        value.encode_compact(&mut dest, 0).expect("encode");
+        assert_eq!(dest.len(), value.compact_write_size(0));
+        assert_eq!(dest[0], 6);
+
+        let mut decoded = String::default();
+        decoded.decode_compact(&mut Cursor::new(&dest), 0).expect("decode");
+        assert_eq!(decoded, "hello");
+
+        let none: Option<String> = None;
+        let mut dest = vec![];
+        none.encode_compact(&mut dest, 0).expect("encode");
+        assert_eq!(dest, vec![0]);
+
+        let mut decoded: Option<String> = Some("x".to_owned());
+        decoded.decode_compact(&mut Cursor::new(&dest), 0).expect("decode");
+        assert!(decoded.is_none());
+    }
+
+    #[test]
+    fn test_compact_array() {
+        let values = vec!["a".to_owned(), "bc".to_owned()];
+        let mut dest = vec![];
+        values.encode_compact(&mut dest, 0).expect("encode");
+        assert_eq!(dest, vec![3, 2, 0x61, 3, 0x62, 0x63]);
+        assert_eq!(dest.len(), values.compact_write_size(0));
+
+        let mut decoded: Vec<String> = vec![];
+        decoded.decode_compact(&mut Cursor::new(&dest), 0).expect("decode");
+        assert_eq!(decoded, values);
+
+        let null: Option<Vec<i32>> = None;
+        let mut dest = vec![];
+        null.encode_compact(&mut dest, 0).expect("encode");
+        assert_eq!(dest, vec![0]);
+
+        let mut decoded: Option<Vec<i32>> = Some(vec![]);
+        decoded.decode_compact(&mut Cursor::new(&dest), 0).expect("decode");
+        assert!(decoded.is_none());
+    }
+
+    #[test]
+    fn test_compact_array_len_exceeds_remaining() {
+        // length of u32::MAX - 1 with only 2 bytes left
+        let dest = vec![0xff, 0xff, 0xff, 0xff, 0x0f, 1, 2];
+
+        let mut decoded: Vec<i32> = vec![];
+        assert!(decoded.decode_compact(&mut Cursor::new(&dest), 0).is_err());
+        assert!(decoded.is_empty());
+
+        let mut decoded: Option<Vec<u8>> = None;
+        assert!(decoded.decode_compact(&mut Cursor::new(&dest), 0).is_err());
+    }
+
+    #[test]
+    fn test_tagged_fields() {
+        let value = "tag".to_owned();
+        let mut dest = vec![];
+        encode_uvarint(&mut dest, 2).expect("count");
+        encode_tagged_field(&mut dest, 0, &value, 0).expect("tag 0");
+        // unknown field is skipped
+        encode_uvarint(&mut dest, 5).expect("tag");
+        encode_uvarint(&mut dest, 2).expect("size");
+        dest.extend_from_slice(&[0xff, 0xff]);
+        assert_eq!(dest.len(), 1 + tagged_field_size(0, &value, 0) + 4);
+
+        let mut src = Cursor::new(&dest);
+        assert_eq!(decode_uvarint(&mut src).expect("count"), 2);
+        assert_eq!(decode_uvarint(&mut src).expect("tag"), 0);
+        let size = decode_uvarint(&mut src).expect("size") as usize;
+        let mut decoded = String::default();
+        decode_tagged_field(&mut src, size, &mut decoded, 0).expect("decode");
+        assert_eq!(decoded, "tag");
+
+        let mut src = Cursor::new(&dest);
+        skip_tagged_fields(&mut src).expect("skip");
+        assert_eq!(src.position() as usize, dest.len());
+    }
+}
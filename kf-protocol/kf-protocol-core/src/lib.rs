@@ -4,6 +4,7 @@ mod decoder;
 mod encoder;
 mod varint;
 mod zerocopy;
+pub mod flexible;
 // mod buffer;
 
 pub use self::decoder::Decoder;
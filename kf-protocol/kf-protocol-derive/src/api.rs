@@ -120,7 +120,7 @@ fn generate_encoder(data: &DataStruct,name: &Ident) -> TokenStream {
 
 
 
-fn generate_request_trait_impl(name: &Ident,attrs: &Vec<Attribute>) -> TokenStream {
+fn generate_request_trait_impl(name: &Ident,attrs: &[Attribute]) -> TokenStream {
 
     // check if we have api version
     let version_meta = if let Some(version) = find_attr(attrs, "fluvio_kf") { 
@@ -165,6 +165,15 @@ fn generate_request_trait_impl(name: &Ident,attrs: &Vec<Attribute>) -> TokenStre
     };
 
 
+    let flexible_version = if let Some(flexible_version) = find_int_name_value(&version_meta,"flexible_versions") {
+        quote! {
+            const MIN_FLEXIBLE_VERSION: i16 = #flexible_version as i16;
+        }
+    } else {
+        quote!{}
+    };
+
+
     quote! {
 
         impl Request for #name {
@@ -175,6 +184,8 @@ fn generate_request_trait_impl(name: &Ident,attrs: &Vec<Attribute>) -> TokenStre
            
             #max_version
 
+            #flexible_version
+
             type Response = #response_type;
 
         } 
@@ -17,6 +17,9 @@ use syn::UnOp;
 
 use crate::default_int_type;
 use super::version::Version;
+use super::flexible::find_flexible_version;
+use super::flexible::find_tag;
+use super::flexible::decode_tagged_fields;
 use super::util::find_attr;
 use super::util::find_string_name_value;
 
@@ -27,7 +30,7 @@ pub fn generate_decode_traits(input: &DeriveInput) -> TokenStream {
 
     let int_type = default_int_type(&input.attrs);
 
-    let decoded_field_tokens = decode_fields(&input.data,&int_type,name);
+    let decoded_field_tokens = decode_fields(&input.data,&input.attrs,&int_type,name);
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -50,20 +53,23 @@ pub fn generate_decode_traits(input: &DeriveInput) -> TokenStream {
 }
 
 /// generate syntax for decoding
-fn decode_fields(data: &Data,int_type: &Ident,name: &Ident) -> TokenStream {
+fn decode_fields(data: &Data,attrs: &[Attribute],int_type: &Ident,name: &Ident) -> TokenStream {
     match *data {
-        Data::Struct(ref data) => parse_struct(name,data),
+        Data::Struct(ref data) => parse_struct(name,data,attrs),
         Data::Enum(ref enum_data) => parse_enum(enum_data,int_type,name),
          _ => unimplemented!()
     }
 }
 
 
-fn parse_struct(struct_name: &Ident,data: &DataStruct) -> TokenStream {
+fn parse_struct(struct_name: &Ident,data: &DataStruct,attrs: &[Attribute]) -> TokenStream {
+
+    let flexible = find_flexible_version(attrs);
    
     match data.fields {
         Fields::Named(ref fields) => {
-            let recurse = fields.named.iter().map(|f| {
+            // tagged fields are read from their own section
+            let recurse = fields.named.iter().filter(|f| find_tag(&f.attrs).is_none()).map(|f| {
                 let fname = &f.ident;
                 if f.attrs
                     .iter()
@@ -85,10 +91,21 @@ fn parse_struct(struct_name: &Ident,data: &DataStruct) -> TokenStream {
                     }
                 } else {
 
+                    let decode = match flexible {
+                        Some(flexible) => quote! {
+                            if version >= #flexible {
+                                self.#fname.decode_compact(src,version)
+                            } else {
+                                self.#fname.decode(src,version)
+                            }
+                        },
+                        None => quote! { self.#fname.decode(src,version) }
+                    };
+
                     let base = quote! {
             
                         log::trace!("start decoding struct: <{}> field: <{}>",stringify!(#struct_name),stringify!(#fname));
-                        let result = self.#fname.decode(src,version);
+                        let result = #decode;
                         if result.is_ok() {
                             log::trace!("decoding struct: <{}> field: <{}> => {:#?}",stringify!(#struct_name),stringify!(#fname),&self.#fname);
                         } else {
@@ -109,8 +126,11 @@ fn parse_struct(struct_name: &Ident,data: &DataStruct) -> TokenStream {
                 }
             });
 
+            let tagged = decode_tagged_fields(struct_name,fields,flexible);
+
             quote! {
                 #(#recurse)*
+                #tagged
             }
         },
          _ => unimplemented!()
@@ -0,0 +1,186 @@
+use quote::quote;
+use proc_macro2::TokenStream;
+use syn::Attribute;
+use syn::Field;
+use syn::Ident;
+use syn::FieldsNamed;
+use syn::spanned::Spanned;
+
+use super::version::Version;
+use super::util::find_attr;
+use super::util::find_int_name_value;
+
+/// first flexible version of structure, set by `#[fluvio_kf(flexible_versions = <version>)]`
+pub(crate) fn find_flexible_version(attrs: &[Attribute]) -> Option<i16> {
+    find_attr(attrs, "fluvio_kf")
+        .and_then(|meta| find_int_name_value(&meta, "flexible_versions"))
+        .map(|version| version as i16)
+}
+
+/// tag of tagged field, set by `#[fluvio_kf(tag = <tag>)]`
+pub(crate) fn find_tag(attrs: &[Attribute]) -> Option<u32> {
+    find_attr(attrs, "fluvio_kf")
+        .and_then(|meta| find_int_name_value(&meta, "tag"))
+        .map(|tag| tag as u32)
+}
+
+fn tagged_fields(fields: &FieldsNamed) -> Vec<(&Field, u32)> {
+    fields
+        .named
+        .iter()
+        .filter_map(|f| find_tag(&f.attrs).map(|tag| (f, tag)))
+        .collect()
+}
+
+/// tagged field is written only if version is in its range
+fn field_condition(f: &Field) -> TokenStream {
+    match Version::find_version(&f.attrs) {
+        Some(version) => version.condition(),
+        None => quote! { true },
+    }
+}
+
+/// tagged fields are only allowed in structure with flexible versions
+fn validate(fields: &FieldsNamed, flexible: Option<i16>) -> Result<Option<i16>, TokenStream> {
+    if flexible.is_none() {
+        if let Some((f, _)) = tagged_fields(fields).first() {
+            return Err(syn::Error::new(f.span(), "tagged field requires flexible_versions").to_compile_error());
+        }
+    }
+    Ok(flexible)
+}
+
+/// count tagged fields to be written in this version
+fn count_tagged_fields(tagged: &[(&Field, u32)]) -> TokenStream {
+    let count = tagged.iter().map(|(f, _)| {
+        let condition = field_condition(f);
+        quote! {
+            if #condition {
+                tagged_fields += 1;
+            }
+        }
+    });
+
+    quote! {
+        let mut tagged_fields: u32 = 0;
+        #(#count)*
+    }
+}
+
+/// generate tagged fields section for encoding, written after regular fields
+pub(crate) fn encode_tagged_fields(struct_name: &Ident, fields: &FieldsNamed, flexible: Option<i16>) -> TokenStream {
+    let flexible = match validate(fields, flexible) {
+        Ok(Some(flexible)) => flexible,
+        Ok(None) => return quote! {},
+        Err(err) => return err,
+    };
+
+    let tagged = tagged_fields(fields);
+    if tagged.is_empty() {
+        return quote! {
+            if version >= #flexible {
+                kf_protocol::flexible::encode_empty_tagged_fields(src)?;
+            }
+        };
+    }
+
+    let count = count_tagged_fields(&tagged);
+    let writes = tagged.iter().map(|(f, tag)| {
+        let fname = &f.ident;
+        let condition = field_condition(f);
+        quote! {
+            if #condition {
+                log::trace!("encoding struct: <{}>, tagged field <{}> => {:?}",stringify!(#struct_name),stringify!(#fname),&self.#fname);
+                kf_protocol::flexible::encode_tagged_field(src,#tag,&self.#fname,version)?;
+            }
+        }
+    });
+
+    quote! {
+        if version >= #flexible {
+            #count
+            kf_protocol::flexible::encode_uvarint(src,tagged_fields)?;
+            #(#writes)*
+        }
+    }
+}
+
+/// generate size of tagged fields section
+pub(crate) fn tagged_fields_size(fields: &FieldsNamed, flexible: Option<i16>) -> TokenStream {
+    let flexible = match validate(fields, flexible) {
+        Ok(Some(flexible)) => flexible,
+        // error is reported by encoding
+        Ok(None) | Err(_) => return quote! {},
+    };
+
+    let tagged = tagged_fields(fields);
+    if tagged.is_empty() {
+        return quote! {
+            if version >= #flexible {
+                len = len + kf_protocol::flexible::uvarint_size(0);
+            }
+        };
+    }
+
+    let count = count_tagged_fields(&tagged);
+    let sizes = tagged.iter().map(|(f, tag)| {
+        let fname = &f.ident;
+        let condition = field_condition(f);
+        quote! {
+            if #condition {
+                len = len + kf_protocol::flexible::tagged_field_size(#tag,&self.#fname,version);
+            }
+        }
+    });
+
+    quote! {
+        if version >= #flexible {
+            #count
+            len = len + kf_protocol::flexible::uvarint_size(tagged_fields);
+            #(#sizes)*
+        }
+    }
+}
+
+/// generate decoding of tagged fields section, unknown tags are skipped
+pub(crate) fn decode_tagged_fields(struct_name: &Ident, fields: &FieldsNamed, flexible: Option<i16>) -> TokenStream {
+    let flexible = match validate(fields, flexible) {
+        Ok(Some(flexible)) => flexible,
+        Ok(None) => return quote! {},
+        Err(err) => return err,
+    };
+
+    let tagged = tagged_fields(fields);
+    if tagged.is_empty() {
+        return quote! {
+            if version >= #flexible {
+                kf_protocol::flexible::skip_tagged_fields(src)?;
+            }
+        };
+    }
+
+    let reads = tagged.iter().map(|(f, tag)| {
+        let fname = &f.ident;
+        let condition = field_condition(f);
+        quote! {
+            #tag if #condition => {
+                kf_protocol::flexible::decode_tagged_field(src,size,&mut self.#fname,version)?;
+                log::trace!("decoding struct: <{}> tagged field: <{}> => {:#?}",stringify!(#struct_name),stringify!(#fname),&self.#fname);
+            },
+        }
+    });
+
+    quote! {
+        if version >= #flexible {
+            let tagged_fields = kf_protocol::flexible::decode_uvarint(src)?;
+            for _ in 0..tagged_fields {
+                let tag = kf_protocol::flexible::decode_uvarint(src)?;
+                let size = kf_protocol::flexible::decode_uvarint(src)? as usize;
+                match tag {
+                    #(#reads)*
+                    _ => kf_protocol::flexible::skip_tagged_field(src,tag,size)?
+                }
+            }
+        }
+    }
+}
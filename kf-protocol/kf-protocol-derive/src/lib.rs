@@ -7,6 +7,7 @@ mod ser;
 mod util;
 mod api;
 mod version;
+mod flexible;
 
 pub(crate) use self::util::default_int_type;
 use self::de::generate_decode_traits;
@@ -80,7 +81,10 @@ use syn::DeriveInput;
 /// * `#[varint]` force decode using varint format.
 /// * `#fluvio_kf(min_version = <version>)]` decodes only if version is equal or greater than min_version
 /// * `#fluvio_kf(max_version = <version>)]`decodes only if version is less or greater than max_version
+/// * `#fluvio_kf(tag = <tag>)]` tagged field, decoded from tagged fields section of flexible versions
 ///
+/// Container attribute `#[fluvio_kf(flexible_versions = <version>)]` enables flexible encoding from that version:
+/// strings, bytes and arrays use compact length and structure ends with tagged fields section.
 ///
 #[proc_macro_derive(Decode, attributes(varint, fluvio_kf))]
 pub fn kf_decode(input: TokenStream) -> TokenStream {
@@ -173,6 +177,7 @@ pub fn kf_api(input: TokenStream) -> TokenStream {
 /// * `api_max_version`:  max version that API supports.  This is optional.
 /// * `api_key`:  API number.  This is required
 /// * `response`:  Response struct.  This is required
+/// * `flexible_versions`:  first version using flexible encoding and request header v2.  This is optional.
 ///
 #[proc_macro_derive(RequestApi, attributes(varint, fluvio_kf))]
 pub fn kf_request(input: TokenStream) -> TokenStream {
@@ -17,6 +17,10 @@ use syn::UnOp;
 
 use crate::default_int_type;
 use super::version::Version;
+use super::flexible::find_flexible_version;
+use super::flexible::find_tag;
+use super::flexible::encode_tagged_fields;
+use super::flexible::tagged_fields_size;
 
 /// generate implementation for encoding kf protocol
 pub fn generate_encode_traits(input: &DeriveInput) -> TokenStream {
@@ -52,10 +56,10 @@ pub fn generate_encode_traits(input: &DeriveInput) -> TokenStream {
 }
 
 /// generate syntax for encoding
-fn encode_fields_for_writing(data: &Data,attrs: &Vec<Attribute>,name: &Ident) -> TokenStream  {
+fn encode_fields_for_writing(data: &Data,attrs: &[Attribute],name: &Ident) -> TokenStream  {
 
     match *data {
-        Data::Struct(ref struct_data) => parse_structf_encoding(name,struct_data),
+        Data::Struct(ref struct_data) => parse_structf_encoding(name,struct_data,attrs),
         Data::Enum(ref enum_data) =>  parse_enum_for_writing(enum_data,attrs,name),
         _ => unimplemented!()
     }
@@ -63,12 +67,15 @@ fn encode_fields_for_writing(data: &Data,attrs: &Vec<Attribute>,name: &Ident) ->
 }
 
 
-fn parse_structf_encoding(struct_name: &Ident,data: &DataStruct) -> TokenStream {
+fn parse_structf_encoding(struct_name: &Ident,data: &DataStruct,attrs: &[Attribute]) -> TokenStream {
+
+    let flexible = find_flexible_version(attrs);
 
     match data.fields  {
         Fields::Named(ref fields) =>  {
 
-            let recurse = fields.named.iter().map(|f| {
+            // tagged fields are written in their own section
+            let recurse = fields.named.iter().filter(|f| find_tag(&f.attrs).is_none()).map(|f| {
                 let fname = &f.ident;           
 
                 if f.attrs.iter().flat_map(Attribute::interpret_meta).find( |meta| meta.name() == "varint").is_some() {
@@ -83,9 +90,20 @@ fn parse_structf_encoding(struct_name: &Ident,data: &DataStruct) -> TokenStream
                     }
                 } else {
                     
+                    let encode = match flexible {
+                        Some(flexible) => quote! {
+                            if version >= #flexible {
+                                self.#fname.encode_compact(src,version)
+                            } else {
+                                self.#fname.encode(src,version)
+                            }
+                        },
+                        None => quote! { self.#fname.encode(src,version) }
+                    };
+
                     let base = quote! {
                         log::trace!("encoding struct: <{}>, field <{}> => {:?}",stringify!(#struct_name),stringify!(#fname),&self.#fname);
-                        let result = self.#fname.encode(src,version);
+                        let result = #encode;
                         if result.is_err() {
                             log::error!("Error Encoding <{}> ==> {}",stringify!(#fname),result.as_ref().unwrap_err());
                             return result;
@@ -111,16 +129,19 @@ fn parse_structf_encoding(struct_name: &Ident,data: &DataStruct) -> TokenStream
                 }
                 
             });
+
+            let tagged = encode_tagged_fields(struct_name,fields,flexible);
             
             quote! {
                 #(#recurse)*
+                #tagged
             }
         },
         _ => unimplemented!()
     }
 }
 
-fn parse_enum_for_writing(data: &DataEnum, attrs: &Vec<Attribute>,name: &Ident) -> TokenStream  {
+fn parse_enum_for_writing(data: &DataEnum, attrs: &[Attribute],name: &Ident) -> TokenStream  {
 
     // find repr sentation
     let int_type = default_int_type(attrs);
@@ -207,10 +228,10 @@ fn parse_enum_for_writing(data: &DataEnum, attrs: &Vec<Attribute>,name: &Ident)
 
 
 /// generate syntax for encoding
-fn encode_field_sizes(data: &Data,attrs: &Vec<Attribute>,name: &Ident) -> TokenStream  {
+fn encode_field_sizes(data: &Data,attrs: &[Attribute],name: &Ident) -> TokenStream  {
 
     match *data {
-        Data::Struct(ref struct_data) => parse_structf_size(name,struct_data),
+        Data::Struct(ref struct_data) => parse_structf_size(name,struct_data,attrs),
         Data::Enum(ref enum_data) =>  parse_enum_for_size(enum_data,attrs,name),
         _ => unimplemented!()
     }
@@ -218,12 +239,14 @@ fn encode_field_sizes(data: &Data,attrs: &Vec<Attribute>,name: &Ident) -> TokenS
 }
 
 
-fn parse_structf_size(struct_name: &Ident,data: &DataStruct) -> TokenStream {
+fn parse_structf_size(struct_name: &Ident,data: &DataStruct,attrs: &[Attribute]) -> TokenStream {
+
+    let flexible = find_flexible_version(attrs);
 
     match data.fields  {
         Fields::Named(ref fields) =>  {
 
-            let recurse = fields.named.iter().map(|f| {
+            let recurse = fields.named.iter().filter(|f| find_tag(&f.attrs).is_none()).map(|f| {
                 let fname = &f.ident;
                 if f.attrs.iter().flat_map(Attribute::interpret_meta).find( |meta| meta.name() == "varint").is_some() {
                     quote! {
@@ -232,8 +255,19 @@ fn parse_structf_size(struct_name: &Ident,data: &DataStruct) -> TokenStream {
                         len = len + write_size;
                     }
                 } else {
+                    let size = match flexible {
+                        Some(flexible) => quote! {
+                            if version >= #flexible {
+                                self.#fname.compact_write_size(version)
+                            } else {
+                                self.#fname.write_size(version)
+                            }
+                        },
+                        None => quote! { self.#fname.write_size(version) }
+                    };
+
                     let base = quote! {
-                        let write_size = self.#fname.write_size(version);
+                        let write_size = #size;
                         log::trace!("write size: <{}> field: <{}> => {}",stringify!(#struct_name),stringify!(#fname),write_size);
                         len = len + write_size;
                     };
@@ -256,8 +290,11 @@ fn parse_structf_size(struct_name: &Ident,data: &DataStruct) -> TokenStream {
                 
             });
 
+            let tagged = tagged_fields_size(fields,flexible);
+
             quote! {
                 #(#recurse)*
+                #tagged
             }
         },
         _ => unimplemented!()
@@ -266,7 +303,7 @@ fn parse_structf_size(struct_name: &Ident,data: &DataStruct) -> TokenStream {
 
 
 
-fn parse_enum_for_size(data: &DataEnum,attrs: &Vec<Attribute>,name: &Ident) -> TokenStream  {
+fn parse_enum_for_size(data: &DataEnum,attrs: &[Attribute],name: &Ident) -> TokenStream  {
     
     let int_type = default_int_type(attrs);
 
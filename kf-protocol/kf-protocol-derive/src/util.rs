@@ -10,7 +10,7 @@ use proc_macro2::Span;
 
 
 /// find type using rep, if not found return u8
-pub(crate) fn default_int_type(attrs: &Vec<Attribute>) -> Ident {
+pub(crate) fn default_int_type(attrs: &[Attribute]) -> Ident {
     let mut rep_list = vec![];
     for attr in attrs {
         let meta = attr.parse_meta().expect("meta");
@@ -40,7 +40,7 @@ pub(crate) fn default_int_type(attrs: &Vec<Attribute>) -> Ident {
 
 
 
-pub(crate) fn find_attr(attrs: &Vec<Attribute>,name: &str) -> Option<Meta> {
+pub(crate) fn find_attr(attrs: &[Attribute],name: &str) -> Option<Meta> {
     attrs.iter()
         .find_map(|a| {
             if let Ok(meta) = a.parse_meta() {
@@ -17,7 +17,7 @@ pub(crate) struct Version {
 impl Version {
     
     // find fluvio versions
-    pub(crate) fn find_version(attrs: &Vec<Attribute>) -> Option<Self> {
+    pub(crate) fn find_version(attrs: &[Attribute]) -> Option<Self> {
         
         if let Some(version) = find_attr(attrs,"fluvio_kf") {
 
@@ -79,4 +79,16 @@ impl Version {
         }
 
     }
+
+    // generate condition for field to be present in version
+    pub(crate) fn condition(&self) -> TokenStream {
+
+        let min = self.min;
+
+        if let Some(max) = self.max {
+            quote! { version >= #min && version <= #max }
+        } else {
+            quote! { version >= #min }
+        }
+    }
 }
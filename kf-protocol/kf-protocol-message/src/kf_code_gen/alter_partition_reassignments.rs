@@ -0,0 +1,107 @@
+/// WARNING: CODE GENERATED FILE
+/// * This file is generated by kfspec2code.
+/// * Any changes applied to this file will be lost when a new spec is generated.
+use serde::{Deserialize, Serialize};
+
+use kf_protocol_api::ErrorCode;
+use kf_protocol_api::Request;
+
+use kf_protocol_derive::Decode;
+use kf_protocol_derive::Encode;
+use kf_protocol_derive::KfDefault;
+
+// -----------------------------------
+// KfAlterPartitionReassignmentsRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfAlterPartitionReassignmentsRequest {
+    /// The time in ms to wait for the request to complete.
+    #[fluvio_kf()]
+    pub timeout_ms: i32,
+
+    /// The topics to reassign.
+    pub topics: Vec<ReassignableTopic>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct ReassignableTopic {
+    /// The topic name.
+    pub name: String,
+
+    /// The partitions to reassign.
+    pub partitions: Vec<ReassignablePartition>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct ReassignablePartition {
+    /// The partition index.
+    pub partition_index: i32,
+
+    /// The replicas to place the partitions on, or null to cancel a pending reassignment for this
+    /// partition.
+    #[fluvio_kf()]
+    pub replicas: Option<Vec<i32>>,
+}
+
+// -----------------------------------
+// KfAlterPartitionReassignmentsResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfAlterPartitionReassignmentsResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or zero if the request did not violate any quota.
+    pub throttle_time_ms: i32,
+
+    /// The top-level error code, or 0 if there was no error.
+    pub error_code: ErrorCode,
+
+    /// The top-level error message, or null if there was no error.
+    pub error_message: Option<String>,
+
+    /// The responses to topics to reassign.
+    pub responses: Vec<ReassignableTopicResponse>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct ReassignableTopicResponse {
+    /// The topic name
+    pub name: String,
+
+    /// The responses to partitions to reassign
+    pub partitions: Vec<ReassignablePartitionResponse>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct ReassignablePartitionResponse {
+    /// The partition index.
+    pub partition_index: i32,
+
+    /// The error code for this partition, or 0 if there was no error.
+    pub error_code: ErrorCode,
+
+    /// The error message for this partition, or null if there was no error.
+    pub error_message: Option<String>,
+}
+
+// -----------------------------------
+// Implementation - KfAlterPartitionReassignmentsRequest
+// -----------------------------------
+
+impl Request for KfAlterPartitionReassignmentsRequest {
+    const API_KEY: u16 = 45;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 0;
+    const MIN_FLEXIBLE_VERSION: i16 = 0;
+
+    type Response = KfAlterPartitionReassignmentsResponse;
+}
@@ -3,6 +3,7 @@
 /// * Any changes applied to this file will be lost when a new spec is generated.
 use serde::{Deserialize, Serialize};
 
+use kf_protocol::Version;
 use kf_protocol_api::ErrorCode;
 use kf_protocol_api::Request;
 
@@ -15,13 +16,23 @@ use kf_protocol_derive::KfDefault;
 // -----------------------------------
 
 #[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
-pub struct KfApiVersionsRequest {}
+#[fluvio_kf(flexible_versions = 3)]
+pub struct KfApiVersionsRequest {
+    /// The name of the client.
+    #[fluvio_kf(min_version = 3, ignorable)]
+    pub client_software_name: String,
+
+    /// The version of the client.
+    #[fluvio_kf(min_version = 3, ignorable)]
+    pub client_software_version: String,
+}
 
 // -----------------------------------
 // KfApiVersionsResponse
 // -----------------------------------
 
 #[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 3)]
 pub struct KfApiVersionsResponse {
     /// The top-level error code.
     pub error_code: ErrorCode,
@@ -33,12 +44,22 @@ pub struct KfApiVersionsResponse {
     /// or zero if the request did not violate any quota.
     #[fluvio_kf(min_version = 1, ignorable)]
     pub throttle_time_ms: i32,
+
+    /// Features supported by the broker.
+    #[fluvio_kf(min_version = 3, ignorable, tag = 0)]
+    pub supported_features: Vec<SupportedFeatureKey>,
+
+    /// The monotonically increasing epoch for the finalized features information. Valid values are
+    /// >= 0. A value of -1 is special and represents unknown epoch.
+    #[fluvio_kf(min_version = 3, ignorable, tag = 1)]
+    pub finalized_features_epoch: i64,
 }
 
 #[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 3)]
 pub struct ApiVersionsResponseKey {
     /// The API index.
-    pub index: i16,
+    pub api_key: i16,
 
     /// The minimum supported version, inclusive.
     pub min_version: i16,
@@ -47,6 +68,22 @@ pub struct ApiVersionsResponseKey {
     pub max_version: i16,
 }
 
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 3)]
+pub struct SupportedFeatureKey {
+    /// The name of the feature.
+    #[fluvio_kf(min_version = 3)]
+    pub name: String,
+
+    /// The minimum supported version for the feature.
+    #[fluvio_kf(min_version = 3)]
+    pub min_version: i16,
+
+    /// The maximum supported version for the feature.
+    #[fluvio_kf(min_version = 3)]
+    pub max_version: i16,
+}
+
 // -----------------------------------
 // Implementation - KfApiVersionsRequest
 // -----------------------------------
@@ -55,8 +92,14 @@ impl Request for KfApiVersionsRequest {
     const API_KEY: u16 = 18;
 
     const MIN_API_VERSION: i16 = 0;
-    const MAX_API_VERSION: i16 = 2;
-    const DEFAULT_API_VERSION: i16 = 2;
+    const MAX_API_VERSION: i16 = 3;
+    const DEFAULT_API_VERSION: i16 = 3;
+    const MIN_FLEXIBLE_VERSION: i16 = 3;
 
     type Response = KfApiVersionsResponse;
+
+    /// ApiVersions response header is never flexible, so client can read it before versions are known
+    fn is_flexible_response_header(_version: Version) -> bool {
+        false
+    }
 }
@@ -0,0 +1,87 @@
+/// WARNING: CODE GENERATED FILE
+/// * This file is generated by kfspec2code.
+/// * Any changes applied to this file will be lost when a new spec is generated.
+use serde::{Deserialize, Serialize};
+
+use kf_protocol_api::ErrorCode;
+use kf_protocol_api::Request;
+
+use kf_protocol_derive::Decode;
+use kf_protocol_derive::Encode;
+use kf_protocol_derive::KfDefault;
+
+// -----------------------------------
+// KfDescribeClusterRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfDescribeClusterRequest {
+    /// Whether to include cluster authorized operations.
+    pub include_cluster_authorized_operations: bool,
+}
+
+// -----------------------------------
+// KfDescribeClusterResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfDescribeClusterResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or zero if the request did not violate any quota.
+    pub throttle_time_ms: i32,
+
+    /// The top-level error code, or 0 if there was no error
+    pub error_code: ErrorCode,
+
+    /// The top-level error message, or null if there was no error.
+    #[fluvio_kf()]
+    pub error_message: Option<String>,
+
+    /// The cluster ID that responding broker belongs to.
+    pub cluster_id: String,
+
+    /// The ID of the controller broker.
+    #[fluvio_kf()]
+    pub controller_id: i32,
+
+    /// Each broker in the response.
+    pub brokers: Vec<DescribeClusterBroker>,
+
+    /// 32-bit bitfield to represent authorized operations for this cluster.
+    #[fluvio_kf()]
+    pub cluster_authorized_operations: i32,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct DescribeClusterBroker {
+    /// The broker ID.
+    pub broker_id: i32,
+
+    /// The broker hostname.
+    pub host: String,
+
+    /// The broker port.
+    pub port: i32,
+
+    /// The rack of the broker, or null if it has not been assigned to a rack.
+    #[fluvio_kf()]
+    pub rack: Option<String>,
+}
+
+// -----------------------------------
+// Implementation - KfDescribeClusterRequest
+// -----------------------------------
+
+impl Request for KfDescribeClusterRequest {
+    const API_KEY: u16 = 60;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 0;
+    const MIN_FLEXIBLE_VERSION: i16 = 0;
+
+    type Response = KfDescribeClusterResponse;
+}
@@ -0,0 +1,97 @@
+/// WARNING: CODE GENERATED FILE
+/// * This file is generated by kfspec2code.
+/// * Any changes applied to this file will be lost when a new spec is generated.
+use serde::{Deserialize, Serialize};
+
+use kf_protocol_api::ErrorCode;
+use kf_protocol_api::Request;
+
+use kf_protocol_derive::Decode;
+use kf_protocol_derive::Encode;
+use kf_protocol_derive::KfDefault;
+
+// -----------------------------------
+// KfIncrementalAlterConfigsRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 1)]
+pub struct KfIncrementalAlterConfigsRequest {
+    /// The incremental updates for each resource.
+    pub resources: Vec<AlterConfigsResource>,
+
+    /// True if we should validate the request, but not change the configurations.
+    pub validate_only: bool,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 1)]
+pub struct AlterConfigsResource {
+    /// The resource type.
+    pub resource_type: i8,
+
+    /// The resource name.
+    pub resource_name: String,
+
+    /// The configurations.
+    pub configs: Vec<AlterableConfig>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 1)]
+pub struct AlterableConfig {
+    /// The configuration key name.
+    pub name: String,
+
+    /// The type (Set, Delete, Append, Subtract) of operation.
+    pub config_operation: i8,
+
+    /// The value to set for the configuration key.
+    pub value: Option<String>,
+}
+
+// -----------------------------------
+// KfIncrementalAlterConfigsResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 1)]
+pub struct KfIncrementalAlterConfigsResponse {
+    /// Duration in milliseconds for which the request was throttled due to a quota violation, or
+    /// zero if the request did not violate any quota.
+    pub throttle_time_ms: i32,
+
+    /// The responses for each resource.
+    pub responses: Vec<AlterConfigsResourceResponse>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 1)]
+pub struct AlterConfigsResourceResponse {
+    /// The resource error code.
+    pub error_code: ErrorCode,
+
+    /// The resource error message, or null if there was no error.
+    pub error_message: Option<String>,
+
+    /// The resource type.
+    pub resource_type: i8,
+
+    /// The resource name.
+    pub resource_name: String,
+}
+
+// -----------------------------------
+// Implementation - KfIncrementalAlterConfigsRequest
+// -----------------------------------
+
+impl Request for KfIncrementalAlterConfigsRequest {
+    const API_KEY: u16 = 44;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 1;
+    const DEFAULT_API_VERSION: i16 = 1;
+    const MIN_FLEXIBLE_VERSION: i16 = 1;
+
+    type Response = KfIncrementalAlterConfigsResponse;
+}
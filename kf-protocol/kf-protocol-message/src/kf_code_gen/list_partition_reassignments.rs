@@ -0,0 +1,99 @@
+/// WARNING: CODE GENERATED FILE
+/// * This file is generated by kfspec2code.
+/// * Any changes applied to this file will be lost when a new spec is generated.
+use serde::{Deserialize, Serialize};
+
+use kf_protocol_api::ErrorCode;
+use kf_protocol_api::Request;
+
+use kf_protocol_derive::Decode;
+use kf_protocol_derive::Encode;
+use kf_protocol_derive::KfDefault;
+
+// -----------------------------------
+// KfListPartitionReassignmentsRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfListPartitionReassignmentsRequest {
+    /// The time in ms to wait for the request to complete.
+    #[fluvio_kf()]
+    pub timeout_ms: i32,
+
+    /// The topics to list partition reassignments for, or null to list everything.
+    #[fluvio_kf()]
+    pub topics: Option<Vec<ListPartitionReassignmentsTopics>>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct ListPartitionReassignmentsTopics {
+    /// The topic name
+    pub name: String,
+
+    /// The partitions to list partition reassignments for.
+    pub partition_indexes: Vec<i32>,
+}
+
+// -----------------------------------
+// KfListPartitionReassignmentsResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct KfListPartitionReassignmentsResponse {
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or zero if the request did not violate any quota.
+    pub throttle_time_ms: i32,
+
+    /// The top-level error code, or 0 if there was no error
+    pub error_code: ErrorCode,
+
+    /// The top-level error message, or null if there was no error.
+    pub error_message: Option<String>,
+
+    /// The ongoing reassignments for each topic.
+    pub topics: Vec<OngoingTopicReassignment>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct OngoingTopicReassignment {
+    /// The topic name.
+    pub name: String,
+
+    /// The ongoing reassignments for each partition.
+    pub partitions: Vec<OngoingPartitionReassignment>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+#[fluvio_kf(flexible_versions = 0)]
+pub struct OngoingPartitionReassignment {
+    /// The index of the partition.
+    pub partition_index: i32,
+
+    /// The current replica set.
+    pub replicas: Vec<i32>,
+
+    /// The set of replicas we are currently adding.
+    pub adding_replicas: Vec<i32>,
+
+    /// The set of replicas we are currently removing.
+    pub removing_replicas: Vec<i32>,
+}
+
+// -----------------------------------
+// Implementation - KfListPartitionReassignmentsRequest
+// -----------------------------------
+
+impl Request for KfListPartitionReassignmentsRequest {
+    const API_KEY: u16 = 46;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 0;
+    const MIN_FLEXIBLE_VERSION: i16 = 0;
+
+    type Response = KfListPartitionReassignmentsResponse;
+}
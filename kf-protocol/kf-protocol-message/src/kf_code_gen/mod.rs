@@ -14,40 +14,49 @@ pub mod delete_groups;
 
 pub mod create_topics;
 pub mod delete_topics;
+pub mod create_partitions;
 
 pub mod metadata;
 pub mod update_metadata;
+pub mod describe_cluster;
 
 pub mod list_offset;
 pub mod offset_fetch;
+pub mod offset_commit;
+pub mod offset_delete;
+pub mod offset_for_leader_epoch;
 
 pub mod leader_and_isr;
+pub mod stop_replica;
+pub mod controlled_shutdown;
+pub mod elect_preferred_leaders;
+pub mod alter_partition_reassignments;
+pub mod list_partition_reassignments;
 
-/*
-pub mod add_offsets_to_txn;
-pub mod add_partitions_to_txn;
+pub mod describe_configs;
 pub mod alter_configs;
-pub mod alter_replica_log_dirs;
-pub mod controlled_shutdown;
+pub mod incremental_alter_configs;
+
 pub mod create_acls;
-pub mod create_delegation_token;
-pub mod create_partitions;
-pub mod delete_acls;
-pub mod delete_records;
 pub mod describe_acls;
-pub mod describe_configs;
+pub mod delete_acls;
+
+pub mod sasl_handshake;
+pub mod sasl_authenticate;
+
+pub mod create_delegation_token;
+pub mod renew_delegation_token;
+pub mod expire_delegation_token;
 pub mod describe_delegation_token;
+
+pub mod delete_records;
+
 pub mod describe_log_dirs;
-pub mod elect_preferred_leaders;
-pub mod end_txn;
-pub mod expire_delegation_token;
+pub mod alter_replica_log_dirs;
+
 pub mod init_producer_id;
-pub mod offset_commit;
-pub mod offset_for_leader_epoch;
-pub mod renew_delegation_token;
-pub mod sasl_authenticate;
-pub mod sasl_handshake;
-pub mod stop_replica;
-pub mod txn_offset_commit;
+pub mod add_partitions_to_txn;
+pub mod add_offsets_to_txn;
+pub mod end_txn;
 pub mod write_txn_markers;
-*/
+pub mod txn_offset_commit;
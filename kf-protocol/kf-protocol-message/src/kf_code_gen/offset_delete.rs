@@ -0,0 +1,89 @@
+/// WARNING: CODE GENERATED FILE
+/// * This file is generated by kfspec2code.
+/// * Any changes applied to this file will be lost when a new spec is generated.
+use serde::{Deserialize, Serialize};
+
+use kf_protocol_api::ErrorCode;
+use kf_protocol_api::Request;
+
+use kf_protocol_derive::Decode;
+use kf_protocol_derive::Encode;
+use kf_protocol_derive::KfDefault;
+
+// -----------------------------------
+// KfOffsetDeleteRequest
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct KfOffsetDeleteRequest {
+    /// The unique group identifier.
+    pub group_id: String,
+
+    /// The topics to delete offsets for
+    pub topics: Vec<OffsetDeleteRequestTopic>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct OffsetDeleteRequestTopic {
+    /// The topic name.
+    pub topic_name: String,
+
+    /// Each partition to delete offsets for.
+    pub partitions: Vec<OffsetDeleteRequestPartition>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct OffsetDeleteRequestPartition {
+    /// The partition index.
+    pub partition_index: i32,
+}
+
+// -----------------------------------
+// KfOffsetDeleteResponse
+// -----------------------------------
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct KfOffsetDeleteResponse {
+    /// The top-level error code, or 0 if there was no error.
+    pub error_code: ErrorCode,
+
+    /// The duration in milliseconds for which the request was throttled due to a quota violation,
+    /// or zero if the request did not violate any quota.
+    #[fluvio_kf(ignorable)]
+    pub throttle_time_ms: i32,
+
+    /// The responses for each topic.
+    pub topics: Vec<OffsetDeleteResponseTopic>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct OffsetDeleteResponseTopic {
+    /// The topic name.
+    pub topic_name: String,
+
+    /// The responses for each partition in the topic.
+    pub partitions: Vec<OffsetDeleteResponsePartition>,
+}
+
+#[derive(Encode, Decode, Serialize, Deserialize, KfDefault, Debug)]
+pub struct OffsetDeleteResponsePartition {
+    /// The partition index.
+    pub partition_index: i32,
+
+    /// The error code, or 0 if there was no error.
+    pub error_code: ErrorCode,
+}
+
+// -----------------------------------
+// Implementation - KfOffsetDeleteRequest
+// -----------------------------------
+
+impl Request for KfOffsetDeleteRequest {
+    const API_KEY: u16 = 47;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 0;
+
+    type Response = KfOffsetDeleteResponse;
+}
@@ -128,5 +128,4 @@ where
     const DEFAULT_API_VERSION: i16 = 7;
 
     type Response = KfProduceResponse;
-
 }
@@ -12,11 +12,61 @@ pub mod api_versions {
 pub mod topic {
    pub use crate::kf_code_gen::create_topics::*;
    pub use crate::kf_code_gen::delete_topics::*;
+   pub use crate::kf_code_gen::create_partitions::*;
+}
+
+pub mod config {
+   pub use crate::kf_code_gen::describe_configs::*;
+   pub use crate::kf_code_gen::alter_configs::*;
+
+   /// resource type for topic configs
+   pub const TOPIC_RESOURCE_TYPE: i8 = 2;
+
+   /// resource type for broker (spu) configs
+   pub const BROKER_RESOURCE_TYPE: i8 = 4;
+
+   /// config source, value set on topic
+   pub const TOPIC_CONFIG_SOURCE: i8 = 1;
+
+   /// config source, value not set, default applies
+   pub const DEFAULT_CONFIG_SOURCE: i8 = 5;
+}
+
+/// incremental alter configs shares structure names with alter configs
+pub mod incremental_config {
+   pub use crate::kf_code_gen::incremental_alter_configs::*;
+
+   /// config operations of incremental alter configs
+   pub const SET_CONFIG_OPERATION: i8 = 0;
+   pub const DELETE_CONFIG_OPERATION: i8 = 1;
+   pub const APPEND_CONFIG_OPERATION: i8 = 2;
+   pub const SUBTRACT_CONFIG_OPERATION: i8 = 3;
+}
+
+pub mod acl {
+   pub use crate::kf_code_gen::create_acls::*;
+   pub use crate::kf_code_gen::describe_acls::*;
+   pub use crate::kf_code_gen::delete_acls::*;
+}
+
+pub mod sasl {
+   pub use crate::kf_code_gen::sasl_handshake::*;
+   pub use crate::kf_code_gen::sasl_authenticate::*;
+}
+
+pub mod records {
+   pub use crate::kf_code_gen::delete_records::*;
+}
+
+pub mod log_dirs {
+   pub use crate::kf_code_gen::describe_log_dirs::*;
+   pub use crate::kf_code_gen::alter_replica_log_dirs::*;
 }
 
 pub mod metadata {
    pub use crate::kf_code_gen::metadata::*;
    pub use crate::kf_code_gen::update_metadata::*;
+   pub use crate::kf_code_gen::describe_cluster::*;
 }
 
 pub mod produce {
@@ -46,8 +96,35 @@ pub mod group {
 pub mod offset {
    pub use crate::kf_code_gen::list_offset::*;
    pub use crate::kf_code_gen::offset_fetch::*;
+   pub use crate::kf_code_gen::offset_commit::*;
+   pub use crate::kf_code_gen::offset_delete::*;
+   pub use crate::kf_code_gen::offset_for_leader_epoch::*;
 }
 
 pub mod isr {
    pub use crate::kf_code_gen::leader_and_isr::*;
+   pub use crate::kf_code_gen::stop_replica::*;
+   pub use crate::kf_code_gen::controlled_shutdown::*;
+   pub use crate::kf_code_gen::elect_preferred_leaders::*;
+}
+
+pub mod reassignment {
+   pub use crate::kf_code_gen::alter_partition_reassignments::*;
+   pub use crate::kf_code_gen::list_partition_reassignments::*;
+}
+
+pub mod delegation_token {
+   pub use crate::kf_code_gen::create_delegation_token::*;
+   pub use crate::kf_code_gen::renew_delegation_token::*;
+   pub use crate::kf_code_gen::expire_delegation_token::*;
+   pub use crate::kf_code_gen::describe_delegation_token::*;
+}
+
+pub mod txn {
+   pub use crate::kf_code_gen::init_producer_id::*;
+   pub use crate::kf_code_gen::add_partitions_to_txn::*;
+   pub use crate::kf_code_gen::add_offsets_to_txn::*;
+   pub use crate::kf_code_gen::end_txn::*;
+   pub use crate::kf_code_gen::write_txn_markers::*;
+   pub use crate::kf_code_gen::txn_offset_commit::*;
 }
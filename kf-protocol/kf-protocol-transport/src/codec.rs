@@ -1,5 +1,6 @@
 use std::io::Cursor;
 use std::io::Error as IoError;
+use std::io::ErrorKind;
 
 use bytes::BufMut;
 use bytes::Bytes;
@@ -10,8 +11,29 @@ use tokio_codec::Encoder;
 
 use kf_protocol::Decoder as KDecoder;
 
-#[derive(Debug, Default)]
-pub struct KfCodec(());
+/// frames larger than this are rejected unless codec is configured otherwise
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 104_857_600;
+
+#[derive(Debug)]
+pub struct KfCodec {
+    max_frame_size: usize,
+}
+
+impl KfCodec {
+    pub fn new(max_frame_size: usize) -> Self {
+        Self { max_frame_size }
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+}
+
+impl Default for KfCodec {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FRAME_SIZE)
+    }
+}
 
 impl Decoder for KfCodec {
     type Item = BytesMut;
@@ -25,7 +47,25 @@ impl Decoder for KfCodec {
             let mut packet_len: i32 = 0;
             packet_len.decode(&mut src,0)?;
             trace!("Codec decoder content len: {}", packet_len);
-            if (packet_len + 4) as usize <= len {
+
+            // reject before buffering, length prefix can't be trusted
+            if packet_len <= 0 {
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid frame length: {}", packet_len),
+                ));
+            }
+            if packet_len as usize > self.max_frame_size {
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "frame length: {} exceeds max frame size: {}",
+                        packet_len, self.max_frame_size
+                    ),
+                ));
+            }
+
+            if (packet_len as usize) + 4 <= len {
                 trace!(
                     "Codec decoder: fully decoded packet len+4: {} ",
                     packet_len + 4
@@ -83,6 +123,8 @@ mod test {
     use kf_protocol::Encoder as KEncoder;
     use log::debug;
 
+    use tokio_codec::Decoder;
+
     use super::KfCodec;
 
     fn to_bytes(bytes: Vec<u8>) -> Bytes {
@@ -190,4 +232,32 @@ mod test {
         Ok(())
     }
 
+    fn frame(len: i32, content: usize) -> BytesMut {
+        let mut buf = vec![];
+        len.encode(&mut buf, 0).expect("encode");
+        buf.extend(vec![0; content]);
+        BytesMut::from(buf)
+    }
+
+    #[test]
+    fn test_decode_frame() {
+        let mut codec = KfCodec::new(10);
+
+        let mut partial = frame(5, 3);
+        assert!(codec.decode(&mut partial).expect("partial").is_none());
+
+        let mut full = frame(5, 5);
+        assert_eq!(codec.decode(&mut full).expect("full").expect("frame").len(), 5);
+    }
+
+    #[test]
+    fn test_decode_invalid_frame_length() {
+        let mut codec = KfCodec::new(10);
+
+        assert!(codec.decode(&mut frame(0, 0)).is_err());
+        assert!(codec.decode(&mut frame(-1, 0)).is_err());
+        // rejected from length alone, without waiting for content
+        assert!(codec.decode(&mut frame(11, 0)).is_err());
+        assert!(codec.decode(&mut frame(i32::max_value(), 0)).is_err());
+    }
 }
@@ -1,3 +1,4 @@
 mod codec;
 
-pub use self::codec::KfCodec;
\ No newline at end of file
+pub use self::codec::KfCodec;
+pub use self::codec::DEFAULT_MAX_FRAME_SIZE;
@@ -9,6 +9,7 @@ pub use kf_protocol_core::Encoder;
 pub use kf_protocol_core::DecoderVarInt;
 pub use kf_protocol_core::EncoderVarInt;
 pub use kf_protocol_core::Version;
+pub use kf_protocol_core::flexible;
 
 pub mod bytes {
     pub use kf_protocol_core::bytes::Buf;
@@ -21,6 +22,7 @@ pub mod api  {
 
 pub mod transport {
     pub use kf_protocol_transport::KfCodec;
+    pub use kf_protocol_transport::DEFAULT_MAX_FRAME_SIZE;
 }
 
 pub mod message {
@@ -0,0 +1,122 @@
+use std::io::Cursor;
+
+use kf_protocol::derive::Decode;
+use kf_protocol::derive::Encode;
+use kf_protocol::derive::KfDefault;
+use kf_protocol::derive::RequestApi;
+use kf_protocol::Decoder;
+use kf_protocol::Encoder;
+use kf_protocol::api::Request;
+
+
+#[derive(Encode,Decode,KfDefault,RequestApi,Debug)]
+#[fluvio_kf(api_min_version = 0, api_max_version = 2, api_key = 60, response = "FlexibleResponse", flexible_versions = 2)]
+pub struct FlexibleRequest {
+    pub name: String,
+
+    pub topics: Vec<FlexibleTopic>,
+
+    #[fluvio_kf(min_version = 2, tag = 0)]
+    pub cluster_id: Option<String>,
+
+    #[fluvio_kf(min_version = 2, tag = 1)]
+    pub epoch: i32,
+}
+
+#[derive(Encode,Decode,KfDefault,Debug)]
+#[fluvio_kf(flexible_versions = 2)]
+pub struct FlexibleTopic {
+    pub name: String,
+    pub partitions: Vec<i32>,
+}
+
+#[derive(Encode,Decode,KfDefault,Debug)]
+#[fluvio_kf(flexible_versions = 2)]
+pub struct FlexibleResponse {
+    pub error_code: i16,
+}
+
+fn request() -> FlexibleRequest {
+    let mut request = FlexibleRequest::default();
+    request.name = "test".to_owned();
+    request.topics.push(FlexibleTopic {
+        name: "t".to_owned(),
+        partitions: vec![1],
+    });
+    request.cluster_id = Some("c".to_owned());
+    request.epoch = 5;
+    request
+}
+
+#[test]
+fn test_flexible_request() {
+    assert_eq!(FlexibleRequest::MIN_FLEXIBLE_VERSION,2);
+    assert!(!FlexibleRequest::is_flexible(1));
+    assert!(FlexibleRequest::is_flexible(2));
+}
+
+#[test]
+fn test_encode_non_flexible_version() {
+    let record = request();
+
+    // regular lengths and no tagged fields
+    let mut dest = vec![];
+    record.encode(&mut dest,1).expect("encode");
+    assert_eq!(dest.len(),record.write_size(1));
+    assert_eq!(
+        dest,
+        vec![
+            0x00, 0x04, 0x74, 0x65, 0x73, 0x74,             // name
+            0x00, 0x00, 0x00, 0x01,                         // topics
+            0x00, 0x01, 0x74,                               // topic name
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01  // partitions
+        ]
+    );
+}
+
+#[test]
+fn test_encode_flexible_version() {
+    let record = request();
+
+    let mut dest = vec![];
+    record.encode(&mut dest,2).expect("encode");
+    assert_eq!(dest.len(),record.write_size(2));
+    assert_eq!(
+        dest,
+        vec![
+            0x05, 0x74, 0x65, 0x73, 0x74,   // compact name
+            0x02,                           // compact topics
+            0x02, 0x74,                     // compact topic name
+            0x02, 0x00, 0x00, 0x00, 0x01,   // compact partitions
+            0x00,                           // topic tagged fields
+            0x02,                           // request tagged fields
+            0x00, 0x02, 0x02, 0x63,         // tag 0, cluster id
+            0x01, 0x04, 0x00, 0x00, 0x00, 0x05  // tag 1, epoch
+        ]
+    );
+}
+
+#[test]
+fn test_decode_flexible_version() {
+    let record = request();
+    let mut dest = vec![];
+    record.encode(&mut dest,2).expect("encode");
+
+    let decoded = FlexibleRequest::decode_from(&mut Cursor::new(&dest),2).expect("decode");
+    assert_eq!(decoded.name,"test");
+    assert_eq!(decoded.topics.len(),1);
+    assert_eq!(decoded.topics[0].name,"t");
+    assert_eq!(decoded.topics[0].partitions,vec![1]);
+    assert_eq!(decoded.cluster_id,Some("c".to_owned()));
+    assert_eq!(decoded.epoch,5);
+}
+
+#[test]
+fn test_decode_unknown_tagged_field() {
+    // response with unknown tag 7 is skipped
+    let data = [0x00, 0x03, 0x01, 0x07, 0x02, 0xff, 0xff];
+    let mut src = Cursor::new(&data);
+    let response = FlexibleResponse::decode_from(&mut src,2).expect("decode");
+    assert_eq!(response.error_code,3);
+    assert_eq!(src.position() as usize,data.len());
+}
@@ -0,0 +1,215 @@
+use std::io::Cursor;
+
+use kf_protocol::Decoder;
+use kf_protocol::Encoder;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::Request;
+use kf_protocol::message::api_versions::KfApiVersionsRequest;
+use kf_protocol::message::incremental_config::AlterConfigsResource;
+use kf_protocol::message::incremental_config::AlterableConfig;
+use kf_protocol::message::incremental_config::KfIncrementalAlterConfigsRequest;
+use kf_protocol::message::incremental_config::SET_CONFIG_OPERATION;
+use kf_protocol::message::metadata::DescribeClusterBroker;
+use kf_protocol::message::metadata::KfDescribeClusterRequest;
+use kf_protocol::message::metadata::KfDescribeClusterResponse;
+use kf_protocol::message::offset::KfOffsetDeleteRequest;
+use kf_protocol::message::offset::OffsetDeleteRequestPartition;
+use kf_protocol::message::offset::OffsetDeleteRequestTopic;
+use kf_protocol::message::reassignment::KfAlterPartitionReassignmentsRequest;
+use kf_protocol::message::reassignment::KfListPartitionReassignmentsRequest;
+use kf_protocol::message::reassignment::ReassignablePartition;
+use kf_protocol::message::reassignment::ReassignableTopic;
+
+fn encode<E: Encoder>(value: &E, version: i16) -> Vec<u8> {
+    let mut dest = vec![];
+    value.encode(&mut dest, version).expect("encode");
+    assert_eq!(dest.len(), value.write_size(version));
+    dest
+}
+
+#[test]
+fn test_api_versions_response_header() {
+    // ApiVersions response header stays at v0 regardless of version
+    assert!(!KfApiVersionsRequest::is_flexible_response_header(0));
+    assert!(!KfApiVersionsRequest::is_flexible_response_header(3));
+}
+
+#[test]
+fn test_api_versions_v3_request() {
+    assert_eq!(KfApiVersionsRequest::MAX_API_VERSION, 3);
+    assert!(!KfApiVersionsRequest::is_flexible(2));
+    assert!(KfApiVersionsRequest::is_flexible(3));
+
+    let mut request = KfApiVersionsRequest::default();
+    request.client_software_name = "fluvio".to_owned();
+    request.client_software_version = "0.1".to_owned();
+
+    // client software fields are added in v3
+    assert!(encode(&request, 2).is_empty());
+
+    // compact strings followed by empty tagged fields
+    assert_eq!(
+        encode(&request, 3),
+        vec![0x07, b'f', b'l', b'u', b'v', b'i', b'o', 0x04, b'0', b'.', b'1', 0x00]
+    );
+}
+
+#[test]
+fn test_describe_cluster() {
+    assert_eq!(KfDescribeClusterRequest::API_KEY, 60);
+    assert!(KfDescribeClusterRequest::is_flexible(0));
+    assert!(KfDescribeClusterRequest::is_flexible_response_header(0));
+
+    let mut request = KfDescribeClusterRequest::default();
+    request.include_cluster_authorized_operations = true;
+    assert_eq!(encode(&request, 0), vec![0x01, 0x00]);
+
+    let mut response = KfDescribeClusterResponse::default();
+    response.cluster_id = "c1".to_owned();
+    response.controller_id = 5001;
+    response.brokers.push(DescribeClusterBroker {
+        broker_id: 5001,
+        host: "localhost".to_owned(),
+        port: 9005,
+        rack: None,
+    });
+
+    let data = encode(&response, 0);
+    let decoded = KfDescribeClusterResponse::decode_from(&mut Cursor::new(&data), 0).expect("decode");
+    assert_eq!(decoded.error_code, ErrorCode::None);
+    assert_eq!(decoded.error_message, None);
+    assert_eq!(decoded.cluster_id, "c1");
+    assert_eq!(decoded.controller_id, 5001);
+    assert_eq!(decoded.brokers.len(), 1);
+    assert_eq!(decoded.brokers[0].host, "localhost");
+    assert_eq!(decoded.brokers[0].port, 9005);
+    assert_eq!(decoded.brokers[0].rack, None);
+}
+
+fn incremental_request() -> KfIncrementalAlterConfigsRequest {
+    let mut request = KfIncrementalAlterConfigsRequest::default();
+    request.resources.push(AlterConfigsResource {
+        resource_type: 2,
+        resource_name: "t".to_owned(),
+        configs: vec![AlterableConfig {
+            name: "a".to_owned(),
+            config_operation: SET_CONFIG_OPERATION,
+            value: Some("1".to_owned()),
+        }],
+    });
+    request
+}
+
+#[test]
+fn test_incremental_alter_configs() {
+    assert!(!KfIncrementalAlterConfigsRequest::is_flexible(0));
+    assert!(KfIncrementalAlterConfigsRequest::is_flexible(1));
+
+    let request = incremental_request();
+    assert_eq!(
+        encode(&request, 0),
+        vec![
+            0x00, 0x00, 0x00, 0x01, // resources
+            0x02, // resource type
+            0x00, 0x01, 0x74, // resource name
+            0x00, 0x00, 0x00, 0x01, // configs
+            0x00, 0x01, 0x61, // name
+            0x00, // operation
+            0x00, 0x01, 0x31, // value
+            0x00 // validate only
+        ]
+    );
+
+    let data = encode(&request, 1);
+    assert_eq!(
+        data,
+        vec![
+            0x02, // compact resources
+            0x02, // resource type
+            0x02, 0x74, // compact resource name
+            0x02, // compact configs
+            0x02, 0x61, // compact name
+            0x00, // operation
+            0x02, 0x31, // compact value
+            0x00, // config tagged fields
+            0x00, // resource tagged fields
+            0x00, // validate only
+            0x00 // request tagged fields
+        ]
+    );
+
+    let decoded =
+        KfIncrementalAlterConfigsRequest::decode_from(&mut Cursor::new(&data), 1).expect("decode");
+    assert_eq!(decoded.resources.len(), 1);
+    assert_eq!(decoded.resources[0].resource_name, "t");
+    assert_eq!(decoded.resources[0].configs[0].value, Some("1".to_owned()));
+}
+
+#[test]
+fn test_list_partition_reassignments() {
+    assert_eq!(KfListPartitionReassignmentsRequest::API_KEY, 46);
+
+    // null topics list all reassignments
+    let mut request = KfListPartitionReassignmentsRequest::default();
+    request.timeout_ms = 60000;
+    let data = encode(&request, 0);
+    assert_eq!(data, vec![0x00, 0x00, 0xea, 0x60, 0x00, 0x00]);
+
+    let decoded =
+        KfListPartitionReassignmentsRequest::decode_from(&mut Cursor::new(&data), 0).expect("decode");
+    assert_eq!(decoded.timeout_ms, 60000);
+    assert!(decoded.topics.is_none());
+}
+
+#[test]
+fn test_alter_partition_reassignments() {
+    assert_eq!(KfAlterPartitionReassignmentsRequest::API_KEY, 45);
+
+    // null replicas cancel pending reassignment
+    let mut request = KfAlterPartitionReassignmentsRequest::default();
+    request.topics.push(ReassignableTopic {
+        name: "t".to_owned(),
+        partitions: vec![
+            ReassignablePartition {
+                partition_index: 0,
+                replicas: Some(vec![5001, 5002]),
+            },
+            ReassignablePartition {
+                partition_index: 1,
+                replicas: None,
+            },
+        ],
+    });
+
+    let data = encode(&request, 0);
+    let decoded =
+        KfAlterPartitionReassignmentsRequest::decode_from(&mut Cursor::new(&data), 0).expect("decode");
+    let partitions = &decoded.topics[0].partitions;
+    assert_eq!(partitions.len(), 2);
+    assert_eq!(partitions[0].replicas, Some(vec![5001, 5002]));
+    assert_eq!(partitions[1].replicas, None);
+}
+
+#[test]
+fn test_offset_delete() {
+    assert_eq!(KfOffsetDeleteRequest::API_KEY, 47);
+    assert!(!KfOffsetDeleteRequest::is_flexible(0));
+
+    let mut request = KfOffsetDeleteRequest::default();
+    request.group_id = "g".to_owned();
+    request.topics.push(OffsetDeleteRequestTopic {
+        topic_name: "t".to_owned(),
+        partitions: vec![OffsetDeleteRequestPartition { partition_index: 0 }],
+    });
+
+    assert_eq!(
+        encode(&request, 0),
+        vec![
+            0x00, 0x01, 0x67, // group id
+            0x00, 0x00, 0x00, 0x01, // topics
+            0x00, 0x01, 0x74, // topic name
+            0x00, 0x00, 0x00, 0x01, // partitions
+            0x00, 0x00, 0x00, 0x00 // partition index
+        ]
+    );
+}
@@ -4,7 +4,10 @@ use std::marker::PhantomData;
 use std::net::SocketAddr;
 
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::process;
+use std::time::Duration;
 
 use futures::Future;
 use futures::StreamExt;
@@ -25,6 +28,9 @@ use future_aio::net::AsyncTcpStream;
 use future_helper::spawn;
 use kf_protocol::api::KfRequestMessage;
 use kf_protocol::Decoder as KfDecoder;
+use kf_protocol::transport::KfCodec;
+use kf_protocol::transport::DEFAULT_MAX_FRAME_SIZE;
+use kf_socket::ConnectionLimits;
 use kf_socket::KfSocket;
 use kf_socket::KfSocketError;
 use types::print_cli_err;
@@ -42,13 +48,48 @@ pub trait KfService {
 
 }
 
+/// Safeguards against clients exhausting server resources
+#[derive(Debug, Clone, PartialEq)]
+pub struct KfServerConfig {
+    /// larger frames are rejected and connection is closed
+    pub max_frame_size: usize,
+    /// new connections are closed while this many are open
+    pub max_connections: Option<usize>,
+    /// connection without request for this long is closed
+    pub idle_timeout: Option<Duration>,
+    /// requests of connection above this rate are delayed
+    pub max_requests_per_sec: Option<u32>,
+}
+
+impl Default for KfServerConfig {
+    fn default() -> Self {
+        Self {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            max_connections: None,
+            idle_timeout: None,
+            max_requests_per_sec: None,
+        }
+    }
+}
+
+impl KfServerConfig {
+    fn connection_limits(&self) -> ConnectionLimits {
+        ConnectionLimits {
+            idle_timeout: self.idle_timeout,
+            max_requests_per_sec: self.max_requests_per_sec,
+        }
+    }
+}
+
 /// Transform Service into Futures 01
 pub struct KfApiServer<R, A, C, S> {
     req: PhantomData<R>,
     api: PhantomData<A>,
     context: C,
     service: Arc<S>,
-    addr: SocketAddr
+    addr: SocketAddr,
+    config: KfServerConfig,
+    connections: Arc<AtomicUsize>,
 }
 
 impl<R, A, C, S> KfApiServer<R, A, C, S>
@@ -62,10 +103,33 @@ where
             api: PhantomData,
             service: Arc::new(service),
             context,
-            addr
+            addr,
+            config: KfServerConfig::default(),
+            connections: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    pub fn with_config(mut self, config: KfServerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+}
+
+/// counts connection as open until dropped
+struct OpenConnection(Arc<AtomicUsize>);
+
+impl OpenConnection {
+    fn new(connections: Arc<AtomicUsize>) -> Self {
+        connections.fetch_add(1, Ordering::SeqCst);
+        Self(connections)
+    }
+}
+
+impl Drop for OpenConnection {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 
@@ -151,17 +215,29 @@ where
             match incoming_stream {
                 Ok(stream) => {
 
+                    if let Some(max_connections) = self.config.max_connections {
+                        if self.connections.load(Ordering::SeqCst) >= max_connections {
+                            warn!("max connections: {} reached, closing connection {}", max_connections, stream);
+                            return;
+                        }
+                    }
+
                     let context = self.context.clone();
                     let service = self.service.clone();
+                    let codec = KfCodec::new(self.config.max_frame_size);
+                    let limits = self.config.connection_limits();
+                    let open_connection = OpenConnection::new(self.connections.clone());
 
                     let ft = async move {
                         trace!("incoming connection {}",stream);
 
-                        let socket: KfSocket = stream.into();
+                        let mut socket = KfSocket::from_stream(stream, codec);
+                        socket.get_mut_stream().set_limits(limits);
 
                         if let Err(err) = service.respond(context.clone(),socket).await {
                                error!("error handling stream: {}", err);
                         }
+                        drop(open_connection);
                     };
 
                     spawn(ft);
@@ -206,14 +282,18 @@ mod test {
     use crate::test_request::TestContext;
     use crate::test_request::TestKafkaApiEnum;
     use crate::test_request::TestService;
+    use crate::test_request::WatchRequest;
 
     use super::KfApiServer;
+    use super::KfServerConfig;
+
+    type TestApiServer = KfApiServer<TestApiRequest, TestKafkaApiEnum, SharedTestContext, TestService>;
 
     fn create_server(
         addr: SocketAddr,
-    ) -> KfApiServer<TestApiRequest, TestKafkaApiEnum, SharedTestContext, TestService> {
+    ) -> TestApiServer {
         let ctx = Arc::new(TestContext::new());
-        let server: KfApiServer<TestApiRequest, TestKafkaApiEnum, SharedTestContext, TestService> =
+        let server: TestApiServer =
             KfApiServer::new(addr, ctx,TestService::new());
 
         server
@@ -264,4 +344,74 @@ mod test {
         Ok(())
     }
 
+    async fn echo(socket: &mut KfSocket, msg: &str) -> Result<String, KfSocketError> {
+        let msg = RequestMessage::new_request(EchoRequest::new(msg.to_owned()));
+        let reply = socket.send(&msg).await?;
+        Ok(reply.response.msg)
+    }
+
+    async fn test_limited_client(addr: SocketAddr,mut shutdown: Sender<bool>) -> Result<(), KfSocketError> {
+        let mut socket = create_client(addr).await?;
+        assert_eq!(echo(&mut socket, "hello").await?, "hello");
+
+        // only one connection is allowed
+        let mut refused = create_client(addr).await?;
+        assert!(echo(&mut refused, "refused").await.is_err());
+
+        // connection is closed after being idle
+        sleep(Duration::from_millis(300)).await;
+        assert!(echo(&mut socket, "idle").await.is_err());
+
+        shutdown.send(true).await.expect("shutdown should succeed");
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_server_limits() -> Result<(), KfSocketError> {
+        let socket_addr = "127.0.0.1:30002".parse::<SocketAddr>().expect("parse");
+
+        let (sender,receiver) = channel::<bool>(1);
+
+        let server = create_server(socket_addr).with_config(KfServerConfig {
+            max_connections: Some(1),
+            idle_timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        });
+        let client_ft = test_limited_client(socket_addr,sender);
+
+        let (client,_) = join(client_ft,server.run_shutdown(receiver)).await;
+        client
+    }
+
+    async fn test_watch_client(addr: SocketAddr,mut shutdown: Sender<bool>) -> Result<(), KfSocketError> {
+        let mut socket = create_client(addr).await?;
+
+        let msg = RequestMessage::new_request(WatchRequest::default());
+        let reply = socket.send(&msg).await?;
+        assert_eq!(reply.response.msg, "snapshot");
+
+        // change is pushed after connection has been idle longer than idle timeout
+        let change = socket.get_mut_stream().next_response(&msg).await?;
+        assert_eq!(change.response.msg, "change");
+
+        shutdown.send(true).await.expect("shutdown should succeed");
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_server_idle_watch() -> Result<(), KfSocketError> {
+        let socket_addr = "127.0.0.1:30003".parse::<SocketAddr>().expect("parse");
+
+        let (sender,receiver) = channel::<bool>(1);
+
+        let server = create_server(socket_addr).with_config(KfServerConfig {
+            idle_timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        });
+        let client_ft = test_watch_client(socket_addr,sender);
+
+        let (client,_) = join(client_ft,server.run_shutdown(receiver)).await;
+        client
+    }
+
 }
@@ -2,6 +2,9 @@
 #![recursion_limit = "128"]
 
 mod kf_server;
+mod metrics_server;
+mod request_metrics;
+pub mod sasl;
 
 #[cfg(test)]
 pub mod test_request;
@@ -9,9 +12,24 @@ pub mod test_request;
 pub use kf_protocol::transport::KfCodec;
 pub use self::kf_server::KfApiServer;
 pub use self::kf_server::KfService;
+pub use self::kf_server::KfServerConfig;
+pub use self::metrics_server::MetricsServer;
+pub use self::metrics_server::HealthCheck;
+pub use self::metrics_server::SharedHealthCheck;
+pub use self::request_metrics::RequestMetrics;
+pub use self::request_metrics::SharedRequestMetrics;
+pub use self::request_metrics::RequestTimer;
+pub use self::request_metrics::ConnectionGuard;
 
 #[macro_export]
 macro_rules! call_service {
+    ($req:expr,$handler:expr,$sink:expr,$msg:expr,$metrics:expr) => {{
+        {
+            let _timer = $metrics.start($req.header.api_key());
+            $crate::call_service!($req, $handler, $sink, $msg)
+        }
+    }};
+
     ($req:expr,$handler:expr,$sink:expr,$msg:expr) => {{
         {
             let version = $req.header.api_version();
@@ -55,6 +73,48 @@ macro_rules! api_loop {
     }};
 }
 
+/// same as api_loop but when SASL is required, only permitted requests are
+/// dispatched until session is authenticated.  Connection is closed on failed authentication.
+#[macro_export]
+macro_rules! sasl_api_loop {
+    ( $api_stream:ident, $session:ident, [ $($permitted:pat)|+ ], $($matcher:pat => $result:expr),*) => {{
+
+        use futures::stream::StreamExt;
+        loop {
+
+            log::trace!("waiting for next api request");
+            if let Some(msg) = $api_stream.next().await {
+                if let Ok(req_message) = msg {
+                    log::trace!("received request: {:#?}",req_message);
+                    if !$session.is_complete() {
+                        match &req_message {
+                            $($permitted)|+ => {},
+                            _ => {
+                                log::error!("request received before sasl authentication, closing connection");
+                                break;
+                            }
+                        }
+                    }
+                    match req_message {
+                        $($matcher => $result),*
+                    }
+                    if $session.is_failed() {
+                        log::error!("sasl authentication failed, closing connection");
+                        break;
+                    }
+                } else {
+                    log::trace!("no content, end of connection {:#?}", msg);
+                    break;
+                }
+
+            } else {
+                log::trace!("client connect terminated");
+                break;
+            }
+        }
+    }};
+}
+
 /// wait for a single request
 #[macro_export]
 macro_rules! wait_for_request {
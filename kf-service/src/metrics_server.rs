@@ -0,0 +1,215 @@
+//!
+//! # Metrics Server
+//!
+//! Minimal HTTP/1.1 server exposing metrics registry at `/metrics` in Prometheus text format,
+//! liveness at `/healthz` and readiness at `/ready`.
+//! Each connection serves single request and is closed after response.
+//!
+use std::sync::Arc;
+use std::net::SocketAddr;
+use std::io::Error as IoError;
+
+use log::error;
+use log::info;
+use log::trace;
+use futures::sink::SinkExt;
+use futures::stream::StreamExt;
+use bytes::Bytes;
+use bytes::BytesMut;
+
+use future_aio::net::AsyncTcpListener;
+use future_aio::net::AsyncTcpStream;
+use future_aio::net::SimpleCodec;
+use future_aio::net::TcpStreamSplit;
+use future_helper::spawn;
+use utils::metrics::SharedMetricsRegistry;
+use utils::metrics::MetricsRegistry;
+
+pub const METRICS_PATH: &str = "/metrics";
+pub const HEALTH_PATH: &str = "/healthz";
+pub const READY_PATH: &str = "/ready";
+
+/// max size of request head, larger requests are rejected
+const MAX_REQUEST_HEAD: usize = 8192;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// readiness of server, liveness is implied by server responding
+pub trait HealthCheck: Send + Sync {
+    fn is_ready(&self) -> bool;
+}
+
+pub type SharedHealthCheck = Arc<dyn HealthCheck>;
+
+pub struct MetricsServer {
+    addr: SocketAddr,
+    registry: SharedMetricsRegistry,
+    health: SharedHealthCheck,
+}
+
+impl MetricsServer {
+    pub fn new(addr: SocketAddr, registry: SharedMetricsRegistry, health: SharedHealthCheck) -> Self {
+        Self {
+            addr,
+            registry,
+            health,
+        }
+    }
+
+    pub fn run(self) {
+        spawn(self.event_loop());
+    }
+
+    /// metrics are not essential, failure to bind is logged and server is not started
+    async fn event_loop(self) {
+        let listener = match AsyncTcpListener::bind(&self.addr) {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!("metrics server can't bind: {}, {}", self.addr, err);
+                return;
+            }
+        };
+
+        info!("starting metrics server at: {}", self.addr);
+        let mut incoming = listener.incoming();
+        while let Some(stream) = incoming.next().await {
+            match stream {
+                Ok(stream) => {
+                    let registry = self.registry.clone();
+                    let health = self.health.clone();
+                    spawn(async move {
+                        if let Err(err) = serve(stream, &registry, health.as_ref()).await {
+                            error!("error serving metrics: {}", err);
+                        }
+                    });
+                }
+                Err(err) => error!("error with metrics stream: {}", err),
+            }
+        }
+
+        info!("metrics server terminating");
+    }
+}
+
+async fn serve(
+    stream: AsyncTcpStream,
+    registry: &MetricsRegistry,
+    health: &dyn HealthCheck,
+) -> Result<(), IoError> {
+    let split: TcpStreamSplit<SimpleCodec> = stream.split();
+    let (mut sink, mut stream) = split.as_tuple();
+
+    let mut head = BytesMut::new();
+    while !is_head_complete(&head) && head.len() < MAX_REQUEST_HEAD {
+        match stream.next().await {
+            Some(bytes) => head.extend_from_slice(&bytes?),
+            None => return Ok(()),
+        }
+    }
+
+    let response = http_response(&head, registry, health);
+    sink.send(Bytes::from(response)).await?;
+    sink.close().await
+}
+
+fn is_head_complete(head: &[u8]) -> bool {
+    head.windows(4).any(|window| window == b"\r\n\r\n")
+}
+
+/// response to request head
+fn http_response(head: &[u8], registry: &MetricsRegistry, health: &dyn HealthCheck) -> Vec<u8> {
+    let request_line = head
+        .split(|byte| *byte == b'\n')
+        .next()
+        .map(|line| String::from_utf8_lossy(line).trim().to_owned())
+        .unwrap_or_default();
+    trace!("metrics request: {}", request_line);
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+
+    if !is_head_complete(head) {
+        return response("400 Bad Request", "text/plain", "bad request\n");
+    }
+
+    if method != "GET" {
+        return response("405 Method Not Allowed", "text/plain", "method not allowed\n");
+    }
+
+    // ignore query string
+    match path.split('?').next() {
+        Some(METRICS_PATH) => response("200 OK", PROMETHEUS_CONTENT_TYPE, &registry.encode()),
+        Some(HEALTH_PATH) => response("200 OK", "text/plain", "ok\n"),
+        Some(READY_PATH) => {
+            if health.is_ready() {
+                response("200 OK", "text/plain", "ready\n")
+            } else {
+                response("503 Service Unavailable", "text/plain", "not ready\n")
+            }
+        }
+        _ => response("404 Not Found", "text/plain", "not found\n"),
+    }
+}
+
+fn response(status: &str, content_type: &str, body: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    use utils::metrics::MetricsRegistry;
+
+    use super::http_response;
+    use super::HealthCheck;
+
+    impl HealthCheck for AtomicBool {
+        fn is_ready(&self) -> bool {
+            self.load(Ordering::SeqCst)
+        }
+    }
+
+    #[test]
+    fn test_http_response() {
+        let registry = MetricsRegistry::default();
+        registry.gauge("connections", "open connections", &[]).set(1);
+        let health = AtomicBool::new(true);
+
+        let ok = String::from_utf8(http_response(b"GET /metrics HTTP/1.1\r\nHost: spu\r\n\r\n", &registry, &health)).unwrap();
+        assert!(ok.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(ok.ends_with("\r\n\r\n# HELP connections open connections\n# TYPE connections gauge\nconnections 1\n"));
+
+        let not_found = String::from_utf8(http_response(b"GET / HTTP/1.1\r\n\r\n", &registry, &health)).unwrap();
+        assert!(not_found.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        let not_allowed = String::from_utf8(http_response(b"POST /metrics HTTP/1.1\r\n\r\n", &registry, &health)).unwrap();
+        assert!(not_allowed.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[test]
+    fn test_health_response() {
+        let registry = MetricsRegistry::default();
+        let health = AtomicBool::new(false);
+
+        let alive = String::from_utf8(http_response(b"GET /healthz HTTP/1.1\r\n\r\n", &registry, &health)).unwrap();
+        assert!(alive.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        let not_ready = String::from_utf8(http_response(b"GET /ready HTTP/1.1\r\n\r\n", &registry, &health)).unwrap();
+        assert!(not_ready.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+
+        health.store(true, Ordering::SeqCst);
+        let ready = String::from_utf8(http_response(b"GET /ready HTTP/1.1\r\n\r\n", &registry, &health)).unwrap();
+        assert!(ready.starts_with("HTTP/1.1 200 OK\r\n"));
+    }
+}
@@ -0,0 +1,148 @@
+//!
+//! # Request Metrics
+//!
+//! Request rate and latency per api key, and connection counts of api server.
+//!
+use std::sync::Arc;
+use std::time::Instant;
+
+use utils::metrics::SharedMetricsRegistry;
+use utils::metrics::Gauge;
+use utils::metrics::LATENCY_BUCKETS;
+
+pub type SharedRequestMetrics = Arc<RequestMetrics>;
+
+pub struct RequestMetrics {
+    prefix: &'static str,
+    registry: SharedMetricsRegistry,
+    api_key_name: fn(u16) -> String,
+}
+
+impl std::fmt::Debug for RequestMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "RequestMetrics({})", self.prefix)
+    }
+}
+
+impl RequestMetrics {
+    /// metrics are named with prefix, api key is labeled with name given by `api_key_name`
+    pub fn new(
+        prefix: &'static str,
+        registry: SharedMetricsRegistry,
+        api_key_name: fn(u16) -> String,
+    ) -> Self {
+        Self {
+            prefix,
+            registry,
+            api_key_name,
+        }
+    }
+
+    pub fn new_shared(
+        prefix: &'static str,
+        registry: SharedMetricsRegistry,
+        api_key_name: fn(u16) -> String,
+    ) -> SharedRequestMetrics {
+        Arc::new(Self::new(prefix, registry, api_key_name))
+    }
+
+    /// start timing request, request is recorded when timer is dropped
+    pub fn start(&self, api_key: u16) -> RequestTimer {
+        RequestTimer {
+            metrics: self,
+            api_key,
+            start: Instant::now(),
+        }
+    }
+
+    /// track open connection, connection is closed when guard is dropped
+    pub fn open_connection(&self) -> ConnectionGuard {
+        self.registry
+            .counter(
+                &format!("{}_connections_total", self.prefix),
+                "Total connections accepted",
+                &[],
+            )
+            .inc();
+        let gauge = self.registry.gauge(
+            &format!("{}_connections", self.prefix),
+            "Open connections",
+            &[],
+        );
+        gauge.inc();
+        ConnectionGuard { gauge }
+    }
+
+    fn record(&self, api_key: u16, start: Instant) {
+        let name = (self.api_key_name)(api_key);
+        let labels = [("api_key", name.as_str())];
+        self.registry
+            .counter(
+                &format!("{}_requests_total", self.prefix),
+                "Total requests by api key",
+                &labels,
+            )
+            .inc();
+        self.registry
+            .histogram(
+                &format!("{}_request_duration_seconds", self.prefix),
+                "Request latency by api key",
+                &labels,
+                LATENCY_BUCKETS,
+            )
+            .observe_duration(start.elapsed());
+    }
+}
+
+pub struct RequestTimer<'a> {
+    metrics: &'a RequestMetrics,
+    api_key: u16,
+    start: Instant,
+}
+
+impl<'a> Drop for RequestTimer<'a> {
+    fn drop(&mut self) {
+        self.metrics.record(self.api_key, self.start);
+    }
+}
+
+pub struct ConnectionGuard {
+    gauge: Arc<Gauge>,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.gauge.dec();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use utils::metrics::MetricsRegistry;
+    use utils::metrics::LATENCY_BUCKETS;
+
+    use super::RequestMetrics;
+
+    #[test]
+    fn test_request_metrics() {
+        let registry = MetricsRegistry::new_shared();
+        let metrics = RequestMetrics::new("test", registry.clone(), |key| format!("key{}", key));
+
+        {
+            let _connection = metrics.open_connection();
+            assert_eq!(registry.gauge("test_connections", "", &[]).get(), 1);
+            let _timer = metrics.start(1);
+        }
+
+        assert_eq!(registry.gauge("test_connections", "", &[]).get(), 0);
+        assert_eq!(registry.counter("test_connections_total", "", &[]).get(), 1);
+        assert_eq!(registry.counter("test_requests_total", "", &[("api_key", "key1")]).get(), 1);
+        assert_eq!(
+            registry
+                .histogram("test_request_duration_seconds", "", &[("api_key", "key1")], LATENCY_BUCKETS)
+                .count(),
+            1
+        );
+    }
+}
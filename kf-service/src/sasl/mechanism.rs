@@ -0,0 +1,96 @@
+//!
+//! # SASL Mechanisms
+//!
+//! Mechanisms supported on Fluvio listeners and SASL configuration of a listener.
+//!
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScramMechanism {
+    Sha256,
+    Sha512,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaslMechanism {
+    Plain,
+    Scram(ScramMechanism),
+}
+
+/// SASL settings of a listener. When enabled, clients must authenticate
+/// before any request other than api versions or SASL is accepted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaslConfig {
+    pub enabled: bool,
+    pub mechanisms: Vec<SaslMechanism>,
+}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl ScramMechanism {
+    pub fn all() -> Vec<Self> {
+        vec![ScramMechanism::Sha256, ScramMechanism::Sha512]
+    }
+}
+
+impl SaslMechanism {
+    pub fn all() -> Vec<Self> {
+        vec![
+            SaslMechanism::Plain,
+            SaslMechanism::Scram(ScramMechanism::Sha256),
+            SaslMechanism::Scram(ScramMechanism::Sha512),
+        ]
+    }
+
+    /// name as used in the Kafka protocol
+    pub fn name(&self) -> &'static str {
+        match self {
+            SaslMechanism::Plain => "PLAIN",
+            SaslMechanism::Scram(ScramMechanism::Sha256) => "SCRAM-SHA-256",
+            SaslMechanism::Scram(ScramMechanism::Sha512) => "SCRAM-SHA-512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|mechanism| mechanism.name() == name)
+    }
+
+    /// parse mechanism names from configuration
+    pub fn from_names(names: &[String]) -> Result<Vec<Self>, IoError> {
+        names
+            .iter()
+            .map(|name| {
+                Self::from_name(name).ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "unknown sasl mechanism '{}', expected: PLAIN, SCRAM-SHA-256 or SCRAM-SHA-512",
+                            name
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for SaslConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mechanisms: SaslMechanism::all(),
+        }
+    }
+}
+
+impl SaslConfig {
+    pub fn mechanism_names(&self) -> Vec<String> {
+        self.mechanisms
+            .iter()
+            .map(|mechanism| mechanism.name().to_owned())
+            .collect()
+    }
+}
@@ -0,0 +1,21 @@
+mod mechanism;
+mod scram;
+mod session;
+
+pub use self::mechanism::SaslConfig;
+pub use self::mechanism::SaslMechanism;
+pub use self::mechanism::ScramMechanism;
+pub use self::scram::ScramCredential;
+pub use self::scram::MIN_ITERATIONS;
+pub use self::session::SaslSession;
+pub use self::session::USER_PRINCIPAL_PREFIX;
+pub use self::session::handle_sasl_handshake_request;
+pub use self::session::handle_sasl_authenticate_request;
+
+/// handshake version 0 sends raw SASL tokens without Kafka framing, which is not supported
+pub const SASL_HANDSHAKE_MIN_VERSION: i16 = 1;
+
+/// source of SCRAM credentials for authenticating users
+pub trait SaslCredentialLookup {
+    fn scram_credential(&self, mechanism: &ScramMechanism, user: &str) -> Option<ScramCredential>;
+}
@@ -0,0 +1,405 @@
+//!
+//! # SCRAM
+//!
+//! Server side of SCRAM-SHA-256/512 (RFC 5802, RFC 7677).  Passwords are never stored,
+//! only salted keys from which the client proof can be verified.
+//!
+
+use hmac::Hmac;
+use hmac::Mac;
+use rand::Rng;
+use sha2::Digest;
+use sha2::Sha256;
+use sha2::Sha512;
+
+use super::ScramMechanism;
+
+/// length of random salt generated for new credentials
+const SALT_LEN: usize = 32;
+
+/// length of random nonce added by server
+const NONCE_LEN: usize = 24;
+
+/// minimum iterations accepted by Kafka for SCRAM credentials
+pub const MIN_ITERATIONS: i32 = 4096;
+
+/// salted credential of a user for a SCRAM mechanism
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScramCredential {
+    pub salt: Vec<u8>,
+    pub iterations: i32,
+    pub stored_key: Vec<u8>,
+    pub server_key: Vec<u8>,
+}
+
+/// parsed client-first message
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScramClientFirst {
+    pub gs2_header: String,
+    pub user: String,
+    pub nonce: String,
+    pub bare: String,
+}
+
+/// state kept by server between client-first and client-final messages
+#[derive(Debug, Clone)]
+pub struct ScramServerFirst {
+    mechanism: ScramMechanism,
+    user: String,
+    credential: ScramCredential,
+    gs2_header: String,
+    nonce: String,
+    client_first_bare: String,
+    server_first: String,
+}
+
+// -----------------------------------
+// Implementation - ScramCredential
+// -----------------------------------
+
+impl ScramCredential {
+    /// generate credential with random salt
+    pub fn generate(mechanism: &ScramMechanism, password: &str, iterations: i32) -> Self {
+        let mut salt = vec![0u8; SALT_LEN];
+        rand::thread_rng().fill(&mut salt[..]);
+        Self::with_salt(mechanism, password, salt, iterations)
+    }
+
+    pub fn with_salt(
+        mechanism: &ScramMechanism,
+        password: &str,
+        salt: Vec<u8>,
+        iterations: i32,
+    ) -> Self {
+        let salted_password = hi(mechanism, password.as_bytes(), &salt, iterations);
+        let client_key = hmac(mechanism, &salted_password, b"Client Key");
+        let stored_key = hash(mechanism, &client_key);
+        let server_key = hmac(mechanism, &salted_password, b"Server Key");
+
+        Self {
+            salt,
+            iterations,
+            stored_key,
+            server_key,
+        }
+    }
+
+    /// decode credential from base64 encoded values
+    pub fn decode(
+        salt: &str,
+        iterations: i32,
+        stored_key: &str,
+        server_key: &str,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            salt: base64::decode(salt).map_err(|err| format!("invalid salt: {}", err))?,
+            iterations,
+            stored_key: base64::decode(stored_key)
+                .map_err(|err| format!("invalid stored key: {}", err))?,
+            server_key: base64::decode(server_key)
+                .map_err(|err| format!("invalid server key: {}", err))?,
+        })
+    }
+
+    pub fn encoded_salt(&self) -> String {
+        base64::encode(&self.salt)
+    }
+
+    pub fn encoded_stored_key(&self) -> String {
+        base64::encode(&self.stored_key)
+    }
+
+    pub fn encoded_server_key(&self) -> String {
+        base64::encode(&self.server_key)
+    }
+
+    /// check clear text password against credential (used by PLAIN)
+    pub fn verify_password(&self, mechanism: &ScramMechanism, password: &str) -> bool {
+        let expected = Self::with_salt(mechanism, password, self.salt.clone(), self.iterations);
+        constant_time_eq(&expected.stored_key, &self.stored_key)
+    }
+}
+
+// -----------------------------------
+// Implementation - ScramServerFirst
+// -----------------------------------
+
+impl ScramServerFirst {
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    pub fn server_first(&self) -> &str {
+        &self.server_first
+    }
+}
+
+/// parse client-first message
+pub fn parse_client_first(message: &str) -> Result<ScramClientFirst, String> {
+    // gs2 header: channel binding flag, optional authzid
+    let mut parts = message.splitn(3, ',');
+    let cb_flag = parts.next().unwrap_or_default();
+    let authzid = parts.next().ok_or_else(|| "missing gs2 header".to_owned())?;
+    let bare = parts.next().ok_or_else(|| "missing client first message".to_owned())?;
+
+    match cb_flag {
+        "n" | "y" => {}
+        _ => return Err("channel binding is not supported".to_owned()),
+    }
+    if !authzid.is_empty() && !authzid.starts_with("a=") {
+        return Err(format!("invalid authzid: {}", authzid));
+    }
+
+    let mut user = None;
+    let mut nonce = None;
+    for attribute in bare.split(',') {
+        if attribute.starts_with("n=") {
+            user = Some(decode_username(&attribute[2..])?);
+        } else if attribute.starts_with("r=") {
+            nonce = Some(attribute[2..].to_owned());
+        } else if attribute.starts_with("m=") {
+            return Err("extensions are not supported".to_owned());
+        }
+    }
+
+    let user = user.ok_or_else(|| "missing user name".to_owned())?;
+    let nonce = nonce.ok_or_else(|| "missing client nonce".to_owned())?;
+    Ok(ScramClientFirst {
+        gs2_header: format!("{},{},", cb_flag, authzid),
+        user,
+        nonce,
+        bare: bare.to_owned(),
+    })
+}
+
+/// process client-first message and build server-first
+pub fn server_first(
+    mechanism: &ScramMechanism,
+    credential: ScramCredential,
+    client_first: ScramClientFirst,
+) -> ScramServerFirst {
+    let nonce = format!("{}{}", client_first.nonce, generate_nonce());
+    let server_first = format!(
+        "r={},s={},i={}",
+        nonce,
+        credential.encoded_salt(),
+        credential.iterations
+    );
+
+    ScramServerFirst {
+        mechanism: mechanism.clone(),
+        user: client_first.user,
+        credential,
+        gs2_header: client_first.gs2_header,
+        nonce,
+        client_first_bare: client_first.bare,
+        server_first,
+    }
+}
+
+/// verify client-final message, on success returns server-final message
+pub fn verify_client_final(first: &ScramServerFirst, message: &str) -> Result<String, String> {
+    let proof_pos = message
+        .rfind(",p=")
+        .ok_or_else(|| "missing client proof".to_owned())?;
+    let without_proof = &message[..proof_pos];
+    let proof = base64::decode(&message[proof_pos + 3..])
+        .map_err(|err| format!("invalid client proof: {}", err))?;
+
+    let mut channel_binding = None;
+    let mut nonce = None;
+    for attribute in without_proof.split(',') {
+        if attribute.starts_with("c=") {
+            channel_binding = Some(&attribute[2..]);
+        } else if attribute.starts_with("r=") {
+            nonce = Some(&attribute[2..]);
+        }
+    }
+
+    if channel_binding != Some(base64::encode(&first.gs2_header).as_str()) {
+        return Err("invalid channel binding".to_owned());
+    }
+    if nonce != Some(first.nonce.as_str()) {
+        return Err("invalid nonce".to_owned());
+    }
+
+    let auth_message = format!(
+        "{},{},{}",
+        first.client_first_bare, first.server_first, without_proof
+    );
+    let mechanism = &first.mechanism;
+    let credential = &first.credential;
+
+    let client_signature = hmac(mechanism, &credential.stored_key, auth_message.as_bytes());
+    if proof.len() != client_signature.len() {
+        return Err("invalid client proof".to_owned());
+    }
+    let client_key: Vec<u8> = proof
+        .iter()
+        .zip(client_signature.iter())
+        .map(|(p, s)| p ^ s)
+        .collect();
+    if !constant_time_eq(&hash(mechanism, &client_key), &credential.stored_key) {
+        return Err(format!("authentication failed for user: {}", first.user));
+    }
+
+    let server_signature = hmac(mechanism, &credential.server_key, auth_message.as_bytes());
+    Ok(format!("v={}", base64::encode(&server_signature)))
+}
+
+/// user names escape ',' and '=' as '=2C' and '=3D'
+fn decode_username(name: &str) -> Result<String, String> {
+    let mut decoded = String::with_capacity(name.len());
+    let mut rest = name;
+    while let Some(pos) = rest.find('=') {
+        decoded.push_str(&rest[..pos]);
+        let escaped = rest.get(pos..pos + 3);
+        match escaped {
+            Some("=2C") => decoded.push(','),
+            Some("=3D") => decoded.push('='),
+            _ => return Err(format!("invalid user name: {}", name)),
+        }
+        rest = &rest[pos + 3..];
+    }
+    decoded.push_str(rest);
+    Ok(decoded)
+}
+
+fn generate_nonce() -> String {
+    let mut bytes = vec![0u8; NONCE_LEN];
+    rand::thread_rng().fill(&mut bytes[..]);
+    base64::encode(&bytes)
+}
+
+fn hash(mechanism: &ScramMechanism, data: &[u8]) -> Vec<u8> {
+    match mechanism {
+        ScramMechanism::Sha256 => Sha256::digest(data).to_vec(),
+        ScramMechanism::Sha512 => Sha512::digest(data).to_vec(),
+    }
+}
+
+fn hmac(mechanism: &ScramMechanism, key: &[u8], data: &[u8]) -> Vec<u8> {
+    match mechanism {
+        ScramMechanism::Sha256 => {
+            let mut mac = Hmac::<Sha256>::new_varkey(key).expect("hmac accepts any key size");
+            mac.input(data);
+            mac.result().code().to_vec()
+        }
+        ScramMechanism::Sha512 => {
+            let mut mac = Hmac::<Sha512>::new_varkey(key).expect("hmac accepts any key size");
+            mac.input(data);
+            mac.result().code().to_vec()
+        }
+    }
+}
+
+/// PBKDF2 with HMAC as pseudo random function
+fn hi(mechanism: &ScramMechanism, password: &[u8], salt: &[u8], iterations: i32) -> Vec<u8> {
+    let mut first = salt.to_vec();
+    first.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut previous = hmac(mechanism, password, &first);
+    let mut result = previous.clone();
+    for _ in 1..iterations {
+        previous = hmac(mechanism, password, &previous);
+        for (r, p) in result.iter_mut().zip(previous.iter()) {
+            *r ^= p;
+        }
+    }
+    result
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    /// client side computation of proof
+    fn client_proof(
+        mechanism: &ScramMechanism,
+        password: &str,
+        salt: &[u8],
+        iterations: i32,
+        auth_message: &str,
+    ) -> String {
+        let salted_password = hi(mechanism, password.as_bytes(), salt, iterations);
+        let client_key = hmac(mechanism, &salted_password, b"Client Key");
+        let stored_key = hash(mechanism, &client_key);
+        let client_signature = hmac(mechanism, &stored_key, auth_message.as_bytes());
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(k, s)| k ^ s)
+            .collect();
+        base64::encode(&proof)
+    }
+
+    /// RFC 7677 test vector
+    #[test]
+    fn test_rfc7677_exchange() {
+        let mechanism = ScramMechanism::Sha256;
+        let salt = base64::decode("W22ZaJ0SNY7soEsUEjb6gQ==").expect("salt");
+        let credential = ScramCredential::with_salt(&mechanism, "pencil", salt, 4096);
+
+        let client_first =
+            parse_client_first("n,,n=user,r=rOprNGfwEbeRWgbNEkqO").expect("client first");
+        assert_eq!(client_first.user, "user");
+
+        let mut first = server_first(&mechanism, credential, client_first);
+        // use nonce and server first from the rfc
+        first.nonce = "rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0".to_owned();
+        first.server_first =
+            "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096"
+                .to_owned();
+
+        let server_final = verify_client_final(
+            &first,
+            "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=",
+        )
+        .expect("verify");
+        assert_eq!(server_final, "v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=");
+    }
+
+    #[test]
+    fn test_sha512_round_trip() {
+        let mechanism = ScramMechanism::Sha512;
+        let credential = ScramCredential::generate(&mechanism, "secret", MIN_ITERATIONS);
+        assert!(credential.verify_password(&mechanism, "secret"));
+        assert!(!credential.verify_password(&mechanism, "wrong"));
+
+        let salt = credential.salt.clone();
+        let client_first = parse_client_first("n,,n=alice,r=abcdef").expect("client first");
+        let first = server_first(&mechanism, credential, client_first);
+
+        let without_proof = format!("c=biws,r={}", first.nonce);
+        let auth_message = format!(
+            "{},{},{}",
+            first.client_first_bare, first.server_first, without_proof
+        );
+        let proof = client_proof(&mechanism, "secret", &salt, MIN_ITERATIONS, &auth_message);
+        assert!(verify_client_final(&first, &format!("{},p={}", without_proof, proof)).is_ok());
+
+        let bad_proof = client_proof(&mechanism, "wrong", &salt, MIN_ITERATIONS, &auth_message);
+        assert!(verify_client_final(&first, &format!("{},p={}", without_proof, bad_proof)).is_err());
+    }
+
+    #[test]
+    fn test_parse_client_first() {
+        let client_first = parse_client_first("n,a=admin,n=a=2Cb=3Dc,r=xyz").expect("parse");
+        assert_eq!(client_first.gs2_header, "n,a=admin,");
+        assert_eq!(client_first.user, "a,b=c");
+        assert_eq!(client_first.nonce, "xyz");
+        assert_eq!(client_first.bare, "n=a=2Cb=3Dc,r=xyz");
+
+        assert!(parse_client_first("p=tls-unique,,n=user,r=xyz").is_err());
+        assert!(parse_client_first("n,,r=xyz").is_err());
+        assert!(parse_client_first("n,,n=a=b,r=xyz").is_err());
+    }
+}
@@ -0,0 +1,352 @@
+//!
+//! # SASL Session
+//!
+//! Per connection state machine for SaslHandshake and SaslAuthenticate requests.
+//! Only framed authentication (handshake version 1) is supported.
+//!
+
+use std::io::Error as IoError;
+use std::mem;
+
+use log::debug;
+use log::warn;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+use kf_protocol::message::sasl::KfSaslAuthenticateRequest;
+use kf_protocol::message::sasl::KfSaslAuthenticateResponse;
+use kf_protocol::message::sasl::KfSaslHandshakeRequest;
+use kf_protocol::message::sasl::KfSaslHandshakeResponse;
+
+use super::scram;
+use super::scram::ScramServerFirst;
+use super::SaslConfig;
+use super::SaslCredentialLookup;
+use super::SaslMechanism;
+use super::ScramMechanism;
+
+/// prefix of principal for authenticated users
+pub const USER_PRINCIPAL_PREFIX: &str = "User:";
+
+#[derive(Debug)]
+enum SaslState {
+    Initial,
+    Mechanism(SaslMechanism),
+    ScramFirst(Box<ScramServerFirst>),
+    Authenticated(String),
+    Failed,
+}
+
+#[derive(Debug)]
+pub struct SaslSession {
+    config: SaslConfig,
+    state: SaslState,
+}
+
+impl SaslSession {
+    pub fn new(config: SaslConfig) -> Self {
+        Self {
+            config,
+            state: SaslState::Initial,
+        }
+    }
+
+    /// true if connection may proceed with other requests
+    pub fn is_complete(&self) -> bool {
+        match self.state {
+            SaslState::Authenticated(_) => true,
+            _ => !self.config.enabled,
+        }
+    }
+
+    /// true if authentication failed, connection must be closed
+    pub fn is_failed(&self) -> bool {
+        match self.state {
+            SaslState::Failed => true,
+            _ => false,
+        }
+    }
+
+    /// principal of authenticated user
+    pub fn principal(&self) -> Option<String> {
+        match &self.state {
+            SaslState::Authenticated(user) => Some(format!("{}{}", USER_PRINCIPAL_PREFIX, user)),
+            _ => None,
+        }
+    }
+
+    pub fn handle_handshake(&mut self, request: &KfSaslHandshakeRequest) -> KfSaslHandshakeResponse {
+        let mut response = KfSaslHandshakeResponse::default();
+        response.mechanisms = self.config.mechanism_names();
+
+        match self.state {
+            SaslState::Initial => {}
+            _ => {
+                warn!("unexpected sasl handshake, handshake already received");
+                self.state = SaslState::Failed;
+                response.error_code = ErrorCode::IllegalSaslState;
+                return response;
+            }
+        }
+
+        match SaslMechanism::from_name(&request.mechanism) {
+            Some(ref mechanism) if self.config.mechanisms.contains(mechanism) => {
+                debug!("sasl mechanism selected: {}", mechanism.name());
+                self.state = SaslState::Mechanism(mechanism.clone());
+            }
+            _ => {
+                warn!("unsupported sasl mechanism: {}", request.mechanism);
+                response.error_code = ErrorCode::UnsupportedSaslMechanism;
+            }
+        }
+
+        response
+    }
+
+    pub fn handle_authenticate<L>(
+        &mut self,
+        request: &KfSaslAuthenticateRequest,
+        lookup: &L,
+    ) -> KfSaslAuthenticateResponse
+    where
+        L: SaslCredentialLookup,
+    {
+        let state = mem::replace(&mut self.state, SaslState::Failed);
+        let result = match state {
+            SaslState::Mechanism(SaslMechanism::Plain) => {
+                authenticate_plain(&request.auth_bytes, lookup)
+                    .map(|user| (SaslState::Authenticated(user), vec![]))
+            }
+            SaslState::Mechanism(SaslMechanism::Scram(mechanism)) => {
+                scram_client_first(&mechanism, &request.auth_bytes, lookup).map(|first| {
+                    let bytes = first.server_first().as_bytes().to_vec();
+                    (SaslState::ScramFirst(Box::new(first)), bytes)
+                })
+            }
+            SaslState::ScramFirst(first) => scram_client_final(&first, &request.auth_bytes)
+                .map(|server_final| {
+                    (
+                        SaslState::Authenticated(first.user().to_owned()),
+                        server_final.into_bytes(),
+                    )
+                }),
+            _ => {
+                warn!("unexpected sasl authenticate, no mechanism selected");
+                let mut response = KfSaslAuthenticateResponse::default();
+                response.error_code = ErrorCode::IllegalSaslState;
+                response.error_message = Some("unexpected sasl authenticate request".to_owned());
+                return response;
+            }
+        };
+
+        let mut response = KfSaslAuthenticateResponse::default();
+        match result {
+            Ok((state, auth_bytes)) => {
+                if let SaslState::Authenticated(user) = &state {
+                    debug!("sasl user authenticated: {}", user);
+                }
+                self.state = state;
+                response.auth_bytes = auth_bytes;
+            }
+            Err(err) => {
+                warn!("sasl authentication failed: {}", err);
+                response.error_code = ErrorCode::SaslAuthenticationFailed;
+                response.error_message = Some("Authentication failed".to_owned());
+            }
+        }
+        response
+    }
+}
+
+/// PLAIN message: [authzid] NUL authcid NUL passwd.
+/// Password is verified against any SCRAM credential of the user.
+fn authenticate_plain<L>(bytes: &[u8], lookup: &L) -> Result<String, String>
+where
+    L: SaslCredentialLookup,
+{
+    let message = std::str::from_utf8(bytes).map_err(|_| "invalid plain message".to_owned())?;
+    let parts: Vec<&str> = message.split('\u{0}').collect();
+    if parts.len() != 3 {
+        return Err("invalid plain message".to_owned());
+    }
+    let (authzid, user, password) = (parts[0], parts[1], parts[2]);
+    if !authzid.is_empty() && authzid != user {
+        return Err(format!("authorization id {} does not match user {}", authzid, user));
+    }
+
+    let verified = ScramMechanism::all().iter().any(|mechanism| {
+        lookup
+            .scram_credential(mechanism, user)
+            .map(|credential| credential.verify_password(mechanism, password))
+            .unwrap_or(false)
+    });
+
+    if verified {
+        Ok(user.to_owned())
+    } else {
+        Err(format!("invalid credentials for user: {}", user))
+    }
+}
+
+fn scram_client_first<L>(
+    mechanism: &ScramMechanism,
+    bytes: &[u8],
+    lookup: &L,
+) -> Result<ScramServerFirst, String>
+where
+    L: SaslCredentialLookup,
+{
+    let message = std::str::from_utf8(bytes).map_err(|_| "invalid client first".to_owned())?;
+    let client_first = scram::parse_client_first(message)?;
+    let credential = lookup
+        .scram_credential(mechanism, &client_first.user)
+        .ok_or_else(|| format!("unknown user: {}", client_first.user))?;
+
+    Ok(scram::server_first(mechanism, credential, client_first))
+}
+
+fn scram_client_final(first: &ScramServerFirst, bytes: &[u8]) -> Result<String, String> {
+    let message = std::str::from_utf8(bytes).map_err(|_| "invalid client final".to_owned())?;
+    scram::verify_client_final(first, message)
+}
+
+/// handle handshake request, used with `call_service!`
+pub async fn handle_sasl_handshake_request(
+    request: RequestMessage<KfSaslHandshakeRequest>,
+    session: &mut SaslSession,
+) -> Result<ResponseMessage<KfSaslHandshakeResponse>, IoError> {
+    let response = session.handle_handshake(request.request());
+    Ok(request.new_response(response))
+}
+
+/// handle authenticate request, used with `call_service!`
+pub async fn handle_sasl_authenticate_request<L>(
+    request: RequestMessage<KfSaslAuthenticateRequest>,
+    session: &mut SaslSession,
+    lookup: &L,
+) -> Result<ResponseMessage<KfSaslAuthenticateResponse>, IoError>
+where
+    L: SaslCredentialLookup,
+{
+    let response = session.handle_authenticate(request.request(), lookup);
+    Ok(request.new_response(response))
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::sasl::ScramCredential;
+
+    struct TestCredentials(HashMap<String, ScramCredential>);
+
+    impl SaslCredentialLookup for TestCredentials {
+        fn scram_credential(&self, mechanism: &ScramMechanism, user: &str) -> Option<ScramCredential> {
+            match mechanism {
+                ScramMechanism::Sha256 => self.0.get(user).cloned(),
+                ScramMechanism::Sha512 => None,
+            }
+        }
+    }
+
+    fn credentials() -> TestCredentials {
+        let mut users = HashMap::new();
+        users.insert(
+            "alice".to_owned(),
+            ScramCredential::generate(&ScramMechanism::Sha256, "secret", 4096),
+        );
+        TestCredentials(users)
+    }
+
+    fn enabled_session() -> SaslSession {
+        SaslSession::new(SaslConfig {
+            enabled: true,
+            ..Default::default()
+        })
+    }
+
+    fn handshake(session: &mut SaslSession, mechanism: &str) -> ErrorCode {
+        session
+            .handle_handshake(&KfSaslHandshakeRequest {
+                mechanism: mechanism.to_owned(),
+            })
+            .error_code
+    }
+
+    fn authenticate(session: &mut SaslSession, bytes: &[u8]) -> KfSaslAuthenticateResponse {
+        session.handle_authenticate(
+            &KfSaslAuthenticateRequest {
+                auth_bytes: bytes.to_vec(),
+            },
+            &credentials(),
+        )
+    }
+
+    #[test]
+    fn test_disabled_session_is_complete() {
+        let session = SaslSession::new(SaslConfig::default());
+        assert!(session.is_complete());
+        assert!(session.principal().is_none());
+        assert!(!enabled_session().is_complete());
+    }
+
+    #[test]
+    fn test_plain_authentication() {
+        let mut session = enabled_session();
+        assert_eq!(handshake(&mut session, "PLAIN"), ErrorCode::None);
+        let response = authenticate(&mut session, b"\0alice\0secret");
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert!(session.is_complete());
+        assert_eq!(session.principal(), Some("User:alice".to_owned()));
+
+        let mut session = enabled_session();
+        handshake(&mut session, "PLAIN");
+        let response = authenticate(&mut session, b"\0alice\0wrong");
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+        assert!(session.is_failed());
+        assert!(!session.is_complete());
+    }
+
+    #[test]
+    fn test_unsupported_mechanism() {
+        let mut session = SaslSession::new(SaslConfig {
+            enabled: true,
+            mechanisms: vec![SaslMechanism::Scram(ScramMechanism::Sha256)],
+        });
+        assert_eq!(handshake(&mut session, "PLAIN"), ErrorCode::UnsupportedSaslMechanism);
+        assert_eq!(handshake(&mut session, "GSSAPI"), ErrorCode::UnsupportedSaslMechanism);
+        assert_eq!(handshake(&mut session, "SCRAM-SHA-256"), ErrorCode::None);
+        assert_eq!(handshake(&mut session, "SCRAM-SHA-256"), ErrorCode::IllegalSaslState);
+    }
+
+    #[test]
+    fn test_scram_unknown_user() {
+        let mut session = enabled_session();
+        handshake(&mut session, "SCRAM-SHA-256");
+        let response = authenticate(&mut session, b"n,,n=bob,r=abc");
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+        assert!(session.is_failed());
+    }
+
+    #[test]
+    fn test_scram_first_message() {
+        let mut session = enabled_session();
+        handshake(&mut session, "SCRAM-SHA-256");
+        let response = authenticate(&mut session, b"n,,n=alice,r=abc");
+        assert_eq!(response.error_code, ErrorCode::None);
+        let server_first = String::from_utf8(response.auth_bytes).expect("utf8");
+        assert!(server_first.starts_with("r=abc"));
+        assert!(server_first.ends_with(",i=4096"));
+        assert!(!session.is_complete());
+
+        // wrong proof fails
+        let nonce = &server_first[2..server_first.find(",s=").expect("salt")];
+        let final_message = format!("c=biws,r={},p=AAAA", nonce);
+        let response = authenticate(&mut session, final_message.as_bytes());
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+        assert!(session.is_failed());
+    }
+}
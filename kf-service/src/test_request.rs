@@ -1,10 +1,17 @@
 use std::sync::Arc;
 use std::io::Error as IoError;
 use std::convert::TryInto;
+use std::time::Duration;
 
+use log::trace;
+use futures::select;
+use futures::stream::Stream;
+use futures::stream::StreamExt;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 
+use future_helper::sleep;
+
 use kf_protocol::api::KfRequestMessage;
 use kf_protocol::api::RequestMessage;
 use kf_protocol::api::ResponseMessage;
@@ -15,6 +22,7 @@ use kf_protocol::derive::Decode;
 use kf_protocol::derive::Encode;
 use kf_protocol::api::Request;
 use kf_socket::KfSocket;
+use kf_socket::KfSink;
 use kf_socket::KfSocketError;
 
 use crate::KfService;
@@ -26,7 +34,8 @@ use crate::api_loop;
 #[repr(u16)]
 pub(crate) enum TestKafkaApiEnum {
     Echo = 1000,
-    Save = 1001
+    Save = 1001,
+    Watch = 1002
 }
 
 impl Default for TestKafkaApiEnum {
@@ -76,10 +85,27 @@ impl Request for SaveRequest{
 pub(crate) struct SaveResponse{}
 
 
+/// change is pushed after this delay, longer than idle timeout of test server
+pub(crate) const WATCH_CHANGE_DELAY_MS: u64 = 300;
+
+#[derive(Decode,Encode,Debug,Default)]
+pub(crate) struct WatchRequest {}
+impl Request for WatchRequest{
+    const API_KEY: u16 =  TestKafkaApiEnum::Watch as u16;
+    type Response = WatchResponse;
+}
+
+#[derive(Decode,Encode,Debug,Default)]
+pub(crate) struct WatchResponse{
+    pub msg: String
+}
+
+
 #[derive(Debug,Encode)]
 pub(crate) enum TestApiRequest {
     EchoRequest(RequestMessage<EchoRequest>),
-    SaveRequest(RequestMessage<SaveRequest>)
+    SaveRequest(RequestMessage<SaveRequest>),
+    WatchRequest(RequestMessage<WatchRequest>)
 }
 
 // Added to satisfy Encode/Decode traits
@@ -102,7 +128,8 @@ impl KfRequestMessage for TestApiRequest {
     {
         match header.api_key().try_into()? {
             TestKafkaApiEnum::Echo => api_decode!(TestApiRequest,EchoRequest,src,header),
-            TestKafkaApiEnum::Save => api_decode!(TestApiRequest,SaveRequest,src,header)
+            TestKafkaApiEnum::Save => api_decode!(TestApiRequest,SaveRequest,src,header),
+            TestKafkaApiEnum::Watch => api_decode!(TestApiRequest,WatchRequest,src,header)
         }
     }
 }
@@ -133,6 +160,7 @@ impl TestService {
     async fn handle(self: Arc<Self>,  _context: SharedTestContext, socket: KfSocket) -> Result<(),KfSocketError> {
 
         let (mut sink,mut stream) = socket.split();
+        let idle_timeout = stream.idle_timeout_handle();
         let mut api_stream = stream.api_stream::<TestApiRequest,TestKafkaApiEnum>();
 
         api_loop!(
@@ -147,6 +175,10 @@ impl TestService {
                 drop(api_stream);
                 let _orig_socket: KfSocket  = (sink,stream).into();
                 break;
+            },
+            TestApiRequest::WatchRequest(request) => {
+                idle_timeout.disable();
+                return handle_watch_request(request, &mut sink, &mut api_stream).await;
             }
         );
 
@@ -167,6 +199,36 @@ async fn handle_echo_request(
 
 
 
+/// respond with snapshot, then push change unless client has terminated
+async fn handle_watch_request<S>(
+    request: RequestMessage<WatchRequest>,
+    sink: &mut KfSink,
+    api_stream: &mut S,
+) -> Result<(),KfSocketError>
+    where S: Stream<Item=Result<TestApiRequest,KfSocketError>> + Unpin
+{
+    let version = request.header.api_version();
+    let mut response = WatchResponse::default();
+    response.msg = "snapshot".to_owned();
+    let resp_msg = request.new_response(response);
+    sink.send_response(&resp_msg, version).await?;
+
+    select! {
+        _ = (sleep(Duration::from_millis(WATCH_CHANGE_DELAY_MS))).fuse() => {
+            let mut response = WatchResponse::default();
+            response.msg = "change".to_owned();
+            let resp_msg = request.new_response(response);
+            sink.send_response(&resp_msg, version).await?;
+        },
+        _ = api_stream.next().fuse() => {
+            trace!("watch client terminated");
+        }
+    }
+
+    Ok(())
+}
+
+
 impl KfService for TestService {
     type Context = SharedTestContext;
     type Request = TestApiRequest;
@@ -9,6 +9,8 @@ mod sink;
 mod sink_pool;
 mod file_fetch;
 mod file_produce;
+mod multiplexing;
+mod limits;
 
 #[cfg(test)]
 pub mod test_request;
@@ -21,6 +23,9 @@ pub use sink_pool::SharedSinkPool;
 pub use stream::KfStream;
 pub use sink::KfSink;
 pub use sink::ExclusiveKfSink;
+pub use multiplexing::MultiplexerSocket;
+pub use limits::ConnectionLimits;
+pub use limits::IdleTimeoutHandle;
 pub use file_fetch::FilePartitionResponse;
 pub use file_fetch::FileFetchResponse;
 pub use file_fetch::FileTopicResponse;
@@ -0,0 +1,176 @@
+//!
+//! # Connection Limits
+//!
+//! Safeguards for requests received on server connection. Idle connection is closed by ending
+//! request stream, unless idle timeout is disabled through IdleTimeoutHandle. Request rate is limited by
+//! pausing reads, so client is slowed down by TCP back pressure.
+//!
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use log::debug;
+use log::warn;
+use futures::Stream;
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+
+use future_helper::sleep;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ConnectionLimits {
+    /// connection is closed when no request arrives within this time
+    pub idle_timeout: Option<Duration>,
+    /// reading is paused while requests exceed this rate
+    pub max_requests_per_sec: Option<u32>,
+}
+
+/// switch shared with request stream to stop closing idle connection.
+/// used by connections which are quiet by design, such as watches waiting for changes
+#[derive(Debug, Clone, Default)]
+pub struct IdleTimeoutHandle(Arc<AtomicBool>);
+
+impl IdleTimeoutHandle {
+    /// connection is no longer closed for being idle
+    pub fn disable(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// token bucket holding at most one second worth of requests
+#[derive(Debug)]
+struct RateLimiter {
+    rate: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl RateLimiter {
+    fn new(max_requests_per_sec: u32, now: Instant) -> Self {
+        let rate = f64::from(max_requests_per_sec.max(1));
+        Self {
+            rate,
+            tokens: rate,
+            last: now,
+        }
+    }
+
+    /// take token for request, return how long to wait before next request can be read
+    fn acquire(&mut self, now: Instant) -> Option<Duration> {
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate) - 1.0;
+        if self.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(-self.tokens / self.rate))
+        }
+    }
+}
+
+/// request stream with connection limits applied
+pub struct LimitedStream<'a, S> {
+    inner: &'a mut S,
+    idle_timeout: Option<Duration>,
+    idle_handle: IdleTimeoutHandle,
+    idle: Option<BoxFuture<'static, ()>>,
+    rate: Option<RateLimiter>,
+    throttle: Option<BoxFuture<'static, ()>>,
+}
+
+impl<'a, S> LimitedStream<'a, S> {
+    pub fn new(inner: &'a mut S, limits: ConnectionLimits, idle_handle: IdleTimeoutHandle) -> Self {
+        Self {
+            inner,
+            idle_timeout: limits.idle_timeout,
+            idle_handle,
+            idle: None,
+            rate: limits
+                .max_requests_per_sec
+                .map(|rate| RateLimiter::new(rate, Instant::now())),
+            throttle: None,
+        }
+    }
+}
+
+impl<'a, S> Stream for LimitedStream<'a, S>
+where
+    S: Stream + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if let Some(throttle) = this.throttle.as_mut() {
+            match throttle.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(()) => this.throttle = None,
+            }
+        }
+
+        match Pin::new(&mut *this.inner).poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                // idle time is only counted while waiting for next request
+                this.idle = None;
+                if let Some(rate) = this.rate.as_mut() {
+                    if let Some(wait) = rate.acquire(Instant::now()) {
+                        debug!("request rate exceeded, pausing for: {:?}", wait);
+                        this.throttle = Some(sleep(wait).boxed());
+                    }
+                }
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => {
+                if let Some(timeout) = this.idle_timeout {
+                    if this.idle_handle.is_disabled() {
+                        this.idle = None;
+                        return Poll::Pending;
+                    }
+                    let idle = this.idle.get_or_insert_with(|| sleep(timeout).boxed());
+                    if let Poll::Ready(()) = idle.as_mut().poll(cx) {
+                        warn!("no request for: {:?}, closing idle connection", timeout);
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn test_rate_limiter() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(2, start);
+
+        // burst up to rate
+        assert_eq!(limiter.acquire(start), None);
+        assert_eq!(limiter.acquire(start), None);
+
+        // then one request per half second
+        let wait = limiter.acquire(start).expect("wait");
+        assert_eq!(wait, Duration::from_millis(500));
+
+        let later = start + Duration::from_secs(10);
+        assert_eq!(limiter.acquire(later), None);
+    }
+}
@@ -0,0 +1,303 @@
+//!
+//! # Multiplexed Socket
+//!
+//! Many requests can be in flight over single connection. Each request gets its own correlation id
+//! and background reader matches responses to pending requests, so responses may complete in any order.
+//! Requests are written in order they acquire sink, which is order server processes them,
+//! so Kafka ordering guarantee per connection still holds.
+//!
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicI32;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use log::debug;
+use log::error;
+use log::trace;
+use futures::channel::oneshot;
+use futures::future::FutureExt;
+use futures::select;
+use futures::stream::StreamExt;
+
+use future_aio::BytesMut;
+use future_helper::sleep;
+use future_helper::spawn;
+use kf_protocol::Decoder;
+use kf_protocol::api::Request;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+
+use crate::ExclusiveKfSink;
+use crate::KfSocket;
+use crate::KfSocketError;
+use crate::KfStream;
+
+/// pending requests by correlation id, never locked across await
+type Senders = Arc<Mutex<HashMap<i32, oneshot::Sender<BytesMut>>>>;
+
+pub struct MultiplexerSocket {
+    correlation_id: AtomicI32,
+    sink: ExclusiveKfSink,
+    senders: Senders,
+    terminated: Arc<AtomicBool>,
+}
+
+impl MultiplexerSocket {
+    /// take over socket, responses are read by background task
+    pub fn new(socket: KfSocket) -> Self {
+        let (sink, stream) = socket.split();
+        let senders: Senders = Arc::new(Mutex::new(HashMap::new()));
+        let terminated = Arc::new(AtomicBool::new(false));
+
+        spawn(dispatch_responses(stream, senders.clone(), terminated.clone()));
+
+        Self {
+            correlation_id: AtomicI32::new(1),
+            sink: ExclusiveKfSink::new(sink),
+            senders,
+            terminated,
+        }
+    }
+
+    /// connection has been closed, no more responses will be received
+    pub fn is_terminated(&self) -> bool {
+        self.terminated.load(Ordering::SeqCst)
+    }
+
+    /// number of requests waiting for response
+    pub fn pending_count(&self) -> usize {
+        self.senders.lock().map(|senders| senders.len()).unwrap_or(0)
+    }
+
+    pub async fn send_and_receive<R>(
+        &self,
+        req_msg: RequestMessage<R>,
+    ) -> Result<ResponseMessage<R::Response>, KfSocketError>
+    where
+        R: Request,
+    {
+        self.send_with_timeout(req_msg, None).await
+    }
+
+    /// send request and wait for its response. correlation id of request is replaced.
+    /// request is forgotten if it times out or this future is dropped, late response is discarded
+    pub async fn send_with_timeout<R>(
+        &self,
+        mut req_msg: RequestMessage<R>,
+        timeout: Option<Duration>,
+    ) -> Result<ResponseMessage<R::Response>, KfSocketError>
+    where
+        R: Request,
+    {
+        let correlation_id = self.correlation_id.fetch_add(1, Ordering::SeqCst);
+        req_msg.get_mut_header().set_correlation_id(correlation_id);
+
+        let (sender, receiver) = oneshot::channel();
+        let _pending = PendingRequest::new(correlation_id, self.senders.clone(), sender);
+
+        // reader marks termination before clearing senders, so request registered after that is caught here
+        if self.is_terminated() {
+            return Err(connection_closed());
+        }
+
+        trace!("sending request with correlation id: {}", correlation_id);
+        self.sink.send_request(&req_msg).await?;
+
+        let bytes = match timeout {
+            Some(timeout) => select! {
+                bytes = receiver.fuse() => bytes,
+                _ = (sleep(timeout)).fuse() => {
+                    debug!("request with correlation id: {} timed out", correlation_id);
+                    return Err(KfSocketError::IoError(IoError::new(
+                        ErrorKind::TimedOut,
+                        format!("no response for correlation id: {}", correlation_id),
+                    )));
+                }
+            },
+            None => receiver.await,
+        };
+        let bytes = bytes.map_err(|_| connection_closed())?;
+
+        let response =
+            req_msg.decode_response(&mut Cursor::new(&bytes), req_msg.header.api_version())?;
+        Ok(response)
+    }
+}
+
+/// removes pending request when request is completed, timed out or cancelled
+struct PendingRequest {
+    correlation_id: i32,
+    senders: Senders,
+}
+
+impl PendingRequest {
+    fn new(correlation_id: i32, senders: Senders, sender: oneshot::Sender<BytesMut>) -> Self {
+        if let Ok(mut pending) = senders.lock() {
+            pending.insert(correlation_id, sender);
+        }
+        Self {
+            correlation_id,
+            senders,
+        }
+    }
+}
+
+impl Drop for PendingRequest {
+    fn drop(&mut self) {
+        if let Ok(mut pending) = self.senders.lock() {
+            pending.remove(&self.correlation_id);
+        }
+    }
+}
+
+fn connection_closed() -> KfSocketError {
+    KfSocketError::IoError(IoError::new(
+        ErrorKind::UnexpectedEof,
+        "server has terminated connection",
+    ))
+}
+
+/// route each response to request with same correlation id
+async fn dispatch_responses(mut stream: KfStream, senders: Senders, terminated: Arc<AtomicBool>) {
+    let tcp_stream = stream.get_mut_tcp_stream();
+    while let Some(result) = tcp_stream.next().await {
+        match result {
+            Ok(bytes) => {
+                let mut correlation_id: i32 = 0;
+                if let Err(err) = correlation_id.decode(&mut Cursor::new(&bytes), 0) {
+                    error!("invalid response header: {}", err);
+                    break;
+                }
+
+                let sender = senders
+                    .lock()
+                    .ok()
+                    .and_then(|mut pending| pending.remove(&correlation_id));
+                match sender {
+                    Some(sender) => {
+                        trace!("received response for correlation id: {}", correlation_id);
+                        let _ = sender.send(bytes);
+                    }
+                    None => debug!(
+                        "no pending request for correlation id: {}, dropping response",
+                        correlation_id
+                    ),
+                }
+            }
+            Err(err) => {
+                error!("error receiving response: {}", err);
+                break;
+            }
+        }
+    }
+
+    debug!("multiplexed connection terminated");
+    terminated.store(true, Ordering::SeqCst);
+    // dropping senders completes waiting requests with error
+    if let Ok(mut pending) = senders.lock() {
+        pending.clear();
+    };
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    use futures::future::join;
+    use futures::stream::StreamExt;
+    use log::debug;
+
+    use future_aio::net::AsyncTcpListener;
+    use future_helper::sleep;
+    use future_helper::test_async;
+    use kf_protocol::api::RequestMessage;
+
+    use super::MultiplexerSocket;
+    use crate::KfSocket;
+    use crate::KfSocketError;
+    use crate::test_request::EchoRequest;
+    use crate::test_request::EchoResponse;
+
+    /// read requests, then respond in reverse order
+    async fn reverse_server(addr: SocketAddr, count: usize) -> Result<(), KfSocketError> {
+        let listener = AsyncTcpListener::bind(&addr)?;
+        let mut incoming = listener.incoming();
+        if let Some(stream) = incoming.next().await {
+            let mut socket: KfSocket = stream?.into();
+            let mut requests = vec![];
+            for _ in 0..count {
+                let req_msg: RequestMessage<EchoRequest> = socket
+                    .get_mut_stream()
+                    .next_request_item()
+                    .await
+                    .expect("request")?;
+                debug!("server: received: {}", req_msg.request.msg);
+                requests.push(req_msg);
+            }
+
+            for req_msg in requests.into_iter().rev() {
+                let resp_msg = req_msg.new_response(EchoResponse::new(req_msg.request.msg.clone()));
+                socket.get_mut_sink().send_response(&resp_msg, 0).await?;
+            }
+
+            // keep connection until client is done
+            sleep(Duration::from_millis(200)).await;
+        }
+        Ok(())
+    }
+
+    async fn echo(socket: &MultiplexerSocket, msg: &str, timeout: Option<Duration>) -> Result<String, KfSocketError> {
+        let req_msg = RequestMessage::new_request(EchoRequest::new(msg.to_owned()));
+        let response = socket.send_with_timeout(req_msg, timeout).await?;
+        Ok(response.response.msg)
+    }
+
+    async fn out_of_order_client(addr: SocketAddr) -> Result<(), KfSocketError> {
+        sleep(Duration::from_millis(10)).await;
+        let socket = MultiplexerSocket::new(KfSocket::connect(&addr).await?);
+
+        let (first, second) = join(echo(&socket, "first", None), echo(&socket, "second", None)).await;
+        assert_eq!(first?, "first");
+        assert_eq!(second?, "second");
+        assert_eq!(socket.pending_count(), 0);
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_multiplexing_out_of_order() -> Result<(), KfSocketError> {
+        let addr = "127.0.0.1:20010".parse::<SocketAddr>().expect("parse");
+        let (server, client) = join(reverse_server(addr, 2), out_of_order_client(addr)).await;
+        server?;
+        client
+    }
+
+    async fn timeout_client(addr: SocketAddr) -> Result<(), KfSocketError> {
+        sleep(Duration::from_millis(10)).await;
+        let socket = MultiplexerSocket::new(KfSocket::connect(&addr).await?);
+
+        // server waits for 2 requests before responding, so first one times out
+        let result = echo(&socket, "lost", Some(Duration::from_millis(50))).await;
+        assert!(result.is_err());
+        assert_eq!(socket.pending_count(), 0);
+
+        // late response of timed out request is dropped
+        assert_eq!(echo(&socket, "found", None).await?, "found");
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_multiplexing_timeout() -> Result<(), KfSocketError> {
+        let addr = "127.0.0.1:20011".parse::<SocketAddr>().expect("parse");
+        let (server, client) = join(reverse_server(addr, 2), timeout_client(addr)).await;
+        server?;
+        client
+    }
+}
@@ -14,6 +14,7 @@ use pin_utils::unsafe_pinned;
 use kf_protocol::api::Request;
 use kf_protocol::api::RequestMessage;
 use kf_protocol::api::ResponseMessage;
+use kf_protocol::transport::KfCodec;
 
 
 use future_aio::net::AsyncTcpStream;
@@ -29,6 +30,7 @@ pub struct KfSocket {
     sink: KfSink,
     stream: KfStream,
     stale: bool,
+    peer_addr: Option<SocketAddr>,
 }
 
 unsafe impl Sync for KfSocket {}
@@ -39,6 +41,7 @@ impl KfSocket {
             sink,
             stream,
             stale: false,
+            peer_addr: None,
         }
     }
 
@@ -56,6 +59,24 @@ impl KfSocket {
     }
     
 
+    /// create socket from accepted connection, frames are decoded by given codec
+    pub fn from_stream(tcp_stream: AsyncTcpStream, codec: KfCodec) -> Self {
+        let fd = tcp_stream.as_raw_fd();
+        let peer_addr = tcp_stream.peer_addr().ok();
+        let (sink, stream) = tcp_stream.split_with_codec(codec).as_tuple();
+        KfSocket {
+            sink: KfSink::new(sink, fd),
+            stream: stream.into(),
+            stale: false,
+            peer_addr,
+        }
+    }
+
+    /// address of remote end, if known
+    pub fn peer_addr(&self) -> Option<&SocketAddr> {
+        self.peer_addr.as_ref()
+    }
+
     pub fn split(self) -> (KfSink, KfStream) {
         (self.sink, self.stream)
     }
@@ -93,13 +114,7 @@ impl KfSocket {
 
 impl From<AsyncTcpStream> for KfSocket {
     fn from(tcp_stream: AsyncTcpStream) -> Self {
-        let fd = tcp_stream.as_raw_fd();
-        let (sink, stream) = tcp_stream.split().as_tuple();
-        KfSocket {
-            sink: KfSink::new(sink, fd),
-            stream: stream.into(),
-            stale: false,
-        }
+        Self::from_stream(tcp_stream, KfCodec::default())
     }
 }
 
@@ -18,14 +18,31 @@ use kf_protocol::api::KfRequestMessage;
 use future_aio::net::TcpStreamSplitStream;
 
 use crate::KfSocketError;
+use crate::ConnectionLimits;
+use crate::limits::LimitedStream;
+use crate::limits::IdleTimeoutHandle;
 
 #[derive(Debug)]
-pub struct KfStream(TcpStreamSplitStream<KfCodec>);
+pub struct KfStream {
+    inner: TcpStreamSplitStream<KfCodec>,
+    limits: ConnectionLimits,
+    idle_handle: IdleTimeoutHandle,
+}
 
 impl KfStream {
 
     pub fn get_mut_tcp_stream(&mut self) -> &mut TcpStreamSplitStream<KfCodec> {
-        &mut self.0
+        &mut self.inner
+    }
+
+    /// limits applied to request and api streams
+    pub fn set_limits(&mut self, limits: ConnectionLimits) {
+        self.limits = limits;
+    }
+
+    /// handle to stop idle timeout while request or api stream is borrowed
+    pub fn idle_timeout_handle(&self) -> IdleTimeoutHandle {
+        self.idle_handle.clone()
     }
 
     /// as server, get stream of request coming from client 
@@ -33,7 +50,7 @@ impl KfStream {
         where  
             RequestMessage<R>: KfDecoder + Debug
     {
-        (&mut self.0).map( | req_bytes_r | {
+        LimitedStream::new(&mut self.inner, self.limits, self.idle_handle.clone()).map( | req_bytes_r | {
             
             match req_bytes_r {
                 Ok(req_bytes) => {
@@ -63,7 +80,7 @@ impl KfStream {
     {
 
         trace!("waiting for response");
-        let next = self.0.next().await;
+        let next = self.inner.next().await;
         if let Some(result) = next {
             match result {
                 Ok(req_bytes) => {
@@ -94,7 +111,7 @@ impl KfStream {
             R: KfRequestMessage<ApiKey=A>,
             A: KfDecoder+ Debug
     {
-        (&mut self.0).map(|req_bytes_r| {
+        LimitedStream::new(&mut self.inner, self.limits, self.idle_handle.clone()).map(|req_bytes_r| {
 
             match req_bytes_r {
                 Ok(req_bytes) => {
@@ -123,6 +140,10 @@ impl KfStream {
 
 impl From<TcpStreamSplitStream<KfCodec>> for KfStream {
     fn from(stream: TcpStreamSplitStream<KfCodec>) -> Self {
-        KfStream(stream)
+        KfStream {
+            inner: stream,
+            limits: ConnectionLimits::default(),
+            idle_handle: IdleTimeoutHandle::default(),
+        }
     }
 }
\ No newline at end of file
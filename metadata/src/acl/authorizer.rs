@@ -0,0 +1,280 @@
+//!
+//! # ACL Authorizer
+//!
+//! Authorization check shared by SC and SPU. Authorization is opt-in: unless
+//! `enabled` is set, every request is allowed. Super users are always allowed.
+//! Resource without any ACL is denied, unless `allow_everyone_if_no_acl_found` is set.
+//! Once a resource has ACLs, deny wins over allow and the principal must be
+//! allowed explicitly (or through an implied operation).
+//!
+use super::AclSpec;
+use super::AclResourceType;
+use super::AclOperation;
+
+/// principal of connections that have not authenticated
+pub const ANONYMOUS_PRINCIPAL: &'static str = "User:ANONYMOUS";
+
+/// principal and host of a connection
+#[derive(Debug, Clone, PartialEq)]
+pub struct AclIdentity {
+    pub principal: String,
+    pub host: String,
+}
+
+impl AclIdentity {
+    pub fn new<P, H>(principal: P, host: H) -> Self
+    where
+        P: Into<String>,
+        H: Into<String>,
+    {
+        Self {
+            principal: principal.into(),
+            host: host.into(),
+        }
+    }
+
+    pub fn anonymous<H>(host: H) -> Self
+    where
+        H: Into<String>,
+    {
+        Self::new(ANONYMOUS_PRINCIPAL, host)
+    }
+}
+
+/// authorizer settings, default allows everything (authorizer disabled)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuthorizerConfig {
+    /// enforce ACLs, everything is allowed when disabled
+    pub enabled: bool,
+    /// principals allowed to perform any operation, e.g. "User:admin"
+    pub super_users: Vec<String>,
+    /// allow all principals on resources without ACLs
+    pub allow_everyone_if_no_acl_found: bool,
+}
+
+impl AuthorizerConfig {
+    pub fn is_super_user(&self, principal: &str) -> bool {
+        self.super_users.iter().any(|user| user == principal)
+    }
+}
+
+/// check if identity may perform operation on resource
+pub fn authorize<'a, I>(
+    acls: I,
+    config: &AuthorizerConfig,
+    identity: &AclIdentity,
+    resource_type: &AclResourceType,
+    resource_name: &str,
+    operation: &AclOperation,
+) -> bool
+where
+    I: IntoIterator<Item = &'a AclSpec>,
+{
+    authorize_with(
+        acls,
+        config,
+        config.allow_everyone_if_no_acl_found,
+        identity,
+        resource_type,
+        resource_name,
+        operation,
+    )
+}
+
+/// check if identity is super user or allowed by ACL on resource.
+/// resource without ACLs is always denied, used for operations that change ACLs
+pub fn authorize_explicit<'a, I>(
+    acls: I,
+    config: &AuthorizerConfig,
+    identity: &AclIdentity,
+    resource_type: &AclResourceType,
+    resource_name: &str,
+    operation: &AclOperation,
+) -> bool
+where
+    I: IntoIterator<Item = &'a AclSpec>,
+{
+    authorize_with(acls, config, false, identity, resource_type, resource_name, operation)
+}
+
+fn authorize_with<'a, I>(
+    acls: I,
+    config: &AuthorizerConfig,
+    allow_if_no_acl: bool,
+    identity: &AclIdentity,
+    resource_type: &AclResourceType,
+    resource_name: &str,
+    operation: &AclOperation,
+) -> bool
+where
+    I: IntoIterator<Item = &'a AclSpec>,
+{
+    if !config.enabled || config.is_super_user(&identity.principal) {
+        return true;
+    }
+
+    let resource_acls: Vec<&AclSpec> = acls
+        .into_iter()
+        .filter(|acl| acl.matches_resource(resource_type, resource_name))
+        .collect();
+
+    if resource_acls.is_empty() {
+        return allow_if_no_acl;
+    }
+
+    let identity_acls: Vec<&&AclSpec> = resource_acls
+        .iter()
+        .filter(|acl| acl.matches_principal(&identity.principal, &identity.host))
+        .collect();
+
+    let denied = identity_acls
+        .iter()
+        .any(|acl| !acl.is_allow() && acl.matches_operation(operation));
+    if denied {
+        return false;
+    }
+
+    let implied = operation.implied_by();
+    identity_acls.iter().any(|acl| {
+        acl.is_allow()
+            && (acl.matches_operation(operation)
+                || implied.iter().any(|op| acl.matches_operation(op)))
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::super::AclSpec;
+    use super::super::AclResourceType;
+    use super::super::AclPatternType;
+    use super::super::AclOperation;
+    use super::super::AclPermission;
+    use super::authorize;
+    use super::authorize_explicit;
+    use super::AclIdentity;
+    use super::AuthorizerConfig;
+
+    fn topic_acl(
+        principal: &str,
+        pattern_type: AclPatternType,
+        name: &str,
+        operation: AclOperation,
+        permission: AclPermission,
+    ) -> AclSpec {
+        AclSpec {
+            principal: principal.to_owned(),
+            host: "*".to_owned(),
+            resource_type: AclResourceType::Topic,
+            pattern_type,
+            resource_name: name.to_owned(),
+            operation,
+            permission,
+        }
+    }
+
+    #[test]
+    fn test_authorize_topic() {
+        let alice = AclIdentity::new("User:alice", "10.0.0.1");
+        let bob = AclIdentity::new("User:bob", "10.0.0.2");
+        let acls = vec![
+            topic_acl(
+                "User:alice",
+                AclPatternType::Prefixed,
+                "orders",
+                AclOperation::Write,
+                AclPermission::Allow,
+            ),
+            topic_acl(
+                "User:*",
+                AclPatternType::Literal,
+                "orders-eu",
+                AclOperation::All,
+                AclPermission::Deny,
+            ),
+        ];
+
+        let topic = AclResourceType::Topic;
+        let config = AuthorizerConfig {
+            enabled: true,
+            ..Default::default()
+        };
+
+        // no acl on resource, denied by default
+        assert!(!authorize(&acls, &config, &bob, &topic, "payments", &AclOperation::Read));
+
+        // allowed by prefix, describe implied by write
+        assert!(authorize(&acls, &config, &alice, &topic, "orders-us", &AclOperation::Write));
+        assert!(authorize(&acls, &config, &alice, &topic, "orders-us", &AclOperation::Describe));
+        assert!(!authorize(&acls, &config, &alice, &topic, "orders-us", &AclOperation::Read));
+
+        // not allowed principal
+        assert!(!authorize(&acls, &config, &bob, &topic, "orders-us", &AclOperation::Write));
+
+        // deny wins over allow
+        assert!(!authorize(&acls, &config, &alice, &topic, "orders-eu", &AclOperation::Write));
+    }
+
+    #[test]
+    fn test_authorize_config() {
+        let admin = AclIdentity::new("User:admin", "10.0.0.1");
+        let bob = AclIdentity::new("User:bob", "10.0.0.2");
+        let acls = vec![topic_acl(
+            "User:*",
+            AclPatternType::Literal,
+            "orders",
+            AclOperation::All,
+            AclPermission::Deny,
+        )];
+        let topic = AclResourceType::Topic;
+        let config = AuthorizerConfig {
+            enabled: true,
+            super_users: vec!["User:admin".to_owned()],
+            allow_everyone_if_no_acl_found: true,
+        };
+
+        // super user skips acls
+        assert!(authorize(&acls, &config, &admin, &topic, "orders", &AclOperation::Write));
+        assert!(!authorize(&acls, &config, &bob, &topic, "orders", &AclOperation::Write));
+
+        // resource without acl is open only if configured, never for explicit check
+        assert!(authorize(&acls, &config, &bob, &topic, "payments", &AclOperation::Write));
+        assert!(!authorize_explicit(&acls, &config, &bob, &topic, "payments", &AclOperation::Write));
+        assert!(authorize_explicit(&acls, &config, &admin, &topic, "payments", &AclOperation::Write));
+    }
+
+    #[test]
+    fn test_authorize_disabled() {
+        let anonymous = AclIdentity::anonymous("10.0.0.1");
+        let acls = vec![topic_acl(
+            "User:*",
+            AclPatternType::Literal,
+            "orders",
+            AclOperation::All,
+            AclPermission::Deny,
+        )];
+        let topic = AclResourceType::Topic;
+        let config = AuthorizerConfig::default();
+
+        // disabled authorizer ignores acls
+        assert!(authorize(&acls, &config, &anonymous, &topic, "payments", &AclOperation::Create));
+        assert!(authorize(&acls, &config, &anonymous, &topic, "orders", &AclOperation::Read));
+        assert!(authorize_explicit(&acls, &config, &anonymous, &topic, "payments", &AclOperation::Alter));
+    }
+
+    #[test]
+    fn test_acl_key() {
+        let acl = topic_acl(
+            "User:alice",
+            AclPatternType::Literal,
+            "orders",
+            AclOperation::Read,
+            AclPermission::Allow,
+        );
+        let mut other = acl.clone();
+        assert_eq!(acl.key(), other.key());
+        other.operation = AclOperation::Write;
+        assert_ne!(acl.key(), other.key());
+        assert!(acl.key().starts_with("acl-"));
+    }
+}
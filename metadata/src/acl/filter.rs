@@ -0,0 +1,110 @@
+//!
+//! # ACL Filter
+//!
+//! Selects ACL bindings for describe and delete requests. Unset fields match any value.
+//!
+use super::AclSpec;
+use super::AclResourceType;
+use super::AclPatternType;
+use super::AclOperation;
+use super::AclPermission;
+use super::ACL_WILDCARD;
+
+/// Kafka code for "any" in filters
+const KF_ANY: i8 = 1;
+
+/// Kafka code for pattern "match": literal, wildcard and prefixed ACLs applying to name
+const KF_PATTERN_MATCH: i8 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AclPatternFilter {
+    Any,
+    Match,
+    Exact(AclPatternType),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AclFilter {
+    pub resource_type: Option<AclResourceType>,
+    pub resource_name: Option<String>,
+    pub pattern: AclPatternFilter,
+    pub principal: Option<String>,
+    pub host: Option<String>,
+    pub operation: Option<AclOperation>,
+    pub permission: Option<AclPermission>,
+}
+
+impl AclFilter {
+    /// build filter from Kafka codes, error for unknown codes
+    pub fn from_kf_codes(
+        resource_type: i8,
+        resource_name: Option<String>,
+        pattern_type: i8,
+        principal: Option<String>,
+        host: Option<String>,
+        operation: i8,
+        permission: i8,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            resource_type: any_or(resource_type, "resource type", AclResourceType::from_kf_code)?,
+            resource_name,
+            pattern: match pattern_type {
+                KF_ANY => AclPatternFilter::Any,
+                KF_PATTERN_MATCH => AclPatternFilter::Match,
+                code => AclPatternFilter::Exact(
+                    AclPatternType::from_kf_code(code)
+                        .ok_or_else(|| format!("unknown pattern type: {}", code))?,
+                ),
+            },
+            principal,
+            host,
+            operation: any_or(operation, "operation", AclOperation::from_kf_code)?,
+            permission: any_or(permission, "permission", AclPermission::from_kf_code)?,
+        })
+    }
+
+    pub fn matches(&self, acl: &AclSpec) -> bool {
+        if let Some(resource_type) = &self.resource_type {
+            if resource_type != &acl.resource_type {
+                return false;
+            }
+        }
+
+        let name_matches = match (&self.pattern, &self.resource_name) {
+            (AclPatternFilter::Exact(pattern_type), name) => {
+                pattern_type == &acl.pattern_type && name_equals(name, acl)
+            }
+            (AclPatternFilter::Any, name) => name_equals(name, acl),
+            (AclPatternFilter::Match, None) => true,
+            (AclPatternFilter::Match, Some(name)) => match acl.pattern_type {
+                AclPatternType::Literal => {
+                    &acl.resource_name == name || acl.resource_name == ACL_WILDCARD
+                }
+                AclPatternType::Prefixed => name.starts_with(&acl.resource_name),
+            },
+        };
+
+        name_matches
+            && self.principal.as_ref().map_or(true, |p| p == &acl.principal)
+            && self.host.as_ref().map_or(true, |h| h == &acl.host)
+            && self.operation.as_ref().map_or(true, |o| o == &acl.operation)
+            && self.permission.as_ref().map_or(true, |p| p == &acl.permission)
+    }
+}
+
+fn name_equals(name: &Option<String>, acl: &AclSpec) -> bool {
+    name.as_ref().map_or(true, |name| name == &acl.resource_name)
+}
+
+fn any_or<T, F>(code: i8, label: &str, convert: F) -> Result<Option<T>, String>
+where
+    F: Fn(i8) -> Option<T>,
+{
+    if code == KF_ANY {
+        Ok(None)
+    } else {
+        convert(code)
+            .map(Some)
+            .ok_or_else(|| format!("unknown {}: {}", label, code))
+    }
+}
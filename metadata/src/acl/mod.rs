@@ -0,0 +1,24 @@
+mod spec;
+mod status;
+mod filter;
+mod authorizer;
+
+pub use self::spec::AclSpec;
+pub use self::spec::AclResourceType;
+pub use self::spec::AclPatternType;
+pub use self::spec::AclOperation;
+pub use self::spec::AclPermission;
+pub use self::spec::ACL_WILDCARD;
+pub use self::spec::ACL_WILDCARD_PRINCIPAL;
+pub use self::spec::ACL_CLUSTER_NAME;
+
+pub use self::status::AclStatus;
+
+pub use self::filter::AclFilter;
+pub use self::filter::AclPatternFilter;
+
+pub use self::authorizer::authorize;
+pub use self::authorizer::authorize_explicit;
+pub use self::authorizer::AuthorizerConfig;
+pub use self::authorizer::AclIdentity;
+pub use self::authorizer::ANONYMOUS_PRINCIPAL;
@@ -0,0 +1,405 @@
+//!
+//! # ACL Spec
+//!
+//! ACL binding: principal and host are allowed or denied an operation on a resource.
+//! Resource is matched by name (literal) or by name prefix (prefixed).
+//!
+use std::fmt;
+
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::acl::AclSpec as K8AclSpec;
+use k8_metadata::acl::AclResourceType as K8AclResourceType;
+use k8_metadata::acl::AclPatternType as K8AclPatternType;
+use k8_metadata::acl::AclOperation as K8AclOperation;
+use k8_metadata::acl::AclPermission as K8AclPermission;
+
+/// wildcard for principal ("User:*"), host and literal resource name
+pub const ACL_WILDCARD: &'static str = "*";
+pub const ACL_WILDCARD_PRINCIPAL: &'static str = "User:*";
+
+/// name of the single cluster resource, same as Kafka
+pub const ACL_CLUSTER_NAME: &'static str = "kafka-cluster";
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+#[derive(Decode, Encode, Default, Debug, Clone, PartialEq)]
+pub struct AclSpec {
+    pub principal: String,
+    pub host: String,
+    pub resource_type: AclResourceType,
+    pub pattern_type: AclPatternType,
+    pub resource_name: String,
+    pub operation: AclOperation,
+    pub permission: AclPermission,
+}
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum AclResourceType {
+    Topic,
+    Group,
+    Cluster,
+}
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum AclPatternType {
+    Literal,
+    Prefixed,
+}
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum AclOperation {
+    All,
+    Read,
+    Write,
+    Create,
+    Delete,
+    Alter,
+    Describe,
+    ClusterAction,
+    DescribeConfigs,
+    AlterConfigs,
+}
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum AclPermission {
+    Allow,
+    Deny,
+}
+
+// -----------------------------------
+// Implementation - AclSpec
+// -----------------------------------
+
+impl AclSpec {
+    /// build binding from Kafka codes, error for unknown or "any" codes
+    pub fn from_kf_codes(
+        resource_type: i8,
+        resource_name: String,
+        pattern_type: i8,
+        principal: String,
+        host: String,
+        operation: i8,
+        permission: i8,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            principal,
+            host,
+            resource_type: AclResourceType::from_kf_code(resource_type)
+                .ok_or_else(|| format!("invalid resource type: {}", resource_type))?,
+            pattern_type: AclPatternType::from_kf_code(pattern_type)
+                .ok_or_else(|| format!("invalid pattern type: {}", pattern_type))?,
+            resource_name,
+            operation: AclOperation::from_kf_code(operation)
+                .ok_or_else(|| format!("invalid operation: {}", operation))?,
+            permission: AclPermission::from_kf_code(permission)
+                .ok_or_else(|| format!("invalid permission: {}", permission))?,
+        })
+    }
+
+    /// Deterministic name derived from all fields, so same binding always maps to same object
+    pub fn key(&self) -> String {
+        // FNV-1a, stable across builds
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let fields = [
+            self.principal.as_str(),
+            self.host.as_str(),
+            self.resource_type.label(),
+            self.pattern_type.label(),
+            self.resource_name.as_str(),
+            self.operation.label(),
+            self.permission.label(),
+        ];
+        for field in fields.iter() {
+            for byte in field.bytes().chain(std::iter::once(0)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        format!("acl-{:016x}", hash)
+    }
+
+    /// ACL applies to resource
+    pub fn matches_resource(&self, resource_type: &AclResourceType, name: &str) -> bool {
+        if &self.resource_type != resource_type {
+            return false;
+        }
+        match self.pattern_type {
+            AclPatternType::Literal => self.resource_name == name || self.resource_name == ACL_WILDCARD,
+            AclPatternType::Prefixed => name.starts_with(&self.resource_name),
+        }
+    }
+
+    /// ACL applies to principal connecting from host
+    pub fn matches_principal(&self, principal: &str, host: &str) -> bool {
+        (self.principal == principal || self.principal == ACL_WILDCARD_PRINCIPAL)
+            && (self.host == host || self.host == ACL_WILDCARD)
+    }
+
+    /// ACL operation covers requested operation
+    pub fn matches_operation(&self, operation: &AclOperation) -> bool {
+        self.operation == AclOperation::All || &self.operation == operation
+    }
+
+    pub fn is_allow(&self) -> bool {
+        self.permission == AclPermission::Allow
+    }
+}
+
+impl fmt::Display for AclSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} from {} on {}:{}:{}",
+            self.permission.label(),
+            self.principal,
+            self.operation.label(),
+            self.host,
+            self.resource_type.label(),
+            self.pattern_type.label(),
+            self.resource_name
+        )
+    }
+}
+
+// -----------------------------------
+// Labels & Kafka codes
+// -----------------------------------
+
+impl AclResourceType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Topic => "topic",
+            Self::Group => "group",
+            Self::Cluster => "cluster",
+        }
+    }
+
+    /// Kafka resource type code
+    pub fn kf_code(&self) -> i8 {
+        match self {
+            Self::Topic => 2,
+            Self::Group => 3,
+            Self::Cluster => 4,
+        }
+    }
+
+    /// None for unknown or unsupported codes
+    pub fn from_kf_code(code: i8) -> Option<Self> {
+        match code {
+            2 => Some(Self::Topic),
+            3 => Some(Self::Group),
+            4 => Some(Self::Cluster),
+            _ => None,
+        }
+    }
+}
+
+impl AclPatternType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Literal => "literal",
+            Self::Prefixed => "prefixed",
+        }
+    }
+
+    pub fn kf_code(&self) -> i8 {
+        match self {
+            Self::Literal => 3,
+            Self::Prefixed => 4,
+        }
+    }
+
+    pub fn from_kf_code(code: i8) -> Option<Self> {
+        match code {
+            3 => Some(Self::Literal),
+            4 => Some(Self::Prefixed),
+            _ => None,
+        }
+    }
+}
+
+impl AclOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Create => "create",
+            Self::Delete => "delete",
+            Self::Alter => "alter",
+            Self::Describe => "describe",
+            Self::ClusterAction => "cluster-action",
+            Self::DescribeConfigs => "describe-configs",
+            Self::AlterConfigs => "alter-configs",
+        }
+    }
+
+    pub fn kf_code(&self) -> i8 {
+        match self {
+            Self::All => 2,
+            Self::Read => 3,
+            Self::Write => 4,
+            Self::Create => 5,
+            Self::Delete => 6,
+            Self::Alter => 7,
+            Self::Describe => 8,
+            Self::ClusterAction => 9,
+            Self::DescribeConfigs => 10,
+            Self::AlterConfigs => 11,
+        }
+    }
+
+    pub fn from_kf_code(code: i8) -> Option<Self> {
+        match code {
+            2 => Some(Self::All),
+            3 => Some(Self::Read),
+            4 => Some(Self::Write),
+            5 => Some(Self::Create),
+            6 => Some(Self::Delete),
+            7 => Some(Self::Alter),
+            8 => Some(Self::Describe),
+            9 => Some(Self::ClusterAction),
+            10 => Some(Self::DescribeConfigs),
+            11 => Some(Self::AlterConfigs),
+            _ => None,
+        }
+    }
+
+    /// operations which implicitly grant this operation when allowed
+    pub fn implied_by(&self) -> Vec<AclOperation> {
+        match self {
+            Self::Describe => vec![Self::Read, Self::Write, Self::Delete, Self::Alter],
+            Self::DescribeConfigs => vec![Self::AlterConfigs],
+            _ => vec![],
+        }
+    }
+}
+
+impl AclPermission {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Deny => "deny",
+        }
+    }
+
+    pub fn kf_code(&self) -> i8 {
+        match self {
+            Self::Deny => 2,
+            Self::Allow => 3,
+        }
+    }
+
+    pub fn from_kf_code(code: i8) -> Option<Self> {
+        match code {
+            2 => Some(Self::Deny),
+            3 => Some(Self::Allow),
+            _ => None,
+        }
+    }
+}
+
+// -----------------------------------
+// Defaults
+// -----------------------------------
+
+impl Default for AclResourceType {
+    fn default() -> Self {
+        AclResourceType::Topic
+    }
+}
+
+impl Default for AclPatternType {
+    fn default() -> Self {
+        AclPatternType::Literal
+    }
+}
+
+impl Default for AclOperation {
+    fn default() -> Self {
+        AclOperation::All
+    }
+}
+
+impl Default for AclPermission {
+    fn default() -> Self {
+        AclPermission::Allow
+    }
+}
+
+// -----------------------------------
+// Conversions - K8
+// -----------------------------------
+
+impl From<K8AclSpec> for AclSpec {
+    fn from(k8_spec: K8AclSpec) -> Self {
+        AclSpec {
+            principal: k8_spec.principal,
+            host: k8_spec.host,
+            resource_type: match k8_spec.resource_type {
+                K8AclResourceType::Topic => AclResourceType::Topic,
+                K8AclResourceType::Group => AclResourceType::Group,
+                K8AclResourceType::Cluster => AclResourceType::Cluster,
+            },
+            pattern_type: match k8_spec.pattern_type {
+                K8AclPatternType::Literal => AclPatternType::Literal,
+                K8AclPatternType::Prefixed => AclPatternType::Prefixed,
+            },
+            resource_name: k8_spec.resource_name,
+            operation: match k8_spec.operation {
+                K8AclOperation::All => AclOperation::All,
+                K8AclOperation::Read => AclOperation::Read,
+                K8AclOperation::Write => AclOperation::Write,
+                K8AclOperation::Create => AclOperation::Create,
+                K8AclOperation::Delete => AclOperation::Delete,
+                K8AclOperation::Alter => AclOperation::Alter,
+                K8AclOperation::Describe => AclOperation::Describe,
+                K8AclOperation::ClusterAction => AclOperation::ClusterAction,
+                K8AclOperation::DescribeConfigs => AclOperation::DescribeConfigs,
+                K8AclOperation::AlterConfigs => AclOperation::AlterConfigs,
+            },
+            permission: match k8_spec.permission {
+                K8AclPermission::Allow => AclPermission::Allow,
+                K8AclPermission::Deny => AclPermission::Deny,
+            },
+        }
+    }
+}
+
+impl From<AclSpec> for K8AclSpec {
+    fn from(spec: AclSpec) -> Self {
+        K8AclSpec {
+            principal: spec.principal,
+            host: spec.host,
+            resource_type: match spec.resource_type {
+                AclResourceType::Topic => K8AclResourceType::Topic,
+                AclResourceType::Group => K8AclResourceType::Group,
+                AclResourceType::Cluster => K8AclResourceType::Cluster,
+            },
+            pattern_type: match spec.pattern_type {
+                AclPatternType::Literal => K8AclPatternType::Literal,
+                AclPatternType::Prefixed => K8AclPatternType::Prefixed,
+            },
+            resource_name: spec.resource_name,
+            operation: match spec.operation {
+                AclOperation::All => K8AclOperation::All,
+                AclOperation::Read => K8AclOperation::Read,
+                AclOperation::Write => K8AclOperation::Write,
+                AclOperation::Create => K8AclOperation::Create,
+                AclOperation::Delete => K8AclOperation::Delete,
+                AclOperation::Alter => K8AclOperation::Alter,
+                AclOperation::Describe => K8AclOperation::Describe,
+                AclOperation::ClusterAction => K8AclOperation::ClusterAction,
+                AclOperation::DescribeConfigs => K8AclOperation::DescribeConfigs,
+                AclOperation::AlterConfigs => K8AclOperation::AlterConfigs,
+            },
+            permission: match spec.permission {
+                AclPermission::Allow => K8AclPermission::Allow,
+                AclPermission::Deny => K8AclPermission::Deny,
+            },
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! # ACL Status
+//!
+//! ACL bindings have no runtime state, status is kept to match other metadata objects.
+//!
+use std::fmt;
+
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::acl::AclStatus as K8AclStatus;
+
+#[derive(Decode, Encode, Default, Debug, Clone, PartialEq)]
+pub struct AclStatus {}
+
+impl fmt::Display for AclStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ok")
+    }
+}
+
+impl From<K8AclStatus> for AclStatus {
+    fn from(_k8_status: K8AclStatus) -> Self {
+        AclStatus {}
+    }
+}
+
+impl From<AclStatus> for K8AclStatus {
+    fn from(_status: AclStatus) -> Self {
+        K8AclStatus {}
+    }
+}
@@ -0,0 +1,118 @@
+//!
+//! # SASL Credential Spec
+//!
+//! Salted SCRAM credentials of a user, cached locally by SC and SPU for authentication.
+//!
+use std::fmt;
+
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::auth_token::SaslCredentialSpec as K8SaslCredentialSpec;
+use k8_metadata::auth_token::SaslCredentialStatus as K8SaslCredentialStatus;
+use k8_metadata::auth_token::CredentialMechanism as K8CredentialMechanism;
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Default)]
+pub struct SaslCredentialSpec {
+    pub user: String,
+    pub mechanism: CredentialMechanism,
+
+    /// base64 encoded values
+    pub salt: String,
+    pub iterations: i32,
+    pub stored_key: String,
+    pub server_key: String,
+}
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq)]
+pub enum CredentialMechanism {
+    ScramSha256,
+    ScramSha512,
+}
+
+#[derive(Decode, Encode, Default, Debug, Clone, PartialEq)]
+pub struct SaslCredentialStatus {}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl SaslCredentialSpec {
+    /// true if credential belongs to user for SASL mechanism name
+    pub fn matches(&self, user: &str, mechanism_name: &str) -> bool {
+        self.user == user && self.mechanism.sasl_name() == mechanism_name
+    }
+}
+
+impl CredentialMechanism {
+    /// SASL mechanism name as used in the Kafka protocol
+    pub fn sasl_name(&self) -> &'static str {
+        match self {
+            CredentialMechanism::ScramSha256 => "SCRAM-SHA-256",
+            CredentialMechanism::ScramSha512 => "SCRAM-SHA-512",
+        }
+    }
+}
+
+impl Default for CredentialMechanism {
+    fn default() -> Self {
+        CredentialMechanism::ScramSha256
+    }
+}
+
+impl fmt::Display for SaslCredentialStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ok")
+    }
+}
+
+// -----------------------------------
+// Conversions - K8
+// -----------------------------------
+
+impl From<K8SaslCredentialSpec> for SaslCredentialSpec {
+    fn from(k8_spec: K8SaslCredentialSpec) -> Self {
+        Self {
+            user: k8_spec.user,
+            mechanism: match k8_spec.mechanism {
+                K8CredentialMechanism::ScramSha256 => CredentialMechanism::ScramSha256,
+                K8CredentialMechanism::ScramSha512 => CredentialMechanism::ScramSha512,
+            },
+            salt: k8_spec.salt,
+            iterations: k8_spec.iterations,
+            stored_key: k8_spec.stored_key,
+            server_key: k8_spec.server_key,
+        }
+    }
+}
+
+impl From<SaslCredentialSpec> for K8SaslCredentialSpec {
+    fn from(spec: SaslCredentialSpec) -> Self {
+        Self {
+            user: spec.user,
+            mechanism: match spec.mechanism {
+                CredentialMechanism::ScramSha256 => K8CredentialMechanism::ScramSha256,
+                CredentialMechanism::ScramSha512 => K8CredentialMechanism::ScramSha512,
+            },
+            salt: spec.salt,
+            iterations: spec.iterations,
+            stored_key: spec.stored_key,
+            server_key: spec.server_key,
+        }
+    }
+}
+
+impl From<K8SaslCredentialStatus> for SaslCredentialStatus {
+    fn from(_k8_status: K8SaslCredentialStatus) -> Self {
+        SaslCredentialStatus {}
+    }
+}
+
+impl From<SaslCredentialStatus> for K8SaslCredentialStatus {
+    fn from(_status: SaslCredentialStatus) -> Self {
+        K8SaslCredentialStatus {}
+    }
+}
@@ -1,8 +1,13 @@
 mod spec;
 mod status;
+mod credential;
 
 pub use self::spec::AuthTokenSpec;
 pub use self::spec::TokenType;
 
 pub use self::status::AuthTokenStatus;
 pub use self::status::TokenResolution;
+
+pub use self::credential::SaslCredentialSpec;
+pub use self::credential::SaslCredentialStatus;
+pub use self::credential::CredentialMechanism;
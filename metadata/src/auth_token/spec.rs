@@ -43,6 +43,16 @@ impl From<K8AuthTokenSpec> for AuthTokenSpec {
 }
 
 
+impl From<AuthTokenSpec> for K8AuthTokenSpec {
+    fn from(spec: AuthTokenSpec) -> Self {
+        K8AuthTokenSpec {
+            token_type: spec.token_type.into(),
+            min_spu: spec.min_spu,
+            max_spu: spec.max_spu,
+        }
+    }
+}
+
 impl From<K8TokenType> for TokenType {
     fn from(k8_token_type: K8TokenType) -> Self {
         match k8_token_type {
@@ -53,6 +63,16 @@ impl From<K8TokenType> for TokenType {
     }
 }
 
+impl From<TokenType> for K8TokenType {
+    fn from(token_type: TokenType) -> Self {
+        match token_type {
+            TokenType::Any => K8TokenType::Any,
+            TokenType::Custom => K8TokenType::Custom,
+            TokenType::Managed => K8TokenType::Managed,
+        }
+    }
+}
+
 // -----------------------------------
 // Implementation - AuthTokenSpec
 // -----------------------------------
@@ -3,6 +3,8 @@
 //!
 //! Auth Token Status metadata information cached locally.
 //!
+use std::fmt;
+
 use kf_protocol::derive::{Decode, Encode};
 
 use k8_metadata::auth_token::AuthTokenStatus as K8AuthTokenStatus;
@@ -48,18 +50,18 @@ impl From<K8TokenResolution> for TokenResolution {
     }
 }
 
-impl Into<K8AuthTokenStatus> for AuthTokenStatus {
-    fn into(self) -> K8AuthTokenStatus {
+impl From<AuthTokenStatus> for K8AuthTokenStatus {
+    fn from(status: AuthTokenStatus) -> Self {
         K8AuthTokenStatus {
-            resolution: self.resolution.into(),
-            reason: self.reason.clone(),
+            resolution: status.resolution.into(),
+            reason: status.reason,
         }
     }
 }
 
-impl Into<K8TokenResolution> for TokenResolution {
-    fn into(self) -> K8TokenResolution {
-        match self {
+impl From<TokenResolution> for K8TokenResolution {
+    fn from(resolution: TokenResolution) -> Self {
+        match resolution {
             TokenResolution::Ok => K8TokenResolution::Ok,
             TokenResolution::Init => K8TokenResolution::Init,
             TokenResolution::Invalid => K8TokenResolution::Invalid,
@@ -87,6 +89,12 @@ impl ::std::default::Default for TokenResolution {
     }
 }
 
+impl fmt::Display for AuthTokenStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::resolution_label(&self.resolution))
+    }
+}
+
 // -----------------------------------
 // Implementation
 // -----------------------------------
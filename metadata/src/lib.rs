@@ -4,3 +4,6 @@ pub mod auth_token;
 pub mod spu;
 pub mod topic;
 pub mod partition;
+pub mod acl;
+pub mod quota;
+pub mod throttle;
@@ -4,6 +4,7 @@
 //! Partition Spec metadata information cached locally.
 //!
 use types::SpuId;
+use std::collections::BTreeMap;
 use kf_protocol::derive::{Decode, Encode};
 use k8_metadata::partition::PartitionSpec as K8PartitionSpec;
 
@@ -15,6 +16,8 @@ use k8_metadata::partition::PartitionSpec as K8PartitionSpec;
 pub struct PartitionSpec {
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
+    /// configuration inherited from parent topic
+    pub config: BTreeMap<String, String>,
 }
 
 // -----------------------------------
@@ -26,15 +29,23 @@ impl From<K8PartitionSpec> for PartitionSpec {
         PartitionSpec {
             leader: kv_spec.leader,
             replicas: kv_spec.replicas,
+            config: kv_spec.config.unwrap_or_default(),
         }
     }
 }
 
 impl From<PartitionSpec> for K8PartitionSpec {
     fn from(spec: PartitionSpec) -> K8PartitionSpec {
+        let config = if spec.config.is_empty() {
+            None
+        } else {
+            Some(spec.config)
+        };
+
         K8PartitionSpec {
             leader: spec.leader,
-            replicas: spec.replicas
+            replicas: spec.replicas,
+            config,
         }
     }
 }
@@ -48,6 +59,7 @@ impl std::default::Default for PartitionSpec {
         PartitionSpec {
             leader: 0,
             replicas: Vec::default(),
+            config: BTreeMap::default(),
         }
     }
 }
@@ -60,13 +72,28 @@ impl PartitionSpec {
     pub fn new(leader: SpuId,replicas: Vec<SpuId>) -> Self {
         Self {
             leader,
-            replicas
+            replicas,
+            config: BTreeMap::default(),
         }
     }
 
+    pub fn with_config(mut self, config: BTreeMap<String, String>) -> Self {
+        self.config = config;
+        self
+    }
+
     pub fn has_spu(&self,spu: &SpuId) -> bool {
         self.replicas.contains(spu)
     }
+
+    /// preferred leader is first entry in the replica list
+    pub fn preferred_leader(&self) -> Option<SpuId> {
+        self.replicas.first().cloned()
+    }
+
+    pub fn is_preferred_leader(&self) -> bool {
+        self.preferred_leader() == Some(self.leader)
+    }
     
 }
 
@@ -105,12 +105,26 @@ impl PartitionStatus {
     pub fn has_live_replicas(&self) -> bool {
         self.replicas.len() > 0
     }
+
+    /// check if replica has caught up with leader.
+    /// leader is always in sync with itself
+    pub fn is_replica_in_sync(&self,spu: SpuId) -> bool {
+        if self.leader.spu == spu {
+            return true;
+        }
+        let leader_leo = self.leader.leo;
+        self.replicas
+            .iter()
+            .find(|re| re.spu == spu)
+            .map(|re| re.leo != -1 && re.leo == leader_leo)
+            .unwrap_or(false)
+    }
    
     
     /// Fnd best candidate from online replicas
     /// If there are multiple matches, find with best score (lowest lag)
     pub fn candidate_leader<P>(&self,online: &HashSet<SpuId>,policy: &P) -> Option<SpuId> 
-        where P: ElectionPolicy
+        where P: ElectionPolicy + ?Sized
     {
         
         let mut candiate_spu = None;
@@ -251,13 +265,15 @@ impl From<PartitionResolution> for K8PartitionResolution {
 pub struct ReplicaStatus {
     pub spu: i32,
     pub hw: i64,
-    pub leo: i64
+    pub leo: i64,
+    pub size: i64,          // bytes of log segments, -1 if unknown
+    pub segments: u32
 }
 
 
 impl fmt::Display for ReplicaStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f,"spu:{} hw:{} leo: {}",self.spu,self.hw,self.leo)
+        write!(f,"spu:{} hw:{} leo: {} size: {}",self.spu,self.hw,self.leo,self.size)
     }
 }
 
@@ -268,7 +284,9 @@ impl Default for ReplicaStatus {
         ReplicaStatus {
             spu: -1,
             hw: -1,
-            leo: -1
+            leo: -1,
+            size: -1,
+            segments: 0
         }
     }
 }
@@ -281,10 +299,19 @@ impl ReplicaStatus {
         Self {
             spu,
             hw,
-            leo
+            leo,
+            size: -1,
+            segments: 0
         }
     }
 
+    /// set disk usage of replica
+    pub fn set_usage(mut self,size: i64,segments: u32) -> Self {
+        self.size = size;
+        self.segments = segments;
+        self
+    }
+
     /// compute lag score respect to leader
     pub fn leader_lag(&self,leader_status: &Self) -> i64 {
         leader_status.leo - self.leo
@@ -317,15 +344,22 @@ impl ReplicaStatus {
             if source.hw != -1 {
                 self.hw = source.hw;
             }
+
+            if source.size != -1 {
+                self.size = source.size;
+                self.segments = source.segments;
+            }
             None
         } else {
 
-            let old = Self::new(self.spu,self.hw,self.leo);
+            let old = self.clone();
             
             self.spu = source.spu;
 
             self.leo = source.leo;
             self.hw = source.hw;
+            self.size = source.size;
+            self.segments = source.segments;
             
             Some(old)
 
@@ -350,7 +384,9 @@ impl From<K8ReplicaStatus> for ReplicaStatus  {
         Self {
             spu: status.spu,
             hw: status.hw,
-            leo: status.leo
+            leo: status.leo,
+            size: status.size,
+            segments: status.segments
         }
     }
 }
@@ -360,7 +396,9 @@ impl From<ReplicaStatus> for K8ReplicaStatus {
         Self {
             spu: status.spu,
             hw: status.hw,
-            leo: status.leo
+            leo: status.leo,
+            size: status.size,
+            segments: status.segments
         }
     }
 }
@@ -461,6 +499,24 @@ mod test {
         assert!(status.candidate_leader(&online_spu,&policy).is_none());
     }
 
+    #[test]
+    fn test_replica_in_sync() {
+
+        let status = PartitionStatus::new(
+            (5000,100,110),
+            vec![
+                (5001,100,110).into(),      // caught up with leader
+                (5002,100,105).into(),      // lagging
+                (5003,-1,-1).into()         // unknown
+           ]);
+
+        assert!(status.is_replica_in_sync(5000));
+        assert!(status.is_replica_in_sync(5001));
+        assert!(!status.is_replica_in_sync(5002));
+        assert!(!status.is_replica_in_sync(5003));
+        assert!(!status.is_replica_in_sync(5004));
+    }
+
 
     #[test]
     fn test_merge_initial() {
@@ -510,6 +566,22 @@ mod test {
     }
 
 
+    #[test]
+    fn test_merge_usage() {
+
+        let mut target = PartitionStatus::leader(ReplicaStatus::new(5000,10,10).set_usage(1000,2));
+
+        // unknown usage keeps existing one
+        target.merge(PartitionStatus::leader((5000,20,20)));
+        assert_eq!(target.leader.size,1000);
+        assert_eq!(target.leader.segments,2);
+
+        target.merge(PartitionStatus::leader(ReplicaStatus::new(5000,30,30).set_usage(2000,3)));
+        assert_eq!(target.leader.size,2000);
+        assert_eq!(target.leader.segments,3);
+    }
+
+
     #[test]
     fn test_merge_lrs_different_leader() {
 
@@ -0,0 +1,12 @@
+mod spec;
+mod status;
+mod resolver;
+
+pub use self::spec::ClientQuotaSpec;
+pub use self::spec::QuotaType;
+pub use self::spec::QUOTA_DEFAULT_ENTITY;
+
+pub use self::status::ClientQuotaStatus;
+
+pub use self::resolver::resolve_quota;
+pub use self::resolver::ResolvedQuota;
@@ -0,0 +1,98 @@
+//!
+//! # Quota Resolver
+//!
+//! Find quota which applies to a connection, shared by SC and SPU. For each quota type,
+//! most specific entity defining rate of that type wins. Quota is shared by all
+//! connections that resolve to same entity.
+//!
+use super::ClientQuotaSpec;
+use super::QuotaType;
+
+/// quota applied to connection and entity its usage is tracked under
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResolvedQuota {
+    pub byte_rate: i64,
+    pub principal: Option<String>,
+    pub client_id: Option<String>,
+}
+
+/// resolve quota for principal and client id, none if unlimited
+pub fn resolve_quota<'a, I>(
+    quotas: I,
+    quota_type: QuotaType,
+    principal: &str,
+    client_id: &str,
+) -> Option<ResolvedQuota>
+where
+    I: IntoIterator<Item = &'a ClientQuotaSpec>,
+{
+    quotas
+        .into_iter()
+        .filter_map(|quota| {
+            let byte_rate = quota.byte_rate(quota_type)?;
+            let rank = quota.match_rank(principal, client_id)?;
+            Some((rank, byte_rate, quota))
+        })
+        .max_by_key(|(rank, _, _)| *rank)
+        .map(|(_, byte_rate, quota)| ResolvedQuota {
+            byte_rate,
+            principal: if quota.has_principal() { Some(principal.to_owned()) } else { None },
+            client_id: if quota.has_client_id() { Some(client_id.to_owned()) } else { None },
+        })
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::super::ClientQuotaSpec;
+    use super::super::QuotaType;
+    use super::super::QUOTA_DEFAULT_ENTITY;
+    use super::resolve_quota;
+
+    fn quota(principal: Option<&str>, client_id: Option<&str>, produce: Option<i64>, fetch: Option<i64>) -> ClientQuotaSpec {
+        ClientQuotaSpec {
+            principal: principal.map(|name| name.to_owned()),
+            client_id: client_id.map(|name| name.to_owned()),
+            producer_byte_rate: produce,
+            consumer_byte_rate: fetch,
+        }
+    }
+
+    #[test]
+    fn test_no_quota() {
+        let quotas = vec![quota(Some("User:alice"), None, Some(100), None)];
+        assert!(resolve_quota(&quotas, QuotaType::Produce, "User:bob", "app").is_none());
+        assert!(resolve_quota(&quotas, QuotaType::Fetch, "User:alice", "app").is_none());
+    }
+
+    #[test]
+    fn test_most_specific_quota() {
+        let quotas = vec![
+            quota(None, Some(QUOTA_DEFAULT_ENTITY), Some(10), Some(10)),
+            quota(None, Some("app"), Some(20), None),
+            quota(Some(QUOTA_DEFAULT_ENTITY), None, Some(30), None),
+            quota(Some("User:alice"), None, Some(40), None),
+            quota(Some("User:alice"), Some("app"), Some(50), None),
+        ];
+
+        let resolved = resolve_quota(&quotas, QuotaType::Produce, "User:alice", "app").expect("quota");
+        assert_eq!(resolved.byte_rate, 50);
+        assert_eq!(resolved.principal, Some("User:alice".to_owned()));
+        assert_eq!(resolved.client_id, Some("app".to_owned()));
+
+        let resolved = resolve_quota(&quotas, QuotaType::Produce, "User:alice", "other").expect("quota");
+        assert_eq!(resolved.byte_rate, 40);
+        assert_eq!(resolved.client_id, None);
+
+        // principal has precedence over client id
+        let resolved = resolve_quota(&quotas, QuotaType::Produce, "User:bob", "app").expect("quota");
+        assert_eq!(resolved.byte_rate, 30);
+        assert_eq!(resolved.principal, Some("User:bob".to_owned()));
+
+        // only client default defines fetch rate
+        let resolved = resolve_quota(&quotas, QuotaType::Fetch, "User:alice", "app").expect("quota");
+        assert_eq!(resolved.byte_rate, 10);
+        assert_eq!(resolved.principal, None);
+        assert_eq!(resolved.client_id, Some("app".to_owned()));
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! # Client Quota Spec
+//!
+//! Produce and fetch byte rates of a quota entity. Entity is principal and/or client id,
+//! either of them may be `<default>` which applies to any principal or client id that
+//! doesn't have its own quota. Entity without principal (or client id) is not keyed by it.
+//!
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::quota::ClientQuotaSpec as K8ClientQuotaSpec;
+
+/// entity name which applies to all principals or client ids without specific quota
+pub const QUOTA_DEFAULT_ENTITY: &'static str = "<default>";
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Default)]
+pub struct ClientQuotaSpec {
+    pub principal: Option<String>,
+    pub client_id: Option<String>,
+
+    /// bytes per second, none for unlimited
+    pub producer_byte_rate: Option<i64>,
+    pub consumer_byte_rate: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum QuotaType {
+    Produce,
+    Fetch,
+}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl ClientQuotaSpec {
+    pub fn byte_rate(&self, quota_type: QuotaType) -> Option<i64> {
+        match quota_type {
+            QuotaType::Produce => self.producer_byte_rate,
+            QuotaType::Fetch => self.consumer_byte_rate,
+        }
+    }
+
+    /// rank of match for principal and client id, none if entity doesn't match.
+    /// higher rank is more specific: exact name, then default, then entity not used.
+    /// principal has precedence over client id
+    pub fn match_rank(&self, principal: &str, client_id: &str) -> Option<(u8, u8)> {
+        Some((
+            entity_rank(&self.principal, principal)?,
+            entity_rank(&self.client_id, client_id)?,
+        ))
+    }
+
+    pub fn has_principal(&self) -> bool {
+        self.principal.is_some()
+    }
+
+    pub fn has_client_id(&self) -> bool {
+        self.client_id.is_some()
+    }
+}
+
+fn entity_rank(entity: &Option<String>, name: &str) -> Option<u8> {
+    match entity {
+        None => Some(0),
+        Some(entity) if entity == QUOTA_DEFAULT_ENTITY => Some(1),
+        Some(entity) if entity == name => Some(2),
+        Some(_) => None,
+    }
+}
+
+// -----------------------------------
+// Conversions - K8
+// -----------------------------------
+
+impl From<K8ClientQuotaSpec> for ClientQuotaSpec {
+    fn from(k8_spec: K8ClientQuotaSpec) -> Self {
+        Self {
+            principal: k8_spec.principal,
+            client_id: k8_spec.client_id,
+            producer_byte_rate: k8_spec.producer_byte_rate,
+            consumer_byte_rate: k8_spec.consumer_byte_rate,
+        }
+    }
+}
+
+impl From<ClientQuotaSpec> for K8ClientQuotaSpec {
+    fn from(spec: ClientQuotaSpec) -> Self {
+        Self {
+            principal: spec.principal,
+            client_id: spec.client_id,
+            producer_byte_rate: spec.producer_byte_rate,
+            consumer_byte_rate: spec.consumer_byte_rate,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! # Client Quota Status
+//!
+//! Quotas have no runtime state, status is kept to match other metadata objects.
+//!
+use std::fmt;
+
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::quota::ClientQuotaStatus as K8ClientQuotaStatus;
+
+#[derive(Decode, Encode, Default, Debug, Clone, PartialEq)]
+pub struct ClientQuotaStatus {}
+
+impl fmt::Display for ClientQuotaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ok")
+    }
+}
+
+impl From<K8ClientQuotaStatus> for ClientQuotaStatus {
+    fn from(_k8_status: K8ClientQuotaStatus) -> Self {
+        ClientQuotaStatus {}
+    }
+}
+
+impl From<ClientQuotaStatus> for K8ClientQuotaStatus {
+    fn from(_status: ClientQuotaStatus) -> Self {
+        K8ClientQuotaStatus {}
+    }
+}
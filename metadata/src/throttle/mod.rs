@@ -0,0 +1,8 @@
+mod spec;
+mod status;
+
+pub use self::spec::ReplicationThrottleSpec;
+pub use self::spec::THROTTLE_ALL_SPUS;
+pub use self::spec::throttle_key;
+
+pub use self::status::ReplicationThrottleStatus;
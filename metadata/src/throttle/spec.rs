@@ -0,0 +1,71 @@
+//!
+//! # Replication Throttle Spec
+//!
+//! Runtime override of leader replication throttle rate, set through alter configs.
+//! Override is either for a single SPU or for all SPUs, single SPU takes precedence.
+//!
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::throttle::ReplicationThrottleSpec as K8ReplicationThrottleSpec;
+use types::SpuId;
+
+/// key of override which applies to all SPUs without their own override
+pub const THROTTLE_ALL_SPUS: &'static str = "all-spus";
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Default)]
+pub struct ReplicationThrottleSpec {
+    /// none if override applies to all spus
+    pub spu: Option<SpuId>,
+
+    /// bytes per second
+    pub rate: i64,
+}
+
+// -----------------------------------
+// Implementation
+// -----------------------------------
+
+impl ReplicationThrottleSpec {
+    pub fn new(spu: Option<SpuId>, rate: i64) -> Self {
+        Self { spu, rate }
+    }
+
+    pub fn key(&self) -> String {
+        throttle_key(self.spu)
+    }
+}
+
+/// key of override for spu, or for all spus if spu is none
+pub fn throttle_key(spu: Option<SpuId>) -> String {
+    match spu {
+        Some(spu) => format!("spu-{}", spu),
+        None => THROTTLE_ALL_SPUS.to_owned(),
+    }
+}
+
+// -----------------------------------
+// Conversions - K8
+// -----------------------------------
+
+impl From<K8ReplicationThrottleSpec> for ReplicationThrottleSpec {
+    fn from(k8_spec: K8ReplicationThrottleSpec) -> Self {
+        Self {
+            spu: k8_spec.spu,
+            rate: k8_spec.rate,
+        }
+    }
+}
+
+impl From<ReplicationThrottleSpec> for K8ReplicationThrottleSpec {
+    fn from(spec: ReplicationThrottleSpec) -> Self {
+        Self {
+            spu: spec.spu,
+            rate: spec.rate,
+        }
+    }
+}
+
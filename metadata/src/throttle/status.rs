@@ -0,0 +1,31 @@
+//!
+//! # Replication Throttle Status
+//!
+//! Throttles have no runtime state, status is kept to match other metadata objects.
+//!
+use std::fmt;
+
+use kf_protocol::derive::{Decode, Encode};
+
+use k8_metadata::throttle::ReplicationThrottleStatus as K8ReplicationThrottleStatus;
+
+#[derive(Decode, Encode, Default, Debug, Clone, PartialEq)]
+pub struct ReplicationThrottleStatus {}
+
+impl fmt::Display for ReplicationThrottleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ok")
+    }
+}
+
+impl From<K8ReplicationThrottleStatus> for ReplicationThrottleStatus {
+    fn from(_k8_status: K8ReplicationThrottleStatus) -> Self {
+        ReplicationThrottleStatus {}
+    }
+}
+
+impl From<ReplicationThrottleStatus> for K8ReplicationThrottleStatus {
+    fn from(_status: ReplicationThrottleStatus) -> Self {
+        K8ReplicationThrottleStatus {}
+    }
+}
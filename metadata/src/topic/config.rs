@@ -0,0 +1,212 @@
+//!
+//! # Topic Config
+//!
+//! Per topic configuration entries. Entries override SPU defaults for replicas of the topic.
+//! Keys follow Kafka topic configuration names, values are kept as strings.
+//!
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+
+pub type TopicConfig = BTreeMap<String, String>;
+
+// -----------------------------------
+// Config Keys
+// -----------------------------------
+
+pub const SEGMENT_BYTES: &'static str = "segment.bytes";
+pub const SEGMENT_MS: &'static str = "segment.ms";
+pub const SEGMENT_INDEX_BYTES: &'static str = "segment.index.bytes";
+pub const INDEX_INTERVAL_BYTES: &'static str = "index.interval.bytes";
+pub const RETENTION_MS: &'static str = "retention.ms";
+pub const MAX_MESSAGE_BYTES: &'static str = "max.message.bytes";
+pub const CLEANUP_POLICY: &'static str = "cleanup.policy";
+pub const MIN_INSYNC_REPLICAS: &'static str = "min.insync.replicas";
+pub const MESSAGE_TIMESTAMP_TYPE: &'static str = "message.timestamp.type";
+pub const MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS: &'static str = "message.timestamp.difference.max.ms";
+pub const UNCLEAN_LEADER_ELECTION_ENABLE: &'static str = "unclean.leader.election.enable";
+pub const LEADER_ELECTION_POLICY: &'static str = "leader.election.policy";
+
+/// all keys that can be set on a topic
+pub const TOPIC_CONFIG_KEYS: [&'static str; 12] = [
+    SEGMENT_BYTES,
+    SEGMENT_MS,
+    SEGMENT_INDEX_BYTES,
+    INDEX_INTERVAL_BYTES,
+    RETENTION_MS,
+    MAX_MESSAGE_BYTES,
+    CLEANUP_POLICY,
+    MIN_INSYNC_REPLICAS,
+    MESSAGE_TIMESTAMP_TYPE,
+    MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS,
+    UNCLEAN_LEADER_ELECTION_ENABLE,
+    LEADER_ELECTION_POLICY,
+];
+
+/// log compaction is not supported, old segments are only deleted
+pub const CLEANUP_POLICY_DELETE: &'static str = "delete";
+
+pub const TIMESTAMP_TYPE_CREATE_TIME: &'static str = "CreateTime";
+pub const TIMESTAMP_TYPE_LOG_APPEND_TIME: &'static str = "LogAppendTime";
+
+pub const ELECTION_POLICY_LOWEST_LAG: &'static str = "lowest-lag";
+pub const ELECTION_POLICY_RACK_DIVERSE: &'static str = "rack-diverse";
+pub const ELECTION_POLICY_UNCLEAN: &'static str = "unclean";
+
+// -----------------------------------
+// Validation
+// -----------------------------------
+
+/// Validate all entries in topic config and entries that must agree with each other
+pub fn validate_topic_config(config: &TopicConfig) -> Result<(), Error> {
+    for (key, value) in config.iter() {
+        validate_topic_config_entry(key, value)?;
+    }
+
+    // unclean policy would silently override explicitly disabled unclean election
+    let unclean_disabled = config
+        .get(UNCLEAN_LEADER_ELECTION_ENABLE)
+        .map(|value| value == "false")
+        .unwrap_or(false);
+    let unclean_policy = config
+        .get(LEADER_ELECTION_POLICY)
+        .map(|value| value == ELECTION_POLICY_UNCLEAN)
+        .unwrap_or(false);
+    if unclean_disabled && unclean_policy {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "topic config '{}={}' conflicts with '{}=false'",
+                LEADER_ELECTION_POLICY, ELECTION_POLICY_UNCLEAN, UNCLEAN_LEADER_ELECTION_ENABLE
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate single config entry, key must be known and value must parse
+pub fn validate_topic_config_entry(key: &str, value: &str) -> Result<(), Error> {
+    match key {
+        SEGMENT_BYTES | SEGMENT_INDEX_BYTES | INDEX_INTERVAL_BYTES | MAX_MESSAGE_BYTES => {
+            match value.parse::<u32>() {
+                Ok(bytes) if bytes > 0 => Ok(()),
+                _ => Err(invalid_value(key, value, "positive number of bytes")),
+            }
+        }
+        SEGMENT_MS => match value.parse::<u64>() {
+            Ok(ms) if ms > 0 => Ok(()),
+            _ => Err(invalid_value(key, value, "positive number of milliseconds")),
+        },
+        RETENTION_MS => match value.parse::<i64>() {
+            Ok(ms) if ms == -1 || ms > 0 => Ok(()),
+            _ => Err(invalid_value(key, value, "positive milliseconds or -1 for unlimited")),
+        },
+        MIN_INSYNC_REPLICAS => match value.parse::<u16>() {
+            Ok(replicas) if replicas > 0 => Ok(()),
+            _ => Err(invalid_value(key, value, "number greater than 0")),
+        },
+        MESSAGE_TIMESTAMP_TYPE => match value {
+            TIMESTAMP_TYPE_CREATE_TIME | TIMESTAMP_TYPE_LOG_APPEND_TIME => Ok(()),
+            _ => Err(invalid_value(key, value, "'CreateTime' or 'LogAppendTime'")),
+        },
+        MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS => match value.parse::<i64>() {
+            Ok(ms) if ms >= -1 => Ok(()),
+            _ => Err(invalid_value(key, value, "milliseconds or -1 for unlimited")),
+        },
+        CLEANUP_POLICY => match value {
+            CLEANUP_POLICY_DELETE => Ok(()),
+            _ => Err(invalid_value(key, value, "'delete', compaction is not supported")),
+        },
+        UNCLEAN_LEADER_ELECTION_ENABLE => match value.parse::<bool>() {
+            Ok(_) => Ok(()),
+            _ => Err(invalid_value(key, value, "'true' or 'false'")),
+        },
+        LEADER_ELECTION_POLICY => match value {
+            ELECTION_POLICY_LOWEST_LAG | ELECTION_POLICY_RACK_DIVERSE | ELECTION_POLICY_UNCLEAN => {
+                Ok(())
+            }
+            _ => Err(invalid_value(key, value, "'lowest-lag', 'rack-diverse' or 'unclean'")),
+        },
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown topic config '{}'", key),
+        )),
+    }
+}
+
+fn invalid_value(key: &str, value: &str, expected: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("invalid value '{}' for topic config '{}', expected {}", value, key, expected),
+    )
+}
+
+// -----------------------------------
+// Unit Tests
+// -----------------------------------
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_topic_config_entry() {
+        assert!(validate_topic_config_entry(SEGMENT_BYTES, "1048576").is_ok());
+        assert!(validate_topic_config_entry(SEGMENT_BYTES, "0").is_err());
+        assert!(validate_topic_config_entry(SEGMENT_BYTES, "1Mi").is_err());
+        assert!(validate_topic_config_entry(SEGMENT_MS, "3600000").is_ok());
+        assert!(validate_topic_config_entry(SEGMENT_MS, "0").is_err());
+        assert!(validate_topic_config_entry(RETENTION_MS, "-1").is_ok());
+        assert!(validate_topic_config_entry(RETENTION_MS, "0").is_err());
+        assert!(validate_topic_config_entry(RETENTION_MS, "-2").is_err());
+        assert!(validate_topic_config_entry(MIN_INSYNC_REPLICAS, "2").is_ok());
+        assert!(validate_topic_config_entry(CLEANUP_POLICY, "delete").is_ok());
+        assert!(validate_topic_config_entry(CLEANUP_POLICY, "compact").is_err());
+        assert!(validate_topic_config_entry(CLEANUP_POLICY, "purge").is_err());
+        assert!(validate_topic_config_entry(MESSAGE_TIMESTAMP_TYPE, "LogAppendTime").is_ok());
+        assert!(validate_topic_config_entry(MESSAGE_TIMESTAMP_TYPE, "AppendTime").is_err());
+        assert!(validate_topic_config_entry(MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS, "60000").is_ok());
+        assert!(validate_topic_config_entry(MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS, "-2").is_err());
+        assert!(validate_topic_config_entry(UNCLEAN_LEADER_ELECTION_ENABLE, "true").is_ok());
+        assert!(validate_topic_config_entry(UNCLEAN_LEADER_ELECTION_ENABLE, "yes").is_err());
+        assert!(validate_topic_config_entry(LEADER_ELECTION_POLICY, "rack-diverse").is_ok());
+        assert!(validate_topic_config_entry(LEADER_ELECTION_POLICY, "random").is_err());
+
+        let unknown = validate_topic_config_entry("unknown.key", "1");
+        assert_eq!(
+            format!("{}", unknown.unwrap_err()),
+            "unknown topic config 'unknown.key'"
+        );
+    }
+
+    #[test]
+    fn test_validate_topic_config() {
+        let mut config = TopicConfig::new();
+        config.insert(SEGMENT_BYTES.to_owned(), "1000".to_owned());
+        config.insert(RETENTION_MS.to_owned(), "60000".to_owned());
+        assert!(validate_topic_config(&config).is_ok());
+
+        config.insert(MAX_MESSAGE_BYTES.to_owned(), "-1".to_owned());
+        assert!(validate_topic_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_validate_conflicting_election_config() {
+        let mut config = TopicConfig::new();
+        config.insert(LEADER_ELECTION_POLICY.to_owned(), ELECTION_POLICY_UNCLEAN.to_owned());
+        assert!(validate_topic_config(&config).is_ok());
+
+        config.insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "true".to_owned());
+        assert!(validate_topic_config(&config).is_ok());
+
+        config.insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "false".to_owned());
+        let conflict = validate_topic_config(&config);
+        assert_eq!(
+            format!("{}", conflict.unwrap_err()),
+            "topic config 'leader.election.policy=unclean' conflicts with 'unclean.leader.election.enable=false'"
+        );
+
+        config.insert(LEADER_ELECTION_POLICY.to_owned(), ELECTION_POLICY_LOWEST_LAG.to_owned());
+        assert!(validate_topic_config(&config).is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 mod spec;
 mod status;
+mod config;
 
 pub use self::spec::TopicSpec;
 pub use self::spec::PartitionMap;
@@ -9,5 +10,28 @@ pub use self::spec::TopicReplicaParam;
 pub use self::status::TopicStatus;
 pub use self::status::TopicResolution;
 
+pub use self::config::TopicConfig;
+pub use self::config::validate_topic_config;
+pub use self::config::validate_topic_config_entry;
+pub use self::config::TOPIC_CONFIG_KEYS;
+pub use self::config::SEGMENT_BYTES;
+pub use self::config::SEGMENT_MS;
+pub use self::config::SEGMENT_INDEX_BYTES;
+pub use self::config::INDEX_INTERVAL_BYTES;
+pub use self::config::RETENTION_MS;
+pub use self::config::MAX_MESSAGE_BYTES;
+pub use self::config::CLEANUP_POLICY;
+pub use self::config::MIN_INSYNC_REPLICAS;
+pub use self::config::MESSAGE_TIMESTAMP_TYPE;
+pub use self::config::MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS;
+pub use self::config::TIMESTAMP_TYPE_CREATE_TIME;
+pub use self::config::TIMESTAMP_TYPE_LOG_APPEND_TIME;
+pub use self::config::UNCLEAN_LEADER_ELECTION_ENABLE;
+pub use self::config::LEADER_ELECTION_POLICY;
+pub use self::config::ELECTION_POLICY_LOWEST_LAG;
+pub use self::config::ELECTION_POLICY_RACK_DIVERSE;
+pub use self::config::ELECTION_POLICY_UNCLEAN;
 
-pub const PENDING_REASON: &'static str = "waiting for live spus";
\ No newline at end of file
+
+pub const PENDING_REASON: &'static str = "waiting for live spus";
+pub const DELETING_REASON: &'static str = "waiting for spus to remove replicas";
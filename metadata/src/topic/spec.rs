@@ -19,6 +19,8 @@ use kf_protocol::{Decoder, Encoder};
 use k8_metadata::topic::TopicSpec as K8TopicSpec;
 use k8_metadata::topic::Partition as K8Partition;
 
+use super::TopicConfig;
+
 // -----------------------------------
 // Data Structures
 // -----------------------------------
@@ -27,7 +29,9 @@ use k8_metadata::topic::Partition as K8Partition;
 pub struct TopicReplicaParam {
     pub partitions: PartitionCount,
     pub replication_factor: ReplicationFactor,
-    pub ignore_rack_assignment: IgnoreRackAssignment
+    pub ignore_rack_assignment: IgnoreRackAssignment,
+    #[fluvio_kf(min_version = 1)]
+    pub config: TopicConfig,
 }
 
 
@@ -38,7 +42,8 @@ impl TopicReplicaParam {
         Self {
             partitions,
             replication_factor,
-            ignore_rack_assignment
+            ignore_rack_assignment,
+            config: TopicConfig::new(),
         }
     }
 }
@@ -61,13 +66,16 @@ impl std::fmt::Display for TopicReplicaParam {
 /// Hack: field instead of new type to get around encode and decode limitations
 #[derive(Debug,Default,Clone,PartialEq,Encode,Decode)]
 pub struct PartitionMaps {
-    maps: Vec<PartitionMap>
+    maps: Vec<PartitionMap>,
+    #[fluvio_kf(min_version = 1)]
+    config: TopicConfig,
 }
 
 impl From<Vec<PartitionMap>> for PartitionMaps {
     fn from(maps: Vec<PartitionMap>) -> Self {
         Self {
-            maps
+            maps,
+            config: TopicConfig::new(),
         }
     }
 }
@@ -323,6 +331,26 @@ impl TopicSpec {
         }
     }
 
+    /// per topic configuration
+    pub fn config(&self) -> &TopicConfig {
+        match self {
+            TopicSpec::Computed(param) => &param.config,
+            TopicSpec::Assigned(partition_map) => &partition_map.config,
+        }
+    }
+
+    pub fn config_mut(&mut self) -> &mut TopicConfig {
+        match self {
+            TopicSpec::Computed(param) => &mut param.config,
+            TopicSpec::Assigned(partition_map) => &mut partition_map.config,
+        }
+    }
+
+    pub fn with_config(mut self, config: TopicConfig) -> Self {
+        *self.config_mut() = config;
+        self
+    }
+
     // -----------------------------------
     // Labels & Strings
     // -----------------------------------
@@ -508,7 +536,13 @@ impl Encoder for TopicSpec {
 impl From<TopicSpec> for K8TopicSpec {
     fn from(spec: TopicSpec) -> Self {
         
-        match spec {
+        let config = if spec.config().is_empty() {
+            None
+        } else {
+            Some(spec.config().clone())
+        };
+
+        let k8_spec = match spec {
             TopicSpec::Computed(computed_param) => K8TopicSpec::new(
                     Some(computed_param.partitions),
                     Some(computed_param.replication_factor),
@@ -521,7 +555,9 @@ impl From<TopicSpec> for K8TopicSpec {
                     None,
                     Some(replica_map_to_k8_partition(assign_param))
                 )
-        }
+        };
+
+        k8_spec.with_config(config)
     }
 }
 
@@ -823,6 +859,29 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn test_encode_decode_topic_spec_config() {
+        let mut config = TopicConfig::new();
+        config.insert("segment.bytes".to_owned(), "1000".to_owned());
+        let topic_spec = TopicSpec::Computed((2, 3, true).into()).with_config(config.clone());
+
+        // config is only encoded from version 1
+        let mut dest_v0 = vec![];
+        topic_spec.encode(&mut dest_v0, 0).expect("encode v0");
+        assert_eq!(dest_v0.len(), 10);
+
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 1).expect("encode v1");
+        assert_eq!(dest.len(), topic_spec.write_size(1));
+
+        let mut topic_spec_decoded = TopicSpec::default();
+        topic_spec_decoded
+            .decode(&mut Cursor::new(&dest), 1)
+            .expect("decode v1");
+        assert_eq!(topic_spec_decoded.config(), &config);
+        assert_eq!(topic_spec_decoded, topic_spec);
+    }
+
     #[test]
     fn test_partition_map_str() {
         // Test multiple
@@ -40,6 +40,7 @@ pub enum TopicResolution {
     InsufficientResources,    // replica map cannot be created due to lack of capacity  
     InvalidConfig,              // invalid configuration
     Provisioned,                // topics are allocated
+    Deleting,                   // waiting for spus to remove replicas
 }
 
 impl TopicResolution {
@@ -51,6 +52,7 @@ impl TopicResolution {
             TopicResolution::Pending => "pending",
             TopicResolution::InsufficientResources => "insufficient-resources",
             TopicResolution::InvalidConfig => "invalid-config",
+            TopicResolution::Deleting => "deleting",
         }
     }
 
@@ -88,6 +90,7 @@ impl From<K8TopicStatus> for TopicStatus {
             K8TopicStatusResolution::Pending => TopicResolution::Pending,
             K8TopicStatusResolution::InsufficientResources => TopicResolution::InsufficientResources,
             K8TopicStatusResolution::InvalidConfig => TopicResolution::InvalidConfig,
+            K8TopicStatusResolution::Deleting => TopicResolution::Deleting,
         };
 
         TopicStatus {
@@ -106,6 +109,7 @@ impl From<TopicStatus> for K8TopicStatus {
             TopicResolution::Pending => K8TopicStatusResolution::Pending,
             TopicResolution::InsufficientResources => K8TopicStatusResolution::InsufficientResources,
             TopicResolution::InvalidConfig => K8TopicStatusResolution::InvalidConfig,
+            TopicResolution::Deleting => K8TopicStatusResolution::Deleting,
         };
 
         K8TopicStatus {
@@ -256,6 +260,15 @@ impl TopicStatus {
         self.resolution == TopicResolution::Provisioned
     }
 
+    pub fn is_resolution_deleting(&self) -> bool {
+        self.resolution == TopicResolution::Deleting
+    }
+
+    /// no replicas are assigned to spus
+    pub fn is_replica_map_empty(&self) -> bool {
+        self.replica_map.values().all(|replicas| replicas.is_empty())
+    }
+
     pub fn next_resolution_provisoned() -> (TopicResolution,String){
         (TopicResolution::Provisioned,"".to_owned())
     }
@@ -269,6 +282,11 @@ impl TopicStatus {
         (TopicResolution::InvalidConfig,reason.into())
     }
 
+    /// waiting for spus to confirm replica removal
+    pub fn next_resolution_deleting() -> (TopicResolution,String) {
+        (TopicResolution::Deleting,super::DELETING_REASON.to_owned())
+    }
+
     pub fn set_resolution_no_resource<S>(reason: S) -> (TopicResolution,String) where S: Into<String> {
         (TopicResolution::InsufficientResources, reason.into())
     }
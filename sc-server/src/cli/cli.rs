@@ -9,6 +9,7 @@
 //!
 use std::io::Error as IoError;
 use std::io::ErrorKind;
+use std::path::PathBuf;
 use std::process;
 
 use log::info;
@@ -20,6 +21,7 @@ use k8_config::K8Config;
 
 use crate::ScServerError;
 use super::ScConfig;
+use super::MetadataStoreConfig;
 
 /// cli options
 #[derive(Debug, StructOpt)]
@@ -33,29 +35,48 @@ pub struct ScOpt {
     /// Address for external communication
     pub bind_public: Option<String>,
 
+    #[structopt(short = "m", long = "bind-metrics", value_name = "host:port")]
+    /// Address for metrics endpoint
+    pub bind_metrics: Option<String>,
+
     #[structopt(short = "f", long = "conf", value_name = "file")]
     /// Configuration file
     pub config_file: Option<String>,
 
     #[structopt(short = "n", long = "namespace", value_name = "namespace")]
-    pub namespace: Option<String>
+    pub namespace: Option<String>,
+
+    #[structopt(short = "l", long = "local-store", value_name = "dir")]
+    /// Keep metadata in local directory instead of Kubernetes
+    pub local_store: Option<String>
 }
 
 /// validate streaming controller cli inputs and generate ScConfig
-pub fn get_sc_config() -> Result<(ScConfig,K8Config), ScServerError> {
+pub fn get_sc_config() -> Result<(ScConfig,Option<K8Config>), ScServerError> {
     sc_opt_to_sc_config(ScOpt::from_args())
 }
 
 
 /// convert cli options to sc_config
-fn sc_opt_to_sc_config(opt: ScOpt) -> Result<(ScConfig,K8Config), ScServerError> {
+fn sc_opt_to_sc_config(opt: ScOpt) -> Result<(ScConfig,Option<K8Config>), ScServerError> {
 
     let mut sc_config = ScConfig::new(opt.config_file)?;
 
-    let k8_config = K8Config::load().expect("no k8 config founded");
+    // override metadata store if set
+    if let Some(local_store) = opt.local_store {
+        sc_config.metadata_store = MetadataStoreConfig::Local(PathBuf::from(local_store));
+    }
+
+    // kubernetes config is only needed for k8 metadata store
+    let k8_config = if sc_config.metadata_store.is_k8() {
+        let k8_config = K8Config::load().expect("no k8 config founded");
 
-    sc_config.namespace = k8_config.namespace().to_owned();
-    info!("using {} as namespace from kubernetes config",sc_config.namespace);
+        sc_config.namespace = k8_config.namespace().to_owned();
+        info!("using {} as namespace from kubernetes config",sc_config.namespace);
+        Some(k8_config)
+    } else {
+        None
+    };
 
 
     // override id if set
@@ -81,6 +102,18 @@ fn sc_opt_to_sc_config(opt: ScOpt) -> Result<(ScConfig,K8Config), ScServerError>
         sc_config.public_endpoint = addr.into();
     }
 
+    // override metrics if set
+    if let Some(bind_metrics) = opt.bind_metrics {
+
+        let addr = string_to_socket_addr(&bind_metrics).map_err(
+            |_| IoError::new(
+                ErrorKind::InvalidInput,
+                format!("Expected <host:port>, found '{}'", bind_metrics),
+            ))?;
+
+        sc_config.metrics_endpoint = addr.into();
+    }
+
 
     // override namespace if set
     if let Some(namespace) = opt.namespace {
@@ -93,7 +126,7 @@ fn sc_opt_to_sc_config(opt: ScOpt) -> Result<(ScConfig,K8Config), ScServerError>
 }
 
 /// return SC configuration or exist program.
-pub fn parse_cli_or_exit() -> (ScConfig,K8Config) {
+pub fn parse_cli_or_exit() -> (ScConfig,Option<K8Config>) {
     match get_sc_config() {
         Err(err) => {
             print_cli_err!(err);
@@ -115,17 +148,22 @@ pub mod test {
     
     use types::socket_helpers::EndPoint;
 
+    use std::path::PathBuf;
+
     use super::ScOpt;
     use super::sc_opt_to_sc_config;
     use super::ScConfig;
+    use super::MetadataStoreConfig;
 
     #[test]
     fn test_get_sc_config_no_params() {
         let sc_opt = ScOpt {
             id: None,
             bind_public: None,
+            bind_metrics: None,
             config_file: None,
-            namespace: Some("test".to_owned())
+            namespace: Some("test".to_owned()),
+            local_store: None
         };
 
         // test read & parse
@@ -148,8 +186,10 @@ pub mod test {
         let sc_opt = ScOpt {
             id: None,
             bind_public: None,
+            bind_metrics: None,
             config_file: Some("./test-data/config/sc_server.toml".to_owned()),
-            namespace: Some("test".to_owned())
+            namespace: Some("test".to_owned()),
+            local_store: None
         };
 
         // test read & parse
@@ -172,8 +212,10 @@ pub mod test {
         let sc_opt = ScOpt {
             id: Some(100),
             bind_public: Some("1.1.1.1:8888".to_owned()),
+            bind_metrics: Some("1.1.1.1:8889".to_owned()),
             config_file: Some("./test-data/config/sc_server.toml".to_owned()),
-            namespace: Some("test".to_owned())
+            namespace: Some("test".to_owned()),
+            local_store: None
         };
 
         // test read & parse
@@ -184,6 +226,7 @@ pub mod test {
         let expected = ScConfig {
             id: 100,
             public_endpoint: (SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 8888)).into(),
+            metrics_endpoint: (SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 8889)).into(),
             namespace: "test".to_owned(),
             ..Default::default()
         };
@@ -191,5 +234,25 @@ pub mod test {
         assert_eq!(result.unwrap().0, expected);
     }
 
+    #[test]
+    fn test_get_sc_config_local_store() {
+        let sc_opt = ScOpt {
+            id: None,
+            bind_public: None,
+            bind_metrics: None,
+            config_file: None,
+            namespace: None,
+            local_store: Some("/tmp/fluvio-sc-test".to_owned())
+        };
+
+        // test read & parse, no k8 config needed
+        let (sc_config, k8_config) = sc_opt_to_sc_config(sc_opt).expect("config");
+        assert!(k8_config.is_none());
+        assert_eq!(
+            sc_config.metadata_store,
+            MetadataStoreConfig::Local(PathBuf::from("/tmp/fluvio-sc-test"))
+        );
+    }
+
 }
 //        println!("{:#?}", result);
@@ -6,4 +6,5 @@ pub use self::cli::parse_cli_or_exit;
 
 pub use self::sc_config::ScConfig;
 pub use self::sc_config::ScConfigBuilder;
+pub use self::sc_config::MetadataStoreConfig;
 pub use self::sc_config_file::ScConfigFile;
@@ -5,12 +5,22 @@
 //!
 use std::io::Error as IoError;
 use std::path::Path;
+use std::path::PathBuf;
 
 use types::defaults::SC_CONFIG_FILE;
 use types::defaults::{SC_DEFAULT_ID, SC_PUBLIC_PORT};
 use types::defaults::SC_PRIVATE_PORT;
+use types::defaults::SC_METRICS_PORT;
+use types::defaults::SC_LEADER_REBALANCE_INTERVAL_SEC;
+use types::defaults::SC_LOCAL_STORE_DIR;
 use utils::config_helper::build_server_config_file_path;
 use types::socket_helpers::EndPoint;
+use kf_service::sasl::SaslConfig;
+use kf_service::KfServerConfig;
+use metadata::acl::AuthorizerConfig;
+
+use crate::core::partitions::ElectionConfig;
+use crate::ha::HaConfig;
 
 
 use super::ScConfigFile;
@@ -25,8 +35,43 @@ pub struct ScConfig {
     pub id: i32,
     pub public_endpoint: EndPoint,
     pub private_endpoint: EndPoint,
+    /// prometheus metrics endpoint
+    pub metrics_endpoint: EndPoint,
     pub run_k8_dispatchers: bool,
-    pub namespace: String
+    pub namespace: String,
+    /// interval for preferred leader election, 0 to disable
+    pub leader_rebalance_interval_sec: u64,
+    /// cluster leader election policy, topic config may override it
+    pub election: ElectionConfig,
+    pub metadata_store: MetadataStoreConfig,
+    /// lease based leader election between SC replicas
+    pub ha: HaConfig,
+    /// sasl authentication on public endpoint
+    pub sasl: SaslConfig,
+    /// acl authorization on public endpoint, disabled by default
+    pub authorizer: AuthorizerConfig,
+    /// frame, connection and request limits on public endpoint
+    pub public_server: KfServerConfig,
+}
+
+/// where SC keeps metadata (spus, topics, partitions)
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataStoreConfig {
+    K8,                 // kubernetes custom resources
+    Local(PathBuf),     // json files in local directory
+}
+
+impl MetadataStoreConfig {
+    pub fn local_default() -> Self {
+        MetadataStoreConfig::Local(PathBuf::from(SC_LOCAL_STORE_DIR))
+    }
+
+    pub fn is_k8(&self) -> bool {
+        match self {
+            MetadataStoreConfig::K8 => true,
+            _ => false,
+        }
+    }
 }
 
 
@@ -50,9 +95,16 @@ impl ::std::default::Default for ScConfig {
             id: SC_DEFAULT_ID,
             public_endpoint: EndPoint::all_end_point(SC_PUBLIC_PORT),
             private_endpoint: EndPoint::all_end_point(SC_PRIVATE_PORT),
+            metrics_endpoint: EndPoint::all_end_point(SC_METRICS_PORT),
             run_k8_dispatchers: true,
-            namespace: "default".to_owned()
-
+            namespace: "default".to_owned(),
+            leader_rebalance_interval_sec: SC_LEADER_REBALANCE_INTERVAL_SEC,
+            election: ElectionConfig::default(),
+            metadata_store: MetadataStoreConfig::K8,
+            ha: HaConfig::default(),
+            sasl: SaslConfig::default(),
+            authorizer: AuthorizerConfig::default(),
+            public_server: KfServerConfig::default(),
         }
     }
 }
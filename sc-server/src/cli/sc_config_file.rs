@@ -10,8 +10,15 @@ use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::net::SocketAddr;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use kf_service::sasl::SaslMechanism;
+
+use crate::core::partitions::ElectionPolicyKind;
 
 use super::{ScConfig, ScConfigBuilder};
+use super::MetadataStoreConfig;
 
 // -----------------------------------
 // Data Structures
@@ -22,6 +29,14 @@ pub struct ScConfigFile {
     pub version: String,
     sc: Option<ScGroup>,
     bind_public: Option<BindGroup>,
+    bind_metrics: Option<BindGroup>,
+    leader_rebalance: Option<LeaderRebalanceGroup>,
+    election: Option<ElectionGroup>,
+    metadata_store: Option<MetadataStoreGroup>,
+    ha: Option<HaGroup>,
+    sasl: Option<SaslGroup>,
+    authorizer: Option<AuthorizerGroup>,
+    public_server: Option<ServerLimitsGroup>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -35,6 +50,51 @@ struct BindGroup {
     pub port: u16,
 }
 
+#[derive(Debug, PartialEq, Deserialize)]
+struct LeaderRebalanceGroup {
+    pub interval_sec: u64,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct ElectionGroup {
+    pub policy: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct MetadataStoreGroup {
+    pub kind: String,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct HaGroup {
+    pub enabled: Option<bool>,
+    pub lease_name: Option<String>,
+    pub lease_duration_sec: Option<u64>,
+    pub renew_interval_sec: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SaslGroup {
+    pub enabled: Option<bool>,
+    pub mechanisms: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct AuthorizerGroup {
+    pub enabled: Option<bool>,
+    pub super_users: Option<Vec<String>>,
+    pub allow_everyone_if_no_acl_found: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct ServerLimitsGroup {
+    pub max_frame_size: Option<usize>,
+    pub max_connections: Option<usize>,
+    pub idle_timeout_ms: Option<u64>,
+    pub max_requests_per_sec: Option<u32>,
+}
+
 // ---------------------------------------
 // Implementation
 // ---------------------------------------
@@ -59,6 +119,106 @@ impl ScConfigBuilder for ScConfigFile {
             sc_config.public_endpoint = bind_addr.into();
         }
 
+        // update metrics bind_addr (if configured)
+        if let Some(ref bind_metrics) = &self.bind_metrics {
+            let host_port_str = format!("{}:{}", bind_metrics.host, bind_metrics.port);
+
+            let bind_addr = host_port_str
+                .parse::<SocketAddr>()
+                .map_err(|err| IoError::new(ErrorKind::InvalidInput, format!("{}", err)))?;
+            sc_config.metrics_endpoint = bind_addr.into();
+        }
+
+        // update leader rebalance interval (if configured)
+        if let Some(ref leader_rebalance) = &self.leader_rebalance {
+            sc_config.leader_rebalance_interval_sec = leader_rebalance.interval_sec;
+        }
+
+        // update cluster election policy (if configured), topics override it in topic config
+        if let Some(ref election) = &self.election {
+            if let Some(ref policy) = &election.policy {
+                sc_config.election.policy = policy.parse::<ElectionPolicyKind>()?;
+            }
+        }
+
+        // update metadata store (if configured)
+        if let Some(ref metadata_store) = &self.metadata_store {
+            sc_config.metadata_store = match metadata_store.kind.as_str() {
+                "k8" => MetadataStoreConfig::K8,
+                "local" => match &metadata_store.path {
+                    Some(path) => MetadataStoreConfig::Local(PathBuf::from(path)),
+                    None => MetadataStoreConfig::local_default(),
+                },
+                kind => {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!("unknown metadata store '{}', expected: k8 or local", kind),
+                    ))
+                }
+            };
+        }
+
+        // update high availability (if configured)
+        if let Some(ref ha) = &self.ha {
+            if let Some(enabled) = ha.enabled {
+                sc_config.ha.enabled = enabled;
+            }
+            if let Some(ref lease_name) = &ha.lease_name {
+                sc_config.ha.lease_name = lease_name.clone();
+            }
+            if let Some(lease_duration_sec) = ha.lease_duration_sec {
+                sc_config.ha.lease_duration_sec = lease_duration_sec;
+            }
+            if let Some(renew_interval_sec) = ha.renew_interval_sec {
+                sc_config.ha.renew_interval_sec = renew_interval_sec;
+            }
+            if sc_config.ha.renew_interval_sec >= sc_config.ha.lease_duration_sec {
+                return Err(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "ha renew_interval_sec must be less than lease_duration_sec",
+                ));
+            }
+        }
+
+        // update sasl authentication (if configured)
+        if let Some(ref sasl) = &self.sasl {
+            if let Some(enabled) = sasl.enabled {
+                sc_config.sasl.enabled = enabled;
+            }
+            if let Some(ref mechanisms) = &sasl.mechanisms {
+                sc_config.sasl.mechanisms = SaslMechanism::from_names(mechanisms)?;
+            }
+        }
+
+        // update acl authorizer (if configured)
+        if let Some(ref authorizer) = &self.authorizer {
+            if let Some(enabled) = authorizer.enabled {
+                sc_config.authorizer.enabled = enabled;
+            }
+            if let Some(ref super_users) = &authorizer.super_users {
+                sc_config.authorizer.super_users = super_users.clone();
+            }
+            if let Some(allow) = authorizer.allow_everyone_if_no_acl_found {
+                sc_config.authorizer.allow_everyone_if_no_acl_found = allow;
+            }
+        }
+
+        // update public server limits (if configured)
+        if let Some(ref public_server) = &self.public_server {
+            if let Some(max_frame_size) = public_server.max_frame_size {
+                sc_config.public_server.max_frame_size = max_frame_size;
+            }
+            if public_server.max_connections.is_some() {
+                sc_config.public_server.max_connections = public_server.max_connections;
+            }
+            if let Some(idle_timeout_ms) = public_server.idle_timeout_ms {
+                sc_config.public_server.idle_timeout = Some(Duration::from_millis(idle_timeout_ms));
+            }
+            if public_server.max_requests_per_sec.is_some() {
+                sc_config.public_server.max_requests_per_sec = public_server.max_requests_per_sec;
+            }
+        }
+
         Ok(sc_config)
     }
 }
@@ -107,10 +267,43 @@ pub mod test {
                 host: "127.0.0.1".to_owned(),
                 port: 9999,
             }),
+            bind_metrics: None,
+            leader_rebalance: None,
+            election: None,
+            metadata_store: None,
+            ha: None,
+            sasl: None,
+            authorizer: None,
+            public_server: None,
         };
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[test]
+    fn test_sc_config_authorizer() {
+        let config_file: ScConfigFile = toml::from_str(
+            r#"
+            version = "1.0"
+
+            [authorizer]
+            enabled = true
+            super_users = ["User:admin"]
+            allow_everyone_if_no_acl_found = true
+            "#,
+        )
+        .expect("parse");
+
+        let sc_config = config_file.to_sc_config().expect("config");
+        assert!(sc_config.authorizer.enabled);
+        assert_eq!(sc_config.authorizer.super_users, vec!["User:admin".to_owned()]);
+        assert!(sc_config.authorizer.allow_everyone_if_no_acl_found);
+
+        // authorizer is opt-in, once enabled resources without acls are denied
+        let default_config = ScConfig::default();
+        assert!(!default_config.authorizer.enabled);
+        assert!(!default_config.authorizer.allow_everyone_if_no_acl_found);
+    }
+
     #[test]
     fn test_default_sc_config_not_found() {
         let mut sc_config_path = PathBuf::new();
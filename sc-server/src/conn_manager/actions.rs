@@ -38,14 +38,14 @@ impl std::fmt::Display for SpuConnectionStatusChange {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum SpuSpecChange {
     Add(SpuSpec),                                // New Spec
     Mod(SpuSpec,SpuSpec),                     // Update SPU spec (new,old)
     Remove(SpuSpec),  
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum PartitionSpecChange {
     Add(ReplicaKey,PartitionSpec),
     Mod(ReplicaKey,PartitionSpec,PartitionSpec),
@@ -53,11 +53,15 @@ pub enum PartitionSpecChange {
 }
 
 /// Request to made to Connection Manager
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ConnectionRequest {
     Spu(SpuSpecChange),
     Partition(PartitionSpecChange),
     RefreshSpu(SpuId),                                       // Refresh SPU with it' metadata including SPU and Replica
+    RefreshAcls,                                             // Send all ACL bindings to live SPUs
+    RefreshCredentials,                                      // Send all SASL credentials to live SPUs
+    RefreshQuotas,                                           // Send all client quotas to live SPUs
+    RefreshReplicationThrottle,                              // Send replication throttle override to live SPUs
 }
 
 
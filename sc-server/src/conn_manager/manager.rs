@@ -40,6 +40,10 @@ use internal_api::messages::ReplicaMsgs;
 use internal_api::UpdateSpuRequest;
 use internal_api::UpdateReplicaRequest;
 use internal_api::UpdateAllRequest;
+use internal_api::UpdateAclRequest;
+use internal_api::UpdateCredentialsRequest;
+use internal_api::UpdateQuotasRequest;
+use internal_api::UpdateReplicationThrottleRequest;
 use kf_protocol::api::Request;
 use kf_protocol::api::RequestMessage;
 
@@ -48,6 +52,15 @@ use crate::core::spus::SpuLocalStore;
 use crate::core::spus::SpuKV;
 use crate::core::partitions::SharedPartitionStore;
 use crate::core::partitions::PartitionLocalStore;
+use crate::core::topics::TopicLocalStore;
+use crate::core::acls::SharedAclLocalStore;
+use crate::core::acls::AclLocalStore;
+use crate::core::credentials::SharedCredentialLocalStore;
+use crate::core::credentials::CredentialLocalStore;
+use crate::core::quotas::SharedQuotaLocalStore;
+use crate::core::quotas::QuotaLocalStore;
+use crate::core::throttles::SharedThrottleLocalStore;
+use crate::core::throttles::ThrottleLocalStore;
 use crate::core::ShareLocalStores;
 use crate::ScServerError;
 
@@ -103,6 +116,11 @@ pub type SharedConnManager = Arc<ConnManager>;
 pub struct ConnManager {
     spu_store: SharedSpuLocalStore,
     partition_store: SharedPartitionStore,
+    topic_store: Arc<TopicLocalStore>,
+    acl_store: SharedAclLocalStore,
+    credential_store: SharedCredentialLocalStore,
+    quota_store: SharedQuotaLocalStore,
+    throttle_store: SharedThrottleLocalStore,
     conn_params: SimpleConcurrentBTreeMap<SpuId, ConnParams>,
     sinks: SinkPool<SpuId>,
     counter_tbl: CounterTable<SpuId, ConnCntr>, 
@@ -110,7 +128,7 @@ pub struct ConnManager {
 
 impl Default for ConnManager {
     fn default() -> Self {
-        Self::new(SpuLocalStore::new_shared(),PartitionLocalStore::new_shared())
+        Self::new(SpuLocalStore::new_shared(),PartitionLocalStore::new_shared(),TopicLocalStore::new_shared(),AclLocalStore::new_shared(),CredentialLocalStore::new_shared(),QuotaLocalStore::new_shared(),ThrottleLocalStore::new_shared())
     }
 }
 
@@ -120,14 +138,19 @@ impl ConnManager {
 
    
     pub fn new_with_local_stores(local_stores: ShareLocalStores) -> Self {
-        Self::new(local_stores.spus().clone(),local_stores.partitions().clone())
+        Self::new(local_stores.spus().clone(),local_stores.partitions().clone(),local_stores.topics().clone(),local_stores.acls().clone(),local_stores.credentials().clone(),local_stores.quotas().clone(),local_stores.replication_throttles().clone())
     }
 
     /// internal connection manager constructor
-    pub fn new(spu_store: SharedSpuLocalStore,partition_store: SharedPartitionStore) -> Self {
+    pub fn new(spu_store: SharedSpuLocalStore,partition_store: SharedPartitionStore,topic_store: Arc<TopicLocalStore>,acl_store: SharedAclLocalStore,credential_store: SharedCredentialLocalStore,quota_store: SharedQuotaLocalStore,throttle_store: SharedThrottleLocalStore) -> Self {
         ConnManager {
             spu_store,
             partition_store,
+            topic_store,
+            acl_store,
+            credential_store,
+            quota_store,
+            throttle_store,
             conn_params: SimpleConcurrentBTreeMap::new(),
             counter_tbl: CounterTable::default().with_columns(CONN_COUNTERS.to_vec()),
             sinks: SinkPool::new(),
@@ -220,6 +243,18 @@ impl ConnManager {
                 ConnectionRequest::RefreshSpu(spu_id) => {
                     log_on_err!(self.refresh_spu(spu_id).await);
                 },
+                ConnectionRequest::RefreshAcls => {
+                    self.send_to_all_live_spus("acls", |_| self.acl_request()).await;
+                },
+                ConnectionRequest::RefreshCredentials => {
+                    self.send_to_all_live_spus("credentials", |_| self.credentials_request()).await;
+                },
+                ConnectionRequest::RefreshQuotas => {
+                    self.send_to_all_live_spus("quotas", |_| self.quotas_request()).await;
+                },
+                ConnectionRequest::RefreshReplicationThrottle => {
+                    self.send_to_all_live_spus("replication throttle", |spu| self.replication_throttle_request(spu)).await;
+                },
                 ConnectionRequest::Partition(partition_req) => {
                     match partition_req {
                         PartitionSpecChange::Add(key,spec) => {
@@ -230,7 +265,9 @@ impl ConnManager {
                             self.refresh_partition(key,new_spec).await;
                             
                         }
-                        _ => {}
+                        PartitionSpecChange::Remove(key,spec) => {
+                            self.remove_partition(key,spec).await;
+                        }
                     }
                 }
             }
@@ -327,7 +364,8 @@ impl ConnManager {
             Replica::new(
                 key,
                 spec.leader,
-                spec.replicas.clone())));
+                spec.replicas.clone())
+                .with_config(spec.config.clone())));
 
         let request = UpdateReplicaRequest::encode_request(replica_msgs);
         let mut message = RequestMessage::new_request(request);
@@ -349,6 +387,54 @@ impl ConnManager {
     }
 
 
+    /// Ask all SPUs in the spec to remove replica
+    async fn remove_partition(&self,key: ReplicaKey,spec: PartitionSpec) {
+
+        let mut replica_msgs = ReplicaMsgs::default();
+        replica_msgs.push(ReplicaMsg::create_delete_msg(key.clone(),spec.leader));
+
+        for spu in spec.replicas {
+            debug!("sending replica remove: {} to spu: {}",key,spu);
+            match self.send_replica_msgs(&spu, replica_msgs.clone()).await {
+                Ok(status) => {
+                    if !status {
+                         trace!("unable to send remove partition: {} to offline spu: {}",key,spu);
+                    }
+                },
+                Err(err) => warn!("error {} sending remove partition: {} to spu: {}",err,key,spu)
+            }
+        }
+    }
+
+    /// send replica messages to spu, false if spu is not connected
+    async fn send_replica_msgs(&self,spu: &SpuId,replica_msgs: ReplicaMsgs) -> Result<bool,ScServerError> {
+        let request = UpdateReplicaRequest::encode_request(replica_msgs);
+        let mut message = RequestMessage::new_request(request);
+        message
+            .get_mut_header()
+            .set_client_id("controller");
+
+        self.send_msg(spu, &message).await
+    }
+
+    /// replicas of topics being deleted which are not yet confirmed by the spu.
+    /// by convention, first spu in replica map is leader
+    fn deleting_replicas_for_spu(&self,spu_id: SpuId) -> Vec<ReplicaMsg> {
+        let mut msgs = vec![];
+        self.topic_store.visit_values(|topic| {
+            if topic.status.is_resolution_deleting() {
+                for (partition,replicas) in topic.status.replica_map.iter() {
+                    if replicas.contains(&spu_id) {
+                        msgs.push(ReplicaMsg::create_delete_msg(
+                            ReplicaKey::new(topic.key_owned(),*partition),
+                            replicas[0]));
+                    }
+                }
+            }
+        });
+        msgs
+    }
+
     /// looks-up metadata and sends all SPUs and Replicas leaders associated with the SPU.
     async fn refresh_spu(
         &self,
@@ -395,9 +481,68 @@ impl ConnManager {
 
         self.send_msg(spu.id(),&message).await?;
 
+        // spu may have missed removal of replicas while it was offline
+        let deleting_replicas = self.deleting_replicas_for_spu(*spu.id());
+        if !deleting_replicas.is_empty() {
+            debug!("SEND SPU Metadata: >> ({}): {} replica removals",spu.id(),deleting_replicas.len());
+            self.send_replica_msgs(spu.id(),ReplicaMsgs::new(deleting_replicas)).await?;
+        }
+
+        self.send_controller_request(spu.id(), self.acl_request()).await?;
+        self.send_controller_request(spu.id(), self.credentials_request()).await?;
+        self.send_controller_request(spu.id(), self.quotas_request()).await?;
+        self.send_controller_request(spu.id(), self.replication_throttle_request(spu.id())).await?;
+
         Ok(())
     }
 
+    fn acl_request(&self) -> UpdateAclRequest {
+        UpdateAclRequest::new(self.acl_store.all_specs())
+    }
+
+    fn credentials_request(&self) -> UpdateCredentialsRequest {
+        UpdateCredentialsRequest::new(self.credential_store.credential_specs())
+    }
+
+    fn quotas_request(&self) -> UpdateQuotasRequest {
+        UpdateQuotasRequest::new(self.quota_store.all_specs())
+    }
+
+    fn replication_throttle_request(&self, spu: &SpuId) -> UpdateReplicationThrottleRequest {
+        UpdateReplicationThrottleRequest::new(self.throttle_store.rate(spu))
+    }
+
+    /// send request from controller to spu, false if spu is not connected
+    async fn send_controller_request<R>(&self, spu: &SpuId, request: R) -> Result<bool, ScServerError>
+        where R: Request + Send + Sync + 'static
+    {
+        debug!("SEND SPU Metadata: >> ({}): api key: {}", spu, R::API_KEY);
+        trace!("{:#?}", request);
+
+        let mut message = RequestMessage::new_request(request);
+        message
+            .get_mut_header()
+            .set_client_id("controller");
+
+        self.send_msg(spu, &message).await
+    }
+
+    /// send request built for each live SPU, used for metadata which SPU receives as full set
+    async fn send_to_all_live_spus<R, F>(&self, label: &str, build_request: F)
+        where
+            R: Request + Send + Sync + 'static,
+            F: Fn(&SpuId) -> R
+    {
+        let online_spus = self.spu_store.online_spus();
+        debug!("trying to send {} to active Spu: {}", label, online_spus.len());
+        for live_spu in online_spus {
+            let request = build_request(live_spu.id());
+            if let Err(err) = self.send_controller_request(live_spu.id(), request).await {
+                error!("error sending {} {}", label, err);
+            }
+        }
+    }
+
     
     /// send messages to all live SPU
     async fn send_msg_to_all_live_spus(&self,msgs: Vec<SpuMsg>) {
@@ -0,0 +1,98 @@
+//!
+//! # ACL Authorizer
+//!
+//! Authorizes public API requests with ACL bindings cached on SC and authorizer config.
+//!
+use metadata::acl::AclIdentity;
+use metadata::acl::AclResourceType;
+use metadata::acl::AclOperation;
+use metadata::acl::AuthorizerConfig;
+use metadata::acl::authorize;
+use metadata::acl::authorize_explicit;
+use metadata::acl::ACL_CLUSTER_NAME;
+
+use super::SharedAclLocalStore;
+
+#[derive(Debug)]
+pub struct AclAuthorizer {
+    acls: SharedAclLocalStore,
+    config: AuthorizerConfig,
+}
+
+impl AclAuthorizer {
+    pub fn new(acls: SharedAclLocalStore, config: AuthorizerConfig) -> Self {
+        Self { acls, config }
+    }
+
+    /// check if identity may perform operation on resource
+    pub fn authorize(
+        &self,
+        identity: &AclIdentity,
+        resource_type: &AclResourceType,
+        resource_name: &str,
+        operation: &AclOperation,
+    ) -> bool {
+        authorize(
+            self.acls.inner_store().read().values().map(|acl| &acl.spec),
+            &self.config,
+            identity,
+            resource_type,
+            resource_name,
+            operation,
+        )
+    }
+
+    pub fn authorize_topic(
+        &self,
+        identity: &AclIdentity,
+        topic: &str,
+        operation: &AclOperation,
+    ) -> bool {
+        self.authorize(identity, &AclResourceType::Topic, topic, operation)
+    }
+
+    pub fn authorize_cluster(&self, identity: &AclIdentity, operation: &AclOperation) -> bool {
+        self.authorize(identity, &AclResourceType::Cluster, ACL_CLUSTER_NAME, operation)
+    }
+
+    /// cluster operation that must be granted by ACL or super user.
+    /// used by ACL APIs, so cluster without ACLs can't be taken over by any client
+    pub fn authorize_cluster_explicit(&self, identity: &AclIdentity, operation: &AclOperation) -> bool {
+        authorize_explicit(
+            self.acls.inner_store().read().values().map(|acl| &acl.spec),
+            &self.config,
+            identity,
+            &AclResourceType::Cluster,
+            ACL_CLUSTER_NAME,
+            operation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use metadata::acl::AclIdentity;
+    use metadata::acl::AclOperation;
+
+    use crate::cli::ScConfig;
+    use super::super::AclLocalStore;
+    use super::AclAuthorizer;
+
+    #[test]
+    fn test_default_config_allows_anonymous() {
+        let mut config = ScConfig::default();
+        let anonymous = AclIdentity::anonymous("10.0.0.1");
+
+        // create-topic and fetch topics from unauthenticated client, no acls
+        let authorizer = AclAuthorizer::new(AclLocalStore::new_shared(), config.authorizer.clone());
+        assert!(authorizer.authorize_topic(&anonymous, "test", &AclOperation::Create));
+        assert!(authorizer.authorize_topic(&anonymous, "test", &AclOperation::Describe));
+        assert!(authorizer.authorize_cluster_explicit(&anonymous, &AclOperation::Alter));
+
+        // once enabled, resource without acls is denied
+        config.authorizer.enabled = true;
+        let authorizer = AclAuthorizer::new(AclLocalStore::new_shared(), config.authorizer);
+        assert!(!authorizer.authorize_topic(&anonymous, "test", &AclOperation::Create));
+        assert!(!authorizer.authorize_topic(&anonymous, "test", &AclOperation::Describe));
+    }
+}
@@ -0,0 +1,67 @@
+//!
+//! # ACL Metadata
+//!
+//! ACL bindings cached on SC, used to authorize public API requests.
+//!
+use std::io::Error as IoError;
+
+use metadata::acl::{AclSpec, AclStatus};
+use metadata::acl::AclFilter;
+use k8_metadata::acl::AclSpec as K8AclSpec;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+
+use crate::core::common::LocalStore;
+use crate::core::common::KVObject;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::k8::default_convert_from_k8;
+
+impl Spec for AclSpec {
+    const LABEL: &'static str = "Acl";
+    type Key = String;
+    type Status = AclStatus;
+    type K8Spec = K8AclSpec;
+    type Owner = AclSpec;
+
+    fn convert_from_k8(
+        k8_obj: K8Obj<Self::K8Spec, <Self::K8Spec as K8Spec>::Status>,
+    ) -> Result<KVObject<Self>, IoError> {
+        default_convert_from_k8(k8_obj)
+    }
+}
+
+impl Status for AclStatus {}
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+pub type AclKV = KVObject<AclSpec>;
+pub type AclLocalStore = LocalStore<AclSpec>;
+
+// -----------------------------------
+// AclKV - Implementation
+// -----------------------------------
+
+impl From<AclSpec> for AclKV {
+    fn from(spec: AclSpec) -> Self {
+        Self::with_spec(spec.key(), spec)
+    }
+}
+
+// -----------------------------------
+// AclLocalStore - Implementation
+// -----------------------------------
+
+impl AclLocalStore {
+    /// all bindings selected by filter
+    pub fn filter(&self, filter: &AclFilter) -> Vec<AclKV> {
+        self.inner_store()
+            .read()
+            .values()
+            .filter(|acl| filter.matches(&acl.spec))
+            .cloned()
+            .collect()
+    }
+}
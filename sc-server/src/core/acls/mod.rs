@@ -0,0 +1,14 @@
+mod metadata;
+mod authorizer;
+
+pub use self::metadata::{AclKV, AclLocalStore};
+pub use self::authorizer::AclAuthorizer;
+
+use std::sync::Arc;
+use ::metadata::acl::AclSpec;
+use crate::core::common::SpecRefreshController;
+use crate::k8::K8ClusterStateDispatcher;
+
+pub type AclController = SpecRefreshController<AclSpec>;
+pub type K8AclChangeDispatcher = K8ClusterStateDispatcher<AclSpec>;
+pub type SharedAclLocalStore = Arc<AclLocalStore>;
@@ -0,0 +1,32 @@
+//!
+//! # Auth Token Metadata
+//!
+//! Auth tokens written through world store by SC, used by SPUs to register.
+//!
+use std::io::Error as IoError;
+
+use metadata::auth_token::{AuthTokenSpec, AuthTokenStatus};
+use k8_metadata::auth_token::AuthTokenSpec as K8AuthTokenSpec;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+
+use crate::core::common::KVObject;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::k8::default_convert_from_k8;
+
+impl Spec for AuthTokenSpec {
+    const LABEL: &'static str = "AuthToken";
+    type Key = String;
+    type Status = AuthTokenStatus;
+    type K8Spec = K8AuthTokenSpec;
+    type Owner = AuthTokenSpec;
+
+    fn convert_from_k8(
+        k8_obj: K8Obj<Self::K8Spec, <Self::K8Spec as K8Spec>::Status>,
+    ) -> Result<KVObject<Self>, IoError> {
+        default_convert_from_k8(k8_obj)
+    }
+}
+
+impl Status for AuthTokenStatus {}
@@ -0,0 +1 @@
+mod metadata;
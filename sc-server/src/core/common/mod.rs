@@ -3,6 +3,7 @@ mod kv_context;
 mod store;
 mod kv_obj;
 mod actions;
+mod refresh_controller;
 
 #[cfg(test)]
 pub mod test_fixtures;
@@ -13,3 +14,4 @@ pub use self::store::LocalStore;
 pub use self::kv_obj::KVObject;
 pub use self::actions::LSChange;
 pub use self::actions::WSAction;
+pub use self::refresh_controller::SpecRefreshController;
@@ -0,0 +1,78 @@
+//!
+//! # Spec Refresh Controller
+//!
+//! Pushes specs to SPUs whenever specs of a type change. SPUs always receive the
+//! full set, so changes are not tracked individually. Used for ACLs, credentials, quotas
+//! and replication throttles.
+//!
+use std::fmt::Debug;
+
+use log::info;
+use log::trace;
+use log::error;
+use futures::stream::StreamExt;
+
+use future_helper::spawn;
+use utils::actions::Actions;
+
+use crate::conn_manager::SharedConnManager;
+use crate::conn_manager::ConnectionRequest;
+use crate::core::Spec;
+use crate::core::WSChangeChannel;
+
+#[derive(Debug)]
+pub struct SpecRefreshController<S>
+where
+    S: Spec,
+    S::Key: Debug,
+    S::Status: Debug + PartialEq,
+{
+    conn_manager: SharedConnManager,
+    receiver: WSChangeChannel<S>,
+    refresh_request: ConnectionRequest,
+}
+
+impl<S> SpecRefreshController<S>
+where
+    S: Spec + Send + Sync + 'static,
+    S::Key: Debug + Send + Sync,
+    S::Status: Debug + PartialEq + Send + Sync,
+{
+    /// refresh request is sent to connection manager on every change
+    pub fn new(
+        conn_manager: SharedConnManager,
+        receiver: WSChangeChannel<S>,
+        refresh_request: ConnectionRequest,
+    ) -> Self {
+        Self {
+            conn_manager,
+            receiver,
+            refresh_request,
+        }
+    }
+
+    pub fn run(self) {
+        spawn(self.dispatch_loop());
+    }
+
+    async fn dispatch_loop(mut self) {
+        loop {
+            match self.receiver.next().await {
+                None => {
+                    error!("{} LC dispatcher has been terminated. Ending server loop", S::LABEL);
+                    break;
+                }
+                Some(changes) => {
+                    trace!("received {} changes: {}", S::LABEL, changes.count());
+                    if changes.count() > 0 {
+                        let mut requests = Actions::default();
+                        requests.push(self.refresh_request.clone());
+                        self.conn_manager.process_requests(requests).await;
+                    }
+                }
+            }
+        }
+
+        info!("{} controller is terminated", S::LABEL);
+    }
+}
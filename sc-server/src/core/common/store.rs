@@ -1,4 +1,6 @@
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::borrow::Borrow;
@@ -11,9 +13,9 @@ use crate::core::Spec;
 
 use super::KVObject;
 
-/// Local state in memory
+/// Local state in memory, synced once initial objects are loaded from metadata store
 #[derive(Debug)]
-pub struct LocalStore<S>(SimpleConcurrentBTreeMap<S::Key, KVObject<S>>)
+pub struct LocalStore<S>(SimpleConcurrentBTreeMap<S::Key, KVObject<S>>, AtomicBool)
 where
     S: Spec,
     <S as Spec>::Status: Debug;
@@ -24,7 +26,7 @@ where
     <S as Spec>::Status: Debug,
 {
     fn default() -> Self {
-        LocalStore(SimpleConcurrentBTreeMap::new())
+        LocalStore(SimpleConcurrentBTreeMap::new(), AtomicBool::new(false))
     }
 }
 
@@ -61,6 +63,15 @@ where
         &self.0
     }
 
+    /// initial objects have been loaded from metadata store
+    pub fn mark_synced(&self) {
+        self.1.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.1.load(Ordering::SeqCst)
+    }
+
     pub fn insert(&self, value: KVObject<S>) -> Option<KVObject<S>> {
         self.inner_store().write().insert(value.key_owned(), value)
     }
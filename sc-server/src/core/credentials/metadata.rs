@@ -0,0 +1,82 @@
+//!
+//! # SASL Credential Metadata
+//!
+//! SCRAM credentials cached on SC, used to authenticate public API connections.
+//!
+use std::io::Error as IoError;
+
+use log::warn;
+
+use kf_service::sasl::SaslCredentialLookup;
+use kf_service::sasl::SaslMechanism;
+use kf_service::sasl::ScramCredential;
+use kf_service::sasl::ScramMechanism;
+use metadata::auth_token::{SaslCredentialSpec, SaslCredentialStatus};
+use k8_metadata::auth_token::SaslCredentialSpec as K8SaslCredentialSpec;
+use k8_metadata::auth_token::credential_name;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+
+use crate::core::common::LocalStore;
+use crate::core::common::KVObject;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::k8::default_convert_from_k8;
+
+impl Spec for SaslCredentialSpec {
+    const LABEL: &'static str = "SaslCredential";
+    type Key = String;
+    type Status = SaslCredentialStatus;
+    type K8Spec = K8SaslCredentialSpec;
+    type Owner = SaslCredentialSpec;
+
+    fn convert_from_k8(
+        k8_obj: K8Obj<Self::K8Spec, <Self::K8Spec as K8Spec>::Status>,
+    ) -> Result<KVObject<Self>, IoError> {
+        default_convert_from_k8(k8_obj)
+    }
+}
+
+impl Status for SaslCredentialStatus {}
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+pub type CredentialLocalStore = LocalStore<SaslCredentialSpec>;
+
+// -----------------------------------
+// CredentialLocalStore - Implementation
+// -----------------------------------
+
+impl CredentialLocalStore {
+    /// specs of credentials stored under the name derived from their user and mechanism,
+    /// objects under any other name are ignored so each user has at most one credential
+    pub fn credential_specs(&self) -> Vec<SaslCredentialSpec> {
+        self.inner_store()
+            .read()
+            .iter()
+            .filter(|(name, credential)| is_credential_name(name, &credential.spec))
+            .map(|(_, credential)| credential.spec.clone())
+            .collect()
+    }
+}
+
+impl SaslCredentialLookup for CredentialLocalStore {
+    fn scram_credential(&self, mechanism: &ScramMechanism, user: &str) -> Option<ScramCredential> {
+        let mechanism_name = SaslMechanism::Scram(mechanism.clone()).name();
+        let name = credential_name(user, mechanism_name);
+        self.value(&name)
+            .filter(|credential| credential.spec.matches(user, mechanism_name))
+            .and_then(|credential| {
+                let spec = &credential.spec;
+                ScramCredential::decode(&spec.salt, spec.iterations, &spec.stored_key, &spec.server_key)
+                    .map_err(|err| warn!("invalid credential {}: {}", credential.key(), err))
+                    .ok()
+            })
+    }
+}
+
+fn is_credential_name(name: &str, spec: &SaslCredentialSpec) -> bool {
+    name == credential_name(&spec.user, spec.mechanism.sasl_name())
+}
@@ -0,0 +1,12 @@
+mod metadata;
+
+pub use self::metadata::CredentialLocalStore;
+
+use std::sync::Arc;
+use ::metadata::auth_token::SaslCredentialSpec;
+use crate::core::common::SpecRefreshController;
+use crate::k8::K8ClusterStateDispatcher;
+
+pub type CredentialController = SpecRefreshController<SaslCredentialSpec>;
+pub type K8CredentialChangeDispatcher = K8ClusterStateDispatcher<SaslCredentialSpec>;
+pub type SharedCredentialLocalStore = Arc<CredentialLocalStore>;
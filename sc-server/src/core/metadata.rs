@@ -5,11 +5,29 @@
 //!
 use std::sync::Arc;
 
+use kf_service::RequestMetrics;
+use kf_service::SharedRequestMetrics;
+use kf_service::HealthCheck;
+use utils::metrics::MetricsRegistry;
+use utils::metrics::SharedMetricsRegistry;
+
 use crate::cli::ScConfig;
 use crate::core::partitions::PartitionLocalStore;
 use crate::core::spus::SharedSpuLocalStore;
 use crate::core::spus::SpuLocalStore;
 use crate::core::topics::TopicLocalStore;
+use crate::core::acls::AclLocalStore;
+use crate::core::acls::SharedAclLocalStore;
+use crate::core::acls::AclAuthorizer;
+use crate::core::credentials::CredentialLocalStore;
+use crate::core::credentials::SharedCredentialLocalStore;
+use crate::core::quotas::QuotaLocalStore;
+use crate::core::quotas::SharedQuotaLocalStore;
+use crate::core::throttles::ThrottleLocalStore;
+use crate::core::throttles::SharedThrottleLocalStore;
+use crate::core::metrics::LocalStoreCollector;
+use crate::core::metrics::SC_METRICS_PREFIX;
+use crate::core::metrics::sc_api_key_name;
 
 pub type ShareLocalStores = Arc<LocalStores>;
 
@@ -18,6 +36,13 @@ pub struct LocalStores {
     spus: SharedSpuLocalStore,
     partitions: Arc<PartitionLocalStore>,
     topics: Arc<TopicLocalStore>,
+    acls: SharedAclLocalStore,
+    authorizer: AclAuthorizer,
+    credentials: SharedCredentialLocalStore,
+    quotas: SharedQuotaLocalStore,
+    replication_throttles: SharedThrottleLocalStore,
+    metrics: SharedMetricsRegistry,
+    request_metrics: SharedRequestMetrics,
     config: ScConfig,
 
 }
@@ -33,10 +58,32 @@ impl LocalStores {
 
     /// private function to provision metadata
     fn new(config: ScConfig) -> Self {
+        let spus = SpuLocalStore::new_shared();
+        let partitions = PartitionLocalStore::new_shared();
+        let topics = TopicLocalStore::new_shared();
+        let acls = AclLocalStore::new_shared();
+        let authorizer = AclAuthorizer::new(acls.clone(), config.authorizer.clone());
+
+        let metrics = MetricsRegistry::new_shared();
+        metrics.add_collector(LocalStoreCollector::new(
+            spus.clone(),
+            topics.clone(),
+            partitions.clone(),
+        ));
+        let request_metrics =
+            RequestMetrics::new_shared(SC_METRICS_PREFIX, metrics.clone(), sc_api_key_name);
+
         LocalStores {
-            spus: SpuLocalStore::new_shared(),
-            partitions: PartitionLocalStore::new_shared(),
-            topics: TopicLocalStore::new_shared(),
+            spus,
+            partitions,
+            topics,
+            acls,
+            authorizer,
+            credentials: CredentialLocalStore::new_shared(),
+            quotas: QuotaLocalStore::new_shared(),
+            replication_throttles: ThrottleLocalStore::new_shared(),
+            metrics,
+            request_metrics,
             config: config,
         }
     }
@@ -61,11 +108,57 @@ impl LocalStores {
         &self.topics
     }
 
+    /// reference to acls
+    pub fn acls(&self) -> &SharedAclLocalStore {
+        &self.acls
+    }
+
+    /// authorizer for public api requests
+    pub fn authorizer(&self) -> &AclAuthorizer {
+        &self.authorizer
+    }
+
+    /// reference to sasl credentials
+    pub fn credentials(&self) -> &SharedCredentialLocalStore {
+        &self.credentials
+    }
+
+    /// reference to client quotas
+    pub fn quotas(&self) -> &SharedQuotaLocalStore {
+        &self.quotas
+    }
+
+    /// reference to replication throttle overrides
+    pub fn replication_throttles(&self) -> &SharedThrottleLocalStore {
+        &self.replication_throttles
+    }
+
+    /// reference to metrics registry
+    pub fn metrics(&self) -> &SharedMetricsRegistry {
+        &self.metrics
+    }
+
+    /// request rate and latency of public api
+    pub fn request_metrics(&self) -> &SharedRequestMetrics {
+        &self.request_metrics
+    }
+
     /// reference to config
     pub fn config(&self) -> &ScConfig {
         &self.config
     }
 
+    /// all stores have loaded initial objects from metadata store
+    pub fn is_synced(&self) -> bool {
+        self.spus.is_synced()
+            && self.topics.is_synced()
+            && self.partitions.is_synced()
+            && self.acls.is_synced()
+            && self.credentials.is_synced()
+            && self.quotas.is_synced()
+            && self.replication_throttles.is_synced()
+    }
+
     /// format metadata cache into a table string
     #[allow(dead_code)]
     pub fn table_fmt(&self) -> String {
@@ -80,3 +173,9 @@ impl LocalStores {
         table
     }
 }
+
+impl HealthCheck for LocalStores {
+    fn is_ready(&self) -> bool {
+        self.is_synced()
+    }
+}
@@ -0,0 +1,77 @@
+//!
+//! # SC Metrics
+//!
+//! Counts of spus, topics and partitions are read from local stores when metrics are scraped.
+//!
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use sc_api::ScApiKey;
+use utils::metrics::MetricsCollector;
+use utils::metrics::MetricsRegistry;
+
+use crate::core::spus::SharedSpuLocalStore;
+use crate::core::topics::TopicLocalStore;
+use crate::core::partitions::PartitionLocalStore;
+
+pub const SC_METRICS_PREFIX: &str = "sc";
+
+const SPUS: &str = "sc_spus";
+const ONLINE_SPUS: &str = "sc_online_spus";
+const TOPICS: &str = "sc_topics";
+const PARTITIONS: &str = "sc_partitions";
+const OFFLINE_PARTITIONS: &str = "sc_offline_partitions";
+
+/// api key label of sc request
+pub fn sc_api_key_name(api_key: u16) -> String {
+    ScApiKey::try_from(api_key)
+        .map(|key| format!("{:?}", key))
+        .unwrap_or_else(|_| api_key.to_string())
+}
+
+/// refresh metadata gauges from local stores
+pub struct LocalStoreCollector {
+    spus: SharedSpuLocalStore,
+    topics: Arc<TopicLocalStore>,
+    partitions: Arc<PartitionLocalStore>,
+}
+
+impl LocalStoreCollector {
+    pub fn new(
+        spus: SharedSpuLocalStore,
+        topics: Arc<TopicLocalStore>,
+        partitions: Arc<PartitionLocalStore>,
+    ) -> Self {
+        Self {
+            spus,
+            topics,
+            partitions,
+        }
+    }
+}
+
+impl MetricsCollector for LocalStoreCollector {
+    fn collect(&self, registry: &MetricsRegistry) {
+        registry
+            .gauge(SPUS, "Registered spus", &[])
+            .set(i64::from(self.spus.count()));
+        registry
+            .gauge(ONLINE_SPUS, "Online spus", &[])
+            .set(i64::from(self.spus.online_spu_count()));
+        registry
+            .gauge(TOPICS, "Topics", &[])
+            .set(i64::from(self.topics.count()));
+
+        let partitions = self.partitions.all_values();
+        let offline = partitions
+            .iter()
+            .filter(|partition| partition.status.is_offline())
+            .count();
+        registry
+            .gauge(PARTITIONS, "Partitions", &[])
+            .set(partitions.len() as i64);
+        registry
+            .gauge(OFFLINE_PARTITIONS, "Partitions without online leader", &[])
+            .set(offline as i64);
+    }
+}
@@ -3,15 +3,23 @@ mod metadata;
 mod world_store;
 
 pub mod common;
+pub mod acls;
+pub mod auth_tokens;
+pub mod credentials;
+pub mod quotas;
+pub mod throttles;
 pub mod partitions;
 pub mod spus;
 pub mod topics;
+pub mod watch;
+pub mod metrics;
 
 
 pub use self::metadata::{LocalStores, ShareLocalStores};
 pub use self::world_store::WSUpdateService;
 pub use self::world_store::WSChangeChannel;
 pub use self::world_store::WSChangeDispatcher;
+pub use self::world_store::ResyncNotifier;
 
 use std::io::Error as IoError;
 
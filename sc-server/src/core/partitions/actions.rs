@@ -25,7 +25,8 @@ use super::PartitionLSChange;
 pub enum PartitionChangeRequest {
     Partition(Actions<PartitionLSChange>),
     Spu(Actions<SpuLSChange>),
-    LrsUpdate(UpdateLrsRequest)
+    LrsUpdate(UpdateLrsRequest),
+    RebalanceLeaders
 }
 
 
@@ -35,7 +36,8 @@ impl fmt::Display for PartitionChangeRequest {
         match self {
             PartitionChangeRequest::Partition(req) =>  write!(f,"Partition LS: {}",req.count()),
             PartitionChangeRequest::Spu(req) => write!(f,"SPU LS: {}",req.count()),
-            PartitionChangeRequest::LrsUpdate(lrs) => write!(f,"Lrs Rep: {}",lrs.id)
+            PartitionChangeRequest::LrsUpdate(lrs) => write!(f,"Lrs Rep: {}",lrs.id),
+            PartitionChangeRequest::RebalanceLeaders => write!(f,"Rebalance Leaders")
         }
     }
 }
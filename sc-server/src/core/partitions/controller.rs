@@ -2,19 +2,26 @@
 //! # Auth Controller
 //!
 
+use std::time::Duration;
+use std::time::Instant;
+
 use log::trace;
 use log::error;
 use log::info;
+use log::debug;
 use futures::select;
+use futures::future::FutureExt;
 use futures::stream::StreamExt;
 use futures::channel::mpsc::Receiver;
 use futures::channel::mpsc::Sender;
 use futures::channel::mpsc::channel;
 
 use types::log_on_err;
+use types::defaults::SC_LEADER_REBALANCE_INTERVAL_SEC;
 use metadata::partition::PartitionSpec;
 use metadata::spu::SpuSpec;
 use future_helper::spawn;
+use future_helper::sleep;
 use internal_api::UpdateLrsRequest;
 
 use crate::core::WSUpdateService;
@@ -60,7 +67,9 @@ impl<W> PartitionController<W>
             reducer: PartitionReducer::new(
                 local_stores.partitions().clone(),
                 local_stores.spus().clone()
-            ),
+            )
+            .with_election_config(local_stores.config().election.clone())
+            .with_topic_store(local_stores.topics().clone()),
             spu_receiver,
             partition_receiver,
             lrs_receiver,
@@ -79,8 +88,33 @@ impl<W> PartitionController<W>
 
     async fn dispatch_loop(mut self)  {
 
+        let rebalance_interval_sec = self.local_stores.config().leader_rebalance_interval_sec;
+        let rebalance_enabled = rebalance_interval_sec > 0;
+        let rebalance_interval = if rebalance_enabled {
+            Duration::from_secs(rebalance_interval_sec)
+        } else {
+            Duration::from_secs(SC_LEADER_REBALANCE_INTERVAL_SEC)
+        };
+        let mut next_rebalance = Instant::now() + rebalance_interval;
+
         loop {
+
+            // timer is reset on every request, so keep track of deadline separately
+            let now = Instant::now();
+            let rebalance_wait = if next_rebalance > now {
+                next_rebalance - now
+            } else {
+                Duration::from_secs(0)
+            };
+
             select! {
+                _ = (sleep(rebalance_wait)).fuse() => {
+                    next_rebalance = Instant::now() + rebalance_interval;
+                    if rebalance_enabled {
+                        debug!("timer fired - kickoff preferred leader election");
+                        self.process_request(PartitionChangeRequest::RebalanceLeaders).await;
+                    }
+                },
                 partition_req = self.partition_receiver.next() => {
                     match partition_req {
                         None => {
@@ -4,6 +4,7 @@
 //! Partition metadata information on cached in the local Controller.
 //!
 use std::io::Error as IoError;
+use std::collections::HashSet;
 
 use log::debug;
 
@@ -149,11 +150,36 @@ impl PartitionLocalStore {
                         partition_spec.leader,
                         partition_spec.replicas
                     )
+                    .with_config(partition_spec.config)
             ).collect();
         debug!("{} computing replic msg for spuy: {}, msg: {}",self,target_spu,msgs.len());
         msgs
     }
 
+    /// find partitions whose leader can be moved back to preferred replica.
+    /// preferred replica must be online and in sync with current leader.
+    /// returns partition with leader set to preferred replica, along with current leader.
+    /// if topics is empty, all partitions are checked
+    pub fn preferred_leader_moves(&self, online: &HashSet<SpuId>, topics: &[String]) -> Vec<(PartitionKV, SpuId)> {
+        let mut moves = vec![];
+        for (name, partition) in self.inner_store().read().iter() {
+            if !topics.is_empty() && !topics.contains(&name.topic) {
+                continue;
+            }
+            if !partition.status.is_online() || partition.spec.is_preferred_leader() {
+                continue;
+            }
+            if let Some(preferred) = partition.spec.preferred_leader() {
+                if online.contains(&preferred) && partition.status.is_replica_in_sync(preferred) {
+                    let mut part_kv_change = partition.clone();
+                    part_kv_change.spec.leader = preferred;
+                    moves.push((part_kv_change, partition.spec.leader));
+                }
+            }
+        }
+        moves
+    }
+
     pub fn table_fmt(&self) -> String {
         let mut table = String::new();
 
@@ -213,6 +239,12 @@ impl <S>From<Vec<((S, i32), Vec<i32>)>> for PartitionLocalStore where S: Into<St
 #[cfg(test)]
 pub mod test {
 
+    use std::collections::HashSet;
+
+    use metadata::partition::ReplicaKey;
+    use metadata::partition::PartitionStatus;
+    use metadata::partition::PartitionResolution;
+
     use super::PartitionLocalStore;
 
     #[test]
@@ -227,4 +259,42 @@ pub mod test {
 
     }
 
+    #[test]
+    fn test_preferred_leader_moves() {
+
+        let partitions = PartitionLocalStore::default();
+        partitions.bulk_add(vec![
+            (("topic1", 0), vec![10,11]),
+            (("topic1", 1), vec![11,10]),
+            (("topic2", 0), vec![10,11]),
+        ]);
+
+        // topic1-0 and topic2-0 failed over to 11, 10 has caught up only for topic1-0
+        for &(key,leo) in &[(("topic1",0),100),(("topic2",0),90)] {
+            let key: ReplicaKey = key.into();
+            let mut partition = partitions.value(&key).expect("partition");
+            partition.spec.leader = 11;
+            partition.status = PartitionStatus::new2((11,100,100),vec![(10,leo,leo).into()],PartitionResolution::Online);
+            partitions.insert(partition);
+        }
+
+        let mut online = HashSet::new();
+        online.insert(10);
+        online.insert(11);
+
+        let moves = partitions.preferred_leader_moves(&online,&[]);
+        assert_eq!(moves.len(),1);
+        let (partition,from) = &moves[0];
+        assert_eq!(partition.key,("topic1",0).into());
+        assert_eq!(partition.spec.leader,10);
+        assert_eq!(*from,11);
+
+        // filtered out by topic
+        assert_eq!(partitions.preferred_leader_moves(&online,&["topic2".to_owned()]).len(),0);
+
+        // preferred is offline
+        online.remove(&10);
+        assert_eq!(partitions.preferred_leader_moves(&online,&[]).len(),0);
+    }
+
 }
\ No newline at end of file
@@ -2,12 +2,14 @@ mod actions;
 mod metadata;
 mod controller;
 mod reducer;
+mod policy;
 
 
 pub use self::actions::PartitionActions;
 pub use self::actions::PartitionChangeRequest;
 pub use self::metadata::{PartitionKV, PartitionLocalStore};
 pub use self::controller::PartitionController;
+pub use self::policy::{ElectionConfig, ElectionPolicyKind};
 
 use std::sync::Arc;
 use ::metadata::partition::PartitionSpec;
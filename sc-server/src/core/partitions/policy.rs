@@ -0,0 +1,296 @@
+//!
+//! # Leader Election Policies
+//!
+//! Built-in policies used by partition reducer to score candidate leaders.
+//! Policy is selected in SC configuration and can be overridden per topic through
+//! topic config `leader.election.policy` or `unclean.leader.election.enable`.
+//!
+use std::fmt;
+use std::collections::HashMap;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use types::SpuId;
+use metadata::partition::ReplicaStatus;
+use metadata::partition::ElectionPolicy;
+use metadata::partition::ElectionScoring;
+use metadata::topic::TopicConfig;
+use metadata::topic::LEADER_ELECTION_POLICY;
+use metadata::topic::UNCLEAN_LEADER_ELECTION_ENABLE;
+
+/// max lag of end offset for replica to be considered in sync
+pub const MAX_LEADER_LAG: i64 = 4;
+
+/// score penalty for candidate in same rack as current leader
+const SAME_RACK_PENALTY: u16 = MAX_LEADER_LAG as u16;
+
+/// score offset for out of sync replica, so in sync replica is always preferred
+const UNCLEAN_SCORE_BASE: u16 = 2 * SAME_RACK_PENALTY + MAX_LEADER_LAG as u16;
+
+// -----------------------------------
+// Policy Configuration
+// -----------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ElectionPolicyKind {
+    LowestLag,      // in sync replica with least lag
+    RackDiverse,    // prefer in sync replica outside of leader's rack
+    Unclean,        // allow out of sync replica if no in sync replica remains
+}
+
+impl Default for ElectionPolicyKind {
+    fn default() -> Self {
+        ElectionPolicyKind::LowestLag
+    }
+}
+
+impl fmt::Display for ElectionPolicyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::LowestLag => write!(f, "lowest-lag"),
+            Self::RackDiverse => write!(f, "rack-diverse"),
+            Self::Unclean => write!(f, "unclean"),
+        }
+    }
+}
+
+impl FromStr for ElectionPolicyKind {
+    type Err = IoError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "lowest-lag" => Ok(Self::LowestLag),
+            "rack-diverse" => Ok(Self::RackDiverse),
+            "unclean" => Ok(Self::Unclean),
+            _ => Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "unknown election policy '{}', expected: lowest-lag, rack-diverse or unclean",
+                    value
+                ),
+            )),
+        }
+    }
+}
+
+/// cluster wide election policy, topics may override it in their config
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ElectionConfig {
+    pub policy: ElectionPolicyKind,
+}
+
+impl ElectionConfig {
+    /// policy for topic with given config, topic override takes precedence.
+    /// explicit policy wins over unclean flag (unclean policy with disabled flag is rejected
+    /// by topic config validation), invalid values fall back to cluster policy
+    pub fn policy_kind(&self, topic_config: Option<&TopicConfig>) -> ElectionPolicyKind {
+        let topic_config = match topic_config {
+            Some(config) => config,
+            None => return self.policy,
+        };
+
+        if let Some(policy) = topic_config.get(LEADER_ELECTION_POLICY) {
+            if let Ok(kind) = policy.parse::<ElectionPolicyKind>() {
+                return kind;
+            }
+        }
+
+        match topic_config
+            .get(UNCLEAN_LEADER_ELECTION_ENABLE)
+            .and_then(|value| value.parse::<bool>().ok())
+        {
+            Some(true) => ElectionPolicyKind::Unclean,
+            Some(false) if self.policy == ElectionPolicyKind::Unclean => {
+                ElectionPolicyKind::LowestLag
+            }
+            _ => self.policy,
+        }
+    }
+}
+
+/// create election policy for partition whose current leader is given
+pub fn create_policy<'a>(
+    kind: ElectionPolicyKind,
+    racks: &'a HashMap<SpuId, String>,
+    leader: SpuId,
+) -> Box<dyn ElectionPolicy + 'a> {
+    match kind {
+        ElectionPolicyKind::LowestLag => Box::new(LowestLagPolicy {}),
+        ElectionPolicyKind::RackDiverse => Box::new(RackDiversePolicy {
+            racks,
+            leader_rack: racks.get(&leader),
+        }),
+        ElectionPolicyKind::Unclean => Box::new(UncleanPolicy {}),
+    }
+}
+
+// -----------------------------------
+// Policies
+// -----------------------------------
+
+/// pick in sync replica with lowest lag
+pub struct LowestLagPolicy {}
+
+impl ElectionPolicy for LowestLagPolicy {
+    fn potential_leader_score(
+        &self,
+        replica_status: &ReplicaStatus,
+        leader: &ReplicaStatus,
+    ) -> ElectionScoring {
+        let lag = replica_status.leader_lag(leader);
+        if lag < MAX_LEADER_LAG {
+            ElectionScoring::Score(lag as u16)
+        } else {
+            ElectionScoring::NotSuitable
+        }
+    }
+}
+
+/// same as lowest lag, but replica outside of leader's rack is preferred
+/// since whole rack is likely to be gone
+pub struct RackDiversePolicy<'a> {
+    racks: &'a HashMap<SpuId, String>,
+    leader_rack: Option<&'a String>,
+}
+
+impl<'a> ElectionPolicy for RackDiversePolicy<'a> {
+    fn potential_leader_score(
+        &self,
+        replica_status: &ReplicaStatus,
+        leader: &ReplicaStatus,
+    ) -> ElectionScoring {
+        let lowest_lag = LowestLagPolicy {};
+        match lowest_lag.potential_leader_score(replica_status, leader) {
+            ElectionScoring::Score(score) => {
+                let same_rack = self.leader_rack.is_some()
+                    && self.racks.get(&replica_status.spu) == self.leader_rack;
+                if same_rack {
+                    ElectionScoring::Score(score + SAME_RACK_PENALTY)
+                } else {
+                    ElectionScoring::Score(score)
+                }
+            }
+            ElectionScoring::NotSuitable => ElectionScoring::NotSuitable,
+        }
+    }
+}
+
+/// in sync replica is always preferred, but out of sync replica can be elected
+/// when no in sync replica remains. this may lose committed records.
+pub struct UncleanPolicy {}
+
+impl ElectionPolicy for UncleanPolicy {
+    fn potential_leader_score(
+        &self,
+        replica_status: &ReplicaStatus,
+        leader: &ReplicaStatus,
+    ) -> ElectionScoring {
+        let lowest_lag = LowestLagPolicy {};
+        match lowest_lag.potential_leader_score(replica_status, leader) {
+            ElectionScoring::Score(score) => ElectionScoring::Score(score),
+            ElectionScoring::NotSuitable => {
+                let lag = replica_status.leader_lag(leader).max(0) as u64;
+                let score = (u64::from(UNCLEAN_SCORE_BASE) + lag).min(u64::from(u16::max_value()));
+                ElectionScoring::Score(score as u16)
+            }
+        }
+    }
+}
+
+// -----------------------------------
+//  Unit Tests
+// -----------------------------------
+
+#[cfg(test)]
+pub mod test {
+
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use metadata::partition::PartitionStatus;
+    use metadata::topic::TopicConfig;
+    use metadata::topic::LEADER_ELECTION_POLICY;
+    use metadata::topic::UNCLEAN_LEADER_ELECTION_ENABLE;
+
+    use super::ElectionConfig;
+    use super::ElectionPolicyKind;
+    use super::create_policy;
+
+    fn online(spus: Vec<i32>) -> HashSet<i32> {
+        spus.into_iter().collect()
+    }
+
+    #[test]
+    fn test_policy_kind_from_str() {
+        assert_eq!("lowest-lag".parse::<ElectionPolicyKind>().expect("parse"), ElectionPolicyKind::LowestLag);
+        assert_eq!("rack-diverse".parse::<ElectionPolicyKind>().expect("parse"), ElectionPolicyKind::RackDiverse);
+        assert_eq!("unclean".parse::<ElectionPolicyKind>().expect("parse"), ElectionPolicyKind::Unclean);
+        assert!("random".parse::<ElectionPolicyKind>().is_err());
+    }
+
+    #[test]
+    fn test_topic_override() {
+        let mut config = ElectionConfig::default();
+        config.policy = ElectionPolicyKind::Unclean;
+
+        let mut critical = TopicConfig::new();
+        critical.insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "false".to_owned());
+        let mut racked = TopicConfig::new();
+        racked.insert(LEADER_ELECTION_POLICY.to_owned(), "rack-diverse".to_owned());
+        racked.insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "true".to_owned());
+
+        assert_eq!(config.policy_kind(Some(&critical)), ElectionPolicyKind::LowestLag);
+        assert_eq!(config.policy_kind(Some(&racked)), ElectionPolicyKind::RackDiverse);
+        assert_eq!(config.policy_kind(Some(&TopicConfig::new())), ElectionPolicyKind::Unclean);
+        assert_eq!(config.policy_kind(None), ElectionPolicyKind::Unclean);
+
+        config.policy = ElectionPolicyKind::LowestLag;
+        let mut unclean = TopicConfig::new();
+        unclean.insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "true".to_owned());
+        assert_eq!(config.policy_kind(Some(&unclean)), ElectionPolicyKind::Unclean);
+    }
+
+    #[test]
+    fn test_rack_diverse_prefer_other_rack() {
+        let status = PartitionStatus::new(
+            (5000, 100, 110),
+            vec![
+                (5001, 100, 110).into(), // same rack as leader
+                (5002, 100, 109).into(), // other rack, 1 behind
+            ],
+        );
+        let mut racks = HashMap::new();
+        racks.insert(5000, "r1".to_owned());
+        racks.insert(5001, "r1".to_owned());
+        racks.insert(5002, "r2".to_owned());
+
+        let lowest_lag = create_policy(ElectionPolicyKind::LowestLag, &racks, 5000);
+        assert_eq!(status.candidate_leader(&online(vec![5001, 5002]), lowest_lag.as_ref()), Some(5001));
+
+        let rack_diverse = create_policy(ElectionPolicyKind::RackDiverse, &racks, 5000);
+        assert_eq!(status.candidate_leader(&online(vec![5001, 5002]), rack_diverse.as_ref()), Some(5002));
+    }
+
+    #[test]
+    fn test_unclean_only_when_no_in_sync() {
+        let status = PartitionStatus::new(
+            (5000, 100, 110),
+            vec![
+                (5001, 50, 60).into(),   // far behind
+                (5002, 100, 110).into(), // in sync
+            ],
+        );
+        let racks = HashMap::new();
+
+        let clean = create_policy(ElectionPolicyKind::LowestLag, &racks, 5000);
+        let unclean = create_policy(ElectionPolicyKind::Unclean, &racks, 5000);
+
+        // in sync replica wins
+        assert_eq!(status.candidate_leader(&online(vec![5001, 5002]), unclean.as_ref()), Some(5002));
+
+        // only out of sync replica left
+        assert_eq!(status.candidate_leader(&online(vec![5001]), clean.as_ref()), None);
+        assert_eq!(status.candidate_leader(&online(vec![5001]), unclean.as_ref()), Some(5001));
+    }
+}
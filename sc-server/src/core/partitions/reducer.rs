@@ -3,18 +3,19 @@
 //!
 //! Partition metadata information on cached in the local Controller.
 //!
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use log::trace;
 use log::debug;
 use log::error;
 use log::warn;
 
 use types::log_on_err;
+use types::SpuId;
 use metadata::partition::PartitionSpec;
 use metadata::partition::PartitionResolution;
 use metadata::partition::PartitionStatus;
-use metadata::partition::ReplicaStatus;
-use metadata::partition::ElectionPolicy;
-use metadata::partition::ElectionScoring;
 use internal_api::UpdateLrsRequest;
 
 use crate::conn_manager::ConnectionRequest;
@@ -24,6 +25,7 @@ use crate::core::common::WSAction;
 use crate::core::spus::SharedSpuLocalStore;
 use crate::core::spus::SpuLocalStore;
 use crate::core::spus::SpuKV;
+use crate::core::topics::TopicLocalStore;
 use crate::ScServerError;
 
 use super::PartitionChangeRequest;
@@ -31,6 +33,9 @@ use super::PartitionActions;
 use super::PartitionLocalStore;
 use super::PartitionKV;
 use super::SharedPartitionStore;
+use super::ElectionConfig;
+use super::ElectionPolicyKind;
+use super::policy::create_policy;
 
 type PartitionWSAction = WSAction<PartitionSpec>;
 
@@ -72,6 +77,8 @@ type PartitionWSAction = WSAction<PartitionSpec>;
 pub struct PartitionReducer {
     partition_store: SharedPartitionStore,
     spu_store: SharedSpuLocalStore,
+    topic_store: Arc<TopicLocalStore>,
+    election: ElectionConfig,
 }
 
 impl Default for PartitionReducer {
@@ -79,6 +86,8 @@ impl Default for PartitionReducer {
         Self {
             partition_store: PartitionLocalStore::new_shared(),
             spu_store: SpuLocalStore::new_shared(),
+            topic_store: TopicLocalStore::new_shared(),
+            election: ElectionConfig::default(),
         }
     }
 }
@@ -92,9 +101,29 @@ impl PartitionReducer {
         Self {
             partition_store: partition_store.into(),
             spu_store: spu_store.into(),
+            topic_store: TopicLocalStore::new_shared(),
+            election: ElectionConfig::default(),
         }
     }
 
+    pub fn with_election_config(mut self, election: ElectionConfig) -> Self {
+        self.election = election;
+        self
+    }
+
+    /// topics whose config may override election policy
+    pub fn with_topic_store(mut self, topic_store: Arc<TopicLocalStore>) -> Self {
+        self.topic_store = topic_store;
+        self
+    }
+
+    /// election policy for topic, looked up from topic config at election time
+    fn election_policy_kind(&self, topic: &str) -> ElectionPolicyKind {
+        let topic_kv = self.topic_store.topic(topic);
+        self.election
+            .policy_kind(topic_kv.as_ref().map(|topic_kv| topic_kv.spec.config()))
+    }
+
     ///
     /// Process Partition Actions - dispatch to ADD/MOD/DEL handlers
     ///
@@ -148,6 +177,9 @@ impl PartitionReducer {
             PartitionChangeRequest::LrsUpdate(lrs_status) => {
                 self.process_lrs_update_from_spu(lrs_status, &mut actions);
             }
+            PartitionChangeRequest::RebalanceLeaders => {
+                self.preferred_leader_election(&mut actions);
+            }
         }
 
         Ok(actions)
@@ -227,29 +259,23 @@ impl PartitionReducer {
     /// # Remarks
     /// Action handler performs the following operations:
     /// * remove partition from local cluster cache
-    /// * generate message for live replicas
+    /// * generate remove message for replicas
     ///
     fn del_partition_action_handler(
         &self,
         partition: PartitionKV,
-        _actions: &mut PartitionActions,
+        actions: &mut PartitionActions,
     ) -> Result<(), ScServerError> {
         debug!("DelPartition({}) - remove from metadata", partition.key());
         trace!("delete partition {:#?}", partition);
 
-        /*
-        // notify msg for live replicas
-        if partition.has_live_replicas() {
-            notify_msg_for_live_replicas(
-                &mut actions.spu_messages,
-                MsgType::DELETE,
-                "DELETE",
-                partition.key(),
-                &partition,
-                partition.live_replicas(),
-            );
-        }
-        */
+        // notify replicas, so they can be removed from spus
+        actions
+            .conns
+            .push(ConnectionRequest::Partition(PartitionSpecChange::Remove(
+                partition.key_owned(),
+                partition.spec,
+            )));
 
         Ok(())
     }
@@ -259,6 +285,11 @@ impl PartitionReducer {
         lrs_req: UpdateLrsRequest,
         actions: &mut PartitionActions,
     ) {
+        if !lrs_req.offline.is_empty() {
+            self.process_offline_replicas(lrs_req, actions);
+            return;
+        }
+
         debug!("updating lrs for replica: {}", lrs_req.id);
         if self
             .partition_store
@@ -280,6 +311,28 @@ impl PartitionReducer {
         }
     }
 
+    /// replicas reported offline by spu, their storage is not usable.
+    /// perform election if leader replica is offline, followers are left to catch up later
+    fn process_offline_replicas(&self, lrs_req: UpdateLrsRequest, actions: &mut PartitionActions) {
+        debug!("replica: {} is offline on spus: {:?}", lrs_req.id, lrs_req.offline);
+
+        let mut spu_status = self.spu_store.online_status();
+        for spu in &lrs_req.offline {
+            spu_status.remove(spu);
+        }
+
+        let found = self
+            .partition_store
+            .find_and_do(&lrs_req.id, |partition_kv| {
+                if lrs_req.offline.contains(&partition_kv.spec.leader) {
+                    self.elect_leader(partition_kv, &spu_status, actions);
+                }
+            });
+        if found.is_none() {
+            error!("offline replica update failed, no replica: {}", lrs_req.id);
+        }
+    }
+
     /// perform election when spu goes offline
     fn force_election_spu_off(&self, offline_spu: SpuKV, actions: &mut PartitionActions) {
         debug!(
@@ -290,50 +343,70 @@ impl PartitionReducer {
 
         let spu_status = self.spu_store.online_status();
 
-        let policy = SimplePolicy::new();
-
         // go thru each partitions whose leader matches offline spu.
         self.partition_store.visit_values(|partition_kv| {
             // find partition who's leader is same as offline spu
             if partition_kv.spec.leader == offline_leader_spu_id {
-                // find suitable leader
-                if let Some(candidate_leader) = partition_kv.status.candidate_leader(&spu_status, &policy)
-                {
-                    debug!(
-                        "suitable leader has found: {} leader: {}",
-                        partition_kv.key(),
-                        candidate_leader
-                    );
-                    let mut part_kv_change = partition_kv.clone();
-                    part_kv_change.spec.leader = candidate_leader;
-                    actions
-                        .partitions
-                        .push(PartitionWSAction::UpdateSpec(part_kv_change));
-                // change the
-                } else {
-                    warn!("no suitable leader has found: {}", partition_kv.key());
-                    let mut part_kv_change = partition_kv.clone();
-                    part_kv_change.status.resolution = PartitionResolution::LeaderOffline;
-                    actions
-                        .partitions
-                        .push(PartitionWSAction::UpdateStatus(part_kv_change));
-                }
+                self.elect_leader(partition_kv, &spu_status, actions);
             }
         });
     }
 
+    /// move leader of partition to suitable online replica, partition is leader offline if none is found
+    fn elect_leader(
+        &self,
+        partition_kv: &PartitionKV,
+        spu_status: &HashSet<SpuId>,
+        actions: &mut PartitionActions,
+    ) {
+        let spu_racks = self.spu_store.spu_racks();
+        let policy = create_policy(
+            self.election_policy_kind(&partition_kv.key.topic),
+            &spu_racks,
+            partition_kv.spec.leader,
+        );
+        // find suitable leader
+        if let Some(candidate_leader) = partition_kv
+            .status
+            .candidate_leader(spu_status, policy.as_ref())
+        {
+            debug!(
+                "suitable leader has found: {} leader: {}",
+                partition_kv.key(),
+                candidate_leader
+            );
+            let mut part_kv_change = partition_kv.clone();
+            part_kv_change.spec.leader = candidate_leader;
+            actions
+                .partitions
+                .push(PartitionWSAction::UpdateSpec(part_kv_change));
+        } else {
+            warn!("no suitable leader has found: {}", partition_kv.key());
+            let mut part_kv_change = partition_kv.clone();
+            part_kv_change.status.resolution = PartitionResolution::LeaderOffline;
+            actions
+                .partitions
+                .push(PartitionWSAction::UpdateStatus(part_kv_change));
+        }
+    }
+
     /// perform election when spu become online
     fn force_election_spu_on(&self, online_spu: SpuKV, actions: &mut PartitionActions) {
         debug!("start election spu went online: {}", online_spu.key());
         let online_leader_spu_id = online_spu.spec.id;
 
-        let policy = SimplePolicy::new();
+        let spu_racks = self.spu_store.spu_racks();
         // go thru each partitions which are not online and try to promote given online spu
 
         self.partition_store.visit_values(|partition_kv| {
             if partition_kv.status.is_offline() {
                 // we only care about partition who is follower since, leader will set partition status when it start up
                 if partition_kv.spec.leader != online_leader_spu_id {
+                    let policy = create_policy(
+                        self.election_policy_kind(&partition_kv.key.topic),
+                        &spu_racks,
+                        partition_kv.spec.leader,
+                    );
                     for replica_status in partition_kv.status.replica_iter() {
                         if replica_status.spu == online_leader_spu_id
                             && policy
@@ -359,27 +432,25 @@ impl PartitionReducer {
             }
         });
     }
-}
-
-struct SimplePolicy {}
 
-impl SimplePolicy {
-    fn new() -> Self {
-        SimplePolicy {}
-    }
-}
+    /// move leadership back to preferred replica if it is online and caught up with leader.
+    /// this undo leader pile up on surviving spu after spu restarts
+    fn preferred_leader_election(&self, actions: &mut PartitionActions) {
+        let spu_status = self.spu_store.online_status();
 
-impl ElectionPolicy for SimplePolicy {
-    fn potential_leader_score(
-        &self,
-        replica_status: &ReplicaStatus,
-        leader: &ReplicaStatus,
-    ) -> ElectionScoring {
-        let lag = leader.leo - replica_status.leo;
-        if lag < 4 {
-            ElectionScoring::Score(lag as u16)
-        } else {
-            ElectionScoring::NotSuitable
+        for (part_kv_change, old_leader) in self
+            .partition_store
+            .preferred_leader_moves(&spu_status, &[])
+        {
+            debug!(
+                "moving leader to preferred replica: {} leader: {} => {}",
+                part_kv_change.key(),
+                old_leader,
+                part_kv_change.spec.leader
+            );
+            actions
+                .partitions
+                .push(PartitionWSAction::UpdateSpec(part_kv_change));
         }
     }
 }
@@ -398,6 +469,127 @@ pub mod test {
     use super::PartitionChangeRequest;
     use super::PartitionWSAction;
     use super::super::PartitionLSChange;
+    use super::super::PartitionLocalStore;
+    use super::super::PartitionKV;
+    use super::SpuLocalStore;
+    use super::PartitionStatus;
+    use super::PartitionResolution;
+    use super::TopicLocalStore;
+
+    use std::sync::Arc;
+
+    use metadata::topic::TopicStatus;
+    use metadata::topic::UNCLEAN_LEADER_ELECTION_ENABLE;
+    use internal_api::UpdateLrsRequest;
+
+    use crate::core::common::LSChange;
+    use crate::core::spus::SpuKV;
+    use crate::core::topics::TopicKV;
+
+    #[test]
+    fn test_rebalance_leaders_to_preferred_replica() {
+
+        let spus: SpuLocalStore = vec![(10, true, None), (11, true, None)].into();
+        let partitions: PartitionLocalStore = vec![(("topic1", 0), vec![10, 11])].into();
+
+        // leader has failed over to 11 and 10 has caught up
+        let mut partition: PartitionKV = (("topic1", 0), vec![10, 11]).into();
+        partition.spec.leader = 11;
+        partition.status = PartitionStatus::new2((11, 50, 50), vec![(10, 50, 50).into()], PartitionResolution::Online);
+        partitions.insert(partition.clone());
+
+        let partition_reducer = PartitionReducer::new(partitions, spus);
+        let actions = partition_reducer
+            .process_requests(PartitionChangeRequest::RebalanceLeaders)
+            .expect("actions");
+
+        partition.spec.leader = 10;
+        let expected_partitions: Actions<PartitionWSAction> = vec![
+            PartitionWSAction::UpdateSpec(partition),
+        ]
+        .into();
+        assert_eq!(actions.partitions, expected_partitions);
+    }
+
+    #[test]
+    fn test_unclean_election_from_topic_config() {
+
+        let spus: SpuLocalStore = vec![(10, false, None), (11, true, None)].into();
+        let partitions: PartitionLocalStore = vec![(("topic1", 0), vec![10, 11])].into();
+
+        // only replica left is far behind leader
+        let mut partition: PartitionKV = (("topic1", 0), vec![10, 11]).into();
+        partition.status = PartitionStatus::new2((10, 100, 110), vec![(11, 50, 60).into()], PartitionResolution::Online);
+        partitions.insert(partition.clone());
+
+        let mut spu_offline: SpuKV = ("spu-10".to_owned(), 10, false, None).into();
+        spu_offline.status.set_offline();
+        let spu_online: SpuKV = ("spu-10".to_owned(), 10, true, None).into();
+        let spu_requests = || Actions::from(vec![LSChange::Mod(spu_offline.clone(), spu_online.clone())]);
+
+        let partitions = Arc::new(partitions);
+        let spus = Arc::new(spus);
+
+        // cluster default does not allow out of sync leader
+        let partition_reducer = PartitionReducer::new(partitions.clone(), spus.clone());
+        let actions = partition_reducer
+            .process_requests(PartitionChangeRequest::Spu(spu_requests()))
+            .expect("actions");
+        let mut offline = partition.clone();
+        offline.status.resolution = PartitionResolution::LeaderOffline;
+        let expected_partitions: Actions<PartitionWSAction> = vec![
+            PartitionWSAction::UpdateStatus(offline),
+        ]
+        .into();
+        assert_eq!(actions.partitions, expected_partitions);
+
+        // topic config enables unclean election
+        let mut topic: TopicKV = TopicKV::new("topic1", (1, 2, false).into(), TopicStatus::default());
+        topic.spec.config_mut().insert(UNCLEAN_LEADER_ELECTION_ENABLE.to_owned(), "true".to_owned());
+        let topics = TopicLocalStore::new_shared();
+        topics.insert(topic);
+
+        let partition_reducer = PartitionReducer::new(partitions, spus).with_topic_store(topics);
+        let actions = partition_reducer
+            .process_requests(PartitionChangeRequest::Spu(spu_requests()))
+            .expect("actions");
+        partition.spec.leader = 11;
+        let expected_partitions: Actions<PartitionWSAction> = vec![
+            PartitionWSAction::UpdateSpec(partition),
+        ]
+        .into();
+        assert_eq!(actions.partitions, expected_partitions);
+    }
+
+    #[test]
+    fn test_election_on_offline_replica() {
+
+        let spus: SpuLocalStore = vec![(10, true, None), (11, true, None)].into();
+        let partitions: PartitionLocalStore = vec![(("topic1", 0), vec![10, 11])].into();
+
+        let mut partition: PartitionKV = (("topic1", 0), vec![10, 11]).into();
+        partition.status = PartitionStatus::new2((10, 100, 110), vec![(11, 100, 110).into()], PartitionResolution::Online);
+        partitions.insert(partition.clone());
+
+        let partition_reducer = PartitionReducer::new(Arc::new(partitions), Arc::new(spus));
+
+        // offline follower does not change leader
+        let actions = partition_reducer
+            .process_requests(PartitionChangeRequest::LrsUpdate(UpdateLrsRequest::offline(("topic1", 0).into(), 11)))
+            .expect("actions");
+        assert_eq!(actions.partitions.count(), 0);
+
+        // leader replica offline while its spu is online
+        let actions = partition_reducer
+            .process_requests(PartitionChangeRequest::LrsUpdate(UpdateLrsRequest::offline(("topic1", 0).into(), 10)))
+            .expect("actions");
+        partition.spec.leader = 11;
+        let expected_partitions: Actions<PartitionWSAction> = vec![
+            PartitionWSAction::UpdateSpec(partition),
+        ]
+        .into();
+        assert_eq!(actions.partitions, expected_partitions);
+    }
 
     #[test]
     fn test_process_partition_actions_without_partitions() {
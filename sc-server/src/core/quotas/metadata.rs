@@ -0,0 +1,39 @@
+//!
+//! # Client Quota Metadata
+//!
+//! Client quotas cached on SC, pushed to SPUs which enforce them.
+//!
+use std::io::Error as IoError;
+
+use metadata::quota::{ClientQuotaSpec, ClientQuotaStatus};
+use k8_metadata::quota::ClientQuotaSpec as K8ClientQuotaSpec;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+
+use crate::core::common::LocalStore;
+use crate::core::common::KVObject;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::k8::default_convert_from_k8;
+
+impl Spec for ClientQuotaSpec {
+    const LABEL: &'static str = "ClientQuota";
+    type Key = String;
+    type Status = ClientQuotaStatus;
+    type K8Spec = K8ClientQuotaSpec;
+    type Owner = ClientQuotaSpec;
+
+    fn convert_from_k8(
+        k8_obj: K8Obj<Self::K8Spec, <Self::K8Spec as K8Spec>::Status>,
+    ) -> Result<KVObject<Self>, IoError> {
+        default_convert_from_k8(k8_obj)
+    }
+}
+
+impl Status for ClientQuotaStatus {}
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+pub type QuotaLocalStore = LocalStore<ClientQuotaSpec>;
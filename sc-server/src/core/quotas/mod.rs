@@ -0,0 +1,12 @@
+mod metadata;
+
+pub use self::metadata::QuotaLocalStore;
+
+use std::sync::Arc;
+use ::metadata::quota::ClientQuotaSpec;
+use crate::core::common::SpecRefreshController;
+use crate::k8::K8ClusterStateDispatcher;
+
+pub type QuotaController = SpecRefreshController<ClientQuotaSpec>;
+pub type K8QuotaChangeDispatcher = K8ClusterStateDispatcher<ClientQuotaSpec>;
+pub type SharedQuotaLocalStore = Arc<QuotaLocalStore>;
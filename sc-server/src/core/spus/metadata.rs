@@ -5,6 +5,7 @@
 //!
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::io::Error as IoError;
 use std::io::ErrorKind;
@@ -365,6 +366,15 @@ impl SpuLocalStore {
             .sum()
     }
 
+    /// rack of each spu which has rack assigned
+    pub fn spu_racks(&self) -> HashMap<SpuId, String> {
+        self.inner_store()
+            .read()
+            .values()
+            .filter_map(|spu| spu.rack_clone().map(|rack| (*spu.id(), rack)))
+            .collect()
+    }
+
     // Returns array of touples [("r1", [0,1,2]), ("r2", [3,4]), ("r3", [5])]
     pub fn live_spu_rack_map_sorted(spus: &SpuLocalStore) -> Vec<(String, Vec<i32>)> {
         let rack_map = SpuLocalStore::online_spu_rack_map(spus);
@@ -0,0 +1,99 @@
+//!
+//! # Replication Throttle Metadata
+//!
+//! Replication throttle overrides cached on SC, resolved per SPU and pushed to SPUs.
+//! Override of single SPU takes precedence over override of all SPUs.
+//! SPUs without any override use rate from their config.
+//!
+use std::io::Error as IoError;
+
+use metadata::throttle::{ReplicationThrottleSpec, ReplicationThrottleStatus};
+use metadata::throttle::throttle_key;
+use k8_metadata::throttle::ReplicationThrottleSpec as K8ReplicationThrottleSpec;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+use types::SpuId;
+
+use crate::core::common::LocalStore;
+use crate::core::common::KVObject;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::k8::default_convert_from_k8;
+
+impl Spec for ReplicationThrottleSpec {
+    const LABEL: &'static str = "ReplicationThrottle";
+    type Key = String;
+    type Status = ReplicationThrottleStatus;
+    type K8Spec = K8ReplicationThrottleSpec;
+    type Owner = ReplicationThrottleSpec;
+
+    fn convert_from_k8(
+        k8_obj: K8Obj<Self::K8Spec, <Self::K8Spec as K8Spec>::Status>,
+    ) -> Result<KVObject<Self>, IoError> {
+        default_convert_from_k8(k8_obj)
+    }
+}
+
+impl Status for ReplicationThrottleStatus {}
+
+// -----------------------------------
+// Data Structures
+// -----------------------------------
+
+pub type ThrottleKV = KVObject<ReplicationThrottleSpec>;
+pub type ThrottleLocalStore = LocalStore<ReplicationThrottleSpec>;
+
+// -----------------------------------
+// ThrottleKV - Implementation
+// -----------------------------------
+
+impl From<ReplicationThrottleSpec> for ThrottleKV {
+    fn from(spec: ReplicationThrottleSpec) -> Self {
+        Self::with_spec(spec.key(), spec)
+    }
+}
+
+// -----------------------------------
+// ThrottleLocalStore - Implementation
+// -----------------------------------
+
+impl ThrottleLocalStore {
+    /// rate override for spu, none if spu should use its config
+    pub fn rate(&self, spu: &SpuId) -> Option<u64> {
+        self.configured_rate(Some(*spu))
+            .or_else(|| self.configured_rate(None))
+    }
+
+    /// rate override set directly for spu or for all spus if spu is none
+    pub fn configured_rate(&self, spu: Option<SpuId>) -> Option<u64> {
+        self.value(&throttle_key(spu))
+            .map(|throttle| throttle.spec.rate.max(0) as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use metadata::throttle::ReplicationThrottleSpec;
+
+    use super::ThrottleKV;
+    use super::ThrottleLocalStore;
+
+    #[test]
+    fn test_spu_rate_precedence() {
+        let store = ThrottleLocalStore::default();
+        assert_eq!(store.rate(&5001), None);
+
+        store.insert(ThrottleKV::from(ReplicationThrottleSpec::new(None, 1000)));
+        assert_eq!(store.rate(&5001), Some(1000));
+
+        store.insert(ThrottleKV::from(ReplicationThrottleSpec::new(Some(5001), 500)));
+        assert_eq!(store.rate(&5001), Some(500));
+        assert_eq!(store.rate(&5002), Some(1000));
+
+        store.delete(&"spu-5001".to_owned());
+        assert_eq!(store.rate(&5001), Some(1000));
+        assert_eq!(store.configured_rate(Some(5001)), None);
+        assert_eq!(store.configured_rate(None), Some(1000));
+    }
+}
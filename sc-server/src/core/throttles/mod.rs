@@ -0,0 +1,12 @@
+mod metadata;
+
+pub use self::metadata::{ThrottleKV, ThrottleLocalStore};
+
+use std::sync::Arc;
+use ::metadata::throttle::ReplicationThrottleSpec;
+use crate::core::common::SpecRefreshController;
+use crate::k8::K8ClusterStateDispatcher;
+
+pub type ReplicationThrottleController = SpecRefreshController<ReplicationThrottleSpec>;
+pub type K8ThrottleChangeDispatcher = K8ClusterStateDispatcher<ReplicationThrottleSpec>;
+pub type SharedThrottleLocalStore = Arc<ThrottleLocalStore>;
@@ -6,6 +6,7 @@
 use std::fmt;
 
 use utils::actions::Actions;
+use internal_api::ReplicaRemovedRequest;
 
 use crate::core::partitions::PartitionWSAction;
 use crate::core::spus::SpuLSChange;
@@ -17,7 +18,8 @@ use super::TopicLSChange;
 #[derive(Debug, PartialEq, Clone)]
 pub enum TopicChangeRequest {
     Topic(Actions<TopicLSChange>),
-    Spu(Actions<SpuLSChange>)
+    Spu(Actions<SpuLSChange>),
+    ReplicaRemoved(ReplicaRemovedRequest)
 }
 
 
@@ -26,7 +28,8 @@ impl fmt::Display for TopicChangeRequest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TopicChangeRequest::Topic(req) =>  write!(f,"Topic LS: {}",req.count()),
-            TopicChangeRequest::Spu(req) => write!(f,"SPU LS: {}",req.count())
+            TopicChangeRequest::Spu(req) => write!(f,"SPU LS: {}",req.count()),
+            TopicChangeRequest::ReplicaRemoved(req) => write!(f,"Replica Removed: {}",req.id)
         }
     }
 }
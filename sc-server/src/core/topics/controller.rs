@@ -9,11 +9,15 @@ use log::error;
 use log::info;
 use futures::select;
 use futures::stream::StreamExt;
+use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::Sender;
+use futures::channel::mpsc::channel;
 
 use types::log_on_err;
 use metadata::topic::TopicSpec;
 use metadata::spu::SpuSpec;
 use future_helper::spawn;
+use internal_api::ReplicaRemovedRequest;
 
 use crate::core::WSUpdateService;
 use crate::core::WSChangeChannel;
@@ -30,6 +34,8 @@ pub struct TopicController<W> {
     ws_service: W,
     topic_receiver: WSChangeChannel<TopicSpec>,
     spu_receiver: WSChangeChannel<SpuSpec>,
+    replica_removed_receiver: Receiver<ReplicaRemovedRequest>,
+    replica_removed_sender: Sender<ReplicaRemovedRequest>,
     reducer: TopicReducer,
 }
 
@@ -47,6 +53,7 @@ impl <W>TopicController<W>
         topic_receiver: WSChangeChannel<TopicSpec>,
         ws_service: W) -> Self {
 
+        let (replica_removed_sender,replica_removed_receiver) = channel(100);
 
         Self {
             reducer: TopicReducer::new(local_stores.topics().clone(),
@@ -57,10 +64,16 @@ impl <W>TopicController<W>
             spu_receiver,
             topic_receiver,
             ws_service,
-            
+            replica_removed_receiver,
+            replica_removed_sender
         }
     }
 
+    /// sender for replica removal confirmed by SPUs
+    pub fn replica_removed_sender(&self) -> Sender<ReplicaRemovedRequest> {
+        self.replica_removed_sender.clone()
+    }
+
 
 
     pub fn run(self)  {
@@ -97,6 +110,18 @@ impl <W>TopicController<W>
                             self.process_request(TopicChangeRequest::Spu(request)).await;
                         }
                     }
+                },
+                removed_req = self.replica_removed_receiver.next() => {
+                    match removed_req {
+                        None => {
+                            error!("Replica removed channel has been terminated. Ending server loop");
+                            break;
+                        },
+                        Some(req) => {
+                            trace!("replica removed request: {:#?}",req);
+                            self.process_request(TopicChangeRequest::ReplicaRemoved(req)).await;
+                        }
+                    }
                 }
                 complete => {},
             }
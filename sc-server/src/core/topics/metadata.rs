@@ -25,6 +25,7 @@ use metadata::topic::{TopicSpec, TopicStatus,PartitionMap,TopicResolution};
 use metadata::topic::TopicReplicaParam;
 use metadata::topic::PartitionMaps;
 use metadata::partition::ReplicaKey;
+use metadata::partition::PartitionSpec;
 use k8_metadata::topic::TopicSpec as K8TopicSpec;
 use k8_metadata::topic::TopicStatus as K8TopicStatus;
 
@@ -88,6 +89,8 @@ impl Spec for TopicSpec
 ///    values derived from custom replica assignment.
 fn create_computed_topic_spec_from_k8_spec(k8_topic_spec: &K8TopicSpec) -> TopicSpec {
 
+    let config = k8_topic_spec.config.clone().unwrap_or_default();
+
     if let Some(k8_replica_assign) = &k8_topic_spec.custom_replica_assignment {
         // Assigned Topic
         let mut partition_map: Vec<PartitionMap> = vec![];
@@ -99,7 +102,7 @@ fn create_computed_topic_spec_from_k8_spec(k8_topic_spec: &K8TopicSpec) -> Topic
             });
         }
 
-        TopicSpec::new_assigned(partition_map)
+        TopicSpec::new_assigned(partition_map).with_config(config)
 
     } else {
         // Computed Topic
@@ -117,7 +120,7 @@ fn create_computed_topic_spec_from_k8_spec(k8_topic_spec: &K8TopicSpec) -> Topic
             partitions,
             replication_factor,
             k8_topic_spec.ignore_rack_assignment,
-        )
+        ).with_config(config)
     }
 
 }
@@ -412,7 +415,8 @@ impl TopicKV {
                     Some(
                         PartitionKV::with_spec(
                             replica_key,
-                            replicas.clone().into()
+                            PartitionSpec::from(replicas.clone())
+                                .with_config(self.spec.config().clone())
                         )
                         .with_kv_ctx(parent_kv_ctx.clone())
                     )
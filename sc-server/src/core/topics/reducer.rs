@@ -16,7 +16,11 @@ use std::io::ErrorKind;
 
 use log::{debug, trace};
 use types::log_on_err;
+use types::ReplicaMap;
+use types::SpuId;
 use metadata::spu::SpuSpec;
+use internal_api::ReplicaRemovedRequest;
+use utils::SimpleConcurrentBTreeMap;
 
 use crate::core::spus::SpuLocalStore;
 use crate::core::partitions::PartitionWSAction;
@@ -43,11 +47,16 @@ use super::TopicWSAction;
 ///
 ///
 /// Actually replica assignment is done by Partition controller.
+///
+/// Deleted topic stays in deleting state until every SPU in replica map confirms
+/// that replica has been removed. Confirmations are only tracked in memory,
+/// SC resends replica removal to SPUs when they reconnect.
 #[derive(Debug)]
 pub struct TopicReducer {
     topic_store: Arc<TopicLocalStore>,
     spu_store: Arc<SpuLocalStore>,
     partition_store: Arc<PartitionLocalStore>,
+    pending_removals: SimpleConcurrentBTreeMap<String, ReplicaMap>,
 }
 
 impl Default for TopicReducer {
@@ -56,6 +65,7 @@ impl Default for TopicReducer {
             topic_store: TopicLocalStore::new_shared(),
             spu_store: SpuLocalStore::new_shared(),
             partition_store: PartitionLocalStore::new_shared(),
+            pending_removals: SimpleConcurrentBTreeMap::new(),
         }
     }
 }
@@ -71,6 +81,7 @@ impl TopicReducer {
             topic_store: topic_store.into(),
             spu_store: spu_store.into(),
             partition_store: partition_store.into(),
+            pending_removals: SimpleConcurrentBTreeMap::new(),
         }
     }
     fn topic_store(&self) -> &TopicLocalStore {
@@ -108,8 +119,9 @@ impl TopicReducer {
                             ));
                         }
 
-                        LSChange::Delete(_) => {
-                            // ignore for now
+                        LSChange::Delete(topic) => {
+                            debug!("DelTopic({})", topic.key());
+                            self.pending_removals.write().remove(topic.key());
                         }
                     }
                 }
@@ -120,6 +132,10 @@ impl TopicReducer {
                     self.process_spu_kv(request, &mut actions);
                 }
             }
+
+            TopicChangeRequest::ReplicaRemoved(removed) => {
+                self.process_replica_removed(removed, &mut actions);
+            }
         }
 
         trace!("\n{}", self.topic_store().table_fmt());
@@ -142,7 +158,13 @@ impl TopicReducer {
 
         debug!("AddTopic({}) - {}", name, topic);
 
-        self.update_actions_next_state(&topic, actions);
+        if topic.status.is_resolution_deleting() {
+            // SC restarted while topic was being deleted, partitions may not have been removed
+            self.update_actions_delete_partitions(&topic, actions);
+            self.update_actions_delete_topic(&topic, actions);
+        } else {
+            self.update_actions_next_state(&topic, actions);
+        }
     }
 
     ///
@@ -163,14 +185,28 @@ impl TopicReducer {
         let name = new_topic.key();
         debug!("Handling ModTopic: {} ", name);
 
-        // if spec changed - not a supported feature
+        // topic is being deleted, other changes are ignored
+        if new_topic.status.is_resolution_deleting() {
+            if !old_topic.status.is_resolution_deleting() {
+                self.update_actions_delete_partitions(&new_topic, actions);
+                self.update_actions_delete_topic(&new_topic, actions);
+            }
+            return Ok(());
+        }
+
+        // if spec changed - only config changes are supported
         if new_topic.spec != old_topic.spec {
-            return Err(IoError::new(
-                ErrorKind::InvalidData,
-                format!(
-                    "topic '{}' - update spec... not implemented",
-                    name)
-            ));
+            let old_spec_with_new_config = old_topic.spec.clone().with_config(new_topic.spec.config().clone());
+            if new_topic.spec != old_spec_with_new_config {
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "topic '{}' - update spec... not implemented",
+                        name)
+                ));
+            }
+
+            self.update_actions_partition_config(&new_topic, actions);
         }
 
         // if topic changed, update status & notify partitions
@@ -209,12 +245,87 @@ impl TopicReducer {
                 }
                 */
             }
-            _ => {
-                // do nothing, ignore for now
+            LSChange::Delete(old_spu) => {
+                debug!("processing SPU delete: {}", old_spu);
+
+                // deleted spu will never confirm replica removal
+                self.topic_store().visit_values(|topic| {
+                    if topic.status.is_resolution_deleting() {
+                        for partition in topic.status.replica_map.keys() {
+                            self.remove_pending_replica(topic, *partition, old_spu.spec.id, actions);
+                        }
+                    }
+                });
             }
         }
     }
 
+    /// SPU has removed replica of topic being deleted
+    fn process_replica_removed(&self, removed: ReplicaRemovedRequest, actions: &mut TopicActions) {
+        debug!("replica: {} removed by spu: {}", removed.id, removed.spu);
+
+        match self.topic_store().topic(&removed.id.topic) {
+            Some(topic) => {
+                if topic.status.is_resolution_deleting() {
+                    self.remove_pending_replica(&topic, removed.id.partition, removed.spu, actions);
+                } else {
+                    debug!("topic: {} is not being deleted, ignoring", topic.key());
+                }
+            }
+            None => debug!("topic for replica: {} not found, ignoring", removed.id),
+        }
+    }
+
+    /// remove spu from replicas waiting for removal, delete topic if there are none left
+    fn remove_pending_replica(
+        &self,
+        topic: &TopicKV,
+        partition: i32,
+        spu: SpuId,
+        actions: &mut TopicActions,
+    ) {
+        let mut pending_removals = self.pending_removals.write();
+        let replica_map = pending_removals
+            .entry(topic.key_owned())
+            .or_insert_with(|| topic.status.replica_map.clone());
+
+        let removed = match replica_map.get_mut(&partition) {
+            Some(replicas) => {
+                let count = replicas.len();
+                replicas.retain(|id| *id != spu);
+                replicas.len() != count
+            }
+            None => false,
+        };
+
+        if removed && replica_map.values().all(|replicas| replicas.is_empty()) {
+            debug!("topic: {} all replicas removed, deleting", topic.key());
+            pending_removals.remove(topic.key());
+            actions.topics.push(TopicWSAction::Delete(topic.key_owned()));
+        }
+    }
+
+    ///
+    /// Topic moved to deleting state, remove partitions of the topic.
+    /// SPUs are notified to remove replicas when partitions are deleted.
+    ///
+    fn update_actions_delete_partitions(&self, topic: &TopicKV, actions: &mut TopicActions) {
+        for partition in self.partition_store().topic_partitions(topic.key()).into_iter() {
+            debug!("topic: {} delete partition: {}", topic.key(), partition.key());
+            actions
+                .partitions
+                .push(PartitionWSAction::Delete(partition.key_owned()));
+        }
+    }
+
+    /// topic without replicas can be deleted without waiting for SPUs
+    fn update_actions_delete_topic(&self, topic: &TopicKV, actions: &mut TopicActions) {
+        if topic.status.is_replica_map_empty() {
+            debug!("topic: {} has no replicas, deleting", topic.key());
+            actions.topics.push(TopicWSAction::Delete(topic.key_owned()));
+        }
+    }
+
     ///
     /// Compute next state for topic
     /// if state is different, apply actions
@@ -242,6 +353,23 @@ impl TopicReducer {
 
     }
 
+    ///
+    /// Push topic config to existing partitions, so SPUs can apply it to replicas
+    ///
+    fn update_actions_partition_config(&self, topic: &TopicKV, actions: &mut TopicActions) {
+        let config = topic.spec.config();
+        for partition in self.partition_store().topic_partitions(topic.key()).into_iter() {
+            if &partition.spec.config != config {
+                debug!("topic: {} update config for partition: {}", topic.key(), partition.key());
+                let mut part_kv_change = partition;
+                part_kv_change.spec.config = config.clone();
+                actions
+                    .partitions
+                    .push(PartitionWSAction::UpdateSpec(part_kv_change));
+            }
+        }
+    }
+
     ///
     /// Generate Replica Map for all Topics handler
     ///
@@ -271,9 +399,18 @@ impl TopicReducer {
 mod test2 {
     use metadata::topic::{TopicResolution, TopicStatus};
     use metadata::topic::PENDING_REASON;
+    use metadata::topic::TopicConfig;
+    use metadata::topic::SEGMENT_BYTES;
+    use metadata::partition::ReplicaKey;
+    use internal_api::ReplicaRemovedRequest;
     use utils::actions::Actions;
 
+    use crate::core::spus::SpuLocalStore;
+    use crate::core::partitions::PartitionLocalStore;
+    use crate::core::partitions::PartitionWSAction;
+
     use super::TopicReducer;
+    use super::TopicLocalStore;
     use super::TopicChangeRequest;
     use super::TopicWSAction;
     use super::TopicKV;
@@ -310,6 +447,97 @@ mod test2 {
         assert_eq!(actions.topics, expected_actions);
     }
 
+    // change in topic config only should be pushed to partitions
+    #[test]
+    fn test_topic_reducer_config_change() {
+        let partition_store = PartitionLocalStore::new_shared();
+        partition_store.bulk_add(vec![(("topic1", 0), vec![5000])]);
+        let topic_reducer = TopicReducer::new(
+            TopicLocalStore::new_shared(),
+            SpuLocalStore::new_shared(),
+            partition_store.clone(),
+        );
+
+        let status = TopicStatus::new(TopicResolution::Provisioned, vec![vec![5000]], "");
+        let old_topic = TopicKV::new("topic1", (1, 1).into(), status.clone());
+        let mut config = TopicConfig::new();
+        config.insert(SEGMENT_BYTES.to_owned(), "1000".to_owned());
+        let new_topic = TopicKV::new(
+            "topic1",
+            old_topic.spec.clone().with_config(config.clone()),
+            status,
+        );
+
+        let topic_requests: Actions<TopicLSChange> =
+            vec![TopicLSChange::update(new_topic, old_topic)].into();
+
+        let actions = topic_reducer
+            .process_requests(TopicChangeRequest::Topic(topic_requests))
+            .expect("actions");
+
+        let mut partition = partition_store
+            .topic_partitions("topic1")
+            .pop()
+            .expect("partition");
+        partition.spec.config = config;
+        let expected_actions: Actions<PartitionWSAction> =
+            vec![PartitionWSAction::UpdateSpec(partition)].into();
+        assert_eq!(actions.partitions, expected_actions);
+        assert_eq!(actions.topics, Actions::default());
+    }
+
+    // deleting topic removes partitions, topic is deleted after all spus removed replicas
+    #[test]
+    fn test_topic_reducer_deleting() {
+        let topic_store = TopicLocalStore::new_shared();
+        let partition_store = PartitionLocalStore::new_shared();
+        partition_store.bulk_add(vec![(("topic1", 0), vec![5000, 5001])]);
+        let topic_reducer = TopicReducer::new(
+            topic_store.clone(),
+            SpuLocalStore::new_shared(),
+            partition_store.clone(),
+        );
+
+        let old_topic = TopicKV::new(
+            "topic1",
+            (1, 2).into(),
+            TopicStatus::new(TopicResolution::Provisioned, vec![vec![5000, 5001]], ""),
+        );
+        let mut new_topic = old_topic.clone();
+        new_topic
+            .status
+            .set_next_resolution(TopicStatus::next_resolution_deleting());
+        topic_store.insert(new_topic.clone());
+
+        let topic_requests: Actions<TopicLSChange> =
+            vec![TopicLSChange::update(new_topic, old_topic)].into();
+        let actions = topic_reducer
+            .process_requests(TopicChangeRequest::Topic(topic_requests))
+            .expect("actions");
+
+        let expected_actions: Actions<PartitionWSAction> =
+            vec![PartitionWSAction::Delete(ReplicaKey::new("topic1", 0))].into();
+        assert_eq!(actions.partitions, expected_actions);
+        assert_eq!(actions.topics, Actions::default());
+
+        // first spu confirms, still waiting for second one
+        let actions = topic_reducer
+            .process_requests(TopicChangeRequest::ReplicaRemoved(
+                ReplicaRemovedRequest::new(ReplicaKey::new("topic1", 0), 5000),
+            ))
+            .expect("actions");
+        assert_eq!(actions.topics, Actions::default());
+
+        let actions = topic_reducer
+            .process_requests(TopicChangeRequest::ReplicaRemoved(
+                ReplicaRemovedRequest::new(ReplicaKey::new("topic1", 0), 5001),
+            ))
+            .expect("actions");
+        let expected_actions: Actions<TopicWSAction> =
+            vec![TopicWSAction::Delete("topic1".to_owned())].into();
+        assert_eq!(actions.topics, expected_actions);
+    }
+
     /*
     #[test]
     fn test_process_topics_actions_with_topics() {
@@ -0,0 +1,9 @@
+mod watch_state;
+mod publisher;
+
+pub use self::watch_state::MetadataWatch;
+pub use self::watch_state::SharedMetadataWatch;
+pub use self::watch_state::WatchChange;
+pub use self::watch_state::WatchEvent;
+pub use self::watch_state::WatchStart;
+pub use self::publisher::MetadataWatchPublisher;
@@ -0,0 +1,163 @@
+//!
+//! # Metadata Watch Publisher
+//!
+//! Receives topic, partition and SPU changes from the world store dispatcher and
+//! publishes them to metadata watch subscribers.
+//!
+use log::trace;
+use log::error;
+use log::info;
+use futures::select;
+use futures::stream::StreamExt;
+
+use metadata::topic::TopicSpec;
+use metadata::partition::PartitionSpec;
+use metadata::spu::SpuSpec;
+use future_helper::spawn;
+use utils::actions::Actions;
+use sc_api::watch::FlvChangeType;
+use sc_api::watch::FlvTopicChange;
+use sc_api::watch::FlvPartitionChange;
+use sc_api::watch::FlvSpuChange;
+
+use crate::core::Spec;
+use crate::core::WSChangeChannel;
+use crate::core::common::LSChange;
+use crate::core::common::KVObject;
+
+use super::SharedMetadataWatch;
+use super::WatchChange;
+
+#[derive(Debug)]
+pub struct MetadataWatchPublisher {
+    watch: SharedMetadataWatch,
+    topic_receiver: WSChangeChannel<TopicSpec>,
+    partition_receiver: WSChangeChannel<PartitionSpec>,
+    spu_receiver: WSChangeChannel<SpuSpec>,
+}
+
+impl MetadataWatchPublisher {
+    pub fn new(
+        watch: SharedMetadataWatch,
+        topic_receiver: WSChangeChannel<TopicSpec>,
+        partition_receiver: WSChangeChannel<PartitionSpec>,
+        spu_receiver: WSChangeChannel<SpuSpec>,
+    ) -> Self {
+        Self {
+            watch,
+            topic_receiver,
+            partition_receiver,
+            spu_receiver,
+        }
+    }
+
+    pub fn run(self) {
+        spawn(self.dispatch_loop());
+    }
+
+    async fn dispatch_loop(mut self) {
+        loop {
+            select! {
+                topic_req = self.topic_receiver.next() => {
+                    match topic_req {
+                        None => {
+                            error!("Topic LC dispatcher has been terminated. Ending watch loop");
+                            break;
+                        },
+                        Some(changes) => {
+                            trace!("watch topic changes: {}",changes.count());
+                            self.watch.publish(topic_changes(changes));
+                        }
+                    }
+                },
+                partition_req = self.partition_receiver.next() => {
+                    match partition_req {
+                        None => {
+                            error!("Partition LC dispatcher has been terminated. Ending watch loop");
+                            break;
+                        },
+                        Some(changes) => {
+                            trace!("watch partition changes: {}",changes.count());
+                            self.watch.publish(partition_changes(changes));
+                        }
+                    }
+                },
+                spu_req = self.spu_receiver.next() => {
+                    match spu_req {
+                        None => {
+                            error!("SPU LC dispatcher has been terminated. Ending watch loop");
+                            break;
+                        },
+                        Some(changes) => {
+                            trace!("watch spu changes: {}",changes.count());
+                            self.watch.publish(spu_changes(changes));
+                        }
+                    }
+                }
+                complete => {},
+            }
+        }
+
+        info!("metadata watch publisher is terminated");
+    }
+}
+
+/// split local store change into change type and latest value
+fn split_change<S>(change: LSChange<S>) -> (FlvChangeType, KVObject<S>)
+where
+    S: Spec,
+    S::Key: std::fmt::Debug,
+    S::Status: std::fmt::Debug + PartialEq,
+{
+    match change {
+        LSChange::Add(obj) => (FlvChangeType::Add, obj),
+        LSChange::Mod(new_obj, _) => (FlvChangeType::Mod, new_obj),
+        LSChange::Delete(obj) => (FlvChangeType::Delete, obj),
+    }
+}
+
+fn topic_changes(changes: Actions<LSChange<TopicSpec>>) -> Vec<WatchChange> {
+    changes
+        .into_iter()
+        .map(|change| {
+            let (change_type, obj) = split_change(change);
+            WatchChange::Topic(FlvTopicChange {
+                change_type,
+                name: obj.key,
+                spec: obj.spec,
+                status: obj.status,
+            })
+        })
+        .collect()
+}
+
+fn partition_changes(changes: Actions<LSChange<PartitionSpec>>) -> Vec<WatchChange> {
+    changes
+        .into_iter()
+        .map(|change| {
+            let (change_type, obj) = split_change(change);
+            WatchChange::Partition(FlvPartitionChange {
+                change_type,
+                topic: obj.key.topic,
+                partition: obj.key.partition,
+                spec: obj.spec,
+                status: obj.status,
+            })
+        })
+        .collect()
+}
+
+fn spu_changes(changes: Actions<LSChange<SpuSpec>>) -> Vec<WatchChange> {
+    changes
+        .into_iter()
+        .map(|change| {
+            let (change_type, obj) = split_change(change);
+            WatchChange::Spu(FlvSpuChange {
+                change_type,
+                name: obj.key,
+                spec: obj.spec,
+                status: obj.status,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,236 @@
+//!
+//! # Metadata Watch State
+//!
+//! Keeps a bounded history of metadata changes tagged with epochs, and the subscribers
+//! that receive new changes as they are published.
+//!
+//! Epochs increase by one for every change. Each SC process has a random generation id,
+//! an epoch from another generation is unknown to this instance and forces a snapshot.
+//!
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::collections::VecDeque;
+
+use log::debug;
+use futures::channel::mpsc::Sender;
+use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::channel;
+
+use sc_api::watch::FlvTopicChange;
+use sc_api::watch::FlvPartitionChange;
+use sc_api::watch::FlvSpuChange;
+
+pub type SharedMetadataWatch = Arc<MetadataWatch>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchChange {
+    Topic(FlvTopicChange),
+    Partition(FlvPartitionChange),
+    Spu(FlvSpuChange),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchEvent {
+    pub epoch: i64,
+    pub change: WatchChange,
+}
+
+/// Starting point of a new subscription
+#[derive(Debug, PartialEq)]
+pub enum WatchStart {
+    /// changes missed since the requested epoch
+    Resume(Vec<WatchEvent>),
+
+    /// requested epoch is unknown, client needs a snapshot as of this epoch
+    Snapshot(i64),
+}
+
+#[derive(Debug)]
+pub struct MetadataWatch {
+    history_size: usize,
+    generation: i64,
+    inner: Mutex<WatchInner>,
+}
+
+#[derive(Debug)]
+struct WatchInner {
+    epoch: i64,
+    history: VecDeque<WatchEvent>,
+    subscribers: Vec<Sender<WatchEvent>>,
+}
+
+impl MetadataWatch {
+    pub fn new_shared(history_size: usize) -> SharedMetadataWatch {
+        Arc::new(Self::new(history_size, rand::random()))
+    }
+
+    fn new(history_size: usize, generation: i64) -> Self {
+        Self {
+            history_size,
+            generation,
+            inner: Mutex::new(WatchInner {
+                epoch: 0,
+                history: VecDeque::new(),
+                subscribers: vec![],
+            }),
+        }
+    }
+
+    /// generation of this SC process, epochs are only comparable within same generation
+    pub fn generation(&self) -> i64 {
+        self.generation
+    }
+
+    /// epoch of last published change
+    pub fn epoch(&self) -> i64 {
+        self.inner.lock().unwrap().epoch
+    }
+
+    /// assign epoch to each change, record it in history and forward it to subscribers.
+    /// Subscribers that can't keep up are dropped; their stream ends and client must resume.
+    pub fn publish(&self, changes: Vec<WatchChange>) {
+        let mut guard = self.inner.lock().unwrap();
+        let inner = &mut *guard;
+
+        for change in changes {
+            inner.epoch += 1;
+            let event = WatchEvent {
+                epoch: inner.epoch,
+                change,
+            };
+
+            let mut subscribers = Vec::with_capacity(inner.subscribers.len());
+            for mut sender in inner.subscribers.drain(..) {
+                if sender.try_send(event.clone()).is_ok() {
+                    subscribers.push(sender);
+                } else {
+                    debug!("dropping watch subscriber at epoch: {}", event.epoch);
+                }
+            }
+            inner.subscribers = subscribers;
+
+            inner.history.push_back(event);
+            while inner.history.len() > self.history_size {
+                inner.history.pop_front();
+            }
+        }
+    }
+
+    /// register new subscriber. History lookup and registration are done under same lock,
+    /// so every change after the starting point is delivered through the receiver.
+    /// epoch from other generation starts with snapshot
+    pub fn subscribe(&self, generation: i64, epoch: i64) -> (WatchStart, Receiver<WatchEvent>) {
+        let mut inner = self.inner.lock().unwrap();
+        let (sender, receiver) = channel(self.history_size);
+        inner.subscribers.push(sender);
+
+        let start = if generation == self.generation {
+            inner.start_from(epoch)
+        } else {
+            debug!("watch generation: {} is not current: {}, sending snapshot", generation, self.generation);
+            WatchStart::Snapshot(inner.epoch)
+        };
+        (start, receiver)
+    }
+}
+
+impl WatchInner {
+    fn start_from(&self, epoch: i64) -> WatchStart {
+        if epoch < 0 || epoch > self.epoch {
+            return WatchStart::Snapshot(self.epoch);
+        }
+
+        if epoch == self.epoch {
+            return WatchStart::Resume(vec![]);
+        }
+
+        match self.history.front() {
+            Some(oldest) if oldest.epoch <= epoch + 1 => WatchStart::Resume(
+                self.history
+                    .iter()
+                    .filter(|event| event.epoch > epoch)
+                    .cloned()
+                    .collect(),
+            ),
+            _ => WatchStart::Snapshot(self.epoch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use futures::stream::StreamExt;
+
+    use future_helper::test_async;
+    use sc_api::watch::FlvSpuChange;
+
+    use super::MetadataWatch;
+    use super::WatchChange;
+    use super::WatchStart;
+
+    fn spu_change(name: &str) -> WatchChange {
+        WatchChange::Spu(FlvSpuChange {
+            name: name.to_owned(),
+            ..Default::default()
+        })
+    }
+
+    const GENERATION: i64 = 7;
+
+    #[test]
+    fn test_watch_resume_from_history() {
+        let watch = MetadataWatch::new(2, GENERATION);
+        watch.publish(vec![spu_change("spu-1"), spu_change("spu-2")]);
+        assert_eq!(watch.epoch(), 2);
+
+        // resume in the middle of history
+        match watch.subscribe(GENERATION, 1).0 {
+            WatchStart::Resume(events) => {
+                assert_eq!(events.len(), 1);
+                assert_eq!(events[0].epoch, 2);
+                assert_eq!(events[0].change, spu_change("spu-2"));
+            }
+            start => panic!("unexpected start: {:#?}", start),
+        }
+
+        // up to date
+        assert_eq!(watch.subscribe(GENERATION, 2).0, WatchStart::Resume(vec![]));
+
+        // oldest change (1) is evicted, resume from 0 is no longer possible
+        watch.publish(vec![spu_change("spu-3")]);
+        assert_eq!(watch.subscribe(GENERATION, 0).0, WatchStart::Snapshot(3));
+        assert!(match watch.subscribe(GENERATION, 1).0 {
+            WatchStart::Resume(events) => events.len() == 2,
+            _ => false,
+        });
+
+        // unknown epochs
+        assert_eq!(watch.subscribe(GENERATION, -1).0, WatchStart::Snapshot(3));
+        assert_eq!(watch.subscribe(GENERATION, 500).0, WatchStart::Snapshot(3));
+    }
+
+    #[test]
+    fn test_watch_other_generation() {
+        let watch = MetadataWatch::new(10, GENERATION);
+        watch.publish(vec![spu_change("spu-1"), spu_change("spu-2")]);
+
+        // same epoch from previous SC process is not resumed
+        assert_eq!(watch.subscribe(GENERATION + 1, 1).0, WatchStart::Snapshot(2));
+        assert_eq!(watch.subscribe(GENERATION + 1, 2).0, WatchStart::Snapshot(2));
+    }
+
+    #[test_async]
+    async fn test_watch_subscriber_receives_changes() -> Result<(), ()> {
+        let watch = MetadataWatch::new(10, GENERATION);
+        let (_, mut receiver) = watch.subscribe(GENERATION, -1);
+
+        watch.publish(vec![spu_change("spu-1")]);
+
+        let event = receiver.next().await.expect("event");
+        assert_eq!(event.epoch, 1);
+        assert_eq!(event.change, spu_change("spu-1"));
+
+        Ok(())
+    }
+}
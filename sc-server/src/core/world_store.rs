@@ -1,10 +1,17 @@
 use futures::Future;
 use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::UnboundedSender;
+use log::error;
 
 use utils::actions::Actions;
 use metadata::spu::SpuSpec; 
 use metadata::topic::TopicSpec;
 use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::auth_token::AuthTokenSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
 
 use crate::ScServerError;
 use crate::core::common::WSAction;
@@ -19,6 +26,11 @@ pub trait WSUpdateService {
     fn update_spu(&self,ws_actions: WSAction<SpuSpec>) -> Self::ResponseFuture;
     fn update_topic(&self,ws_actions: WSAction<TopicSpec>) -> Self::ResponseFuture;
     fn update_partition(&self,ws_actions: WSAction<PartitionSpec>) -> Self::ResponseFuture;
+    fn update_acl(&self,ws_actions: WSAction<AclSpec>) -> Self::ResponseFuture;
+    fn update_credential(&self,ws_actions: WSAction<SaslCredentialSpec>) -> Self::ResponseFuture;
+    fn update_quota(&self,ws_actions: WSAction<ClientQuotaSpec>) -> Self::ResponseFuture;
+    fn update_auth_token(&self,ws_actions: WSAction<AuthTokenSpec>) -> Self::ResponseFuture;
+    fn update_replication_throttle(&self,ws_actions: WSAction<ReplicationThrottleSpec>) -> Self::ResponseFuture;
 
 }
 
@@ -26,6 +38,27 @@ pub trait WSUpdateService {
 pub type WSChangeChannel<S> = Receiver<Actions<LSChange<S>>>;
 
 
+/// Request dispatchers to reload world store and send every object to controllers,
+/// so they reconcile all objects again (for example after SC becomes active)
+#[derive(Clone,Default)]
+pub struct ResyncNotifier(Vec<UnboundedSender<()>>);
+
+impl ResyncNotifier {
+
+    pub fn add(&mut self,sender: UnboundedSender<()>) {
+        self.0.push(sender);
+    }
+
+    pub fn resync(&self) {
+        for sender in &self.0 {
+            if let Err(err) = sender.unbounded_send(()) {
+                error!("error requesting resync: {}",err);
+            }
+        }
+    }
+}
+
+
 pub trait WSChangeDispatcher {
 
     fn create_spu_channel(&mut self) -> WSChangeChannel<SpuSpec>;
@@ -33,4 +66,14 @@ pub trait WSChangeDispatcher {
     fn create_topic_channel(&mut self) -> WSChangeChannel<TopicSpec>;
 
     fn create_partition_channel(&mut self) -> WSChangeChannel<PartitionSpec>;
+
+    fn create_acl_channel(&mut self) -> WSChangeChannel<AclSpec>;
+
+    fn create_credential_channel(&mut self) -> WSChangeChannel<SaslCredentialSpec>;
+
+    fn create_quota_channel(&mut self) -> WSChangeChannel<ClientQuotaSpec>;
+
+    fn create_throttle_channel(&mut self) -> WSChangeChannel<ReplicationThrottleSpec>;
+
+    fn resync_notifier(&self) -> ResyncNotifier;
 }
@@ -21,7 +21,8 @@ pub enum ScServerError {
     PartitionError(PartitionError),
     K8ClientError(ClientError),
     UnknownSpu(SpuId),
-    SpuCommuncationError(SpuId,KfSocketError),    
+    SpuCommuncationError(SpuId,KfSocketError),
+    NotActive,
 }
 
 impl fmt::Display for ScServerError {
@@ -34,7 +35,8 @@ impl fmt::Display for ScServerError {
             Self::PartitionError(err) => write!(f,"{}",err),
             Self::K8ClientError(err) => write!(f,"{}",err),
             Self::UnknownSpu(spu) => write!(f,"unknown spu: {}",spu),
-            Self::SpuCommuncationError(id,err) => write!(f,"spu comm error: {}, {}",id,err)   
+            Self::SpuCommuncationError(id,err) => write!(f,"spu comm error: {}, {}",id,err),
+            Self::NotActive => write!(f,"SC is standby, world store update rejected")
         }
     }
 }
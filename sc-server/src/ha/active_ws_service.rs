@@ -0,0 +1,117 @@
+//!
+//! # Active only World Store updates
+//!
+//! Forwards world store updates only when this SC is active. Standby controllers
+//! still process changes to keep local stores warm, but their updates are rejected.
+//! Once SC becomes active, all stores are resynced so rejected work is reconciled.
+//!
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use futures::future::ready;
+use log::debug;
+
+use metadata::spu::SpuSpec;
+use metadata::topic::TopicSpec;
+use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::AuthTokenSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
+use crate::ScServerError;
+
+use super::ActiveState;
+
+#[derive(Clone)]
+pub struct ActiveWSUpdateService<W> {
+    inner: W,
+    state: ActiveState,
+}
+
+impl<W> ActiveWSUpdateService<W> {
+    pub fn new(inner: W, state: ActiveState) -> Self {
+        Self { inner, state }
+    }
+
+    fn rejected(&self, label: &str) -> BoxFuture<'static, Result<(), ScServerError>> {
+        debug!("SC standby, rejecting {} update", label);
+        ready(Err(ScServerError::NotActive)).boxed()
+    }
+}
+
+impl<W> WSUpdateService for ActiveWSUpdateService<W>
+where
+    W: WSUpdateService,
+{
+    type ResponseFuture = BoxFuture<'static, Result<(), ScServerError>>;
+
+    fn update_spu(&self, ws_actions: WSAction<SpuSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_spu(ws_actions).boxed()
+        } else {
+            self.rejected("spu")
+        }
+    }
+
+    fn update_topic(&self, ws_actions: WSAction<TopicSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_topic(ws_actions).boxed()
+        } else {
+            self.rejected("topic")
+        }
+    }
+
+    fn update_partition(&self, ws_actions: WSAction<PartitionSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_partition(ws_actions).boxed()
+        } else {
+            self.rejected("partition")
+        }
+    }
+
+    fn update_acl(&self, ws_actions: WSAction<AclSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_acl(ws_actions).boxed()
+        } else {
+            self.rejected("acl")
+        }
+    }
+
+    fn update_credential(&self, ws_actions: WSAction<SaslCredentialSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_credential(ws_actions).boxed()
+        } else {
+            self.rejected("credential")
+        }
+    }
+
+    fn update_quota(&self, ws_actions: WSAction<ClientQuotaSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_quota(ws_actions).boxed()
+        } else {
+            self.rejected("quota")
+        }
+    }
+
+    fn update_auth_token(&self, ws_actions: WSAction<AuthTokenSpec>) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_auth_token(ws_actions).boxed()
+        } else {
+            self.rejected("auth token")
+        }
+    }
+
+    fn update_replication_throttle(
+        &self,
+        ws_actions: WSAction<ReplicationThrottleSpec>,
+    ) -> Self::ResponseFuture {
+        if self.state.is_active() {
+            self.inner.update_replication_throttle(ws_actions).boxed()
+        } else {
+            self.rejected("replication throttle")
+        }
+    }
+}
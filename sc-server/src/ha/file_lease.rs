@@ -0,0 +1,74 @@
+//!
+//! # File Lease
+//!
+//! Lease for local metadata store. Active SC holds exclusive lock on lock file
+//! in store directory. Lock is released by OS when process exits.
+//!
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use futures::future::ready;
+use log::debug;
+
+use crate::ScServerError;
+
+use super::LeaseService;
+
+pub struct FileLease {
+    path: PathBuf,
+    identity: String,
+    file: Option<File>,
+}
+
+impl FileLease {
+    pub fn new(path: PathBuf, identity: String) -> Self {
+        Self {
+            path,
+            identity,
+            file: None,
+        }
+    }
+
+    fn try_lock(&mut self) -> Result<bool, IoError> {
+        if self.file.is_some() {
+            return Ok(true);
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&self.path)?;
+
+        let rc = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) };
+        if rc != 0 {
+            let err = IoError::last_os_error();
+            return if err.kind() == ErrorKind::WouldBlock {
+                Ok(false)
+            } else {
+                Err(err)
+            };
+        }
+
+        // record holder for operators, content is informational only
+        file.set_len(0)?;
+        file.write_all(self.identity.as_bytes())?;
+        file.sync_all()?;
+
+        debug!("locked lease file: {}", self.path.display());
+        self.file = Some(file);
+        Ok(true)
+    }
+}
+
+impl LeaseService for FileLease {
+    fn try_acquire_or_renew(&mut self) -> BoxFuture<'_, Result<bool, ScServerError>> {
+        ready(self.try_lock().map_err(|err| err.into())).boxed()
+    }
+}
@@ -0,0 +1,37 @@
+//!
+//! # HA Configuration
+//!
+use std::env;
+
+use types::defaults::SC_LEASE_NAME;
+use types::defaults::SC_LEASE_DURATION_SEC;
+use types::defaults::SC_LEASE_RENEW_INTERVAL_SEC;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HaConfig {
+    pub enabled: bool,
+    pub lease_name: String,
+    pub lease_duration_sec: u64,
+    pub renew_interval_sec: u64,
+}
+
+impl ::std::default::Default for HaConfig {
+    fn default() -> Self {
+        HaConfig {
+            enabled: false,
+            lease_name: SC_LEASE_NAME.to_owned(),
+            lease_duration_sec: SC_LEASE_DURATION_SEC,
+            renew_interval_sec: SC_LEASE_RENEW_INTERVAL_SEC,
+        }
+    }
+}
+
+impl HaConfig {
+    /// unique identity of this SC replica, pod name (hostname) if available
+    pub fn identity(&self, sc_id: i32) -> String {
+        match env::var("HOSTNAME") {
+            Ok(ref hostname) if !hostname.is_empty() => format!("sc-{}-{}", sc_id, hostname),
+            _ => format!("sc-{}-{}", sc_id, std::process::id()),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//!
+//! # Kubernetes Lease
+//!
+//! Lease stored as Kubernetes Lease object (coordination.k8s.io). Updates use resource
+//! version so only one SC can win. Expiry is measured against local clock from the time
+//! lease was last observed to change, so clocks of SC replicas don't need to be in sync.
+//!
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use log::debug;
+use log::info;
+
+use k8_client::ClientError;
+use k8_client::LeaseSpec;
+use k8_client::LeaseStatus;
+use k8_client::micro_time;
+use k8_metadata::core::metadata::InputK8Obj;
+use k8_metadata::core::metadata::InputObjectMeta;
+use k8_metadata::core::metadata::K8SpecObj;
+
+use crate::ScServerError;
+use crate::k8::SharedK8Client;
+
+use super::LeaseService;
+
+pub struct K8Lease {
+    client: SharedK8Client,
+    namespace: String,
+    name: String,
+    identity: String,
+    duration_sec: u64,
+    observed: Option<(String, Instant)>, // resource version of other holder's lease, when seen
+}
+
+impl K8Lease {
+    pub fn new(
+        client: SharedK8Client,
+        namespace: String,
+        name: String,
+        identity: String,
+        duration_sec: u64,
+    ) -> Self {
+        Self {
+            client,
+            namespace,
+            name,
+            identity,
+            duration_sec,
+            observed: None,
+        }
+    }
+
+    async fn acquire_or_renew(&mut self) -> Result<bool, ScServerError> {
+        let input_meta = InputObjectMeta::named(self.name.clone(), self.namespace.clone());
+
+        let lease = match self.client.retrieve_item::<LeaseSpec, _>(&input_meta).await {
+            Ok(lease) => lease,
+            Err(ClientError::NotFound) => return self.create(input_meta).await,
+            Err(err) => return Err(err.into()),
+        };
+
+        let holder = lease.spec.holder_identity.clone().unwrap_or_default();
+        let is_holder = holder == self.identity;
+
+        if !is_holder && !holder.is_empty() {
+            let now = Instant::now();
+            let version = &lease.metadata.resource_version;
+            let changed = match &self.observed {
+                Some((observed_version, _)) => observed_version != version,
+                None => true,
+            };
+            if changed {
+                self.observed = Some((version.clone(), now));
+            }
+
+            let observed_at = self.observed.as_ref().map(|(_, at)| *at).unwrap_or(now);
+            let duration = lease
+                .spec
+                .lease_duration_seconds
+                .map(|secs| secs.max(0) as u64)
+                .unwrap_or(self.duration_sec);
+            if now.duration_since(observed_at) < Duration::from_secs(duration) {
+                return Ok(false);
+            }
+
+            info!("lease {} held by {} has expired, taking over", self.name, holder);
+        }
+
+        let now_time = micro_time(SystemTime::now());
+        let mut spec = lease.spec.clone();
+        if !is_holder {
+            spec.acquire_time = Some(now_time.clone());
+            spec.lease_transitions = Some(spec.lease_transitions.unwrap_or(0) + 1);
+        }
+        spec.holder_identity = Some(self.identity.clone());
+        spec.lease_duration_seconds = Some(self.duration_sec as i32);
+        spec.renew_time = Some(now_time);
+
+        let update = K8SpecObj::new(spec, lease.metadata.as_update());
+
+        match self.client.replace_item(&update).await {
+            Ok(_) => Ok(true),
+            Err(ClientError::Conflict) => {
+                debug!("lease {} updated by other SC", self.name);
+                Ok(false)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn create(&mut self, input_meta: InputObjectMeta) -> Result<bool, ScServerError> {
+        let now_time = micro_time(SystemTime::now());
+        let spec = LeaseSpec {
+            holder_identity: Some(self.identity.clone()),
+            lease_duration_seconds: Some(self.duration_sec as i32),
+            acquire_time: Some(now_time.clone()),
+            renew_time: Some(now_time),
+            lease_transitions: Some(0),
+        };
+
+        debug!("creating lease: {}", self.name);
+        let input: InputK8Obj<LeaseSpec> = InputK8Obj::new(spec, input_meta);
+        match self.client.create_item::<LeaseSpec, LeaseStatus>(input).await {
+            Ok(_) => Ok(true),
+            Err(ClientError::Conflict) => Ok(false), // created by other SC
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl LeaseService for K8Lease {
+    fn try_acquire_or_renew(&mut self) -> BoxFuture<'_, Result<bool, ScServerError>> {
+        self.acquire_or_renew().boxed()
+    }
+}
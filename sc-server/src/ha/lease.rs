@@ -0,0 +1,152 @@
+//!
+//! # Lease based leader election
+//!
+//! Standby SC polls lease until it can be acquired. Active SC renews lease periodically.
+//! If lease is lost, process exits so it can be restarted as standby.
+//!
+use std::process;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::future::BoxFuture;
+use log::error;
+use log::info;
+use log::trace;
+
+use future_helper::spawn;
+use future_helper::sleep;
+
+use crate::ScServerError;
+
+use super::HaConfig;
+
+/// backend holding the lease
+pub trait LeaseService: Send + 'static {
+    /// acquire lease or renew it if already held. returns true if this SC holds lease
+    fn try_acquire_or_renew(&mut self) -> BoxFuture<'_, Result<bool, ScServerError>>;
+}
+
+/// shared flag, true when this SC is active
+#[derive(Debug, Clone)]
+pub struct ActiveState(Arc<AtomicBool>);
+
+impl ActiveState {
+    pub fn active() -> Self {
+        ActiveState(Arc::new(AtomicBool::new(true)))
+    }
+
+    pub fn standby() -> Self {
+        ActiveState(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn set_active(&self) {
+        self.0.store(true, Ordering::SeqCst)
+    }
+}
+
+pub struct LeaderElector<L> {
+    lease: L,
+    state: ActiveState,
+    config: HaConfig,
+}
+
+impl<L> LeaderElector<L>
+where
+    L: LeaseService,
+{
+    pub fn new(lease: L, state: ActiveState, config: HaConfig) -> Self {
+        Self {
+            lease,
+            state,
+            config,
+        }
+    }
+
+    /// wait as standby until lease is acquired
+    pub async fn acquire(&mut self) {
+        info!("SC standby, waiting for lease: {}", self.config.lease_name);
+        loop {
+            match self.lease.try_acquire_or_renew().await {
+                Ok(true) => {
+                    self.state.set_active();
+                    info!("SC acquired lease: {}, now active", self.config.lease_name);
+                    return;
+                }
+                Ok(false) => trace!("lease held by other SC, remain standby"),
+                Err(err) => error!("error acquiring lease: {}", err),
+            }
+            sleep(self.renew_interval()).await;
+        }
+    }
+
+    /// renew lease in background
+    pub fn run(self) {
+        spawn(self.renew_loop());
+    }
+
+    async fn renew_loop(mut self) {
+        let lease_duration = Duration::from_secs(self.config.lease_duration_sec);
+        let renew_interval = self.renew_interval();
+        let mut last_renew = Instant::now();
+        loop {
+            sleep(renew_interval).await;
+
+            // lease is valid from the time renewal was requested, not when it completed
+            let renew_start = Instant::now();
+            match self.lease.try_acquire_or_renew().await {
+                Ok(true) => {
+                    trace!("lease renewed: {}", self.config.lease_name);
+                    last_renew = renew_start;
+                }
+                Ok(false) => {
+                    error!("SC lease lost to another SC, exiting");
+                    process::exit(0x0100);
+                }
+                Err(err) => {
+                    error!("error renewing lease: {}", err);
+                    if lease_expiring(last_renew.elapsed(), renew_interval, lease_duration) {
+                        error!("SC lease will expire before next renewal, exiting");
+                        process::exit(0x0100);
+                    }
+                }
+            }
+        }
+    }
+
+    fn renew_interval(&self) -> Duration {
+        Duration::from_secs(self.config.renew_interval_sec)
+    }
+}
+
+/// true if lease can expire before next renewal attempt completes.
+/// active SC must step down before standby SC can acquire lease, otherwise both are active
+fn lease_expiring(elapsed: Duration, renew_interval: Duration, lease_duration: Duration) -> bool {
+    elapsed + renew_interval >= lease_duration
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use super::lease_expiring;
+
+    #[test]
+    fn test_lease_expiring() {
+        let renew_interval = Duration::from_secs(5);
+        let lease_duration = Duration::from_secs(15);
+
+        assert!(!lease_expiring(Duration::from_secs(5), renew_interval, lease_duration));
+        assert!(!lease_expiring(Duration::from_secs(9), renew_interval, lease_duration));
+        // next renewal would happen at or after expiry
+        assert!(lease_expiring(Duration::from_secs(10), renew_interval, lease_duration));
+        assert!(lease_expiring(Duration::from_secs(15), renew_interval, lease_duration));
+    }
+}
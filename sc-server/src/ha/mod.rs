@@ -0,0 +1,36 @@
+//!
+//! # SC High Availability
+//!
+//! Several SC replicas can run at the same time, only the one holding the lease is active.
+//! Standby replicas run dispatchers and controllers so local stores are kept warm,
+//! but their world store updates are rejected and public/private servers are not started.
+//! When lease is acquired, all stores are resynced so controllers reconcile every object.
+//! SPUs connect to whichever SC is active.
+//!
+mod ha_config;
+mod lease;
+mod k8_lease;
+mod file_lease;
+mod active_ws_service;
+
+use crate::core::ResyncNotifier;
+
+pub use ha_config::HaConfig;
+pub use lease::ActiveState;
+pub use lease::LeaseService;
+pub use lease::LeaderElector;
+pub use k8_lease::K8Lease;
+pub use file_lease::FileLease;
+pub use active_ws_service::ActiveWSUpdateService;
+
+/// acquire lease (waits as standby) and keep renewing it in background.
+/// once active, request full resync since updates were rejected while standby
+pub async fn become_active<L>(lease: L, state: ActiveState, config: HaConfig, resync: ResyncNotifier)
+where
+    L: LeaseService,
+{
+    let mut elector = LeaderElector::new(lease, state, config);
+    elector.acquire().await;
+    resync.resync();
+    elector.run();
+}
@@ -5,11 +5,17 @@
 //! and receivers.
 //!
 use std::sync::Arc;
+use std::path::PathBuf;
 use log::info;
 
+use types::defaults::SC_WATCH_HISTORY_SIZE;
+
 use future_helper::run;
+use k8_config::K8Config;
+use kf_service::MetricsServer;
 
 use crate::conn_manager::ConnManager;
+use crate::conn_manager::ConnectionRequest;
 
 use crate::core::LocalStores;
 use crate::core::ShareLocalStores;
@@ -18,20 +24,49 @@ use crate::core::WSChangeDispatcher;
 use crate::core::spus::SpuController;
 use crate::core::topics::TopicController;
 use crate::core::partitions::PartitionController;
+use crate::core::acls::AclController;
+use crate::core::credentials::CredentialController;
+use crate::core::quotas::QuotaController;
+use crate::core::throttles::ReplicationThrottleController;
+use crate::core::watch::MetadataWatch;
+use crate::core::watch::SharedMetadataWatch;
+use crate::core::watch::MetadataWatchPublisher;
 use crate::cli::parse_cli_or_exit;
+use crate::cli::ScConfig;
+use crate::cli::MetadataStoreConfig;
 use crate::services::create_public_server;
 use crate::services::create_internal_server;
 use crate::services::InternalApiServer;
 use crate::services::PubliApiServer;
+use crate::services::PublicWSService;
 use crate::k8::K8WSUpdateService;
 use crate::k8::new_shared;
 use crate::k8::K8AllChangeDispatcher;
 use crate::k8::operator::run_spg_operator;
+use crate::local::MetadataFileStore;
+use crate::local::LocalWSUpdateService;
+use crate::local::LocalAllChangeDispatcher;
+use crate::ha::ActiveState;
+use crate::ha::ActiveWSUpdateService;
+use crate::ha::K8Lease;
+use crate::ha::FileLease;
+use crate::ha::become_active;
 
 pub fn main_loop() {
     // parse configuration (program exits on error)
     let (sc_config,k8_config) = parse_cli_or_exit();
 
+    match sc_config.metadata_store.clone() {
+        MetadataStoreConfig::K8 => {
+            let k8_config = k8_config.expect("k8 config is required for k8 metadata store");
+            run_with_k8_store(sc_config,k8_config)
+        },
+        MetadataStoreConfig::Local(base_dir) => run_with_local_store(sc_config,base_dir)
+    }
+}
+
+/// run SC with metadata in kubernetes
+fn run_with_k8_store(sc_config: ScConfig,k8_config: K8Config) {
 
     run( async move {
 
@@ -39,14 +74,32 @@ pub fn main_loop() {
         let k8_client = new_shared(k8_config);
 
         let namespace = sc_config.namespace.clone();
+        let sc_id = sc_config.id;
+        let ha_config = sc_config.ha.clone();
+        let active_state = initial_active_state(&sc_config);
         let local_stores = LocalStores::shared_metadata(sc_config);
 
-        let k8_ws_service = K8WSUpdateService::new(k8_client.clone());
+        let k8_ws_service = K8WSUpdateService::new(k8_client.clone(),namespace.clone());
         let mut k8_dispatcher = K8AllChangeDispatcher::new(k8_client.clone(),namespace.clone(),local_stores.clone());
-        let (metadata,internal_server) =  create_core_services(local_stores,k8_ws_service.clone(),&mut k8_dispatcher);
-        let public_server =  create_k8_services(metadata,k8_ws_service,namespace);
+        let controller_ws_service = ActiveWSUpdateService::new(k8_ws_service.clone(),active_state.clone());
+        let (metadata,internal_server,watch) =  create_core_services(local_stores,controller_ws_service,&mut k8_dispatcher);
+        start_metrics_server(&metadata);
 
+        let resync_notifier = k8_dispatcher.resync_notifier();
         k8_dispatcher.run();
+
+        // standby SC waits here until lease is acquired
+        if ha_config.enabled {
+            let lease = K8Lease::new(
+                k8_client.clone(),
+                namespace.clone(),
+                ha_config.lease_name.clone(),
+                ha_config.identity(sc_id),
+                ha_config.lease_duration_sec);
+            become_active(lease,active_state,ha_config,resync_notifier).await;
+        }
+
+        let public_server =  create_k8_services(metadata,k8_ws_service,namespace,watch);
         let _public_shutdown = public_server.run();
         let _private_shutdown = internal_server.run();
 
@@ -56,8 +109,64 @@ pub fn main_loop() {
 
 }
 
+/// run SC with metadata in local directory, no kubernetes required
+fn run_with_local_store(sc_config: ScConfig,base_dir: PathBuf) {
+
+    run( async move {
+
+        let file_store = MetadataFileStore::new_shared(base_dir.clone()).expect("Error: local metadata store failed to initialize!");
+
+        let namespace = sc_config.namespace.clone();
+        let sc_id = sc_config.id;
+        let ha_config = sc_config.ha.clone();
+        let active_state = initial_active_state(&sc_config);
+        let local_stores = LocalStores::shared_metadata(sc_config);
+
+        let mut local_dispatcher = LocalAllChangeDispatcher::new(file_store.clone(),local_stores.clone());
+        let local_ws_service = LocalWSUpdateService::new(file_store,local_dispatcher.notifier());
+        let controller_ws_service = ActiveWSUpdateService::new(local_ws_service.clone(),active_state.clone());
+        let (metadata,internal_server,watch) =  create_core_services(local_stores,controller_ws_service,&mut local_dispatcher);
+        start_metrics_server(&metadata);
+
+        let resync_notifier = local_dispatcher.resync_notifier();
+        local_dispatcher.run();
+
+        // standby SC waits here until lock on store directory is acquired
+        if ha_config.enabled {
+            let lease = FileLease::new(base_dir.join("sc.lock"),ha_config.identity(sc_id));
+            become_active(lease,active_state,ha_config,resync_notifier).await;
+        }
+
+        let public_server = create_public_server(metadata,PublicWSService::Local(local_ws_service),namespace,watch);
+        let _public_shutdown = public_server.run();
+        let _private_shutdown = internal_server.run();
+
+        println!("Streaming Coordinator started successfully (local metadata store)");
+        info!("SC started successfully with local metadata store")
+    });
+
+}
+
+/// metrics and health are served by standby SC as well
+fn start_metrics_server(local_stores: &ShareLocalStores) {
+    MetricsServer::new(
+        local_stores.config().metrics_endpoint.addr,
+        local_stores.metrics().clone(),
+        local_stores.clone()
+    ).run();
+}
+
+/// with HA enabled, SC starts as standby until lease is acquired
+fn initial_active_state(sc_config: &ScConfig) -> ActiveState {
+    if sc_config.ha.enabled {
+        ActiveState::standby()
+    } else {
+        ActiveState::active()
+    }
+}
+
 /// essential services which are needed
-pub fn create_core_services<W,D>(local_stores: ShareLocalStores,ws_service: W,ws_dispatcher: &mut D) -> (ShareLocalStores,InternalApiServer)
+pub fn create_core_services<W,D>(local_stores: ShareLocalStores,ws_service: W,ws_dispatcher: &mut D) -> (ShareLocalStores,InternalApiServer,SharedMetadataWatch)
     where W: WSUpdateService + Clone + Sync + Send + 'static,
       D: WSChangeDispatcher
 {
@@ -69,6 +178,13 @@ pub fn create_core_services<W,D>(local_stores: ShareLocalStores,ws_service: W,ws
     let topic_topic_channel = ws_dispatcher.create_topic_channel();
     let partition_channel = ws_dispatcher.create_partition_channel();
     let partition_spu_channel = ws_dispatcher.create_spu_channel();
+    let watch_topic_channel = ws_dispatcher.create_topic_channel();
+    let watch_partition_channel = ws_dispatcher.create_partition_channel();
+    let watch_spu_channel = ws_dispatcher.create_spu_channel();
+    let acl_channel = ws_dispatcher.create_acl_channel();
+    let credential_channel = ws_dispatcher.create_credential_channel();
+    let quota_channel = ws_dispatcher.create_quota_channel();
+    let throttle_channel = ws_dispatcher.create_throttle_channel();
 
     let shared_conn_manager = Arc::new(conn_manager);
 
@@ -87,37 +203,79 @@ pub fn create_core_services<W,D>(local_stores: ShareLocalStores,ws_service: W,ws
         ws_service.clone()
     );
 
+    let topic_controller = TopicController::new(
+      local_stores.clone(),
+      topic_spu_channel,
+      topic_topic_channel,
+      ws_service.clone()
+    );
+
+    let acl_controller = AclController::new(
+        shared_conn_manager.clone(),
+        acl_channel,
+        ConnectionRequest::RefreshAcls
+    );
+
+    let credential_controller = CredentialController::new(
+        shared_conn_manager.clone(),
+        credential_channel,
+        ConnectionRequest::RefreshCredentials
+    );
+
+    let quota_controller = QuotaController::new(
+        shared_conn_manager.clone(),
+        quota_channel,
+        ConnectionRequest::RefreshQuotas
+    );
+
+    let throttle_controller = ReplicationThrottleController::new(
+        shared_conn_manager.clone(),
+        throttle_channel,
+        ConnectionRequest::RefreshReplicationThrottle
+    );
+
     let private_server = create_internal_server(
         local_stores.clone(),
         shared_conn_manager,
         spu_controller.conn_sender(),
-        partiton_controller.lrs_sendr()
+        partiton_controller.lrs_sendr(),
+        topic_controller.replica_removed_sender()
     );
 
     spu_controller.run();
 
-    let topic_controller = TopicController::new(
-      local_stores.clone(),
-      topic_spu_channel,
-      topic_topic_channel,
-      ws_service.clone()
-    );
     topic_controller.run();
 
     
     partiton_controller.run();
 
+    acl_controller.run();
+
+    credential_controller.run();
+
+    quota_controller.run();
+
+    throttle_controller.run();
+
+    // publish metadata changes to watch subscribers
+    let watch = MetadataWatch::new_shared(SC_WATCH_HISTORY_SIZE);
+    MetadataWatchPublisher::new(
+        watch.clone(),
+        watch_topic_channel,
+        watch_partition_channel,
+        watch_spu_channel
+    ).run();
 
-    (local_stores,private_server)
+    (local_stores,private_server,watch)
 
 }
 
 /// k8 specific services
-fn create_k8_services(metadata: ShareLocalStores, k8_ws: K8WSUpdateService,namespace: String) -> PubliApiServer {
+fn create_k8_services(metadata: ShareLocalStores, k8_ws: K8WSUpdateService,namespace: String,watch: SharedMetadataWatch) -> PubliApiServer {
    
     // k8 operators
     run_spg_operator(k8_ws.own_client(),namespace.clone(),metadata.owned_spus());
     
-    create_public_server(metadata.clone(),k8_ws,namespace)
+    create_public_server(metadata.clone(),PublicWSService::K8(k8_ws),namespace,watch)
 
 }
@@ -11,6 +11,9 @@ use std::fmt::Display;
 
 use futures::future::FutureExt;
 use futures::channel::mpsc::Sender;
+use futures::channel::mpsc::UnboundedSender;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::mpsc::unbounded;
 use futures::select;
 use futures::stream::StreamExt;
 use futures::sink::SinkExt;
@@ -37,6 +40,7 @@ use crate::ScServerError;
 
 use crate::k8::SharedK8Client;
 use crate::k8::k8_events_to_actions::k8_events_to_metadata_actions;
+use crate::k8::k8_events_to_actions::k8_events_to_resync_actions;
 use crate::k8::k8_events_to_actions::k8_event_stream_to_metadata_actions;
 
 
@@ -47,7 +51,10 @@ pub struct K8ClusterStateDispatcher<S> where S: Spec, S::Status: Debug + Partial
     client: SharedK8Client,
     metadata: Arc<LocalStore<S>>,
     senders: Vec<Sender<Actions<LSChange<S>>>>,
-    namespace: String
+    namespace: String,
+    resync_sender: UnboundedSender<()>,
+    resync_receiver: UnboundedReceiver<()>,
+    resync_pending: bool
 }
 
 
@@ -64,14 +71,23 @@ impl <S>K8ClusterStateDispatcher<S>
         
 {
     pub fn new(namespace: String,client: SharedK8Client,metadata: Arc<LocalStore<S>>) -> Self {
+        let (resync_sender,resync_receiver) = unbounded();
         Self {
             namespace,
             client,
             metadata,
             senders: vec![],
+            resync_sender,
+            resync_receiver,
+            resync_pending: false
         }
     }
 
+    /// sender used to request full resync of all objects
+    pub fn resync_sender(&self) -> UnboundedSender<()> {
+        self.resync_sender.clone()
+    }
+
     pub fn create_channel(&mut self) -> WSChangeChannel<S> {
         let (sender,receiver) = new_channel();
         self.senders.push(sender);
@@ -119,6 +135,14 @@ impl <S>K8ClusterStateDispatcher<S>
                     break;
                 },
 
+                msg = self.resync_receiver.next() => {
+                    if msg.is_some() {
+                        debug!("resync requested - reload all {}",S::LABEL);
+                        self.resync_pending = true;
+                        break;
+                    }
+                },
+
                 k8_result = k8_stream.next() =>  {
 
                     if let Some(result) = k8_result {
@@ -169,9 +193,15 @@ impl <S>K8ClusterStateDispatcher<S>
         debug!("UpdateAll {}",S::LABEL);
 
         // wait to receive all items before sending to channel
-        let actions  = k8_events_to_metadata_actions(k8_items, &self.metadata)?;
+        let actions = if self.resync_pending {
+            self.resync_pending = false;
+            k8_events_to_resync_actions(k8_items, &self.metadata)?
+        } else {
+            k8_events_to_metadata_actions(k8_items, &self.metadata)?
+        };
 
         self.send_actions(actions).await;
+        self.metadata.mark_synced();
 
         // return versions to the caller
         Ok(version)
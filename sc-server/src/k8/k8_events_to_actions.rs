@@ -112,6 +112,36 @@ pub fn k8_events_to_metadata_actions<S>(
     Ok(actions)
 }
 
+///
+/// Same as k8_events_to_metadata_actions, but every object in local store is sent as Add
+/// so controllers reconcile all objects, not only changed ones.
+///
+pub fn k8_events_to_resync_actions<S>(
+    k8_tokens: K8List<S::K8Spec,<S::K8Spec as K8Spec>::Status>,
+    local_store: &LocalStore<S>,
+) -> Result<Actions<LSChange<S>>, ScServerError> 
+    where 
+        S: Spec + PartialEq, 
+        S::Status: Status + PartialEq + Debug , 
+        S::K8Spec: Debug, 
+        S::Key: Clone + Ord  + Debug + Display
+{
+    let changes = k8_events_to_metadata_actions(k8_tokens, local_store)?;
+
+    let mut actions: Actions<LSChange<S>> = Actions::default();
+    for change in changes.into_iter() {
+        if let LSChange::Delete(_) = change {
+            actions.push(change);
+        }
+    }
+    for value in local_store.all_values().into_iter() {
+        actions.push(LSChange::add(value));
+    }
+
+    debug!("{} resync: {} objects",S::LABEL,local_store.count());
+    Ok(actions)
+}
+
 ///
 /// Translates K8 events into metadata action.
 ///
@@ -257,6 +287,7 @@ pub mod test {
     use crate::core::topics::TopicLocalStore;
 
     use super::k8_events_to_metadata_actions;
+    use super::k8_events_to_resync_actions;
     use super::k8_event_stream_to_metadata_actions;
     use super::k8_obj_to_kv_obj;
    
@@ -333,6 +364,32 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn test_resync_sends_unchanged_items() {
+
+        let mut topics = TopicList::new();
+        topics.items.push(K8Topic::new("topic1",K8TopicSpec::default()));
+
+        let topic_store = TopicLocalStore::default();
+        let topic_kv = k8_obj_to_kv_obj(K8Topic::new("topic1",K8TopicSpec::default())).expect("work");
+        topic_store.insert(topic_kv);
+        let removed_kv = k8_obj_to_kv_obj(K8Topic::new("topic2",K8TopicSpec::default())).expect("work");
+        topic_store.insert(removed_kv);
+
+        let kv_actions = k8_events_to_resync_actions(topics,&topic_store).expect("conversion");
+
+        assert_eq!(kv_actions.count(),2);
+        let mut actions = kv_actions.iter();
+        match actions.next().expect("first") {
+            LSChange::Delete(old_value) => assert_eq!(old_value.key(),"topic2"),
+            _ => panic!("expected delete"),
+        }
+        match actions.next().expect("second") {
+            LSChange::Add(value) => assert_eq!(value.key(),"topic1"),
+            _ => panic!("expected add"),
+        }
+    }
+
     #[test]
     fn test_items_delete() {
  
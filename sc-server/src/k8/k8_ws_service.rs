@@ -11,15 +11,20 @@ use std::io::ErrorKind;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 use log::trace;
-use log::warn;
 use log::debug;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use metadata::topic::TopicSpec;
 use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::AuthTokenSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
 use metadata::spu::SpuSpec; 
 use k8_metadata::core::metadata::InputK8Obj;
+use k8_metadata::core::metadata::InputObjectMeta;
 
 use types::log_on_err;
 
@@ -35,23 +40,29 @@ use crate::core::common::WSAction;
 use super::SharedK8Client;
 
 #[derive(Clone)]
-pub struct K8WSUpdateService(SharedK8Client);
+pub struct K8WSUpdateService {
+    client: SharedK8Client,
+    namespace: String
+}
 
 
 
 impl K8WSUpdateService {
 
-    pub fn new(client: SharedK8Client) -> Self {
-         Self(client)
+    pub fn new(client: SharedK8Client,namespace: String) -> Self {
+         Self {
+             client,
+             namespace
+         }
     }
 
 
     pub fn client(&self) -> &K8Client {
-        &self.0
+        &self.client
     }
 
     pub fn own_client(&self) -> SharedK8Client {
-        self.0.clone()
+        self.client.clone()
     }
 
 
@@ -77,7 +88,7 @@ impl K8WSUpdateService {
             
             let new_k8 = InputK8Obj::new(k8_spec,item_ctx.into());
 
-            self.0
+            self.client
                 .apply(new_k8)
                 .await.map(|_| ()).map_err(|err|err.into())
 
@@ -87,7 +98,7 @@ impl K8WSUpdateService {
 
             let new_k8 = InputK8Obj::new(k8_spec,parent_metadata.make_child_input_metadata::<<<S as Spec>::Owner as Spec>::K8Spec>(item_name));
 
-            self.0
+            self.client
                 .apply(new_k8)
                 .await.map(|_| ()).map_err(|err| err.into())
         } else {
@@ -129,7 +140,7 @@ impl K8WSUpdateService {
             };
 
         
-            self.0
+            self.client
                 .update_status(&k8_input)
                 .await
                 .map(|_| ())
@@ -175,7 +186,7 @@ impl K8WSUpdateService {
             };
 
         
-            self.0
+            self.client
                 .apply(k8_input)
                 .await
                 .map(|_| ())
@@ -191,6 +202,31 @@ impl K8WSUpdateService {
     }
 
 
+    /// delete by key, objects are looked up in our namespace
+    async fn delete<S>(
+        &self,
+        key: S::Key,
+    ) -> Result<(), ScServerError>
+         where S: Spec,
+            S::Key: Display
+    {
+
+        debug!("K8 Delete: {} key: {}",S::LABEL,key);
+
+        let input = InputObjectMeta {
+            name: key.to_string(),
+            namespace: self.namespace.clone(),
+            ..Default::default()
+        };
+
+        self.client
+            .delete_item::<S::K8Spec,_>(&input)
+            .await
+            .map(|_| ())
+            .map_err(|err| err.into())
+    }
+
+
     async fn inner_process<S>(&self,action: WSAction<S>) -> Result<(), ScServerError> 
 
         where 
@@ -206,7 +242,7 @@ impl K8WSUpdateService {
             WSAction::Add(value) => log_on_err!(self.add(value).await),
             WSAction::UpdateStatus(value) => log_on_err!(self.update_status(value).await),
             WSAction::UpdateSpec(value) => log_on_err!(self.update_spec(value).await),
-            WSAction::Delete(_key) => warn!("delete not yet implemente")
+            WSAction::Delete(key) => log_on_err!(self.delete::<S>(key).await)
         }
             
         Ok(())
@@ -243,5 +279,50 @@ impl WSUpdateService for K8WSUpdateService {
             Ok(())
         }.boxed()
     }
-    
-}
\ No newline at end of file
+
+    fn update_acl(&self,ws_actions: WSAction<AclSpec>) -> Self::ResponseFuture {
+
+        let service = self.clone();
+        async move {
+            service.inner_process(ws_actions).await?;
+            Ok(())
+        }.boxed()
+    }
+
+    fn update_credential(&self,ws_actions: WSAction<SaslCredentialSpec>) -> Self::ResponseFuture {
+
+        let service = self.clone();
+        async move {
+            service.inner_process(ws_actions).await?;
+            Ok(())
+        }.boxed()
+    }
+
+    fn update_quota(&self,ws_actions: WSAction<ClientQuotaSpec>) -> Self::ResponseFuture {
+
+        let service = self.clone();
+        async move {
+            service.inner_process(ws_actions).await?;
+            Ok(())
+        }.boxed()
+    }
+
+    fn update_auth_token(&self,ws_actions: WSAction<AuthTokenSpec>) -> Self::ResponseFuture {
+
+        let service = self.clone();
+        async move {
+            service.inner_process(ws_actions).await?;
+            Ok(())
+        }.boxed()
+    }
+
+    fn update_replication_throttle(&self,ws_actions: WSAction<ReplicationThrottleSpec>) -> Self::ResponseFuture {
+
+        let service = self.clone();
+        async move {
+            service.inner_process(ws_actions).await?;
+            Ok(())
+        }.boxed()
+    }
+
+}
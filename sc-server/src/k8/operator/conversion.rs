@@ -15,6 +15,7 @@ use k8_client::ContainerSpec;
 use k8_client::ContainerPortSpec;
 use k8_client::PodSpec;
 use k8_client::VolumeMount;
+use k8_client::Probe;
 use k8_client::ResourceRequirements;
 use k8_client::VolumeRequest;
 use k8_client::PersistentVolumeClaim;
@@ -25,6 +26,7 @@ use k8_metadata::core::Spec;
 use types::defaults::SPU_DEFAULT_NAME;
 use types::defaults::SPU_PUBLIC_PORT;
 use types::defaults::SPU_PRIVATE_PORT;
+use types::defaults::SPU_METRICS_PORT;
 use types::defaults::PRODUCT_NAME;
 use types::defaults::IMAGE_NAME;
 use types::defaults::FLV_LOG_BASE_DIR;
@@ -75,6 +77,14 @@ fn generate_stateful(spg_spec: &SpuGroupSpec,name: &str,namespace: &str) -> Stat
     };
     private_port.name = Some("private".to_owned());
 
+    // metrics port also serves liveness and readiness probes
+    let metrics_port = ContainerPortSpec::new(SPU_METRICS_PORT, "metrics");
+    let mut liveness_probe = Probe::http("/healthz", SPU_METRICS_PORT);
+    liveness_probe.initial_delay_seconds = Some(10);
+    liveness_probe.period_seconds = Some(10);
+    let mut readiness_probe = Probe::http("/ready", SPU_METRICS_PORT);
+    readiness_probe.period_seconds = Some(5);
+
     // storage is special because defaults are explicit.
     let storage = spg_spec.template.spec.storage.clone().unwrap_or_default();
     let size =  storage.size();
@@ -99,13 +109,15 @@ fn generate_stateful(spg_spec: &SpuGroupSpec,name: &str,namespace: &str) -> Stat
             containers: vec![ContainerSpec {
                 name: SPU_DEFAULT_NAME.to_owned(),
                 image: Some(format!("{}:0.1-alpha", IMAGE_NAME)),
-                ports: vec![public_port, private_port],
+                ports: vec![public_port, private_port, metrics_port],
                 volume_mounts: vec![VolumeMount {
                     name: "data".to_owned(),
                     mount_path: format!("/var/lib/{}/data", PRODUCT_NAME),
                     ..Default::default()
                 }],
                 env: Some(env),
+                liveness_probe: Some(liveness_probe),
+                readiness_probe: Some(readiness_probe),
                 ..Default::default()
             }],
             ..Default::default()
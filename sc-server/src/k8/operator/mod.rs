@@ -5,15 +5,24 @@ mod spg_group;
 use metadata::spu::SpuSpec; 
 use metadata::topic::TopicSpec;
 use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
 
 use crate::core::WSChangeDispatcher;
 use crate::core::WSChangeChannel;
+use crate::core::ResyncNotifier;
 use crate::k8::SharedK8Client;
 use crate::core::ShareLocalStores;
 use crate::core::spus::K8SpuChangeDispatcher;
 use crate::core::spus::SharedSpuLocalStore;
 use crate::core::partitions::K8PartitionChangeDispatcher;
 use crate::core::topics::K8TopicChangeDispatcher;
+use crate::core::acls::K8AclChangeDispatcher;
+use crate::core::credentials::K8CredentialChangeDispatcher;
+use crate::core::quotas::K8QuotaChangeDispatcher;
+use crate::core::throttles::K8ThrottleChangeDispatcher;
 use spg_operator::SpgOperator;
 
 use self::conversion::convert_cluster_to_statefulset;
@@ -24,7 +33,11 @@ use self::spg_group::SpuValidation;
 pub struct K8AllChangeDispatcher {
     spu: K8SpuChangeDispatcher,
     topic: K8TopicChangeDispatcher,
-    partition: K8PartitionChangeDispatcher
+    partition: K8PartitionChangeDispatcher,
+    acl: K8AclChangeDispatcher,
+    credential: K8CredentialChangeDispatcher,
+    quota: K8QuotaChangeDispatcher,
+    throttle: K8ThrottleChangeDispatcher
 }
 
 impl K8AllChangeDispatcher {
@@ -34,7 +47,11 @@ impl K8AllChangeDispatcher {
         Self {
             spu: K8SpuChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.spus().clone()),
             topic: K8TopicChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.topics().clone()),
-            partition: K8PartitionChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.partitions().clone())
+            partition: K8PartitionChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.partitions().clone()),
+            acl: K8AclChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.acls().clone()),
+            credential: K8CredentialChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.credentials().clone()),
+            quota: K8QuotaChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.quotas().clone()),
+            throttle: K8ThrottleChangeDispatcher::new(namespace.clone(),client.clone(),local_stores.replication_throttles().clone())
         }
     }
 
@@ -42,6 +59,10 @@ impl K8AllChangeDispatcher {
         self.spu.run();
         self.topic.run();
         self.partition.run();
+        self.acl.run();
+        self.credential.run();
+        self.quota.run();
+        self.throttle.run();
     }
 
 
@@ -61,6 +82,34 @@ impl WSChangeDispatcher for K8AllChangeDispatcher {
     fn create_partition_channel(&mut self) -> WSChangeChannel<PartitionSpec>{
         self.partition.create_channel()
     }
+
+    fn create_acl_channel(&mut self) -> WSChangeChannel<AclSpec> {
+        self.acl.create_channel()
+    }
+
+    fn create_credential_channel(&mut self) -> WSChangeChannel<SaslCredentialSpec> {
+        self.credential.create_channel()
+    }
+
+    fn create_quota_channel(&mut self) -> WSChangeChannel<ClientQuotaSpec> {
+        self.quota.create_channel()
+    }
+
+    fn create_throttle_channel(&mut self) -> WSChangeChannel<ReplicationThrottleSpec> {
+        self.throttle.create_channel()
+    }
+
+    fn resync_notifier(&self) -> ResyncNotifier {
+        let mut notifier = ResyncNotifier::default();
+        notifier.add(self.spu.resync_sender());
+        notifier.add(self.topic.resync_sender());
+        notifier.add(self.partition.resync_sender());
+        notifier.add(self.acl.resync_sender());
+        notifier.add(self.credential.resync_sender());
+        notifier.add(self.quota.resync_sender());
+        notifier.add(self.throttle.resync_sender());
+        notifier
+    }
 }
 
 
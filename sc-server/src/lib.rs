@@ -8,6 +8,8 @@ mod core;
 mod init;
 mod services;
 mod k8;
+mod local;
+mod ha;
 mod error;
 
 //#[cfg(test)]
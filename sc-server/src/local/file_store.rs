@@ -0,0 +1,478 @@
+//!
+//! # Metadata File Store
+//!
+//! Persists SC metadata on local disk, one JSON file per object at `<base_dir>/<label>/<name>.json`.
+//!
+//! Objects are stored in the same layout as Kubernetes objects, so they are converted
+//! to KV objects by the same code used by the K8 dispatcher. Every write bumps
+//! the object resource version and replaces the file atomically (temp file + rename).
+//!
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use log::debug;
+use log::info;
+use log::trace;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::K8Obj;
+use k8_metadata::core::metadata::K8List;
+use k8_metadata::core::metadata::ListMetadata;
+use k8_metadata::core::metadata::ObjectMeta;
+use k8_metadata::core::metadata::validate_name;
+
+use crate::core::Spec;
+
+const FILE_EXTENSION: &'static str = "json";
+const TEMP_EXTENSION: &'static str = "tmp";
+
+pub type SharedMetadataFileStore = Arc<MetadataFileStore>;
+
+pub type StoredObj<S> = K8Obj<<S as Spec>::K8Spec, <<S as Spec>::K8Spec as K8Spec>::Status>;
+pub type StoredList<S> = K8List<<S as Spec>::K8Spec, <<S as Spec>::K8Spec as K8Spec>::Status>;
+
+/// metadata part of stored object, used to follow owner references across kinds
+#[derive(Deserialize)]
+struct StoredMeta {
+    metadata: ObjectMeta,
+}
+
+#[derive(Debug)]
+pub struct MetadataFileStore {
+    base_dir: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl MetadataFileStore {
+    pub fn open<P>(base_dir: P) -> Result<Self, IoError>
+    where
+        P: Into<PathBuf>,
+    {
+        let base_dir = base_dir.into();
+        fs::create_dir_all(&base_dir)?;
+        info!("using local metadata store at: {}", base_dir.display());
+
+        Ok(Self {
+            base_dir,
+            lock: Mutex::new(()),
+        })
+    }
+
+    pub fn new_shared<P>(base_dir: P) -> Result<SharedMetadataFileStore, IoError>
+    where
+        P: Into<PathBuf>,
+    {
+        Ok(Arc::new(Self::open(base_dir)?))
+    }
+
+    #[cfg(test)]
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    /// retrieve all objects of given kind
+    pub fn retrieve_items<S>(&self) -> Result<StoredList<S>, IoError>
+    where
+        S: Spec,
+        StoredObj<S>: DeserializeOwned,
+    {
+        let _guard = self.lock()?;
+
+        let mut items: Vec<StoredObj<S>> = vec![];
+        let mut version: u64 = 0;
+        for path in item_paths(&self.kind_dir::<S>())? {
+            let obj: StoredObj<S> = read_json(&path)?;
+            version = version.max(resource_version(&obj.metadata));
+            items.push(obj);
+        }
+
+        trace!("retrieved {} {} objects from local store", items.len(), S::LABEL);
+
+        Ok(K8List {
+            api_version: S::K8Spec::api_version(),
+            kind: S::K8Spec::kind(),
+            items,
+            metadata: ListMetadata {
+                _continue: None,
+                resource_version: version.to_string(),
+                self_link: "".to_owned(),
+            },
+        })
+    }
+
+    /// retrieve single object, none if it doesn't exist
+    #[cfg(test)]
+    pub fn retrieve_item<S>(&self, name: &str) -> Result<Option<StoredObj<S>>, IoError>
+    where
+        S: Spec,
+        StoredObj<S>: DeserializeOwned,
+    {
+        let _guard = self.lock()?;
+        self.read_item::<S>(name)
+    }
+
+    /// create object or replace its spec if exists. status of existing object is kept
+    pub fn apply<S>(&self, metadata: ObjectMeta, spec: S::K8Spec) -> Result<(), IoError>
+    where
+        S: Spec,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        let _guard = self.lock()?;
+
+        let name = metadata.name.clone();
+        let obj = match self.read_item::<S>(&name)? {
+            Some(mut existing) => {
+                existing.metadata.labels = metadata.labels;
+                existing.metadata.owner_references = metadata.owner_references;
+                existing.metadata.resource_version = next_version(&existing.metadata);
+                existing.spec = spec;
+                existing
+            }
+            None => {
+                let mut metadata = metadata;
+                metadata.uid = new_uid(&name);
+                metadata.resource_version = next_version(&ObjectMeta::default());
+                K8Obj {
+                    api_version: S::K8Spec::api_version(),
+                    kind: S::K8Spec::kind(),
+                    metadata,
+                    spec,
+                    status: None,
+                    data: None,
+                }
+            }
+        };
+
+        debug!("local store apply: {}:{} version: {}", S::LABEL, name, obj.metadata.resource_version);
+        write_json(&self.item_path::<S>(&name)?, &obj)
+    }
+
+    /// replace spec of existing object
+    pub fn update_spec<S>(&self, name: &str, spec: S::K8Spec) -> Result<(), IoError>
+    where
+        S: Spec,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        self.update::<S, _>(name, |obj| obj.spec = spec)
+    }
+
+    /// replace status of existing object
+    pub fn update_status<S>(
+        &self,
+        name: &str,
+        status: <S::K8Spec as K8Spec>::Status,
+    ) -> Result<(), IoError>
+    where
+        S: Spec,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        self.update::<S, _>(name, |obj| obj.status = Some(status))
+    }
+
+    /// delete object and all objects owned by it (similar to K8 garbage collection)
+    pub fn delete<S>(&self, name: &str) -> Result<(), IoError>
+    where
+        S: Spec,
+    {
+        let _guard = self.lock()?;
+
+        let path = self.item_path::<S>(name)?;
+        if !path.exists() {
+            return Err(not_found::<S>(name));
+        }
+
+        let stored: StoredMeta = read_json(&path)?;
+        fs::remove_file(&path)?;
+        debug!("local store delete: {}:{}", S::LABEL, name);
+
+        self.delete_owned_by(&stored.metadata.uid)
+    }
+
+    fn update<S, F>(&self, name: &str, update_fn: F) -> Result<(), IoError>
+    where
+        S: Spec,
+        StoredObj<S>: Serialize + DeserializeOwned,
+        F: FnOnce(&mut StoredObj<S>),
+    {
+        let _guard = self.lock()?;
+
+        let mut obj = match self.read_item::<S>(name)? {
+            Some(obj) => obj,
+            None => return Err(not_found::<S>(name)),
+        };
+
+        update_fn(&mut obj);
+        obj.metadata.resource_version = next_version(&obj.metadata);
+
+        debug!("local store update: {}:{} version: {}", S::LABEL, name, obj.metadata.resource_version);
+        write_json(&self.item_path::<S>(name)?, &obj)
+    }
+
+    /// remove objects whose owner reference points to uid, recursively
+    fn delete_owned_by(&self, uid: &str) -> Result<(), IoError> {
+        if uid.is_empty() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(&self.base_dir)? {
+            let kind_dir = entry?.path();
+            if !kind_dir.is_dir() {
+                continue;
+            }
+
+            for path in item_paths(&kind_dir)? {
+                let stored: StoredMeta = read_json(&path)?;
+                let owned = stored
+                    .metadata
+                    .owner_references
+                    .iter()
+                    .any(|owner| owner.uid == uid);
+                if owned {
+                    debug!("local store delete owned: {}", path.display());
+                    fs::remove_file(&path)?;
+                    self.delete_owned_by(&stored.metadata.uid)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_item<S>(&self, name: &str) -> Result<Option<StoredObj<S>>, IoError>
+    where
+        S: Spec,
+        StoredObj<S>: DeserializeOwned,
+    {
+        let path = self.item_path::<S>(name)?;
+        if path.exists() {
+            Ok(Some(read_json(&path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn kind_dir<S>(&self) -> PathBuf
+    where
+        S: Spec,
+    {
+        self.base_dir.join(S::LABEL.to_lowercase())
+    }
+
+    /// path of object file, name must be valid object name so it can't escape kind directory
+    fn item_path<S>(&self, name: &str) -> Result<PathBuf, IoError>
+    where
+        S: Spec,
+    {
+        validate_name(name).map_err(|err| {
+            IoError::new(ErrorKind::InvalidInput, format!("{}: {}", S::LABEL, err))
+        })?;
+
+        Ok(self.kind_dir::<S>()
+            .join(format!("{}.{}", name, FILE_EXTENSION)))
+    }
+
+    fn lock(&self) -> Result<MutexGuard<()>, IoError> {
+        self.lock
+            .lock()
+            .map_err(|_| IoError::new(ErrorKind::Other, "local metadata store lock poisoned"))
+    }
+}
+
+// -----------------------------------
+// Helpers
+// -----------------------------------
+
+fn not_found<S>(name: &str) -> IoError
+where
+    S: Spec,
+{
+    IoError::new(
+        ErrorKind::NotFound,
+        format!("{} '{}': not found in local store", S::LABEL, name),
+    )
+}
+
+/// json files in directory, empty if directory doesn't exist yet
+fn item_paths(dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().map(|ext| ext == FILE_EXTENSION).unwrap_or(false) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn read_json<T>(path: &Path) -> Result<T, IoError>
+where
+    T: DeserializeOwned,
+{
+    let file_str = fs::read_to_string(path)?;
+    serde_json::from_str(&file_str).map_err(|err| {
+        IoError::new(
+            ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), err),
+        )
+    })
+}
+
+/// write to temp file and rename, so readers never see partial content.
+/// directory is synced after rename so rename itself survives crash
+fn write_json<T>(path: &Path, value: &T) -> Result<(), IoError>
+where
+    T: Serialize,
+{
+    let dir = match path.parent() {
+        Some(dir) => dir,
+        None => {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("{}: no parent directory", path.display()),
+            ))
+        }
+    };
+    fs::create_dir_all(dir)?;
+
+    let bytes = serde_json::to_vec_pretty(value)
+        .map_err(|err| IoError::new(ErrorKind::InvalidData, format!("{}", err)))?;
+
+    let temp_path = path.with_extension(TEMP_EXTENSION);
+    let mut file = File::create(&temp_path)?;
+    file.write_all(&bytes)?;
+    file.sync_all()?;
+
+    fs::rename(&temp_path, path)?;
+    File::open(dir)?.sync_all()
+}
+
+fn resource_version(metadata: &ObjectMeta) -> u64 {
+    metadata.resource_version.parse::<u64>().unwrap_or(0)
+}
+
+fn next_version(metadata: &ObjectMeta) -> String {
+    (resource_version(metadata) + 1).to_string()
+}
+
+fn new_uid(name: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or(0);
+    format!("{}-{:x}", name, nanos)
+}
+
+// -----------------------------------
+//  Unit Tests
+// -----------------------------------
+
+#[cfg(test)]
+pub mod test {
+
+    use std::env::temp_dir;
+    use std::fs;
+
+    use k8_metadata::core::metadata::ObjectMeta;
+    use k8_metadata::topic::TopicSpec as K8TopicSpec;
+    use metadata::topic::TopicSpec;
+    use metadata::partition::PartitionSpec;
+    use metadata::partition::PartitionStatus;
+
+    use super::MetadataFileStore;
+
+    fn test_store(name: &str) -> MetadataFileStore {
+        let base_dir = temp_dir().join(name);
+        let _ = fs::remove_dir_all(&base_dir);
+        MetadataFileStore::open(base_dir).expect("open store")
+    }
+
+    #[test]
+    fn test_apply_and_update_bump_version() {
+        let store = test_store("sc-local-store-version");
+
+        let topic: TopicSpec = (1, 1).into();
+        store
+            .apply::<TopicSpec>(ObjectMeta::named("test"), topic.clone().into())
+            .expect("apply");
+        store
+            .apply::<TopicSpec>(ObjectMeta::named("test"), topic.into())
+            .expect("apply");
+
+        let stored = store.retrieve_item::<TopicSpec>("test").expect("read").expect("exists");
+        assert_eq!(stored.metadata.resource_version, "2");
+        assert!(!stored.metadata.uid.is_empty());
+
+        let status: PartitionStatus = PartitionStatus::default();
+        assert!(store
+            .update_status::<PartitionSpec>("test-0", status.into())
+            .is_err());
+
+        let list = store.retrieve_items::<TopicSpec>().expect("list");
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(list.metadata.resource_version, "2");
+    }
+
+    #[test]
+    fn test_delete_removes_owned_objects() {
+        let store = test_store("sc-local-store-delete");
+
+        let topic: TopicSpec = (1, 1).into();
+        store
+            .apply::<TopicSpec>(ObjectMeta::named("test"), topic.into())
+            .expect("apply");
+        let topic_meta = store
+            .retrieve_item::<TopicSpec>("test")
+            .expect("read")
+            .expect("exists")
+            .metadata;
+
+        let mut partition_meta = ObjectMeta::named("test-0");
+        partition_meta.owner_references = vec![topic_meta.make_owner_reference::<K8TopicSpec>()];
+        store
+            .apply::<PartitionSpec>(partition_meta, PartitionSpec::new(5000, vec![5000]).into())
+            .expect("apply");
+        assert_eq!(store.retrieve_items::<PartitionSpec>().expect("list").items.len(), 1);
+
+        store.delete::<TopicSpec>("test").expect("delete");
+
+        assert_eq!(store.retrieve_items::<TopicSpec>().expect("list").items.len(), 0);
+        assert_eq!(store.retrieve_items::<PartitionSpec>().expect("list").items.len(), 0);
+        assert!(store.delete::<TopicSpec>("test").is_err());
+    }
+
+    #[test]
+    fn test_reject_invalid_names() {
+        let store = test_store("sc-local-store-names");
+
+        for name in &["..", "../test", "a/b", "/tmp/test"] {
+            let topic: TopicSpec = (1, 1).into();
+            assert!(store
+                .apply::<TopicSpec>(ObjectMeta::named(*name), topic.into())
+                .is_err());
+            assert!(store.retrieve_item::<TopicSpec>(name).is_err());
+            assert!(store.delete::<TopicSpec>(name).is_err());
+        }
+
+        assert!(!store.base_dir().join("test.json").exists());
+        assert!(!store.base_dir().join("topic").exists());
+        assert_eq!(store.retrieve_items::<TopicSpec>().expect("list").items.len(), 0);
+    }
+}
@@ -0,0 +1,250 @@
+//!
+//! # Local Store Dispatcher
+//!
+//! Reloads objects from local file store, compares them against local stores and
+//! sends out Local State Changes. Reload is triggered by local update service after
+//! each write and by reconciliation timer.
+//!
+use std::time::Duration;
+use std::sync::Arc;
+use std::fmt::Debug;
+use std::fmt::Display;
+
+use futures::future::FutureExt;
+use futures::channel::mpsc::Sender;
+use futures::channel::mpsc::UnboundedSender;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::mpsc::unbounded;
+use futures::select;
+use futures::stream::StreamExt;
+use futures::sink::SinkExt;
+use log::debug;
+use log::error;
+use log::info;
+use log::trace;
+use serde::de::DeserializeOwned;
+
+use utils::actions::Actions;
+use types::defaults::SC_RECONCILIATION_INTERVAL_SEC;
+use future_helper::spawn;
+use future_helper::sleep;
+use future_aio::asyncify;
+use metadata::spu::SpuSpec;
+use metadata::topic::TopicSpec;
+use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
+
+use crate::core::common::new_channel;
+use crate::core::common::LocalStore;
+use crate::core::common::LSChange;
+use crate::core::Spec;
+use crate::core::Status;
+use crate::core::ShareLocalStores;
+use crate::core::WSChangeChannel;
+use crate::core::WSChangeDispatcher;
+use crate::core::ResyncNotifier;
+use crate::k8::k8_events_to_actions::k8_events_to_metadata_actions;
+use crate::k8::k8_events_to_actions::k8_events_to_resync_actions;
+
+use super::SharedMetadataFileStore;
+use super::StoredObj;
+
+/// Sends out Local State Changes by comparing against objects in local file store
+pub struct LocalClusterStateDispatcher<S>
+where
+    S: Spec,
+    S::Status: Debug + PartialEq,
+    S::Key: Debug,
+{
+    store: SharedMetadataFileStore,
+    metadata: Arc<LocalStore<S>>,
+    senders: Vec<Sender<Actions<LSChange<S>>>>,
+}
+
+impl<S> LocalClusterStateDispatcher<S>
+where
+    S: Spec + PartialEq + Debug,
+    S::Status: Status + PartialEq + Debug,
+    S::Key: Display + Debug + Clone + Ord,
+    S::K8Spec: Debug,
+    StoredObj<S>: DeserializeOwned,
+{
+    pub fn new(store: SharedMetadataFileStore, metadata: Arc<LocalStore<S>>) -> Self {
+        Self {
+            store,
+            metadata,
+            senders: vec![],
+        }
+    }
+
+    pub fn create_channel(&mut self) -> WSChangeChannel<S> {
+        let (sender, receiver) = new_channel();
+        self.senders.push(sender);
+        receiver
+    }
+
+    /// reload all objects from file store and send changes, file store is read on blocking pool.
+    /// with resync, all objects are sent, not only changed ones
+    async fn sync(&mut self, resync: bool) {
+        let store = self.store.clone();
+        let items = match asyncify(move || store.retrieve_items::<S>()).await {
+            Ok(items) => items,
+            Err(err) => {
+                error!("cannot retrieve {} objects from local store: {}", S::LABEL, err);
+                return;
+            }
+        };
+
+        let actions = if resync {
+            k8_events_to_resync_actions(items, &self.metadata)
+        } else {
+            k8_events_to_metadata_actions(items, &self.metadata)
+        };
+
+        match actions {
+            Ok(actions) => {
+                if actions.count() > 0 {
+                    self.send_actions(actions).await;
+                }
+                self.metadata.mark_synced();
+            }
+            Err(err) => error!("cannot convert {} objects: {}", S::LABEL, err),
+        }
+    }
+
+    async fn send_actions(&mut self, actions: Actions<LSChange<S>>) {
+        trace!(
+            "sending {} LS Changes: {} to {} senders",
+            S::LABEL,
+            actions.count(),
+            self.senders.len()
+        );
+        for sender in &mut self.senders {
+            if let Err(err) = sender.send(actions.clone()).await {
+                error!("error sending actions: {:#?}", err);
+            }
+        }
+    }
+}
+
+pub struct LocalAllChangeDispatcher {
+    spu: LocalClusterStateDispatcher<SpuSpec>,
+    topic: LocalClusterStateDispatcher<TopicSpec>,
+    partition: LocalClusterStateDispatcher<PartitionSpec>,
+    acl: LocalClusterStateDispatcher<AclSpec>,
+    credential: LocalClusterStateDispatcher<SaslCredentialSpec>,
+    quota: LocalClusterStateDispatcher<ClientQuotaSpec>,
+    throttle: LocalClusterStateDispatcher<ReplicationThrottleSpec>,
+    notifier: UnboundedSender<()>,
+    receiver: UnboundedReceiver<()>,
+    resync_sender: UnboundedSender<()>,
+    resync_receiver: UnboundedReceiver<()>,
+}
+
+impl LocalAllChangeDispatcher {
+    pub fn new(store: SharedMetadataFileStore, local_stores: ShareLocalStores) -> Self {
+        let (notifier, receiver) = unbounded();
+        let (resync_sender, resync_receiver) = unbounded();
+
+        Self {
+            spu: LocalClusterStateDispatcher::new(store.clone(), local_stores.spus().clone()),
+            topic: LocalClusterStateDispatcher::new(store.clone(), local_stores.topics().clone()),
+            partition: LocalClusterStateDispatcher::new(store.clone(), local_stores.partitions().clone()),
+            acl: LocalClusterStateDispatcher::new(store.clone(), local_stores.acls().clone()),
+            credential: LocalClusterStateDispatcher::new(store.clone(), local_stores.credentials().clone()),
+            quota: LocalClusterStateDispatcher::new(store.clone(), local_stores.quotas().clone()),
+            throttle: LocalClusterStateDispatcher::new(store, local_stores.replication_throttles().clone()),
+            notifier,
+            receiver,
+            resync_sender,
+            resync_receiver,
+        }
+    }
+
+    /// sender used by update service to trigger reload
+    pub fn notifier(&self) -> UnboundedSender<()> {
+        self.notifier.clone()
+    }
+
+    pub fn run(self) {
+        spawn(self.dispatch_loop());
+    }
+
+    async fn dispatch_loop(mut self) {
+        info!("starting local store dispatcher loop");
+        let mut resync = false;
+        loop {
+            self.sync_all(resync).await;
+            resync = false;
+
+            select! {
+                _ = (sleep(Duration::from_secs(SC_RECONCILIATION_INTERVAL_SEC))).fuse() => {
+                    debug!("timer fired - reload local store");
+                },
+                msg = self.receiver.next() => {
+                    if msg.is_none() {
+                        info!("local store notifier is removed. end");
+                        break;
+                    }
+                    // coalesce pending notifications into single reload
+                    while let Ok(Some(_)) = self.receiver.try_next() {}
+                },
+                msg = self.resync_receiver.next() => {
+                    if msg.is_some() {
+                        debug!("resync requested - reload all objects");
+                        resync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn sync_all(&mut self, resync: bool) {
+        self.spu.sync(resync).await;
+        self.topic.sync(resync).await;
+        self.partition.sync(resync).await;
+        self.acl.sync(resync).await;
+        self.credential.sync(resync).await;
+        self.quota.sync(resync).await;
+        self.throttle.sync(resync).await;
+    }
+}
+
+impl WSChangeDispatcher for LocalAllChangeDispatcher {
+    fn create_spu_channel(&mut self) -> WSChangeChannel<SpuSpec> {
+        self.spu.create_channel()
+    }
+
+    fn create_topic_channel(&mut self) -> WSChangeChannel<TopicSpec> {
+        self.topic.create_channel()
+    }
+
+    fn create_partition_channel(&mut self) -> WSChangeChannel<PartitionSpec> {
+        self.partition.create_channel()
+    }
+
+    fn create_acl_channel(&mut self) -> WSChangeChannel<AclSpec> {
+        self.acl.create_channel()
+    }
+
+    fn create_credential_channel(&mut self) -> WSChangeChannel<SaslCredentialSpec> {
+        self.credential.create_channel()
+    }
+
+    fn create_quota_channel(&mut self) -> WSChangeChannel<ClientQuotaSpec> {
+        self.quota.create_channel()
+    }
+
+    fn create_throttle_channel(&mut self) -> WSChangeChannel<ReplicationThrottleSpec> {
+        self.throttle.create_channel()
+    }
+
+    fn resync_notifier(&self) -> ResyncNotifier {
+        let mut notifier = ResyncNotifier::default();
+        notifier.add(self.resync_sender.clone());
+        notifier
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! # Update Local File Store
+//!
+//! World store update service backed by local file store. After each write,
+//! local dispatcher is notified to reload changes into local stores.
+//! File store is blocking, writes are run on blocking pool.
+//!
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use futures::channel::mpsc::UnboundedSender;
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use log::debug;
+use log::error;
+use log::trace;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use future_aio::asyncify;
+use k8_metadata::core::Spec as K8Spec;
+use k8_metadata::core::metadata::ObjectMeta;
+use metadata::topic::TopicSpec;
+use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::AuthTokenSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
+use metadata::spu::SpuSpec;
+
+use crate::ScServerError;
+use crate::core::Spec;
+use crate::core::common::KVObject;
+use crate::core::common::WSAction;
+use crate::core::WSUpdateService;
+
+use super::SharedMetadataFileStore;
+use super::StoredObj;
+
+#[derive(Clone)]
+pub struct LocalWSUpdateService {
+    store: SharedMetadataFileStore,
+    notifier: UnboundedSender<()>,
+}
+
+impl LocalWSUpdateService {
+    pub fn new(store: SharedMetadataFileStore, notifier: UnboundedSender<()>) -> Self {
+        Self { store, notifier }
+    }
+
+    /// add object, metadata is derived from item or parent context (same as K8)
+    pub async fn add<S>(&self, value: KVObject<S>) -> Result<(), ScServerError>
+    where
+        S: Spec,
+        S::Key: Display,
+        S::K8Spec: From<S>,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        debug!("Local Add: {}:{}", S::LABEL, value.key());
+
+        let (key, spec, kv_ctx) = value.parts();
+        let metadata = if let Some(item_ctx) = kv_ctx.item_ctx {
+            item_ctx
+        } else if let Some(ref parent_ctx) = kv_ctx.parent_ctx {
+            ObjectMeta {
+                name: key.to_string(),
+                namespace: parent_ctx.namespace.clone(),
+                owner_references: vec![parent_ctx
+                    .make_owner_reference::<<<S as Spec>::Owner as Spec>::K8Spec>()],
+                ..Default::default()
+            }
+        } else {
+            return Err(IoError::new(
+                ErrorKind::Other,
+                format!("{} add failed - no item or context {}", S::LABEL, key),
+            )
+            .into());
+        };
+
+        let store = self.store.clone();
+        let k8_spec: S::K8Spec = spec.into();
+        asyncify(move || store.apply::<S>(metadata, k8_spec)).await?;
+        self.notify();
+        Ok(())
+    }
+
+    /// delete object and objects owned by it
+    pub async fn delete<S>(&self, key: &S::Key) -> Result<(), ScServerError>
+    where
+        S: Spec,
+        S::Key: Display,
+    {
+        debug!("Local Delete: {}:{}", S::LABEL, key);
+
+        let store = self.store.clone();
+        let name = key.to_string();
+        asyncify(move || store.delete::<S>(&name)).await?;
+        self.notify();
+        Ok(())
+    }
+
+    async fn inner_process<S>(&self, action: WSAction<S>) -> Result<(), ScServerError>
+    where
+        S: Spec + Debug,
+        S::Key: Display + Debug,
+        S::Status: Debug + PartialEq + Into<<<S as Spec>::K8Spec as K8Spec>::Status>,
+        S::K8Spec: From<S>,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        match action {
+            WSAction::Add(value) => self.add(value).await,
+            WSAction::UpdateStatus(value) => {
+                trace!("Local Update Status: {}:{}", S::LABEL, value.key());
+                let (name, status) = (value.key().to_string(), value.status);
+                let store = self.store.clone();
+                asyncify(move || store.update_status::<S>(&name, status.into())).await?;
+                self.notify();
+                Ok(())
+            }
+            WSAction::UpdateSpec(value) => {
+                trace!("Local Update Spec: {}:{}", S::LABEL, value.key());
+                let (name, spec) = (value.key().to_string(), value.spec);
+                let store = self.store.clone();
+                asyncify(move || store.update_spec::<S>(&name, spec.into())).await?;
+                self.notify();
+                Ok(())
+            }
+            WSAction::Delete(key) => self.delete::<S>(&key).await,
+        }
+    }
+
+    /// wake up local dispatcher
+    fn notify(&self) {
+        if let Err(err) = self.notifier.unbounded_send(()) {
+            error!("error notifying local dispatcher: {}", err);
+        }
+    }
+}
+
+impl WSUpdateService for LocalWSUpdateService {
+    type ResponseFuture = BoxFuture<'static, Result<(), ScServerError>>;
+
+    fn update_spu(&self, ws_actions: WSAction<SpuSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_topic(&self, ws_actions: WSAction<TopicSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_partition(&self, ws_actions: WSAction<PartitionSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_acl(&self, ws_actions: WSAction<AclSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_credential(&self, ws_actions: WSAction<SaslCredentialSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_quota(&self, ws_actions: WSAction<ClientQuotaSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_auth_token(&self, ws_actions: WSAction<AuthTokenSpec>) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+
+    fn update_replication_throttle(
+        &self,
+        ws_actions: WSAction<ReplicationThrottleSpec>,
+    ) -> Self::ResponseFuture {
+        let service = self.clone();
+        async move { service.inner_process(ws_actions).await }.boxed()
+    }
+}
@@ -0,0 +1,14 @@
+//!
+//! # Local Metadata Store
+//!
+//! Runs SC without Kubernetes, metadata is persisted in local file store.
+//!
+mod file_store;
+mod local_ws_service;
+mod local_dispatcher;
+
+pub use file_store::MetadataFileStore;
+pub use file_store::SharedMetadataFileStore;
+pub use file_store::StoredObj;
+pub use local_ws_service::LocalWSUpdateService;
+pub use local_dispatcher::LocalAllChangeDispatcher;
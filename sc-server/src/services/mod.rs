@@ -5,5 +5,6 @@ mod private_api;
 
 pub use public_api::create_public_server;
 pub use public_api::PubliApiServer;
+pub use public_api::PublicWSService;
 pub use private_api::create_internal_server;
 pub use private_api::InternalApiServer;
@@ -8,6 +8,7 @@ use futures::sink::SinkExt;
 use types::SpuId;
 use kf_socket::KfSink;
 use internal_api::UpdateLrsRequest;
+use internal_api::ReplicaRemovedRequest;
 
 use crate::core::ShareLocalStores;
 use crate::conn_manager::SharedConnManager;
@@ -20,7 +21,8 @@ pub struct InternalContext
     pub local_stores: ShareLocalStores,
     conn_mgr: SharedConnManager,
     conn_status_sender: Sender<SpuConnectionStatusChange>,
-    lrs_sender: Sender<UpdateLrsRequest>
+    lrs_sender: Sender<UpdateLrsRequest>,
+    replica_removed_sender: Sender<ReplicaRemovedRequest>
 }
 
 
@@ -30,13 +32,15 @@ impl InternalContext {
         local_stores: ShareLocalStores,
         conn_mgr: SharedConnManager,
         conn_status_sender: Sender<SpuConnectionStatusChange>,
-        lrs_sender: Sender<UpdateLrsRequest>
+        lrs_sender: Sender<UpdateLrsRequest>,
+        replica_removed_sender: Sender<ReplicaRemovedRequest>
     ) -> Self {
             Self {
                 local_stores,
                 conn_mgr,
                 conn_status_sender,
-                lrs_sender
+                lrs_sender,
+                replica_removed_sender
             }
         }
 
@@ -56,6 +60,13 @@ impl InternalContext {
         }
     }
 
+    pub async fn send_replica_removed_to_sender(&self, removed: ReplicaRemovedRequest) {
+        let mut sender = self.replica_removed_sender.clone();
+        if let Err(err) = sender.send(removed).await {
+            error!("error sending replica removed to sender: {:#?}",err);
+        }
+    }
+
     /// Register new sink 
     /// true if successfully register
     pub async fn register_sink(&self, spu_id: SpuId, sink: KfSink, param: ConnParams)  {
@@ -12,6 +12,7 @@ use private_server::ScInternalService;
 use internal_api::InternalScKey;
 use internal_api::InternalScRequest;
 use internal_api::UpdateLrsRequest;
+use internal_api::ReplicaRemovedRequest;
 use kf_service::KfApiServer;
 
 use crate::core::ShareLocalStores;
@@ -28,6 +29,7 @@ pub fn create_internal_server(
     conn_mgr: SharedConnManager,
     conn_status_sender: Sender<SpuConnectionStatusChange>,
      lrs_sender: Sender<UpdateLrsRequest>,
+     replica_removed_sender: Sender<ReplicaRemovedRequest>,
 ) -> InternalApiServer
 {
     let addr = local_stores.config().private_endpoint.addr.clone();
@@ -35,7 +37,8 @@ pub fn create_internal_server(
         local_stores,
         conn_mgr,
         conn_status_sender,
-        lrs_sender
+        lrs_sender,
+        replica_removed_sender
     );
     info!("SC: starting internal services at: {}", addr);
 
@@ -76,6 +76,10 @@ impl ScInternalService
                     debug!("received lrs request: {}",msg);
                     context.send_lrs_to_sender(msg.request).await;
                 },
+                InternalScRequest::ReplicaRemovedRequest(msg) => {
+                    debug!("received replica removed request: {}",msg.request);
+                    context.send_replica_removed_to_sender(msg.request).await;
+                },
                 InternalScRequest::RegisterSpuRequest(_request) => {
                     error!("registration req only valid during initialization");
                     return Err(KfSocketError::IoError(IoError::new(ErrorKind::InvalidData,"register spu request is only valid beggining")))
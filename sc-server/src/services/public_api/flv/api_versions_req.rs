@@ -4,6 +4,14 @@ use log::debug;
 use kf_protocol::api::RequestMessage;
 use kf_protocol::api::ResponseMessage;
 use kf_protocol::api::Request;
+use kf_protocol::message::config::KfDescribeConfigsRequest;
+use kf_protocol::message::config::KfAlterConfigsRequest;
+use kf_protocol::message::acl::KfCreateAclsRequest;
+use kf_protocol::message::acl::KfDescribeAclsRequest;
+use kf_protocol::message::acl::KfDeleteAclsRequest;
+use kf_protocol::message::sasl::KfSaslHandshakeRequest;
+use kf_protocol::message::sasl::KfSaslAuthenticateRequest;
+use kf_service::sasl::SASL_HANDSHAKE_MIN_VERSION;
 
 use sc_api::versions::ApiVersionKey;
 use sc_api::versions::{ApiVersionsRequest, ApiVersionsResponse};
@@ -12,7 +20,9 @@ use sc_api::topic::FlvCreateTopicsRequest;
 use sc_api::topic::FlvDeleteTopicsRequest;
 use sc_api::topic::FlvFetchTopicsRequest;
 use sc_api::topic::FlvTopicCompositionRequest;
+use sc_api::topic::FlvRebalanceLeadersRequest;
 use sc_api::spu::FlvFetchSpusRequest;
+use sc_api::watch::FlvWatchMetadataRequest;
 
 pub async fn handle_api_versions_request(
     request: RequestMessage<ApiVersionsRequest>,
@@ -41,6 +51,53 @@ pub async fn handle_api_versions_request(
         FlvTopicCompositionRequest::DEFAULT_API_VERSION,
     ));
 
+    response.api_keys.push(make_version_key(
+        ScApiKey::FlvRebalanceLeaders,
+        FlvRebalanceLeadersRequest::DEFAULT_API_VERSION,
+        FlvRebalanceLeadersRequest::DEFAULT_API_VERSION,
+    ));
+
+    // topic config versions
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfDescribeConfigs,
+        KfDescribeConfigsRequest::MIN_API_VERSION,
+        KfDescribeConfigsRequest::MAX_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfAlterConfigs,
+        KfAlterConfigsRequest::MIN_API_VERSION,
+        KfAlterConfigsRequest::MAX_API_VERSION,
+    ));
+
+    // acl versions
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfCreateAcls,
+        KfCreateAclsRequest::MIN_API_VERSION,
+        KfCreateAclsRequest::MAX_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfDescribeAcls,
+        KfDescribeAclsRequest::MIN_API_VERSION,
+        KfDescribeAclsRequest::MAX_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfDeleteAcls,
+        KfDeleteAclsRequest::MIN_API_VERSION,
+        KfDeleteAclsRequest::MAX_API_VERSION,
+    ));
+
+    // sasl versions
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfSaslHandshake,
+        SASL_HANDSHAKE_MIN_VERSION,
+        KfSaslHandshakeRequest::MAX_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        ScApiKey::KfSaslAuthenticate,
+        KfSaslAuthenticateRequest::MIN_API_VERSION,
+        KfSaslAuthenticateRequest::MAX_API_VERSION,
+    ));
+
     // spus versions
     response.api_keys.push(make_version_key(
         ScApiKey::FlvFetchSpus,
@@ -48,6 +105,13 @@ pub async fn handle_api_versions_request(
         FlvFetchSpusRequest::DEFAULT_API_VERSION,
     ));
 
+    // metadata versions
+    response.api_keys.push(make_version_key(
+        ScApiKey::FlvWatchMetadata,
+        FlvWatchMetadataRequest::DEFAULT_API_VERSION,
+        FlvWatchMetadataRequest::DEFAULT_API_VERSION,
+    ));
+
     debug!("flv api versions response");
 
     Ok(request.new_response(response))
@@ -11,6 +11,8 @@ use kf_protocol::api::FlvErrorCode;
 
 use k8_metadata::core::metadata::ObjectMeta;
 use metadata::spu::{SpuSpec, Endpoint, SpuType};
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use sc_api::FlvResponseMessage;
 use sc_api::spu::{FlvCreateCustomSpusRequest, FlvCreateCustomSpusResponse};
@@ -27,11 +29,16 @@ use super::PublicContext;
 pub async fn handle_create_custom_spus_request(
     request: RequestMessage<FlvCreateCustomSpusRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvCreateCustomSpusResponse>, Error> {
     let (header, custom_spu_req) = request.get_header_request();
 
     let mut response = FlvCreateCustomSpusResponse::default();
     let mut results: Vec<FlvResponseMessage> = vec![];
+    let authorized = ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster(identity, &AclOperation::Alter);
 
     // process create custom spus requests in sequence
     for custom_spu_req in custom_spu_req.custom_spus {
@@ -40,6 +47,15 @@ pub async fn handle_create_custom_spus_request(
             custom_spu_req.name, custom_spu_req.id
         );
 
+        if !authorized {
+            results.push(FlvResponseMessage::new(
+                custom_spu_req.name,
+                FlvErrorCode::ClusterAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
         // validate custom-spu request
         if let Err(err_msg) = validate_custom_spu_request(&custom_spu_req, ctx.metadata()) {
             results.push(err_msg);
@@ -114,5 +130,5 @@ async fn create_custom_spu(
     let kv_ctx = KvContext::default().with_ctx(meta);
     let custom_spu_kv = SpuKV::new_with_context(spu_req.name.clone(), spu_spec, kv_ctx);
 
-    ctx.k8_ws().add(custom_spu_kv).await
+    ctx.ws_service().add(custom_spu_kv).await
 }
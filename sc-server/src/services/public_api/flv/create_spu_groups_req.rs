@@ -19,6 +19,8 @@ use sc_api::spu::{FlvCreateSpuGroupsRequest, FlvCreateSpuGroupsResponse};
 use sc_api::spu::FlvCreateSpuGroupRequest;
 use sc_api::spu::FlvEnvVar;
 use sc_api::spu::FlvStorageConfig;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use super::PublicContext;
 
@@ -26,16 +28,30 @@ use super::PublicContext;
 pub async fn handle_create_spu_groups_request(
     request: RequestMessage<FlvCreateSpuGroupsRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvCreateSpuGroupsResponse>, Error> {
     let (header, spu_group_req) = request.get_header_request();
 
     let mut results: Vec<FlvResponseMessage> = vec![];
+    let authorized = ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster(identity, &AclOperation::Alter);
 
     // process create spu groups requests in sequence
     for spu_group in spu_group_req.spu_groups {
         
         debug!("api request: create spu-group '{}'", spu_group.name);
 
+        if !authorized {
+            results.push(FlvResponseMessage::new(
+                spu_group.name,
+                FlvErrorCode::ClusterAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
       
         let result = process_custom_spu_request(ctx, spu_group).await;
         results.push(result);
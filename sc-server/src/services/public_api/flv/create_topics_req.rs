@@ -15,11 +15,15 @@ use kf_protocol::api::{RequestMessage, ResponseMessage};
 use kf_protocol::api::FlvErrorCode;
 
 use k8_metadata::core::metadata::ObjectMeta;
+use k8_metadata::core::metadata::validate_name;
 
 use sc_api::FlvResponseMessage;
 use sc_api::topic::{FlvCreateTopicsRequest, FlvCreateTopicsResponse};
 
 use metadata::topic::TopicSpec;
+use metadata::topic::validate_topic_config;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use crate::ScServerError;
 use crate::core::LocalStores;
@@ -32,6 +36,7 @@ use super::PublicContext;
 pub async fn handle_create_topics_request(
     request: RequestMessage<FlvCreateTopicsRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvCreateTopicsResponse>, Error> {
     let (header, topic_request) = request.get_header_request();
 
@@ -45,6 +50,15 @@ pub async fn handle_create_topics_request(
         let topic_spec = topic_req.topic;
         debug!("api request: create topic '{}'", name);
 
+        if !ctx.metadata().authorizer().authorize_topic(identity, &name, &AclOperation::Create) {
+            topic_results.push(FlvResponseMessage::new(
+                name,
+                FlvErrorCode::TopicAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
         // validate topic request
         if let Err(validation_message) = validate_topic_request(&name, &topic_spec, ctx.metadata())
         {
@@ -75,6 +89,15 @@ fn validate_topic_request(
 ) -> Result<(), FlvResponseMessage> {
     debug!("validating topic: {}", name);
 
+    // topic name is used as object name in metadata stores
+    if let Err(err) = validate_name(name) {
+        return Err(FlvResponseMessage::new(
+            name.to_string(),
+            FlvErrorCode::TopicError,
+            Some(err),
+        ));
+    }
+
     // check if topic already exists
     if metadata.topics().contains_key(name) {
         return Err(FlvResponseMessage::new(
@@ -84,6 +107,15 @@ fn validate_topic_request(
         ));
     }
 
+    // check topic config
+    if let Err(err) = validate_topic_config(topic_spec.config()) {
+        return Err(FlvResponseMessage::new(
+            name.to_string(),
+            FlvErrorCode::TopicError,
+            Some(err.to_string()),
+        ));
+    }
+
     // create temporary topic status to return validation result
     let topic_kv = TopicKV::with_spec(name.to_owned(), topic_spec.clone());
     match topic_spec {
@@ -160,5 +192,5 @@ async fn create_topic(
     let kv_ctx = KvContext::default().with_ctx(meta);
     let topic_kv = TopicKV::new_with_context(name, topic, kv_ctx);
 
-    ctx.k8_ws().add(topic_kv).await
+    ctx.ws_service().add(topic_kv).await
 }
@@ -1,8 +1,8 @@
 //!
 //! # Delete Custom Spus Request
 //!
-//! Lookup custom-spu in local metadata, grab its K8 context
-//! and send K8 a delete message.
+//! Lookup custom-spu in local metadata, grab its KV context
+//! and send metadata store a delete message.
 //!
 use log::{debug, trace};
 use std::io::Error;
@@ -12,7 +12,8 @@ use kf_protocol::api::FlvErrorCode;
 use sc_api::{FlvResponseMessage};
 use sc_api::spu::{FlvDeleteCustomSpusRequest, FlvDeleteCustomSpusResponse};
 use sc_api::spu::FlvCustomSpu;
-use k8_metadata::spu::SpuSpec as K8SpuSpec;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use crate::core::spus::SpuKV;
 use super::PublicContext;
@@ -21,12 +22,30 @@ use super::PublicContext;
 pub async fn handle_delete_custom_spus_request(
     request: RequestMessage<FlvDeleteCustomSpusRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvDeleteCustomSpusResponse>, Error> {
     let mut response = FlvDeleteCustomSpusResponse::default();
     let mut results: Vec<FlvResponseMessage> = vec![];
+    let authorized = ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster(identity, &AclOperation::Alter);
 
     // look-up custom spus based on their names or ids
     for custom_spu in &request.request.custom_spus {
+        if !authorized {
+            let name = match custom_spu {
+                FlvCustomSpu::Name(spu_name) => spu_name.clone(),
+                FlvCustomSpu::Id(spu_id) => spu_id.to_string(),
+            };
+            results.push(FlvResponseMessage::new(
+                name,
+                FlvErrorCode::ClusterAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
         let result = match custom_spu {
             FlvCustomSpu::Name(spu_name) => {
                 debug!("api request: delete custom-spu with name '{}'", spu_name);
@@ -88,20 +107,16 @@ pub async fn delete_custom_spu(
     }
 
     // have have KV context
-    let item_ctx = match &spu.kv_ctx().item_ctx {
-        Some(ctx) => ctx,
-        None => {
-            return Ok(FlvResponseMessage::new(
-                spu_name.clone(),
-                FlvErrorCode::SpuError,
-                Some("missing Kv context".to_owned()),
-            ))
-        }
-    };
+    if spu.kv_ctx().item_ctx.is_none() {
+        return Ok(FlvResponseMessage::new(
+            spu_name.clone(),
+            FlvErrorCode::SpuError,
+            Some("missing Kv context".to_owned()),
+        ));
+    }
 
     // delete custom spec and return result
-    let item = item_ctx.as_input();
-    match ctx.k8_client().delete_item::<K8SpuSpec,_>(&item).await {
+    match ctx.ws_service().delete(spu).await {
         Ok(_) => Ok(FlvResponseMessage::new_ok(spu_name.clone())),
         Err(err) => Ok(FlvResponseMessage::new(
             spu_name.clone(),
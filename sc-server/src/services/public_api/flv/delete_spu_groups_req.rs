@@ -12,6 +12,8 @@ use kf_protocol::api::FlvErrorCode;
 use kf_protocol::api::{RequestMessage, ResponseMessage};
 use sc_api::{FlvResponseMessage};
 use sc_api::spu::{FlvDeleteSpuGroupsRequest, FlvDeleteSpuGroupsResponse};
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 
 use super::PublicContext;
@@ -20,6 +22,7 @@ use super::PublicContext;
 pub async fn handle_delete_spu_groups_request(
     request: RequestMessage<FlvDeleteSpuGroupsRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvDeleteSpuGroupsResponse>, Error> {
     let mut response = FlvDeleteSpuGroupsResponse::default();
     let mut results: Vec<FlvResponseMessage> = vec![];
@@ -33,6 +36,15 @@ pub async fn handle_delete_spu_groups_request(
     for spg_name in &request.request.spu_groups {
         debug!("api request: delete spu group '{}'", spg_name);
 
+        if !ctx.metadata().authorizer().authorize_cluster(identity, &AclOperation::Alter) {
+            results.push(FlvResponseMessage::new(
+                spg_name.clone(),
+                FlvErrorCode::ClusterAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
         let result = match ctx.delete::<SpuGroupSpec>(spg_name).await {
             Ok(_) =>  FlvResponseMessage::new_ok(spg_name.clone()),
             Err(err) => {
@@ -1,8 +1,10 @@
 //!
 //! # Delete Topic Request
 //!
-//! Delete topic request handler. Lookup topic in local metadata, grab its K8 context
-//! and send K8 a delete message.
+//! Delete topic request handler. Lookup topic in local metadata, grab its KV context
+//! and move topic to deleting state. Topic is removed from metadata store by topic controller
+//! once all SPUs confirmed removal of their replicas.
+//! Topics without replicas are deleted right away.
 //!
 use log::{debug, trace};
 use std::io::Error;
@@ -11,7 +13,12 @@ use kf_protocol::api::{RequestMessage, ResponseMessage};
 use kf_protocol::api::FlvErrorCode;
 use sc_api::{FlvResponseMessage};
 use sc_api::topic::{FlvDeleteTopicsRequest, FlvDeleteTopicsResponse};
-use k8_metadata::topic::TopicSpec as K8TopicSpec;
+use metadata::topic::TopicStatus;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
 
 use super::PublicContext;
 
@@ -19,6 +26,7 @@ use super::PublicContext;
 pub async fn handle_delete_topics_request(
     request: RequestMessage<FlvDeleteTopicsRequest>,
     ctx: &PublicContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvDeleteTopicsResponse>, Error> {
     let mut response = FlvDeleteTopicsResponse::default();
     let mut topic_results: Vec<FlvResponseMessage> = vec![];
@@ -27,11 +35,34 @@ pub async fn handle_delete_topics_request(
     for topic_name in &request.request.topics {
         debug!("api request: delete topic '{}'", topic_name);
 
+        if !ctx.metadata().authorizer().authorize_topic(identity, topic_name, &AclOperation::Delete) {
+            topic_results.push(FlvResponseMessage::new(
+                topic_name.clone(),
+                FlvErrorCode::TopicAuthorizationFailed,
+                None,
+            ));
+            continue;
+        }
+
         // topic name must exist
         let result = if let Some(topic) = ctx.metadata().topics().topic(topic_name) {
-            if let Some(item_ctx) = &topic.kv_ctx().item_ctx {
-                let item = item_ctx.as_input();
-                if let Err(err) = ctx.k8_client().delete_item::<K8TopicSpec,_>(&item).await {
+            if topic.kv_ctx().item_ctx.is_some() {
+                let delete_result = if topic.status.is_resolution_deleting() {
+                    debug!("topic '{}' is already being deleted", topic_name);
+                    Ok(())
+                } else if topic.status.is_replica_map_empty() {
+                    ctx.ws_service().delete(&topic).await
+                } else {
+                    let mut deleting_topic = topic.clone();
+                    deleting_topic
+                        .status
+                        .set_next_resolution(TopicStatus::next_resolution_deleting());
+                    ctx.ws_service()
+                        .update_topic(WSAction::UpdateStatus(deleting_topic))
+                        .await
+                };
+
+                if let Err(err) = delete_result {
                     FlvResponseMessage::new(
                         topic_name.clone(),
                         FlvErrorCode::TopicError,
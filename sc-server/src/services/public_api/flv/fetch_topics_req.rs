@@ -7,6 +7,8 @@ use sc_api::topic::{FlvFetchTopicsRequest, FlvFetchTopicsResponse};
 use sc_api::topic::FlvFetchTopicResponse;
 use sc_api::topic::FlvPartitionReplica;
 use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use crate::core::ShareLocalStores;
 use crate::core::topics::TopicLocalStore;
@@ -15,16 +17,27 @@ use crate::core::partitions::PartitionLocalStore;
 pub async fn handle_fetch_topics_request(
     request: RequestMessage<FlvFetchTopicsRequest>,
     metadata: ShareLocalStores,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvFetchTopicsResponse>, Error> {
-    // is names is provided, return list, otherwise generate all names
+    // is names is provided, return list, otherwise generate all names the principal may describe
     let topic_names = match &request.request.names {
         Some(topic_names) => topic_names.clone(),
-        None => metadata.topics().all_keys(),
+        None => metadata
+            .topics()
+            .all_keys()
+            .into_iter()
+            .filter(|name| metadata.authorizer().authorize_topic(identity, name, &AclOperation::Describe))
+            .collect(),
     };
 
     // encode topics
     let mut topics = vec![];
     for topic_name in &topic_names {
+        if !metadata.authorizer().authorize_topic(identity, topic_name, &AclOperation::Describe) {
+            topics.push(FlvFetchTopicResponse::new_not_authorized(topic_name.clone()));
+            continue;
+        }
+
         let mut topic_response =
             topic_store_metadata_to_topic_response(metadata.topics(), topic_name);
 
@@ -86,6 +99,7 @@ pub fn partition_metadata_to_replica_response(
                 leader: partition.spec.leader,
                 replicas: partition.spec.replicas.clone(),
                 live_replicas: partition.status.live_replicas().clone(),
+                size: partition.status.leader.size,
             })
         }
     }
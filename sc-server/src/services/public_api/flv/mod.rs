@@ -13,4 +13,8 @@ pub mod delete_topics_req;
 pub mod fetch_topics_req;
 pub mod topic_composition_req;
 
+pub mod rebalance_leaders_req;
+
+pub mod watch_metadata_req;
+
 use super::PublicContext;
@@ -0,0 +1,76 @@
+//!
+//! # Rebalance Leaders Request
+//!
+//! Move partition leaders back to preferred replica. Preferred replica must be
+//! online and in sync with current leader. For dry run, planned moves are returned
+//! without updating KV store.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+use kf_protocol::api::FlvErrorCode;
+use sc_api::topic::{FlvRebalanceLeadersRequest, FlvRebalanceLeadersResponse};
+use sc_api::topic::FlvLeaderMove;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
+
+use super::PublicContext;
+
+/// Handler for rebalance leaders request
+pub async fn handle_rebalance_leaders_request(
+    request: RequestMessage<FlvRebalanceLeadersRequest>,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<FlvRebalanceLeadersResponse>, Error> {
+    let mut response = FlvRebalanceLeadersResponse::default();
+    let req = &request.request;
+
+    debug!(
+        "api request: rebalance leaders topics: {:?}, dry run: {}",
+        req.topics, req.dry_run
+    );
+
+    if !ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster(identity, &AclOperation::Alter)
+    {
+        response.error_code = FlvErrorCode::ClusterAuthorizationFailed;
+        return Ok(request.new_response(response));
+    }
+
+    let online = ctx.metadata().spus().online_status();
+    let moves = ctx
+        .metadata()
+        .partitions()
+        .preferred_leader_moves(&online, &req.topics);
+
+    for (partition, from_leader) in moves.into_iter() {
+        response.moves.push(FlvLeaderMove {
+            topic: partition.key.topic.clone(),
+            partition_idx: partition.key.partition,
+            from_leader,
+            to_leader: partition.spec.leader,
+        });
+
+        if !req.dry_run {
+            if let Err(err) = ctx
+                .ws_service()
+                .update_partition(WSAction::UpdateSpec(partition))
+                .await
+            {
+                response.error_code = FlvErrorCode::PartitionError;
+                response.error_message = Some(err.to_string());
+                break;
+            }
+        }
+    }
+
+    trace!("flv rebalance leaders resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
@@ -8,12 +8,15 @@ use sc_api::topic::FetchTopicReponse;
 use sc_api::topic::FetchPartitionResponse;
 use sc_api::topic::FetchSpuReponse;
 use kf_protocol::api::FlvErrorCode;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
 
 use crate::core::ShareLocalStores;
 
 pub async fn handle_topic_composition_request(
     request: RequestMessage<FlvTopicCompositionRequest>,
     metadata: ShareLocalStores,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<FlvTopicCompositionResponse>, Error> {
     let mut topic_comp_resp = FlvTopicCompositionResponse::default();
     let mut spu_ids = vec![];
@@ -29,6 +32,12 @@ pub async fn handle_topic_composition_request(
         let mut topic = FetchTopicReponse::default();
         topic.name = topic_name.clone();
 
+        if !metadata.authorizer().authorize_topic(identity, topic_name, &AclOperation::Describe) {
+            topic.error_code = FlvErrorCode::TopicAuthorizationFailed;
+            topics.push(topic);
+            continue;
+        }
+
         // if topic is found encode it, otherwise error
         if let Some(topic_metadata) = metadata.topics().topic(topic_name) {
             // check topic resolution, return error if not OK
@@ -0,0 +1,205 @@
+//!
+//! # Watch Metadata Request
+//!
+//! Subscribe client to topic, partition and SPU changes. First response is either a
+//! snapshot or the changes missed since the requested epoch; subsequent changes are
+//! pushed as responses with the same correlation id until the client disconnects.
+//!
+//! Snapshot is read after subscribing, so a change may appear in the snapshot and again
+//! as an event. Clients apply Add/Mod as upserts, which makes replays harmless.
+//!
+//! Topics and partitions are only sent if client is authorized to describe the topic.
+//! ACLs are checked for every change, so bindings updated during the watch apply to
+//! changes sent after the update.
+//!
+//! Watch may wait for changes longer than idle timeout of connection, so connection is
+//! exempted from it once watch starts.
+//!
+use log::{debug, trace};
+
+use futures::select;
+use futures::stream::Stream;
+use futures::stream::StreamExt;
+use futures::future::FutureExt;
+
+use kf_protocol::api::RequestMessage;
+use kf_socket::KfSink;
+use kf_socket::IdleTimeoutHandle;
+use kf_socket::KfSocketError;
+use sc_api::PublicRequest;
+use sc_api::watch::{FlvWatchMetadataRequest, FlvWatchMetadataResponse};
+use sc_api::watch::FlvChangeType;
+use sc_api::watch::FlvTopicChange;
+use sc_api::watch::FlvPartitionChange;
+use sc_api::watch::FlvSpuChange;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
+use crate::core::LocalStores;
+use crate::core::watch::WatchChange;
+use crate::core::watch::WatchEvent;
+use crate::core::watch::WatchStart;
+
+use super::PublicContext;
+
+/// Handler for watch metadata request, runs until client or subscription terminates
+pub async fn handle_watch_metadata_request<S>(
+    request: RequestMessage<FlvWatchMetadataRequest>,
+    ctx: &PublicContext,
+    sink: &mut KfSink,
+    api_stream: &mut S,
+    idle_timeout: &IdleTimeoutHandle,
+    identity: &AclIdentity,
+) -> Result<(), KfSocketError>
+where
+    S: Stream<Item = Result<PublicRequest, KfSocketError>> + Unpin,
+{
+    let version = request.header.api_version();
+    let req = &request.request;
+
+    debug!(
+        "api request: watch metadata topics: {}, partitions: {}, spus: {}, generation: {}, epoch: {}",
+        req.topics, req.partitions, req.spus, req.generation, req.epoch
+    );
+
+    idle_timeout.disable();
+
+    let generation = ctx.watch().generation();
+    let (start, mut receiver) = ctx.watch().subscribe(req.generation, req.epoch);
+
+    let mut response = match start {
+        WatchStart::Snapshot(epoch) => snapshot_response(req, ctx.metadata(), identity, epoch),
+        WatchStart::Resume(events) => {
+            let mut response = FlvWatchMetadataResponse::default();
+            response.epoch = req.epoch;
+            for event in events.into_iter() {
+                add_event(&mut response, req, ctx.metadata(), identity, event);
+            }
+            response
+        }
+    };
+    response.generation = generation;
+
+    trace!("watch metadata initial response: {:#?}", response);
+    let resp_msg = request.new_response(response);
+    sink.send_response(&resp_msg, version).await?;
+
+    loop {
+        select! {
+            event = receiver.next() => {
+                match event {
+                    None => {
+                        debug!("watch subscription dropped, closing connection");
+                        break;
+                    },
+                    Some(event) => {
+                        let mut response = FlvWatchMetadataResponse::default();
+                        response.generation = generation;
+                        add_event(&mut response, req, ctx.metadata(), identity, event);
+                        if !response.is_empty() {
+                            trace!("watch metadata response: {:#?}", response);
+                            let resp_msg = request.new_response(response);
+                            sink.send_response(&resp_msg, version).await?;
+                        }
+                    }
+                }
+            },
+            client_msg = api_stream.next().fuse() => {
+                // watch connection is dedicated, any further message terminates the watch
+                debug!("watch client terminated: {:?}", client_msg.is_none());
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// all subscribed objects visible to client as Add changes
+fn snapshot_response(
+    req: &FlvWatchMetadataRequest,
+    metadata: &LocalStores,
+    identity: &AclIdentity,
+    epoch: i64,
+) -> FlvWatchMetadataResponse {
+    let mut response = FlvWatchMetadataResponse::default();
+    response.epoch = epoch;
+    response.snapshot = true;
+
+    if req.topics {
+        for (name, topic) in metadata.topics().inner_store().read().iter() {
+            if !can_describe(metadata, identity, name) {
+                continue;
+            }
+            response.topics.push(FlvTopicChange {
+                change_type: FlvChangeType::Add,
+                name: name.clone(),
+                spec: topic.spec.clone(),
+                status: topic.status.clone(),
+            });
+        }
+    }
+
+    if req.partitions {
+        for (key, partition) in metadata.partitions().inner_store().read().iter() {
+            if !can_describe(metadata, identity, &key.topic) {
+                continue;
+            }
+            response.partitions.push(FlvPartitionChange {
+                change_type: FlvChangeType::Add,
+                topic: key.topic.clone(),
+                partition: key.partition,
+                spec: partition.spec.clone(),
+                status: partition.status.clone(),
+            });
+        }
+    }
+
+    if req.spus {
+        for (name, spu) in metadata.spus().inner_store().read().iter() {
+            response.spus.push(FlvSpuChange {
+                change_type: FlvChangeType::Add,
+                name: name.clone(),
+                spec: spu.spec.clone(),
+                status: spu.status.clone(),
+            });
+        }
+    }
+
+    response
+}
+
+/// add event to response if client subscribed to its object type and can describe it.
+/// epoch is advanced even if change is filtered out
+fn add_event(
+    response: &mut FlvWatchMetadataResponse,
+    req: &FlvWatchMetadataRequest,
+    metadata: &LocalStores,
+    identity: &AclIdentity,
+    event: WatchEvent,
+) {
+    response.epoch = event.epoch;
+    match event.change {
+        WatchChange::Topic(change) => {
+            if req.topics && can_describe(metadata, identity, &change.name) {
+                response.topics.push(change);
+            }
+        }
+        WatchChange::Partition(change) => {
+            if req.partitions && can_describe(metadata, identity, &change.topic) {
+                response.partitions.push(change);
+            }
+        }
+        WatchChange::Spu(change) => {
+            if req.spus {
+                response.spus.push(change);
+            }
+        }
+    }
+}
+
+fn can_describe(metadata: &LocalStores, identity: &AclIdentity, topic: &str) -> bool {
+    metadata
+        .authorizer()
+        .authorize_topic(identity, topic, &AclOperation::Describe)
+}
@@ -0,0 +1,214 @@
+//!
+//! # Kafka Alter Configs Request
+//!
+//! Replaces configuration of topics. As in Kafka, entries not in the request
+//! are removed from topic config.
+//!
+//! Broker resources set replication throttle of SPUs at runtime. Resource name is
+//! SPU id, or empty for all SPUs. Removing the entry reverts to SPU config.
+//! Throttles are stored as metadata, so they survive SC restart.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::message::config::{KfAlterConfigsRequest, KfAlterConfigsResponse};
+use kf_protocol::message::config::AlterConfigsResource;
+use kf_protocol::message::config::AlterConfigsResourceResponse;
+use kf_protocol::message::config::TOPIC_RESOURCE_TYPE;
+use kf_protocol::message::config::BROKER_RESOURCE_TYPE;
+
+use kf_protocol::api::ErrorCode as KfErrorCode;
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+
+use metadata::topic::TopicConfig;
+use metadata::topic::validate_topic_config;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use metadata::throttle::ReplicationThrottleSpec;
+use metadata::throttle::throttle_key;
+use types::SpuId;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
+use crate::core::throttles::ThrottleKV;
+
+use super::PublicContext;
+
+/// bytes/sec of replication to out of sync followers
+pub const LEADER_REPLICATION_THROTTLED_RATE: &str = "leader.replication.throttled.rate";
+
+pub async fn handle_kf_alter_configs_request(
+    request: RequestMessage<KfAlterConfigsRequest>,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfAlterConfigsResponse>, Error> {
+    let mut response = KfAlterConfigsResponse::default();
+    let validate_only = request.request.validate_only;
+
+    for resource in &request.request.resources {
+        debug!("api request: alter configs '{}'", resource.resource_name);
+        let result = alter_resource(resource, validate_only, ctx, identity).await;
+        response.resources.push(result);
+    }
+
+    trace!("kf alter configs resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
+
+/// Validate and apply config of single resource
+async fn alter_resource(
+    resource: &AlterConfigsResource,
+    validate_only: bool,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> AlterConfigsResourceResponse {
+    let mut result = AlterConfigsResourceResponse::default();
+    result.resource_type = resource.resource_type;
+    result.resource_name = resource.resource_name.clone();
+
+    match resource.resource_type {
+        TOPIC_RESOURCE_TYPE => alter_topic(resource, validate_only, ctx, identity, result).await,
+        BROKER_RESOURCE_TYPE => alter_broker(resource, validate_only, ctx, identity, result).await,
+        _ => {
+            result.error_code = KfErrorCode::InvalidRequest;
+            result.error_message = Some("only topic and broker configs are supported".to_owned());
+            result
+        }
+    }
+}
+
+/// Validate and apply config of topic
+async fn alter_topic(
+    resource: &AlterConfigsResource,
+    validate_only: bool,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+    mut result: AlterConfigsResourceResponse,
+) -> AlterConfigsResourceResponse {
+
+    if !ctx.metadata().authorizer().authorize_topic(
+        identity,
+        &resource.resource_name,
+        &AclOperation::AlterConfigs,
+    ) {
+        result.error_code = KfErrorCode::TopicAuthorizationFailed;
+        return result;
+    }
+
+    let mut topic = match ctx.metadata().topics().topic(&resource.resource_name) {
+        Some(topic) => topic,
+        None => {
+            result.error_code = KfErrorCode::UnknownTopicOrPartition;
+            result.error_message = Some(format!("topic '{}' not found", resource.resource_name));
+            return result;
+        }
+    };
+
+    let mut config = TopicConfig::new();
+    for entry in &resource.configs {
+        if let Some(value) = &entry.value {
+            config.insert(entry.name.clone(), value.clone());
+        }
+    }
+
+    if let Err(err) = validate_topic_config(&config) {
+        result.error_code = KfErrorCode::InvalidConfig;
+        result.error_message = Some(err.to_string());
+        return result;
+    }
+
+    if validate_only || topic.spec.config() == &config {
+        return result;
+    }
+
+    *topic.spec.config_mut() = config;
+    if let Err(err) = ctx
+        .ws_service()
+        .update_topic(WSAction::UpdateSpec(topic))
+        .await
+    {
+        result.error_code = KfErrorCode::UnknownServerError;
+        result.error_message = Some(err.to_string());
+    }
+
+    result
+}
+
+/// Validate and apply replication throttle of spu, or of all spus if resource name is empty
+async fn alter_broker(
+    resource: &AlterConfigsResource,
+    validate_only: bool,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+    mut result: AlterConfigsResourceResponse,
+) -> AlterConfigsResourceResponse {
+    if !ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster(identity, &AclOperation::AlterConfigs)
+    {
+        result.error_code = KfErrorCode::ClusterAuthorizationFailed;
+        return result;
+    }
+
+    let spu = if resource.resource_name.is_empty() {
+        None
+    } else {
+        match resource.resource_name.parse::<SpuId>() {
+            Ok(spu) if ctx.metadata().spus().get_by_id(&spu).is_some() => Some(spu),
+            _ => {
+                result.error_code = KfErrorCode::InvalidRequest;
+                result.error_message = Some(format!("spu '{}' not found", resource.resource_name));
+                return result;
+            }
+        }
+    };
+
+    let mut rate = None;
+    for entry in &resource.configs {
+        if entry.name != LEADER_REPLICATION_THROTTLED_RATE {
+            result.error_code = KfErrorCode::InvalidConfig;
+            result.error_message = Some(format!("unsupported broker config '{}'", entry.name));
+            return result;
+        }
+        if let Some(value) = &entry.value {
+            match value.parse::<i64>() {
+                Ok(value) if value >= 0 => rate = Some(value),
+                _ => {
+                    result.error_code = KfErrorCode::InvalidConfig;
+                    result.error_message = Some(format!(
+                        "{} must be bytes per second, got '{}'",
+                        LEADER_REPLICATION_THROTTLED_RATE, value
+                    ));
+                    return result;
+                }
+            }
+        }
+    }
+
+    if validate_only {
+        return result;
+    }
+
+    let existing = ctx.metadata().replication_throttles().value(&throttle_key(spu));
+    let action = match (existing, rate) {
+        (Some(ref throttle), Some(rate)) if throttle.spec.rate == rate => return result,
+        (Some(mut throttle), Some(rate)) => {
+            throttle.spec.rate = rate;
+            WSAction::UpdateSpec(throttle)
+        }
+        (None, Some(rate)) => {
+            WSAction::Add(ThrottleKV::from(ReplicationThrottleSpec::new(spu, rate)))
+        }
+        (Some(throttle), None) => WSAction::Delete(throttle.key_owned()),
+        (None, None) => return result,
+    };
+
+    if let Err(err) = ctx.ws_service().update_replication_throttle(action).await {
+        result.error_code = KfErrorCode::UnknownServerError;
+        result.error_message = Some(err.to_string());
+    }
+
+    result
+}
@@ -0,0 +1,103 @@
+//!
+//! # Kafka Create ACLs Request
+//!
+//! Stores ACL bindings as SC metadata. Requires Alter permission on cluster.
+//! Creating an existing binding is not an error.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::message::acl::{KfCreateAclsRequest, KfCreateAclsResponse};
+use kf_protocol::message::acl::CreatableAcl;
+use kf_protocol::message::acl::CreatableAclResult;
+
+use kf_protocol::api::ErrorCode as KfErrorCode;
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+
+use metadata::acl::AclSpec;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclResourceType;
+use metadata::acl::AclPatternType;
+use metadata::acl::AclOperation;
+use metadata::acl::ACL_CLUSTER_NAME;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
+use crate::core::acls::AclKV;
+
+use super::PublicContext;
+
+pub async fn handle_kf_create_acls_request(
+    request: RequestMessage<KfCreateAclsRequest>,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfCreateAclsResponse>, Error> {
+    let version = request.header.api_version();
+    let mut response = KfCreateAclsResponse::default();
+
+    let authorized = ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster_explicit(identity, &AclOperation::Alter);
+
+    for creation in &request.request.creations {
+        debug!("api request: create acl on '{}'", creation.resource_name);
+        let result = if authorized {
+            create_acl(creation, version, ctx).await
+        } else {
+            error_result(KfErrorCode::ClusterAuthorizationFailed, None)
+        };
+        response.results.push(result);
+    }
+
+    trace!("kf create acls resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
+
+/// Validate and store single binding
+async fn create_acl(creation: &CreatableAcl, version: i16, ctx: &PublicContext) -> CreatableAclResult {
+    // pattern type was introduced in version 1, literal before
+    let pattern_type = if version < 1 {
+        AclPatternType::Literal.kf_code()
+    } else {
+        creation.resource_pattern_type
+    };
+
+    let spec = match AclSpec::from_kf_codes(
+        creation.resource_type,
+        creation.resource_name.clone(),
+        pattern_type,
+        creation.principal.clone(),
+        creation.host.clone(),
+        creation.operation,
+        creation.permission_type,
+    ) {
+        Ok(spec) => spec,
+        Err(err) => return error_result(KfErrorCode::InvalidRequest, Some(err)),
+    };
+
+    if spec.resource_type == AclResourceType::Cluster && spec.resource_name != ACL_CLUSTER_NAME {
+        return error_result(
+            KfErrorCode::InvalidRequest,
+            Some(format!("cluster resource name must be '{}'", ACL_CLUSTER_NAME)),
+        );
+    }
+
+    if ctx.metadata().acls().contains_key(&spec.key()) {
+        debug!("acl '{}' already exists", spec);
+        return CreatableAclResult::default();
+    }
+
+    match ctx.ws_service().update_acl(WSAction::Add(AclKV::from(spec))).await {
+        Ok(_) => CreatableAclResult::default(),
+        Err(err) => error_result(KfErrorCode::UnknownServerError, Some(err.to_string())),
+    }
+}
+
+fn error_result(error_code: KfErrorCode, error_message: Option<String>) -> CreatableAclResult {
+    CreatableAclResult {
+        error_code,
+        error_message,
+    }
+}
@@ -0,0 +1,112 @@
+//!
+//! # Kafka Delete ACLs Request
+//!
+//! Removes ACL bindings selected by each filter. Requires Alter permission on cluster.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::message::acl::{KfDeleteAclsRequest, KfDeleteAclsResponse};
+use kf_protocol::message::acl::DeleteAclsFilter;
+use kf_protocol::message::acl::DeleteAclsFilterResult;
+use kf_protocol::message::acl::DeleteAclsMatchingAcl;
+
+use kf_protocol::api::ErrorCode as KfErrorCode;
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+
+use metadata::acl::AclFilter;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclPatternType;
+use metadata::acl::AclOperation;
+
+use crate::core::WSUpdateService;
+use crate::core::common::WSAction;
+
+use super::PublicContext;
+
+pub async fn handle_kf_delete_acls_request(
+    request: RequestMessage<KfDeleteAclsRequest>,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfDeleteAclsResponse>, Error> {
+    let version = request.header.api_version();
+    let mut response = KfDeleteAclsResponse::default();
+
+    let authorized = ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster_explicit(identity, &AclOperation::Alter);
+
+    for filter in &request.request.filters {
+        debug!("api request: delete acls {:?}", filter.resource_name_filter);
+        let result = if authorized {
+            delete_acls(filter, version, ctx).await
+        } else {
+            let mut result = DeleteAclsFilterResult::default();
+            result.error_code = KfErrorCode::ClusterAuthorizationFailed;
+            result
+        };
+        response.filter_results.push(result);
+    }
+
+    trace!("kf delete acls resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
+
+/// Delete all bindings matching single filter
+async fn delete_acls(
+    filter: &DeleteAclsFilter,
+    version: i16,
+    ctx: &PublicContext,
+) -> DeleteAclsFilterResult {
+    let mut result = DeleteAclsFilterResult::default();
+
+    // pattern type was introduced in version 1, literal before
+    let pattern_type = if version < 1 {
+        AclPatternType::Literal.kf_code()
+    } else {
+        filter.pattern_type_filter
+    };
+
+    let acl_filter = match AclFilter::from_kf_codes(
+        filter.resource_type_filter,
+        filter.resource_name_filter.clone(),
+        pattern_type,
+        filter.principal_filter.clone(),
+        filter.host_filter.clone(),
+        filter.operation,
+        filter.permission_type,
+    ) {
+        Ok(acl_filter) => acl_filter,
+        Err(err) => {
+            result.error_code = KfErrorCode::InvalidRequest;
+            result.error_message = Some(err);
+            return result;
+        }
+    };
+
+    for acl in ctx.metadata().acls().filter(&acl_filter) {
+        let mut matching = DeleteAclsMatchingAcl::default();
+        if let Err(err) = ctx
+            .ws_service()
+            .update_acl(WSAction::Delete(acl.key_owned()))
+            .await
+        {
+            matching.error_code = KfErrorCode::UnknownServerError;
+            matching.error_message = Some(err.to_string());
+        }
+
+        let spec = acl.spec;
+        matching.resource_type = spec.resource_type.kf_code();
+        matching.resource_name = spec.resource_name;
+        matching.pattern_type = spec.pattern_type.kf_code();
+        matching.principal = spec.principal;
+        matching.host = spec.host;
+        matching.operation = spec.operation.kf_code();
+        matching.permission_type = spec.permission.kf_code();
+        result.matching_acls.push(matching);
+    }
+
+    result
+}
@@ -0,0 +1,97 @@
+//!
+//! # Kafka Describe ACLs Request
+//!
+//! Returns ACL bindings selected by filter, grouped by resource.
+//! Requires Describe permission on cluster.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::message::acl::{KfDescribeAclsRequest, KfDescribeAclsResponse};
+use kf_protocol::message::acl::DescribeAclsResource;
+use kf_protocol::message::acl::AclDescription;
+
+use kf_protocol::api::ErrorCode as KfErrorCode;
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+
+use metadata::acl::AclFilter;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclPatternType;
+use metadata::acl::AclOperation;
+
+use super::PublicContext;
+
+pub async fn handle_kf_describe_acls_request(
+    request: RequestMessage<KfDescribeAclsRequest>,
+    ctx: &PublicContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfDescribeAclsResponse>, Error> {
+    let version = request.header.api_version();
+    let req = &request.request;
+    let mut response = KfDescribeAclsResponse::default();
+
+    debug!("api request: describe acls {:?}", req.resource_name_filter);
+
+    if !ctx
+        .metadata()
+        .authorizer()
+        .authorize_cluster_explicit(identity, &AclOperation::Describe)
+    {
+        response.error_code = KfErrorCode::ClusterAuthorizationFailed;
+        return Ok(request.new_response(response));
+    }
+
+    // pattern type was introduced in version 1, literal before
+    let pattern_type = if version < 1 {
+        AclPatternType::Literal.kf_code()
+    } else {
+        req.resource_pattern_type
+    };
+
+    let filter = match AclFilter::from_kf_codes(
+        req.resource_type,
+        req.resource_name_filter.clone(),
+        pattern_type,
+        req.principal_filter.clone(),
+        req.host_filter.clone(),
+        req.operation,
+        req.permission_type,
+    ) {
+        Ok(filter) => filter,
+        Err(err) => {
+            response.error_code = KfErrorCode::InvalidRequest;
+            response.error_message = Some(err);
+            return Ok(request.new_response(response));
+        }
+    };
+
+    for acl in ctx.metadata().acls().filter(&filter) {
+        let spec = acl.spec;
+        let description = AclDescription {
+            principal: spec.principal.clone(),
+            host: spec.host.clone(),
+            operation: spec.operation.kf_code(),
+            permission_type: spec.permission.kf_code(),
+        };
+
+        let resource_type = spec.resource_type.kf_code();
+        let resource_pattern = spec.pattern_type.kf_code();
+        match response.resources.iter_mut().find(|resource| {
+            resource.typ == resource_type
+                && resource.pattern_type == resource_pattern
+                && resource.name == spec.resource_name
+        }) {
+            Some(resource) => resource.acls.push(description),
+            None => response.resources.push(DescribeAclsResource {
+                typ: resource_type,
+                name: spec.resource_name,
+                pattern_type: resource_pattern,
+                acls: vec![description],
+            }),
+        }
+    }
+
+    trace!("kf describe acls resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
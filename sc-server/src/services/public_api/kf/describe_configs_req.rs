@@ -0,0 +1,100 @@
+//!
+//! # Kafka Describe Configs Request
+//!
+//! Returns configuration of topics. Only topic resources are supported.
+//! If no keys are requested, all known topic config keys are returned.
+//!
+use log::{debug, trace};
+use std::io::Error;
+
+use kf_protocol::message::config::{KfDescribeConfigsRequest, KfDescribeConfigsResponse};
+use kf_protocol::message::config::DescribeConfigsResource;
+use kf_protocol::message::config::DescribeConfigsResult;
+use kf_protocol::message::config::DescribeConfigsResourceResult;
+use kf_protocol::message::config::TOPIC_RESOURCE_TYPE;
+use kf_protocol::message::config::TOPIC_CONFIG_SOURCE;
+use kf_protocol::message::config::DEFAULT_CONFIG_SOURCE;
+
+use kf_protocol::api::ErrorCode as KfErrorCode;
+use kf_protocol::api::{RequestMessage, ResponseMessage};
+
+use metadata::topic::TOPIC_CONFIG_KEYS;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
+use crate::core::ShareLocalStores;
+use crate::core::topics::TopicLocalStore;
+use crate::core::acls::AclAuthorizer;
+
+pub async fn handle_kf_describe_configs_request(
+    request: RequestMessage<KfDescribeConfigsRequest>,
+    metadata: ShareLocalStores,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfDescribeConfigsResponse>, Error> {
+    let mut response = KfDescribeConfigsResponse::default();
+
+    for resource in &request.request.resources {
+        debug!("api request: describe configs '{}'", resource.resource_name);
+        response
+            .results
+            .push(describe_resource(resource, metadata.topics(), metadata.authorizer(), identity));
+    }
+
+    trace!("kf describe configs resp {:#?}", response);
+
+    Ok(request.new_response(response))
+}
+
+/// Describe config of single topic resource
+fn describe_resource(
+    resource: &DescribeConfigsResource,
+    topics: &TopicLocalStore,
+    authorizer: &AclAuthorizer,
+    identity: &AclIdentity,
+) -> DescribeConfigsResult {
+    let mut result = DescribeConfigsResult::default();
+    result.resource_type = resource.resource_type;
+    result.resource_name = resource.resource_name.clone();
+
+    if resource.resource_type != TOPIC_RESOURCE_TYPE {
+        result.error_code = KfErrorCode::InvalidRequest;
+        result.error_message = Some("only topic configs are supported".to_owned());
+        return result;
+    }
+
+    if !authorizer.authorize_topic(identity, &resource.resource_name, &AclOperation::DescribeConfigs) {
+        result.error_code = KfErrorCode::TopicAuthorizationFailed;
+        return result;
+    }
+
+    let topic = match topics.topic(&resource.resource_name) {
+        Some(topic) => topic,
+        None => {
+            result.error_code = KfErrorCode::UnknownTopicOrPartition;
+            result.error_message = Some(format!("topic '{}' not found", resource.resource_name));
+            return result;
+        }
+    };
+
+    let config = topic.spec.config();
+    for key in TOPIC_CONFIG_KEYS.iter() {
+        if let Some(keys) = &resource.configuration_keys {
+            if !keys.iter().any(|requested| requested.as_str() == *key) {
+                continue;
+            }
+        }
+
+        let value = config.get(*key).cloned();
+        let mut entry = DescribeConfigsResourceResult::default();
+        entry.name = key.to_string();
+        entry.config_source = if value.is_some() {
+            TOPIC_CONFIG_SOURCE
+        } else {
+            DEFAULT_CONFIG_SOURCE
+        };
+        entry.value = value;
+        result.configs.push(entry);
+    }
+
+    result
+}
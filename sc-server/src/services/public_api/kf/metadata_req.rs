@@ -1,7 +1,6 @@
 use log::trace;
 use std::io::Error;
 
-use types::Name;
 
 use kf_protocol::message::metadata::{KfMetadataRequest, KfMetadataResponse};
 use kf_protocol::message::metadata::MetadataResponseTopic;
@@ -11,14 +10,19 @@ use kf_protocol::message::metadata::MetadataResponsePartition;
 use kf_protocol::api::ErrorCode as KfErrorCode;
 use kf_protocol::api::{RequestMessage, ResponseMessage};
 
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
 use crate::core::ShareLocalStores;
 use crate::core::spus::SpuKV;
 use crate::core::topics::TopicLocalStore;
 use crate::core::partitions::PartitionLocalStore;
+use crate::core::acls::AclAuthorizer;
 
 pub async fn handle_kf_metadata_request(
     request: RequestMessage<KfMetadataRequest>,
     metadata: ShareLocalStores,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<KfMetadataResponse>, Error> {
     // generate broker metadata (from online spus)
     let spus = metadata.spus().online_spus();
@@ -29,12 +33,25 @@ pub async fn handle_kf_metadata_request(
     if let Some(topics_req) = &request.request.topics {
         // lookup specific topics
         for topic_req in topics_req {
-            resp_topics.push(make_kf_topic_response(&topic_req.name, metadata.topics()));
+            resp_topics.push(make_kf_topic_response(
+                &topic_req.name,
+                metadata.topics(),
+                metadata.authorizer(),
+                identity,
+            ));
         }
     } else {
-        // generate all "ok" topics
+        // generate all "ok" topics, skip topics principal may not describe
         for topic_name in metadata.topics().all_keys() {
-            resp_topics.push(make_kf_topic_response(&topic_name, metadata.topics()));
+            if !metadata.authorizer().authorize_topic(identity, &topic_name, &AclOperation::Describe) {
+                continue;
+            }
+            resp_topics.push(make_kf_topic_response(
+                &topic_name,
+                metadata.topics(),
+                metadata.authorizer(),
+                identity,
+            ));
         }
     }
 
@@ -60,11 +77,21 @@ pub async fn handle_kf_metadata_request(
 }
 
 /// Given a topic name, generate Topic Response
-fn make_kf_topic_response(name: &Name, topics: &TopicLocalStore) -> MetadataResponseTopic {
+fn make_kf_topic_response(
+    name: &str,
+    topics: &TopicLocalStore,
+    authorizer: &AclAuthorizer,
+    identity: &AclIdentity,
+) -> MetadataResponseTopic {
     let mut topic_resp = MetadataResponseTopic::default();
-    topic_resp.name = name.clone();
+    topic_resp.name = name.to_owned();
+
+    if !authorizer.authorize_topic(identity, name, &AclOperation::Describe) {
+        topic_resp.error_code = KfErrorCode::TopicAuthorizationFailed;
+        return topic_resp;
+    }
 
-    if let Some(flv_topic) = topics.topic(&name) {
+    if let Some(flv_topic) = topics.topic(name) {
         if !flv_topic.is_provisioned() {
             topic_resp.error_code = KfErrorCode::UnknownTopicOrPartition;
         }
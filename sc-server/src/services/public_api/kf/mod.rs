@@ -1 +1,8 @@
 pub mod metadata_req;
+pub mod describe_configs_req;
+pub mod alter_configs_req;
+pub mod create_acls_req;
+pub mod describe_acls_req;
+pub mod delete_acls_req;
+
+use super::PublicContext;
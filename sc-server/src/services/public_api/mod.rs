@@ -1,4 +1,5 @@
 mod public_server;
+mod public_ws_service;
 
 mod flv;
 mod kf;
@@ -9,6 +10,11 @@ mod api {
 
      // kafka
      pub use super::kf::metadata_req::*;
+     pub use super::kf::describe_configs_req::*;
+     pub use super::kf::alter_configs_req::*;
+     pub use super::kf::create_acls_req::*;
+     pub use super::kf::describe_acls_req::*;
+     pub use super::kf::delete_acls_req::*;
 
      // fluvio
      pub use super::flv::create_topics_req::*;
@@ -23,6 +29,10 @@ mod api {
      pub use super::flv::create_spu_groups_req::*;
      pub use super::flv::delete_spu_groups_req::*;
      pub use super::flv::fetch_spu_groups_req::*;
+
+     pub use super::flv::rebalance_leaders_req::*;
+
+     pub use super::flv::watch_metadata_req::*;
 }
 
 use std::sync::Arc;
@@ -37,7 +47,6 @@ use sc_api::PublicRequest;
 use sc_api::ScApiKey;
 use kf_service::KfApiServer;
 use public_server::PublicService;
-use k8_client::K8Client;
 use k8_client::ClientError;
 use k8_metadata::core::metadata::InputObjectMeta;
 use k8_metadata::core::metadata::InputK8Obj;
@@ -46,8 +55,10 @@ use k8_metadata::core::Spec as K8Spec;
 
 
 use crate::core::ShareLocalStores;
-use crate::k8::K8WSUpdateService;
 use crate::core::LocalStores;
+use crate::core::watch::SharedMetadataWatch;
+
+pub use public_ws_service::PublicWSService;
 
 pub type SharedPublicContext = Arc<PublicContext>;
 
@@ -56,43 +67,48 @@ pub type PubliApiServer = KfApiServer<PublicRequest, ScApiKey, SharedPublicConte
 /// create public server
 pub fn create_public_server(
      metadata: ShareLocalStores,
-     k8_ws: K8WSUpdateService,
+     ws_service: PublicWSService,
      namespace: String,
+     watch: SharedMetadataWatch,
 ) -> PubliApiServer {
      let addr = metadata.config().public_endpoint.addr.clone();
+     let server_config = metadata.config().public_server.clone();
      info!("start public api service at: {}", addr);
 
      KfApiServer::new(
           addr,
           Arc::new(PublicContext {
                metadata,
-               k8_ws,
+               ws_service,
                namespace,
+               watch,
           }),
           PublicService::new(),
      )
+     .with_config(server_config)
 }
 
 #[derive(Clone)]
 pub struct PublicContext {
      metadata: ShareLocalStores,
-     k8_ws: K8WSUpdateService,
+     ws_service: PublicWSService,
      namespace: String,
+     watch: SharedMetadataWatch,
 }
 
 impl PublicContext {
-     pub fn k8_client(&self) -> &K8Client {
-          self.k8_ws.client()
-     }
-
-     pub fn k8_ws(&self) -> &K8WSUpdateService {
-          &self.k8_ws
+     pub fn ws_service(&self) -> &PublicWSService {
+          &self.ws_service
      }
 
      pub fn metadata(&self) -> &LocalStores {
           &self.metadata
      }
 
+     pub fn watch(&self) -> &SharedMetadataWatch {
+          &self.watch
+     }
+
      /// Create input metadata for our context
      /// which has namespace
      pub async fn create<S>(
@@ -117,7 +133,7 @@ impl PublicContext {
                ..Default::default()
           };
 
-          let client = self.k8_ws.client();
+          let client = self.ws_service.k8_client()?;
           client.apply(input).await?;
 
           Ok(())
@@ -140,7 +156,7 @@ impl PublicContext {
                ..Default::default()
           };
 
-          let client = self.k8_ws.client();
+          let client = self.ws_service.k8_client()?;
           client.delete_item::<S,_>(&meta).await?;
 
           Ok(())
@@ -155,7 +171,7 @@ impl PublicContext {
            K8List<S,S::Status>: DeserializeOwned,
      {
         
-          let client = self.k8_ws.client();
+          let client = self.ws_service.k8_client()?;
           client.retrieve_items::<S>(&self.namespace).await
      }
 
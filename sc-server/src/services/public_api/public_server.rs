@@ -11,19 +11,29 @@ use std::sync::Arc;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 
-use kf_service::api_loop;
+use kf_service::sasl_api_loop;
 use kf_service::call_service;
 use kf_socket::KfSocket;
 use kf_socket::KfSocketError;
 
 use kf_service::KfService;
+use kf_service::sasl::SaslSession;
+use kf_service::sasl::handle_sasl_handshake_request;
+use kf_service::sasl::handle_sasl_authenticate_request;
 
 use sc_api::PublicRequest;
 use sc_api::ScApiKey;
 
+use metadata::acl::AclIdentity;
+
 use super::api::handle_api_versions_request;
 
 use super::api::handle_kf_metadata_request;
+use super::api::handle_kf_describe_configs_request;
+use super::api::handle_kf_alter_configs_request;
+use super::api::handle_kf_create_acls_request;
+use super::api::handle_kf_describe_acls_request;
+use super::api::handle_kf_delete_acls_request;
 
 use super::api::handle_create_topics_request;
 use super::api::handle_delete_topics_request;
@@ -38,6 +48,10 @@ use super::api::handle_create_spu_groups_request;
 use super::api::handle_delete_spu_groups_request;
 use super::api::handle_fetch_spu_groups_request;
 
+use super::api::handle_rebalance_leaders_request;
+
+use super::api::handle_watch_metadata_request;
+
 use super::SharedPublicContext;
 
 pub struct PublicService {}
@@ -52,92 +66,194 @@ impl PublicService {
         ctx: SharedPublicContext,
         socket: KfSocket,
     ) -> Result<(), KfSocketError> {
+        // principal is anonymous until connection is authenticated
+        let host = socket
+            .peer_addr()
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_default();
+        let mut identity = AclIdentity::anonymous(host);
+        let mut session = SaslSession::new(ctx.metadata().config().sasl.clone());
+
+        let _connection = ctx.metadata().request_metrics().open_connection();
         let (mut sink, mut stream) = socket.split();
+        let idle_timeout = stream.idle_timeout_handle();
         let mut api_stream = stream.api_stream::<PublicRequest, ScApiKey>();
 
-        api_loop!(
+        sasl_api_loop!(
             api_stream,
+            session,
+            [
+                PublicRequest::ApiVersionsRequest(_)
+                    | PublicRequest::KfSaslHandshakeRequest(_)
+                    | PublicRequest::KfSaslAuthenticateRequest(_)
+            ],
 
             // Common
             PublicRequest::ApiVersionsRequest(request) => call_service!(
                 request,
                 handle_api_versions_request(request),
                 sink,
-                "api version handler"
+                "api version handler",
+                ctx.metadata().request_metrics()
+            ),
+
+            // Kafka - SASL
+            PublicRequest::KfSaslHandshakeRequest(request) => call_service!(
+                request,
+                handle_sasl_handshake_request(request, &mut session),
+                sink,
+                "sasl handshake handler",
+                ctx.metadata().request_metrics()
             ),
+            PublicRequest::KfSaslAuthenticateRequest(request) => {
+                call_service!(
+                    request,
+                    handle_sasl_authenticate_request(
+                        request,
+                        &mut session,
+                        ctx.metadata().credentials().as_ref()
+                    ),
+                    sink,
+                    "sasl authenticate handler",
+                    ctx.metadata().request_metrics()
+                );
+                if let Some(principal) = session.principal() {
+                    identity.principal = principal;
+                }
+            },
 
             // Kafka
             PublicRequest::KfMetadataRequest(request) => call_service!(
                 request,
-                handle_kf_metadata_request(request, ctx.metadata.clone()),
+                handle_kf_metadata_request(request, ctx.metadata.clone(), &identity),
                 sink,
-                "metadata request handler"
+                "metadata request handler",
+                ctx.metadata().request_metrics()
+            ),
+            PublicRequest::KfDescribeConfigsRequest(request) => call_service!(
+                request,
+                handle_kf_describe_configs_request(request, ctx.metadata.clone(), &identity),
+                sink,
+                "describe configs handler",
+                ctx.metadata().request_metrics()
+            ),
+            PublicRequest::KfAlterConfigsRequest(request) => call_service!(
+                request,
+                handle_kf_alter_configs_request(request, &ctx, &identity),
+                sink,
+                "alter configs handler",
+                ctx.metadata().request_metrics()
+            ),
+            PublicRequest::KfCreateAclsRequest(request) => call_service!(
+                request,
+                handle_kf_create_acls_request(request, &ctx, &identity),
+                sink,
+                "create acls handler",
+                ctx.metadata().request_metrics()
+            ),
+            PublicRequest::KfDescribeAclsRequest(request) => call_service!(
+                request,
+                handle_kf_describe_acls_request(request, &ctx, &identity),
+                sink,
+                "describe acls handler",
+                ctx.metadata().request_metrics()
+            ),
+            PublicRequest::KfDeleteAclsRequest(request) => call_service!(
+                request,
+                handle_kf_delete_acls_request(request, &ctx, &identity),
+                sink,
+                "delete acls handler",
+                ctx.metadata().request_metrics()
             ),
 
             // Fluvio - Topics
             PublicRequest::FlvCreateTopicsRequest(request) => call_service!(
                 request,
-                handle_create_topics_request(request, &ctx),
+                handle_create_topics_request(request, &ctx, &identity),
                 sink,
-                "create topic handler"
+                "create topic handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvDeleteTopicsRequest(request) => call_service!(
                 request,
-                handle_delete_topics_request(request, &ctx),
+                handle_delete_topics_request(request, &ctx, &identity),
                 sink,
-                "delete topic handler"
+                "delete topic handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvFetchTopicsRequest(request) => call_service!(
                 request,
-                handle_fetch_topics_request(request, ctx.metadata.clone()),
+                handle_fetch_topics_request(request, ctx.metadata.clone(), &identity),
                 sink,
-                "fetch topic handler"
+                "fetch topic handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvTopicCompositionRequest(request) => call_service!(
                 request,
-                handle_topic_composition_request(request, ctx.metadata.clone()),
+                handle_topic_composition_request(request, ctx.metadata.clone(), &identity),
                 sink,
-                "topic metadata handler"
+                "topic metadata handler",
+                ctx.metadata().request_metrics()
             ),
 
             // Fluvio - Spus
             PublicRequest::FlvCreateCustomSpusRequest(request) => call_service!(
                 request,
-                handle_create_custom_spus_request(request, &ctx),
+                handle_create_custom_spus_request(request, &ctx, &identity),
                 sink,
-                "create custom spus handler"
+                "create custom spus handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvDeleteCustomSpusRequest(request) => call_service!(
                 request,
-                handle_delete_custom_spus_request(request, &ctx),
+                handle_delete_custom_spus_request(request, &ctx, &identity),
                 sink,
-                "delete custom spus handler"
+                "delete custom spus handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvFetchSpusRequest(request) => call_service!(
                 request,
                 handle_fetch_spu_request(request, ctx.metadata.clone()),
                 sink,
-                "fetch spus handler"
+                "fetch spus handler",
+                ctx.metadata().request_metrics()
             ),
 
             PublicRequest::FlvCreateSpuGroupsRequest(request) => call_service!(
                 request,
-                handle_create_spu_groups_request(request, &ctx),
+                handle_create_spu_groups_request(request, &ctx, &identity),
                 sink,
-                "create spu groups handler"
+                "create spu groups handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvDeleteSpuGroupsRequest(request) => call_service!(
                 request,
-                handle_delete_spu_groups_request(request, &ctx),
+                handle_delete_spu_groups_request(request, &ctx, &identity),
                 sink,
-                "delete spu groups handler"
+                "delete spu groups handler",
+                ctx.metadata().request_metrics()
             ),
             PublicRequest::FlvFetchSpuGroupsRequest(request) => call_service!(
                 request,
                 handle_fetch_spu_groups_request(request, &ctx),
                 sink,
-                "fetch spu groups handler"
-            )
+                "fetch spu groups handler",
+                ctx.metadata().request_metrics()
+            ),
+
+            // Fluvio - Partitions
+            PublicRequest::FlvRebalanceLeadersRequest(request) => call_service!(
+                request,
+                handle_rebalance_leaders_request(request, &ctx, &identity),
+                sink,
+                "rebalance leaders handler",
+                ctx.metadata().request_metrics()
+            ),
+
+            // Fluvio - Metadata, watch holds the connection until client disconnects
+            PublicRequest::FlvWatchMetadataRequest(request) => {
+                return handle_watch_metadata_request(request, &ctx, &mut sink, &mut api_stream, &idle_timeout, &identity).await;
+            }
 
         );
 
@@ -0,0 +1,158 @@
+//!
+//! # Public API World Store
+//!
+//! Public API handlers write either to Kubernetes or to local file store,
+//! depending on metadata store selected in SC configuration.
+//!
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use futures::future::BoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use k8_client::K8Client;
+use k8_client::ClientError;
+use k8_metadata::core::Spec as K8Spec;
+use metadata::topic::TopicSpec;
+use metadata::partition::PartitionSpec;
+use metadata::acl::AclSpec;
+use metadata::auth_token::AuthTokenSpec;
+use metadata::auth_token::SaslCredentialSpec;
+use metadata::quota::ClientQuotaSpec;
+use metadata::throttle::ReplicationThrottleSpec;
+use metadata::spu::SpuSpec;
+
+use crate::ScServerError;
+use crate::core::Spec;
+use crate::core::WSUpdateService;
+use crate::core::common::KVObject;
+use crate::core::common::WSAction;
+use crate::k8::K8WSUpdateService;
+use crate::local::LocalWSUpdateService;
+use crate::local::StoredObj;
+
+#[derive(Clone)]
+pub enum PublicWSService {
+    K8(K8WSUpdateService),
+    Local(LocalWSUpdateService),
+}
+
+impl PublicWSService {
+    /// kubernetes client, only available for k8 metadata store
+    pub fn k8_client(&self) -> Result<&K8Client, ClientError> {
+        match self {
+            Self::K8(k8_ws) => Ok(k8_ws.client()),
+            Self::Local(_) => Err(ClientError::IoError(IoError::new(
+                ErrorKind::Other,
+                "not supported by local metadata store, requires kubernetes",
+            ))),
+        }
+    }
+
+    pub async fn add<S>(&self, value: KVObject<S>) -> Result<(), ScServerError>
+    where
+        S: Spec + Debug,
+        S::Status: Debug + PartialEq,
+        S::Key: Display + Debug,
+        <S as Spec>::K8Spec: Debug + From<S> + Default + DeserializeOwned + Serialize + Clone,
+        <<S as Spec>::K8Spec as K8Spec>::Status:
+            Default + Debug + DeserializeOwned + Serialize + Clone,
+        StoredObj<S>: Serialize + DeserializeOwned,
+    {
+        match self {
+            Self::K8(k8_ws) => k8_ws.add(value).await,
+            Self::Local(local_ws) => local_ws.add(value).await,
+        }
+    }
+
+    /// delete object, objects owned by it are removed by the store
+    pub async fn delete<S>(&self, value: &KVObject<S>) -> Result<(), ScServerError>
+    where
+        S: Spec,
+        S::Key: Display,
+    {
+        match self {
+            Self::K8(k8_ws) => {
+                let item_ctx = value.kv_ctx().item_ctx.as_ref().ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::Other,
+                        format!("{} delete failed - missing KV ctx {}", S::LABEL, value.key()),
+                    )
+                })?;
+                let input = item_ctx.as_input();
+                k8_ws
+                    .client()
+                    .delete_item::<S::K8Spec, _>(&input)
+                    .await?;
+                Ok(())
+            }
+            Self::Local(local_ws) => local_ws.delete::<S>(value.key()).await,
+        }
+    }
+}
+
+impl WSUpdateService for PublicWSService {
+    type ResponseFuture = BoxFuture<'static, Result<(), ScServerError>>;
+
+    fn update_spu(&self, ws_actions: WSAction<SpuSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_spu(ws_actions),
+            Self::Local(local_ws) => local_ws.update_spu(ws_actions),
+        }
+    }
+
+    fn update_topic(&self, ws_actions: WSAction<TopicSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_topic(ws_actions),
+            Self::Local(local_ws) => local_ws.update_topic(ws_actions),
+        }
+    }
+
+    fn update_partition(&self, ws_actions: WSAction<PartitionSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_partition(ws_actions),
+            Self::Local(local_ws) => local_ws.update_partition(ws_actions),
+        }
+    }
+
+    fn update_acl(&self, ws_actions: WSAction<AclSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_acl(ws_actions),
+            Self::Local(local_ws) => local_ws.update_acl(ws_actions),
+        }
+    }
+
+    fn update_credential(&self, ws_actions: WSAction<SaslCredentialSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_credential(ws_actions),
+            Self::Local(local_ws) => local_ws.update_credential(ws_actions),
+        }
+    }
+
+    fn update_quota(&self, ws_actions: WSAction<ClientQuotaSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_quota(ws_actions),
+            Self::Local(local_ws) => local_ws.update_quota(ws_actions),
+        }
+    }
+
+    fn update_auth_token(&self, ws_actions: WSAction<AuthTokenSpec>) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_auth_token(ws_actions),
+            Self::Local(local_ws) => local_ws.update_auth_token(ws_actions),
+        }
+    }
+
+    fn update_replication_throttle(
+        &self,
+        ws_actions: WSAction<ReplicationThrottleSpec>,
+    ) -> Self::ResponseFuture {
+        match self {
+            Self::K8(k8_ws) => k8_ws.update_replication_throttle(ws_actions),
+            Self::Local(local_ws) => local_ws.update_replication_throttle(ws_actions),
+        }
+    }
+}
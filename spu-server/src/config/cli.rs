@@ -33,6 +33,10 @@ pub struct SpuOpt {
     /// Spu server for internal cluster communication
     pub private_server: Option<String>,
 
+    #[structopt(short = "m", long = "metrics-server", value_name = "host:port")]
+    /// Http server for metrics
+    pub metrics_server: Option<String>,
+
     /// Address of the SC Server
     #[structopt(short = "c", long = "sc-controller", value_name = "host:port")]
     pub sc_server: Option<String>,
@@ -10,6 +10,7 @@ pub use self::spu_config::SpuConfig;
 pub use self::spu_config::SpuType;
 pub use self::spu_config::Endpoint;
 pub use self::spu_config::Log;
+pub use self::spu_config::Replication;
 
 pub use self::spu_config_file::SpuConfigFile;
 
@@ -12,6 +12,7 @@ use std::io::ErrorKind;
 use std::net::SocketAddr;
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use log::debug;
 use log::error;
@@ -19,14 +20,19 @@ use log::error;
 // defaults values
 use types::defaults::{SPU_PUBLIC_HOSTNAME, SPU_PUBLIC_PORT};
 use types::defaults::{SPU_PRIVATE_HOSTNAME, SPU_PRIVATE_PORT};
+use types::defaults::{SPU_METRICS_HOSTNAME, SPU_METRICS_PORT};
 use types::defaults::{SC_HOSTNAME, SC_PRIVATE_PORT};
 use types::defaults::SPU_RETRY_SC_TIMEOUT_MS;
 use types::defaults::SPU_MIN_IN_SYNC_REPLICAS;
+use types::defaults::SPU_REPLICA_LAG_TIME_MAX_MS;
+use types::defaults::SPU_REPLICATION_THROTTLED_RATE;
+use types::defaults::SPU_REPLICATION_MAX_SYNC_BYTES;
 use types::defaults::SPU_LOG_BASE_DIR;
 use types::defaults::SPU_LOG_SIZE;
 use types::defaults::SPU_LOG_INDEX_MAX_BYTES;
 use types::defaults::SPU_LOG_INDEX_MAX_INTERVAL_BYTES;
 use types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
+use types::defaults::SPU_LOG_SEGMENT_MAX_AGE_MS;
 
 // environment variables
 use types::defaults::FLV_SPU_ID;
@@ -34,6 +40,7 @@ use types::defaults::FLV_SPU_TYPE;
 use types::defaults::FLV_RACK;
 use types::defaults::{FLV_SPU_PUBLIC_HOST, FLV_SPU_PUBLIC_PORT};
 use types::defaults::{FLV_SPU_PRIVATE_HOST, FLV_SPU_PRIVATE_PORT};
+use types::defaults::{FLV_SPU_METRICS_HOST, FLV_SPU_METRICS_PORT};
 use types::defaults::{FLV_SC_PRIVATE_HOST, FLV_SC_PRIVATE_PORT};
 use types::defaults::FLV_SC_RETRY_TIMEOUT_MS;
 use types::defaults::FLV_REPLICA_IN_SYNC_REPLICA_MIN;
@@ -42,11 +49,20 @@ use types::defaults::FLV_LOG_SIZE;
 use types::defaults::FLV_LOG_INDEX_MAX_BYTES;
 use types::defaults::FLV_LOG_INDEX_MAX_INTERVAL_BYTES;
 use types::defaults::FLV_LOG_SEGMENT_MAX_BYTES;
+use types::defaults::FLV_LOG_SEGMENT_MAX_AGE_MS;
 
 use types::SpuId;
 use types::socket_helpers::ServerAddress;
 use types::socket_helpers::server_to_socket_addr;
 use storage::ConfigOption;
+use storage::TierConfig;
+use kf_service::sasl::SaslConfig;
+use kf_service::sasl::SaslMechanism;
+use metadata::acl::AuthorizerConfig;
+use kf_service::KfServerConfig;
+
+use crate::core::ProducePolicy;
+use crate::core::TimestampType;
 
 use super::{SpuOpt, SpuConfigFile};
 
@@ -66,15 +82,31 @@ pub struct Endpoint {
 #[derive(Debug, PartialEq, Clone)]
 pub struct Replication {
     pub min_in_sync_replicas: u16,
+    pub lag_time_max_ms: u64,      // follower not caught up within this time is out of sync
+    pub throttled_rate: u64,       // bytes/sec to out of sync followers, 0 for unlimited
+    pub max_sync_bytes: u32,       // max record bytes per follower sync request
+}
+
+impl Default for Replication {
+    fn default() -> Self {
+        Self {
+            min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
+            lag_time_max_ms: SPU_REPLICA_LAG_TIME_MAX_MS,
+            throttled_rate: SPU_REPLICATION_THROTTLED_RATE,
+            max_sync_bytes: SPU_REPLICATION_MAX_SYNC_BYTES,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Log {
     pub base_dir: PathBuf,
+    pub dirs: Vec<PathBuf>,     // additional log directories
     pub size: String,
     pub index_max_bytes: u32,
     pub index_max_interval_bytes: u32,
     pub segment_max_bytes: u32,
+    pub segment_max_age_ms: u64,
 }
 
 impl Log {
@@ -86,7 +118,19 @@ impl Log {
             self.index_max_bytes, 
             self.index_max_interval_bytes, 
             self.segment_max_bytes
-        )     
+        )
+        .segment_max_age_ms(self.segment_max_age_ms)
+    }
+
+    /// all log directories, base directory is first
+    pub fn all_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = vec![self.base_dir.clone()];
+        for dir in &self.dirs {
+            if !dirs.contains(dir) {
+                dirs.push(dir.clone());
+            }
+        }
+        dirs
     }
 }
 
@@ -102,14 +146,30 @@ pub struct SpuConfig {
     // spu (local server) points
     pub public_endpoint: Endpoint,
     pub private_endpoint: Endpoint,
+    pub metrics_endpoint: Endpoint,
 
     // sc (remote server) endpoint
     pub sc_endpoint: ServerAddress,
+    // standby sc endpoints, tried in order when sc_endpoint is not reachable
+    pub sc_standby_endpoints: Vec<ServerAddress>,
     pub sc_retry_ms: u16,
 
     // parameters
     pub replication: Replication,
-    pub log: Log
+    pub log: Log,
+    pub produce: ProducePolicy,
+
+    // sasl authentication on public endpoint
+    pub sasl: SaslConfig,
+
+    // acl authorization on public endpoint
+    pub authorizer: AuthorizerConfig,
+
+    // frame, connection and request limits on public endpoint
+    pub public_server: KfServerConfig,
+
+    // offloading of closed segments to object store
+    pub tier: Option<TierConfig>,
 }
 
 
@@ -128,14 +188,24 @@ impl SpuConfig {
         let rack = SpuConfig::make_rack(&file_cfg)?;
         let public_endpoint = SpuConfig::make_public_endpoint(&cli_cfg, &file_cfg)?;
         let private_endpoint = SpuConfig::make_private_endpoint(&cli_cfg, &file_cfg)?;
+        let metrics_endpoint = SpuConfig::make_metrics_endpoint(&cli_cfg, &file_cfg)?;
         let sc_endpoint = SpuConfig::make_sc_endpoint(&cli_cfg, &file_cfg)?;
+        let sc_standby_endpoints = SpuConfig::make_sc_standby_endpoints(&file_cfg)?;
         let sc_retry_ms = SpuConfig::make_sc_retry_ms(&file_cfg)?;
         let min_in_sync_replicas = SpuConfig::make_min_in_sync_replicas(&file_cfg)?;
+        let replication = SpuConfig::make_replication(min_in_sync_replicas, &file_cfg);
         let log_base_dir = SpuConfig::make_log_base_dir(&file_cfg)?;
+        let log_dirs = SpuConfig::make_log_dirs(&file_cfg)?;
         let log_size = SpuConfig::make_log_size(&file_cfg)?;
         let log_index_max_bytes = SpuConfig::make_log_index_max_bytes(&file_cfg)?;
         let log_index_max_interval_bytes = SpuConfig::make_log_index_max_interval_bytes(&file_cfg)?;
         let log_segment_max_bytes = SpuConfig::make_log_segment_max_bytes(&file_cfg)?;
+        let log_segment_max_age_ms = SpuConfig::make_log_segment_max_age_ms(&file_cfg)?;
+        let produce = SpuConfig::make_produce(min_in_sync_replicas, &file_cfg)?;
+        let sasl = SpuConfig::make_sasl(&file_cfg)?;
+        let authorizer = SpuConfig::make_authorizer(&file_cfg);
+        let public_server = SpuConfig::make_public_server(&file_cfg);
+        let tier = SpuConfig::make_tier(&file_cfg)?;
 
         Ok(SpuConfig {
             id: spu_id,
@@ -143,18 +213,25 @@ impl SpuConfig {
             rack: rack,
             public_endpoint: public_endpoint,
             private_endpoint: private_endpoint,
+            metrics_endpoint: metrics_endpoint,
             sc_endpoint: sc_endpoint,
+            sc_standby_endpoints: sc_standby_endpoints,
             sc_retry_ms: sc_retry_ms,
-            replication: Replication {
-                min_in_sync_replicas: min_in_sync_replicas,
-            },
+            replication: replication,
             log: Log {
                 base_dir: log_base_dir,
+                dirs: log_dirs,
                 size: log_size,
                 index_max_bytes: log_index_max_bytes,
                 index_max_interval_bytes: log_index_max_interval_bytes,
                 segment_max_bytes: log_segment_max_bytes,
-            }
+                segment_max_age_ms: log_segment_max_age_ms,
+            },
+            produce: produce,
+            sasl: sasl,
+            authorizer: authorizer,
+            public_server: public_server,
+            tier: tier,
         })
     }
 
@@ -336,6 +413,45 @@ impl SpuConfig {
         Ok(ep)
     }
 
+    /// Generate metrics_endpoint by combining all config elements. Returns error on failure.
+    fn make_metrics_endpoint(
+        cli_cfg: &SpuOpt,
+        file_cfg: &Option<SpuConfigFile>,
+    ) -> Result<Endpoint, IoError> {
+        // 1) check cli and convert to server address
+        let mut metrics_ep = server_str_to_server_addr(&cli_cfg.metrics_server)?;
+
+        // 2) environment variable (optional field, ignore errors)
+        if metrics_ep.is_none() {
+            if let Ok(host) = env::var(FLV_SPU_METRICS_HOST) {
+                if let Ok(port_str) = env::var(FLV_SPU_METRICS_PORT) {
+                    let port: u16 = port_str.parse().map_err(|err| {
+                        IoError::new(
+                            ErrorKind::InvalidInput,
+                            format!("invalid env port: {}", err),
+                        )
+                    })?;
+                    metrics_ep = Some(ServerAddress { host, port });
+                }
+            }
+        }
+
+        // 3) config file
+        if metrics_ep.is_none() && file_cfg.is_some() {
+            metrics_ep = file_cfg.as_ref().unwrap().metrics_endpoint();
+        }
+
+        // 4) use default
+        if metrics_ep.is_none() {
+            let host = SPU_METRICS_HOSTNAME.to_owned();
+            let port = SPU_METRICS_PORT;
+            metrics_ep = Some(ServerAddress { host, port });
+        }
+
+        // 5) create endpoint
+        Endpoint::new(&metrics_ep.unwrap())
+    }
+
     /// Generate sc_endpoint by combining all config elements. Returns error on failure.
     fn make_sc_endpoint(
         cli_cfg: &SpuOpt,
@@ -384,6 +500,21 @@ impl SpuConfig {
         }
     }
 
+    /// Generate standby sc endpoints from config file. Returns error on failure.
+    fn make_sc_standby_endpoints(
+        file_cfg: &Option<SpuConfigFile>,
+    ) -> Result<Vec<ServerAddress>, IoError> {
+        let mut endpoints = vec![];
+        if let Some(standby) = file_cfg.as_ref().and_then(|cfg| cfg.controller_standby()) {
+            for server in standby {
+                if let Some(addr) = server_str_to_server_addr(&Some(server))? {
+                    endpoints.push(addr);
+                }
+            }
+        }
+        Ok(endpoints)
+    }
+
     /// Generate retry SC connection by combining all config elements. Returns error on failure.
     fn make_sc_retry_ms(file_cfg: &Option<SpuConfigFile>) -> Result<u16, IoError> {
         let mut sc_retry_ms = None;
@@ -447,6 +578,14 @@ impl SpuConfig {
         Ok(log_base_dir.unwrap_or(PathBuf::from(SPU_LOG_BASE_DIR)))
     }
 
+    /// Generate additional log dirs from config file
+    fn make_log_dirs(file_cfg: &Option<SpuConfigFile>) -> Result<Vec<PathBuf>, IoError> {
+        Ok(file_cfg
+            .as_ref()
+            .and_then(|cfg| cfg.log_dirs())
+            .unwrap_or_default())
+    }
+
     /// Generate log size by combining all config elements. Returns error on failure.
     fn make_log_size(file_cfg: &Option<SpuConfigFile>) -> Result<String, IoError> {
         let mut log_size = None;
@@ -539,6 +678,139 @@ impl SpuConfig {
         Ok(log_segment_max_bytes.unwrap_or(SPU_LOG_SEGMENT_MAX_BYTES))
     }
 
+    /// Generate log segment_max_age_ms by combining all config elements. Returns error on failure.
+    fn make_log_segment_max_age_ms(file_cfg: &Option<SpuConfigFile>) -> Result<u64, IoError> {
+        let mut log_segment_max_age_ms = None;
+
+        // 1) environment variable (optional field, ignore errors)
+        if let Ok(log_segment_max_age_ms_str) = env::var(FLV_LOG_SEGMENT_MAX_AGE_MS) {
+            let segment_max_age_ms: u64 = log_segment_max_age_ms_str.parse().map_err(|err| {
+                IoError::new(
+                    ErrorKind::InvalidInput,
+                    format!("log segment_max_age_ms: {}", err),
+                )
+            })?;
+            log_segment_max_age_ms = Some(segment_max_age_ms);
+        }
+
+        // 2) config file
+        if log_segment_max_age_ms.is_none() && file_cfg.is_some() {
+            log_segment_max_age_ms = file_cfg.as_ref().unwrap().log_segment_max_age_ms();
+        }
+
+        // 3) unwrap or use default
+        Ok(log_segment_max_age_ms.unwrap_or(SPU_LOG_SEGMENT_MAX_AGE_MS))
+    }
+
+    /// Generate replication settings, settings other than min in sync replicas are from config file only
+    fn make_replication(min_in_sync_replicas: u16, file_cfg: &Option<SpuConfigFile>) -> Replication {
+        let mut replication = Replication {
+            min_in_sync_replicas,
+            ..Default::default()
+        };
+
+        if let Some(file_cfg) = file_cfg {
+            if let Some(lag_time_max_ms) = file_cfg.replication_lag_time_max_ms() {
+                replication.lag_time_max_ms = lag_time_max_ms;
+            }
+            if let Some(throttled_rate) = file_cfg.replication_throttled_rate() {
+                replication.throttled_rate = throttled_rate;
+            }
+            if let Some(max_sync_bytes) = file_cfg.replication_max_sync_bytes() {
+                replication.max_sync_bytes = max_sync_bytes;
+            }
+        }
+
+        replication
+    }
+
+    /// Generate produce policy from config file or use defaults
+    fn make_produce(min_in_sync_replicas: u16, file_cfg: &Option<SpuConfigFile>) -> Result<ProducePolicy, IoError> {
+        let mut produce = ProducePolicy::default();
+        produce.min_in_sync_replicas = min_in_sync_replicas;
+
+        if let Some(file_cfg) = file_cfg {
+            if let Some(max_message_bytes) = file_cfg.produce_max_message_bytes() {
+                produce.max_message_bytes = max_message_bytes;
+            }
+            if let Some(name) = file_cfg.produce_timestamp_type() {
+                produce.timestamp_type = TimestampType::from_name(&name).ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!("produce timestamp_type: expected 'CreateTime' or 'LogAppendTime', found '{}'", name),
+                    )
+                })?;
+            }
+            if let Some(ms) = file_cfg.produce_timestamp_difference_max_ms() {
+                produce.timestamp_difference_max_ms = ms;
+            }
+        }
+
+        Ok(produce)
+    }
+
+    /// Generate sasl config from config file or use defaults (disabled)
+    fn make_sasl(file_cfg: &Option<SpuConfigFile>) -> Result<SaslConfig, IoError> {
+        let mut sasl = SaslConfig::default();
+
+        if let Some(file_cfg) = file_cfg {
+            if let Some(enabled) = file_cfg.sasl_enabled() {
+                sasl.enabled = enabled;
+            }
+            if let Some(mechanisms) = file_cfg.sasl_mechanisms() {
+                sasl.mechanisms = SaslMechanism::from_names(&mechanisms)?;
+            }
+        }
+
+        Ok(sasl)
+    }
+
+    /// Generate acl authorizer config from config file or use defaults (authorizer disabled)
+    fn make_authorizer(file_cfg: &Option<SpuConfigFile>) -> AuthorizerConfig {
+        let mut authorizer = AuthorizerConfig::default();
+
+        if let Some(file_cfg) = file_cfg {
+            if let Some(enabled) = file_cfg.authorizer_enabled() {
+                authorizer.enabled = enabled;
+            }
+            if let Some(super_users) = file_cfg.authorizer_super_users() {
+                authorizer.super_users = super_users;
+            }
+            if let Some(allow) = file_cfg.authorizer_allow_everyone_if_no_acl_found() {
+                authorizer.allow_everyone_if_no_acl_found = allow;
+            }
+        }
+
+        authorizer
+    }
+
+    /// Generate public server limits from config file or use defaults
+    fn make_public_server(file_cfg: &Option<SpuConfigFile>) -> KfServerConfig {
+        let mut public_server = KfServerConfig::default();
+
+        if let Some(file_cfg) = file_cfg {
+            if let Some(max_frame_size) = file_cfg.public_server_max_frame_size() {
+                public_server.max_frame_size = max_frame_size;
+            }
+            if let Some(max_connections) = file_cfg.public_server_max_connections() {
+                public_server.max_connections = Some(max_connections);
+            }
+            if let Some(idle_timeout_ms) = file_cfg.public_server_idle_timeout_ms() {
+                public_server.idle_timeout = Some(Duration::from_millis(idle_timeout_ms));
+            }
+            if let Some(max_requests_per_sec) = file_cfg.public_server_max_requests_per_sec() {
+                public_server.max_requests_per_sec = Some(max_requests_per_sec);
+            }
+        }
+
+        public_server
+    }
+
+    /// Generate tiered storage config, only available from config file
+    fn make_tier(file_cfg: &Option<SpuConfigFile>) -> Result<Option<TierConfig>, IoError> {
+        Ok(file_cfg.as_ref().and_then(|cfg| cfg.tier()))
+    }
+
     pub fn id(&self) -> SpuId {
         self.id
     }
@@ -565,6 +837,13 @@ impl SpuConfig {
         &self.sc_endpoint
     }
 
+    /// primary sc endpoint followed by standby endpoints
+    pub fn sc_endpoints(&self) -> Vec<&ServerAddress> {
+        let mut endpoints = vec![&self.sc_endpoint];
+        endpoints.extend(self.sc_standby_endpoints.iter());
+        endpoints
+    }
+
     pub fn public_socket_addr(&self) -> &SocketAddr {
         &self.public_endpoint.socket_addr
     }
@@ -581,6 +860,10 @@ impl SpuConfig {
         &self.public_endpoint.server_addr
     }
 
+    pub fn metrics_socket_addr(&self) -> &SocketAddr {
+        &self.metrics_endpoint.socket_addr
+    }
+
 
     pub fn storage(&self) -> &Log {
         &self.log
@@ -633,6 +916,7 @@ pub mod test {
    
     use super::*;
     use types::defaults::SPU_DEFAULT_ID;
+    use storage::ObjectStoreConfig;
 
     #[test]
     fn test_get_spu_config_with_secret() {
@@ -656,6 +940,12 @@ pub mod test {
         });
         assert!(private_endpoint_res.is_ok());
 
+        let metrics_endpoint_res = Endpoint::new(&ServerAddress {
+            host: SPU_METRICS_HOSTNAME.to_owned(),
+            port: SPU_METRICS_PORT,
+        });
+        assert!(metrics_endpoint_res.is_ok());
+
         let sc_endpoint_res = ServerAddress {
             host: SC_HOSTNAME.to_owned(),
             port: SC_PRIVATE_PORT,
@@ -668,18 +958,25 @@ pub mod test {
             rack: None,
             public_endpoint: public_endpoint_res.unwrap(),
             private_endpoint: private_endpoint_res.unwrap(),
+            metrics_endpoint: metrics_endpoint_res.unwrap(),
             sc_endpoint: sc_endpoint_res,
+            sc_standby_endpoints: vec![],
             sc_retry_ms: SPU_RETRY_SC_TIMEOUT_MS,
-            replication: Replication {
-                min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
-            },
+            replication: Replication::default(),
             log: Log {
                 base_dir: PathBuf::from(SPU_LOG_BASE_DIR),
+                dirs: vec![],
                 size: SPU_LOG_SIZE.to_owned(),
                 index_max_bytes: SPU_LOG_INDEX_MAX_BYTES,
                 index_max_interval_bytes: SPU_LOG_INDEX_MAX_INTERVAL_BYTES,
                 segment_max_bytes: SPU_LOG_SEGMENT_MAX_BYTES,
-            }
+                segment_max_age_ms: SPU_LOG_SEGMENT_MAX_AGE_MS,
+            },
+            produce: ProducePolicy::default(),
+            sasl: SaslConfig::default(),
+            authorizer: AuthorizerConfig::default(),
+            public_server: KfServerConfig::default(),
+            tier: None,
         };
 
         assert_eq!(result.unwrap(), expected);
@@ -710,6 +1007,12 @@ pub mod test {
         });
         assert!(private_endpoint_res.is_ok());
 
+        let metrics_endpoint_res = Endpoint::new(&ServerAddress {
+            host: "127.0.0.1".to_owned(),
+            port: 5557,
+        });
+        assert!(metrics_endpoint_res.is_ok());
+
         let sc_endpoint_res = ServerAddress {
             host: "127.0.0.1".to_owned(),
             port: 5554,
@@ -722,18 +1025,49 @@ pub mod test {
             rack: Some("rack-1".to_owned()),
             public_endpoint: public_endpoint_res.unwrap(),
             private_endpoint: private_endpoint_res.unwrap(),
+            metrics_endpoint: metrics_endpoint_res.unwrap(),
             sc_endpoint: sc_endpoint_res,
+            sc_standby_endpoints: vec![ServerAddress {
+                host: "127.0.0.1".to_owned(),
+                port: 5564,
+            }],
             sc_retry_ms: 2000,
             replication: Replication {
                 min_in_sync_replicas: 3,
+                lag_time_max_ms: 10000,
+                throttled_rate: 10485760,
+                max_sync_bytes: 524288,
             },
             log: Log {
                 base_dir: PathBuf::from("/tmp/data_streams"),
+                dirs: vec![PathBuf::from("/tmp/data_streams_2")],
                 size: "2Gi".to_owned(),
                 index_max_bytes: 888888,
                 index_max_interval_bytes: 2222,
                 segment_max_bytes: 9999999,
-            }
+                segment_max_age_ms: 3600000,
+            },
+            produce: ProducePolicy {
+                max_message_bytes: 2000000,
+                timestamp_type: TimestampType::LogAppendTime,
+                timestamp_difference_max_ms: 60000,
+                min_in_sync_replicas: 3,
+            },
+            sasl: SaslConfig::default(),
+            authorizer: AuthorizerConfig::default(),
+            public_server: KfServerConfig {
+                max_frame_size: 1048576,
+                max_connections: Some(500),
+                idle_timeout: Some(Duration::from_millis(600000)),
+                max_requests_per_sec: None,
+            },
+            tier: Some(TierConfig {
+                store: ObjectStoreConfig::Fs {
+                    path: PathBuf::from("/tmp/data_streams_tier"),
+                },
+                local_retention_ms: 600000,
+                cache_segments: 4,
+            }),
         };
 
         assert_eq!(result.unwrap(), expected);
@@ -747,6 +1081,7 @@ pub mod test {
             id: Some(9898),
             public_server: Some("1.1.1.1:8888".to_owned()),
             private_server: Some("2.2.2.2:9999".to_owned()),
+            metrics_server: Some("4.4.4.4:7777".to_owned()),
             sc_server: Some("3.3.3.3:5555".to_owned()),
             config_file: None,
         };
@@ -772,6 +1107,12 @@ pub mod test {
         });
         assert!(private_endpoint_res.is_ok());
 
+        let metrics_endpoint_res = Endpoint::new(&ServerAddress {
+            host: "4.4.4.4".to_owned(),
+            port: 7777,
+        });
+        assert!(metrics_endpoint_res.is_ok());
+
         let sc_endpoint_res = ServerAddress {
             host: "3.3.3.3".to_owned(),
             port: 5555,
@@ -784,18 +1125,49 @@ pub mod test {
             rack: Some("rack-1".to_owned()),
             public_endpoint: public_endpoint_res.unwrap(),
             private_endpoint: private_endpoint_res.unwrap(),
+            metrics_endpoint: metrics_endpoint_res.unwrap(),
             sc_endpoint: sc_endpoint_res,
+            sc_standby_endpoints: vec![ServerAddress {
+                host: "127.0.0.1".to_owned(),
+                port: 5564,
+            }],
             sc_retry_ms: 2000,
             replication: Replication {
                 min_in_sync_replicas: 3,
+                lag_time_max_ms: 10000,
+                throttled_rate: 10485760,
+                max_sync_bytes: 524288,
             },
             log: Log {
                 base_dir: PathBuf::from("/tmp/data_streams"),
+                dirs: vec![PathBuf::from("/tmp/data_streams_2")],
                 size: "2Gi".to_owned(),
                 index_max_bytes: 888888,
                 index_max_interval_bytes: 2222,
                 segment_max_bytes: 9999999,
-            }
+                segment_max_age_ms: 3600000,
+            },
+            produce: ProducePolicy {
+                max_message_bytes: 2000000,
+                timestamp_type: TimestampType::LogAppendTime,
+                timestamp_difference_max_ms: 60000,
+                min_in_sync_replicas: 3,
+            },
+            sasl: SaslConfig::default(),
+            authorizer: AuthorizerConfig::default(),
+            public_server: KfServerConfig {
+                max_frame_size: 1048576,
+                max_connections: Some(500),
+                idle_timeout: Some(Duration::from_millis(600000)),
+                max_requests_per_sec: None,
+            },
+            tier: Some(TierConfig {
+                store: ObjectStoreConfig::Fs {
+                    path: PathBuf::from("/tmp/data_streams_tier"),
+                },
+                local_retention_ms: 600000,
+                cache_segments: 4,
+            }),
         };
 
         assert_eq!(result.unwrap(), expected);
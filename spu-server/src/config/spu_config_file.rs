@@ -12,6 +12,7 @@ use std::path::PathBuf;
 
 use serde::Deserialize;
 use types::socket_helpers::ServerAddress;
+use storage::TierConfig;
 
 use types::defaults::SPU_CONFIG_FILE;
 use utils::config_helper::build_server_config_file_path;
@@ -27,6 +28,10 @@ pub struct SpuConfigFile {
     servers: Option<ServersGroup>,
     controller: Option<ControllerGroup>,
     configurations: Option<ConfigurationsGroup>,
+    sasl: Option<SaslGroup>,
+    authorizer: Option<AuthorizerGroup>,
+    public_server: Option<ServerLimitsGroup>,
+    tier: Option<TierConfig>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -39,6 +44,7 @@ struct SpuGroup {
 struct ServersGroup {
     pub public: Option<ServerGroup>,
     pub private: Option<ServerGroup>,
+    pub metrics: Option<ServerGroup>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -52,26 +58,61 @@ struct ControllerGroup {
     pub host: String,
     pub port: u16,
     pub retry_timeout_ms: Option<u16>,
+    pub standby: Option<Vec<String>>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
 struct ConfigurationsGroup {
     pub replication: Option<ReplicationGroup>,
     pub log: Option<LogGroup>,
+    pub produce: Option<ProduceGroup>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
 struct ReplicationGroup {
     pub min_in_sync_replicas: Option<u16>,
+    pub lag_time_max_ms: Option<u64>,
+    pub throttled_rate: Option<u64>,
+    pub max_sync_bytes: Option<u32>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
 struct LogGroup {
     pub base_dir: Option<PathBuf>,
+    pub dirs: Option<Vec<PathBuf>>,
     pub size: Option<String>,
     pub index_max_bytes: Option<u32>,
     pub index_max_interval_bytes: Option<u32>,
     pub segment_max_bytes: Option<u32>,
+    pub segment_max_age_ms: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct ProduceGroup {
+    pub max_message_bytes: Option<u32>,
+    pub timestamp_type: Option<String>,
+    pub timestamp_difference_max_ms: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct SaslGroup {
+    pub enabled: Option<bool>,
+    pub mechanisms: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct AuthorizerGroup {
+    pub enabled: Option<bool>,
+    pub super_users: Option<Vec<String>>,
+    pub allow_everyone_if_no_acl_found: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct ServerLimitsGroup {
+    pub max_frame_size: Option<usize>,
+    pub max_connections: Option<usize>,
+    pub idle_timeout_ms: Option<u64>,
+    pub max_requests_per_sec: Option<u32>,
 }
 
 // ---------------------------------------
@@ -135,6 +176,19 @@ impl SpuConfigFile {
         None
     }
 
+    /// Retrieve metrics endpoint or none
+    pub fn metrics_endpoint(&self) -> Option<ServerAddress> {
+        if let Some(ref servers_group) = &self.servers {
+            if let Some(ref server) = servers_group.metrics {
+                return Some(ServerAddress {
+                    host: server.host.clone(),
+                    port: server.port,
+                });
+            }
+        }
+        None
+    }
+
     /// Retrieve controller private endpoint or none
     pub fn controller_endpoint(&self) -> Option<ServerAddress> {
         if let Some(ref controller) = &self.controller {
@@ -145,6 +199,15 @@ impl SpuConfigFile {
         }
         None
     }
+
+    /// Retrieve standby controller endpoints ("host:port") or none
+    pub fn controller_standby(&self) -> Option<Vec<String>> {
+        if let Some(ref controller) = &self.controller {
+            return controller.standby.clone();
+        }
+        None
+    }
+
     /// Retrieve controller connection retry in miliseconds or none
     pub fn sc_retry_ms(&self) -> Option<u16> {
         if let Some(ref controller) = &self.controller {
@@ -163,6 +226,36 @@ impl SpuConfigFile {
         None
     }
 
+    /// Retrieve max time follower may lag before it is out of sync or none
+    pub fn replication_lag_time_max_ms(&self) -> Option<u64> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref replication_group) = &config_group.replication {
+                return replication_group.lag_time_max_ms.clone();
+            }
+        }
+        None
+    }
+
+    /// Retrieve replication throttled rate or none
+    pub fn replication_throttled_rate(&self) -> Option<u64> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref replication_group) = &config_group.replication {
+                return replication_group.throttled_rate.clone();
+            }
+        }
+        None
+    }
+
+    /// Retrieve max bytes per follower sync request or none
+    pub fn replication_max_sync_bytes(&self) -> Option<u32> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref replication_group) = &config_group.replication {
+                return replication_group.max_sync_bytes.clone();
+            }
+        }
+        None
+    }
+
     /// Retrieve log base directory or none
     pub fn log_base_dir(&self) -> Option<PathBuf> {
         if let Some(ref config_group) = &self.configurations {
@@ -173,6 +266,16 @@ impl SpuConfigFile {
         None
     }
 
+    /// Retrieve additional log directories or none
+    pub fn log_dirs(&self) -> Option<Vec<PathBuf>> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref log_group) = &config_group.log {
+                return log_group.dirs.clone();
+            }
+        }
+        None
+    }
+
     /// Retrieve log size or none
     pub fn log_size(&self) -> Option<String> {
         if let Some(ref config_group) = &self.configurations {
@@ -212,6 +315,111 @@ impl SpuConfigFile {
         }
         None
     }
+
+    /// Retrieve log segment max age in milliseconds or none
+    pub fn log_segment_max_age_ms(&self) -> Option<u64> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref log_group) = &config_group.log {
+                return log_group.segment_max_age_ms.clone();
+            }
+        }
+        None
+    }
+
+    /// Retrieve produce max message bytes or none
+    pub fn produce_max_message_bytes(&self) -> Option<u32> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref produce_group) = &config_group.produce {
+                return produce_group.max_message_bytes;
+            }
+        }
+        None
+    }
+
+    /// Retrieve produce timestamp type name or none
+    pub fn produce_timestamp_type(&self) -> Option<String> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref produce_group) = &config_group.produce {
+                return produce_group.timestamp_type.clone();
+            }
+        }
+        None
+    }
+
+    /// Retrieve produce timestamp difference max ms or none
+    pub fn produce_timestamp_difference_max_ms(&self) -> Option<i64> {
+        if let Some(ref config_group) = &self.configurations {
+            if let Some(ref produce_group) = &config_group.produce {
+                return produce_group.timestamp_difference_max_ms;
+            }
+        }
+        None
+    }
+
+    /// Retrieve sasl enabled flag or none
+    pub fn sasl_enabled(&self) -> Option<bool> {
+        if let Some(ref sasl_group) = &self.sasl {
+            return sasl_group.enabled;
+        }
+        None
+    }
+
+    /// Retrieve sasl mechanism names or none
+    pub fn sasl_mechanisms(&self) -> Option<Vec<String>> {
+        if let Some(ref sasl_group) = &self.sasl {
+            return sasl_group.mechanisms.clone();
+        }
+        None
+    }
+
+    /// Retrieve authorizer enabled flag or none
+    pub fn authorizer_enabled(&self) -> Option<bool> {
+        if let Some(ref authorizer_group) = &self.authorizer {
+            return authorizer_group.enabled;
+        }
+        None
+    }
+
+    /// Retrieve authorizer super users or none
+    pub fn authorizer_super_users(&self) -> Option<Vec<String>> {
+        if let Some(ref authorizer_group) = &self.authorizer {
+            return authorizer_group.super_users.clone();
+        }
+        None
+    }
+
+    /// Retrieve authorizer allow-if-no-acl flag or none
+    pub fn authorizer_allow_everyone_if_no_acl_found(&self) -> Option<bool> {
+        if let Some(ref authorizer_group) = &self.authorizer {
+            return authorizer_group.allow_everyone_if_no_acl_found;
+        }
+        None
+    }
+
+    /// Retrieve public server max frame size or none
+    pub fn public_server_max_frame_size(&self) -> Option<usize> {
+        self.public_server.as_ref().and_then(|group| group.max_frame_size)
+    }
+
+    /// Retrieve public server max connections or none
+    pub fn public_server_max_connections(&self) -> Option<usize> {
+        self.public_server.as_ref().and_then(|group| group.max_connections)
+    }
+
+    /// Retrieve public server idle timeout or none
+    pub fn public_server_idle_timeout_ms(&self) -> Option<u64> {
+        self.public_server.as_ref().and_then(|group| group.idle_timeout_ms)
+    }
+
+    /// Retrieve public server max requests per second or none
+    pub fn public_server_max_requests_per_sec(&self) -> Option<u32> {
+        self.public_server.as_ref().and_then(|group| group.max_requests_per_sec)
+    }
+
+    /// Retrieve tiered storage config or none
+    pub fn tier(&self) -> Option<TierConfig> {
+        self.tier.clone()
+    }
 }
 
 // ---------------------------------------
@@ -223,6 +431,7 @@ impl SpuConfigFile {
 #[cfg(test)]
 pub mod test {
     use types::defaults::CONFIG_FILE_EXTENTION;
+    use storage::ObjectStoreConfig;
     use super::*;
 
     /// Use a base path and defaults to stitch together th spu configuration file
@@ -263,23 +472,53 @@ pub mod test {
                     host: "127.0.0.1".to_owned(),
                     port: 5556,
                 }),
+                metrics: Some(ServerGroup {
+                    host: "127.0.0.1".to_owned(),
+                    port: 5557,
+                }),
             }),
             controller: Some(ControllerGroup {
                 host: "127.0.0.1".to_owned(),
                 port: 5554,
                 retry_timeout_ms: Some(2000),
+                standby: Some(vec!["127.0.0.1:5564".to_owned()]),
             }),
             configurations: Some(ConfigurationsGroup {
                 replication: Some(ReplicationGroup {
                     min_in_sync_replicas: Some(3),
+                    lag_time_max_ms: Some(10000),
+                    throttled_rate: Some(10485760),
+                    max_sync_bytes: Some(524288),
                 }),
                 log: Some(LogGroup {
                     base_dir: Some(PathBuf::from("/tmp/data_streams")),
+                    dirs: Some(vec![PathBuf::from("/tmp/data_streams_2")]),
                     size: Some("2Gi".to_owned()),
                     index_max_bytes: Some(888888),
                     index_max_interval_bytes: Some(2222),
                     segment_max_bytes: Some(9999999),
+                    segment_max_age_ms: Some(3600000),
                 }),
+                produce: Some(ProduceGroup {
+                    max_message_bytes: Some(2000000),
+                    timestamp_type: Some("LogAppendTime".to_owned()),
+                    timestamp_difference_max_ms: Some(60000),
+                }),
+            }),
+            sasl: None,
+            authorizer: None,
+            public_server: Some(ServerLimitsGroup {
+                max_frame_size: Some(1048576),
+                max_connections: Some(500),
+                idle_timeout_ms: Some(600000),
+                max_requests_per_sec: None,
+            }),
+            tier: Some(TierConfig {
+                store: ObjectStoreConfig::Fs {
+                    path: PathBuf::from("/tmp/data_streams_tier"),
+                },
+                local_retention_ms: 600000,
+                cache_segments: 4,
             }),
         };
         assert_eq!(result.unwrap(), expected);
@@ -306,8 +545,13 @@ pub mod test {
                 host: "1.1.1.1".to_owned(),
                 port: 2323,
                 retry_timeout_ms: None,
+                standby: None,
             }),
             configurations: None,
+            sasl: None,
+            authorizer: None,
+            public_server: None,
+            tier: None,
         };
         assert_eq!(result.unwrap(), expected);
     }
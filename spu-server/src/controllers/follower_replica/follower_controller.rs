@@ -9,6 +9,7 @@ use log::debug;
 
 
 use futures::channel::mpsc::Receiver;
+use futures::channel::oneshot::Sender;
 use futures::select;
 use futures::StreamExt;
 use futures::FutureExt;
@@ -23,13 +24,17 @@ use internal_api::messages::Replica;
 use types::SpuId;
 use types::log_on_err;
 use storage::FileReplica;
+use storage::StorageError;
 use metadata::spu::SpuSpec;
+use metadata::partition::ReplicaKey;
 
 
 use crate::controllers::leader_replica::UpdateOffsetRequest;
 use crate::services::internal::FetchStreamRequest;
 use crate::core::spus::SharedSpuLocalStore;
 use crate::core::SharedSpuConfig;
+use crate::core::storage::replica_config;
+use crate::core::storage::SharedLogDirs;
 
 use super::FollowerReplicaControllerCommand;
 use super::FollowerReplicaState;
@@ -48,7 +53,8 @@ pub struct ReplicaFollowerController<S> {
     spu_localstore: SharedSpuLocalStore,
     followers_state: SharedFollowersState<S>,
     receiver: Receiver<FollowerReplicaControllerCommand>,
-    config: SharedSpuConfig
+    config: SharedSpuConfig,
+    log_dirs: SharedLogDirs
 }
 
 impl <S>ReplicaFollowerController<S> {
@@ -59,14 +65,16 @@ impl <S>ReplicaFollowerController<S> {
         receiver: Receiver<FollowerReplicaControllerCommand>,
         spu_localstore: SharedSpuLocalStore,
         followers_state: SharedFollowersState<S>,
-        config: SharedSpuConfig
+        config: SharedSpuConfig,
+        log_dirs: SharedLogDirs
     ) -> Self {
         Self {
             leader_id,
             spu_localstore,
             receiver,
             followers_state,
-            config
+            config,
+            log_dirs
         }
     }
 }
@@ -141,6 +149,9 @@ impl ReplicaFollowerController<FileReplica> {
                             FollowerReplicaControllerCommand::UpdateReplica(replica) => {
                                 self.update_replica(replica).await;
                                 self.sync_all_offsets_to_leader(&mut sink).await;
+                            },
+                            FollowerReplicaControllerCommand::RemoveReplica(replica_key,confirm) => {
+                                self.remove_replica(&replica_key,confirm);
                             }
                         }
                     } else {
@@ -220,7 +231,8 @@ impl ReplicaFollowerController<FileReplica> {
                     if let Some(cmd) = msg {
                         match cmd {
                             FollowerReplicaControllerCommand::AddReplica(replica) => self.update_replica(replica).await,
-                            FollowerReplicaControllerCommand::UpdateReplica(replica) => self.update_replica(replica).await
+                            FollowerReplicaControllerCommand::UpdateReplica(replica) => self.update_replica(replica).await,
+                            FollowerReplicaControllerCommand::RemoveReplica(replica_key,confirm) => self.remove_replica(&replica_key,confirm)
                         }
                     } else {
                         error!("mailbox seems terminated, we should termina also");
@@ -272,19 +284,45 @@ impl ReplicaFollowerController<FileReplica> {
         debug!("received update replica {} from leader: {}",replica_msg,self.leader_id);
 
         let replica_key = replica_msg.id.clone();
-        if self.followers_state.has_replica(&replica_key) {
-            debug!("has already follower replica: {}, igoring",replica_key);
+        let log = &replica_config(&self.config.storage().new_config(), &replica_msg.config);
+        if let Some(mut follower_replica) = self.followers_state.get_mut_replica(&replica_key) {
+            debug!("has already follower replica: {}, updating config",replica_key);
+            follower_replica.mut_storage().update_option(log);
         } else {
-            let log = &self.config.storage().new_config();
+             let log = match self.log_dirs.replica_config(&replica_key, log) {
+                 Some(log) => log,
+                 None => {
+                     error!("no online log dir for follower replica: {}, replica is offline",replica_key);
+                     return;
+                 }
+             };
              match FollowerReplicaState::new(self.config.id(),replica_msg.leader,&replica_key,&log).await {
                  Ok(replica_state) => {
                     self.followers_state.insert_replica(replica_state);
                  },
-                 Err(err) => error!("error creating follower replica: {}, errr: {:#?}",replica_key,err)
+                 Err(err) => {
+                     error!("error creating follower replica: {}, errr: {:#?}",replica_key,err);
+                     if let StorageError::IoError(_) = err {
+                         self.log_dirs.mark_offline(&log.base_dir);
+                     }
+                 }
              }
         }
     }
 
+    /// remove replica from followers state and confirm, storage is closed when replica is dropped.
+    /// replica is not written while it is removed since writes are done by this controller
+    fn remove_replica(&self, replica_key: &ReplicaKey, confirm: Sender<()>) {
+
+        debug!("removing replica: {} from leader: {}",replica_key,self.leader_id);
+        if self.followers_state.remove_replica(&self.leader_id,replica_key).is_none() {
+            error!("there was no follower replica: {}",replica_key);
+        }
+        if confirm.send(()).is_err() {
+            error!("no one is waiting for removal of follower replica: {}",replica_key);
+        }
+    }
+
     /// send offset to leader, so it can chronize
     async fn sync_all_offsets_to_leader(&self, sink: &mut KfSink) {
 
@@ -15,11 +15,15 @@ pub use self::sync::PeerFilePartitionResponse;
 pub use self::sync::DefaultSyncRequest;
 pub use self::sync::FileSyncRequest;
 
+use futures::channel::oneshot::Sender;
+
 use internal_api::messages::Replica;
+use metadata::partition::ReplicaKey;
 
 
 #[derive(Debug)]
 pub enum FollowerReplicaControllerCommand {
     AddReplica(Replica),
-    UpdateReplica(Replica)
+    UpdateReplica(Replica),
+    RemoveReplica(ReplicaKey,Sender<()>)    // controller confirms when replica is removed
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+use std::cmp::max;
+use std::cmp::min;
 use std::sync::RwLock;
 use std::sync::Arc;
 use std::fmt::Debug;
@@ -7,6 +9,7 @@ use std::collections::HashSet;
 use log::debug;
 use log::trace;
 use log::error;
+use log::warn;
 use futures::channel::mpsc::Sender;
 use futures::channel::mpsc::Receiver;
 use futures::channel::mpsc::channel;
@@ -16,6 +19,8 @@ use chashmap::WriteGuard;
 
 use metadata::partition::ReplicaKey;
 use kf_protocol::api::DefaultRecords;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::Offset;
 use storage::FileReplica;
 use storage::ConfigOption;
 use storage::StorageError;
@@ -187,6 +192,31 @@ impl FollowersState<FileReplica> {
                 let replica_key = ReplicaKey::new(topic.clone(), rep_id);
                 trace!("sync request for replica: {}", replica_key);
                 if let Some(mut replica) = self.get_mut_replica(&replica_key) {
+                    // leader sends its end offset as last stable offset
+                    replica.set_leader_leo(partition_request.last_stable_offset);
+                    match partition_request.error_code {
+                        ErrorCode::None => {},
+                        ErrorCode::OffsetOutOfRange => {
+                            // records before leader's log start are gone, restart log from there
+                            let log_start_offset = partition_request.log_start_offset;
+                            warn!("follower replica: {} is behind leader log start: {}, truncating",replica_key,log_start_offset);
+                            if let Err(err) = replica
+                                .mut_storage()
+                                .reset_log_start_offset(log_start_offset)
+                                .await
+                            {
+                                error!("error truncating replica: {}, error: {}",replica_key, err);
+                                continue;
+                            }
+                            drop(replica);
+                            self.add_replica_offset_to(&replica_key, &mut offsets);
+                            continue;
+                        },
+                        error_code => {
+                            error!("leader sync error for replica: {}, error: {:#?}",replica_key, error_code);
+                            continue;
+                        }
+                    }
                     match replica.send_records(partition_request.records).await {
                         Ok(_) => {
                             trace!(
@@ -207,6 +237,15 @@ impl FollowersState<FileReplica> {
                             } else {
                                 trace!("replica: {} high watermark is not same as leader high watermark: {}",replica_key,end_offset);
                             }
+                            // follow leader's log start, it can't go beyond what we have
+                            let log_start_offset = min(partition_request.log_start_offset, end_offset);
+                            if let Err(err) = replica
+                                .mut_storage()
+                                .update_log_start_offset(log_start_offset)
+                                .await
+                            {
+                                error!("error updating replica log start offset: {}", err);
+                            }
                             drop(replica);
                             self.add_replica_offset_to(&replica_key, &mut offsets);
                         }
@@ -253,6 +292,8 @@ impl FollowersState<FileReplica> {
             replica_request.replica = replica_id.clone();
             replica_request.leo = storage.get_leo();
             replica_request.hw = storage.get_hw();
+            replica_request.size = storage.get_size() as i64;
+            replica_request.segments = storage.get_segment_count() as u32;
             offsets.replicas.push(replica_request);
         } else {
             error!(
@@ -269,11 +310,17 @@ impl FollowersState<FileReplica> {
 pub struct FollowerReplicaState<S> {
     leader: SpuId,
     replica: ReplicaKey,
-    storage: S
+    storage: S,
+    leader_leo: Offset,        // leader end offset from last sync, -1 if unknown
 }
 
 impl <S>FollowerReplicaState<S> {
 
+    /// update leader end offset received with sync
+    pub fn set_leader_leo(&mut self, leo: Offset) {
+        self.leader_leo = leo;
+    }
+
     pub fn storage(&self) -> &S {
         &self.storage
     }
@@ -287,6 +334,14 @@ impl <S>FollowerReplicaState<S> {
     }
 }
 
+impl <S>FollowerReplicaState<S> where S: ReplicaStorage {
+
+    /// number of offsets follower is behind leader end offset, 0 if leader end offset is not known yet
+    pub fn offset_lag(&self) -> Offset {
+        max(self.leader_leo - self.storage.get_leo(), 0)
+    }
+}
+
 impl FollowerReplicaState<FileReplica> 
 {
     pub async fn new<'a>(
@@ -303,6 +358,7 @@ impl FollowerReplicaState<FileReplica>
             leader,
             replica: replica.clone(),
             storage,
+            leader_leo: -1,
         })
     }
 
@@ -322,6 +378,19 @@ impl FollowerReplicaState<FileReplica>
 #[cfg(test)]
 mod test {
 
+    use std::env::temp_dir;
+
+    use future_helper::test_async;
+    use kf_protocol::api::ErrorCode;
+    use metadata::partition::ReplicaKey;
+    use storage::ConfigOption;
+    use storage::ReplicaStorage;
+    use storage::StorageError;
+    use utils::fixture::ensure_clean_dir;
+
+    use crate::controllers::follower_replica::DefaultSyncRequest;
+    use crate::controllers::follower_replica::sync::PeerFetchableTopicResponse;
+    use crate::controllers::follower_replica::sync::PeerFetchablePartitionResponse;
     use super::FollowerReplicaState;
     use super::FollowersState;
 
@@ -335,7 +404,8 @@ mod test {
         let f1 = FollowerReplicaState {
             leader: 10,
             replica: ("topic",0).into(),
-            storage: FakeStorage{}
+            storage: FakeStorage{},
+            leader_leo: -1,
         };
 
         let k1 = f1.replica.clone();
@@ -344,13 +414,15 @@ mod test {
         let f2 = FollowerReplicaState {
             leader: 20,
             replica: ("topic2",0).into(),
-            storage: FakeStorage{}
+            storage: FakeStorage{},
+            leader_leo: -1,
         };
 
         let f3 = FollowerReplicaState {
             leader: 10,
             replica: ("topic",1).into(),
-            storage: FakeStorage{}
+            storage: FakeStorage{},
+            leader_leo: -1,
         };
 
 
@@ -369,4 +441,41 @@ mod test {
         assert_eq!(old_state.leader,10);
     }
 
-}
\ No newline at end of file
+
+    #[test_async]
+    async fn test_follower_behind_log_start() -> Result<(), StorageError> {
+        let base_dir = temp_dir().join("test_follower_behind_log_start");
+        ensure_clean_dir(&base_dir);
+        let option = ConfigOption::default().base_dir(base_dir);
+        let replica_key: ReplicaKey = ("topic", 0).into();
+
+        let states = FollowersState::new();
+        states.insert_replica(FollowerReplicaState::new(5001, 5000, &replica_key, &option).await?);
+
+        // leader has removed records before 10 while follower is still at 0
+        let mut partition = PeerFetchablePartitionResponse::default();
+        partition.partition_index = 0;
+        partition.error_code = ErrorCode::OffsetOutOfRange;
+        partition.high_watermark = 12;
+        partition.last_stable_offset = 12;
+        partition.log_start_offset = 10;
+        let mut topic = PeerFetchableTopicResponse::default();
+        topic.name = "topic".to_owned();
+        topic.partitions.push(partition);
+        let mut request = DefaultSyncRequest::default();
+        request.topics.push(topic);
+
+        // follower truncates and reports new start so leader syncs from there
+        let offsets = states.send_records(request).await;
+        assert_eq!(offsets.replicas.len(), 1);
+        assert_eq!(offsets.replicas[0].leo, 10);
+        assert_eq!(offsets.replicas[0].hw, 10);
+
+        let replica = states.get_replica(&replica_key).expect("replica");
+        assert_eq!(replica.storage().get_log_start_offset(), 10);
+        assert_eq!(replica.storage().get_leo(), 10);
+        assert_eq!(replica.offset_lag(), 2);
+        Ok(())
+    }
+
+}
@@ -51,12 +51,15 @@ impl <R>fmt::Display for SyncRequest<R> where R: Encoder + Decoder + Debug + Dis
 
 
 
+/// first version of sync request which carries leader's log start offset
+pub const SYNC_LOG_START_MIN_VERSION: Version = 8;
+
 // Request trait
-// Note that DEFAULT_API_VERSION is 7 which is required in order to map all fields for file encoding
+// Note that DEFAULT_API_VERSION is at least 7 which is required in order to map all fields for file encoding
 // TODO: come up with unify encoding
 impl <R>Request for SyncRequest<R> where R: Encoder + Decoder + Debug  {
     const API_KEY: u16 = KfFollowerPeerApiEnum::SyncRecords as u16;
-    const DEFAULT_API_VERSION: i16 = 7;    
+    const DEFAULT_API_VERSION: i16 = SYNC_LOG_START_MIN_VERSION;    
     type Response = SyncResponse;
 }
 
@@ -111,6 +114,8 @@ where
     pub error_code: ErrorCode,
     pub high_watermark: i64,
     pub last_stable_offset: i64,
+    #[fluvio_kf(min_version = 8)]
+    pub log_start_offset: i64,
     pub records: R,
 }
 
@@ -119,7 +124,7 @@ impl <R>fmt::Display for PeerFetchablePartitionResponse<R> where
     R: Encoder + Decoder + Default + Debug + Display
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f,"p: {}, hw: {} {}",self.partition_index,self.high_watermark,self.records)       
+        write!(f,"p: {}, hw: {}, start: {} {}",self.partition_index,self.high_watermark,self.log_start_offset,self.records)       
     }
 }
 
@@ -152,6 +157,9 @@ impl FileWrite for PeerFilePartitionResponse {
         self.error_code.encode(src, version)?;
         self.high_watermark.encode(src, version)?;
         self.last_stable_offset.encode(src, version)?;
+        if version >= SYNC_LOG_START_MIN_VERSION {
+            self.log_start_offset.encode(src, version)?;
+        }
         self.records.file_encode(src, data, version)?;
         Ok(())
     }
@@ -176,7 +184,8 @@ impl SlicePartitionResponse for PeerFilePartitionResponse {
         self.last_stable_offset = offset;
     }
 
-    fn set_log_start_offset(&mut self, _offset: i64) {
+    fn set_log_start_offset(&mut self, offset: i64) {
+        self.log_start_offset = offset;
     }
 
 
@@ -1,4 +1,6 @@
 
+use futures::channel::oneshot::Sender;
+
 use internal_api::messages::Replica;
 use kf_protocol::api::Offset;
 use types::SpuId;
@@ -8,7 +10,9 @@ use types::SpuId;
 pub enum LeaderReplicaControllerCommand {
     UpdateReplicaFromSc(Replica),     
     EndOffsetUpdated,
-    FollowerOffsetUpdate(FollowerOffsetUpdate)
+    LogStartOffsetUpdated,
+    FollowerOffsetUpdate(FollowerOffsetUpdate),
+    Shutdown(Sender<()>)             // controller confirms when it has stopped
 }
 
 
@@ -16,7 +20,9 @@ pub enum LeaderReplicaControllerCommand {
 pub struct FollowerOffsetUpdate {
     pub follower_id: SpuId,
     pub leo: Offset,            // log end offset
-    pub hw: Offset              // high water mark
+    pub hw: Offset,             // high water mark
+    pub size: i64,              // bytes of follower log segments, -1 if unknown
+    pub segments: u32
 }
 
 impl FollowerOffsetUpdate {
@@ -28,7 +34,9 @@ impl FollowerOffsetUpdate {
         Self {
             follower_id,
             leo,
-            hw
+            hw,
+            size: -1,
+            segments: 0
         }
     }
 }
@@ -38,7 +46,9 @@ impl From<(SpuId,Offset,Offset)> for FollowerOffsetUpdate {
         FollowerOffsetUpdate {
             follower_id: value.0,
             leo: value.1,
-            hw: value.2
+            hw: value.2,
+            size: -1,
+            segments: 0
         }
     }
 }
\ No newline at end of file
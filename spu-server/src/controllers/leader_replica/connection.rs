@@ -82,7 +82,9 @@ async fn route_replica_offset(ctx: DefaultSharedGlobalContext,follower_id: SpuId
     let follower_update = FollowerOffsetUpdate {
         follower_id: follower_id,
         leo: replica.leo,
-        hw: replica.hw
+        hw: replica.hw,
+        size: replica.size,
+        segments: replica.segments
     };
             
     
@@ -1,9 +1,14 @@
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use log::debug;
 use log::trace;
 use log::warn;
+use log::error;
 use futures::channel::mpsc::Receiver;
 use futures::future::FutureExt;
 use futures::future::join;
@@ -18,11 +23,14 @@ use types::SpuId;
 use kf_socket::ExclusiveKfSink;
 
 use crate::core::SharedSpuSinks;
+use crate::config::Replication;
 
 
 use super::LeaderReplicaControllerCommand;
 use super::FollowerOffsetUpdate;
 use super::SharedReplicaLeadersState;
+use super::SharedReplicationThrottle;
+use super::replica_state::FollowerReplicaInfo;
 
 /// time for complete reconcillation with followers
 pub const FOLLOWER_RECONCILIATION_INTERVAL_SEC: u64 = 300; // 5 min
@@ -36,7 +44,11 @@ pub struct ReplicaLeaderController<S> {
     controller_receiver: Receiver<LeaderReplicaControllerCommand>,
     leaders_state: SharedReplicaLeadersState<S>,
     follower_sinks: SharedSpuSinks,
-    sc_sink: Arc<ExclusiveKfSink>
+    sc_sink: Arc<ExclusiveKfSink>,
+    throttle: SharedReplicationThrottle,
+    replication: Replication,
+    tiering: Arc<AtomicBool>,
+    deferred_syncs: Arc<Mutex<HashSet<SpuId>>>
 }
 
 impl <S>ReplicaLeaderController<S> {
@@ -48,7 +60,9 @@ impl <S>ReplicaLeaderController<S> {
         controller_receiver: Receiver<LeaderReplicaControllerCommand>,
         leaders_state: SharedReplicaLeadersState<S>,
         follower_sinks: SharedSpuSinks,
-        sc_sink: Arc<ExclusiveKfSink>
+        sc_sink: Arc<ExclusiveKfSink>,
+        throttle: SharedReplicationThrottle,
+        replication: Replication
        ) -> Self 
     {
         Self {
@@ -57,7 +71,11 @@ impl <S>ReplicaLeaderController<S> {
             controller_receiver,
             leaders_state,
             follower_sinks,
-            sc_sink
+            sc_sink,
+            throttle,
+            replication,
+            tiering: Arc::new(AtomicBool::new(false)),
+            deferred_syncs: Arc::new(Mutex::new(HashSet::new()))
         }
 
     }
@@ -83,8 +101,15 @@ impl ReplicaLeaderController<FileReplica>
             select! {
 
                 _ = (sleep(Duration::from_secs(FOLLOWER_RECONCILIATION_INTERVAL_SEC))).fuse() => {
-                    debug!("timer fired - kickoff follower reconcillation");
-                    self.sync_followers().await;
+                    debug!("timer fired - kickoff follower reconcillation and segment maintenance");
+                    self.roll_over_segment().await;
+                    let log_start_moved = self.enforce_retention().await;
+                    self.tier_segments();
+                    if log_start_moved {
+                        join(self.send_status_to_sc(),self.sync_all_followers()).await;
+                    } else {
+                        join(self.send_status_to_sc(),self.sync_followers()).await;
+                    }
                 },
 
                 controller_req = self.controller_receiver.next() => {
@@ -96,6 +121,11 @@ impl ReplicaLeaderController<FileReplica>
                                 join(self.send_status_to_sc(),self.sync_followers()).await;
                             },
 
+                            LeaderReplicaControllerCommand::LogStartOffsetUpdated => {
+                                debug!("leader replica: {} log start offset has updated, sync all followers",self.id);
+                                join(self.send_status_to_sc(),self.sync_all_followers()).await;
+                            },
+
                             LeaderReplicaControllerCommand::FollowerOffsetUpdate(offsets) => {
                                 debug!("Offset update from follower: {:#?} for leader: {}", offsets, self.id);
                                 self.update_follower_offsets(offsets).await;
@@ -103,6 +133,14 @@ impl ReplicaLeaderController<FileReplica>
 
                             LeaderReplicaControllerCommand::UpdateReplicaFromSc(replica) => {
                                 debug!("update replica from sc: {}",replica.id);
+                            },
+
+                            LeaderReplicaControllerCommand::Shutdown(confirm) => {
+                                debug!("shutting down leader controller: {}",self.id);
+                                if confirm.send(()).is_err() {
+                                    warn!("no one is waiting for shutdown of leader controller: {}",self.id);
+                                }
+                                return;
                             }
                         }
                     } else {
@@ -110,6 +148,7 @@ impl ReplicaLeaderController<FileReplica>
                             "mailbox has terminated for replica leader: {}, terminating loop",
                             self.id
                         );
+                        return;
                     }
                 }
             }
@@ -119,41 +158,177 @@ impl ReplicaLeaderController<FileReplica>
 
     /// update the follower offsets
     async fn update_follower_offsets(&self, offsets: FollowerOffsetUpdate)  {
-        if let Some(mut leader_replica) = self.leaders_state.get_mut_replica(&self.id) {
-            let follower_id = offsets.follower_id;
-            let (update_status,sync_follower) = leader_replica.update_follower_offsets(offsets);
-             
-            join(
-                async {
-                    if update_status {
-                        leader_replica.send_status_to_sc(&self.sc_sink).await;
-                    }
-                },
-                async {
-                    if let Some(follower_info) = sync_follower {
-                        leader_replica.sync_follower(
-                            &self.follower_sinks,
-                            follower_id,
-                            &follower_info).await;
-                    }
-                }
-            ).await;
-            
+        let follower_id = offsets.follower_id;
+        let (follower_to_sync,in_sync) = if let Some(mut leader_replica) = self.leaders_state.get_mut_replica(&self.id) {
+            let (update_status,follower_to_sync) = leader_replica.update_follower_offsets(offsets);
+            if update_status {
+                leader_replica.send_status_to_sc(&self.sc_sink).await;
+            }
+            (follower_to_sync,leader_replica.is_follower_in_sync(&follower_id,self.lag_time_max()))
         } else {
             warn!("no replica is found: {} for update follower offsets", self.id);
+            return;
+        };
+
+        if let Some(follower_info) = follower_to_sync {
+            let max_bytes = self.replication.max_sync_bytes;
+            if in_sync || self.throttle.rate() == 0 {
+                sync_follower(&self.leaders_state,&self.follower_sinks,&self.id,follower_id,&follower_info,max_bytes).await;
+                return;
+            }
+
+            // out of sync follower is paced by throttle
+            let delay = self.throttle.reserve(u64::from(max_bytes));
+            if delay == Duration::from_millis(0) {
+                let sent = sync_follower(&self.leaders_state,&self.follower_sinks,&self.id,follower_id,&follower_info,max_bytes).await;
+                self.throttle.release(u64::from(max_bytes).saturating_sub(sent as u64));
+                return;
+            }
+
+            // delayed sync runs in its own task so controller keeps serving other followers
+            if !self.deferred_syncs.lock().expect("deferred syncs lock").insert(follower_id) {
+                debug!("sync of follower: {} for leader: {} is already deferred",follower_id,self.id);
+                self.throttle.release(u64::from(max_bytes));
+                return;
+            }
+
+            debug!("throttling sync of follower: {} for leader: {}, delay: {:#?}",follower_id,self.id,delay);
+            let leaders_state = self.leaders_state.clone();
+            let follower_sinks = self.follower_sinks.clone();
+            let throttle = self.throttle.clone();
+            let deferred_syncs = self.deferred_syncs.clone();
+            let id = self.id.clone();
+            spawn(async move {
+                sleep(delay).await;
+                // follower may have caught up while waiting
+                let follower_info = leaders_state
+                    .get_replica(&id)
+                    .and_then(|leader_replica| leader_replica.followers(&follower_id))
+                    .unwrap_or(follower_info);
+                let sent = sync_follower(&leaders_state,&follower_sinks,&id,follower_id,&follower_info,max_bytes).await;
+                throttle.release(u64::from(max_bytes).saturating_sub(sent as u64));
+                deferred_syncs.lock().expect("deferred syncs lock").remove(&follower_id);
+            });
         }
     }
 
+    fn lag_time_max(&self) -> Duration {
+        Duration::from_millis(self.replication.lag_time_max_ms)
+    }
+
 
-    /// update the follower with my state
+    /// update the follower with my state.
+    /// when throttled, out of sync followers are synced on their offset updates
     async fn sync_followers(&self) {
-        if let Some(leader_replica) = self.leaders_state.get_replica(&self.id) {
-            leader_replica.sync_followers(&self.follower_sinks).await;
+        let syncs = if let Some(leader_replica) = self.leaders_state.get_replica(&self.id) {
+            let skip_lagging = if self.throttle.rate() > 0 {
+                Some(self.lag_time_max())
+            } else {
+                None
+            };
+            leader_replica.follower_syncs(self.replication.max_sync_bytes,skip_lagging).await
         } else {
             warn!("no replica is found: {} for sync followers", self.id);
+            return;
+        };
+
+        for sync in syncs {
+            sync.send(&self.follower_sinks).await;
         }
     }
 
+    /// push log start offset to all followers
+    async fn sync_all_followers(&self) {
+        let syncs = if let Some(leader_replica) = self.leaders_state.get_replica(&self.id) {
+            leader_replica.all_follower_syncs(self.replication.max_sync_bytes).await
+        } else {
+            warn!("no replica is found: {} for sync all followers", self.id);
+            return;
+        };
+
+        for sync in syncs {
+            sync.send(&self.follower_sinks).await;
+        }
+    }
+
+    /// roll active segment when it is older than max age, idle replica has no write to trigger it
+    async fn roll_over_segment(&self) {
+        if let Some(mut leader_replica) = self.leaders_state.get_mut_replica(&self.id) {
+            if let Err(err) = leader_replica.mut_storage().roll_over_if_expired().await {
+                error!("error rolling over segment for replica: {}, {}", self.id, err);
+            }
+        } else {
+            warn!("no replica is found: {} for segment roll over", self.id);
+        }
+    }
+
+    /// remove segments older than topic retention, return true if log start offset has moved
+    async fn enforce_retention(&self) -> bool {
+        if let Some(mut leader_replica) = self.leaders_state.get_mut_replica(&self.id) {
+            match leader_replica.mut_storage().enforce_retention().await {
+                Ok(moved) => moved,
+                Err(err) => {
+                    error!("error enforcing retention for replica: {}, {}", self.id, err);
+                    false
+                }
+            }
+        } else {
+            warn!("no replica is found: {} for retention", self.id);
+            false
+        }
+    }
+
+    /// offload closed segments to object store if tiering is enabled.
+    /// segments are uploaded by spawned task from snapshot, replica is only locked to apply result.
+    /// new upload is not started while previous one is running
+    fn tier_segments(&self) {
+        if self.tiering.load(Ordering::SeqCst) {
+            debug!("tiering of replica: {} is still running, skipping",self.id);
+            return;
+        }
+
+        let snapshot = match self.leaders_state.get_replica(&self.id) {
+            Some(leader_replica) => leader_replica.storage().tier_snapshot(),
+            None => {
+                warn!("no replica is found: {} for tiering segments", self.id);
+                return;
+            }
+        };
+
+        let snapshot = match snapshot {
+            Some(snapshot) => snapshot,
+            None => {
+                // nothing to upload, local copies may still be expired
+                if let Some(mut leader_replica) = self.leaders_state.get_mut_replica(&self.id) {
+                    if let Err(err) = leader_replica.mut_storage().remove_tiered_local_segments() {
+                        error!("error removing tiered segments for replica: {}, {}", self.id, err);
+                    }
+                }
+                return;
+            }
+        };
+
+        self.tiering.store(true, Ordering::SeqCst);
+        let tiering = self.tiering.clone();
+        let leaders_state = self.leaders_state.clone();
+        let id = self.id.clone();
+        spawn(async move {
+            match snapshot.upload().await {
+                Ok(tiered) => {
+                    if let Some(mut leader_replica) = leaders_state.get_mut_replica(&id) {
+                        if let Err(err) = leader_replica.mut_storage().apply_tiered(tiered) {
+                            error!("error applying tiered segments for replica: {}, {}", id, err);
+                        }
+                    } else {
+                        debug!("replica: {} was removed during tiering", id);
+                    }
+                },
+                Err(err) => error!("error tiering segments for replica: {}, {}", id, err)
+            }
+            tiering.store(false, Ordering::SeqCst);
+        });
+    }
+
     /// send status back to sc
     async fn send_status_to_sc(&self) {
 
@@ -168,4 +343,25 @@ impl ReplicaLeaderController<FileReplica>
 }
 
 
+/// send records to follower, return bytes sent
+async fn sync_follower(
+    leaders_state: &SharedReplicaLeadersState<FileReplica>,
+    follower_sinks: &SharedSpuSinks,
+    id: &ReplicaKey,
+    follower_id: SpuId,
+    follower_info: &FollowerReplicaInfo,
+    max_bytes: u32) -> usize
+{
+    let sync = if let Some(leader_replica) = leaders_state.get_replica(id) {
+        leader_replica.follower_sync(
+            follower_id,
+            follower_info,
+            max_bytes).await
+    } else {
+        warn!("no replica is found: {} for sync follower", id);
+        return 0;
+    };
 
+    // replica is released before remote segment is downloaded and records are sent
+    sync.send(follower_sinks).await
+}
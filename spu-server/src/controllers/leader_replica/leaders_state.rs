@@ -50,6 +50,11 @@ impl<S> ReplicaLeadersState<S> {
         Arc::new(Self::default())
     }
 
+    /// keys of all leader replicas
+    pub fn replica_keys(&self) -> Vec<ReplicaKey> {
+        self.mailboxes.read().keys().cloned().collect()
+    }
+
     pub fn has_replica(&self, key: &ReplicaKey) -> bool {
         self.replicas.contains_key(key)
     }
@@ -153,13 +158,19 @@ impl ReplicaLeadersState<FileReplica> {
         isolation: Isolation,
         response: &mut FilePartitionResponse
     ) {
-        if let Some(leader_replica) = self.get_replica(rep_id) {
+        let remote_read = if let Some(leader_replica) = self.get_replica(rep_id) {
             leader_replica
-                .read_records(offset, isolation, response)
-                .await;
+                .read_local_records(offset, isolation, response)
+                .await
         } else {
             warn!("no replica is found: {}", rep_id);
             response.error_code = ErrorCode::NotLeaderForPartition;
+            return;
+        };
+
+        // remote segment is downloaded without holding replica, so producers are not blocked
+        if let Some(remote_read) = remote_read {
+            remote_read.read(response).await;
         }
     }
 
@@ -188,6 +199,43 @@ impl ReplicaLeadersState<FileReplica> {
             Ok(false)
         }
     }
+
+    /// move log start offset of leader replica and notify the leader replica controller
+    /// offset of -1 means high watermark.
+    /// return new log start offset or error code
+    pub async fn delete_records(
+        &self,
+        rep_id: &ReplicaKey,
+        offset: Offset,
+    ) -> Result<Offset, ErrorCode> {
+
+        let log_start_offset = if let Some(mut leader_replica) = self.get_mut_replica(rep_id) {
+            let hw = leader_replica.hw();
+            let offset = if offset == -1 { hw } else { offset };
+            if offset < 0 || offset > hw {
+                debug!("delete offset: {} is out of range, hw: {} for: {}", offset, hw, rep_id);
+                return Err(ErrorCode::OffsetOutOfRange);
+            }
+
+            if let Err(err) = leader_replica.update_log_start_offset(offset).await {
+                error!("error: {} updating log start for: {}", err, rep_id);
+                return Err(ErrorCode::KafkaStorageError);
+            }
+            leader_replica.storage().get_log_start_offset()
+        } else {
+            warn!("no replica is found: {}", rep_id);
+            return Err(ErrorCode::NotLeaderForPartition);
+        };
+
+        if let Err(err) = self
+            .send_message(rep_id, LeaderReplicaControllerCommand::LogStartOffsetUpdated)
+            .await
+        {
+            error!("error: {} notifying leader controller: {}", err, rep_id);
+        }
+
+        Ok(log_start_offset)
+    }
 }
 
 /*
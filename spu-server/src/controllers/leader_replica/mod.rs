@@ -6,6 +6,7 @@ mod api_key;
 mod peer_api;
 mod update_offsets;
 mod actions;
+mod throttle;
 
 pub use self::leader_controller::ReplicaLeaderController;
 pub use leaders_state::ReplicaLeadersState;
@@ -18,3 +19,5 @@ pub use self::update_offsets::UpdateOffsetRequest;
 pub use self::update_offsets::ReplicaOffsetRequest;
 pub use self::actions::FollowerOffsetUpdate;
 pub use self::actions::LeaderReplicaControllerCommand;
+pub use self::throttle::ReplicationThrottle;
+pub use self::throttle::SharedReplicationThrottle;
@@ -1,4 +1,6 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::Instant;
 
 use log::debug;
 use log::trace;
@@ -7,12 +9,14 @@ use log::warn;
 
 use kf_socket::SinkPool;
 use kf_protocol::api::DefaultRecords;
+use kf_protocol::api::ErrorCode;
 use kf_protocol::api::Offset;
 use kf_protocol::api::RequestMessage;
 use kf_protocol::api::Isolation;
 use metadata::partition::ReplicaKey;
 use internal_api::messages::Replica;
 use internal_api::UpdateLrsRequest;
+use metadata::partition::ReplicaStatus;
 use storage::FileReplica;
 use storage::ConfigOption;
 use storage::StorageError;
@@ -20,6 +24,7 @@ use types::SpuId;
 use types::log_on_err;
 use storage::SlicePartitionResponse;
 use storage::ReplicaStorage;
+use storage::RemoteRead;
 use kf_socket::ExclusiveKfSink;
 
 use crate::core::storage::create_replica_storage;
@@ -33,6 +38,91 @@ use super::FollowerOffsetUpdate;
 pub struct FollowerReplicaInfo {
     hw: Offset,
     leo: Offset,
+    size: i64,          // disk usage reported by follower, -1 if unknown
+    segments: u32,
+}
+
+
+/// Records for follower, read while holding replica.
+/// Remote segment is downloaded and records are sent to follower without holding replica
+pub struct FollowerSync {
+    leader_id: SpuId,
+    replica_id: ReplicaKey,
+    follower_id: SpuId,
+    follower_leo: Offset,
+    leo: Offset,
+    hw: Offset,
+    partition_response: PeerFilePartitionResponse,
+    remote_read: Option<RemoteRead>,
+}
+
+impl FollowerSync {
+
+    /// send records to follower, return bytes of records sent
+    pub async fn send(mut self, sinks: &SinkPool<SpuId>) -> usize {
+        let follower_id = self.follower_id;
+        if let Some(mut sink) = sinks.get_sink(&follower_id) {
+
+            trace!(
+                "sink is found for follower: {}, ready to build sync records",
+                follower_id
+            );
+            if let Some(remote_read) = self.remote_read.take() {
+                remote_read.read(&mut self.partition_response).await;
+            }
+            let mut partition_response = self.partition_response;
+            match partition_response.error_code {
+                ErrorCode::None => {},
+                ErrorCode::OffsetOutOfRange if self.follower_leo < partition_response.log_start_offset => {
+                    // follower must truncate and continue from log start
+                    debug!(
+                        "follower: {} leo: {} is behind log start: {}, sending log start",
+                        follower_id,
+                        self.follower_leo,
+                        partition_response.log_start_offset
+                    );
+                },
+                error_code => {
+                    error!(
+                        "error reading records for follower: {}, replica: {}, error: {:#?}",
+                        follower_id,
+                        self.replica_id,
+                        error_code
+                    );
+                    return 0;
+                }
+            }
+            let records_bytes = partition_response.records.len();
+            partition_response.last_stable_offset = self.leo;
+            partition_response.high_watermark = self.hw;
+            let mut topic_response = PeerFileTopicReponse::default();
+            topic_response.name = self.replica_id.topic.to_owned();
+            topic_response.partitions.push(partition_response);
+            let mut sync_request = FileSyncRequest::default();
+            sync_request.topics.push(topic_response);
+
+            let request = RequestMessage::new_request(sync_request).set_client_id(format!(
+                "leader: {}, replica: {}",
+                self.leader_id, self.replica_id
+            ));
+            debug!(
+                "sending records follower: {}, response: {}",
+                follower_id,
+                request
+            );
+            if let Err(err) = sink
+                .encode_file_slices(&request, request.header.api_version())
+                .await
+            {
+                error!("error sending file slice: {:#?}", err);
+                return 0;
+            }
+            records_bytes
+        } else {
+            warn!("no sink exits for follower: {}, skipping ", follower_id);
+            0
+        }
+    }
 }
 
 
@@ -40,7 +130,9 @@ impl Default for FollowerReplicaInfo {
     fn default() -> Self {
         Self {
             hw: -1,
-            leo: -1
+            leo: -1,
+            size: -1,
+            segments: 0
         }
     }
 }
@@ -52,9 +144,17 @@ impl FollowerReplicaInfo {
         Self {
             leo,
             hw,
+            size: -1,
+            segments: 0
         }
     }
 
+    pub fn set_usage(mut self, size: i64, segments: u32) -> Self {
+        self.size = size;
+        self.segments = segments;
+        self
+    }
+
     pub fn hw(&self) -> Offset {
         self.hw
     }
@@ -89,6 +189,7 @@ pub struct LeaderReplicaState<S> {
     replica_id: ReplicaKey,
     leader_id: SpuId,
     followers: BTreeMap<SpuId,FollowerReplicaInfo>,
+    caught_up: BTreeMap<SpuId,Instant>,   // last time follower was at leader end offset
     storage: S,
 }
 
@@ -102,6 +203,7 @@ impl<S> LeaderReplicaState<S> {
             replica_id: replica_id.into(),
             leader_id,
             followers: BTreeMap::new(),
+            caught_up: BTreeMap::new(),
             storage,
         };
         state.add_follower_replica(follower_ids);
@@ -131,6 +233,14 @@ impl<S> LeaderReplicaState<S> {
     }
 
 
+    /// follower is in sync if it has caught up with leader within lag time
+    pub fn is_follower_in_sync(&self, follower_id: &SpuId, lag_time: Duration) -> bool {
+        self.caught_up
+            .get(follower_id)
+            .map(|caught_up| caught_up.elapsed() <= lag_time)
+            .unwrap_or(false)
+    }
+
     /// if replica id's doesn't exists, then add, otherwise ignore it
     fn add_follower_replica(&mut self, follower_ids: Vec<SpuId>) {
        
@@ -167,6 +277,24 @@ where
         self.storage.get_hw()
     }
 
+    /// end offset lag of each follower, follower which has not reported offsets lags by leader end offset
+    pub fn follower_lags(&self) -> Vec<(SpuId, Offset)> {
+        let leo = self.leo();
+        self.followers
+            .iter()
+            .map(|(follower_id, follower_info)| (*follower_id, leo - follower_info.leo.max(0)))
+            .collect()
+    }
+
+    /// number of replicas in sync including leader
+    pub fn in_sync_replica_count(&self, lag_time: Duration) -> usize {
+        1 + self
+            .followers
+            .keys()
+            .filter(|follower_id| self.is_follower_in_sync(follower_id, lag_time))
+            .count()
+    }
+
     /// update followers offset, return (status_needs_to_changed,follower to be synced)
     /// 
     /// // case 1:  follower offset has same value as leader
@@ -191,7 +319,8 @@ where
         // if update offset is greater than leader than something is wrong, in this case
         // we truncate the the follower offset
         let follower_id = follower_offset.follower_id;
-        let mut follower_info = FollowerReplicaInfo::new(follower_offset.leo,follower_offset.hw);
+        let mut follower_info = FollowerReplicaInfo::new(follower_offset.leo,follower_offset.hw)
+            .set_usage(follower_offset.size,follower_offset.segments);
 
         let leader_leo = self.leo();
         let leader_hw = self.hw();
@@ -201,6 +330,10 @@ where
             follower_info.leo = leader_leo;
         }
 
+        if follower_info.leo == leader_leo {
+            self.caught_up.insert(follower_id, Instant::now());
+        }
+
         let changed = 
             if let Some(old_info) = self.followers.insert(follower_id,follower_info.clone()) {
                 old_info != follower_info
@@ -241,9 +374,12 @@ where
     /// convert myself as
     fn as_lrs_request(&self) -> UpdateLrsRequest {
 
-        let leader = (self.leader_id,self.storage.get_hw(),self.storage.get_leo()).into();
+        // follower disk usage is reported by followers with their offsets
+        let leader = ReplicaStatus::new(self.leader_id,self.storage.get_hw(),self.storage.get_leo())
+            .set_usage(self.storage.get_size() as i64,self.storage.get_segment_count() as u32);
         let replicas = self.followers.iter()
-            .map(|(follower_id,follower_info)| (*follower_id,follower_info.hw(),follower_info.leo()).into())
+            .map(|(follower_id,follower_info)| ReplicaStatus::new(*follower_id,follower_info.hw(),follower_info.leo())
+                .set_usage(follower_info.size,follower_info.segments))
             .collect();
 
         UpdateLrsRequest::new(self.replica_id.clone(),leader,replicas)
@@ -286,59 +422,75 @@ impl LeaderReplicaState<FileReplica> {
     }
 
 
-    /// sync specific follower
-    pub async fn sync_follower(&self, sinks: &SinkPool<SpuId>,follower_id: SpuId,follower_info: &FollowerReplicaInfo) {
+    /// read records for follower up to max bytes.
+    /// records are sent with `FollowerSync::send` after replica is released
+    pub async fn follower_sync(&self, follower_id: SpuId, follower_info: &FollowerReplicaInfo, max_bytes: u32) -> FollowerSync {
+        let mut partition_response = PeerFilePartitionResponse::default();
+        partition_response.partition_index = self.replica_id.partition;
+        let remote_read = self.storage.read_local_records_max_bytes(
+                follower_info.leo,
+                None,
+                Some(max_bytes),
+                &mut partition_response,
+            ).await;
+
+        FollowerSync {
+            leader_id: self.leader_id,
+            replica_id: self.replica_id.clone(),
+            follower_id,
+            follower_leo: follower_info.leo,
+            leo: self.leo(),
+            hw: self.hw(),
+            partition_response,
+            remote_read,
+        }
+    }
 
-        if let Some(mut sink) = sinks.get_sink(&follower_id) {
+    /// read records for followers which need update.
+    /// if `skip_lagging` is set, followers out of sync for the lag time are skipped,
+    /// their sync is paced by throttle when they update offsets
+    pub async fn follower_syncs(&self, max_bytes: u32, skip_lagging: Option<Duration>) -> Vec<FollowerSync> {
 
-            trace!(
-                "sink is found for follower: {}, ready to build sync records",
-                follower_id
-            );
-            let mut sync_request = FileSyncRequest::default();
-            let mut topic_response = PeerFileTopicReponse::default();
-            topic_response.name = self.replica_id.topic.to_owned();
-            let mut partition_response = PeerFilePartitionResponse::default();
-            partition_response.partition_index = self.replica_id.partition;
-            self.read_records(
-                    follower_info.leo,
-                    Isolation::ReadUncommitted,
-                    &mut partition_response,
-                ).await;
-            partition_response.last_stable_offset = self.leo();
-            partition_response.high_watermark = self.hw();
-            topic_response.partitions.push(partition_response);
-            sync_request.topics.push(topic_response);
+        let follower_sync = self.need_follower_updates();
 
-            let request = RequestMessage::new_request(sync_request).set_client_id(format!(
-                "leader: {}, replica: {}",
-                self.leader_id, self.replica_id
-            ));
-            debug!(
-                "sending records follower: {}, response: {}",
-                follower_id,
-                request
-            );
-            if let Err(err) = sink
-                .encode_file_slices(&request, request.header.api_version())
-                .await
-            {
-                error!("error sending file slice: {:#?}", err);
+        let mut syncs = vec![];
+        for (follower_id, follower_info) in follower_sync {
+            if let Some(lag_time) = skip_lagging {
+                if !self.is_follower_in_sync(&follower_id, lag_time) {
+                    trace!("follower: {} is out of sync, skipping throttled sync for: {}", follower_id, self.replica_id);
+                    continue;
+                }
             }
-        } else {
-            warn!("no sink exits for follower: {}, skipping ", follower_id);
+            syncs.push(self.follower_sync(follower_id,&follower_info,max_bytes).await);
         }
-
+        syncs
     }
 
-    /// synchronize 
-    pub async fn sync_followers(&self, sinks: &SinkPool<SpuId>) {
+    /// read records for all valid followers even if they are caught up.
+    /// used when log start offset has moved without new records
+    pub async fn all_follower_syncs(&self, max_bytes: u32) -> Vec<FollowerSync> {
 
-        let follower_sync = self.need_follower_updates();
+        let followers: Vec<(SpuId, FollowerReplicaInfo)> = self.followers.iter()
+            .filter(|(_, follower_info)| follower_info.is_valid())
+            .map(|(follower_id, follower_info)| (*follower_id, follower_info.clone()))
+            .collect();
 
-        for (follower_id, follower_info) in follower_sync {
-            self.sync_follower(sinks,follower_id,&follower_info).await;
+        let mut syncs = vec![];
+        for (follower_id, follower_info) in followers {
+            syncs.push(self.follower_sync(follower_id,&follower_info,max_bytes).await);
         }
+        syncs
+    }
+
+    /// move log start offset forward, offset must not be beyond high watermark
+    pub async fn update_log_start_offset(&mut self, offset: Offset) -> Result<(), StorageError> {
+        trace!(
+            "updating log start offset: {} for leader: {} replica: {}",
+            offset,
+            self.leader_id,
+            self.replica_id
+        );
+        self.storage.update_log_start_offset(offset).await
     }
 
     pub async fn read_records<P>(
@@ -352,6 +504,19 @@ impl LeaderReplicaState<FileReplica> {
         self.storage.read_records_with_isolation(offset,isolation,partition_response).await
     }
 
+    /// read records from local segments, records in remote segment are returned as remote read
+    /// to be completed after replica is released
+    pub async fn read_local_records<P>(
+        &self,
+        offset: Offset,
+        isolation: Isolation,
+        partition_response: &mut P,
+    ) -> Option<RemoteRead> where
+        P: SlicePartitionResponse,
+    {
+        self.storage.read_local_records_with_isolation(offset,isolation,partition_response).await
+    }
+
     pub async fn send_records(
         &mut self,
         records: DefaultRecords,
@@ -380,10 +545,13 @@ impl LeaderReplicaState<FileReplica> {
 #[cfg(test)]
 mod test {
 
+    use std::time::Duration;
+
     use storage::ReplicaStorage;
     use kf_protocol::api::Offset;
 
     use super::LeaderReplicaState;
+    use super::FollowerOffsetUpdate;
 
     struct MockReplica {
         hw: Offset,
@@ -408,6 +576,14 @@ mod test {
         fn get_leo(&self) -> Offset {
             self.leo
         }
+
+        fn get_size(&self) -> u64 {
+            0
+        }
+
+        fn get_segment_count(&self) -> usize {
+            1
+        }
     }
 
     #[test]
@@ -437,6 +613,55 @@ mod test {
          assert_eq!(replica_state.update_follower_offsets((5001,20,10)),(false,None));
     }
 
+    #[test]
+    fn test_follower_in_sync() {
+        utils::init_logger();
+        let mock_replica = MockReplica::new(20, 10); // eof, hw
+        let lag_time = Duration::from_secs(10);
+
+        let mut replica_state = LeaderReplicaState::new(("test", 1), 5000, mock_replica, vec![5001]);
+        // follower which never caught up is out of sync
+        assert!(!replica_state.is_follower_in_sync(&5001, lag_time));
+
+        replica_state.update_follower_offsets((5001,10,10));
+        assert!(!replica_state.is_follower_in_sync(&5001, lag_time));
+
+        // caught up with leader end offset
+        replica_state.update_follower_offsets((5001,20,10));
+        assert!(replica_state.is_follower_in_sync(&5001, lag_time));
+
+        // falling behind is in sync until lag time passes
+        replica_state.mut_storage().leo = 30;
+        replica_state.update_follower_offsets((5001,20,10));
+        assert!(replica_state.is_follower_in_sync(&5001, lag_time));
+        assert_eq!(replica_state.in_sync_replica_count(lag_time), 2);
+        assert_eq!(replica_state.follower_lags(), vec![(5001, 10)]);
+    }
+
+
+    #[test]
+    fn test_follower_usage_in_lrs() {
+        utils::init_logger();
+        let mock_replica = MockReplica::new(20, 10); // eof, hw
+
+        let mut replica_state = LeaderReplicaState::new(("test", 1), 5000, mock_replica, vec![5001]);
+        let mut update = FollowerOffsetUpdate::new(5001, 10, 10);
+        update.size = 300;
+        update.segments = 2;
+        assert_eq!(replica_state.update_follower_offsets(update).0, true);
+
+        let lrs = replica_state.as_lrs_request();
+        assert_eq!(lrs.replicas.len(), 1);
+        assert_eq!(lrs.replicas[0].size, 300);
+        assert_eq!(lrs.replicas[0].segments, 2);
+
+        // usage change alone is reported
+        let mut update = FollowerOffsetUpdate::new(5001, 10, 10);
+        update.size = 400;
+        update.segments = 2;
+        assert_eq!(replica_state.update_follower_offsets(update).0, true);
+    }
+
 
     #[test]
     fn test_leader_update() {
@@ -0,0 +1,152 @@
+//!
+//! # Replication Throttle
+//!
+//! Bandwidth limit of SPU for records sent to followers which are out of sync.
+//! Shared by all leader replicas of SPU. Rate from config can be overridden by SC at runtime.
+//!
+//! Token bucket holding up to one second of rate. Sync reserves max bytes of request
+//! before reading records, unused bytes are returned after request is sent.
+//!
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::debug;
+
+use utils::now_ms;
+
+pub type SharedReplicationThrottle = Arc<ReplicationThrottle>;
+
+#[derive(Debug)]
+struct Bucket {
+    override_rate: Option<u64>,
+    tokens: f64,
+    last_refill_ms: u64,
+}
+
+#[derive(Debug)]
+pub struct ReplicationThrottle {
+    config_rate: u64,
+    bucket: Mutex<Bucket>,
+}
+
+impl ReplicationThrottle {
+    /// rate in bytes/sec, 0 for unlimited
+    pub fn new(config_rate: u64) -> Self {
+        Self {
+            config_rate,
+            bucket: Mutex::new(Bucket {
+                override_rate: None,
+                tokens: config_rate as f64,
+                last_refill_ms: now_ms() as u64,
+            }),
+        }
+    }
+
+    pub fn new_shared(config_rate: u64) -> SharedReplicationThrottle {
+        Arc::new(Self::new(config_rate))
+    }
+
+    /// override rate from config, none reverts to config
+    pub fn set_override_rate(&self, rate: Option<u64>) {
+        debug!("replication throttle override rate: {:?}, config rate: {}", rate, self.config_rate);
+        let mut bucket = self.bucket.lock().expect("throttle lock");
+        bucket.override_rate = rate;
+        let rate = bucket.override_rate.unwrap_or(self.config_rate) as f64;
+        bucket.tokens = bucket.tokens.min(rate);
+    }
+
+    /// current rate in bytes/sec, 0 for unlimited
+    pub fn rate(&self) -> u64 {
+        let bucket = self.bucket.lock().expect("throttle lock");
+        bucket.override_rate.unwrap_or(self.config_rate)
+    }
+
+    /// reserve bytes and return how long to wait before sending
+    pub fn reserve(&self, bytes: u64) -> Duration {
+        Duration::from_millis(self.reserve_at(bytes, now_ms() as u64))
+    }
+
+    /// return reserved bytes which were not sent
+    pub fn release(&self, bytes: u64) {
+        self.release_at(bytes, now_ms() as u64)
+    }
+
+    fn reserve_at(&self, bytes: u64, now_ms: u64) -> u64 {
+        let mut bucket = self.bucket.lock().expect("throttle lock");
+        let rate = bucket.override_rate.unwrap_or(self.config_rate);
+        if rate == 0 {
+            return 0;
+        }
+        bucket.refill(rate, now_ms);
+        bucket.tokens -= bytes as f64;
+        if bucket.tokens >= 0.0 {
+            0
+        } else {
+            (-bucket.tokens * 1000.0 / rate as f64).ceil() as u64
+        }
+    }
+
+    fn release_at(&self, bytes: u64, now_ms: u64) {
+        let mut bucket = self.bucket.lock().expect("throttle lock");
+        let rate = bucket.override_rate.unwrap_or(self.config_rate);
+        if rate == 0 {
+            return;
+        }
+        bucket.refill(rate, now_ms);
+        bucket.tokens = (bucket.tokens + bytes as f64).min(rate as f64);
+    }
+}
+
+impl Bucket {
+    fn refill(&mut self, rate: u64, now_ms: u64) {
+        let elapsed_ms = now_ms.saturating_sub(self.last_refill_ms);
+        self.tokens = (self.tokens + rate as f64 * elapsed_ms as f64 / 1000.0).min(rate as f64);
+        self.last_refill_ms = now_ms;
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::ReplicationThrottle;
+
+    #[test]
+    fn test_unlimited() {
+        let throttle = ReplicationThrottle::new(0);
+        assert_eq!(throttle.reserve_at(1_000_000_000, 0), 0);
+    }
+
+    #[test]
+    fn test_reserve_and_release() {
+        let throttle = ReplicationThrottle::new(1000);
+        throttle.bucket.lock().unwrap().last_refill_ms = 0;
+
+        // one second burst is available
+        assert_eq!(throttle.reserve_at(1000, 0), 0);
+        assert_eq!(throttle.reserve_at(500, 0), 500);
+
+        // unused bytes are returned
+        throttle.release_at(500, 0);
+        assert_eq!(throttle.reserve_at(100, 0), 100);
+
+        // refilled over time
+        assert_eq!(throttle.reserve_at(100, 300), 0);
+    }
+
+    #[test]
+    fn test_override_rate() {
+        let throttle = ReplicationThrottle::new(1000);
+        throttle.bucket.lock().unwrap().last_refill_ms = 0;
+
+        throttle.set_override_rate(Some(100));
+        assert_eq!(throttle.rate(), 100);
+        assert_eq!(throttle.reserve_at(200, 0), 1000);
+
+        throttle.set_override_rate(Some(0));
+        assert_eq!(throttle.reserve_at(200, 0), 0);
+
+        throttle.set_override_rate(None);
+        assert_eq!(throttle.rate(), 1000);
+    }
+}
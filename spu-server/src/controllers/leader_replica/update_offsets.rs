@@ -13,15 +13,31 @@ pub struct UpdateOffsetRequest {
 
 impl Request for UpdateOffsetRequest {
     const API_KEY: u16 = KfLeaderPeerApiEnum::UpdateOffsets as u16;
-    const DEFAULT_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = UpdateOffsetResponse;
 }
 
-#[derive(Decode, Encode, Default, Debug)]
+#[derive(Decode, Encode, Debug)]
 pub struct ReplicaOffsetRequest {
     pub replica: ReplicaKey,
     pub leo: Offset,
     pub hw: Offset,
+    #[fluvio_kf(min_version = 1)]
+    pub size: i64,          // bytes of follower log segments, -1 if unknown
+    #[fluvio_kf(min_version = 1)]
+    pub segments: u32,
+}
+
+impl Default for ReplicaOffsetRequest {
+    fn default() -> Self {
+        Self {
+            replica: ReplicaKey::default(),
+            leo: 0,
+            hw: 0,
+            size: -1,
+            segments: 0,
+        }
+    }
 }
 
 // no content, this is one way request
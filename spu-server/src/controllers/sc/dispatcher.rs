@@ -4,6 +4,7 @@ use std::io::Error as IoError;
 use std::sync::Arc;
 use std::net::SocketAddr;
 use std::convert::TryInto;
+use std::path::PathBuf;
 
 use log::info;
 use log::trace;
@@ -15,6 +16,8 @@ use types::print_cli_err;
 use futures::channel::mpsc::Receiver;
 use futures::channel::mpsc::Sender;
 use futures::channel::mpsc::channel;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::oneshot;
 use futures::StreamExt;
 use futures::FutureExt;
 use futures::select;
@@ -28,18 +31,30 @@ use internal_api::RegisterSpuRequest;
 use internal_api::UpdateSpuRequest;
 use internal_api::UpdateReplicaRequest;
 use internal_api::UpdateAllRequest;
+use internal_api::UpdateAclRequest;
+use internal_api::UpdateCredentialsRequest;
+use internal_api::UpdateQuotasRequest;
+use internal_api::UpdateReplicationThrottleRequest;
+use internal_api::ReplicaRemovedRequest;
+use internal_api::UpdateLrsRequest;
 use internal_api::messages::Replica;
+use internal_api::messages::MsgType;
 use kf_protocol::api::RequestMessage;
 use kf_socket::KfSocket;
 use kf_socket::KfSocketError;
 use kf_socket::ExclusiveKfSink;
 use storage::FileReplica;
+use storage::StorageError;
 use metadata::partition::ReplicaKey;
 use types::log_on_err;
 use utils::actions::Actions;
+use types::socket_helpers::ServerAddress;
 
 use crate::core::SharedGlobalContext;
 use crate::core::SpecChange;
+use crate::core::storage::replica_config;
+use crate::core::storage::remove_replica_storage;
+use crate::core::storage::enable_tier;
 use crate::controllers::follower_replica::ReplicaFollowerController;
 use crate::controllers::follower_replica::FollowerReplicaControllerCommand;
 use crate::controllers::leader_replica::ReplicaLeaderController;
@@ -91,6 +106,12 @@ impl ScDispatcher<FileReplica> {
     async fn dispatch_loop(mut self) {
         info!("starting SC Dispatcher");
 
+        let mut offline_receiver = self
+            .ctx
+            .log_dirs()
+            .take_offline_receiver()
+            .expect("offline log dirs are received only by sc dispatcher");
+
         loop {
             if let Some(mut socket) = self.create_socket_to_sc().await {
                 trace!(
@@ -108,11 +129,14 @@ impl ScDispatcher<FileReplica> {
                 }
 
                 // continously process updates from and send back status to SC
-                match self.sc_request_loop(socket).await {
+                match self.sc_request_loop(socket, &mut offline_receiver).await {
                     Ok(_) => {}
                     Err(err) => warn!("error, connecting to sc: {:#?}", err),
                 }
 
+                // not ready until full sync from sc after reconnect
+                self.ctx.set_sc_synced(false);
+
                 // We lost connection to sc.  Retry again
                 // Currently we use 3 seconds to retry but this should be using backoff algorithm
                 sleep(Duration::from_millis(3000)).await
@@ -122,11 +146,19 @@ impl ScDispatcher<FileReplica> {
     }
 
     /// dispatch sc request
-    async fn sc_request_loop(&mut self, socket: KfSocket) -> Result<(), KfSocketError> {
+    async fn sc_request_loop(
+        &mut self,
+        socket: KfSocket,
+        offline_receiver: &mut UnboundedReceiver<PathBuf>,
+    ) -> Result<(), KfSocketError> {
         let (sink, mut stream) = socket.split();
         let mut api_stream = stream.api_stream::<InternalSpuRequest, InternalSpuApi>();
 
         let shared_sink = Arc::new(ExclusiveKfSink::new(sink));
+
+        // replicas which went offline while disconnected
+        self.report_offline_replicas(self.ctx.log_dirs().offline_replicas(), &shared_sink).await;
+
         loop {
             select! {
                 sc_request = api_stream.next().fuse() => {
@@ -136,8 +168,9 @@ impl ScDispatcher<FileReplica> {
                             match req_message {
                                 
                                 InternalSpuRequest::UpdateAllRequest(request) => {
-                                    if let Err(err) = self.handle_sync_all_request(request,shared_sink.clone()).await {
-                                        error!("error handling all request from sc {}", err);
+                                    match self.handle_sync_all_request(request,shared_sink.clone()).await {
+                                        Ok(_) => self.ctx.set_sc_synced(true),
+                                        Err(err) => error!("error handling all request from sc {}", err)
                                     }
                                 },
                                 InternalSpuRequest::UpdateReplicaRequest(request) => {
@@ -149,6 +182,18 @@ impl ScDispatcher<FileReplica> {
                                     if let Err(err) = self.handle_update_spu_request(request,shared_sink.clone()).await {
                                         error!("error handling update spu request: {}",err);
                                     }
+                                },
+                                InternalSpuRequest::UpdateAclRequest(request) => {
+                                    self.handle_update_acl_request(request);
+                                },
+                                InternalSpuRequest::UpdateCredentialsRequest(request) => {
+                                    self.handle_update_credentials_request(request);
+                                },
+                                InternalSpuRequest::UpdateQuotasRequest(request) => {
+                                    self.handle_update_quotas_request(request);
+                                },
+                                InternalSpuRequest::UpdateReplicationThrottleRequest(request) => {
+                                    self.handle_update_replication_throttle_request(request);
                                 }
                             }
                             
@@ -165,6 +210,12 @@ impl ScDispatcher<FileReplica> {
                 },
                 super_command = self.supervisor_command_receiver.next().fuse() => {
 
+                },
+                offline_dir = offline_receiver.next().fuse() => {
+                    if let Some(path) = offline_dir {
+                        let replicas = self.ctx.log_dirs().replicas_in(&path);
+                        self.report_offline_replicas(replicas, &shared_sink).await;
+                    }
                 }
 
             }
@@ -174,6 +225,19 @@ impl ScDispatcher<FileReplica> {
         Ok(())
     }
 
+    /// report replicas whose storage is offline to sc, so it can elect new leaders
+    async fn report_offline_replicas(&self, replicas: Vec<ReplicaKey>, sc_sink: &ExclusiveKfSink) {
+        let local_spu_id = self.ctx.local_spu_id();
+        for replica in replicas {
+            warn!("reporting offline replica: {} to sc", replica);
+            let mut message = RequestMessage::new_request(UpdateLrsRequest::offline(replica, local_spu_id));
+            message
+                .get_mut_header()
+                .set_client_id(format!("spu: {}", local_spu_id));
+            log_on_err!(sc_sink.send_request(&message).await);
+        }
+    }
+
     /// register local spu to sc
     async fn send_spu_registeration(
         &self,
@@ -211,17 +275,26 @@ impl ScDispatcher<FileReplica> {
     }
 
     /// connect to sc if can't connect try until we succeed
-    /// or if we received termination message
+    /// or if we received termination message.
+    /// on failure, rotate through standby sc endpoints
     async fn create_socket_to_sc(&mut self) -> Option<KfSocket> {
         let spu_id = self.ctx.local_spu_id();
-        let sc_endpoint = self.ctx.config().sc_endpoint();
-
-         debug!("trying to resolve sc endpoint: {}",sc_endpoint);
-        let addr: SocketAddr = sc_endpoint.clone().try_into().expect("sc endpoint should be resolving");
-        debug!("sc endpoint resolved to: {}",addr);
+        let sc_endpoints: Vec<ServerAddress> = self
+            .ctx
+            .config()
+            .sc_endpoints()
+            .into_iter()
+            .cloned()
+            .collect();
 
         let wait_interval = self.ctx.config().sc_retry_ms;
+        let mut index = 0;
         loop {
+            let sc_endpoint = &sc_endpoints[index % sc_endpoints.len()];
+            debug!("trying to resolve sc endpoint: {}",sc_endpoint);
+            let addr: SocketAddr = sc_endpoint.clone().try_into().expect("sc endpoint should be resolving");
+            debug!("sc endpoint resolved to: {}",addr);
+
             trace!(
                 "trying to create socket to sc: {:#?} for spu: {}",
                 addr,
@@ -233,12 +306,13 @@ impl ScDispatcher<FileReplica> {
                 socket_res = connect_future.fuse() => {
                     match socket_res {
                         Ok(socket) => {
-                            debug!("connected to sc for spu: {}",spu_id);
+                            debug!("connected to sc: {} for spu: {}",addr,spu_id);
                             return Some(socket)
                         }
-                        Err(err) => warn!("error connecting to sc: {}",err)
+                        Err(err) => warn!("error connecting to sc: {}, {}",addr,err)
                     }
 
+                    index += 1;
                     trace!("sleeping {} ms to connect to sc: {}",wait_interval,spu_id);
                     sleep(Duration::from_millis(wait_interval as u64)).await;
                 },
@@ -305,13 +379,57 @@ impl ScDispatcher<FileReplica> {
         let (_, request) = req_msg.get_header_request();
 
         debug!("received replica update from sc: {:#?}",request);
+
+        let messages = request.replicas().messages;
+
+        // replica may not be known if SPU was restarted while topic was being deleted,
+        // so storage is removed for all delete messages, not only for replica actions
+        let removed_replicas: Vec<ReplicaKey> = messages
+            .iter()
+            .filter(|msg| msg.header == MsgType::DELETE)
+            .map(|msg| msg.content.id.clone())
+            .collect();
     
-        let replica_actions = self.ctx.replica_localstore().apply_changes(request.replicas().messages);
-        self.apply_replica_actions(replica_actions,shared_sc_sink).await;    
+        let replica_actions = self.ctx.replica_localstore().apply_changes(messages);
+        self.apply_replica_actions(replica_actions,shared_sc_sink.clone()).await;
+
+        for replica_id in removed_replicas.into_iter() {
+            self.remove_replica_storage(replica_id,&shared_sc_sink).await;
+        }
         Ok(())
     }
 
 
+    /// remove replica storage and confirm removal to SC.
+    /// controllers for replica have confirmed shutdown when replica actions were applied
+    async fn remove_replica_storage(
+        &self,
+        replica_id: ReplicaKey,
+        shared_sc_sink: &ExclusiveKfSink
+    ) {
+
+        let local_id = self.ctx.local_spu_id();
+        debug!("removing storage for replica: {}",replica_id);
+
+        if let Err(err) = remove_replica_storage(
+            local_id,
+            &replica_id,
+            &self.ctx.config().storage().new_config(),
+            self.ctx.log_dirs()
+        ) {
+            error!("error removing storage for replica: {}, {}",replica_id,err);
+            return;
+        }
+
+        let mut message = RequestMessage::new_request(ReplicaRemovedRequest::new(replica_id,local_id));
+        message
+            .get_mut_header()
+            .set_client_id(format!("spu: {}",local_id));
+
+        log_on_err!(shared_sc_sink.send_request(&message).await);
+    }
+
+
     ///
     /// Follower Update Handler sent by a peer Spu
     ///
@@ -329,6 +447,46 @@ impl ScDispatcher<FileReplica> {
         Ok(())
     }
 
+    /// replace acl bindings, no response is expected by sc
+    fn handle_update_acl_request(&self, req_msg: RequestMessage<UpdateAclRequest>) {
+
+        let (_, request) = req_msg.get_header_request();
+
+        debug!("received acl update from sc: {} bindings",request.acls.len());
+
+        self.ctx.acl_localstore().sync_all(request.acls());
+    }
+
+    /// replace sasl credentials, no response is expected by sc
+    fn handle_update_credentials_request(&self, req_msg: RequestMessage<UpdateCredentialsRequest>) {
+
+        let (_, request) = req_msg.get_header_request();
+
+        debug!("received credential update from sc: {} credentials",request.credentials.len());
+
+        self.ctx.credential_localstore().sync_all(request.credentials());
+    }
+
+    /// replace client quotas, no response is expected by sc
+    fn handle_update_quotas_request(&self, req_msg: RequestMessage<UpdateQuotasRequest>) {
+
+        let (_, request) = req_msg.get_header_request();
+
+        debug!("received quota update from sc: {} quotas",request.quotas.len());
+
+        self.ctx.quota_manager().sync_all(request.quotas());
+    }
+
+    /// override replication throttle rate, no response is expected by sc
+    fn handle_update_replication_throttle_request(&self, req_msg: RequestMessage<UpdateReplicationThrottleRequest>) {
+
+        let (_, request) = req_msg.get_header_request();
+
+        debug!("received replication throttle update from sc: {:?}",request.rate());
+
+        self.ctx.replication_throttle().set_override_rate(request.rate());
+    }
+
 
     async fn apply_replica_actions(
         &self, 
@@ -364,10 +522,11 @@ impl ScDispatcher<FileReplica> {
                     }
                 },
                 SpecChange::Delete(deleted_replica) => {
+                    // storage is removed after this, so wait until controllers have stopped using it
                     if deleted_replica.leader == local_id {
-                        self.remove_leader_replica(&deleted_replica.id);
+                        self.shutdown_leader_replica(&deleted_replica.id).await;
                     } else {
-                        self.remove_follower_replica(deleted_replica);
+                        self.shutdown_follower_replica(deleted_replica).await;
                     }
                 },
                 SpecChange::Mod(new_replica,old_replica) => {
@@ -377,7 +536,7 @@ impl ScDispatcher<FileReplica> {
                     if new_replica.leader != old_replica.leader {
                         if new_replica.leader == local_id  {
                             // we become leader
-                            self.promote_replica(new_replica,old_replica,shared_sc_sink.clone());
+                            self.promote_replica(new_replica,old_replica,shared_sc_sink.clone()).await;
                         } else {
                             // we are follower
                             // if we were leader before, we demote out self
@@ -415,17 +574,31 @@ impl ScDispatcher<FileReplica> {
 
         debug!("adding new leader replica: {}",replica);
 
-        let storage_log = self.ctx.config().storage().new_config();
+        let base_log = replica_config(&self.ctx.config().storage().new_config(), &replica.config);
         let replica_id = replica.id.clone();
+        let storage_log = match self.ctx.log_dirs().replica_config(&replica_id, &base_log) {
+            Some(storage_log) => storage_log,
+            None => {
+                error!("no online log dir for leader replica: {}, replica is offline",replica_id);
+                self.report_offline_replicas(vec![replica_id], &shared_sc_sink).await;
+                return;
+            }
+        };
                     
         match LeaderReplicaState::create_file_replica(replica, &storage_log).await {
-            Ok(leader_replica) => {
+            Ok(mut leader_replica) => {
                 debug!("file replica for leader is created: {}",storage_log);
+                if let Err(err) = enable_tier(leader_replica.mut_storage(), self.ctx.tier()).await {
+                    error!("error enabling tiered storage for leader replica: {}, {}",replica_id,err);
+                }
                 self.spawn_leader_controller(replica_id,leader_replica,shared_sc_sink);
             },
             Err(err) => {
                 error!("error creating storage foer leader replica {:#?}",err);
-                // TODO: send status back to SC
+                // replicas of failed dir are reported to sc when it goes offline
+                if let StorageError::IoError(_) = err {
+                    self.ctx.log_dirs().mark_offline(&storage_log.base_dir);
+                }
             }
         }
 
@@ -442,6 +615,11 @@ impl ScDispatcher<FileReplica> {
             
             debug!("leader replica was found, sending replica info: {}",replica);
 
+            if let Some(mut leader_replica) = self.ctx.leaders_state().get_mut_replica(&replica.id) {
+                let storage_log = replica_config(&self.ctx.config().storage().new_config(), &replica.config);
+                leader_replica.mut_storage().update_option(&storage_log);
+            }
+
             match self.ctx.leaders_state().send_message(
                 &replica.id,
                 LeaderReplicaControllerCommand::UpdateReplicaFromSc(replica.clone()),
@@ -483,7 +661,9 @@ impl ScDispatcher<FileReplica> {
             receiver,
             self.ctx.leader_state_owned(),
             self.ctx.followers_sink_owned(),
-            shared_sc_sink
+            shared_sc_sink,
+            self.ctx.replication_throttle_owned(),
+            self.ctx.config().replication.clone()
         );
         leader_controller.run();
 
@@ -503,12 +683,31 @@ impl ScDispatcher<FileReplica> {
 
 
 
+    /// stop leader controller and remove leader replica after controller has confirmed shutdown
+    async fn shutdown_leader_replica(&self, id: &ReplicaKey) {
+
+        debug!("shutting down leader replica: {}", id);
+
+        let (sender, receiver) = oneshot::channel();
+        match self.ctx.leaders_state().send_message(id,LeaderReplicaControllerCommand::Shutdown(sender)).await {
+            Ok(true) => {
+                if receiver.await.is_err() {
+                    warn!("leader controller: {} terminated without confirming shutdown", id);
+                }
+            },
+            Ok(false) => warn!("no leader controller found for: {}", id),
+            Err(err) => error!("error sending shutdown to leader controller: {}, {}", id, err)
+        }
+
+        self.remove_leader_replica(id);
+    }
+
     /// Promote follower replica as leader, 
     /// This is done in 3 steps
     /// // 1: Remove follower replica from followers state
     /// // 2: Terminate followers controller if need to be (if there are no more follower replicas for that controller)
-    /// // 3: Start leader controller
-    pub fn promote_replica(
+    /// // 3: Load remote segments if tiering is enabled and start leader controller
+    pub async fn promote_replica(
         &self,
         new_replica: Replica,
         old_replica: Replica,
@@ -521,10 +720,15 @@ impl ScDispatcher<FileReplica> {
                     
             debug!("old follower replica exists, converting to leader: {}",old_replica.id);
 
+            let mut storage = follower_replica.storage_owned();
+            if let Err(err) = enable_tier(&mut storage, self.ctx.tier()).await {
+                error!("error enabling tiered storage for promoted replica: {}, {}",new_replica.id,err);
+            }
+
             let leader_state = LeaderReplicaState::new(
                     new_replica.id.clone(),
                     new_replica.leader,
-                    follower_replica.storage_owned(),
+                    storage,
                     new_replica.replicas
             );
 
@@ -570,7 +774,8 @@ impl ScDispatcher<FileReplica> {
                 receiver,
                 self.ctx.spu_localstore_owned(),
                 self.ctx.followers_state_owned(),
-                self.ctx.config_owned()
+                self.ctx.config_owned(),
+                self.ctx.log_dirs_owned()
             );
             follower_controller.run();
             log_on_err!(sender.send(FollowerReplicaControllerCommand::AddReplica(replica)).await);
@@ -594,6 +799,28 @@ impl ScDispatcher<FileReplica> {
 
    
 
+    /// remove follower replica through its controller, so replica is not removed while records are written.
+    /// if there is no controller, replica is removed directly
+    async fn shutdown_follower_replica(&self,replica: Replica) {
+
+        debug!("shutting down follower replica: {}",replica);
+
+        if let Some(mut sender) = self.ctx.followers_state().mailbox(&replica.leader) {
+            let (confirm_sender, confirm_receiver) = oneshot::channel();
+            match sender.send(FollowerReplicaControllerCommand::RemoveReplica(replica.id.clone(),confirm_sender)).await {
+                Ok(_) => {
+                    if confirm_receiver.await.is_ok() {
+                        return;
+                    }
+                    warn!("follower controller: {} terminated without confirming removal of: {}",replica.leader,replica.id);
+                },
+                Err(err) => error!("error sending remove replica to follower controller: {}, {}",replica.leader,err)
+            }
+        }
+
+        self.remove_follower_replica(replica);
+    }
+
     fn remove_follower_replica(&self,replica: Replica) {
 
         debug!("removing follower replica: {}",replica);
@@ -0,0 +1,53 @@
+//
+//  ACL bindings (received from Sc)
+//      >>> SC always sends the full set, so bindings are replaced as a whole
+//
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use log::debug;
+
+use metadata::acl::AclSpec;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclResourceType;
+use metadata::acl::AclOperation;
+use metadata::acl::authorize;
+use metadata::acl::AuthorizerConfig;
+
+#[derive(Debug, Default)]
+pub struct AclLocalStore {
+    acls: RwLock<Vec<AclSpec>>,
+    config: AuthorizerConfig,
+}
+
+impl AclLocalStore {
+    pub fn new_shared(config: AuthorizerConfig) -> Arc<Self> {
+        Arc::new(Self {
+            acls: RwLock::new(vec![]),
+            config,
+        })
+    }
+
+    /// replace bindings with source of truth
+    pub fn sync_all(&self, acls: Vec<AclSpec>) {
+        debug!("apply all <Acl> {} bindings", acls.len());
+        *self.acls.write().expect("acl lock") = acls;
+    }
+
+    /// check if identity may perform operation on topic
+    pub fn authorize_topic(
+        &self,
+        identity: &AclIdentity,
+        topic: &str,
+        operation: &AclOperation,
+    ) -> bool {
+        authorize(
+            self.acls.read().expect("acl lock").iter(),
+            &self.config,
+            identity,
+            &AclResourceType::Topic,
+            topic,
+            operation,
+        )
+    }
+}
@@ -0,0 +1,7 @@
+mod metadata;
+
+pub use self::metadata::AclLocalStore;
+
+use std::sync::Arc;
+
+pub type SharedAclLocalStore = Arc<AclLocalStore>;
@@ -0,0 +1,46 @@
+//
+//  SASL credentials (received from Sc)
+//      >>> SC always sends the full set, so credentials are replaced as a whole
+//
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use log::debug;
+use log::warn;
+
+use kf_service::sasl::SaslCredentialLookup;
+use kf_service::sasl::SaslMechanism;
+use kf_service::sasl::ScramCredential;
+use kf_service::sasl::ScramMechanism;
+use metadata::auth_token::SaslCredentialSpec;
+
+#[derive(Debug, Default)]
+pub struct CredentialLocalStore(RwLock<Vec<SaslCredentialSpec>>);
+
+impl CredentialLocalStore {
+    pub fn new_shared() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// replace credentials with source of truth
+    pub fn sync_all(&self, credentials: Vec<SaslCredentialSpec>) {
+        debug!("apply all <SaslCredential> {} credentials", credentials.len());
+        *self.0.write().expect("credential lock") = credentials;
+    }
+}
+
+impl SaslCredentialLookup for CredentialLocalStore {
+    fn scram_credential(&self, mechanism: &ScramMechanism, user: &str) -> Option<ScramCredential> {
+        let name = SaslMechanism::Scram(mechanism.clone()).name();
+        self.0
+            .read()
+            .expect("credential lock")
+            .iter()
+            .find(|spec| spec.matches(user, name))
+            .and_then(|spec| {
+                ScramCredential::decode(&spec.salt, spec.iterations, &spec.stored_key, &spec.server_key)
+                    .map_err(|err| warn!("invalid credential for {}: {}", user, err))
+                    .ok()
+            })
+    }
+}
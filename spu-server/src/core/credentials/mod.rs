@@ -0,0 +1,7 @@
+mod metadata;
+
+pub use self::metadata::CredentialLocalStore;
+
+use std::sync::Arc;
+
+pub type SharedCredentialLocalStore = Arc<CredentialLocalStore>;
@@ -4,39 +4,70 @@
 //! Global Context stores entities that persist through system operation.
 //!
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::fmt::Debug;
+use std::time::Duration;
 
 use kf_socket::SharedSinkPool;
 use kf_socket::SinkPool;
 use types::SpuId;
 use storage::ReplicaStorage;
+use storage::Tier;
+use utils::metrics::MetricsRegistry;
+use utils::metrics::SharedMetricsRegistry;
+use kf_service::RequestMetrics;
+use kf_service::SharedRequestMetrics;
+use kf_service::HealthCheck;
 
 use crate::config::SpuConfig;
 use crate::controllers::leader_replica::SharedReplicaLeadersState;
 use crate::controllers::follower_replica::FollowersState;
 use crate::controllers::follower_replica::SharedFollowersState;
 use crate::controllers::leader_replica::ReplicaLeadersState;
+use crate::controllers::leader_replica::ReplicationThrottle;
+use crate::controllers::leader_replica::SharedReplicationThrottle;
 use super::spus::SharedSpuLocalStore;
 use super::SharedReplicaLocalStore;
 use super::spus::SpuLocalStore;
+use super::acls::SharedAclLocalStore;
+use super::acls::AclLocalStore;
+use super::credentials::SharedCredentialLocalStore;
+use super::credentials::CredentialLocalStore;
+use super::quotas::SharedQuotaManager;
+use super::quotas::QuotaManager;
 use super::replica::ReplicaStore;
 use super::SharedSpuConfig;
+use super::storage::LogDirs;
+use super::storage::SharedLogDirs;
+use super::metrics::LeaderReplicaCollector;
+use super::metrics::SPU_METRICS_PREFIX;
+use super::metrics::spu_api_key_name;
 
 #[derive(Debug)]
 pub struct GlobalContext<S> {
     config: SharedSpuConfig,
     spu_localstore: SharedSpuLocalStore,
     replica_localstore: SharedReplicaLocalStore,
+    acl_localstore: SharedAclLocalStore,
+    credential_localstore: SharedCredentialLocalStore,
+    quota_manager: SharedQuotaManager,
     leaders_state: SharedReplicaLeadersState<S>,
     followers_state: SharedFollowersState<S>,
-    follower_sinks: SharedSinkPool<SpuId>
+    replication_throttle: SharedReplicationThrottle,
+    follower_sinks: SharedSinkPool<SpuId>,
+    log_dirs: SharedLogDirs,
+    tier: Option<Tier>,
+    metrics: SharedMetricsRegistry,
+    request_metrics: SharedRequestMetrics,
+    sc_synced: AtomicBool,
 }
 
 // -----------------------------------
 // Global Contesxt - Implementation
 // -----------------------------------
 
-impl <S>GlobalContext<S> where S: ReplicaStorage + Debug {
+impl <S>GlobalContext<S> where S: ReplicaStorage + Debug + Send + Sync + 'static {
 
     
     pub fn new_shared_context(spu_config: SpuConfig) -> Arc<Self>  {
@@ -48,13 +79,32 @@ impl <S>GlobalContext<S> where S: ReplicaStorage + Debug {
     pub fn new(spu_config: SpuConfig) -> Self 
     {
        
+        let log_dirs = LogDirs::new_shared(spu_config.id, spu_config.log.all_dirs());
+        let tier = spu_config.tier.as_ref().map(Tier::from);
+        let replication_throttle = ReplicationThrottle::new_shared(spu_config.replication.throttled_rate);
+        let leaders_state = ReplicaLeadersState::new_shared();
+        let metrics = MetricsRegistry::new_shared();
+        metrics.add_collector(LeaderReplicaCollector::new(
+            leaders_state.clone(),
+            Duration::from_millis(spu_config.replication.lag_time_max_ms),
+        ));
+        let request_metrics = RequestMetrics::new_shared(SPU_METRICS_PREFIX, metrics.clone(), spu_api_key_name);
         GlobalContext {
             spu_localstore: SpuLocalStore::new_shared(),
             replica_localstore: ReplicaStore::new_shared(),
+            acl_localstore: AclLocalStore::new_shared(spu_config.authorizer.clone()),
+            credential_localstore: CredentialLocalStore::new_shared(),
+            quota_manager: QuotaManager::new_shared(),
             config: Arc::new(spu_config),
             follower_sinks: SinkPool::new_shared(),
-            leaders_state: ReplicaLeadersState::new_shared(),
-            followers_state: FollowersState::new_shared()
+            leaders_state,
+            followers_state: FollowersState::new_shared(),
+            replication_throttle,
+            log_dirs,
+            tier,
+            metrics,
+            request_metrics,
+            sc_synced: AtomicBool::new(false),
         }
     }
 
@@ -76,6 +126,18 @@ impl <S>GlobalContext<S> where S: ReplicaStorage + Debug {
     pub fn replica_localstore(&self) -> &ReplicaStore {
         &self.replica_localstore
     }
+
+    pub fn acl_localstore(&self) -> &AclLocalStore {
+        &self.acl_localstore
+    }
+
+    pub fn credential_localstore(&self) -> &CredentialLocalStore {
+        &self.credential_localstore
+    }
+
+    pub fn quota_manager(&self) -> &QuotaManager {
+        &self.quota_manager
+    }
  
     pub fn follower_sinks(&self) -> &SinkPool<SpuId> {
         &self.follower_sinks
@@ -101,6 +163,49 @@ impl <S>GlobalContext<S> where S: ReplicaStorage + Debug {
         self.followers_state.clone()
     }
 
+    /// bandwidth limit for syncing out of sync followers
+    pub fn replication_throttle(&self) -> &ReplicationThrottle {
+        &self.replication_throttle
+    }
+
+    pub fn replication_throttle_owned(&self) -> SharedReplicationThrottle {
+        self.replication_throttle.clone()
+    }
+
+    pub fn log_dirs(&self) -> &LogDirs {
+        &self.log_dirs
+    }
+
+    pub fn log_dirs_owned(&self) -> SharedLogDirs {
+        self.log_dirs.clone()
+    }
+
+    /// object store tiering for leader replicas, if enabled
+    pub fn tier(&self) -> Option<&Tier> {
+        self.tier.as_ref()
+    }
+
+    pub fn metrics(&self) -> &MetricsRegistry {
+        &self.metrics
+    }
+
+    pub fn metrics_owned(&self) -> SharedMetricsRegistry {
+        self.metrics.clone()
+    }
+
+    pub fn request_metrics(&self) -> &RequestMetrics {
+        &self.request_metrics
+    }
+
+    /// spu is registered with sc and has applied full metadata sync
+    pub fn is_sc_synced(&self) -> bool {
+        self.sc_synced.load(Ordering::SeqCst)
+    }
+
+    pub fn set_sc_synced(&self, synced: bool) {
+        self.sc_synced.store(synced, Ordering::SeqCst)
+    }
+
     pub fn config(&self) -> &SpuConfig {
         &self.config
     }
@@ -111,3 +216,24 @@ impl <S>GlobalContext<S> where S: ReplicaStorage + Debug {
 
 
 }
+
+/// ready when synced with sc and every replica assigned to spu has its controller running
+impl<S> HealthCheck for GlobalContext<S>
+where
+    S: ReplicaStorage + Debug + Send + Sync + 'static,
+{
+    fn is_ready(&self) -> bool {
+        if !self.is_sc_synced() {
+            return false;
+        }
+
+        let local_id = self.local_spu_id();
+        self.replica_localstore.all_values().iter().all(|replica| {
+            if replica.leader == local_id {
+                self.leaders_state.has_replica(&replica.id)
+            } else {
+                self.followers_state.has_replica(&replica.id)
+            }
+        })
+    }
+}
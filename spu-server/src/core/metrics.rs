@@ -0,0 +1,119 @@
+//!
+//! # SPU Metrics
+//!
+//! Traffic by topic is counted as it happens. Leader replica offsets, lag and segments
+//! are read from leader replicas when metrics are scraped.
+//!
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use spu_api::SpuApiKey;
+use storage::ReplicaStorage;
+use utils::metrics::MetricsCollector;
+use utils::metrics::MetricsRegistry;
+
+use crate::controllers::leader_replica::SharedReplicaLeadersState;
+
+pub const SPU_METRICS_PREFIX: &str = "spu";
+
+const TOPIC_BYTES_IN: &str = "spu_topic_bytes_in_total";
+const TOPIC_BYTES_OUT: &str = "spu_topic_bytes_out_total";
+const REPLICA_HIGH_WATERMARK: &str = "spu_replica_high_watermark";
+const REPLICA_END_OFFSET: &str = "spu_replica_end_offset";
+const REPLICA_FOLLOWER_LAG: &str = "spu_replica_follower_lag";
+const REPLICA_SEGMENTS: &str = "spu_replica_segments";
+const REPLICA_IN_SYNC_REPLICAS: &str = "spu_replica_in_sync_replicas";
+const LEADER_REPLICAS: &str = "spu_leader_replicas";
+
+/// api key label of spu request
+pub fn spu_api_key_name(api_key: u16) -> String {
+    SpuApiKey::try_from(api_key)
+        .map(|key| format!("{:?}", key))
+        .unwrap_or_else(|_| api_key.to_string())
+}
+
+/// record bytes produced to topic
+pub fn record_bytes_in(registry: &MetricsRegistry, topic: &str, bytes: usize) {
+    registry
+        .counter(TOPIC_BYTES_IN, "Bytes produced by topic", &[("topic", topic)])
+        .inc_by(bytes as u64);
+}
+
+/// record bytes fetched from topic
+pub fn record_bytes_out(registry: &MetricsRegistry, topic: &str, bytes: usize) {
+    registry
+        .counter(TOPIC_BYTES_OUT, "Bytes fetched by topic", &[("topic", topic)])
+        .inc_by(bytes as u64);
+}
+
+/// refresh leader replica gauges, replicas which are no longer leader are dropped
+pub struct LeaderReplicaCollector<S> {
+    leaders: SharedReplicaLeadersState<S>,
+    lag_time_max: Duration,
+}
+
+impl<S> LeaderReplicaCollector<S> {
+    pub fn new(leaders: SharedReplicaLeadersState<S>, lag_time_max: Duration) -> Self {
+        Self {
+            leaders,
+            lag_time_max,
+        }
+    }
+}
+
+impl<S> MetricsCollector for LeaderReplicaCollector<S>
+where
+    S: ReplicaStorage + Send + Sync,
+{
+    fn collect(&self, registry: &MetricsRegistry) {
+        for name in &[
+            REPLICA_HIGH_WATERMARK,
+            REPLICA_END_OFFSET,
+            REPLICA_FOLLOWER_LAG,
+            REPLICA_SEGMENTS,
+            REPLICA_IN_SYNC_REPLICAS,
+        ] {
+            registry.clear(name);
+        }
+
+        let keys = self.leaders.replica_keys();
+        registry
+            .gauge(LEADER_REPLICAS, "Leader replicas on spu", &[])
+            .set(keys.len() as i64);
+
+        for key in keys {
+            if let Some(leader) = self.leaders.get_replica(&key) {
+                let partition = key.partition.to_string();
+                let labels = [("topic", key.topic.as_str()), ("partition", partition.as_str())];
+
+                registry
+                    .gauge(REPLICA_HIGH_WATERMARK, "High watermark of leader replica", &labels)
+                    .set(leader.hw());
+                registry
+                    .gauge(REPLICA_END_OFFSET, "End offset of leader replica", &labels)
+                    .set(leader.leo());
+                registry
+                    .gauge(REPLICA_SEGMENTS, "Segments of leader replica", &labels)
+                    .set(leader.storage().get_segment_count() as i64);
+                registry
+                    .gauge(REPLICA_IN_SYNC_REPLICAS, "In sync replicas including leader", &labels)
+                    .set(leader.in_sync_replica_count(self.lag_time_max) as i64);
+
+                for (follower_id, lag) in leader.follower_lags() {
+                    let follower = follower_id.to_string();
+                    registry
+                        .gauge(
+                            REPLICA_FOLLOWER_LAG,
+                            "Offsets follower is behind leader end offset",
+                            &[
+                                ("topic", key.topic.as_str()),
+                                ("partition", partition.as_str()),
+                                ("follower", follower.as_str()),
+                            ],
+                        )
+                        .set(lag);
+                }
+            }
+        }
+    }
+}
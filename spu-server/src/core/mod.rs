@@ -5,6 +5,11 @@ pub(crate) mod storage;
 
 pub mod spus;
 pub mod replica;
+pub mod acls;
+pub mod credentials;
+pub mod quotas;
+pub mod produce_policy;
+pub mod metrics;
 
 pub use self::global_context::GlobalContext;
 pub use self::store::Spec;
@@ -13,6 +18,8 @@ pub use self::store::SpecChange;
 
 pub use self::spus::SpuLocalStore;
 pub use self::replica::SharedReplicaLocalStore;
+pub use self::produce_policy::ProducePolicy;
+pub use self::produce_policy::TimestampType;
 
 use std::sync::Arc;
 use ::storage::FileReplica;
@@ -0,0 +1,237 @@
+//!
+//! # Produce Policy
+//!
+//! Validation applied by leader to produced batches before they are appended.
+//! SPU defaults are overridden by topic config of the replica.
+//!
+use std::collections::BTreeMap;
+
+use log::debug;
+use log::warn;
+
+use kf_protocol::api::DefaultRecords;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::Encoder;
+use metadata::topic::MAX_MESSAGE_BYTES;
+use metadata::topic::MESSAGE_TIMESTAMP_TYPE;
+use metadata::topic::MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS;
+use metadata::topic::MIN_INSYNC_REPLICAS;
+use metadata::topic::TIMESTAMP_TYPE_CREATE_TIME;
+use metadata::topic::TIMESTAMP_TYPE_LOG_APPEND_TIME;
+use types::defaults::SPU_MAX_MESSAGE_BYTES;
+use types::defaults::SPU_MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS;
+use types::defaults::SPU_MIN_IN_SYNC_REPLICAS;
+use utils::now_ms;
+
+/// batch attribute bit for log append time
+const LOG_APPEND_TIME_ATTRIBUTE: i16 = 0x08;
+
+/// producer acks, wait for all in sync replicas
+const ACKS_ALL: i16 = -1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimestampType {
+    CreateTime,
+    LogAppendTime,
+}
+
+impl TimestampType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            TIMESTAMP_TYPE_CREATE_TIME => Some(TimestampType::CreateTime),
+            TIMESTAMP_TYPE_LOG_APPEND_TIME => Some(TimestampType::LogAppendTime),
+            _ => None,
+        }
+    }
+}
+
+impl Default for TimestampType {
+    fn default() -> Self {
+        TimestampType::CreateTime
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProducePolicy {
+    pub max_message_bytes: u32,
+    pub timestamp_type: TimestampType,
+    pub timestamp_difference_max_ms: i64, // -1 for unlimited
+    pub min_in_sync_replicas: u16,        // applies to producer with acks all
+}
+
+impl Default for ProducePolicy {
+    fn default() -> Self {
+        Self {
+            max_message_bytes: SPU_MAX_MESSAGE_BYTES,
+            timestamp_type: TimestampType::default(),
+            timestamp_difference_max_ms: SPU_MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS,
+            min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
+        }
+    }
+}
+
+impl ProducePolicy {
+    /// apply topic config overrides, invalid values are ignored
+    pub fn for_topic(&self, topic_config: &BTreeMap<String, String>) -> Self {
+        let mut policy = self.clone();
+        for (key, value) in topic_config.iter() {
+            let applied = match key.as_str() {
+                MAX_MESSAGE_BYTES => value.parse().map(|bytes| policy.max_message_bytes = bytes).is_ok(),
+                MESSAGE_TIMESTAMP_TYPE => TimestampType::from_name(value)
+                    .map(|timestamp_type| policy.timestamp_type = timestamp_type)
+                    .is_some(),
+                MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS => value
+                    .parse()
+                    .map(|ms| policy.timestamp_difference_max_ms = ms)
+                    .is_ok(),
+                MIN_INSYNC_REPLICAS => value
+                    .parse()
+                    .map(|replicas| policy.min_in_sync_replicas = replicas)
+                    .is_ok(),
+                _ => continue,
+            };
+            if !applied {
+                warn!("ignoring topic config {}: {}", key, value);
+            }
+        }
+        policy
+    }
+
+    /// producer waiting for all replicas is rejected if there are not enough in sync replicas.
+    /// in sync replicas include leader
+    pub fn check_in_sync(&self, acks: i16, in_sync_replicas: usize) -> Result<(), ErrorCode> {
+        if acks == ACKS_ALL && in_sync_replicas < self.min_in_sync_replicas as usize {
+            debug!("in sync replicas: {} is below min: {}", in_sync_replicas, self.min_in_sync_replicas);
+            return Err(ErrorCode::NotEnoughReplicas);
+        }
+        Ok(())
+    }
+
+    /// validate batches and rewrite timestamps for log append time.
+    /// records are not modified if any batch is rejected
+    pub fn apply(&self, records: &mut DefaultRecords) -> Result<(), ErrorCode> {
+        self.apply_at(records, now_ms())
+    }
+
+    fn apply_at(&self, records: &mut DefaultRecords, now: i64) -> Result<(), ErrorCode> {
+        for batch in &records.batches {
+            let batch_size = batch.write_size(0);
+            if batch_size > self.max_message_bytes as usize {
+                debug!("batch size: {} is over max message bytes: {}", batch_size, self.max_message_bytes);
+                return Err(ErrorCode::MessageTooLarge);
+            }
+
+            if self.timestamp_type == TimestampType::CreateTime && self.timestamp_difference_max_ms >= 0 {
+                let header = batch.get_header();
+                for timestamp in &[header.first_timestamp, header.max_time_stamp] {
+                    if (now - timestamp).abs() > self.timestamp_difference_max_ms {
+                        debug!("batch timestamp: {} is out of range from: {}", timestamp, now);
+                        return Err(ErrorCode::InvalidTimestamp);
+                    }
+                }
+            }
+        }
+
+        if self.timestamp_type == TimestampType::LogAppendTime {
+            for batch in &mut records.batches {
+                let header = batch.get_mut_header();
+                header.first_timestamp = now;
+                header.max_time_stamp = now;
+                header.attributes |= LOG_APPEND_TIME_ATTRIBUTE;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::BTreeMap;
+
+    use kf_protocol::api::DefaultBatch;
+    use kf_protocol::api::DefaultRecord;
+    use kf_protocol::api::DefaultRecords;
+    use kf_protocol::api::ErrorCode;
+    use metadata::topic::MAX_MESSAGE_BYTES;
+    use metadata::topic::MESSAGE_TIMESTAMP_TYPE;
+    use metadata::topic::MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS;
+    use metadata::topic::MIN_INSYNC_REPLICAS;
+
+    use super::ProducePolicy;
+    use super::TimestampType;
+
+    const NOW: i64 = 1_560_000_000_000;
+
+    fn create_records(timestamp: i64) -> DefaultRecords {
+        let mut batch = DefaultBatch::default();
+        batch.add_record(DefaultRecord::default());
+        batch.get_mut_header().first_timestamp = timestamp;
+        batch.get_mut_header().max_time_stamp = timestamp;
+        DefaultRecords { batches: vec![batch] }
+    }
+
+    #[test]
+    fn test_policy_for_topic() {
+        let mut topic_config = BTreeMap::new();
+        topic_config.insert(MAX_MESSAGE_BYTES.to_owned(), "100".to_owned());
+        topic_config.insert(MESSAGE_TIMESTAMP_TYPE.to_owned(), "LogAppendTime".to_owned());
+        topic_config.insert(MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS.to_owned(), "bad".to_owned());
+        topic_config.insert(MIN_INSYNC_REPLICAS.to_owned(), "2".to_owned());
+
+        let policy = ProducePolicy::default().for_topic(&topic_config);
+        assert_eq!(policy.min_in_sync_replicas, 2);
+        assert_eq!(policy.max_message_bytes, 100);
+        assert_eq!(policy.timestamp_type, TimestampType::LogAppendTime);
+        assert_eq!(policy.timestamp_difference_max_ms, ProducePolicy::default().timestamp_difference_max_ms);
+    }
+
+    #[test]
+    fn test_min_in_sync_replicas() {
+        let mut policy = ProducePolicy::default();
+        policy.min_in_sync_replicas = 2;
+        assert_eq!(policy.check_in_sync(-1, 1), Err(ErrorCode::NotEnoughReplicas));
+        assert_eq!(policy.check_in_sync(-1, 2), Ok(()));
+        assert_eq!(policy.check_in_sync(1, 1), Ok(()), "only acks all waits for replicas");
+    }
+
+    #[test]
+    fn test_max_message_bytes() {
+        let mut policy = ProducePolicy::default();
+        policy.max_message_bytes = 10;
+        let mut records = create_records(NOW);
+        assert_eq!(policy.apply_at(&mut records, NOW), Err(ErrorCode::MessageTooLarge));
+
+        policy.max_message_bytes = 1000;
+        assert_eq!(policy.apply_at(&mut records, NOW), Ok(()));
+    }
+
+    #[test]
+    fn test_timestamp_difference() {
+        let mut policy = ProducePolicy::default();
+        let mut records = create_records(NOW - 120_000);
+        assert_eq!(policy.apply_at(&mut records, NOW), Ok(()), "unlimited by default");
+
+        policy.timestamp_difference_max_ms = 60_000;
+        assert_eq!(policy.apply_at(&mut records, NOW), Err(ErrorCode::InvalidTimestamp));
+
+        let mut records = create_records(NOW + 30_000);
+        assert_eq!(policy.apply_at(&mut records, NOW), Ok(()));
+    }
+
+    #[test]
+    fn test_log_append_time() {
+        let mut policy = ProducePolicy::default();
+        policy.timestamp_type = TimestampType::LogAppendTime;
+        policy.timestamp_difference_max_ms = 60_000;
+
+        // skew is not checked since timestamp is replaced
+        let mut records = create_records(0);
+        assert_eq!(policy.apply_at(&mut records, NOW), Ok(()));
+        let header = records.batches[0].get_header();
+        assert_eq!(header.first_timestamp, NOW);
+        assert_eq!(header.max_time_stamp, NOW);
+        assert_eq!(header.attributes & 0x08, 0x08);
+    }
+}
@@ -0,0 +1,133 @@
+//!
+//! # Quota Manager
+//!
+//! Client quotas (received from Sc) and usage of quota entities.
+//!      >>> SC always sends the full set, so quotas are replaced as a whole
+//!
+//! Usage is tracked per resolved entity, so all connections of same principal and/or
+//! client id share the quota. Windows of idle entities are dropped periodically.
+//!
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+use log::debug;
+use log::trace;
+
+use metadata::quota::ClientQuotaSpec;
+use metadata::quota::QuotaType;
+use metadata::quota::resolve_quota;
+use utils::now_ms;
+
+use super::RateWindow;
+
+/// length of single sample
+const QUOTA_SAMPLE_MS: u64 = 1000;
+
+/// number of samples in window
+const QUOTA_NUM_SAMPLES: usize = 11;
+
+type EntityKey = (QuotaType, Option<String>, Option<String>);
+
+#[derive(Debug, Default)]
+struct Usage {
+    windows: HashMap<EntityKey, RateWindow>,
+    last_purge_ms: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct QuotaManager {
+    quotas: RwLock<Vec<ClientQuotaSpec>>,
+    usage: Mutex<Usage>,
+}
+
+impl QuotaManager {
+    pub fn new_shared() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// replace quotas with source of truth
+    pub fn sync_all(&self, quotas: Vec<ClientQuotaSpec>) {
+        debug!("apply all <ClientQuota> {} quotas", quotas.len());
+        *self.quotas.write().expect("quota lock") = quotas;
+    }
+
+    /// record bytes for connection and return time in ms the response should be delayed.
+    /// zero if no quota applies or usage is within quota
+    pub fn record(&self, quota_type: QuotaType, principal: &str, client_id: &str, bytes: usize) -> u64 {
+        self.record_at(quota_type, principal, client_id, bytes, now_ms() as u64)
+    }
+
+    fn record_at(
+        &self,
+        quota_type: QuotaType,
+        principal: &str,
+        client_id: &str,
+        bytes: usize,
+        now_ms: u64,
+    ) -> u64 {
+        let resolved = {
+            let quotas = self.quotas.read().expect("quota lock");
+            match resolve_quota(quotas.iter(), quota_type, principal, client_id) {
+                Some(resolved) => resolved,
+                None => return 0,
+            }
+        };
+
+        let mut usage = self.usage.lock().expect("usage lock");
+        if now_ms >= usage.last_purge_ms + QUOTA_SAMPLE_MS * QUOTA_NUM_SAMPLES as u64 {
+            usage.windows.retain(|_, window| !window.is_idle(now_ms));
+            usage.last_purge_ms = now_ms;
+        }
+
+        let window = usage
+            .windows
+            .entry((quota_type, resolved.principal, resolved.client_id))
+            .or_insert_with(|| RateWindow::new(QUOTA_SAMPLE_MS, QUOTA_NUM_SAMPLES));
+        window.record(bytes as u64, now_ms);
+        let throttle_ms = window.throttle_time_ms(resolved.byte_rate.max(0) as u64, now_ms);
+        if throttle_ms > 0 {
+            trace!(
+                "{:?} quota of {} bytes/sec exceeded by principal: {}, client: {}, throttle: {} ms",
+                quota_type,
+                resolved.byte_rate,
+                principal,
+                client_id,
+                throttle_ms
+            );
+        }
+        throttle_ms
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use metadata::quota::ClientQuotaSpec;
+    use metadata::quota::QuotaType;
+    use metadata::quota::QUOTA_DEFAULT_ENTITY;
+
+    use super::QuotaManager;
+
+    #[test]
+    fn test_quota_shared_by_entity() {
+        let manager = QuotaManager::default();
+        manager.sync_all(vec![ClientQuotaSpec {
+            client_id: Some(QUOTA_DEFAULT_ENTITY.to_owned()),
+            producer_byte_rate: Some(100),
+            ..Default::default()
+        }]);
+
+        // each client has its own default quota, principal is not part of entity
+        assert_eq!(manager.record_at(QuotaType::Produce, "User:alice", "app", 1000, 0), 0);
+        assert_eq!(manager.record_at(QuotaType::Produce, "User:bob", "app", 500, 0), 5000);
+        assert_eq!(manager.record_at(QuotaType::Produce, "User:alice", "other", 1000, 0), 0);
+
+        // no fetch quota
+        assert_eq!(manager.record_at(QuotaType::Fetch, "User:alice", "app", 100_000, 0), 0);
+
+        manager.sync_all(vec![]);
+        assert_eq!(manager.record_at(QuotaType::Produce, "User:alice", "app", 100_000, 0), 0);
+    }
+}
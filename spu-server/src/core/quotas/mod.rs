@@ -0,0 +1,9 @@
+mod window;
+mod manager;
+
+pub use self::window::RateWindow;
+pub use self::manager::QuotaManager;
+
+use std::sync::Arc;
+
+pub type SharedQuotaManager = Arc<QuotaManager>;
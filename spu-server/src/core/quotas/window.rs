@@ -0,0 +1,129 @@
+//!
+//! # Rate Window
+//!
+//! Bytes recorded in fixed size samples. Rate is measured over retained samples,
+//! oldest sample is dropped when new one starts.
+//!
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct Sample {
+    start_ms: u64,
+    bytes: u64,
+}
+
+#[derive(Debug)]
+pub struct RateWindow {
+    sample_ms: u64,
+    num_samples: usize,
+    samples: VecDeque<Sample>,
+}
+
+impl RateWindow {
+    pub fn new(sample_ms: u64, num_samples: usize) -> Self {
+        Self {
+            sample_ms: sample_ms.max(1),
+            num_samples: num_samples.max(2),
+            samples: VecDeque::new(),
+        }
+    }
+
+    fn window_ms(&self) -> u64 {
+        self.sample_ms * self.num_samples as u64
+    }
+
+    pub fn record(&mut self, bytes: u64, now_ms: u64) {
+        self.purge(now_ms);
+        let sample_ms = self.sample_ms;
+        match self.samples.back_mut() {
+            Some(ref mut sample) if now_ms < sample.start_ms + sample_ms => sample.bytes += bytes,
+            _ => {
+                let start_ms = now_ms - now_ms % sample_ms;
+                self.samples.push_back(Sample { start_ms, bytes });
+                while self.samples.len() > self.num_samples {
+                    self.samples.pop_front();
+                }
+            }
+        }
+    }
+
+    /// true if no samples are within window
+    pub fn is_idle(&self, now_ms: u64) -> bool {
+        self.samples
+            .back()
+            .map(|sample| sample.start_ms + self.window_ms() <= now_ms)
+            .unwrap_or(true)
+    }
+
+    fn purge(&mut self, now_ms: u64) {
+        let window_ms = self.window_ms();
+        while let Some(sample) = self.samples.front() {
+            if sample.start_ms + window_ms > now_ms {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    /// time in ms until rate of bytes in window falls to byte rate.
+    /// elapsed time is at least full window minus current sample so short bursts
+    /// are not measured over tiny interval
+    pub fn throttle_time_ms(&mut self, byte_rate: u64, now_ms: u64) -> u64 {
+        self.purge(now_ms);
+        let oldest_ms = match self.samples.front() {
+            Some(sample) => sample.start_ms,
+            None => return 0,
+        };
+        let total: u64 = self.samples.iter().map(|sample| sample.bytes).sum();
+        let min_elapsed_ms = self.sample_ms * (self.num_samples as u64 - 1);
+        let elapsed_ms = (now_ms - oldest_ms).max(min_elapsed_ms);
+
+        let byte_rate = byte_rate.max(1);
+        let allowed = byte_rate * elapsed_ms / 1000;
+        if total <= allowed {
+            return 0;
+        }
+        ((total - allowed) * 1000 / byte_rate).min(self.window_ms())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::RateWindow;
+
+    #[test]
+    fn test_rate_within_quota() {
+        let mut window = RateWindow::new(1000, 11);
+        for second in 0..20 {
+            window.record(90, second * 1000);
+            assert_eq!(window.throttle_time_ms(100, second * 1000), 0);
+        }
+    }
+
+    #[test]
+    fn test_throttle_time() {
+        let mut window = RateWindow::new(1000, 11);
+        // 10 seconds worth of quota is allowed as burst
+        window.record(1000, 0);
+        assert_eq!(window.throttle_time_ms(100, 0), 0);
+
+        // 500 bytes over, takes 5 seconds to drain
+        window.record(500, 500);
+        assert_eq!(window.throttle_time_ms(100, 500), 5000);
+
+        // throttle is bounded by window
+        window.record(100_000, 600);
+        assert_eq!(window.throttle_time_ms(100, 600), 11_000);
+    }
+
+    #[test]
+    fn test_samples_expire() {
+        let mut window = RateWindow::new(1000, 11);
+        window.record(5000, 0);
+        assert!(window.throttle_time_ms(100, 0) > 0);
+        assert!(!window.is_idle(10_999));
+        assert!(window.is_idle(11_000));
+        assert_eq!(window.throttle_time_ms(100, 11_000), 0);
+    }
+}
@@ -0,0 +1,271 @@
+//!
+//! # Log Directories
+//!
+//! SPU can be configured with multiple log directories.  Each replica is stored
+//! in one of them.  Directory that fails is marked offline along with its replicas,
+//! replicas on other directories are not affected.  Offline directories are
+//! sent to SC dispatcher, which reports their replicas to SC for leader election.
+//!
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+use futures::channel::mpsc::unbounded;
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::mpsc::UnboundedSender;
+use log::debug;
+use log::error;
+
+use metadata::partition::ReplicaKey;
+use storage::ConfigOption;
+use storage::FileReplica;
+use types::SpuId;
+
+use super::default_config;
+
+pub type SharedLogDirs = Arc<LogDirs>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogDir {
+    pub path: PathBuf,
+    pub online: bool,
+}
+
+#[derive(Debug)]
+pub struct LogDirs {
+    local_spu: SpuId,
+    dirs: RwLock<Vec<LogDir>>,
+    replicas: RwLock<HashMap<ReplicaKey, PathBuf>>,
+    offline_sender: UnboundedSender<PathBuf>,
+    offline_receiver: Mutex<Option<UnboundedReceiver<PathBuf>>>,
+}
+
+impl LogDirs {
+    /// create log dirs, directory which can't be created is offline
+    pub fn new(local_spu: SpuId, paths: Vec<PathBuf>) -> Self {
+        let dirs = paths
+            .into_iter()
+            .map(|path| {
+                let online = match std::fs::create_dir_all(&path) {
+                    Ok(_) => true,
+                    Err(err) => {
+                        error!("log dir: {} is not usable, {}", path.display(), err);
+                        false
+                    }
+                };
+                LogDir { path, online }
+            })
+            .collect();
+
+        let (offline_sender, offline_receiver) = unbounded();
+        Self {
+            local_spu,
+            dirs: RwLock::new(dirs),
+            replicas: RwLock::new(HashMap::new()),
+            offline_sender,
+            offline_receiver: Mutex::new(Some(offline_receiver)),
+        }
+    }
+
+    pub fn new_shared(local_spu: SpuId, paths: Vec<PathBuf>) -> SharedLogDirs {
+        Arc::new(Self::new(local_spu, paths))
+    }
+
+    pub fn dirs(&self) -> Vec<LogDir> {
+        self.dirs.read().unwrap().clone()
+    }
+
+    pub fn is_online(&self, path: &Path) -> bool {
+        self.dirs
+            .read()
+            .unwrap()
+            .iter()
+            .any(|dir| dir.path == path && dir.online)
+    }
+
+    /// mark directory offline, all replicas on it become offline.
+    /// directory is sent to receiver of offline dirs when it goes offline
+    pub fn mark_offline(&self, path: &Path) {
+        let mut changed = false;
+        for dir in self.dirs.write().unwrap().iter_mut() {
+            if dir.path == path && dir.online {
+                error!("marking log dir: {} offline", path.display());
+                dir.online = false;
+                changed = true;
+            }
+        }
+
+        if changed {
+            if let Err(err) = self.offline_sender.unbounded_send(path.to_owned()) {
+                error!("unable to send offline log dir: {}, {}", path.display(), err);
+            }
+        }
+    }
+
+    /// receiver of directories which went offline, can be taken only once
+    pub fn take_offline_receiver(&self) -> Option<UnboundedReceiver<PathBuf>> {
+        self.offline_receiver.lock().unwrap().take()
+    }
+
+    /// replicas stored on offline directories
+    pub fn offline_replicas(&self) -> Vec<ReplicaKey> {
+        let offline_dirs: Vec<PathBuf> = self
+            .dirs
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|dir| !dir.online)
+            .map(|dir| dir.path.clone())
+            .collect();
+        self.replicas
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, path)| offline_dirs.contains(path))
+            .map(|(replica, _)| replica.clone())
+            .collect()
+    }
+
+    /// mark directory of replica offline
+    pub fn mark_replica_offline(&self, replica: &ReplicaKey) {
+        if let Some(path) = self.replica_dir(replica) {
+            self.mark_offline(&path);
+        }
+    }
+
+    /// directory where replica is stored
+    pub fn replica_dir(&self, replica: &ReplicaKey) -> Option<PathBuf> {
+        self.replicas.read().unwrap().get(replica).cloned()
+    }
+
+    /// replica is online unless it is stored on offline directory
+    pub fn is_replica_online(&self, replica: &ReplicaKey) -> bool {
+        match self.replica_dir(replica) {
+            Some(path) => self.is_online(&path),
+            None => true,
+        }
+    }
+
+    /// replicas stored on directory
+    pub fn replicas_in(&self, path: &Path) -> Vec<ReplicaKey> {
+        self.replicas
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, dir)| dir.as_path() == path)
+            .map(|(replica, _)| replica.clone())
+            .collect()
+    }
+
+    pub fn set_replica_dir(&self, replica: ReplicaKey, path: PathBuf) {
+        debug!("replica: {} is stored at: {}", replica, path.display());
+        self.replicas.write().unwrap().insert(replica, path);
+    }
+
+    pub fn release(&self, replica: &ReplicaKey) -> Option<PathBuf> {
+        self.replicas.write().unwrap().remove(replica)
+    }
+
+    /// Storage config for replica with base directory set to its log directory.
+    /// Directory with existing replica storage is used first,
+    /// otherwise replica is placed on online directory with fewest replicas.
+    /// None if replica's directory is offline or no directory is online.
+    pub fn replica_config(
+        &self,
+        replica: &ReplicaKey,
+        base_config: &ConfigOption,
+    ) -> Option<ConfigOption> {
+        let path = match self.replica_dir(replica) {
+            Some(path) => path,
+            None => {
+                let path = self.find_existing(replica, base_config).or_else(|| self.least_used())?;
+                self.set_replica_dir(replica.clone(), path.clone());
+                path
+            }
+        };
+
+        if self.is_online(&path) {
+            Some(base_config.clone().base_dir(path))
+        } else {
+            error!("log dir: {} of replica: {} is offline", path.display(), replica);
+            None
+        }
+    }
+
+    fn find_existing(&self, replica: &ReplicaKey, base_config: &ConfigOption) -> Option<PathBuf> {
+        self.dirs
+            .read()
+            .unwrap()
+            .iter()
+            .find(|dir| {
+                let config = default_config(self.local_spu, &base_config.clone().base_dir(dir.path.clone()));
+                FileReplica::exists(&replica.topic, replica.partition as u32, &config)
+            })
+            .map(|dir| dir.path.clone())
+    }
+
+    fn least_used(&self) -> Option<PathBuf> {
+        let replicas = self.replicas.read().unwrap();
+        self.dirs
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|dir| dir.online)
+            .min_by_key(|dir| replicas.values().filter(|path| **path == dir.path).count())
+            .map(|dir| dir.path.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::env::temp_dir;
+
+    use storage::ConfigOption;
+    use metadata::partition::ReplicaKey;
+    use utils::fixture::ensure_clean_dir;
+
+    use super::LogDirs;
+
+    #[test]
+    fn test_replica_placement() {
+        let dir1 = temp_dir().join("log_dirs_test_1");
+        let dir2 = temp_dir().join("log_dirs_test_2");
+        ensure_clean_dir(&dir1);
+        ensure_clean_dir(&dir2);
+
+        let log_dirs = LogDirs::new(5001, vec![dir1.clone(), dir2.clone()]);
+        let base = ConfigOption::default();
+
+        let r1: ReplicaKey = ("test", 0).into();
+        let r2: ReplicaKey = ("test", 1).into();
+        let r3: ReplicaKey = ("test", 2).into();
+
+        let c1 = log_dirs.replica_config(&r1, &base).expect("config");
+        let c2 = log_dirs.replica_config(&r2, &base).expect("config");
+        assert_eq!(c1.base_dir, dir1);
+        assert_eq!(c2.base_dir, dir2);
+
+        // same replica stays on same dir
+        assert_eq!(log_dirs.replica_config(&r1, &base).expect("config").base_dir, dir1);
+
+        // failed dir takes only its replicas offline
+        let mut offline_receiver = log_dirs.take_offline_receiver().expect("receiver");
+        log_dirs.mark_offline(&dir1);
+        assert!(!log_dirs.is_replica_online(&r1));
+        assert!(log_dirs.is_replica_online(&r2));
+        assert!(log_dirs.replica_config(&r1, &base).is_none());
+        assert_eq!(log_dirs.offline_replicas(), vec![r1.clone()]);
+
+        // offline dir is sent only once
+        log_dirs.mark_offline(&dir1);
+        assert_eq!(offline_receiver.try_next().expect("dir"), Some(dir1.clone()));
+        assert!(offline_receiver.try_next().is_err());
+
+        // new replicas go to remaining dir
+        assert_eq!(log_dirs.replica_config(&r3, &base).expect("config").base_dir, dir2);
+    }
+}
@@ -1,7 +1,22 @@
+mod log_dirs;
+
+pub use self::log_dirs::LogDirs;
+pub use self::log_dirs::LogDir;
+pub use self::log_dirs::SharedLogDirs;
+
+use std::collections::BTreeMap;
+
+use log::warn;
 use storage::ConfigOption;
 use storage::FileReplica;
 use storage::StorageError;
+use storage::Tier;
 use metadata::partition::ReplicaKey;
+use metadata::topic::SEGMENT_BYTES;
+use metadata::topic::SEGMENT_MS;
+use metadata::topic::SEGMENT_INDEX_BYTES;
+use metadata::topic::INDEX_INTERVAL_BYTES;
+use metadata::topic::RETENTION_MS;
 use types::SpuId;
 
 fn default_config(spu_id: SpuId, config: &ConfigOption) -> ConfigOption {
@@ -19,3 +34,122 @@ pub(crate) async fn create_replica_storage(
     let config = default_config(local_spu, base_config);
     FileReplica::create(replica.topic.clone(), replica.partition as u32, 0, &config).await
 }
+
+/// Enable tiering of closed segments on leader replica storage if tier is configured.
+/// Remote segments are loaded from manifest in object store
+pub(crate) async fn enable_tier(storage: &mut FileReplica, tier: Option<&Tier>) -> Result<(), StorageError> {
+    match tier {
+        Some(tier) => storage.set_tier(tier.clone()).await,
+        None => Ok(()),
+    }
+}
+
+/// Remove replica storage directory, used when replica is deleted from SPU.
+/// Replica is removed from all online log directories
+pub(crate) fn remove_replica_storage(
+    local_spu: SpuId,
+    replica: &ReplicaKey,
+    base_config: &ConfigOption,
+    log_dirs: &LogDirs,
+) -> Result<(), StorageError> {
+    log_dirs.release(replica);
+    for dir in log_dirs.dirs().into_iter().filter(|dir| dir.online) {
+        let config = default_config(local_spu, &base_config.clone().base_dir(dir.path));
+        FileReplica::remove(&replica.topic, replica.partition as u32, &config)?;
+    }
+    Ok(())
+}
+
+/// Copy replica storage to another log directory, source is not removed.
+/// Only files changed since previous copy are copied, replica must not be written during final copy
+pub(crate) fn copy_replica_storage(
+    local_spu: SpuId,
+    replica: &ReplicaKey,
+    from_config: &ConfigOption,
+    to_config: &ConfigOption,
+) -> Result<(), StorageError> {
+    FileReplica::copy(
+        &replica.topic,
+        replica.partition as u32,
+        &default_config(local_spu, from_config),
+        &default_config(local_spu, to_config),
+    )
+}
+
+/// Remove replica storage from single log directory
+pub(crate) fn remove_replica_storage_from(
+    local_spu: SpuId,
+    replica: &ReplicaKey,
+    config: &ConfigOption,
+) -> Result<(), StorageError> {
+    FileReplica::remove(&replica.topic, replica.partition as u32, &default_config(local_spu, config))
+}
+
+/// Apply topic config overrides to SPU storage config.
+/// Segment and retention settings are applied to storage, other keys are ignored here.
+pub(crate) fn replica_config(
+    base_config: &ConfigOption,
+    topic_config: &BTreeMap<String, String>,
+) -> ConfigOption {
+    let mut config = base_config.clone();
+    for (key, value) in topic_config.iter() {
+        if key == SEGMENT_MS {
+            match value.parse() {
+                Ok(ms) => config.segment_max_age_ms = ms,
+                Err(err) => warn!("ignoring topic config {}: {}, {}", key, value, err),
+            }
+            continue;
+        }
+        if key == RETENTION_MS {
+            // -1 is unlimited, storage keeps segments forever with 0
+            match value.parse::<i64>() {
+                Ok(ms) => config.retention_max_age_ms = ms.max(0) as u64,
+                Err(err) => warn!("ignoring topic config {}: {}, {}", key, value, err),
+            }
+            continue;
+        }
+        let target = match key.as_str() {
+            SEGMENT_BYTES => &mut config.segment_max_bytes,
+            SEGMENT_INDEX_BYTES => &mut config.index_max_bytes,
+            INDEX_INTERVAL_BYTES => &mut config.index_max_interval_bytes,
+            _ => continue,
+        };
+        match value.parse() {
+            Ok(bytes) => *target = bytes,
+            Err(err) => warn!("ignoring topic config {}: {}, {}", key, value, err),
+        }
+    }
+    config
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::BTreeMap;
+
+    use storage::ConfigOption;
+    use metadata::topic::SEGMENT_BYTES;
+    use metadata::topic::SEGMENT_MS;
+    use metadata::topic::RETENTION_MS;
+
+    use super::replica_config;
+
+    #[test]
+    fn test_replica_config_overrides() {
+        let base = ConfigOption::default();
+        let mut topic_config = BTreeMap::new();
+        topic_config.insert(SEGMENT_BYTES.to_owned(), "1000".to_owned());
+        topic_config.insert(RETENTION_MS.to_owned(), "60000".to_owned());
+        topic_config.insert(SEGMENT_MS.to_owned(), "3600000".to_owned());
+
+        let config = replica_config(&base, &topic_config);
+        assert_eq!(config.segment_max_bytes, 1000);
+        assert_eq!(config.segment_max_age_ms, 3600000);
+        assert_eq!(config.retention_max_age_ms, 60000);
+        assert_eq!(config.index_max_bytes, base.index_max_bytes);
+        assert_eq!(config.base_dir, base.base_dir);
+
+        topic_config.insert(RETENTION_MS.to_owned(), "-1".to_owned());
+        assert_eq!(replica_config(&base, &topic_config).retention_max_age_ms, 0);
+    }
+}
@@ -1,5 +1,5 @@
 #![feature(generators)]
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 
 mod error;
 mod start;
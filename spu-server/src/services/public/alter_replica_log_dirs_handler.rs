@@ -0,0 +1,176 @@
+use std::io::Error;
+use std::path::PathBuf;
+
+use log::trace;
+use log::debug;
+use log::error;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+use kf_protocol::message::log_dirs::KfAlterReplicaLogDirsRequest;
+use kf_protocol::message::log_dirs::KfAlterReplicaLogDirsResponse;
+use kf_protocol::message::log_dirs::AlterReplicaLogDirTopicResult;
+use kf_protocol::message::log_dirs::AlterReplicaLogDirPartitionResult;
+use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use storage::ConfigOption;
+use storage::FileReplica;
+use storage::StorageError;
+use types::SpuId;
+use future_aio::asyncify;
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::core::storage::replica_config;
+use crate::core::storage::create_replica_storage;
+use crate::core::storage::enable_tier;
+use crate::core::storage::copy_replica_storage;
+use crate::core::storage::remove_replica_storage_from;
+
+/// move replicas between log directories of this spu
+pub async fn handle_alter_replica_log_dirs_request(
+    request: RequestMessage<KfAlterReplicaLogDirsRequest>,
+    ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfAlterReplicaLogDirsResponse>, Error> {
+    let (header, alter_request) = request.get_header_request();
+    trace!("handling alter replica log dirs request: {:#?}", alter_request);
+
+    let mut response = KfAlterReplicaLogDirsResponse::default();
+
+    for dir_request in alter_request.dirs {
+        let target_dir = PathBuf::from(&dir_request.path);
+        let dir_online = ctx.log_dirs().is_online(&target_dir);
+
+        for topic_request in dir_request.topics {
+            let topic = &topic_request.name;
+            let mut topic_response = AlterReplicaLogDirTopicResult::default();
+            topic_response.topic_name = topic.to_owned();
+
+            let authorized = ctx
+                .acl_localstore()
+                .authorize_topic(identity, topic, &AclOperation::Alter);
+
+            for partition in topic_request.partitions {
+                let rep_id = ReplicaKey::new(topic.clone(), partition);
+
+                let mut partition_response = AlterReplicaLogDirPartitionResult::default();
+                partition_response.partition_index = partition;
+                partition_response.error_code = if !authorized {
+                    ErrorCode::TopicAuthorizationFailed
+                } else if !dir_online {
+                    ErrorCode::LogDirNotFound
+                } else {
+                    move_replica(&ctx, &rep_id, &target_dir).await
+                };
+
+                topic_response.partitions.push(partition_response);
+            }
+
+            response.results.push(topic_response);
+        }
+    }
+
+    trace!("alter replica log dirs request completed");
+
+    Ok(RequestMessage::<KfAlterReplicaLogDirsRequest>::response_with_header(&header, response))
+}
+
+/// move replica storage to target dir.
+/// replica is copied while it is in use, then locked only to copy records written
+/// meanwhile and to swap storage reopened from target dir
+async fn move_replica(
+    ctx: &DefaultSharedGlobalContext,
+    rep_id: &ReplicaKey,
+    target_dir: &PathBuf,
+) -> ErrorCode {
+    let replica = match ctx.replica_localstore().spec(rep_id) {
+        Some(replica) => replica,
+        None => return ErrorCode::ReplicaNotAvailable,
+    };
+
+    let current_dir = match ctx.log_dirs().replica_dir(rep_id) {
+        Some(dir) => dir,
+        None => return ErrorCode::ReplicaNotAvailable,
+    };
+
+    if current_dir == *target_dir {
+        return ErrorCode::None;
+    }
+
+    if !ctx.log_dirs().is_online(&current_dir) {
+        return ErrorCode::KafkaStorageError;
+    }
+
+    debug!(
+        "moving replica: {} from: {} to: {}",
+        rep_id,
+        current_dir.display(),
+        target_dir.display()
+    );
+
+    let local_spu = ctx.local_spu_id();
+    let base_config = replica_config(&ctx.config().storage().new_config(), &replica.config);
+    let from_config = base_config.clone().base_dir(current_dir);
+    let to_config = base_config.base_dir(target_dir.clone());
+
+    let bulk_from_config = from_config.clone();
+    let bulk_to_config = to_config.clone();
+    let bulk_rep_id = rep_id.clone();
+    if let Err(err) = asyncify(move || {
+        copy_replica_storage(local_spu, &bulk_rep_id, &bulk_from_config, &bulk_to_config)
+    })
+    .await
+    {
+        error!("error copying replica: {}, {}", rep_id, err);
+        let _ = remove_replica_storage_from(local_spu, rep_id, &to_config);
+        return ErrorCode::KafkaStorageError;
+    }
+
+    let result = if let Some(mut leader) = ctx.leaders_state().get_mut_replica(rep_id) {
+        match reopen_storage(local_spu, rep_id, &from_config, &to_config).await {
+            Ok(mut storage) => enable_tier(&mut storage, ctx.tier()).await.map(|_| {
+                *leader.mut_storage() = storage;
+            }),
+            Err(err) => Err(err),
+        }
+    } else if let Some(mut follower) = ctx.followers_state().get_mut_replica(rep_id) {
+        match reopen_storage(local_spu, rep_id, &from_config, &to_config).await {
+            Ok(storage) => {
+                *follower.mut_storage() = storage;
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else {
+        return ErrorCode::ReplicaNotAvailable;
+    };
+
+    match result {
+        Ok(_) => {
+            ctx.log_dirs().set_replica_dir(rep_id.clone(), target_dir.clone());
+            if let Err(err) = remove_replica_storage_from(local_spu, rep_id, &from_config) {
+                error!("error removing old storage of replica: {}, {}", rep_id, err);
+            }
+            ErrorCode::None
+        }
+        Err(err) => {
+            error!("error moving replica: {}, {}", rep_id, err);
+            // clean up partial copy, replica stays on current dir
+            let _ = remove_replica_storage_from(local_spu, rep_id, &to_config);
+            ErrorCode::KafkaStorageError
+        }
+    }
+}
+
+/// copy changes since bulk copy and open storage from target dir
+async fn reopen_storage(
+    local_spu: SpuId,
+    rep_id: &ReplicaKey,
+    from_config: &ConfigOption,
+    to_config: &ConfigOption,
+) -> Result<FileReplica, StorageError> {
+    copy_replica_storage(local_spu, rep_id, from_config, to_config)?;
+    create_replica_storage(local_spu, rep_id, to_config).await
+}
@@ -13,6 +13,15 @@ use kf_protocol::api::ResponseMessage;
 use kf_protocol::api::Request;
 use kf_protocol::message::produce::DefaultKfProduceRequest;
 use kf_protocol::message::fetch::DefaultKfFetchRequest;
+use kf_protocol::message::sasl::KfSaslHandshakeRequest;
+use kf_protocol::message::sasl::KfSaslAuthenticateRequest;
+use kf_protocol::message::records::KfDeleteRecordsRequest;
+use kf_protocol::message::log_dirs::KfDescribeLogDirsRequest;
+use kf_protocol::message::log_dirs::KfAlterReplicaLogDirsRequest;
+use kf_protocol::message::offset::KfListOffsetRequest;
+use kf_service::sasl::SASL_HANDSHAKE_MIN_VERSION;
+
+use super::list_offsets_handler::LIST_OFFSETS_MIN_VERSION;
 
 pub async fn handle_kf_lookup_version_request(
     request: RequestMessage<ApiVersionsRequest>,
@@ -33,6 +42,36 @@ pub async fn handle_kf_lookup_version_request(
         .push(make_version_key(SpuApiKey::KfFetch,
              DefaultKfFetchRequest::MIN_API_VERSION,
              DefaultKfFetchRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfListOffsets,
+             LIST_OFFSETS_MIN_VERSION,
+             KfListOffsetRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfSaslHandshake,
+             SASL_HANDSHAKE_MIN_VERSION,
+             KfSaslHandshakeRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfSaslAuthenticate,
+             KfSaslAuthenticateRequest::MIN_API_VERSION,
+             KfSaslAuthenticateRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfDeleteRecords,
+             KfDeleteRecordsRequest::MIN_API_VERSION,
+             KfDeleteRecordsRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfDescribeLogDirs,
+             KfDescribeLogDirsRequest::MIN_API_VERSION,
+             KfDescribeLogDirsRequest::MAX_API_VERSION));
+    response
+        .api_keys
+        .push(make_version_key(SpuApiKey::KfAlterReplicaLogDirs,
+             KfAlterReplicaLogDirsRequest::MIN_API_VERSION,
+             KfAlterReplicaLogDirsRequest::MAX_API_VERSION));
 
     // Fluvio
     response
@@ -0,0 +1,77 @@
+use std::io::Error;
+
+use log::trace;
+use log::debug;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+use kf_protocol::message::records::KfDeleteRecordsRequest;
+use kf_protocol::message::records::KfDeleteRecordsResponse;
+use kf_protocol::message::records::DeleteRecordsTopicResult;
+use kf_protocol::message::records::DeleteRecordsPartitionResult;
+use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+
+use crate::core::DefaultSharedGlobalContext;
+
+/// move log start offset of leader replicas forward
+pub async fn handle_delete_records_request(
+    request: RequestMessage<KfDeleteRecordsRequest>,
+    ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfDeleteRecordsResponse>, Error> {
+    let (header, delete_request) = request.get_header_request();
+    trace!("handling delete records request: {:#?}", delete_request);
+
+    let mut response = KfDeleteRecordsResponse::default();
+
+    for topic_request in delete_request.topics {
+        let topic = &topic_request.name;
+
+        let mut topic_response = DeleteRecordsTopicResult::default();
+        topic_response.name = topic.to_owned();
+
+        let authorized = ctx
+            .acl_localstore()
+            .authorize_topic(identity, topic, &AclOperation::Delete);
+
+        for partition_request in topic_request.partitions {
+            let rep_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
+
+            let mut partition_response = DeleteRecordsPartitionResult::default();
+            partition_response.partition_index = rep_id.partition;
+            partition_response.low_watermark = -1;
+
+            if !authorized {
+                partition_response.error_code = ErrorCode::TopicAuthorizationFailed;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            match ctx
+                .leaders_state()
+                .delete_records(&rep_id, partition_request.offset)
+                .await
+            {
+                Ok(log_start_offset) => {
+                    debug!("replica: {} log start offset moved to: {}", rep_id, log_start_offset);
+                    partition_response.low_watermark = log_start_offset;
+                    partition_response.error_code = ErrorCode::None;
+                }
+                Err(error_code) => {
+                    partition_response.error_code = error_code;
+                }
+            }
+
+            topic_response.partitions.push(partition_response);
+        }
+
+        response.topics.push(topic_response);
+    }
+
+    trace!("delete records request completed");
+
+    Ok(RequestMessage::<KfDeleteRecordsRequest>::response_with_header(&header, response))
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::path::PathBuf;
+
+use log::trace;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+use kf_protocol::message::log_dirs::KfDescribeLogDirsRequest;
+use kf_protocol::message::log_dirs::KfDescribeLogDirsResponse;
+use kf_protocol::message::log_dirs::DescribeLogDirsResult;
+use kf_protocol::message::log_dirs::DescribeLogDirsTopic;
+use kf_protocol::message::log_dirs::DescribeLogDirsPartition;
+use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use storage::ReplicaStorage;
+
+use crate::core::DefaultSharedGlobalContext;
+
+/// report disk usage of replicas stored on this spu
+pub async fn handle_describe_log_dirs_request(
+    request: RequestMessage<KfDescribeLogDirsRequest>,
+    ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfDescribeLogDirsResponse>, Error> {
+    let (header, describe_request) = request.get_header_request();
+    trace!("handling describe log dirs request: {:#?}", describe_request);
+
+    let requested = describe_request.topics.map(|topics| {
+        topics
+            .into_iter()
+            .flat_map(|topic| {
+                let name = topic.topic;
+                topic
+                    .partition_index
+                    .into_iter()
+                    .map(move |partition| ReplicaKey::new(name.clone(), partition))
+            })
+            .collect::<Vec<ReplicaKey>>()
+    });
+
+    // partitions grouped by log dir and topic
+    let mut dirs: BTreeMap<PathBuf, BTreeMap<String, Vec<DescribeLogDirsPartition>>> = BTreeMap::new();
+
+    for replica_key in ctx.replica_localstore().all_keys() {
+        if let Some(ref keys) = requested {
+            if !keys.contains(&replica_key) {
+                continue;
+            }
+        }
+
+        if !ctx
+            .acl_localstore()
+            .authorize_topic(identity, &replica_key.topic, &AclOperation::Describe)
+        {
+            continue;
+        }
+
+        // lag is measured against leader end offset, so leader never lags
+        let usage = if let Some(leader) = ctx.leaders_state().get_replica(&replica_key) {
+            Some((leader.storage().get_size(), 0))
+        } else if let Some(follower) = ctx.followers_state().get_replica(&replica_key) {
+            Some((follower.storage().get_size(), follower.offset_lag()))
+        } else {
+            None
+        };
+
+        let log_dir = ctx.log_dirs().replica_dir(&replica_key);
+        if let (Some((size, lag)), Some(log_dir)) = (usage, log_dir) {
+            let mut partition = DescribeLogDirsPartition::default();
+            partition.partition_index = replica_key.partition;
+            partition.partition_size = size as i64;
+            partition.offset_lag = lag;
+            dirs.entry(log_dir)
+                .or_insert_with(BTreeMap::new)
+                .entry(replica_key.topic.clone())
+                .or_insert_with(Vec::new)
+                .push(partition);
+        }
+    }
+
+    let mut response = KfDescribeLogDirsResponse::default();
+    for log_dir in ctx.log_dirs().dirs() {
+        let mut result = DescribeLogDirsResult::default();
+        result.log_dir = log_dir.path.display().to_string();
+        if log_dir.online {
+            result.topics = dirs
+                .remove(&log_dir.path)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, partitions)| DescribeLogDirsTopic { name, partitions })
+                .collect();
+        } else {
+            result.error_code = ErrorCode::KafkaStorageError;
+        }
+        response.results.push(result);
+    }
+
+    Ok(RequestMessage::<KfDescribeLogDirsRequest>::response_with_header(&header, response))
+}
@@ -1,22 +1,35 @@
+use std::time::Duration;
+
+use log::debug;
 use log::trace;
 
+use future_helper::sleep;
+
 use kf_socket::KfSink;
 use kf_socket::KfSocketError;
 use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ErrorCode;
+use kf_protocol::Encoder;
 use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use metadata::quota::QuotaType;
 use kf_socket::FileFetchResponse;
 use kf_socket::KfFileFetchRequest;
 use kf_socket::FilePartitionResponse;
 use kf_socket::FileTopicResponse;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::core::metrics::record_bytes_out;
 
 
 pub async fn handle_fetch_request(
     request: RequestMessage<KfFileFetchRequest>,
     ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
     sink: &mut KfSink,
 ) -> Result<(), KfSocketError> {
+    let _timer = ctx.request_metrics().start(request.header.api_key());
     let (header, fetch_request) = request.get_header_request();
  
     let mut fetch_response = FileFetchResponse::default();
@@ -27,6 +40,10 @@ pub async fn handle_fetch_request(
         let mut topic_response = FileTopicResponse::default();
         topic_response.name = topic.clone();
 
+        let authorized = ctx
+            .acl_localstore()
+            .authorize_topic(identity, topic, &AclOperation::Read);
+
         for partition_req in &topic_request.fetch_partitions {
             let partition = &partition_req.partition_index;
             let fetch_offset = partition_req.fetch_offset;
@@ -34,6 +51,18 @@ pub async fn handle_fetch_request(
             let mut partition_response = FilePartitionResponse::default();
             partition_response.partition_index = *partition;
 
+            if !authorized {
+                partition_response.error_code = ErrorCode::TopicAuthorizationFailed;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            if !ctx.log_dirs().is_replica_online(&rep_id) {
+                partition_response.error_code = ErrorCode::KafkaStorageError;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
             ctx.leaders_state().read_records(
                 &rep_id,
                 fetch_offset,
@@ -41,6 +70,7 @@ pub async fn handle_fetch_request(
                 &mut partition_response,
             )
             .await;
+            record_bytes_out(ctx.metrics(), topic, partition_response.records.len());
 
             topic_response.partitions.push(partition_response);
         }
@@ -48,6 +78,18 @@ pub async fn handle_fetch_request(
         fetch_response.topics.push(topic_response);
     }
 
+    let throttle_ms = ctx.quota_manager().record(
+        QuotaType::Fetch,
+        &identity.principal,
+        header.client_id(),
+        fetch_response.write_size(header.api_version()),
+    );
+    if throttle_ms > 0 {
+        debug!("throttling fetch response to client: {} for {} ms", header.client_id(), throttle_ms);
+        fetch_response.throttle_time_ms = throttle_ms as i32;
+        sleep(Duration::from_millis(throttle_ms)).await;
+    }
+
     let response =
         RequestMessage::<KfFileFetchRequest>::response_with_header(&header, fetch_response);
     trace!("sending back file fetch response: {:#?}",response);
@@ -0,0 +1,105 @@
+use std::io::Error;
+
+use log::trace;
+use log::debug;
+use log::error;
+
+use kf_protocol::api::ErrorCode;
+use kf_protocol::api::RequestMessage;
+use kf_protocol::api::ResponseMessage;
+use kf_protocol::message::offset::KfListOffsetRequest;
+use kf_protocol::message::offset::KfListOffsetResponse;
+use kf_protocol::message::offset::ListOffsetTopicResponse;
+use kf_protocol::message::offset::ListOffsetPartitionResponse;
+use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use storage::ReplicaStorage;
+
+use crate::core::DefaultSharedGlobalContext;
+
+/// version 0 returns list of old style offsets, which is not supported
+pub const LIST_OFFSETS_MIN_VERSION: i16 = 1;
+
+/// timestamp requesting offset after last committed record
+const LATEST_TIMESTAMP: i64 = -1;
+
+/// timestamp requesting first offset in log
+const EARLIEST_TIMESTAMP: i64 = -2;
+
+/// look up offsets of leader replicas by timestamp.
+/// offset is -1 if there is no committed record at or after timestamp
+pub async fn handle_list_offsets_request(
+    request: RequestMessage<KfListOffsetRequest>,
+    ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
+) -> Result<ResponseMessage<KfListOffsetResponse>, Error> {
+    let (header, list_request) = request.get_header_request();
+    trace!("handling list offsets request: {:#?}", list_request);
+
+    let mut response = KfListOffsetResponse::default();
+
+    for topic_request in list_request.topics {
+        let topic = &topic_request.name;
+
+        let mut topic_response = ListOffsetTopicResponse::default();
+        topic_response.name = topic.to_owned();
+
+        let authorized = ctx
+            .acl_localstore()
+            .authorize_topic(identity, topic, &AclOperation::Describe);
+
+        for partition_request in topic_request.partitions {
+            let rep_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
+
+            let mut partition_response = ListOffsetPartitionResponse::default();
+            partition_response.partition_index = rep_id.partition;
+            partition_response.timestamp = -1;
+            partition_response.offset = -1;
+            partition_response.leader_epoch = -1;
+
+            if !authorized {
+                partition_response.error_code = ErrorCode::TopicAuthorizationFailed;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            match ctx.leaders_state().get_replica(&rep_id) {
+                Some(replica) => {
+                    let storage = replica.storage();
+                    match partition_request.timestamp {
+                        LATEST_TIMESTAMP => partition_response.offset = storage.get_hw(),
+                        EARLIEST_TIMESTAMP => {
+                            partition_response.offset = storage.get_log_start_offset()
+                        }
+                        timestamp => match storage.find_offset_by_timestamp(timestamp).await {
+                            Ok(Some((offset, batch_timestamp))) => {
+                                partition_response.offset = offset;
+                                partition_response.timestamp = batch_timestamp;
+                            }
+                            Ok(None) => {
+                                debug!("replica: {} has no records at or after: {}", rep_id, timestamp);
+                            }
+                            Err(err) => {
+                                error!("error looking up offset of replica: {}, {}", rep_id, err);
+                                partition_response.error_code = ErrorCode::KafkaStorageError;
+                            }
+                        },
+                    }
+                }
+                None => {
+                    trace!("list offsets, replica is not leader: {}", rep_id);
+                    partition_response.error_code = ErrorCode::NotLeaderForPartition;
+                }
+            }
+
+            topic_response.partitions.push(partition_response);
+        }
+
+        response.topics.push(topic_response);
+    }
+
+    trace!("list offsets request completed");
+
+    Ok(RequestMessage::<KfListOffsetRequest>::response_with_header(&header, response))
+}
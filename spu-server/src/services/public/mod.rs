@@ -2,6 +2,10 @@ mod api_versions;
 mod service_impl;
 mod produce_handler;
 mod fetch_handler;
+mod list_offsets_handler;
+mod delete_records_handler;
+mod describe_log_dirs_handler;
+mod alter_replica_log_dirs_handler;
 mod local_spu_request;
 mod offset_request;
 
@@ -26,5 +30,6 @@ pub fn create_public_server(addr: SocketAddr, ctx: DefaultSharedGlobalContext) -
 {
     info!("starting SPU: {} at public service at: {}", ctx.local_spu_id(),addr);
 
-    KfApiServer::new(addr, ctx, PublicService::new())
+    let server_config = ctx.config().public_server.clone();
+    KfApiServer::new(addr, ctx, PublicService::new()).with_config(server_config)
 }
@@ -1,7 +1,9 @@
 use std::io::Error;
+use std::time::Duration;
 
 use log::warn;
 use log::trace;
+use log::debug;
 use log::error;
 
 use kf_protocol::api::ErrorCode;
@@ -11,21 +13,33 @@ use kf_protocol::message::produce::TopicProduceResponse;
 use kf_protocol::message::produce::PartitionProduceResponse;
 use kf_protocol::api::RequestMessage;
 use kf_protocol::api::ResponseMessage;
+use kf_protocol::Encoder;
+use future_helper::sleep;
 use metadata::partition::ReplicaKey;
+use metadata::acl::AclIdentity;
+use metadata::acl::AclOperation;
+use metadata::quota::QuotaType;
+use storage::StorageError;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::core::metrics::record_bytes_in;
+use crate::InternalServerError;
 
 
 pub async fn handle_produce_request(
     request: RequestMessage<DefaultKfProduceRequest>,
     ctx: DefaultSharedGlobalContext,
+    identity: &AclIdentity,
 ) -> Result<ResponseMessage<KfProduceResponse>, Error> {
     let (header, produce_request) = request.get_header_request();
     trace!("handling produce request: {:#?}", produce_request);
 
+    let request_bytes = produce_request.write_size(header.api_version());
+
     let mut response = KfProduceResponse::default();
 
-    //let ack = produce_request.acks;
+    let acks = produce_request.acks;
+    let lag_time = Duration::from_millis(ctx.config().replication.lag_time_max_ms);
 
     for topic_request in produce_request.topics {
         let topic = &topic_request.name;
@@ -34,6 +48,10 @@ pub async fn handle_produce_request(
         let mut topic_response = TopicProduceResponse::default();
         topic_response.name = topic.to_owned();
 
+        let authorized = ctx
+            .acl_localstore()
+            .authorize_topic(identity, topic, &AclOperation::Write);
+
         for partition_request in topic_request.partitions {
             let rep_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
 
@@ -42,9 +60,45 @@ pub async fn handle_produce_request(
             let mut partition_response = PartitionProduceResponse::default();
             partition_response.partition_index = rep_id.partition;
 
+            if !authorized {
+                partition_response.error_code = ErrorCode::TopicAuthorizationFailed;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            if !ctx.log_dirs().is_replica_online(&rep_id) {
+                partition_response.error_code = ErrorCode::KafkaStorageError;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            let mut records = partition_request.records;
+            let policy = match ctx.replica_localstore().spec(&rep_id) {
+                Some(replica) => ctx.config().produce.for_topic(&replica.config),
+                None => ctx.config().produce.clone(),
+            };
+            if let Err(error_code) = policy.apply(&mut records) {
+                debug!("records rejected for replica: {}, {:?}", rep_id, error_code);
+                partition_response.error_code = error_code;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            let in_sync_check = match ctx.leaders_state().get_replica(&rep_id) {
+                Some(leader) => policy.check_in_sync(acks, leader.in_sync_replica_count(lag_time)),
+                None => Ok(()),
+            };
+            if let Err(error_code) = in_sync_check {
+                debug!("not enough in sync replicas for replica: {}", rep_id);
+                partition_response.error_code = error_code;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            let records_bytes = records.write_size(header.api_version());
             match ctx.leaders_state().send_records(
                 &rep_id,
-                partition_request.records,
+                records,
                 true,
             )
             .await
@@ -53,6 +107,7 @@ pub async fn handle_produce_request(
                     if found_flag {
                         trace!("records has successfull processed for: {}", rep_id);
                         partition_response.error_code = ErrorCode::None;
+                        record_bytes_in(ctx.metrics(), topic, records_bytes);
                     } else {
                         warn!("no replica found: {}", rep_id);
                         partition_response.error_code = ErrorCode::NotLeaderForPartition;
@@ -60,6 +115,9 @@ pub async fn handle_produce_request(
                 }
                 Err(err) => {
                     error!("error: {:#?} writing to replica: {}", err, rep_id);
+                    if let InternalServerError::StorageError(StorageError::IoError(_)) = err {
+                        ctx.log_dirs().mark_replica_offline(&rep_id);
+                    }
                     partition_response.error_code = ErrorCode::KafkaStorageError;
                 }
             }
@@ -70,6 +128,18 @@ pub async fn handle_produce_request(
         response.responses.push(topic_response);
     }
 
+    let throttle_ms = ctx.quota_manager().record(
+        QuotaType::Produce,
+        &identity.principal,
+        header.client_id(),
+        request_bytes,
+    );
+    if throttle_ms > 0 {
+        debug!("throttling produce response from client: {} for {} ms", header.client_id(), throttle_ms);
+        response.throttle_time_ms = throttle_ms as i32;
+        sleep(Duration::from_millis(throttle_ms)).await;
+    }
+
     trace!("produce request completed");
 
     Ok(RequestMessage::<DefaultKfProduceRequest>::response_with_header(&header, response))
@@ -8,14 +8,22 @@ use kf_socket::KfSocket;
 use kf_socket::KfSocketError;
 use kf_service::call_service;
 use kf_service::KfService;
-use kf_service::api_loop;
+use kf_service::sasl_api_loop;
+use kf_service::sasl::SaslSession;
+use kf_service::sasl::handle_sasl_handshake_request;
+use kf_service::sasl::handle_sasl_authenticate_request;
 use spu_api::SpuApiKey;
 use spu_api::PublicRequest;
+use metadata::acl::AclIdentity;
 
 use crate::core::DefaultSharedGlobalContext;
 use super::api_versions::handle_kf_lookup_version_request;
 use super::produce_handler::handle_produce_request;
 use super::fetch_handler::handle_fetch_request;
+use super::list_offsets_handler::handle_list_offsets_request;
+use super::delete_records_handler::handle_delete_records_request;
+use super::describe_log_dirs_handler::handle_describe_log_dirs_request;
+use super::alter_replica_log_dirs_handler::handle_alter_replica_log_dirs_request;
 use super::local_spu_request::handle_spu_request;
 use super::offset_request::handle_offset_request;
 
@@ -30,41 +38,106 @@ impl PublicService {
 
     async fn handle(self: Arc<Self>, context: DefaultSharedGlobalContext, socket: KfSocket) -> Result<(),KfSocketError> {
 
+        // principal is anonymous until connection is authenticated
+        let host = socket.peer_addr().map(|addr| addr.ip().to_string()).unwrap_or_default();
+        let mut identity = AclIdentity::anonymous(host);
+        let mut session = SaslSession::new(context.config().sasl.clone());
+
+        let _connection = context.request_metrics().open_connection();
         let (mut sink,mut stream) = socket.split();
         let mut api_stream = stream.api_stream::<PublicRequest,SpuApiKey>();
 
-        api_loop!(
+        sasl_api_loop!(
             api_stream,
+            session,
+            [
+                PublicRequest::ApiVersionsRequest(_)
+                    | PublicRequest::KfSaslHandshakeRequest(_)
+                    | PublicRequest::KfSaslAuthenticateRequest(_)
+            ],
             
             // Mixed
             PublicRequest::ApiVersionsRequest(request) => call_service!(
                 request,
                 handle_kf_lookup_version_request(request),
                 sink,
-                "kf api version handler"
+                "kf api version handler",
+                context.request_metrics()
+            ),
+
+            // Kafka - SASL
+            PublicRequest::KfSaslHandshakeRequest(request) => call_service!(
+                request,
+                handle_sasl_handshake_request(request,&mut session),
+                sink,
+                "sasl handshake handler",
+                context.request_metrics()
             ),
+            PublicRequest::KfSaslAuthenticateRequest(request) => {
+                call_service!(
+                    request,
+                    handle_sasl_authenticate_request(request,&mut session,context.credential_localstore()),
+                    sink,
+                    "sasl authenticate handler",
+                    context.request_metrics()
+                );
+                if let Some(principal) = session.principal() {
+                    identity.principal = principal;
+                }
+            },
 
             // Kafka
             PublicRequest::KfProduceRequest(request) => call_service!(
                 request,
-                handle_produce_request(request,context.clone()),
+                handle_produce_request(request,context.clone(),&identity),
+                sink,
+                "ks produce request handler",
+                context.request_metrics()
+            ),
+            PublicRequest::KfFileFetchRequest(request) => handle_fetch_request(request,context.clone(),&identity,&mut sink).await?,
+            PublicRequest::KfListOffsetRequest(request) => call_service!(
+                request,
+                handle_list_offsets_request(request,context.clone(),&identity),
+                sink,
+                "ks list offsets handler",
+                context.request_metrics()
+            ),
+            PublicRequest::KfDeleteRecordsRequest(request) => call_service!(
+                request,
+                handle_delete_records_request(request,context.clone(),&identity),
+                sink,
+                "ks delete records handler",
+                context.request_metrics()
+            ),
+            PublicRequest::KfDescribeLogDirsRequest(request) => call_service!(
+                request,
+                handle_describe_log_dirs_request(request,context.clone(),&identity),
+                sink,
+                "ks describe log dirs handler",
+                context.request_metrics()
+            ),
+            PublicRequest::KfAlterReplicaLogDirsRequest(request) => call_service!(
+                request,
+                handle_alter_replica_log_dirs_request(request,context.clone(),&identity),
                 sink,
-                "ks produce request handler"
+                "ks alter replica log dirs handler",
+                context.request_metrics()
             ),
-            PublicRequest::KfFileFetchRequest(request) => handle_fetch_request(request,context.clone(),&mut sink).await?,
             
             // Fluvio
             PublicRequest::FlvFetchLocalSpuRequest(request) => call_service!(
                 request,
                 handle_spu_request(request,context.clone()),
                 sink,
-                "handling local spu request"
+                "handling local spu request",
+                context.request_metrics()
             ),
             PublicRequest::FlvFetchOffsetsRequest(request) => call_service!(
                 request,
                 handle_offset_request(request,context.clone()),
                 sink,
-                "handling offset fetch request"
+                "handling offset fetch request",
+                context.request_metrics()
             )
         );
 
@@ -3,6 +3,7 @@ use log::debug;
 
 use future_helper::run;
 use storage::FileReplica;
+use kf_service::MetricsServer;
 
 use crate::config::process_spu_cli_or_exit;
 use crate::config::SpuConfig;
@@ -29,10 +30,11 @@ pub fn main_loop() {
     debug!("spu config: {:#?}",spu_config);
 
     run(async {
-        let (_ctx, internal_server, public_server) = create_services(spu_config, true, true);
+        let (ctx, internal_server, public_server) = create_services(spu_config, true, true);
 
         let _public_shutdown = internal_server.unwrap().run();
         let _private_shutdown = public_server.unwrap().run();
+        MetricsServer::new(*ctx.config().metrics_socket_addr(), ctx.metrics_owned(), ctx.clone()).run();
     });
 }
 
@@ -8,6 +8,7 @@ use serde::Deserialize;
 use types::defaults::SPU_LOG_INDEX_MAX_BYTES;
 use types::defaults::SPU_LOG_INDEX_MAX_INTERVAL_BYTES;
 use types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
+use types::defaults::SPU_LOG_SEGMENT_MAX_AGE_MS;
 
 use kf_protocol::api::Size;
 
@@ -22,6 +23,10 @@ pub struct ConfigOption {
     pub index_max_interval_bytes: Size,
     #[serde(default = "default_segment_max_bytes")]
     pub segment_max_bytes: Size,
+    #[serde(default = "default_segment_max_age_ms")]
+    pub segment_max_age_ms: u64,    // 0 disables time based roll
+    #[serde(default)]
+    pub retention_max_age_ms: u64,  // 0 keeps segments forever
 }
 
 
@@ -49,13 +54,19 @@ fn default_segment_max_bytes() -> Size {
     SPU_LOG_SEGMENT_MAX_BYTES
 }
 
+fn default_segment_max_age_ms() -> u64 {
+    SPU_LOG_SEGMENT_MAX_AGE_MS
+}
+
 impl ConfigOption {
     pub fn new(base_dir: PathBuf, index_max_bytes: u32, index_max_interval_bytes: u32, segment_max_bytes: u32) -> Self {
         ConfigOption {
             base_dir,
             index_max_bytes,
             index_max_interval_bytes,
-            segment_max_bytes
+            segment_max_bytes,
+            segment_max_age_ms: default_segment_max_age_ms(),
+            retention_max_age_ms: 0,
         }
     }
 
@@ -69,10 +80,25 @@ impl ConfigOption {
         self
     }
 
+    pub fn index_max_interval_bytes(mut self, bytes: Size) -> Self {
+        self.index_max_interval_bytes = bytes;
+        self
+    }
+
     pub fn segment_max_bytes(mut self, bytes: Size) -> Self {
         self.segment_max_bytes = bytes;
         self
     }
+
+    pub fn segment_max_age_ms(mut self, ms: u64) -> Self {
+        self.segment_max_age_ms = ms;
+        self
+    }
+
+    pub fn retention_max_age_ms(mut self, ms: u64) -> Self {
+        self.retention_max_age_ms = ms;
+        self
+    }
 }
 
 impl Default for ConfigOption {
@@ -82,6 +108,8 @@ impl Default for ConfigOption {
             index_max_bytes: default_index_max_bytes(),
             index_max_interval_bytes: default_index_max_interval_bytes(),
             segment_max_bytes: default_segment_max_bytes(),
+            segment_max_age_ms: default_segment_max_age_ms(),
+            retention_max_age_ms: 0,
         }
     }
 }
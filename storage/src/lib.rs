@@ -15,6 +15,7 @@ mod segment;
 mod util;
 mod validator;
 mod config;
+mod tier;
 
 pub use crate::config::ConfigOption;
 pub use crate::batch::DefaultFileBatchStream;
@@ -25,6 +26,16 @@ pub use crate::records::FileRecordsSlice;
 pub use crate::index::LogIndex;
 pub use crate::index::OffsetPosition;
 pub use crate::replica::FileReplica;
+pub use crate::tier::ObjectStore;
+pub use crate::tier::SharedObjectStore;
+pub use crate::tier::ObjectStoreConfig;
+pub use crate::tier::FsObjectStore;
+pub use crate::tier::S3ObjectStore;
+pub use crate::tier::TierConfig;
+pub use crate::tier::Tier;
+pub use crate::tier::TierSnapshot;
+pub use crate::tier::RemoteRead;
+pub use crate::tier::TieredSegments;
 pub(crate) use crate::segment::SegmentSlice;
 
 
@@ -85,4 +96,10 @@ pub trait ReplicaStorage   {
     /// offset mark that beggining of uncommitted
     fn get_leo(&self) -> Offset;
 
+    /// total bytes of message logs
+    fn get_size(&self) -> u64;
+
+    /// number of segments including active segment
+    fn get_segment_count(&self) -> usize;
+
 }
\ No newline at end of file
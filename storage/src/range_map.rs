@@ -1,6 +1,7 @@
 use std::cmp::max;
 use std::cmp::min;
 use std::collections::BTreeMap;
+use std::mem;
 use std::ops::Bound::Excluded;
 use std::ops::Bound::Included;
 use std::ffi::OsStr;
@@ -20,7 +21,8 @@ use crate::util::log_path_get_offset;
 pub(crate) struct SegmentList {
     segments: BTreeMap<Offset, ReadSegment>,
     max_base_offset: Offset, // maximum number of offset for all segments
-    min_base_offset: Offset
+    min_base_offset: Offset,
+    total_size: u64          // total bytes of message logs
 }
 
 impl SegmentList {
@@ -29,7 +31,8 @@ impl SegmentList {
         SegmentList {
             segments: BTreeMap::new(),
             max_base_offset: 0,
-            min_base_offset: -1
+            min_base_offset: -1,
+            total_size: 0
         }
     }
 
@@ -84,7 +87,6 @@ impl SegmentList {
 
     }
 
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.segments.len()
     }
@@ -98,14 +100,35 @@ impl SegmentList {
         self.min_base_offset
     }
 
+    pub fn total_size(&self) -> u64 {
+        self.total_size
+    }
+
     pub fn add_segment(&mut self, segment: ReadSegment)  {
         let base_offset = segment.get_base_offset();
         debug!("inserting segment base: {}", base_offset);
         self.max_base_offset = max(self.max_base_offset,base_offset);
         self.min_base_offset = if self.min_base_offset < 0 { base_offset} else { min(self.min_base_offset,base_offset) };
+        self.total_size += segment.get_log_size();
         &self.segments.insert(segment.get_base_offset(), segment);
     }
 
+    /// remove all segments with base offset less than offset
+    pub fn remove_segments_before(&mut self, offset: Offset) -> Vec<ReadSegment> {
+        let remaining = self.segments.split_off(&offset);
+        let removed = mem::replace(&mut self.segments, remaining);
+        self.min_base_offset = self.segments.keys().next().cloned().unwrap_or(-1);
+        self.max_base_offset = self.segments.keys().next_back().cloned().unwrap_or(0);
+        self.total_size = self.segments.values().map(|segment| segment.get_log_size()).sum();
+        debug!("removed {} segments before: {}", removed.len(), offset);
+        removed.into_iter().map(|(_, segment)| segment).collect()
+    }
+
+    /// segments in order of base offset
+    pub fn iter(&self) -> impl Iterator<Item = (&Offset, &ReadSegment)> {
+        self.segments.iter()
+    }
+
     #[allow(dead_code)]
     pub fn get_segment(&self, offset: Offset) -> Option<&ReadSegment> {
         self.segments.get(&offset)
@@ -63,7 +63,11 @@ impl FileRecordsSlice {
         self.base_offset
     }
 
-    #[allow(dead_code)]
+    /// size of log file in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
     pub async fn validate(&mut self) -> Result<Offset, LogValidationError> {
         validate(&mut self.file).await
     }
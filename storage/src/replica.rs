@@ -1,8 +1,13 @@
+use std::cmp::max;
+use std::cmp::min;
+use std::fs;
 use std::io::Error as IoError;
 use std::mem;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use futures::future::Future;
 use futures::Sink;
@@ -15,6 +20,7 @@ use pin_utils::unsafe_pinned;
 use pin_utils::unsafe_unpinned;
 
 use future_aio::fs::create_dir_all;
+use future_aio::fs::AsyncFileSlice;
 use kf_protocol::api::ErrorCode;
 use kf_protocol::api::DefaultBatch;
 use kf_protocol::api::Offset;
@@ -31,6 +37,13 @@ use crate::SegmentSlice;
 use crate::StorageError;
 use crate::SlicePartitionResponse;
 use crate::ReplicaStorage;
+use crate::tier::Tier;
+use crate::tier::RemoteSegments;
+use crate::tier::RemoteRead;
+use crate::tier::TierSnapshot;
+use crate::tier::TieredSegments;
+use crate::util::generate_file_name;
+use crate::records::MESSAGE_LOG_EXTENSION;
 
 /// alway evaluate expression and return
 /// this is usesful for debugging value
@@ -73,6 +86,8 @@ pub struct FileReplica {
     state: ReplicateState,
     prev_segments: SegmentList,
     commit_checkpoint: CheckPoint<Offset>,
+    log_start_checkpoint: CheckPoint<Offset>,
+    remote: Option<RemoteSegments>,
 }
 
 impl Unpin for FileReplica {}
@@ -90,6 +105,14 @@ impl ReplicaStorage for FileReplica {
         self.active_segment.get_end_offset()
     }
 
+    fn get_size(&self) -> u64 {
+        self.prev_segments.total_size() + self.active_segment.get_log_size()
+    }
+
+    fn get_segment_count(&self) -> usize {
+        self.prev_segments.len() + 1
+    }
+
 }
 
 impl FileReplica {
@@ -153,6 +176,17 @@ impl FileReplica {
             last_base_offset
         ).await?;
 
+        let segment_start_offset = if segments.min_offset() < 0 {
+            last_base_offset
+        } else {
+            segments.min_offset()
+        };
+        let log_start_checkpoint: CheckPoint<Offset> = CheckPoint::create(
+            &rep_option,
+            "log_start.chk",
+            segment_start_offset
+        ).await?;
+
         Ok(FileReplica {
             option: rep_option,
             last_base_offset,
@@ -161,9 +195,219 @@ impl FileReplica {
             state: ReplicateState::Active,
             prev_segments: segments,
             commit_checkpoint,
+            log_start_checkpoint,
+            remote: None,
         })
     }
 
+    /// Remove replica directory with all segments and checkpoints.
+    /// Replica must not be in use, missing directory is not an error.
+    pub fn remove<S>(topic: S, partition: Size, option: &ConfigOption) -> Result<(), StorageError>
+    where
+        S: AsRef<str>,
+    {
+        let replica_dir = option.base_dir.join(replica_dir_name(topic, partition));
+
+        if replica_dir.exists() {
+            debug!("removing rep dir: {}", replica_dir.display());
+            std::fs::remove_dir_all(&replica_dir)?;
+        } else {
+            debug!("rep dir: {} does not exist, nothing to remove", replica_dir.display());
+        }
+        Ok(())
+    }
+
+    /// check if replica directory exists under base directory
+    pub fn exists<S>(topic: S, partition: Size, option: &ConfigOption) -> bool
+    where
+        S: AsRef<str>,
+    {
+        option.base_dir.join(replica_dir_name(topic, partition)).exists()
+    }
+
+    /// Copy replica directory to base directory of another option.
+    /// Existing target is updated: only files changed since they were copied are copied again
+    /// and files no longer in source are removed.  This allows bulk of replica to be copied
+    /// while it is written, replica must not be written only during final copy.
+    pub fn copy<S>(topic: S, partition: Size, from: &ConfigOption, to: &ConfigOption) -> Result<(), StorageError>
+    where
+        S: AsRef<str>,
+    {
+        let dir_name = replica_dir_name(topic, partition);
+        let source_dir = from.base_dir.join(&dir_name);
+        let target_dir = to.base_dir.join(&dir_name);
+
+        debug!("copying rep dir: {} to: {}", source_dir.display(), target_dir.display());
+        fs::create_dir_all(&target_dir)?;
+        for entry in fs::read_dir(&target_dir)? {
+            let entry = entry?;
+            if !source_dir.join(entry.file_name()).exists() {
+                trace!("removing file: {} not in source", entry.path().display());
+                fs::remove_file(entry.path())?;
+            }
+        }
+        for entry in fs::read_dir(&source_dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let target_file = target_dir.join(entry.file_name());
+            let source_metadata = entry.metadata()?;
+            // copy has later modification time than source unless source was written after copy
+            let unchanged = match fs::metadata(&target_file) {
+                Ok(target_metadata) => {
+                    target_metadata.len() == source_metadata.len()
+                        && target_metadata.modified()? > source_metadata.modified()?
+                }
+                Err(_) => false,
+            };
+            if !unchanged {
+                trace!("copying file: {}", entry.path().display());
+                fs::copy(entry.path(), &target_file)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// enable tiering of closed segments to object store.
+    /// segments uploaded before are loaded from manifest in object store
+    pub async fn set_tier(&mut self, tier: Tier) -> Result<(), StorageError> {
+        debug!("enabling tiered storage for: {}", self.option.base_dir.display());
+        self.remote = Some(RemoteSegments::open(tier, &self.option).await?);
+        Ok(())
+    }
+
+    /// snapshot of closed segments not yet uploaded to object store.
+    /// upload can run without holding replica, result is applied with `apply_tiered`.
+    /// return None if tiering is not enabled or there is nothing to upload
+    pub fn tier_snapshot(&self) -> Option<TierSnapshot> {
+        self.remote
+            .as_ref()
+            .and_then(|remote| remote.snapshot(self.prev_segments.iter().map(|(base_offset, _)| *base_offset)))
+    }
+
+    /// record segments uploaded from snapshot and remove local copies older than local retention
+    pub fn apply_tiered(&mut self, tiered: TieredSegments) -> Result<(), StorageError> {
+        let prev_segments = &self.prev_segments;
+        if let Some(remote) = &mut self.remote {
+            remote.add_tiered(tiered, |base_offset| {
+                prev_segments
+                    .find_segment(base_offset)
+                    .map(|(segment_base, _)| *segment_base == base_offset)
+                    .unwrap_or(false)
+            });
+        }
+        self.remove_tiered_local_segments()
+    }
+
+    /// upload closed segments to object store and remove local copies older than local retention.
+    /// replica is held during upload, use `tier_snapshot` to upload without it.
+    /// do nothing if tiering is not enabled
+    pub async fn tier_segments(&mut self) -> Result<(), StorageError> {
+        match self.tier_snapshot() {
+            Some(snapshot) => {
+                let tiered = snapshot.upload().await?;
+                self.apply_tiered(tiered)
+            }
+            None => self.remove_tiered_local_segments(),
+        }
+    }
+
+    /// remove local copies of uploaded segments older than local retention.
+    /// local segments are removed from oldest, only if they have been uploaded
+    pub fn remove_tiered_local_segments(&mut self) -> Result<(), StorageError> {
+        let remote = match &self.remote {
+            Some(remote) => remote,
+            None => return Ok(()),
+        };
+
+        let local_retention = remote.tier().local_retention;
+        let now = SystemTime::now();
+        let mut keep_base_offset = self.active_segment.get_base_offset();
+        for (base_offset, _) in self.prev_segments.iter() {
+            let log_path = generate_file_name(&self.option.base_dir, *base_offset, MESSAGE_LOG_EXTENSION);
+            let modified = fs::metadata(&log_path)?.modified()?;
+            let age = now.duration_since(modified).unwrap_or_default();
+            if !remote.contains(*base_offset) || age < local_retention {
+                keep_base_offset = *base_offset;
+                break;
+            }
+        }
+
+        for segment in self.prev_segments.remove_segments_before(keep_base_offset) {
+            debug!("removing local copy of tiered segment: {}", segment.get_base_offset());
+            segment.remove()?;
+        }
+
+        Ok(())
+    }
+
+    /// update segment limits from new option, base directory is kept.
+    /// active segment keeps its limits, new limits apply to next segment
+    pub fn update_option(&mut self, option: &ConfigOption) {
+        debug!("updating replica option: {:#?}", option);
+        self.option.index_max_bytes = option.index_max_bytes;
+        self.option.index_max_interval_bytes = option.index_max_interval_bytes;
+        self.option.segment_max_bytes = option.segment_max_bytes;
+        self.option.segment_max_age_ms = option.segment_max_age_ms;
+        self.option.retention_max_age_ms = option.retention_max_age_ms;
+    }
+
+    /// move log start past closed segments older than retention and remove them.
+    /// age of segment is time since its last write, segments are removed from oldest.
+    /// log start is not moved beyond highwatermark.  return true if log start was moved
+    pub async fn enforce_retention(&mut self) -> Result<bool, StorageError> {
+        if self.option.retention_max_age_ms == 0 {
+            return Ok(false);
+        }
+
+        let retention = Duration::from_millis(self.option.retention_max_age_ms);
+        let now = SystemTime::now();
+        let mut keep_base_offset = None;
+        let mut expired = false;
+        for (base_offset, _) in self.prev_segments.iter() {
+            let log_path = generate_file_name(&self.option.base_dir, *base_offset, MESSAGE_LOG_EXTENSION);
+            let modified = fs::metadata(&log_path)?.modified()?;
+            if now.duration_since(modified).unwrap_or_default() < retention {
+                keep_base_offset = Some(*base_offset);
+                break;
+            }
+            expired = true;
+        }
+
+        // tiered segments are older than any local segment, they are only removed with expired local segments
+        if !expired {
+            return Ok(false);
+        }
+
+        let keep_base_offset = keep_base_offset.unwrap_or_else(|| self.active_segment.get_base_offset());
+        let new_start = min(keep_base_offset, self.get_hw());
+        if new_start <= self.get_log_start_offset() {
+            return Ok(false);
+        }
+
+        debug!("retention expired segments of: {}, moving log start to: {}", self.option.base_dir.display(), new_start);
+        self.update_log_start_offset(new_start).await?;
+        Ok(true)
+    }
+
+    /// roll active segment if its first batch is older than segment max age.
+    /// writes roll expired segment by themselves, this is for replica without new records.
+    /// return true if segment was rolled
+    pub async fn roll_over_if_expired(&mut self) -> Result<bool, StorageError> {
+        if !self.active_segment.is_expired(self.option.segment_max_age_ms) {
+            return Ok(false);
+        }
+
+        let end_offset = self.get_leo();
+        debug!("active segment: {} is older than max age, rolling over at: {}", self.active_segment.get_base_offset(), end_offset);
+        let new_segment = MutableSegment::create(end_offset, &self.option).await?;
+        let old_mut_segment = mem::replace(&mut self.active_segment, new_segment);
+        let old_segment = old_mut_segment.as_segment().await?;
+        self.prev_segments.add_segment(old_segment);
+        Ok(true)
+    }
+
     /// update committed offset (highwatermark)
     pub async fn update_high_watermark(&mut self, offset: Offset) -> Result<(), IoError> {
         let old_offset = self.get_hw();
@@ -183,8 +427,19 @@ impl FileReplica {
     }
 
 
-    /// earliest offset
+    /// earliest offset, either start of first segment or logical start moved by delete records.
+    /// first segment may be remote segment when tiering is enabled
     pub fn get_log_start_offset(&self) -> Offset {
+        let local_start = self.get_local_start_offset();
+        let segment_start = match self.remote.as_ref().and_then(|remote| remote.min_offset()) {
+            Some(remote_start) => min(remote_start, local_start),
+            None => local_start,
+        };
+        max(segment_start, *self.log_start_checkpoint.get_offset())
+    }
+
+    /// start of first segment stored locally
+    fn get_local_start_offset(&self) -> Offset {
         let min_base_offset = self.prev_segments.min_offset();
         if min_base_offset < 0 {
             self.active_segment.get_base_offset()
@@ -193,6 +448,70 @@ impl FileReplica {
         }
     }
 
+    /// move log start offset forward and remove segments which are entirely before it.
+    /// offset before current log start is ignored, caller must ensure offset is not beyond highwatermark
+    pub async fn update_log_start_offset(&mut self, offset: Offset) -> Result<(), StorageError> {
+        let old_offset = self.get_log_start_offset();
+        if offset <= old_offset {
+            trace!("new log start: {} is not after existing one: {}, skipping", offset, old_offset);
+            return Ok(());
+        }
+
+        debug!("updating log start offset: {} old: {}", offset, old_offset);
+        self.log_start_checkpoint.write(offset).await?;
+
+        // segment containing new start offset is kept, all segments before are removed
+        let keep_base_offset = if offset >= self.active_segment.get_base_offset() {
+            Some(self.active_segment.get_base_offset())
+        } else {
+            self.prev_segments
+                .find_segment(offset)
+                .map(|(base_offset, _)| *base_offset)
+        };
+
+        if let Some(keep_base_offset) = keep_base_offset {
+            for segment in self.prev_segments.remove_segments_before(keep_base_offset) {
+                segment.remove()?;
+            }
+        }
+
+        let local_start = self.get_local_start_offset();
+        if let Some(remote) = &mut self.remote {
+            remote.remove_before(offset, local_start).await?;
+        }
+
+        Ok(())
+    }
+
+    /// discard all records and restart log at offset which is beyond end offset.
+    /// used by follower which fell behind leader's log start, its records can't be continued
+    pub async fn reset_log_start_offset(&mut self, offset: Offset) -> Result<(), StorageError> {
+        let end_offset = self.get_leo();
+        if offset <= end_offset {
+            trace!("reset offset: {} is not after end offset: {}, skipping", offset, end_offset);
+            return Ok(());
+        }
+
+        debug!("resetting log: {} from end offset: {} to: {}", self.option.base_dir.display(), end_offset, offset);
+        let new_segment = MutableSegment::create(offset, &self.option).await?;
+        let old_mut_segment = mem::replace(&mut self.active_segment, new_segment);
+        old_mut_segment.as_segment().await?.remove()?;
+        for segment in self.prev_segments.remove_segments_before(offset) {
+            segment.remove()?;
+        }
+
+        self.log_start_checkpoint.write(offset).await?;
+        if self.get_hw() < offset {
+            self.commit_checkpoint.write(offset).await?;
+        }
+
+        if let Some(remote) = &mut self.remote {
+            remote.remove_before(offset, offset).await?;
+        }
+
+        Ok(())
+    }
+
     /// find the segment that contains offsets
     /// segment could be active segment which can be written
     /// or read only segment.
@@ -225,6 +544,27 @@ impl FileReplica {
     }
 
 
+    /// first committed offset whose batch has records at or after timestamp (ms), with timestamp of batch.
+    /// none if all committed records are older. Remote segments are not searched, so
+    /// lookup before first local segment resolves to start of first local segment
+    pub async fn find_offset_by_timestamp(&self, timestamp: i64) -> Result<Option<(Offset,i64)>,StorageError> {
+        let log_start_offset = self.get_log_start_offset();
+        let mut found = None;
+        for (_,segment) in self.prev_segments.iter() {
+            found = segment.find_batch_by_timestamp(timestamp).await?;
+            if found.is_some() {
+                break;
+            }
+        }
+        if found.is_none() {
+            found = self.active_segment.find_batch_by_timestamp(timestamp).await?;
+        }
+
+        Ok(found
+            .map(|(offset,batch_timestamp)| (max(offset,log_start_offset),batch_timestamp))
+            .filter(|(offset,_)| *offset < self.get_hw()))
+    }
+
     /// read uncommitted records( between highwatermark and end offset) to file response
     pub async fn read_uncommitted_records<P>(&self, response: &mut P)  where P: SlicePartitionResponse{
         self.read_records(self.get_hw(),None,response).await   
@@ -244,33 +584,79 @@ impl FileReplica {
         ) where
             P: SlicePartitionResponse,
     {
-            match isolation {
-                Isolation::ReadCommitted => {
-                
-                    self
-                        .read_committed_records(offset, partition_response)
-                        .await
-                }
-                Isolation::ReadUncommitted => {
-                    self
-                        .read_records(offset, None, partition_response)
-                        .await
-                }
+            if let Some(remote_read) = self.read_local_records_with_isolation(offset, isolation, partition_response).await {
+                remote_read.read(partition_response).await;
             }
     }
 
+    /// same as `read_records_with_isolation`, except records in remote segment are returned as remote read.
+    /// caller completes it with `RemoteRead::read` after releasing replica
+    pub async fn read_local_records_with_isolation<P>(
+        &self,
+        offset: Offset,
+        isolation: Isolation,
+        partition_response: &mut P,
+        ) -> Option<RemoteRead> where
+            P: SlicePartitionResponse,
+    {
+            let max_offset = match isolation {
+                Isolation::ReadCommitted => Some(self.get_hw()),
+                Isolation::ReadUncommitted => None
+            };
+            self.read_local_records_max_bytes(offset, max_offset, None, partition_response).await
+    }
+
     /// read records
     /// * `start_offset`:  start offsets
     /// * `max_offset`:  max offset (exclusive)
     /// * `responsive`:  output
     pub async fn read_records<P>(&self,start_offset: Offset,max_offset: Option<Offset>,response: &mut P)   where P: SlicePartitionResponse {
+        self.read_records_max_bytes(start_offset,max_offset,None,response).await
+    }
+
+    /// read records, slice is limited to max bytes at batch boundary
+    /// * `start_offset`:  start offsets
+    /// * `max_offset`:  max offset (exclusive)
+    /// * `max_bytes`:  max bytes of slice, at least one batch is returned
+    /// * `responsive`:  output
+    pub async fn read_records_max_bytes<P>(&self,start_offset: Offset,max_offset: Option<Offset>,max_bytes: Option<Size>,response: &mut P)   where P: SlicePartitionResponse {
+        if let Some(remote_read) = self.read_local_records_max_bytes(start_offset,max_offset,max_bytes,response).await {
+            remote_read.read(response).await;
+        }
+    }
+
+    /// same as `read_records_max_bytes`, except records in remote segment are not downloaded.
+    /// remote read is returned instead, so download doesn't hold replica
+    pub async fn read_local_records_max_bytes<P>(&self,start_offset: Offset,max_offset: Option<Offset>,max_bytes: Option<Size>,response: &mut P) -> Option<RemoteRead>  where P: SlicePartitionResponse {
         
-        trace!("read records to response from: {} max: {:#?}",start_offset,max_offset);
+        trace!("read records to response from: {} max: {:#?}, max bytes: {:#?}",start_offset,max_offset,max_bytes);
 
         let highwatermark = self.get_hw();
         response.set_hw(highwatermark);
         response.set_last_stable_offset(highwatermark);
-        response.set_log_start_offset(self.get_log_start_offset());
+        let log_start_offset = self.get_log_start_offset();
+        response.set_log_start_offset(log_start_offset);
+
+        if start_offset < log_start_offset {
+            response.set_error_code(ErrorCode::OffsetOutOfRange);
+            debug!("offset: {} is before log start: {}", start_offset, log_start_offset);
+            return None;
+        }
+
+        if let Some(remote) = &self.remote {
+            if start_offset < self.get_local_start_offset() {
+                match remote.find_segment(start_offset) {
+                    Some(base_offset) => {
+                        return Some(remote.remote_read(base_offset,start_offset,max_offset,max_bytes));
+                    },
+                    None => {
+                        response.set_error_code(ErrorCode::OffsetOutOfRange);
+                        debug!("remote segment not found for offset: {}",start_offset);
+                    }
+                }
+                return None;
+            }
+        }
 
         match self.find_segment(start_offset) {
             Some(segment) => {
@@ -281,47 +667,27 @@ impl FileReplica {
                             // optimization
                             if start_offset == self.get_leo() {
                                 trace!("start offset is same as end offset, skipping");
-                                return
+                                return None
                             } else {
                                 debug!("active segment with baseoffset: {} found for offset: {}",segment.get_base_offset(),start_offset);
-                                segment.records_slice(start_offset,max_offset).await
+                                segment.records_slice_max_bytes(start_offset,max_offset,max_bytes).await
                             }
                            
                         },
                         SegmentSlice::Segment(segment) => {
                             debug!("read segment with baseoffset: {} found for offset: {}",segment.get_base_offset(),start_offset);
-                            segment.records_slice(start_offset,max_offset).await
+                            segment.records_slice_max_bytes(start_offset,max_offset,max_bytes).await
                         }
                     };
                 
-                
-                match slice {
-
-                    Ok(slice) => {
-                        match slice {
-                            Some(slice) => {
-                                debug!("retrieved slice: {:#?}",slice);
-                                response.set_slice(slice);
-                            },
-                            None => {
-                                debug!("records not found for: {}",start_offset);
-                                response.set_error_code(ErrorCode::OffsetOutOfRange);
-
-                            }
-                        }
-                    },
-                    Err(err) => {
-                        response.set_error_code(ErrorCode::UnknownServerError);
-                        error!("error fetch: {:#?}",err);
-                    }
-                }
-                
+                set_slice_response(response, slice, start_offset);
             },
             None => {
                 response.set_error_code(ErrorCode::OffsetOutOfRange);
                 debug!("segment not found for offset: {}",start_offset);
             }
         }
+        None
     }
 
 
@@ -349,8 +715,14 @@ impl Sink<DefaultBatch> for FileReplica {
         debug!("start_send");
         match self.as_ref().state {
             ReplicateState::Active => {
+                let expired = self.active_segment.is_expired(self.option.segment_max_age_ms);
                 let active_segment = &mut self.as_mut().active_segment;
                 let offset = active_segment.get_end_offset();
+                if expired {
+                    debug!("active segment is older than max age, rolling over at: {}", offset);
+                    mem::replace(&mut self.state, ReplicateState::Rollover(item, offset));
+                    return Ok(());
+                }
                 match self.as_mut().active_segment().start_send(item) {
                     Err(err) => match err {
                         StorageError::NoRoom(item) => {
@@ -425,6 +797,28 @@ impl Sink<DefaultBatch> for FileReplica {
     }
 }
 
+/// set slice or error from segment read to response
+pub(crate) fn set_slice_response<P>(response: &mut P, slice: Result<Option<AsyncFileSlice>, StorageError>, start_offset: Offset) where P: SlicePartitionResponse {
+    match slice {
+        Ok(slice) => {
+            match slice {
+                Some(slice) => {
+                    debug!("retrieved slice: {:#?}",slice);
+                    response.set_slice(slice);
+                },
+                None => {
+                    debug!("records not found for: {}",start_offset);
+                    response.set_error_code(ErrorCode::OffsetOutOfRange);
+                }
+            }
+        },
+        Err(err) => {
+            response.set_error_code(ErrorCode::UnknownServerError);
+            error!("error fetch: {:#?}",err);
+        }
+    }
+}
+
 // generate replication folder name
 fn replica_dir_name<S: AsRef<str>>(topic_name: S, partition_index: Size) -> String {
     format!("{}-{}", topic_name.as_ref(), partition_index)
@@ -436,11 +830,14 @@ mod tests {
     use futures::sink::SinkExt;
     use log::debug;
     use std::env::temp_dir;
+    use std::sync::Arc;
+    use std::time::Duration;
     use std::fs;
     use std::fs::metadata;
     use std::io::Cursor;
 
     use future_helper::test_async;
+    use future_helper::sleep;
     use kf_protocol::api::DefaultBatch;
     use kf_protocol::api::Offset;
     use kf_protocol::Decoder;
@@ -457,6 +854,9 @@ mod tests {
     use crate::StorageError;
     use crate::SegmentSlice;
     use crate::ReplicaStorage;
+    use crate::FsObjectStore;
+    use crate::Tier;
+    use utils::now_ms;
     
 
     const TEST_SEG_NAME: &str = "00000000000000000020.log";
@@ -585,6 +985,32 @@ mod tests {
         Ok(())
     }
 
+    #[test_async]
+    async fn test_read_records_max_bytes() -> Result<(), StorageError> {
+        let option = base_option("test_read_records_max_bytes");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        let batch_len = create_batch().write_size(0);
+        for _ in 0..3 {
+            replica.send(create_batch()).await?;
+        }
+
+        // limit falls in middle of second batch, only whole batches are returned
+        let mut response = FilePartitionResponse::default();
+        replica.read_records_max_bytes(START_OFFSET, None, Some((batch_len * 2 - 1) as u32), &mut response).await;
+        assert_eq!(response.records.len(), batch_len);
+
+        // first batch is returned even if it exceeds limit
+        let mut response = FilePartitionResponse::default();
+        replica.read_records_max_bytes(START_OFFSET + 2, None, Some(10), &mut response).await;
+        assert_eq!(response.records.len(), batch_len);
+
+        let mut response = FilePartitionResponse::default();
+        replica.read_records_max_bytes(START_OFFSET, None, Some(10000), &mut response).await;
+        assert_eq!(response.records.len(), batch_len * 3);
+        Ok(())
+    }
+
     const TEST_OFFSET_DIR: &str = "testoffset";
 
     #[test_async]
@@ -630,7 +1056,7 @@ mod tests {
         assert_eq!(replica.get_log_start_offset(),START_OFFSET);
         let replica_dir = &option.base_dir.join("test-1");
         let dir_contents = fs::read_dir(&replica_dir)?;
-        assert_eq!(dir_contents.count(), 5, "should be 5 files");
+        assert_eq!(dir_contents.count(), 6, "should be 6 files");
 
         
         let seg2_file = replica_dir.join(TEST_SE2_NAME);
@@ -656,6 +1082,30 @@ mod tests {
     }
 
 
+    #[test_async]
+    async fn test_replica_size() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_size");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert_eq!(replica.get_size(), 0);
+        assert_eq!(replica.get_segment_count(), 1);
+
+        replica.send(create_batch()).await?;
+        assert_eq!(replica.get_size(), 79);
+
+        // second batch rolls over
+        replica.send(create_batch()).await?;
+        assert_eq!(replica.get_size(), 158);
+        assert_eq!(replica.get_segment_count(), 2);
+        drop(replica);
+
+        // size is restored from segments
+        let replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert_eq!(replica.get_size(), 158);
+        assert_eq!(replica.get_segment_count(), 2);
+        Ok(())
+    }
+
+
     const TEST_COMMIT_DIR: &str = "testcommit";
   
     #[test_async]
@@ -724,6 +1174,311 @@ mod tests {
 
         Ok(())
     }
+
+
+    #[test_async]
+    async fn test_replica_remove() -> Result<(), StorageError> {
+        let option = base_option("test_replica_remove");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        replica.send(create_batch()).await?;
+        drop(replica);
+
+        let replica_dir = option.base_dir.join("test-0");
+        assert!(replica_dir.exists());
+
+        FileReplica::remove("test", 0, &option)?;
+        assert!(!replica_dir.exists());
+
+        // removing again is fine
+        FileReplica::remove("test", 0, &option)?;
+
+        // re-created replica starts empty
+        let replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert_eq!(replica.get_leo(), START_OFFSET);
+        Ok(())
+    }
+
+
+    #[test_async]
+    async fn test_replica_copy() -> Result<(), StorageError> {
+        let option = base_option("test_replica_copy");
+        let target_option = base_option("test_replica_copy_target");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        replica.send(create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+
+        // bulk copy while replica is in use
+        FileReplica::copy("test", 0, &option, &target_option)?;
+
+        // records written after copy are copied again
+        replica.send(create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+        drop(replica);
+        FileReplica::copy("test", 0, &option, &target_option)?;
+
+        let replica = FileReplica::create("test", 0, START_OFFSET, &target_option).await.expect("test replica");
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+        assert_eq!(replica.get_hw(), START_OFFSET + 4);
+        Ok(())
+    }
+
+
+    #[test_async]
+    async fn test_replica_delete_records() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_delete_records");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        // each batch rolls over, giving segments 20, 22 and active 24
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+        assert_eq!(replica.get_log_start_offset(), START_OFFSET);
+        assert_eq!(replica.get_segment_count(), 3);
+
+        let replica_dir = option.base_dir.join("test-0");
+
+        // offset in middle of second segment, only first segment is removed
+        replica.update_log_start_offset(23).await?;
+        assert_eq!(replica.get_log_start_offset(), 23);
+        assert!(!replica_dir.join(TEST_SEG_NAME).exists());
+        assert!(!replica_dir.join(TEST_SEG_IDX).exists());
+        assert!(replica_dir.join(TEST_SE2_NAME).exists());
+        assert_eq!(replica.get_segment_count(), 2);
+        assert_eq!(replica.get_size(), 158);
+
+        let mut empty_response = FilePartitionResponse::default();
+        replica.read_records(22, None, &mut empty_response).await;
+        assert_eq!(empty_response.error_code, ErrorCode::OffsetOutOfRange);
+        assert_eq!(empty_response.log_start_offset, 23);
+
+        let mut response = FilePartitionResponse::default();
+        replica.read_records(23, None, &mut response).await;
+        assert_eq!(response.error_code, ErrorCode::None);
+
+        // moving backward is ignored
+        replica.update_log_start_offset(21).await?;
+        assert_eq!(replica.get_log_start_offset(), 23);
+        drop(replica);
+
+        // log start is restored from checkpoint
+        let replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert_eq!(replica.get_log_start_offset(), 23);
+        Ok(())
+    }
+
+
+    #[test_async]
+    async fn test_replica_reset_log_start() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_reset_log_start");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        // segments 20 and active 22
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+
+        // offset within log is ignored
+        replica.reset_log_start_offset(START_OFFSET + 2).await?;
+        assert_eq!(replica.get_log_start_offset(), START_OFFSET);
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+
+        let replica_dir = option.base_dir.join("test-0");
+        replica.reset_log_start_offset(30).await?;
+        assert_eq!(replica.get_log_start_offset(), 30);
+        assert_eq!(replica.get_leo(), 30);
+        assert_eq!(replica.get_hw(), 30);
+        assert_eq!(replica.get_segment_count(), 1);
+        assert!(!replica_dir.join(TEST_SEG_NAME).exists());
+        assert!(!replica_dir.join(TEST_SE2_NAME).exists());
+
+        // log continues from new start
+        replica.send(create_batch()).await?;
+        assert_eq!(replica.get_leo(), 32);
+        drop(replica);
+
+        let replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert_eq!(replica.get_log_start_offset(), 30);
+        assert_eq!(replica.get_leo(), 32);
+        Ok(())
+    }
+
+
+    #[test_async]
+    async fn test_replica_retention() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_retention");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        // each batch rolls over, giving segments 20, 22 and active 24
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        sleep(Duration::from_millis(10)).await;
+
+        // retention is disabled by default
+        assert!(!replica.enforce_retention().await?);
+        assert_eq!(replica.get_segment_count(), 3);
+
+        replica.update_option(&option.clone().retention_max_age_ms(1));
+
+        // log start is not moved beyond highwatermark
+        replica.update_high_watermark(23).await?;
+        assert!(replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), 23);
+        assert_eq!(replica.get_segment_count(), 2);
+
+        replica.update_high_watermark_to_end().await?;
+        assert!(replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), START_OFFSET + 4);
+        assert_eq!(replica.get_segment_count(), 1);
+
+        // active segment is never removed
+        assert!(!replica.enforce_retention().await?);
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_time_roll_over() -> Result<(), StorageError> {
+        let option = base_option("test_replica_time_roll_over")
+            .index_max_interval_bytes(0)
+            .segment_max_age_ms(60000);
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        // empty segment never expires
+        assert!(!replica.roll_over_if_expired().await?);
+
+        let mut old_batch = create_batch();
+        old_batch.get_mut_header().first_timestamp = now_ms() - 120000;
+        replica.send(old_batch).await?;
+        assert_eq!(replica.get_segment_count(), 1);
+
+        // write to expired segment rolls over first and shrinks index
+        replica.send(create_batch()).await?;
+        assert_eq!(replica.get_segment_count(), 2);
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+        let replica_dir = option.base_dir.join("test-0");
+        assert_eq!(metadata(replica_dir.join(TEST_SEG_IDX))?.len(), 8);
+
+        // new active segment starts with current timestamp
+        assert!(!replica.roll_over_if_expired().await?);
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_idle_roll_over() -> Result<(), StorageError> {
+        let option = base_option("test_replica_idle_roll_over")
+            .index_max_interval_bytes(0)
+            .segment_max_age_ms(60000);
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        let mut old_batch = create_batch();
+        old_batch.get_mut_header().first_timestamp = now_ms() - 120000;
+        replica.send(old_batch).await?;
+        replica.update_high_watermark_to_end().await?;
+        drop(replica);
+
+        // first batch timestamp is restored from active segment
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        assert!(replica.roll_over_if_expired().await?);
+        assert_eq!(replica.get_segment_count(), 2);
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+        assert!(!replica.roll_over_if_expired().await?);
+
+        let mut response = FilePartitionResponse::default();
+        replica.read_records(START_OFFSET, None, &mut response).await;
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert_eq!(response.records.len(), create_batch().write_size(0));
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_find_offset_by_timestamp() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_find_offset_by_timestamp");
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+
+        for timestamp in &[1000, 2000, 3000] {
+            let mut batch = create_batch();
+            batch.get_mut_header().first_timestamp = *timestamp;
+            batch.get_mut_header().max_time_stamp = *timestamp;
+            replica.send(batch).await?;
+        }
+        assert!(replica.get_segment_count() > 1);
+
+        // only committed records are found
+        assert_eq!(replica.find_offset_by_timestamp(500).await?, None);
+        replica.update_high_watermark_to_end().await?;
+
+        assert_eq!(replica.find_offset_by_timestamp(500).await?, Some((START_OFFSET, 1000)));
+        assert_eq!(replica.find_offset_by_timestamp(1500).await?, Some((START_OFFSET + 2, 2000)));
+        assert_eq!(replica.find_offset_by_timestamp(3000).await?, Some((START_OFFSET + 4, 3000)));
+        assert_eq!(replica.find_offset_by_timestamp(3500).await?, None);
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_tier_segments() -> Result<(), StorageError> {
+        let option = rollover_option("test_replica_tier_segments");
+        let store_dir = temp_dir().join("test_replica_tier_store");
+        ensure_clean_dir(&store_dir);
+        let store = Arc::new(FsObjectStore::new(store_dir.clone()));
+
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        replica.set_tier(Tier::new(store.clone(), Duration::from_secs(3600))).await?;
+
+        // segments 20, 22 are closed and 24 is active
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.send(create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+
+        // closed segments are uploaded but kept locally within retention
+        let snapshot = replica.tier_snapshot().expect("segments to upload");
+        let tiered = snapshot.upload().await?;
+        replica.apply_tiered(tiered)?;
+        assert!(replica.tier_snapshot().is_none());
+        let replica_dir = option.base_dir.join("test-0");
+        assert!(store_dir.join("test-0").join(TEST_SEG_NAME).exists());
+        assert!(store_dir.join("test-0").join(TEST_SEG2_IDX).exists());
+        // manifest is kept in object store under replica prefix
+        assert!(store_dir.join("test-0").join("tier.manifest").exists());
+        assert!(!replica_dir.join("tier.manifest").exists());
+        assert!(replica_dir.join(TEST_SEG_NAME).exists());
+        assert_eq!(replica.get_segment_count(), 3);
+
+        // local copies are removed after retention
+        replica.set_tier(Tier::new(store.clone(), Duration::from_secs(0))).await?;
+        replica.tier_segments().await?;
+        assert!(!replica_dir.join(TEST_SEG_NAME).exists());
+        assert!(!replica_dir.join(TEST_SE2_NAME).exists());
+        assert_eq!(replica.get_segment_count(), 1);
+        assert_eq!(replica.get_log_start_offset(), START_OFFSET);
+
+        // remote offsets are served from object store
+        let mut response = FilePartitionResponse::default();
+        replica.read_records(START_OFFSET, None, &mut response).await;
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert_eq!(response.records.len(), create_batch().write_size(0));
+
+        let mut response = FilePartitionResponse::default();
+        replica.read_records(23, None, &mut response).await;
+        assert_eq!(response.error_code, ErrorCode::None);
+        drop(replica);
+
+        // remote segments are restored from manifest
+        let mut replica = FileReplica::create("test", 0, START_OFFSET, &option).await.expect("test replica");
+        replica.set_tier(Tier::new(store, Duration::from_secs(0))).await?;
+        assert_eq!(replica.get_log_start_offset(), START_OFFSET);
+
+        // delete records removes remote segments before log start
+        replica.update_log_start_offset(23).await?;
+        assert!(!store_dir.join("test-0").join(TEST_SEG_NAME).exists());
+        assert!(store_dir.join("test-0").join(TEST_SE2_NAME).exists());
+
+        let mut response = FilePartitionResponse::default();
+        replica.read_records(START_OFFSET, None, &mut response).await;
+        assert_eq!(response.error_code, ErrorCode::OffsetOutOfRange);
+        Ok(())
+    }
     
 
 }
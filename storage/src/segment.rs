@@ -19,6 +19,7 @@ use kf_protocol::api::DefaultBatch;
 use kf_protocol::api::Offset;
 use kf_protocol::api::Size;
 use future_aio::fs::AsyncFileSlice;
+use utils::now_ms;
 
 use crate::BatchHeaderStream;
 use crate::mut_index::MutLogIndex;
@@ -34,6 +35,9 @@ use crate::DefaultFileBatchStream;
 use crate::index::OffsetPosition;
 use crate::validator::LogValidationError;
 use crate::util::OffsetError;
+use crate::util::generate_file_name;
+use crate::records::MESSAGE_LOG_EXTENSION;
+use crate::index::EXTENSION as INDEX_EXTENSION;
 
 pub(crate) type MutableSegment = Segment<MutLogIndex,MutFileRecords>;
 pub(crate) type ReadSegment = Segment<LogIndex,FileRecordsSlice>;
@@ -70,6 +74,7 @@ pub(crate) struct Segment<I,L> {
     index: I,
     base_offset: Offset,
     end_offset: Offset,
+    first_batch_timestamp: Option<i64>,     // milliseconds, none if there is no batch
 }
 
 impl <I,L>fmt::Debug for Segment<I,L> {
@@ -130,38 +135,79 @@ impl <I,L> Segment<I,L>
         Ok(DefaultFileBatchStream::new(file))
     }
 
+    /// base offset and first timestamp of first batch which has records at or after timestamp (ms).
+    /// batch headers are scanned from start of segment since there is no time index
+    pub async fn find_batch_by_timestamp(&self, timestamp: i64) -> Result<Option<(Offset,i64)>,StorageError> {
+        let mut header_stream = self.open_batch_header_stream(0).await?;
+        while let Some(batch_pos) = header_stream.next().await {
+            let header = batch_pos.get_batch().get_header();
+            if header.max_time_stamp >= timestamp {
+                return Ok(Some((batch_pos.get_base_offset(),header.first_timestamp)));
+            }
+        }
+        Ok(None)
+    }
+
 
     /// get file slice from offset to end of segment
     pub async fn records_slice(&self,start_offset: Offset,max_offset_opt: Option<Offset>) -> Result<Option<AsyncFileSlice>,StorageError> {
+        self.records_slice_max_bytes(start_offset,max_offset_opt,None).await
+    }
+
+    /// get file slice from offset, limited to max bytes at batch boundary.
+    /// first batch is always included even if it is larger than max bytes
+    pub async fn records_slice_max_bytes(&self,start_offset: Offset,max_offset_opt: Option<Offset>,max_bytes: Option<Size>) -> Result<Option<AsyncFileSlice>,StorageError> {
 
         match self.find_offset_position(start_offset).await? {
             Some(start_pos) => {
                 trace!("found batch: {:#?} at: {}",start_pos.get_batch(),start_pos.get_pos());
-                match max_offset_opt {
+                let slice = match max_offset_opt {
                     Some(max_offset) =>  {
                         // check if max offset same as segment endset
                         if max_offset == self.get_end_offset() {
                             trace!("max offset is same as end offset, reading to end");
-                            Ok(Some(self.msg_log.as_file_slice(start_pos.get_pos())?))
+                            self.msg_log.as_file_slice(start_pos.get_pos())?
                         } else {
                             trace!("end offset is supplied: {}",max_offset);
                             match self.find_offset_position(max_offset).await? {
                                 Some(end_pos) => {
-                                    Ok(Some(self.msg_log.as_file_slice_from_to(start_pos.get_pos(),end_pos.get_pos() - start_pos.get_pos())?))
+                                    self.msg_log.as_file_slice_from_to(start_pos.get_pos(),end_pos.get_pos() - start_pos.get_pos())?
                                 },
-                                None => Err(StorageError::OffsetError(OffsetError::NotExistent))
+                                None => return Err(StorageError::OffsetError(OffsetError::NotExistent))
                             }
                         }
                     },                        
-                    None => Ok(Some(self.msg_log.as_file_slice(start_pos.get_pos())?))
+                    None => self.msg_log.as_file_slice(start_pos.get_pos())?
+                };
+
+                match max_bytes {
+                    Some(max_bytes) if slice.len() > u64::from(max_bytes) => {
+                        let len = self.batches_len(start_pos.get_pos(), slice.len() as Size, max_bytes).await?;
+                        trace!("limiting slice len: {} to: {}",slice.len(),len);
+                        Ok(Some(self.msg_log.as_file_slice_from_to(start_pos.get_pos(),len)?))
+                    },
+                    _ => Ok(Some(slice))
                 }
-                
             }
             None => Ok(None)
         }
         
     }
 
+    /// length of whole batches from position which fits in max bytes, at least one batch
+    async fn batches_len(&self, start_pos: Size, slice_len: Size, max_bytes: Size) -> Result<Size, StorageError> {
+        let mut header_stream = self.open_batch_header_stream(start_pos).await?;
+        let mut len: Size = 0;
+        while let Some(batch_pos) = header_stream.next().await {
+            let next_len = len + batch_pos.total_len();
+            if next_len > slice_len || (len > 0 && next_len > max_bytes) {
+                break;
+            }
+            len = next_len;
+        }
+        Ok(len)
+    }
+
     /// find position of the offset
     pub(crate) async fn find_offset_position(
         &self,
@@ -220,23 +266,44 @@ impl <I,L> Segment<I,L>
 impl Segment<LogIndex,FileRecordsSlice> {
 
     pub async fn open_for_read(base_offset: Offset, option: &ConfigOption) -> Result<Self, StorageError> {
-        let msg_log = FileRecordsSlice::open(base_offset, option).await?;
+        let mut msg_log = FileRecordsSlice::open(base_offset, option).await?;
         let base_offset = msg_log.get_base_offset();
         let index = LogIndex::open_from_offset(base_offset, option).await?;
 
-        let base_offset = msg_log.get_base_offset();
+        // end offset is needed to find records in closed segment
+        let end_offset = msg_log.validate().await?;
+        debug!("opened segment for read, base offset: {}, end offset: {}", base_offset, end_offset);
         Ok(Segment {
             msg_log,
             index,
             option: option.to_owned(),
             base_offset,
-            end_offset: base_offset,
+            end_offset,
+            first_batch_timestamp: None,
         })
     }
 
     pub fn to_segment_slice(&self) -> SegmentSlice {
         SegmentSlice::new_segment(self)
     }
+
+    /// size of message log in bytes
+    pub fn get_log_size(&self) -> u64 {
+        self.msg_log.len()
+    }
+
+    /// remove log and index files of this segment
+    pub fn remove(self) -> Result<(), IoError> {
+        let base_dir = self.option.base_dir.clone();
+        let base_offset = self.base_offset;
+        drop(self);
+        for extension in &[MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+            let path = generate_file_name(&base_dir, base_offset, extension);
+            debug!("removing segment file: {}", path.display());
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
 }
 
 
@@ -245,6 +312,7 @@ impl Segment<MutLogIndex,MutFileRecords> {
     unsafe_pinned!(index: MutLogIndex);
     unsafe_unpinned!(base_offset: Offset);
     unsafe_unpinned!(end_offset: Offset);
+    unsafe_unpinned!(first_batch_timestamp: Option<i64>);
 
     // create segment on base directory
     pub async fn create(
@@ -262,6 +330,7 @@ impl Segment<MutLogIndex,MutFileRecords> {
             index,
             base_offset,
             end_offset: base_offset,
+            first_batch_timestamp: None,
         })
     }
 
@@ -284,6 +353,7 @@ impl Segment<MutLogIndex,MutFileRecords> {
             index,
             base_offset,
             end_offset: base_offset,
+            first_batch_timestamp: None,
         })
     }
 
@@ -291,12 +361,36 @@ impl Segment<MutLogIndex,MutFileRecords> {
         self.msg_log.get_pos()
     }
 
-    /// validate the segment and load last offset
+    /// size of message log in bytes
+    pub fn get_log_size(&self) -> u64 {
+        u64::from(self.get_log_pos())
+    }
+
+    /// validate the segment and load last offset and first batch timestamp
     pub async fn validate(&mut self) -> Result<(), StorageError> {
         self.end_offset = self.msg_log.validate().await?;
+        if self.end_offset > self.base_offset {
+            let mut header_stream = self.open_batch_header_stream(0).await?;
+            if let Some(batch_pos) = header_stream.next().await {
+                let timestamp = batch_pos.get_batch().get_header().first_timestamp;
+                self.first_batch_timestamp = Some(batch_timestamp(timestamp));
+            }
+        }
         Ok(())
     }
 
+    /// check if first batch is older than max age, empty segment never expires.
+    /// zero max age disables time based roll
+    pub fn is_expired(&self, max_age_ms: u64) -> bool {
+        if max_age_ms == 0 {
+            return false;
+        }
+        match self.first_batch_timestamp {
+            Some(timestamp) => now_ms() - timestamp >= max_age_ms as i64,
+            None => false,
+        }
+    }
+
     async fn shrink_index(&mut self) -> Result<(),IoError> {
         self.index.shrink().await
     }
@@ -326,7 +420,6 @@ impl Segment<MutLogIndex,MutFileRecords> {
     }
 
     /// shrink and convert as immutable
-    #[allow(dead_code)]
     pub async fn convert_to_segment(mut self) -> Result<ReadSegment, StorageError> {
         self.shrink_index().await?;
         Segment::open_for_read(self.get_base_offset(), &self.option).await
@@ -341,6 +434,15 @@ impl Segment<MutLogIndex,MutFileRecords> {
 
 impl Unpin for Segment<MutLogIndex,MutFileRecords> {}
 
+/// producer may not set timestamp, use current time instead
+fn batch_timestamp(timestamp: i64) -> i64 {
+    if timestamp > 0 {
+        timestamp
+    } else {
+        now_ms()
+    }
+}
+
 impl Sink<DefaultBatch> for Segment<MutLogIndex,MutFileRecords> {
    
     type Error = StorageError;
@@ -369,6 +471,7 @@ impl Sink<DefaultBatch> for Segment<MutLogIndex,MutFileRecords> {
         }
        
         let batch_offset_delta = (current_offset - base_offset) as i32; 
+        let timestamp = item.get_header().first_timestamp;
         debug!(
             "writing batch with base: {}, file pos: {}",
             base_offset, pos
@@ -376,6 +479,9 @@ impl Sink<DefaultBatch> for Segment<MutLogIndex,MutFileRecords> {
 
         match self.as_mut().msg_log().start_send(item) {
             Ok(_) => {
+                if self.as_ref().first_batch_timestamp.is_none() {
+                    *self.as_mut().first_batch_timestamp() = Some(batch_timestamp(timestamp));
+                }
                 let batch_len = self.msg_log.get_pending_batch_len();
                 self.index()
                     .start_send((batch_offset_delta as u32, pos, batch_len))
@@ -0,0 +1,111 @@
+use std::fs;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use log::trace;
+
+use future_aio::asyncify;
+
+use super::ObjectStore;
+
+/// Object store backed by local directory, objects are files under root.
+/// Mainly used for testing and for stores mounted as file system.
+/// File operations are run on blocking pool
+#[derive(Debug)]
+pub struct FsObjectStore {
+    root: PathBuf,
+}
+
+impl FsObjectStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn object_path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+impl ObjectStore for FsObjectStore {
+
+    fn put<'a>(&'a self, key: &'a str, source: &'a Path) -> BoxFuture<'a, Result<(), IoError>> {
+        let path = self.object_path(key);
+        let source = source.to_owned();
+        trace!("fs store put: {} from: {}", path.display(), source.display());
+        asyncify(move || {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            // copy to temporary file first so partial upload is never visible
+            let tmp_path = path.with_extension("tmp");
+            fs::copy(&source, &tmp_path)?;
+            fs::rename(&tmp_path, &path)
+        })
+        .boxed()
+    }
+
+    fn get<'a>(&'a self, key: &'a str, target: &'a Path) -> BoxFuture<'a, Result<(), IoError>> {
+        let path = self.object_path(key);
+        let target = target.to_owned();
+        trace!("fs store get: {} to: {}", path.display(), target.display());
+        asyncify(move || fs::copy(&path, &target).map(|_| ())).boxed()
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), IoError>> {
+        let path = self.object_path(key);
+        trace!("fs store delete: {}", path.display());
+        asyncify(move || match fs::remove_file(&path) {
+            Err(ref err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            result => result,
+        })
+        .boxed()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::env::temp_dir;
+    use std::fs;
+    use std::io::Error as IoError;
+    use std::io::ErrorKind;
+
+    use future_helper::test_async;
+
+    use crate::fixture::ensure_clean_dir;
+    use crate::tier::ObjectStore;
+
+    use super::FsObjectStore;
+
+    #[test_async]
+    async fn test_fs_store() -> Result<(), IoError> {
+        let root = temp_dir().join("test_fs_store");
+        let local = temp_dir().join("test_fs_store_local");
+        ensure_clean_dir(&root);
+        ensure_clean_dir(&local);
+        fs::create_dir_all(&local).expect("local dir");
+
+        let source = local.join("source.log");
+        fs::write(&source, b"segment").expect("write");
+
+        let store = FsObjectStore::new(root.clone());
+        store.put("test-0/source.log", &source).await?;
+        assert!(root.join("test-0").join("source.log").exists());
+
+        let target = local.join("target.log");
+        store.get("test-0/source.log", &target).await?;
+        assert_eq!(fs::read(&target).expect("read"), b"segment");
+
+        store.delete("test-0/source.log").await?;
+        // delete is idempotent
+        store.delete("test-0/source.log").await?;
+        let err = store.get("test-0/source.log", &target).await.expect_err("missing");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+mod fs_store;
+mod s3_store;
+mod remote;
+
+pub use self::fs_store::FsObjectStore;
+pub use self::s3_store::S3ObjectStore;
+pub use self::remote::TierSnapshot;
+pub use self::remote::RemoteRead;
+pub use self::remote::TieredSegments;
+pub(crate) use self::remote::RemoteSegments;
+
+use std::fmt::Debug;
+use std::io::Error as IoError;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use serde::Deserialize;
+
+/// Object store where closed segments are offloaded.
+/// Objects are addressed by key, which is relative path such as `topic-0/00000000000000000020.log`.
+/// Operations are asynchronous and bounded by store timeouts.
+pub trait ObjectStore: Debug + Send + Sync {
+
+    /// upload local file as object, existing object is replaced
+    fn put<'a>(&'a self, key: &'a str, source: &'a Path) -> BoxFuture<'a, Result<(), IoError>>;
+
+    /// download object into local file, missing object is `NotFound` error
+    fn get<'a>(&'a self, key: &'a str, target: &'a Path) -> BoxFuture<'a, Result<(), IoError>>;
+
+    /// delete object, missing object is not an error
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), IoError>>;
+}
+
+pub type SharedObjectStore = Arc<dyn ObjectStore>;
+
+
+/// object store backend configuration
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ObjectStoreConfig {
+    Fs {
+        path: PathBuf,
+    },
+    S3 {
+        endpoint: String,
+        bucket: String,
+        #[serde(default = "default_region")]
+        region: String,
+        access_key: String,
+        secret_key: String,
+        #[serde(default = "default_request_timeout_ms")]
+        request_timeout_ms: u64,
+        #[serde(default = "default_transfer_timeout_ms")]
+        transfer_timeout_ms: u64,
+    },
+}
+
+impl ObjectStoreConfig {
+
+    pub fn create_store(&self) -> SharedObjectStore {
+        match self {
+            ObjectStoreConfig::Fs { path } => Arc::new(FsObjectStore::new(path.clone())),
+            ObjectStoreConfig::S3 {
+                endpoint,
+                bucket,
+                region,
+                access_key,
+                secret_key,
+                request_timeout_ms,
+                transfer_timeout_ms,
+            } => Arc::new(
+                S3ObjectStore::new(
+                    endpoint.clone(),
+                    bucket.clone(),
+                    region.clone(),
+                    access_key.clone(),
+                    secret_key.clone(),
+                )
+                .request_timeout(Duration::from_millis(*request_timeout_ms))
+                .transfer_timeout(Duration::from_millis(*transfer_timeout_ms)),
+            ),
+        }
+    }
+}
+
+fn default_region() -> String {
+    "us-east-1".to_owned()
+}
+
+fn default_request_timeout_ms() -> u64 {
+    30_000 // 30 seconds
+}
+
+fn default_transfer_timeout_ms() -> u64 {
+    600_000 // 10 min
+}
+
+
+/// tiered storage configuration
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TierConfig {
+    pub store: ObjectStoreConfig,
+    #[serde(default = "default_local_retention_ms")]
+    pub local_retention_ms: u64,
+    #[serde(default = "default_cache_segments")]
+    pub cache_segments: usize,
+}
+
+fn default_local_retention_ms() -> u64 {
+    3_600_000 // 1 hour
+}
+
+fn default_cache_segments() -> usize {
+    4
+}
+
+
+/// Tiering settings shared by replicas.
+/// Closed segments are uploaded to store and local copy is removed after local retention.
+/// Remote segments are downloaded on read and up to `cache_segments` are kept per replica
+#[derive(Debug, Clone)]
+pub struct Tier {
+    pub store: SharedObjectStore,
+    pub local_retention: Duration,
+    pub cache_segments: usize,
+}
+
+impl Tier {
+    pub fn new(store: SharedObjectStore, local_retention: Duration) -> Self {
+        Self {
+            store,
+            local_retention,
+            cache_segments: default_cache_segments(),
+        }
+    }
+
+    pub fn cache_segments(mut self, count: usize) -> Self {
+        self.cache_segments = count;
+        self
+    }
+}
+
+impl From<&TierConfig> for Tier {
+    fn from(config: &TierConfig) -> Self {
+        Tier::new(
+            config.store.create_store(),
+            Duration::from_millis(config.local_retention_ms),
+        )
+        .cache_segments(config.cache_segments)
+    }
+}
@@ -0,0 +1,389 @@
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use log::debug;
+use log::trace;
+use log::warn;
+
+use kf_protocol::api::Offset;
+use kf_protocol::api::Size;
+
+use crate::segment::ReadSegment;
+use crate::util::generate_file_name;
+use crate::records::MESSAGE_LOG_EXTENSION;
+use crate::index::EXTENSION as INDEX_EXTENSION;
+use crate::replica::set_slice_response;
+use crate::ConfigOption;
+use crate::StorageError;
+use crate::SlicePartitionResponse;
+
+use super::Tier;
+
+const MANIFEST_FILE: &str = "tier.manifest";
+const UPLOAD_MANIFEST_FILE: &str = "tier.manifest.upload";
+const CACHE_DIR: &str = "tier-cache";
+
+/// Segments of replica which have been uploaded to object store.
+/// Uploaded base offsets are kept in manifest object under replica prefix (`topic-partition`),
+/// so replica becoming leader on another SPU continues with same remote segments.
+/// Segments downloaded for reads are cached in `tier-cache` directory.
+#[derive(Debug)]
+pub(crate) struct RemoteSegments {
+    tier: Tier,
+    prefix: String,
+    option: ConfigOption,
+    segments: BTreeSet<Offset>,
+    cache: Arc<SegmentCache>,
+    manifest_stale: bool,
+}
+
+impl RemoteSegments {
+
+    /// load remote segments of replica from manifest in object store, option is replica option
+    pub async fn open(tier: Tier, option: &ConfigOption) -> Result<Self, IoError> {
+        let prefix = option
+            .base_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| IoError::new(ErrorKind::InvalidInput, "replica dir has no name"))?;
+
+        // cached segments from previous run are not tracked, start clean
+        let cache_dir = option.base_dir.join(CACHE_DIR);
+        if cache_dir.exists() {
+            fs::remove_dir_all(&cache_dir)?;
+        }
+        fs::create_dir_all(&cache_dir)?;
+
+        let segments = read_manifest(&tier, &prefix, &cache_dir).await?;
+        debug!("loaded {} remote segments for: {}", segments.len(), prefix);
+        Ok(Self {
+            cache: Arc::new(SegmentCache {
+                tier: tier.clone(),
+                prefix: prefix.clone(),
+                option: option.clone().base_dir(cache_dir),
+                segments: Mutex::new(VecDeque::new()),
+                downloads: AtomicU64::new(0),
+            }),
+            tier,
+            prefix,
+            option: option.clone(),
+            segments,
+            manifest_stale: false,
+        })
+    }
+
+    pub fn tier(&self) -> &Tier {
+        &self.tier
+    }
+
+    pub fn contains(&self, base_offset: Offset) -> bool {
+        self.segments.contains(&base_offset)
+    }
+
+    /// lowest base offset of remote segments
+    pub fn min_offset(&self) -> Option<Offset> {
+        self.segments.iter().next().cloned()
+    }
+
+    /// base offset of remote segment which may contain offset.
+    /// offset must be before local segments
+    pub fn find_segment(&self, offset: Offset) -> Option<Offset> {
+        self.segments.range(..=offset).next_back().cloned()
+    }
+
+    /// read of records from remote segment, download and read don't need access to replica
+    pub fn remote_read(
+        &self,
+        base_offset: Offset,
+        start_offset: Offset,
+        max_offset: Option<Offset>,
+        max_bytes: Option<Size>,
+    ) -> RemoteRead {
+        RemoteRead {
+            cache: self.cache.clone(),
+            base_offset,
+            start_offset,
+            max_offset,
+            max_bytes,
+        }
+    }
+
+    /// snapshot for uploading closed segments which are not in object store yet.
+    /// return None if there is nothing to upload
+    pub fn snapshot<I>(&self, closed_segments: I) -> Option<TierSnapshot>
+        where I: Iterator<Item = Offset>
+    {
+        let pending: Vec<Offset> = closed_segments.filter(|base_offset| !self.contains(*base_offset)).collect();
+        if pending.is_empty() && !self.manifest_stale {
+            return None;
+        }
+
+        Some(TierSnapshot {
+            tier: self.tier.clone(),
+            prefix: self.prefix.clone(),
+            base_dir: self.option.base_dir.clone(),
+            manifest_path: self.cache.option.base_dir.join(UPLOAD_MANIFEST_FILE),
+            segments: self.segments.clone(),
+            pending,
+        })
+    }
+
+    /// add segments uploaded from snapshot, segments no longer present locally are skipped.
+    /// if segments changed during upload, manifest is rewritten on next upload
+    pub fn add_tiered<F>(&mut self, tiered: TieredSegments, exists: F)
+        where F: Fn(Offset) -> bool
+    {
+        for base_offset in tiered.uploaded {
+            if exists(base_offset) {
+                self.segments.insert(base_offset);
+            } else {
+                debug!("tiered segment: {} was removed during upload for: {}", base_offset, self.prefix);
+            }
+        }
+        self.manifest_stale = self.segments != tiered.manifest;
+    }
+
+    /// remove remote segments which end at or before offset.
+    /// `end_offset` is base offset of first local segment which marks end of last remote segment
+    pub async fn remove_before(&mut self, offset: Offset, end_offset: Offset) -> Result<(), IoError> {
+        let mut removed = vec![];
+        let mut bases = self.segments.iter().cloned().peekable();
+        while let Some(base_offset) = bases.next() {
+            let segment_end = bases.peek().cloned().unwrap_or(end_offset);
+            if segment_end > offset {
+                break;
+            }
+            removed.push(base_offset);
+        }
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        for base_offset in &removed {
+            for extension in &[MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+                let key = object_key(&self.prefix, *base_offset, extension);
+                self.tier.store.delete(&key).await?;
+            }
+            self.segments.remove(base_offset);
+            self.cache.evict(*base_offset)?;
+        }
+        debug!("removed {} remote segments before: {} for: {}", removed.len(), offset, self.prefix);
+        let manifest_path = self.cache.option.base_dir.join(MANIFEST_FILE);
+        match write_manifest(&self.tier, &self.prefix, &manifest_path, &self.segments).await {
+            Ok(_) => {
+                self.manifest_stale = false;
+                Ok(())
+            },
+            Err(err) => {
+                self.manifest_stale = true;
+                Err(err)
+            }
+        }
+    }
+}
+
+
+/// Remote segments downloaded for reads, shared by replica and its remote reads
+#[derive(Debug)]
+struct SegmentCache {
+    tier: Tier,
+    prefix: String,
+    option: ConfigOption,
+    segments: Mutex<VecDeque<Arc<ReadSegment>>>,
+    downloads: AtomicU64,
+}
+
+impl SegmentCache {
+
+    /// get remote segment from cache or download it
+    async fn fetch(&self, base_offset: Offset) -> Result<Arc<ReadSegment>, StorageError> {
+        if let Some(segment) = self.cached(base_offset)? {
+            trace!("remote segment: {} found in cache", base_offset);
+            return Ok(segment);
+        }
+
+        debug!("downloading remote segment: {} for: {}", base_offset, self.prefix);
+        // concurrent downloads of same segment are written to separate files
+        let download = self.downloads.fetch_add(1, Ordering::SeqCst);
+        for extension in &[MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+            let path = generate_file_name(&self.option.base_dir, base_offset, extension);
+            let download_path = path.with_extension(format!("{}.{}.download", extension, download));
+            let key = object_key(&self.prefix, base_offset, extension);
+            self.tier.store.get(&key, &download_path).await?;
+            fs::rename(&download_path, &path)?;
+        }
+        let segment = Arc::new(ReadSegment::open_for_read(base_offset, &self.option).await?);
+
+        let mut cache = self.lock()?;
+        if let Some(cached) = cache.iter().find(|segment| segment.get_base_offset() == base_offset) {
+            // downloaded concurrently
+            return Ok(cached.clone());
+        }
+        cache.push_back(segment.clone());
+        while cache.len() > self.tier.cache_segments.max(1) {
+            if let Some(evicted) = cache.pop_front() {
+                self.remove_cache_files(evicted.get_base_offset());
+            }
+        }
+        Ok(segment)
+    }
+
+    fn cached(&self, base_offset: Offset) -> Result<Option<Arc<ReadSegment>>, IoError> {
+        let cache = self.lock()?;
+        Ok(cache
+            .iter()
+            .find(|segment| segment.get_base_offset() == base_offset)
+            .cloned())
+    }
+
+    fn evict(&self, base_offset: Offset) -> Result<(), IoError> {
+        let mut cache = self.lock()?;
+        let before = cache.len();
+        cache.retain(|segment| segment.get_base_offset() != base_offset);
+        if cache.len() != before {
+            self.remove_cache_files(base_offset);
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<VecDeque<Arc<ReadSegment>>>, IoError> {
+        self.segments
+            .lock()
+            .map_err(|_| IoError::new(ErrorKind::Other, "remote segment cache lock poisoned"))
+    }
+
+    /// files are unlinked only, readers holding segment can still read them
+    fn remove_cache_files(&self, base_offset: Offset) {
+        for extension in &[MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+            let path = generate_file_name(&self.option.base_dir, base_offset, extension);
+            if let Err(err) = fs::remove_file(&path) {
+                warn!("unable to remove cached segment file: {}, {}", path.display(), err);
+            }
+        }
+    }
+}
+
+
+/// Read of records from remote segment, resolved while holding replica.
+/// Segment is downloaded and read without access to replica, so writers are not blocked
+#[derive(Debug)]
+pub struct RemoteRead {
+    cache: Arc<SegmentCache>,
+    base_offset: Offset,
+    start_offset: Offset,
+    max_offset: Option<Offset>,
+    max_bytes: Option<Size>,
+}
+
+impl RemoteRead {
+
+    /// download segment if not cached and read records to response
+    pub async fn read<P>(self, response: &mut P) where P: SlicePartitionResponse {
+        debug!("remote segment with baseoffset: {} found for offset: {}", self.base_offset, self.start_offset);
+        let slice = match self.cache.fetch(self.base_offset).await {
+            Ok(segment) => segment.records_slice_max_bytes(self.start_offset, self.max_offset, self.max_bytes).await,
+            Err(err) => Err(err)
+        };
+        set_slice_response(response, slice, self.start_offset);
+    }
+}
+
+
+/// Closed segments of replica to be uploaded, taken while holding replica.
+/// Upload runs without access to replica, result is applied back with `FileReplica::apply_tiered`
+#[derive(Debug)]
+pub struct TierSnapshot {
+    tier: Tier,
+    prefix: String,
+    base_dir: PathBuf,
+    manifest_path: PathBuf,
+    segments: BTreeSet<Offset>,
+    pending: Vec<Offset>,
+}
+
+/// Segments uploaded from snapshot and manifest written to object store
+#[derive(Debug)]
+pub struct TieredSegments {
+    uploaded: Vec<Offset>,
+    manifest: BTreeSet<Offset>,
+}
+
+impl TierSnapshot {
+
+    /// upload pending segment files, index is uploaded first so log object implies complete segment.
+    /// manifest is written after segments so it never refers to missing object
+    pub async fn upload(mut self) -> Result<TieredSegments, StorageError> {
+        let mut uploaded = vec![];
+        for base_offset in self.pending {
+            for extension in &[INDEX_EXTENSION, MESSAGE_LOG_EXTENSION] {
+                let path = generate_file_name(&self.base_dir, base_offset, extension);
+                let key = object_key(&self.prefix, base_offset, extension);
+                self.tier.store.put(&key, &path).await?;
+            }
+            debug!("uploaded segment: {} for: {}", base_offset, self.prefix);
+            self.segments.insert(base_offset);
+            uploaded.push(base_offset);
+        }
+
+        write_manifest(&self.tier, &self.prefix, &self.manifest_path, &self.segments).await?;
+        Ok(TieredSegments {
+            uploaded,
+            manifest: self.segments,
+        })
+    }
+}
+
+
+fn object_key(prefix: &str, base_offset: Offset, extension: &str) -> String {
+    let file = generate_file_name("", base_offset, extension);
+    format!("{}/{}", prefix, file.display())
+}
+
+fn manifest_key(prefix: &str) -> String {
+    format!("{}/{}", prefix, MANIFEST_FILE)
+}
+
+/// download manifest of replica, missing manifest means no remote segments
+async fn read_manifest(tier: &Tier, prefix: &str, dir: &Path) -> Result<BTreeSet<Offset>, IoError> {
+    let path = dir.join(MANIFEST_FILE);
+    let key = manifest_key(prefix);
+    match tier.store.get(&key, &path).await {
+        Ok(_) => {}
+        Err(ref err) if err.kind() == ErrorKind::NotFound => {
+            trace!("no manifest found for: {}", prefix);
+            return Ok(BTreeSet::new());
+        }
+        Err(err) => return Err(err),
+    }
+    let content = fs::read_to_string(&path)?;
+    fs::remove_file(&path)?;
+    Ok(content
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .collect())
+}
+
+/// upload manifest with base offsets of remote segments, content is staged in local file at path
+async fn write_manifest(tier: &Tier, prefix: &str, path: &Path, segments: &BTreeSet<Offset>) -> Result<(), IoError> {
+    let content: String = segments
+        .iter()
+        .map(|offset| format!("{}\n", offset))
+        .collect();
+    fs::write(path, content)?;
+    let key = manifest_key(prefix);
+    let result = tier.store.put(&key, path).await;
+    if let Err(err) = fs::remove_file(path) {
+        warn!("unable to remove manifest file: {}, {}", path.display(), err);
+    }
+    result
+}
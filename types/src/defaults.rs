@@ -11,8 +11,12 @@ pub const SC_DEFAULT_ID: i32 = 1;
 pub const SC_CONFIG_FILE: &'static str = "sc_server";
 pub const SC_PUBLIC_PORT: u16 = 9003;
 pub const SC_PRIVATE_PORT: u16 = 9004;
+pub const SC_METRICS_PORT: u16 = 9103;
 pub const SC_HOSTNAME: &'static str = "localhost";
 pub const SC_RECONCILIATION_INTERVAL_SEC: u64 = 300; // 5 min
+pub const SC_LEADER_REBALANCE_INTERVAL_SEC: u64 = 300; // 5 min
+pub const SC_LOCAL_STORE_DIR: &'static str = "/tmp/fluvio/sc";
+pub const SC_WATCH_HISTORY_SIZE: usize = 1000;
 
 // SPU defaults
 pub const SPU_DEFAULT_ID: i32 = 0;
@@ -20,16 +24,24 @@ pub const SPU_DEFAULT_NAME: &'static str = "spu";
 pub const SPU_CONFIG_FILE: &'static str = "spu_server";
 pub const SPU_PUBLIC_PORT: u16 = 9005;
 pub const SPU_PRIVATE_PORT: u16 = 9006;
+pub const SPU_METRICS_PORT: u16 = 9105;
 pub const SPU_PUBLIC_HOSTNAME: &'static str = "0.0.0.0";
 pub const SPU_PRIVATE_HOSTNAME: &'static str = "0.0.0.0";
+pub const SPU_METRICS_HOSTNAME: &'static str = "0.0.0.0";
 pub const SPU_CREDENTIALS_FILE: &'static str = "/etc/fluvio/.credentials/token_secret";
 pub const SPU_RETRY_SC_TIMEOUT_MS: u16 = 3000;
 pub const SPU_MIN_IN_SYNC_REPLICAS: u16 = 1;
+pub const SPU_REPLICA_LAG_TIME_MAX_MS: u64 = 30000;
+pub const SPU_REPLICATION_THROTTLED_RATE: u64 = 0; // unlimited
+pub const SPU_REPLICATION_MAX_SYNC_BYTES: u32 = 1048576;
 pub const SPU_LOG_BASE_DIR: &'static str = "/tmp/fluvio";
 pub const SPU_LOG_SIZE: &'static str = "1Gi";
 pub const SPU_LOG_INDEX_MAX_BYTES: u32 = 10485760;
 pub const SPU_LOG_INDEX_MAX_INTERVAL_BYTES: u32 = 4096;
 pub const SPU_LOG_SEGMENT_MAX_BYTES: u32 = 1073741824;
+pub const SPU_LOG_SEGMENT_MAX_AGE_MS: u64 = 604800000; // 7 days
+pub const SPU_MAX_MESSAGE_BYTES: u32 = 1048588;
+pub const SPU_MESSAGE_TIMESTAMP_DIFFERENCE_MAX_MS: i64 = -1; // unlimited
 
 // CLI config
 pub const CLI_PROFILES_DIR: &'static str = "profiles";
@@ -46,6 +58,8 @@ pub const FLV_SPU_PUBLIC_HOST: &'static str = "FLV_SPU_PUBLIC_HOST";
 pub const FLV_SPU_PUBLIC_PORT: &'static str = "FLV_SPU_PUBLIC_PORT";
 pub const FLV_SPU_PRIVATE_HOST: &'static str = "FLV_SPU_PRIVATE_HOST";
 pub const FLV_SPU_PRIVATE_PORT: &'static str = "FLV_SPU_PRIVATE_PORT";
+pub const FLV_SPU_METRICS_HOST: &'static str = "FLV_SPU_METRICS_HOST";
+pub const FLV_SPU_METRICS_PORT: &'static str = "FLV_SPU_METRICS_PORT";
 pub const FLV_SC_PRIVATE_HOST: &'static str = "FLV_SC_PRIVATE_HOST";
 pub const FLV_SC_PRIVATE_PORT: &'static str = "FLV_SC_PRIVATE_PORT";
 pub const FLV_SC_RETRY_TIMEOUT_MS: &'static str = "FLV_SC_RETRY_TIMEOUT_MS";
@@ -55,6 +69,7 @@ pub const FLV_LOG_SIZE: &'static str = "FLV_LOG_SIZE";
 pub const FLV_LOG_INDEX_MAX_BYTES: &'static str = "FLV_LOG_INDEX_MAX_BYTES";
 pub const FLV_LOG_INDEX_MAX_INTERVAL_BYTES: &'static str = "FLV_LOG_INDEX_MAX_INTERVAL_BYTES";
 pub const FLV_LOG_SEGMENT_MAX_BYTES: &'static str = "FLV_LOG_SEGMENT_MAX_BYTES";
+pub const FLV_LOG_SEGMENT_MAX_AGE_MS: &'static str = "FLV_LOG_SEGMENT_MAX_AGE_MS";
 
 // Health Checks
 pub const HC_SPU_TRIGGER_INTERVAL_SEC: u64 = 60 * 5;
@@ -65,3 +80,8 @@ pub const K8_TOKEN_SECRET_KEY: &'static str = "token_secret";
 
 // Kafka
 pub const KF_REQUEST_TIMEOUT_MS: i32 = 1500;
+
+// SC High Availability
+pub const SC_LEASE_NAME: &'static str = "fluvio-sc";
+pub const SC_LEASE_DURATION_SEC: u64 = 15;
+pub const SC_LEASE_RENEW_INTERVAL_SEC: u64 = 5;
@@ -1,13 +1,16 @@
 pub mod actions;
 pub mod config_helper;
 pub mod counters;
+pub mod metrics;
 pub mod string_helper;
 pub mod generators;
 
 mod logger;
 mod concurrent;
+mod time;
 
 pub use logger::init_logger;
+pub use time::now_ms;
 
 #[cfg(feature = "fixture")]
 pub mod fixture;
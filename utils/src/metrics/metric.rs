@@ -0,0 +1,129 @@
+//!
+//! # Metric values
+//!
+//! Values are updated lock free except histogram sum, so they can be kept by callers
+//! and updated on hot paths.
+//!
+use std::sync::Mutex;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+/// bucket upper bounds in seconds for request latencies
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// monotonically increasing value
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, value: u64) {
+        self.0.fetch_add(value, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// value which can go up and down
+#[derive(Debug, Default)]
+pub struct Gauge(AtomicI64);
+
+impl Gauge {
+    pub fn set(&self, value: i64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    pub fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dec(&self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// distribution of observed values over fixed buckets
+#[derive(Debug)]
+pub struct Histogram {
+    bounds: Vec<f64>,
+    buckets: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum: Mutex<f64>,
+}
+
+impl Histogram {
+    pub fn new(bounds: &[f64]) -> Self {
+        Self {
+            bounds: bounds.to_vec(),
+            buckets: bounds.iter().map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum: Mutex::new(0.0),
+        }
+    }
+
+    pub fn observe(&self, value: f64) {
+        if let Some(index) = self.bounds.iter().position(|bound| value <= *bound) {
+            self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        *self.sum.lock().unwrap() += value;
+    }
+
+    /// observe duration in seconds
+    pub fn observe_duration(&self, duration: Duration) {
+        self.observe(duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1e9);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    pub fn sum(&self) -> f64 {
+        *self.sum.lock().unwrap()
+    }
+
+    /// upper bounds with cumulative counts, values above last bound only appear in count
+    pub fn cumulative_buckets(&self) -> Vec<(f64, u64)> {
+        let mut total = 0;
+        self.bounds
+            .iter()
+            .zip(self.buckets.iter())
+            .map(|(bound, bucket)| {
+                total += bucket.load(Ordering::Relaxed);
+                (*bound, total)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::Histogram;
+
+    #[test]
+    fn test_histogram_buckets() {
+        let histogram = Histogram::new(&[1.0, 5.0]);
+        histogram.observe(0.5);
+        histogram.observe(1.0);
+        histogram.observe(3.0);
+        histogram.observe(10.0);
+
+        assert_eq!(histogram.cumulative_buckets(), vec![(1.0, 2), (5.0, 3)]);
+        assert_eq!(histogram.count(), 4);
+        assert!((histogram.sum() - 14.5).abs() < std::f64::EPSILON);
+    }
+}
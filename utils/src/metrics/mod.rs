@@ -0,0 +1,15 @@
+//!
+//! # Metrics
+//!
+//! Registry of labeled counters, gauges and histograms, encoded in Prometheus text format.
+//!
+mod metric;
+mod registry;
+
+pub use self::metric::Counter;
+pub use self::metric::Gauge;
+pub use self::metric::Histogram;
+pub use self::metric::LATENCY_BUCKETS;
+pub use self::registry::MetricsRegistry;
+pub use self::registry::SharedMetricsRegistry;
+pub use self::registry::MetricsCollector;
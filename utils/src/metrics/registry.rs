@@ -0,0 +1,261 @@
+//!
+//! # Metrics Registry
+//!
+//! Metrics are grouped in families by name, each metric of family has its own label values.
+//! Metrics are created on first lookup and kept by registry until family is cleared.
+//!
+//! Values which are cheaper to read than to track (offsets, counts of objects) are
+//! refreshed by collectors right before registry is encoded.
+//!
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::Counter;
+use super::Gauge;
+use super::Histogram;
+
+pub type SharedMetricsRegistry = Arc<MetricsRegistry>;
+
+type Labels = Vec<(String, String)>;
+
+/// refresh metrics from current state, called before registry is encoded
+pub trait MetricsCollector: Send + Sync {
+    fn collect(&self, registry: &MetricsRegistry);
+}
+
+#[derive(Debug, Clone)]
+enum Metric {
+    Counter(Arc<Counter>),
+    Gauge(Arc<Gauge>),
+    Histogram(Arc<Histogram>),
+}
+
+impl Metric {
+    fn type_label(&self) -> &'static str {
+        match self {
+            Metric::Counter(_) => "counter",
+            Metric::Gauge(_) => "gauge",
+            Metric::Histogram(_) => "histogram",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Family {
+    help: String,
+    metrics: BTreeMap<Labels, Metric>,
+}
+
+#[derive(Default)]
+pub struct MetricsRegistry {
+    families: Mutex<BTreeMap<String, Family>>,
+    collectors: Mutex<Vec<Box<dyn MetricsCollector>>>,
+}
+
+impl std::fmt::Debug for MetricsRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "MetricsRegistry")
+    }
+}
+
+impl MetricsRegistry {
+    pub fn new_shared() -> SharedMetricsRegistry {
+        Arc::new(Self::default())
+    }
+
+    pub fn counter(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Counter> {
+        match self.metric(name, help, labels, || {
+            Metric::Counter(Arc::new(Counter::default()))
+        }) {
+            Metric::Counter(counter) => counter,
+            other => panic!("metric: {} is {}, not counter", name, other.type_label()),
+        }
+    }
+
+    pub fn gauge(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Gauge> {
+        match self.metric(name, help, labels, || Metric::Gauge(Arc::new(Gauge::default()))) {
+            Metric::Gauge(gauge) => gauge,
+            other => panic!("metric: {} is {}, not gauge", name, other.type_label()),
+        }
+    }
+
+    /// histogram with bucket bounds, bounds are only used when histogram is created
+    pub fn histogram(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        bounds: &[f64],
+    ) -> Arc<Histogram> {
+        match self.metric(name, help, labels, || {
+            Metric::Histogram(Arc::new(Histogram::new(bounds)))
+        }) {
+            Metric::Histogram(histogram) => histogram,
+            other => panic!("metric: {} is {}, not histogram", name, other.type_label()),
+        }
+    }
+
+    /// remove all metrics of family, used by collectors to drop metrics of removed objects
+    pub fn clear(&self, name: &str) {
+        if let Some(family) = self.families.lock().unwrap().get_mut(name) {
+            family.metrics.clear();
+        }
+    }
+
+    pub fn add_collector<C>(&self, collector: C)
+    where
+        C: MetricsCollector + 'static,
+    {
+        self.collectors.lock().unwrap().push(Box::new(collector));
+    }
+
+    fn metric<F>(&self, name: &str, help: &str, labels: &[(&str, &str)], create: F) -> Metric
+    where
+        F: FnOnce() -> Metric,
+    {
+        let labels: Labels = labels
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        let mut families = self.families.lock().unwrap();
+        let family = families.entry(name.to_owned()).or_insert_with(|| Family {
+            help: help.to_owned(),
+            metrics: BTreeMap::new(),
+        });
+        family.metrics.entry(labels).or_insert_with(create).clone()
+    }
+
+    /// refresh collected metrics and encode all metrics in Prometheus text format
+    pub fn encode(&self) -> String {
+        // collectors stay locked while encoding, so concurrent scrape doesn't see cleared families
+        let collectors = self.collectors.lock().unwrap();
+        for collector in collectors.iter() {
+            collector.collect(self);
+        }
+
+        let families = self.families.lock().unwrap();
+        let mut out = String::new();
+        for (name, family) in families.iter() {
+            let type_label = match family.metrics.values().next() {
+                Some(metric) => metric.type_label(),
+                None => continue,
+            };
+            let _ = writeln!(out, "# HELP {} {}", name, escape_help(&family.help));
+            let _ = writeln!(out, "# TYPE {} {}", name, type_label);
+
+            for (labels, metric) in family.metrics.iter() {
+                match metric {
+                    Metric::Counter(counter) => {
+                        write_sample(&mut out, name, labels, None, &counter.get().to_string())
+                    }
+                    Metric::Gauge(gauge) => {
+                        write_sample(&mut out, name, labels, None, &gauge.get().to_string())
+                    }
+                    Metric::Histogram(histogram) => {
+                        let bucket_name = format!("{}_bucket", name);
+                        for (bound, count) in histogram.cumulative_buckets() {
+                            let le = bound.to_string();
+                            write_sample(&mut out, &bucket_name, labels, Some(&le), &count.to_string());
+                        }
+                        let count = histogram.count().to_string();
+                        write_sample(&mut out, &bucket_name, labels, Some("+Inf"), &count);
+                        write_sample(&mut out, &format!("{}_sum", name), labels, None, &histogram.sum().to_string());
+                        write_sample(&mut out, &format!("{}_count", name), labels, None, &count);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &[(String, String)], le: Option<&str>, value: &str) {
+    out.push_str(name);
+    if !labels.is_empty() || le.is_some() {
+        let mut pairs: Vec<String> = labels
+            .iter()
+            .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+            .collect();
+        if let Some(le) = le {
+            pairs.push(format!("le=\"{}\"", le));
+        }
+        out.push('{');
+        out.push_str(&pairs.join(","));
+        out.push('}');
+    }
+    out.push(' ');
+    out.push_str(value);
+    out.push('\n');
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::MetricsRegistry;
+    use super::MetricsCollector;
+
+    struct TestCollector;
+
+    impl MetricsCollector for TestCollector {
+        fn collect(&self, registry: &MetricsRegistry) {
+            registry.clear("replicas");
+            registry.gauge("replicas", "replicas", &[("topic", "test")]).set(3);
+        }
+    }
+
+    #[test]
+    fn test_same_metric_for_labels() {
+        let registry = MetricsRegistry::default();
+        registry.counter("requests_total", "requests", &[("api_key", "Produce")]).inc();
+        registry.counter("requests_total", "requests", &[("api_key", "Produce")]).inc_by(2);
+        registry.counter("requests_total", "requests", &[("api_key", "Fetch")]).inc();
+
+        assert_eq!(registry.counter("requests_total", "requests", &[("api_key", "Produce")]).get(), 3);
+        assert_eq!(registry.counter("requests_total", "requests", &[("api_key", "Fetch")]).get(), 1);
+    }
+
+    #[test]
+    fn test_encode() {
+        let registry = MetricsRegistry::default();
+        registry.counter("bytes_in_total", "bytes \"in\"", &[("topic", "a\"b")]).inc_by(10);
+        registry.gauge("connections", "open connections", &[]).set(2);
+        registry
+            .histogram("latency_seconds", "latency", &[("api_key", "Fetch")], &[0.1, 1.0])
+            .observe(0.5);
+        registry.add_collector(TestCollector);
+
+        let expected = "\
+# HELP bytes_in_total bytes \"in\"
+# TYPE bytes_in_total counter
+bytes_in_total{topic=\"a\\\"b\"} 10
+# HELP connections open connections
+# TYPE connections gauge
+connections 2
+# HELP latency_seconds latency
+# TYPE latency_seconds histogram
+latency_seconds_bucket{api_key=\"Fetch\",le=\"0.1\"} 0
+latency_seconds_bucket{api_key=\"Fetch\",le=\"1\"} 1
+latency_seconds_bucket{api_key=\"Fetch\",le=\"+Inf\"} 1
+latency_seconds_sum{api_key=\"Fetch\"} 0.5
+latency_seconds_count{api_key=\"Fetch\"} 1
+# HELP replicas replicas
+# TYPE replicas gauge
+replicas{topic=\"test\"} 3
+";
+        assert_eq!(registry.encode(), expected);
+    }
+}
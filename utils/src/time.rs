@@ -0,0 +1,10 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// current time in milliseconds since epoch, same unit as record timestamps
+pub fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or(0)
+}